@@ -28,6 +28,7 @@ use gm_core::{
     available_solver_descriptors, calculate_recommended_settings, default_solver_configuration,
     models::{ApiInput, SolverConfiguration, SolverKind, SolverResult},
     run_solver, solver_descriptor,
+    solver_support::SolverError,
 };
 use schemars::Schema;
 use serde::{de::DeserializeOwned, Serialize};
@@ -76,12 +77,19 @@ pub struct SchemaSummary {
     pub version: &'static str,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ApiError {
     status: StatusCode,
     body: PublicErrorEnvelope,
 }
 
+impl ApiError {
+    #[cfg(feature = "sqlite")]
+    pub(crate) fn envelope(&self) -> &PublicErrorEnvelope {
+        &self.body
+    }
+}
+
 impl IntoResponse for ApiError {
     fn into_response(self) -> Response {
         (self.status, Json(self.body)).into_response()
@@ -188,7 +196,7 @@ pub async fn solve_handler(body: Bytes) -> Result<Json<SolverResult>, ApiError>
     let payload: SolveRequest = parse_json_body(&body, "solve", &["solve-request"])?;
     let payload: ApiInput = payload.into();
     let result =
-        run_solver(&payload).map_err(|error| map_solver_error(format!("{:?}", error), "solve"))?;
+        run_solver(&payload).map_err(|error| map_input_solver_error(&error, &payload, "solve"))?;
     Ok(Json(result))
 }
 
@@ -237,7 +245,7 @@ pub async fn validate_scenario_handler(body: Bytes) -> Result<Json<ValidateRespo
             valid: false,
             issues: vec![ValidationIssue {
                 code: Some("infeasible-scenario".to_string()),
-                message: format!("{:?}", error),
+                message: error.message(),
                 path: None,
             }],
         },
@@ -261,7 +269,7 @@ pub async fn recommend_settings_handler(
         &request.constraints,
         request.desired_runtime_seconds,
     )
-    .map_err(|error| map_solver_error(format!("{:?}", error), "recommend-settings"))?;
+    .map_err(|error| map_solver_error(error.message(), "recommend-settings"))?;
     Ok(Json(recommended))
 }
 
@@ -290,7 +298,7 @@ pub async fn evaluate_input_handler(body: Bytes) -> Result<Json<SolverResult>, A
     }
     payload.solver.stop_conditions.max_iterations = Some(0);
     let result = run_solver(&payload)
-        .map_err(|error| map_solver_error(format!("{:?}", error), "evaluate-input"))?;
+        .map_err(|error| map_input_solver_error(&error, &payload, "evaluate-input"))?;
     Ok(Json(result))
 }
 
@@ -308,7 +316,7 @@ fn help_path(operation_id: &str) -> String {
 }
 
 #[allow(clippy::result_large_err)]
-fn parse_json_body<T: DeserializeOwned>(
+pub(crate) fn parse_json_body<T: DeserializeOwned>(
     body: &Bytes,
     operation_id: &str,
     schema_ids: &[&str],
@@ -340,7 +348,7 @@ fn parse_json_body<T: DeserializeOwned>(
     })
 }
 
-fn api_error(
+pub(crate) fn api_error(
     code: &str,
     status: StatusCode,
     message: impl Into<String>,
@@ -365,6 +373,7 @@ fn api_error(
                         .map(|operation_id| help_path(operation_id))
                         .collect()
                 }),
+                details: None,
             },
         },
     }
@@ -441,6 +450,17 @@ fn map_solver_error(message: String, operation_id: &str) -> ApiError {
     )
 }
 
+/// Like [`map_solver_error`], but also reports the entities of `input` the error refers to.
+pub(crate) fn map_input_solver_error(
+    error: &SolverError,
+    input: &ApiInput,
+    operation_id: &str,
+) -> ApiError {
+    let mut api_error = map_solver_error(error.message(), operation_id);
+    api_error.body.error.details = Some(error.details(input));
+    api_error
+}
+
 fn route_ref(binding: &HttpContractBinding) -> RouteRef {
     RouteRef {
         method: binding.method,
@@ -0,0 +1,500 @@
+//! Background solve jobs served by the `solver-server` binary.
+//!
+//! `POST /solve` parses the request up front and queues it; at most `workers` jobs solve at
+//! once on the blocking thread pool, and the rest wait their turn. Clients poll
+//! `GET /jobs/{id}` for status and the latest progress, or subscribe to
+//! `GET /jobs/{id}/events` for a Server-Sent Events stream of it, then fetch
+//! `GET /jobs/{id}/result`.
+//! Finished jobs are kept until [`MAX_FINISHED_JOBS`] newer ones have finished.
+//!
+//! Submissions are refused with `503 Service Unavailable` while the queue already holds its
+//! maximum of waiting jobs, and with `413 Payload Too Large` when the scenario's estimated
+//! solver memory exceeds [`MAX_JOB_MEMORY_BYTES`].
+
+use crate::api::handlers::{api_error, map_input_solver_error, parse_json_body, ApiError};
+use crate::api::metrics::{JobMetrics, QueueGauges};
+#[cfg(feature = "sqlite")]
+use crate::api::persistence::{RunStore, PROGRESS_SNAPSHOT_INTERVAL_SECONDS};
+use axum::{
+    body::Bytes,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Json, Response,
+    },
+};
+use futures_util::stream::{self, Stream};
+use gm_contracts::{
+    errors::{INTERNAL_ERROR, INVALID_INPUT_ERROR, SERVER_BUSY_ERROR},
+    types::SolveRequest,
+};
+use gm_core::{
+    estimate_memory,
+    models::{ApiInput, ProgressCallback, ProgressUpdate, SolverResult},
+    run_solver_with_progress,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::convert::Infallible;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tokio::sync::{watch, Semaphore};
+use uuid::Uuid;
+
+/// Finished jobs retained for result lookups before the oldest is dropped.
+pub const MAX_FINISHED_JOBS: usize = 1000;
+
+/// Jobs allowed to wait for a worker unless [`JobQueue::with_max_queued`] says otherwise.
+pub const DEFAULT_MAX_QUEUED_JOBS: usize = 64;
+
+/// Largest estimated solver memory a single job may need.
+pub const MAX_JOB_MEMORY_BYTES: u64 = 2 * 1024 * 1024 * 1024;
+
+/// Largest `POST /solve` body accepted, in bytes.
+pub const MAX_SOLVE_REQUEST_BYTES: usize = 16 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    Queued,
+    Running,
+    Completed,
+    Failed,
+}
+
+impl JobStatus {
+    pub fn is_finished(self) -> bool {
+        matches!(self, Self::Completed | Self::Failed)
+    }
+}
+
+/// The latest progress a running job reported.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct JobProgress {
+    pub iteration: u64,
+    pub max_iterations: u64,
+    pub current_score: f64,
+    pub best_score: f64,
+    pub elapsed_seconds: f64,
+    pub estimated_remaining_seconds: Option<f64>,
+}
+
+impl From<&ProgressUpdate> for JobProgress {
+    fn from(progress: &ProgressUpdate) -> Self {
+        Self {
+            iteration: progress.iteration,
+            max_iterations: progress.max_iterations,
+            current_score: progress.current_score,
+            best_score: progress.best_score,
+            elapsed_seconds: progress.elapsed_seconds,
+            estimated_remaining_seconds: progress.estimated_remaining_seconds,
+        }
+    }
+}
+
+/// Status of a queued, running, or finished solve job.
+#[derive(Serialize, JsonSchema)]
+pub struct JobStatusResponse {
+    pub id: String,
+    pub status: JobStatus,
+    pub progress: Option<JobProgress>,
+    pub result_path: String,
+}
+
+/// What event subscribers see of a job: its status and the latest full progress update.
+#[derive(Clone)]
+struct JobFeed {
+    status: JobStatus,
+    progress: Option<Arc<ProgressUpdate>>,
+}
+
+struct Job {
+    feed: watch::Sender<JobFeed>,
+    estimated_memory_bytes: u64,
+    outcome: Option<JobOutcome>,
+}
+
+#[derive(Clone)]
+enum JobOutcome {
+    Completed(Box<SolverResult>),
+    Failed(Box<ApiError>),
+}
+
+#[derive(Default)]
+struct JobTable {
+    jobs: HashMap<Uuid, Job>,
+    /// Finished job ids, oldest first.
+    finished: VecDeque<Uuid>,
+}
+
+/// Shared queue of solve jobs; cheap to clone into handlers.
+#[derive(Clone)]
+pub struct JobQueue {
+    table: Arc<Mutex<JobTable>>,
+    permits: Arc<Semaphore>,
+    max_queued: usize,
+    metrics: Arc<JobMetrics>,
+    #[cfg(feature = "sqlite")]
+    store: Option<Arc<RunStore>>,
+}
+
+impl JobQueue {
+    /// Creates a queue that solves at most `workers` jobs at once.
+    pub fn new(workers: usize) -> Self {
+        Self {
+            table: Arc::default(),
+            permits: Arc::new(Semaphore::new(workers.max(1))),
+            max_queued: DEFAULT_MAX_QUEUED_JOBS,
+            metrics: Arc::default(),
+            #[cfg(feature = "sqlite")]
+            store: None,
+        }
+    }
+
+    /// Creates a queue like [`JobQueue::new`] that also records every job in `store`.
+    #[cfg(feature = "sqlite")]
+    pub fn with_store(workers: usize, store: RunStore) -> Self {
+        Self {
+            store: Some(Arc::new(store)),
+            ..Self::new(workers)
+        }
+    }
+
+    /// Lets at most `max_queued` jobs wait for a worker; further submissions are refused
+    /// until one starts.
+    pub fn with_max_queued(self, max_queued: usize) -> Self {
+        Self { max_queued, ..self }
+    }
+
+    #[cfg(feature = "sqlite")]
+    pub(crate) fn store(&self) -> Option<&RunStore> {
+        self.store.as_deref()
+    }
+
+    /// Writes to the run store, if there is one. Storage errors are logged and never fail
+    /// the job.
+    #[cfg(feature = "sqlite")]
+    fn persist(&self, id: Uuid, write: impl FnOnce(&RunStore) -> rusqlite::Result<()>) {
+        if let Some(store) = &self.store {
+            if let Err(error) = write(store) {
+                tracing::warn!("failed to record job {id}: {error}");
+            }
+        }
+    }
+
+    /// Queues `input` and returns the new job's id, or refuses it when the queue is full or
+    /// the scenario is too large to solve. `event` labels the run in the run store, if there
+    /// is one. Must be called inside a Tokio runtime.
+    #[cfg_attr(not(feature = "sqlite"), allow(unused_variables))]
+    #[allow(clippy::result_large_err)]
+    pub fn submit(&self, input: ApiInput, event: Option<&str>) -> Result<Uuid, ApiError> {
+        let estimated_memory_bytes = estimate_memory(&input).total_bytes;
+        if estimated_memory_bytes > MAX_JOB_MEMORY_BYTES {
+            return Err(api_error(
+                INVALID_INPUT_ERROR,
+                StatusCode::PAYLOAD_TOO_LARGE,
+                format!(
+                    "Scenario needs an estimated {estimated_memory_bytes} bytes of solver memory; \
+                     jobs are limited to {MAX_JOB_MEMORY_BYTES}"
+                ),
+                Some("scenario".to_string()),
+                Vec::new(),
+                None,
+            ));
+        }
+
+        let id = Uuid::new_v4();
+        {
+            let mut table = self.table.lock().unwrap();
+            let queued = table
+                .jobs
+                .values()
+                .filter(|job| job.feed.borrow().status == JobStatus::Queued)
+                .count();
+            if queued >= self.max_queued {
+                return Err(api_error(
+                    SERVER_BUSY_ERROR,
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    format!("{queued} jobs are already waiting for a worker; retry later"),
+                    None,
+                    Vec::new(),
+                    None,
+                ));
+            }
+            table.jobs.insert(
+                id,
+                Job {
+                    feed: watch::Sender::new(JobFeed {
+                        status: JobStatus::Queued,
+                        progress: None,
+                    }),
+                    estimated_memory_bytes,
+                    outcome: None,
+                },
+            );
+        }
+        #[cfg(feature = "sqlite")]
+        self.persist(id, |store| store.record_submission(id, event, &input));
+
+        let queue = self.clone();
+        tokio::spawn(async move {
+            let _permit = queue.permits.clone().acquire_owned().await.unwrap();
+            queue.update(id, |feed| feed.status = JobStatus::Running);
+            #[cfg(feature = "sqlite")]
+            queue.persist(id, |store| store.record_status(id, JobStatus::Running));
+            let started = Instant::now();
+            let worker_queue = queue.clone();
+            let outcome = tokio::task::spawn_blocking(move || worker_queue.run(id, &input))
+                .await
+                .unwrap_or_else(|error| {
+                    JobOutcome::Failed(Box::new(api_error(
+                        INTERNAL_ERROR,
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        format!("Solve job panicked: {error}"),
+                        None,
+                        Vec::new(),
+                        None,
+                    )))
+                });
+            queue.metrics.record_finished(
+                matches!(outcome, JobOutcome::Completed(_)),
+                started.elapsed(),
+            );
+            queue.finish(id, outcome);
+        });
+        Ok(id)
+    }
+
+    /// Current status of job `id`, if it is known.
+    pub fn status(&self, id: Uuid) -> Option<JobStatusResponse> {
+        let table = self.table.lock().unwrap();
+        let job = table.jobs.get(&id)?;
+        let status = job.feed.borrow().status_response(id);
+        Some(status)
+    }
+
+    /// Subscribes to status and progress changes of job `id`. The receiver sees the current
+    /// state as an unseen change, and closes once the job is evicted.
+    fn subscribe(&self, id: Uuid) -> Option<watch::Receiver<JobFeed>> {
+        let table = self.table.lock().unwrap();
+        let mut receiver = table.jobs.get(&id)?.feed.subscribe();
+        receiver.mark_changed();
+        Some(receiver)
+    }
+
+    /// Prometheus text for the queue's counters and current gauges.
+    pub fn render_metrics(&self) -> String {
+        let mut gauges = QueueGauges {
+            queued: 0,
+            running: 0,
+            iterations_per_second: 0.0,
+            running_estimated_memory_bytes: 0,
+        };
+        for job in self.table.lock().unwrap().jobs.values() {
+            let feed = job.feed.borrow();
+            match feed.status {
+                JobStatus::Queued => gauges.queued += 1,
+                JobStatus::Running => {
+                    gauges.running += 1;
+                    gauges.running_estimated_memory_bytes += job.estimated_memory_bytes;
+                    if let Some(progress) = feed.progress.as_deref() {
+                        if progress.elapsed_seconds > 0.0 {
+                            gauges.iterations_per_second +=
+                                progress.iteration as f64 / progress.elapsed_seconds;
+                        }
+                    }
+                }
+                JobStatus::Completed | JobStatus::Failed => {}
+            }
+        }
+        self.metrics.render(&gauges)
+    }
+
+    fn run(&self, id: Uuid, input: &ApiInput) -> JobOutcome {
+        let queue = self.clone();
+        let last_iteration = AtomicU64::new(0);
+        #[cfg(feature = "sqlite")]
+        let last_snapshot = Mutex::new(f64::NEG_INFINITY);
+        let callback: ProgressCallback = Box::new(move |progress: &ProgressUpdate| {
+            #[cfg(feature = "sqlite")]
+            {
+                let mut last_snapshot = last_snapshot.lock().unwrap();
+                if progress.elapsed_seconds - *last_snapshot >= PROGRESS_SNAPSHOT_INTERVAL_SECONDS {
+                    *last_snapshot = progress.elapsed_seconds;
+                    queue.persist(id, |store| {
+                        store.record_progress(id, &JobProgress::from(progress))
+                    });
+                }
+            }
+            let previous_iteration = last_iteration.swap(progress.iteration, Ordering::Relaxed);
+            queue
+                .metrics
+                .record_iterations(progress.iteration.saturating_sub(previous_iteration));
+            let progress = Arc::new(progress.clone());
+            queue.update(id, |feed| feed.progress = Some(progress));
+            true
+        });
+        match run_solver_with_progress(input, Some(&callback)) {
+            Ok(result) => JobOutcome::Completed(Box::new(result)),
+            Err(error) => {
+                JobOutcome::Failed(Box::new(map_input_solver_error(&error, input, "solve")))
+            }
+        }
+    }
+
+    fn update(&self, id: Uuid, change: impl FnOnce(&mut JobFeed)) {
+        if let Some(job) = self.table.lock().unwrap().jobs.get(&id) {
+            job.feed.send_modify(change);
+        }
+    }
+
+    fn finish(&self, id: Uuid, outcome: JobOutcome) {
+        #[cfg(feature = "sqlite")]
+        self.persist(id, |store| {
+            store.record_outcome(
+                id,
+                match &outcome {
+                    JobOutcome::Completed(result) => Ok(result),
+                    JobOutcome::Failed(error) => Err(error.envelope()),
+                },
+            )
+        });
+        let mut table = self.table.lock().unwrap();
+        if let Some(job) = table.jobs.get_mut(&id) {
+            let status = match outcome {
+                JobOutcome::Completed(_) => JobStatus::Completed,
+                JobOutcome::Failed(_) => JobStatus::Failed,
+            };
+            job.outcome = Some(outcome);
+            job.feed.send_modify(|feed| feed.status = status);
+        }
+        table.finished.push_back(id);
+        while table.finished.len() > MAX_FINISHED_JOBS {
+            if let Some(evicted) = table.finished.pop_front() {
+                table.jobs.remove(&evicted);
+            }
+        }
+    }
+}
+
+impl JobFeed {
+    fn status_response(&self, id: Uuid) -> JobStatusResponse {
+        JobStatusResponse {
+            id: id.to_string(),
+            status: self.status,
+            progress: self.progress.as_deref().map(JobProgress::from),
+            result_path: format!("/jobs/{id}/result"),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SubmitJobQuery {
+    /// Label grouping runs of the same event in the run store.
+    pub event: Option<String>,
+}
+
+pub async fn submit_job_handler(
+    State(queue): State<JobQueue>,
+    Query(query): Query<SubmitJobQuery>,
+    body: Bytes,
+) -> Result<(StatusCode, Json<JobStatusResponse>), ApiError> {
+    let payload: SolveRequest = parse_json_body(&body, "solve", &["solve-request"])?;
+    let id = queue.submit(payload.into(), query.event.as_deref())?;
+    let status = queue.status(id).expect("job was just queued");
+    Ok((StatusCode::ACCEPTED, Json(status)))
+}
+
+pub async fn job_status_handler(
+    State(queue): State<JobQueue>,
+    Path(job_id): Path<String>,
+) -> Result<Json<JobStatusResponse>, ApiError> {
+    let id = parse_job_id(&job_id)?;
+    queue
+        .status(id)
+        .map(Json)
+        .ok_or_else(|| unknown_job_api_error(&job_id))
+}
+
+/// Streams job `job_id` as Server-Sent Events: a `progress` event carrying the full
+/// `ProgressUpdate` whenever the solver reports one, and a `status` event carrying the
+/// [`JobStatusResponse`] while no progress exists yet and once the job finishes, after which
+/// the stream ends. Slow clients skip intermediate updates rather than falling behind.
+pub async fn job_events_handler(
+    State(queue): State<JobQueue>,
+    Path(job_id): Path<String>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ApiError> {
+    let id = parse_job_id(&job_id)?;
+    let receiver = queue
+        .subscribe(id)
+        .ok_or_else(|| unknown_job_api_error(&job_id))?;
+
+    let events = stream::unfold(Some(receiver), move |receiver| async move {
+        let mut receiver = receiver?;
+        receiver.changed().await.ok()?;
+        let feed = receiver.borrow_and_update().clone();
+        let event = match &feed.progress {
+            Some(progress) if !feed.status.is_finished() => {
+                json_event("progress", progress.as_ref())
+            }
+            _ => json_event("status", &feed.status_response(id)),
+        };
+        let receiver = (!feed.status.is_finished()).then_some(receiver);
+        Some((Ok(event), receiver))
+    });
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+fn json_event<T: Serialize>(name: &str, data: &T) -> Event {
+    Event::default()
+        .event(name)
+        .json_data(data)
+        .expect("job events serialize to JSON")
+}
+
+/// Returns the solve result once the job has finished: the solver result for a completed
+/// job, the job's error envelope for a failed one, and `202 Accepted` with the status while it
+/// is still queued or running.
+pub async fn job_result_handler(
+    State(queue): State<JobQueue>,
+    Path(job_id): Path<String>,
+) -> Result<Response, ApiError> {
+    let id = parse_job_id(&job_id)?;
+    let outcome = {
+        let table = queue.table.lock().unwrap();
+        let job = table
+            .jobs
+            .get(&id)
+            .ok_or_else(|| unknown_job_api_error(&job_id))?;
+        job.outcome.clone()
+    };
+    match outcome {
+        Some(JobOutcome::Completed(result)) => Ok(Json(result).into_response()),
+        Some(JobOutcome::Failed(error)) => Err(*error),
+        None => {
+            let status = queue
+                .status(id)
+                .ok_or_else(|| unknown_job_api_error(&job_id))?;
+            Ok((StatusCode::ACCEPTED, Json(status)).into_response())
+        }
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn parse_job_id(job_id: &str) -> Result<Uuid, ApiError> {
+    Uuid::parse_str(job_id).map_err(|_| unknown_job_api_error(job_id))
+}
+
+fn unknown_job_api_error(job_id: &str) -> ApiError {
+    api_error(
+        INVALID_INPUT_ERROR,
+        StatusCode::NOT_FOUND,
+        format!("Unknown job id '{}'", job_id),
+        Some("job_id".to_string()),
+        Vec::new(),
+        Some(Vec::new()),
+    )
+}
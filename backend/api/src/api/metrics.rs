@@ -0,0 +1,178 @@
+//! Prometheus metrics for the `solver-server` job queue, served as text at `GET /metrics`.
+//!
+//! Counters and the job duration histogram accumulate in [`JobMetrics`]; queue depth,
+//! throughput, and memory gauges are read from the job table at scrape time.
+
+use crate::api::jobs::JobQueue;
+use axum::{extract::State, http::header::CONTENT_TYPE, response::IntoResponse};
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Upper bounds of the job duration histogram buckets, in seconds.
+pub const JOB_DURATION_BUCKETS_SECONDS: [f64; 10] =
+    [0.1, 0.5, 1.0, 5.0, 10.0, 30.0, 60.0, 300.0, 900.0, 3600.0];
+
+/// Counters the job queue updates as jobs progress and finish.
+#[derive(Default)]
+pub struct JobMetrics {
+    iterations: AtomicU64,
+    completed: AtomicU64,
+    failed: AtomicU64,
+    durations: Mutex<DurationHistogram>,
+}
+
+#[derive(Default)]
+struct DurationHistogram {
+    /// Jobs at or below each bound of [`JOB_DURATION_BUCKETS_SECONDS`] (not cumulative).
+    buckets: [u64; JOB_DURATION_BUCKETS_SECONDS.len()],
+    sum_seconds: f64,
+    count: u64,
+}
+
+/// Gauges read from the job table when metrics are scraped.
+pub struct QueueGauges {
+    pub queued: usize,
+    pub running: usize,
+    /// Summed iterations per second of the running jobs, from their latest progress.
+    pub iterations_per_second: f64,
+    /// Summed [`gm_core::estimate_memory`] totals of the running jobs.
+    pub running_estimated_memory_bytes: u64,
+}
+
+impl JobMetrics {
+    pub fn record_iterations(&self, iterations: u64) {
+        self.iterations.fetch_add(iterations, Ordering::Relaxed);
+    }
+
+    /// Records a finished job that solved for `duration`.
+    pub fn record_finished(&self, completed: bool, duration: Duration) {
+        let counter = if completed {
+            &self.completed
+        } else {
+            &self.failed
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+
+        let seconds = duration.as_secs_f64();
+        let mut durations = self.durations.lock().unwrap();
+        if let Some(bucket) = JOB_DURATION_BUCKETS_SECONDS
+            .iter()
+            .position(|&bound| seconds <= bound)
+        {
+            durations.buckets[bucket] += 1;
+        }
+        durations.sum_seconds += seconds;
+        durations.count += 1;
+    }
+
+    /// Renders every metric in the Prometheus text exposition format.
+    pub fn render(&self, gauges: &QueueGauges) -> String {
+        let mut out = String::new();
+        gauge(
+            &mut out,
+            "groupmixer_jobs_queued",
+            "Jobs waiting for a solver worker.",
+            gauges.queued as f64,
+        );
+        gauge(
+            &mut out,
+            "groupmixer_jobs_running",
+            "Jobs currently solving.",
+            gauges.running as f64,
+        );
+        gauge(
+            &mut out,
+            "groupmixer_solver_iterations_per_second",
+            "Combined search throughput of the running jobs.",
+            gauges.iterations_per_second,
+        );
+        gauge(
+            &mut out,
+            "groupmixer_running_jobs_estimated_memory_bytes",
+            "Estimated peak memory of the running jobs.",
+            gauges.running_estimated_memory_bytes as f64,
+        );
+        if let Some(resident_bytes) = resident_memory_bytes() {
+            gauge(
+                &mut out,
+                "process_resident_memory_bytes",
+                "Resident memory of the server process.",
+                resident_bytes as f64,
+            );
+        }
+
+        writeln!(
+            out,
+            "# HELP groupmixer_solver_iterations_total Search iterations performed by all jobs.\n\
+             # TYPE groupmixer_solver_iterations_total counter\n\
+             groupmixer_solver_iterations_total {}",
+            self.iterations.load(Ordering::Relaxed)
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "# HELP groupmixer_jobs_finished_total Jobs that finished, by outcome.\n\
+             # TYPE groupmixer_jobs_finished_total counter\n\
+             groupmixer_jobs_finished_total{{status=\"completed\"}} {}\n\
+             groupmixer_jobs_finished_total{{status=\"failed\"}} {}",
+            self.completed.load(Ordering::Relaxed),
+            self.failed.load(Ordering::Relaxed)
+        )
+        .unwrap();
+
+        let durations = self.durations.lock().unwrap();
+        out.push_str(
+            "# HELP groupmixer_job_duration_seconds Time finished jobs spent solving.\n\
+             # TYPE groupmixer_job_duration_seconds histogram\n",
+        );
+        let mut cumulative = 0;
+        for (bound, count) in JOB_DURATION_BUCKETS_SECONDS.iter().zip(durations.buckets) {
+            cumulative += count;
+            writeln!(
+                out,
+                "groupmixer_job_duration_seconds_bucket{{le=\"{bound}\"}} {cumulative}"
+            )
+            .unwrap();
+        }
+        writeln!(
+            out,
+            "groupmixer_job_duration_seconds_bucket{{le=\"+Inf\"}} {}\n\
+             groupmixer_job_duration_seconds_sum {}\n\
+             groupmixer_job_duration_seconds_count {}",
+            durations.count, durations.sum_seconds, durations.count
+        )
+        .unwrap();
+        out
+    }
+}
+
+pub async fn metrics_handler(State(queue): State<JobQueue>) -> impl IntoResponse {
+    (
+        [(CONTENT_TYPE, "text/plain; version=0.0.4")],
+        queue.render_metrics(),
+    )
+}
+
+fn gauge(out: &mut String, name: &str, help: &str, value: f64) {
+    writeln!(
+        out,
+        "# HELP {name} {help}\n# TYPE {name} gauge\n{name} {value}"
+    )
+    .unwrap();
+}
+
+/// Resident set size of this process, where the platform reports it.
+fn resident_memory_bytes() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kilobytes = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    Some(kilobytes * 1024)
+}
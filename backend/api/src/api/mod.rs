@@ -1,3 +1,8 @@
 pub mod contract_surface;
 pub mod handlers;
+pub mod jobs;
+pub mod metrics;
+pub mod openapi;
+#[cfg(feature = "sqlite")]
+pub mod persistence;
 pub mod routes;
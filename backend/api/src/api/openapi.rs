@@ -0,0 +1,142 @@
+//! OpenAPI 3 document for the `solver-server` routes.
+//!
+//! Component schemas come from the same `schemars` derives as the contract schemas, so the
+//! document follows the request and response types without a hand-maintained copy.
+
+use crate::api::jobs::JobStatusResponse;
+use axum::response::Json;
+use gm_contracts::types::{
+    ProgressUpdateContract, PublicErrorEnvelope, SolveRequest, SolveResponse,
+};
+use schemars::{generate::SchemaSettings, JsonSchema, SchemaGenerator};
+use serde_json::{json, Value};
+
+/// Builds the OpenAPI document describing `POST /solve` and the `/jobs/{job_id}` routes.
+pub fn solver_server_openapi() -> Value {
+    let mut generator = SchemaSettings::openapi3().into_generator();
+    let solve_request = schema_ref::<SolveRequest>(&mut generator);
+    let solve_response = schema_ref::<SolveResponse>(&mut generator);
+    let job_status = schema_ref::<JobStatusResponse>(&mut generator);
+    let progress_update = schema_ref::<ProgressUpdateContract>(&mut generator);
+    let error = schema_ref::<PublicErrorEnvelope>(&mut generator);
+    let schemas = generator.take_definitions(true);
+
+    let job_id = json!({
+        "name": "job_id",
+        "in": "path",
+        "required": true,
+        "schema": { "type": "string", "format": "uuid" }
+    });
+    let json_content = |schema: &Value| json!({ "application/json": { "schema": schema } });
+    let error_response =
+        |description: &str| json!({ "description": description, "content": json_content(&error) });
+
+    json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "GroupMixer solver server",
+            "version": env!("CARGO_PKG_VERSION"),
+            "description": "Queues solve requests and serves their progress and results."
+        },
+        "paths": {
+            "/solve": {
+                "post": {
+                    "operationId": "submitSolveJob",
+                    "summary": "Queue a solve request",
+                    "parameters": [{
+                        "name": "event",
+                        "in": "query",
+                        "required": false,
+                        "description": "Label grouping runs of the same event when the server records runs.",
+                        "schema": { "type": "string" }
+                    }],
+                    "requestBody": { "required": true, "content": json_content(&solve_request) },
+                    "responses": {
+                        "202": {
+                            "description": "The job was queued.",
+                            "content": json_content(&job_status)
+                        },
+                        "413": error_response("The request body or the scenario's estimated solver memory is too large."),
+                        "422": error_response("The request body is not a valid solve request."),
+                        "503": error_response("The queue already holds its maximum of waiting jobs.")
+                    }
+                }
+            },
+            "/jobs/{job_id}": {
+                "get": {
+                    "operationId": "getSolveJob",
+                    "summary": "Get the status and latest progress of a job",
+                    "parameters": [job_id],
+                    "responses": {
+                        "200": { "description": "The job status.", "content": json_content(&job_status) },
+                        "404": error_response("No job has this id.")
+                    }
+                }
+            },
+            "/jobs/{job_id}/events": {
+                "get": {
+                    "operationId": "streamSolveJobEvents",
+                    "summary": "Stream job progress as Server-Sent Events",
+                    "description": "`progress` events carry a ProgressUpdate; `status` events carry a JobStatusResponse. The stream ends after the `status` event of a finished job.",
+                    "parameters": [job_id],
+                    "responses": {
+                        "200": {
+                            "description": "An event stream of progress and status events.",
+                            "content": {
+                                "text/event-stream": {
+                                    "schema": { "oneOf": [progress_update, job_status] }
+                                }
+                            }
+                        },
+                        "404": error_response("No job has this id.")
+                    }
+                }
+            },
+            "/jobs/{job_id}/result": {
+                "get": {
+                    "operationId": "getSolveJobResult",
+                    "summary": "Get the result of a finished job",
+                    "parameters": [job_id],
+                    "responses": {
+                        "200": { "description": "The solve result.", "content": json_content(&solve_response) },
+                        "202": {
+                            "description": "The job has not finished yet.",
+                            "content": json_content(&job_status)
+                        },
+                        "404": error_response("No job has this id."),
+                        "422": error_response("The solve failed."),
+                        "500": error_response("The solve job crashed.")
+                    }
+                }
+            },
+            "/metrics": {
+                "get": {
+                    "operationId": "getMetrics",
+                    "summary": "Get queue and solver metrics in the Prometheus text format",
+                    "responses": {
+                        "200": {
+                            "description": "Prometheus metrics.",
+                            "content": { "text/plain": { "schema": { "type": "string" } } }
+                        }
+                    }
+                }
+            },
+            "/openapi.json": {
+                "get": {
+                    "operationId": "getOpenApiDocument",
+                    "summary": "Get this OpenAPI document",
+                    "responses": { "200": { "description": "The OpenAPI document." } }
+                }
+            }
+        },
+        "components": { "schemas": schemas }
+    })
+}
+
+pub async fn openapi_handler() -> Json<Value> {
+    Json(solver_server_openapi())
+}
+
+fn schema_ref<T: JsonSchema>(generator: &mut SchemaGenerator) -> Value {
+    generator.subschema_for::<T>().to_value()
+}
@@ -0,0 +1,343 @@
+//! SQLite record of solve jobs, enabled with the `sqlite` feature.
+//!
+//! Every submitted job becomes a row in `runs` holding its problem, solver configuration,
+//! optional event label, and final result or error; `progress_snapshots` keeps the job's
+//! progress at most once per [`PROGRESS_SNAPSHOT_INTERVAL_SECONDS`] of solve time. Runs
+//! outlive the in-memory job queue, so `GET /runs?event=...` can list every schedule
+//! generated for an event.
+
+use crate::api::handlers::{api_error, ApiError};
+use crate::api::jobs::{JobProgress, JobQueue, JobStatus};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::Json,
+};
+use gm_contracts::{
+    errors::{INTERNAL_ERROR, INVALID_INPUT_ERROR},
+    types::PublicErrorEnvelope,
+};
+use gm_core::models::{ApiInput, SolverResult};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+/// Minimum solve time between two recorded progress snapshots of a run.
+pub const PROGRESS_SNAPSHOT_INTERVAL_SECONDS: f64 = 1.0;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS runs (
+    id TEXT PRIMARY KEY,
+    event TEXT,
+    status TEXT NOT NULL,
+    submitted_at INTEGER NOT NULL,
+    finished_at INTEGER,
+    input_json TEXT NOT NULL,
+    configuration_json TEXT NOT NULL,
+    result_json TEXT,
+    error_json TEXT,
+    final_score REAL,
+    unique_contacts INTEGER
+);
+CREATE INDEX IF NOT EXISTS runs_by_event ON runs (event, submitted_at);
+CREATE TABLE IF NOT EXISTS progress_snapshots (
+    run_id TEXT NOT NULL REFERENCES runs (id),
+    iteration INTEGER NOT NULL,
+    max_iterations INTEGER NOT NULL,
+    current_score REAL NOT NULL,
+    best_score REAL NOT NULL,
+    elapsed_seconds REAL NOT NULL
+);
+CREATE INDEX IF NOT EXISTS progress_snapshots_by_run ON progress_snapshots (run_id);
+";
+
+/// One row of a run listing.
+#[derive(Debug, Clone, Serialize)]
+pub struct RunSummary {
+    pub id: String,
+    pub event: Option<String>,
+    pub status: String,
+    pub submitted_at: i64,
+    pub finished_at: Option<i64>,
+    pub final_score: Option<f64>,
+    pub unique_contacts: Option<i64>,
+}
+
+/// Everything recorded about one run.
+#[derive(Debug, Clone, Serialize)]
+pub struct RunRecord {
+    #[serde(flatten)]
+    pub summary: RunSummary,
+    pub input: Value,
+    pub configuration: Value,
+    pub progress: Vec<JobProgress>,
+    pub result: Option<Value>,
+    pub error: Option<Value>,
+}
+
+/// SQLite database of solve runs.
+pub struct RunStore {
+    connection: Mutex<Connection>,
+}
+
+impl RunStore {
+    /// Opens (creating if needed) the database at `path`.
+    pub fn open(path: impl AsRef<std::path::Path>) -> rusqlite::Result<Self> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    /// Opens a private in-memory database.
+    pub fn open_in_memory() -> rusqlite::Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(connection: Connection) -> rusqlite::Result<Self> {
+        connection.execute_batch(SCHEMA)?;
+        Ok(Self {
+            connection: Mutex::new(connection),
+        })
+    }
+
+    pub fn record_submission(
+        &self,
+        id: Uuid,
+        event: Option<&str>,
+        input: &ApiInput,
+    ) -> rusqlite::Result<()> {
+        self.connection.lock().unwrap().execute(
+            "INSERT INTO runs (id, event, status, submitted_at, input_json, configuration_json)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                id.to_string(),
+                event,
+                status_name(JobStatus::Queued),
+                unix_now(),
+                to_json(input),
+                to_json(&input.solver),
+            ],
+        )?;
+        Ok(())
+    }
+
+    pub fn record_status(&self, id: Uuid, status: JobStatus) -> rusqlite::Result<()> {
+        self.connection.lock().unwrap().execute(
+            "UPDATE runs SET status = ?2 WHERE id = ?1",
+            params![id.to_string(), status_name(status)],
+        )?;
+        Ok(())
+    }
+
+    pub fn record_progress(&self, id: Uuid, progress: &JobProgress) -> rusqlite::Result<()> {
+        self.connection.lock().unwrap().execute(
+            "INSERT INTO progress_snapshots
+                 (run_id, iteration, max_iterations, current_score, best_score, elapsed_seconds)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                id.to_string(),
+                progress.iteration as i64,
+                progress.max_iterations as i64,
+                progress.current_score,
+                progress.best_score,
+                progress.elapsed_seconds,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Records how run `id` ended: its result if it completed, otherwise its error.
+    pub fn record_outcome(
+        &self,
+        id: Uuid,
+        outcome: Result<&SolverResult, &PublicErrorEnvelope>,
+    ) -> rusqlite::Result<()> {
+        let (status, result, error, final_score, unique_contacts) = match outcome {
+            Ok(result) => (
+                JobStatus::Completed,
+                Some(to_json(result)),
+                None,
+                Some(result.final_score),
+                Some(result.unique_contacts as i64),
+            ),
+            Err(error) => (JobStatus::Failed, None, Some(to_json(error)), None, None),
+        };
+        self.connection.lock().unwrap().execute(
+            "UPDATE runs
+             SET status = ?2, finished_at = ?3, result_json = ?4, error_json = ?5,
+                 final_score = ?6, unique_contacts = ?7
+             WHERE id = ?1",
+            params![
+                id.to_string(),
+                status_name(status),
+                unix_now(),
+                result,
+                error,
+                final_score,
+                unique_contacts,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Runs submitted for `event` (every run when `None`), newest first.
+    pub fn list_runs(&self, event: Option<&str>) -> rusqlite::Result<Vec<RunSummary>> {
+        let connection = self.connection.lock().unwrap();
+        let mut statement = connection.prepare(
+            "SELECT id, event, status, submitted_at, finished_at, final_score, unique_contacts
+             FROM runs
+             WHERE ?1 IS NULL OR event = ?1
+             ORDER BY submitted_at DESC, rowid DESC",
+        )?;
+        let rows = statement.query_map(params![event], |row| {
+            Ok(RunSummary {
+                id: row.get(0)?,
+                event: row.get(1)?,
+                status: row.get(2)?,
+                submitted_at: row.get(3)?,
+                finished_at: row.get(4)?,
+                final_score: row.get(5)?,
+                unique_contacts: row.get(6)?,
+            })
+        })?;
+        rows.collect()
+    }
+
+    /// The full record of run `id`, if it exists.
+    pub fn run(&self, id: Uuid) -> rusqlite::Result<Option<RunRecord>> {
+        let connection = self.connection.lock().unwrap();
+        let record = connection
+            .query_row(
+                "SELECT id, event, status, submitted_at, finished_at, final_score,
+                        unique_contacts, input_json, configuration_json, result_json, error_json
+                 FROM runs WHERE id = ?1",
+                params![id.to_string()],
+                |row| {
+                    Ok(RunRecord {
+                        summary: RunSummary {
+                            id: row.get(0)?,
+                            event: row.get(1)?,
+                            status: row.get(2)?,
+                            submitted_at: row.get(3)?,
+                            finished_at: row.get(4)?,
+                            final_score: row.get(5)?,
+                            unique_contacts: row.get(6)?,
+                        },
+                        input: from_json(row.get(7)?),
+                        configuration: from_json(row.get(8)?),
+                        progress: Vec::new(),
+                        result: row.get::<_, Option<String>>(9)?.map(from_json),
+                        error: row.get::<_, Option<String>>(10)?.map(from_json),
+                    })
+                },
+            )
+            .optional()?;
+        let Some(mut record) = record else {
+            return Ok(None);
+        };
+
+        let mut statement = connection.prepare(
+            "SELECT iteration, max_iterations, current_score, best_score, elapsed_seconds
+             FROM progress_snapshots WHERE run_id = ?1 ORDER BY rowid",
+        )?;
+        record.progress = statement
+            .query_map(params![id.to_string()], |row| {
+                Ok(JobProgress {
+                    iteration: row.get::<_, i64>(0)? as u64,
+                    max_iterations: row.get::<_, i64>(1)? as u64,
+                    current_score: row.get(2)?,
+                    best_score: row.get(3)?,
+                    elapsed_seconds: row.get(4)?,
+                    estimated_remaining_seconds: None,
+                })
+            })?
+            .collect::<rusqlite::Result<_>>()?;
+        Ok(Some(record))
+    }
+}
+
+fn status_name(status: JobStatus) -> &'static str {
+    match status {
+        JobStatus::Queued => "queued",
+        JobStatus::Running => "running",
+        JobStatus::Completed => "completed",
+        JobStatus::Failed => "failed",
+    }
+}
+
+fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64)
+}
+
+fn to_json<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value).expect("run records serialize to JSON")
+}
+
+fn from_json(text: String) -> Value {
+    serde_json::from_str(&text).unwrap_or(Value::String(text))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RunListQuery {
+    pub event: Option<String>,
+}
+
+pub async fn list_runs_handler(
+    State(queue): State<JobQueue>,
+    Query(query): Query<RunListQuery>,
+) -> Result<Json<Vec<RunSummary>>, ApiError> {
+    let store = queue.store().ok_or_else(persistence_disabled_api_error)?;
+    store
+        .list_runs(query.event.as_deref())
+        .map(Json)
+        .map_err(storage_api_error)
+}
+
+pub async fn run_handler(
+    State(queue): State<JobQueue>,
+    Path(run_id): Path<String>,
+) -> Result<Json<RunRecord>, ApiError> {
+    let store = queue.store().ok_or_else(persistence_disabled_api_error)?;
+    let id = Uuid::parse_str(&run_id).map_err(|_| unknown_run_api_error(&run_id))?;
+    store
+        .run(id)
+        .map_err(storage_api_error)?
+        .map(Json)
+        .ok_or_else(|| unknown_run_api_error(&run_id))
+}
+
+fn persistence_disabled_api_error() -> ApiError {
+    api_error(
+        INVALID_INPUT_ERROR,
+        StatusCode::NOT_FOUND,
+        "Run persistence is not configured on this server",
+        None,
+        Vec::new(),
+        Some(Vec::new()),
+    )
+}
+
+fn unknown_run_api_error(run_id: &str) -> ApiError {
+    api_error(
+        INVALID_INPUT_ERROR,
+        StatusCode::NOT_FOUND,
+        format!("Unknown run id '{}'", run_id),
+        Some("run_id".to_string()),
+        Vec::new(),
+        Some(Vec::new()),
+    )
+}
+
+fn storage_api_error(error: rusqlite::Error) -> ApiError {
+    api_error(
+        INTERNAL_ERROR,
+        StatusCode::INTERNAL_SERVER_ERROR,
+        format!("Run storage failed: {error}"),
+        None,
+        Vec::new(),
+        None,
+    )
+}
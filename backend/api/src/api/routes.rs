@@ -5,7 +5,16 @@ use super::handlers::{
     recommend_settings_handler, schema_get_handler, schema_list_handler, solve_handler,
     validate_scenario_handler,
 };
+use super::jobs::{
+    job_events_handler, job_result_handler, job_status_handler, submit_job_handler, JobQueue,
+    MAX_SOLVE_REQUEST_BYTES,
+};
+use super::metrics::metrics_handler;
+use super::openapi::openapi_handler;
+#[cfg(feature = "sqlite")]
+use super::persistence::{list_runs_handler, run_handler};
 use axum::{
+    extract::DefaultBodyLimit,
     routing::{get, post},
     Router,
 };
@@ -36,3 +45,23 @@ pub fn create_router() -> Router {
         .route("/api/v1/errors", get(error_list_handler))
         .route("/api/v1/errors/{error_code}", get(error_get_handler))
 }
+
+/// Routes of the `solver-server` binary: queued solves backed by `queue`, plus the OpenAPI
+/// document describing them and, with the `sqlite` feature, the recorded runs.
+pub fn create_solver_server_router(queue: JobQueue) -> Router {
+    let router = Router::new()
+        .route(
+            "/solve",
+            post(submit_job_handler).layer(DefaultBodyLimit::max(MAX_SOLVE_REQUEST_BYTES)),
+        )
+        .route("/jobs/{job_id}", get(job_status_handler))
+        .route("/jobs/{job_id}/events", get(job_events_handler))
+        .route("/jobs/{job_id}/result", get(job_result_handler))
+        .route("/metrics", get(metrics_handler))
+        .route("/openapi.json", get(openapi_handler));
+    #[cfg(feature = "sqlite")]
+    let router = router
+        .route("/runs", get(list_runs_handler))
+        .route("/runs/{run_id}", get(run_handler));
+    router.with_state(queue)
+}
@@ -0,0 +1,63 @@
+//! Shared optimization host: queues solve requests and serves their progress and results.
+//!
+//! Listens on `SOLVER_SERVER_ADDR` (default `127.0.0.1:3000`) and solves at most
+//! `SOLVER_SERVER_WORKERS` jobs at once (default: one per available CPU), with at most
+//! `SOLVER_SERVER_MAX_QUEUED` more waiting (default 64). Built with the `sqlite` feature, it
+//! records every run in the database at `SOLVER_SERVER_DB` when set.
+
+use gm_api::api::jobs::{JobQueue, DEFAULT_MAX_QUEUED_JOBS};
+use gm_api::api::routes::create_solver_server_router;
+use std::net::SocketAddr;
+
+#[tokio::main]
+async fn main() {
+    gm_api::init_tracing();
+    let addr: SocketAddr = std::env::var("SOLVER_SERVER_ADDR")
+        .ok()
+        .map(|value| {
+            value
+                .parse()
+                .expect("SOLVER_SERVER_ADDR must be a socket address")
+        })
+        .unwrap_or_else(|| SocketAddr::from(([127, 0, 0, 1], 3000)));
+    let workers = std::env::var("SOLVER_SERVER_WORKERS")
+        .ok()
+        .map(|value| {
+            value
+                .parse()
+                .expect("SOLVER_SERVER_WORKERS must be a number")
+        })
+        .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |n| n.get()));
+    let max_queued = std::env::var("SOLVER_SERVER_MAX_QUEUED")
+        .ok()
+        .map(|value| {
+            value
+                .parse()
+                .expect("SOLVER_SERVER_MAX_QUEUED must be a number")
+        })
+        .unwrap_or(DEFAULT_MAX_QUEUED_JOBS);
+
+    let queue = match std::env::var("SOLVER_SERVER_DB") {
+        #[cfg(feature = "sqlite")]
+        Ok(path) => {
+            let store = gm_api::api::persistence::RunStore::open(&path)
+                .unwrap_or_else(|error| panic!("failed to open run database {path}: {error}"));
+            tracing::info!("recording runs in {}", path);
+            JobQueue::with_store(workers, store)
+        }
+        #[cfg(not(feature = "sqlite"))]
+        Ok(_) => {
+            panic!("SOLVER_SERVER_DB requires building solver-server with the `sqlite` feature")
+        }
+        Err(_) => JobQueue::new(workers),
+    };
+    let app = create_solver_server_router(queue.with_max_queued(max_queued));
+
+    tracing::info!(
+        "solver-server listening on {} with {} workers",
+        addr,
+        workers
+    );
+    let listener = tokio::net::TcpListener::bind(&addr).await.unwrap();
+    axum::serve(listener, app).await.unwrap();
+}
@@ -3,11 +3,12 @@ use std::net::SocketAddr;
 
 #[tokio::main]
 async fn main() {
+    gm_api::init_tracing();
     let app = create_router();
 
     // run it
     let addr = SocketAddr::from(([127, 0, 0, 1], 3000));
-    println!("listening on {}", addr);
+    tracing::info!("listening on {}", addr);
     let listener = tokio::net::TcpListener::bind(&addr).await.unwrap();
     axum::serve(listener, app).await.unwrap();
 }
@@ -1,12 +1,14 @@
 use axum::body::Body;
 use axum::http::{Request, StatusCode};
+use axum::response::IntoResponse;
 use gm_api::api::contract_surface::public_contract_bindings;
-use gm_api::api::routes::create_router;
+use gm_api::api::jobs::{JobQueue, MAX_SOLVE_REQUEST_BYTES};
+use gm_api::api::routes::{create_router, create_solver_server_router};
 use gm_contracts::types::{
     RecommendSettingsRequest, ResultSummary, SolveRequest, ValidateResponse,
 };
 use gm_core::models::{
-    ApiInput, Group, Objective, Person, ProblemDefinition, SimulatedAnnealingParams,
+    ApiInput, Constraint, Group, Objective, Person, ProblemDefinition, SimulatedAnnealingParams,
     SolverConfiguration, SolverParams, StopConditions,
 };
 use http_body_util::BodyExt;
@@ -19,6 +21,7 @@ fn valid_input() -> ApiInput {
     ApiInput {
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people: vec![
                 Person {
@@ -47,11 +50,15 @@ fn valid_input() -> ApiInput {
                     id: "g0".to_string(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g1".to_string(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 2,
@@ -59,6 +66,8 @@ fn valid_input() -> ApiInput {
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![],
         solver: SolverConfiguration {
@@ -68,6 +77,9 @@ fn valid_input() -> ApiInput {
                 time_limit_seconds: None,
                 no_improvement_iterations: Some(25),
                 stop_on_optimal_score: true,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
                 initial_temperature: 5.0,
@@ -75,6 +87,7 @@ fn valid_input() -> ApiInput {
                 cooling_schedule: "geometric".to_string(),
                 reheat_after_no_improvement: Some(0),
                 reheat_cycles: Some(0),
+                penalty_ramp: None,
             }),
             logging: Default::default(),
             telemetry: Default::default(),
@@ -537,6 +550,45 @@ async fn contract_endpoints_emit_canonical_error_envelopes() {
     assert_eq!(unknown_error_body["error"]["code"], "unknown-error-code");
 }
 
+#[tokio::test]
+async fn solver_errors_name_the_offending_entities() {
+    let mut input = valid_input();
+    input.constraints.push(Constraint::MustStayApart {
+        people: vec!["p0".to_string(), "ghost".to_string()],
+        sessions: None,
+    });
+
+    let response = create_router()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/solve")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::to_vec(&SolveRequest::from(input)).unwrap(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body: serde_json::Value = json_response(response).await;
+    assert_eq!(body["error"]["code"], "infeasible-scenario");
+    let message = body["error"]["message"].as_str().unwrap();
+    assert!(message.contains("ghost"));
+    assert!(!message.contains("UnknownPersonId"));
+    assert_eq!(
+        body["error"]["details"],
+        json!({
+            "kind": "unknown_person_id",
+            "person_ids": ["ghost"],
+            "group_ids": [],
+            "constraint_indices": [0],
+            "sessions": []
+        })
+    );
+}
+
 #[tokio::test]
 async fn help_and_error_navigation_targets_resolve_locally() {
     let app = create_router();
@@ -641,3 +693,336 @@ async fn public_contract_routes_stay_in_parity_with_contract_registry() {
         );
     }
 }
+
+async fn get_json(app: &axum::Router, uri: &str) -> (StatusCode, serde_json::Value) {
+    let response = app
+        .clone()
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    (status, json_response(response).await)
+}
+
+async fn submit_job(app: &axum::Router, request: &SolveRequest) -> serde_json::Value {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/solve")
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(request).unwrap()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    json_response(response).await
+}
+
+async fn wait_for_job(app: &axum::Router, job_id: &str) -> serde_json::Value {
+    for _ in 0..500 {
+        let (status, body) = get_json(app, &format!("/jobs/{job_id}")).await;
+        assert_eq!(status, StatusCode::OK);
+        if body["status"] == "completed" || body["status"] == "failed" {
+            return body;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    panic!("job {job_id} did not finish");
+}
+
+#[tokio::test]
+async fn solver_server_queues_jobs_and_serves_their_results() {
+    let app = create_solver_server_router(JobQueue::new(1));
+
+    let job_ids = [
+        submit_job(&app, &valid_request()).await,
+        submit_job(&app, &valid_request()).await,
+    ]
+    .map(|job| {
+        assert!(job["status"] == "queued" || job["status"] == "running");
+        job["id"].as_str().unwrap().to_string()
+    });
+    assert_ne!(job_ids[0], job_ids[1]);
+
+    for job_id in &job_ids {
+        let finished = wait_for_job(&app, job_id).await;
+        assert_eq!(finished["status"], "completed");
+        assert_eq!(finished["result_path"], format!("/jobs/{job_id}/result"));
+
+        let (status, result) = get_json(&app, &format!("/jobs/{job_id}/result")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(result["schedule"].is_object());
+    }
+}
+
+#[tokio::test]
+async fn solver_server_reports_failed_and_unknown_jobs() {
+    let app = create_solver_server_router(JobQueue::new(1));
+
+    let mut input = valid_input();
+    input.constraints.push(Constraint::MustStayApart {
+        people: vec!["p0".to_string(), "ghost".to_string()],
+        sessions: None,
+    });
+    let job = submit_job(&app, &SolveRequest::from(input)).await;
+    let job_id = job["id"].as_str().unwrap();
+    assert_eq!(wait_for_job(&app, job_id).await["status"], "failed");
+    let (status, body) = get_json(&app, &format!("/jobs/{job_id}/result")).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "infeasible-scenario");
+
+    for uri in [
+        "/jobs/not-a-job",
+        "/jobs/00000000-0000-0000-0000-000000000000/result",
+    ] {
+        let (status, body) = get_json(&app, uri).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["error"]["where_path"], "job_id");
+    }
+}
+
+#[tokio::test]
+async fn solver_server_refuses_jobs_beyond_its_limits() {
+    async fn refusal(error: gm_api::api::handlers::ApiError) -> (StatusCode, serde_json::Value) {
+        let response = error.into_response();
+        let status = response.status();
+        (status, json_response(response).await)
+    }
+
+    let queue = JobQueue::new(1).with_max_queued(1);
+    queue
+        .submit(valid_input(), None)
+        .expect("first job is queued");
+    let (status, body) = refusal(queue.submit(valid_input(), None).unwrap_err()).await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(body["error"]["code"], "server-busy");
+
+    let huge = ApiInput::builder()
+        .people((0..60_000).map(|idx| format!("p{idx}")))
+        .sessions(3)
+        .group("g0", 60_000)
+        .build();
+    let (status, body) = refusal(JobQueue::new(1).submit(huge, None).unwrap_err()).await;
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+    assert_eq!(body["error"]["code"], "invalid-input");
+
+    let response = create_solver_server_router(JobQueue::new(1))
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/solve")
+                .header("content-type", "application/json")
+                .body(Body::from(vec![b' '; MAX_SOLVE_REQUEST_BYTES + 1]))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn solver_server_streams_job_events_until_the_job_finishes() {
+    let app = create_solver_server_router(JobQueue::new(1));
+    let job = submit_job(&app, &valid_request()).await;
+    let job_id = job["id"].as_str().unwrap();
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("/jobs/{job_id}/events"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"].to_str().unwrap(),
+        "text/event-stream"
+    );
+
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let events: Vec<(String, serde_json::Value)> = String::from_utf8(bytes.to_vec())
+        .unwrap()
+        .split("\n\n")
+        .filter_map(|block| {
+            let name = block
+                .lines()
+                .find_map(|line| line.strip_prefix("event: "))?;
+            let data = block.lines().find_map(|line| line.strip_prefix("data: "))?;
+            Some((name.to_string(), serde_json::from_str(data).unwrap()))
+        })
+        .collect();
+
+    let (last_name, last_data) = events.last().unwrap();
+    assert_eq!(last_name, "status");
+    assert_eq!(last_data["status"], "completed");
+    assert!(events
+        .iter()
+        .filter(|(name, _)| name == "progress")
+        .all(|(_, data)| data["iteration"].is_u64()));
+
+    let (status, _) = get_json(&app, "/jobs/not-a-job/events").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+fn collect_refs<'a>(value: &'a serde_json::Value, refs: &mut Vec<&'a str>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            if let Some(reference) = map.get("$ref").and_then(|value| value.as_str()) {
+                refs.push(reference);
+            }
+            map.values().for_each(|value| collect_refs(value, refs));
+        }
+        serde_json::Value::Array(values) => {
+            values.iter().for_each(|value| collect_refs(value, refs))
+        }
+        _ => {}
+    }
+}
+
+#[tokio::test]
+async fn solver_server_openapi_document_covers_routes_and_resolves_schemas() {
+    let app = create_solver_server_router(JobQueue::new(1));
+    let (status, document) = get_json(&app, "/openapi.json").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(document["openapi"], "3.0.3");
+
+    for path in [
+        "/solve",
+        "/jobs/{job_id}",
+        "/jobs/{job_id}/events",
+        "/jobs/{job_id}/result",
+    ] {
+        assert!(document["paths"][path].is_object(), "missing path {path}");
+    }
+
+    let schemas = document["components"]["schemas"].as_object().unwrap();
+    for name in [
+        "SolveRequest",
+        "SolverResult",
+        "JobStatusResponse",
+        "PublicErrorEnvelope",
+    ] {
+        assert!(schemas.contains_key(name), "missing schema {name}");
+    }
+
+    let mut refs = Vec::new();
+    collect_refs(&document, &mut refs);
+    assert!(!refs.is_empty());
+    for reference in refs {
+        let name = reference
+            .strip_prefix("#/components/schemas/")
+            .unwrap_or_else(|| panic!("unexpected reference {reference}"));
+        assert!(
+            schemas.contains_key(name),
+            "unresolved reference {reference}"
+        );
+    }
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn solver_server_records_runs_by_event() {
+    use gm_api::api::persistence::RunStore;
+
+    let store = RunStore::open_in_memory().unwrap();
+    let app = create_solver_server_router(JobQueue::with_store(1, store));
+
+    let submit = |uri: &'static str| {
+        let app = app.clone();
+        async move {
+            let response = app
+                .oneshot(
+                    Request::builder()
+                        .method("POST")
+                        .uri(uri)
+                        .header("content-type", "application/json")
+                        .body(Body::from(serde_json::to_vec(&valid_request()).unwrap()))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::ACCEPTED);
+            let job: serde_json::Value = json_response(response).await;
+            job["id"].as_str().unwrap().to_string()
+        }
+    };
+    let retreat_run = submit("/solve?event=spring-retreat").await;
+    let other_run = submit("/solve?event=other").await;
+    wait_for_job(&app, &retreat_run).await;
+    wait_for_job(&app, &other_run).await;
+
+    let (status, runs) = get_json(&app, "/runs?event=spring-retreat").await;
+    assert_eq!(status, StatusCode::OK);
+    let runs = runs.as_array().unwrap();
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0]["id"], retreat_run);
+    assert_eq!(runs[0]["status"], "completed");
+    assert!(runs[0]["final_score"].is_number());
+
+    let (_, all_runs) = get_json(&app, "/runs").await;
+    assert_eq!(all_runs.as_array().unwrap().len(), 2);
+
+    let (status, run) = get_json(&app, &format!("/runs/{retreat_run}")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(run["event"], "spring-retreat");
+    assert_eq!(run["input"]["problem"]["num_sessions"], 2);
+    assert_eq!(run["configuration"]["solver_type"], "SimulatedAnnealing");
+    assert!(run["result"]["schedule"].is_object());
+    assert!(!run["progress"].as_array().unwrap().is_empty());
+
+    let (status, _) = get_json(&app, "/runs/00000000-0000-0000-0000-000000000000").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn solver_server_exposes_prometheus_metrics() {
+    let app = create_solver_server_router(JobQueue::new(1));
+    let job = submit_job(&app, &valid_request()).await;
+    wait_for_job(&app, job["id"].as_str().unwrap()).await;
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/metrics")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers()["content-type"]
+        .to_str()
+        .unwrap()
+        .starts_with("text/plain"));
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let metrics = String::from_utf8(bytes.to_vec()).unwrap();
+    let value = |name: &str| -> f64 {
+        metrics
+            .lines()
+            .find_map(|line| line.strip_prefix(name)?.strip_prefix(' '))
+            .unwrap_or_else(|| panic!("missing metric {name}"))
+            .parse()
+            .unwrap()
+    };
+
+    assert_eq!(value("groupmixer_jobs_queued"), 0.0);
+    assert_eq!(value("groupmixer_jobs_running"), 0.0);
+    assert_eq!(
+        value("groupmixer_jobs_finished_total{status=\"completed\"}"),
+        1.0
+    );
+    assert_eq!(value("groupmixer_job_duration_seconds_count"), 1.0);
+    assert_eq!(
+        value("groupmixer_job_duration_seconds_bucket{le=\"+Inf\"}"),
+        1.0
+    );
+    assert!(value("groupmixer_solver_iterations_total") >= 0.0);
+    assert!(metrics.contains("# TYPE groupmixer_job_duration_seconds histogram"));
+}
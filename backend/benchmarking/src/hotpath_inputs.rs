@@ -247,6 +247,9 @@ pub fn solver3_swap_bench_input(id: &str) -> Option<Solver3SwapBenchInput> {
                     time_limit_seconds: None,
                     no_improvement_iterations: None,
                     stop_on_optimal_score: false,
+                    stop_on_feasible: false,
+                    feasible_score_threshold: None,
+                    convergence: None,
                 },
                 solver_params: SolverParams::Solver3(Solver3Params::default()),
                 logging: Default::default(),
@@ -326,6 +329,9 @@ pub fn solver3_transfer_bench_input(id: &str) -> Option<Solver3TransferBenchInpu
                     time_limit_seconds: None,
                     no_improvement_iterations: None,
                     stop_on_optimal_score: false,
+                    stop_on_feasible: false,
+                    feasible_score_threshold: None,
+                    convergence: None,
                 },
                 solver_params: SolverParams::Solver3(Solver3Params::default()),
                 logging: Default::default(),
@@ -662,6 +668,9 @@ pub fn solver3_clique_swap_bench_input(id: &str) -> Option<Solver3CliqueSwapBenc
                     time_limit_seconds: None,
                     no_improvement_iterations: None,
                     stop_on_optimal_score: false,
+                    stop_on_feasible: false,
+                    feasible_score_threshold: None,
+                    convergence: None,
                 },
                 solver_params: SolverParams::Solver3(Solver3Params::default()),
                 logging: Default::default(),
@@ -743,6 +752,9 @@ fn real_demo_solver3_state() -> (ApiInput, gm_core::solver3::RuntimeState) {
             time_limit_seconds: None,
             no_improvement_iterations: None,
             stop_on_optimal_score: false,
+            stop_on_feasible: false,
+            feasible_score_threshold: None,
+            convergence: None,
         },
         solver_params: SolverParams::Solver3(Solver3Params::default()),
         logging: Default::default(),
@@ -1052,6 +1064,9 @@ fn search_loop_sailing_trip_demo_real_solver3_input() -> SearchLoopBenchInput {
             time_limit_seconds: None,
             no_improvement_iterations: Some(250_000),
             stop_on_optimal_score: false,
+            stop_on_feasible: false,
+            feasible_score_threshold: None,
+            convergence: None,
         },
         solver_params: SolverParams::Solver3(Solver3Params::default()),
         logging: Default::default(),
@@ -1089,9 +1104,12 @@ pub fn make_api_input(
         problem,
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints,
         solver: seeded_solver_config(max_iterations, seed),
@@ -1106,6 +1124,9 @@ fn seeded_solver_config(max_iterations: u64, seed: u64) -> SolverConfiguration {
             time_limit_seconds: None,
             no_improvement_iterations: None,
             stop_on_optimal_score: false,
+            stop_on_feasible: false,
+            feasible_score_threshold: None,
+            convergence: None,
         },
         solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
             initial_temperature: 10.0,
@@ -1113,6 +1134,7 @@ fn seeded_solver_config(max_iterations: u64, seed: u64) -> SolverConfiguration {
             cooling_schedule: "geometric".to_string(),
             reheat_after_no_improvement: Some(0),
             reheat_cycles: Some(0),
+            penalty_ramp: None,
         }),
         logging: Default::default(),
         telemetry: Default::default(),
@@ -1179,6 +1201,8 @@ fn group(id: &str, size: u32) -> Group {
         id: id.to_string(),
         size,
         session_sizes: None,
+        attributes: HashMap::new(),
+        available_sessions: None,
     }
 }
 
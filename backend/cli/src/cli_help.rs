@@ -1,5 +1,5 @@
 use crate::contract_surface::{
-    binding_for_command, cli_contract_bindings, public_cli_contract_bindings,
+    binding_for_command, cli_contract_bindings, public_cli_contract_bindings, CliSurfaceScope,
 };
 use anyhow::Result;
 use gm_contracts::{bootstrap::bootstrap_spec, examples::example_spec, operations::local_help};
@@ -11,10 +11,10 @@ pub fn try_print_contract_help(args: &[String]) -> Result<bool> {
     }
 
     if let Some(command_name) = requested_command_help(args) {
-        if command_name == "benchmark" {
-            return Ok(false);
-        }
         if let Some(binding) = binding_for_command(command_name) {
+            if binding.scope == CliSurfaceScope::OutOfScopeSupport {
+                return Ok(false);
+            }
             if let Some(operation_id) = binding.operation_id {
                 print!("{}", render_command_help(command_name, operation_id));
             } else {
@@ -75,7 +75,7 @@ pub fn render_root_help() -> String {
     out.push_str("ADDITIONAL COMMANDS (not part of the public solver contract):\n");
     for binding in cli_contract_bindings()
         .iter()
-        .filter(|binding| binding.command_name == "benchmark")
+        .filter(|binding| binding.scope == CliSurfaceScope::OutOfScopeSupport)
     {
         out.push_str(&format!(
             "  {:<12} {}\n",
@@ -188,17 +188,47 @@ fn render_usage_and_options(command_name: &str) -> String {
         "solve" => concat!(
             "  gm-cli solve <FILE> [--output <FILE>] [--pretty]\n",
             "  gm-cli solve --stdin [--output <FILE>] [--pretty]\n",
+            "  gm-cli solve <FILE> --output-format csv [--csv-attributes <KEYS>]\n",
+            "  gm-cli solve <FILE> [--seed <U64>] [--time-limit <SECONDS>] [--progress]\n",
+            "  gm-cli solve <FILE> --top-k <N> [--output <FILE>]\n",
+            "  gm-cli solve <FILE> --checkpoint-every <INTERVAL> --checkpoint-file <FILE>\n",
+            "  gm-cli solve --resume <FILE> [--checkpoint-every <INTERVAL>]\n",
+            "  gm-cli solve <FILE> --lock-sessions 0,1\n",
             "\nOPTIONS:\n",
             "  --stdin           Read input JSON from stdin\n",
             "  -o, --output      Write result JSON to a file instead of stdout\n",
-            "  --pretty          Pretty-print JSON output\n"
+            "  --pretty          Pretty-print JSON output\n",
+            "  --output-format   json (default), pretty, csv (one row per assignment),\n",
+            "                    markdown, or table (aligned text per session)\n",
+            "  --csv-attributes  Comma-separated person attributes to add as CSV columns\n",
+            "  --seed            Random seed overriding solver.seed, to reproduce a run\n",
+            "  --time-limit      Time limit in seconds, overriding the input's stop conditions\n",
+            "  --max-iterations  Iteration limit, overriding the input's stop conditions\n",
+            "  --algorithm       Solver family, e.g. solver1 or solver3, with its default params\n",
+            "  --initial-temp    Initial annealing temperature (solver1 only)\n",
+            "  --lock-sessions   Keep these sessions (0-based) as in the initial schedule\n",
+            "  --only-sessions   Let the search change only these sessions (0-based)\n",
+            "  --progress        Show iterations, best score, temperature and ETA on stderr\n",
+            "  --progress-json   Write each progress update to stderr as one JSON object per\n",
+            "                    line; other stderr lines are plain text\n",
+            "  --top-k           Solve with consecutive seeds and write the N best distinct\n",
+            "                    schedules to result_1.json ... (or <stem>_1.<ext> from -o)\n",
+            "  --checkpoint-every  Save a resumable checkpoint this often (e.g. 60s, 10m, 1h);\n",
+            "                    Ctrl-C also saves one before stopping (solver1 only)\n",
+            "  --checkpoint-file Where to save checkpoints (defaults to the --resume file)\n",
+            "  --resume          Continue the run saved in a checkpoint file\n"
         )
         .to_string(),
         "validate" => concat!(
             "  gm-cli validate <FILE>\n",
             "  gm-cli validate --stdin\n",
             "\nOPTIONS:\n",
-            "  --stdin           Read input JSON from stdin\n"
+            "  --stdin           Read input JSON from stdin\n",
+            "\nPrints a validation report with errors and warnings.\n",
+            "\nEXIT CODES:\n",
+            "  0                 No errors (warnings allowed)\n",
+            "  2                 The input could not be read or parsed\n",
+            "  3                 The input parsed but has validation errors\n"
         )
         .to_string(),
         "default-config" => concat!(
@@ -218,8 +248,11 @@ fn render_usage_and_options(command_name: &str) -> String {
         "evaluate" => concat!(
             "  gm-cli evaluate <FILE> [--pretty]\n",
             "  gm-cli evaluate --stdin [--pretty]\n",
+            "  gm-cli evaluate --problem <FILE> --schedule <FILE> [--pretty]\n",
             "\nOPTIONS:\n",
             "  --stdin           Read input JSON from stdin\n",
+            "  --problem         Problem file to score, instead of <FILE>\n",
+            "  --schedule        Schedule or result file to score instead of initial_schedule\n",
             "  --pretty          Pretty-print JSON output\n"
         )
         .to_string(),
@@ -86,6 +86,72 @@ const CLI_BINDINGS: &[CliContractBinding] = &[
         scope: CliSurfaceScope::PublicContract,
         note: "Public bootstrap/capability listing derived from gm-contracts.",
     },
+    CliContractBinding {
+        command_name: "compare",
+        operation_id: None,
+        scope: CliSurfaceScope::OutOfScopeSupport,
+        note: "Compare two results: score deltas, constraint penalties, and people who moved.",
+    },
+    CliContractBinding {
+        command_name: "report",
+        operation_id: None,
+        scope: CliSurfaceScope::OutOfScopeSupport,
+        note: "Write a shareable HTML or Markdown report of a result for organizers.",
+    },
+    CliContractBinding {
+        command_name: "matrix",
+        operation_id: None,
+        scope: CliSurfaceScope::OutOfScopeSupport,
+        note: "Print a result's pairwise contact matrix and the pairs who never met.",
+    },
+    CliContractBinding {
+        command_name: "explain",
+        operation_id: None,
+        scope: CliSurfaceScope::OutOfScopeSupport,
+        note: "Describe a result's remaining constraint violations in plain language.",
+    },
+    CliContractBinding {
+        command_name: "people",
+        operation_id: None,
+        scope: CliSurfaceScope::OutOfScopeSupport,
+        note: "Print each attendee's groups, contacts, and violations in a result.",
+    },
+    CliContractBinding {
+        command_name: "tune",
+        operation_id: None,
+        scope: CliSurfaceScope::OutOfScopeSupport,
+        note: "Race simulated annealing parameter sets on the input with `auto_tune`.",
+    },
+    CliContractBinding {
+        command_name: "serve",
+        operation_id: None,
+        scope: CliSurfaceScope::OutOfScopeSupport,
+        note: "Solve newline-delimited requests from stdin for driving the CLI from other processes.",
+    },
+    CliContractBinding {
+        command_name: "stats",
+        operation_id: None,
+        scope: CliSurfaceScope::OutOfScopeSupport,
+        note: "Summarize a problem's size, capacity slack, cliques, and constraints.",
+    },
+    CliContractBinding {
+        command_name: "convert",
+        operation_id: None,
+        scope: CliSurfaceScope::OutOfScopeSupport,
+        note: "Convert problems from JSON, YAML or TOML to JSON or YAML, or import people from a CSV file or (with the `xlsx` feature) an Excel workbook.",
+    },
+    CliContractBinding {
+        command_name: "bench",
+        operation_id: None,
+        scope: CliSurfaceScope::OutOfScopeSupport,
+        note: "Time the solver on a built-in suite of problem sizes.",
+    },
+    CliContractBinding {
+        command_name: "batch",
+        operation_id: None,
+        scope: CliSurfaceScope::OutOfScopeSupport,
+        note: "Solve every scenario file in a directory and summarize scores and runtimes.",
+    },
     CliContractBinding {
         command_name: "benchmark",
         operation_id: None,
@@ -18,7 +18,7 @@ mod contract_surface;
 mod public_errors;
 
 use anyhow::{Context, Result};
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use gm_benchmarking::{
     compare_run_to_baseline, create_recording_for_run, create_recording_for_runs,
     export_trajectory, export_trajectory_csv, find_recording_suite_runs, list_recordings,
@@ -34,19 +34,39 @@ use gm_contracts::{
     operations::operation_spec,
     schemas::{export_schema, schema_specs},
     types::{
-        RecommendSettingsRequest, ResultSummary, SolveRequest, SolverCatalogResponse,
-        SolverDescriptorContract, ValidateRequest, ValidateResponse, ValidationIssue,
+        RecommendSettingsRequest, ResultSummary, ScenarioDefinitionContract, SolveRequest,
+        SolverCatalogResponse, SolverDescriptorContract, ValidateRequest,
     },
 };
-use gm_core::models::{ApiInput, SolverKind, SolverResult};
+use gm_core::csv_import::people_from_csv;
+use gm_core::models::{
+    ApiInput, ApiSchedule, BenchmarkEvent, BenchmarkObserver, Constraint, DisplayOptions, Person,
+    ProblemStats, ProgressCallback, ProgressControl, ProgressControlCallback, ProgressUpdate,
+    ReportFormat, ScheduleDiff, SolverCheckpoint, SolverConfiguration, SolverKind, SolverParams,
+    SolverResult, StopReason, TuningBudget,
+};
+use gm_core::serde_yaml;
+use gm_core::telemetry_jsonl::JsonlTelemetryWriter;
+use gm_core::toml_import::from_toml_str;
+#[cfg(feature = "xlsx")]
+use gm_core::xlsx_import::import_workbook;
 use gm_core::{
-    available_solver_descriptors, calculate_recommended_settings, default_solver_configuration,
-    run_solver, solver_descriptor,
+    auto_tune, available_solver_descriptors, calculate_recommendation_report_for,
+    calculate_recommended_settings, default_solver_configuration, default_solver_configuration_for,
+    default_solver_kind, diff_schedules, problem_stats, repair_initial_schedule,
+    resume_from_checkpoint_with_control, run_solver, run_solver_with_benchmark_observer,
+    run_solver_with_control, solver_descriptor, validate,
 };
-use serde::Serialize;
+use indicatif::{ProgressBar, ProgressStyle};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs;
-use std::io::{self, Read};
+use std::io::{self, BufWriter, Read};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Once};
+use std::time::{Duration, Instant};
 
 #[derive(Parser)]
 #[command(name = "gm-cli")]
@@ -82,7 +102,7 @@ enum Commands {
 
     /// Run the solver on a scenario file
     Solve {
-        /// Input JSON file path (use --stdin to read from stdin)
+        /// Input JSON file path, YAML for `.yaml`/`.yml` or TOML for `.toml` (use --stdin to read JSON from stdin)
         #[arg(value_name = "FILE")]
         input: Option<PathBuf>,
 
@@ -90,18 +110,40 @@ enum Commands {
         #[arg(long)]
         stdin: bool,
 
-        /// Output file path (defaults to stdout)
-        #[arg(short, long)]
-        output: Option<PathBuf>,
+        #[command(flatten)]
+        output: SolveOutputArgs,
 
-        /// Pretty-print the JSON output
+        #[command(flatten)]
+        overrides: SolverOverrides,
+
+        #[command(flatten)]
+        sessions: SessionScopeArgs,
+
+        #[command(flatten)]
+        warm_start: WarmStartArgs,
+
+        /// Show a live progress bar on stderr
         #[arg(long)]
-        pretty: bool,
+        progress: bool,
+
+        /// Write each progress update to stderr as one JSON line, for wrapping tools
+        #[arg(long, conflicts_with = "progress")]
+        progress_json: bool,
+
+        #[command(flatten)]
+        checkpoint: CheckpointArgs,
+
+        /// Solve repeatedly with consecutive seeds and write the N best distinct schedules
+        /// to `<stem>_1.<ext>` … `<stem>_N.<ext>` (from `--output`, default `result.json`).
+        /// Schedules that differ only in which group holds which people count as the same.
+        /// Runs up to 3 × N full solves, each under the configured stop conditions
+        #[arg(long, value_name = "N", conflicts_with_all = ["checkpoint_every", "resume"])]
+        top_k: Option<usize>,
     },
 
     /// Validate a scenario file without solving
     Validate {
-        /// Input JSON file path
+        /// Input JSON file path, YAML for `.yaml`/`.yml` or TOML for `.toml`
         #[arg(value_name = "FILE")]
         input: Option<PathBuf>,
 
@@ -120,7 +162,7 @@ enum Commands {
 
     /// Get recommended solver settings for a scenario
     Recommend {
-        /// Input JSON file path (recommend-settings-request)
+        /// Input JSON file path, YAML for `.yaml`/`.yml` or TOML for `.toml` (recommend-settings-request)
         #[arg(value_name = "FILE")]
         input: Option<PathBuf>,
 
@@ -131,11 +173,17 @@ enum Commands {
         /// Pretty-print the JSON output
         #[arg(long)]
         pretty: bool,
+
+        /// Print the full recommendation report (sampled move deltas, trial runs, measured
+        /// speed, and reasoning) for the request's solver family instead of the configuration
+        #[arg(long)]
+        report: bool,
     },
 
     /// Evaluate an existing schedule (compute metrics without solving)
     Evaluate {
-        /// Input JSON file path (must include initial_schedule)
+        /// Input JSON file path, YAML for `.yaml`/`.yml` or TOML for `.toml` (must include initial_schedule
+        /// unless `--schedule` is given)
         #[arg(value_name = "FILE")]
         input: Option<PathBuf>,
 
@@ -143,6 +191,15 @@ enum Commands {
         #[arg(long)]
         stdin: bool,
 
+        /// Problem file to score, as an alternative to FILE
+        #[arg(long, value_name = "FILE", conflicts_with_all = ["input", "stdin"])]
+        problem: Option<PathBuf>,
+
+        /// Schedule to score instead of the input's initial_schedule: a bare schedule or a
+        /// document with a `schedule` field, such as a solve result
+        #[arg(long, value_name = "FILE")]
+        schedule: Option<PathBuf>,
+
         /// Pretty-print the JSON output
         #[arg(long)]
         pretty: bool,
@@ -151,7 +208,7 @@ enum Commands {
     /// Inspect a compact summary from an existing solver result
     #[command(name = "inspect-result")]
     InspectResult {
-        /// Input JSON file path containing a solver result
+        /// Input JSON file path, YAML for `.yaml`/`.yml` or TOML for `.toml`, containing a solver result
         #[arg(value_name = "FILE")]
         input: Option<PathBuf>,
 
@@ -164,6 +221,183 @@ enum Commands {
         pretty: bool,
     },
 
+    /// Compare two solver results: scores, constraint penalties, and who moved
+    Compare {
+        /// Baseline result JSON, YAML for `.yaml`/`.yml` or TOML for `.toml`
+        #[arg(value_name = "A")]
+        a: PathBuf,
+
+        /// Candidate result to compare against the baseline
+        #[arg(value_name = "B")]
+        b: PathBuf,
+
+        /// Emit machine-readable JSON instead of text
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Write a shareable HTML or Markdown report of a solver result
+    Report {
+        /// Result JSON, YAML for `.yaml`/`.yml` or TOML for `.toml`
+        #[arg(value_name = "RESULT")]
+        result: PathBuf,
+
+        /// Report format
+        #[arg(long, value_enum, default_value = "html")]
+        format: ReportFormatArg,
+
+        /// Output file path (defaults to stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Print who met whom how often in a result, and the pairs who never met
+    Matrix {
+        /// Result JSON, YAML for `.yaml`/`.yml` or TOML for `.toml`
+        #[arg(value_name = "RESULT")]
+        result: PathBuf,
+
+        /// Problem the result was solved from; includes people the schedule leaves out
+        #[arg(long, value_name = "PROBLEM")]
+        problem: Option<PathBuf>,
+
+        /// Matrix format; with csv the never-met list goes to stderr
+        #[arg(long, value_enum, default_value = "text")]
+        format: MatrixFormat,
+    },
+
+    /// List the violations left in a result in plain language, grouped by constraint
+    Explain {
+        /// Result JSON, YAML for `.yaml`/`.yml` or TOML for `.toml`
+        #[arg(value_name = "RESULT")]
+        result: PathBuf,
+
+        /// Problem the result was solved from; adds limits and people's names
+        #[arg(long, value_name = "PROBLEM")]
+        problem: Option<PathBuf>,
+    },
+
+    /// Print each attendee's groups, contacts, and the violations involving them
+    People {
+        /// Result JSON, YAML for `.yaml`/`.yml` or TOML for `.toml`
+        #[arg(value_name = "RESULT")]
+        result: PathBuf,
+
+        /// Problem the result was solved from; adds people the schedule leaves out, limits,
+        /// and names
+        #[arg(long, value_name = "PROBLEM")]
+        problem: Option<PathBuf>,
+    },
+
+    /// Race simulated annealing parameter sets on the input by successive halving and print
+    /// the winning configuration
+    Tune {
+        /// Input problem file (solver1), JSON YAML for `.yaml`/`.yml` or TOML for `.toml`
+        #[arg(value_name = "FILE")]
+        input: PathBuf,
+
+        /// Parameter sets raced in the first round; each round keeps the better half
+        #[arg(long, value_name = "N", default_value_t = TuningBudget::default().candidates)]
+        candidates: u32,
+
+        /// Iterations each parameter set runs in the first round, doubled every round
+        #[arg(
+            long,
+            value_name = "N",
+            default_value_t = TuningBudget::default().initial_iterations
+        )]
+        iterations: u64,
+
+        /// Seed shared by every race so parameter sets differ only in their parameters
+        #[arg(long, value_name = "U64")]
+        seed: Option<u64>,
+
+        /// Output file for the best configuration (defaults to stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Pretty-print the JSON configuration
+        #[arg(long)]
+        pretty: bool,
+    },
+
+    /// Solve newline-delimited problem JSONs from stdin, writing one result JSON per line
+    Serve {
+        /// Read requests from stdin (the only transport so far)
+        #[arg(long)]
+        stdin: bool,
+    },
+
+    /// Print how large and how tight a problem is, without solving it
+    Stats {
+        /// Input JSON file path, YAML for `.yaml`/`.yml` or TOML for `.toml`
+        #[arg(value_name = "FILE")]
+        input: Option<PathBuf>,
+
+        /// Read input from stdin instead of a file
+        #[arg(long)]
+        stdin: bool,
+
+        /// Emit machine-readable JSON instead of text
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Convert a problem between JSON and YAML, or import people from a CSV or Excel workbook
+    Convert {
+        /// Input document; a people CSV or `.xlsx` workbook with `--people`
+        #[arg(value_name = "INPUT")]
+        input: PathBuf,
+
+        /// Read INPUT as a people CSV with an `id` column and one column per attribute, or as
+        /// an `.xlsx` workbook with such a `People` sheet and an optional `Constraints` sheet
+        /// of pair constraints (columns `type`, `people`, `penalty_weight`, `sessions`);
+        /// workbooks need gm-cli built with the `xlsx` feature
+        #[arg(long)]
+        people: bool,
+
+        /// Problem to put the imported people into, replacing its people and adding the
+        /// workbook's pair constraints to its own (with `--people`)
+        #[arg(long, value_name = "PROBLEM", requires = "people")]
+        into: Option<PathBuf>,
+
+        /// Output file; `.yaml`/`.yml` writes YAML, anything else except `.toml` JSON
+        #[arg(short, long, value_name = "FILE")]
+        out: PathBuf,
+    },
+
+    /// Time the solver on a built-in suite of problem sizes
+    Bench {
+        /// Seconds to run each problem size
+        #[arg(long, value_name = "SECONDS", default_value_t = 2)]
+        seconds: u64,
+
+        /// Emit machine-readable JSON instead of a table
+        #[arg(long)]
+        json: bool,
+
+        #[command(flatten)]
+        overrides: SolverOverrides,
+    },
+
+    /// Solve every scenario file in a directory, writing results beside the inputs
+    Batch {
+        /// Directory of `.json`, `.yaml`, `.yml` or `.toml` scenario files
+        #[arg(value_name = "DIR")]
+        dir: PathBuf,
+
+        /// Number of scenarios to solve in parallel
+        #[arg(short, long, default_value_t = 1)]
+        jobs: usize,
+
+        /// Pretty-print the JSON results
+        #[arg(long)]
+        pretty: bool,
+
+        #[command(flatten)]
+        overrides: SolverOverrides,
+    },
+
     /// Run / save / compare benchmark artifacts
     Benchmark {
         #[command(subcommand)]
@@ -454,6 +688,257 @@ enum BenchmarkBaselineCommands {
     },
 }
 
+/// `solve` flags choosing where and how the result is written.
+#[derive(Args, Clone, Debug, Default)]
+struct SolveOutputArgs {
+    /// Output file path (defaults to stdout)
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Pretty-print the JSON output
+    #[arg(long)]
+    pretty: bool,
+
+    /// Output format: the full JSON result (`pretty` indents it), one CSV row per
+    /// assignment, Markdown tables, or aligned plain-text tables for the terminal
+    #[arg(long, value_enum, default_value = "json")]
+    output_format: OutputFormat,
+
+    /// Person attributes to add as CSV columns (defaults to every attribute)
+    #[arg(long, value_name = "KEYS", value_delimiter = ',')]
+    csv_attributes: Vec<String>,
+
+    /// Stream every progress update to this file as JSON lines, for offline analysis of
+    /// long runs
+    #[arg(long, value_name = "FILE", conflicts_with = "top_k")]
+    telemetry_jsonl: Option<PathBuf>,
+}
+
+/// `solve` flags for saving and resuming long runs (solver1 only).
+#[derive(Args, Clone, Debug, Default)]
+struct CheckpointArgs {
+    /// Save a resumable checkpoint this often, e.g. `90s`, `10m`, or `1h`
+    #[arg(long, value_name = "INTERVAL", value_parser = parse_checkpoint_interval)]
+    checkpoint_every: Option<Duration>,
+
+    /// Checkpoint file to write (defaults to the `--resume` file)
+    #[arg(long, value_name = "FILE", requires = "checkpoint_every")]
+    checkpoint_file: Option<PathBuf>,
+
+    /// Continue the run saved in a checkpoint file instead of reading an input
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["input", "stdin"])]
+    resume: Option<PathBuf>,
+}
+
+/// Parses `--checkpoint-every`: whole seconds, optionally suffixed with `s`, `m`, or `h`.
+fn parse_checkpoint_interval(value: &str) -> std::result::Result<Duration, String> {
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let unit_seconds = match unit {
+        "" | "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        _ => return Err(format!("unknown unit '{unit}'; use s, m, or h")),
+    };
+    match number.parse::<u64>() {
+        Ok(count) if count > 0 => Ok(Duration::from_secs(count * unit_seconds)),
+        _ => Err("expected a positive whole number, e.g. 90s or 10m".to_string()),
+    }
+}
+
+/// `solve` flags limiting which sessions the search may change, for re-optimizing the rest of
+/// an event that is already under way. Both fill in `solver.allowed_sessions`.
+#[derive(Args, Clone, Debug, Default)]
+struct SessionScopeArgs {
+    /// Sessions to keep as they are in the initial schedule, e.g. `0,1` (0-based)
+    #[arg(
+        long,
+        value_name = "SESSIONS",
+        value_delimiter = ',',
+        conflicts_with = "only_sessions"
+    )]
+    lock_sessions: Vec<u32>,
+
+    /// The only sessions the search may change, e.g. `2,3` (0-based)
+    #[arg(long, value_name = "SESSIONS", value_delimiter = ',')]
+    only_sessions: Vec<u32>,
+}
+
+impl SessionScopeArgs {
+    fn apply(&self, input: &mut ApiInput) -> Result<()> {
+        let (flag, sessions) = if !self.lock_sessions.is_empty() {
+            ("--lock-sessions", &self.lock_sessions)
+        } else if !self.only_sessions.is_empty() {
+            ("--only-sessions", &self.only_sessions)
+        } else {
+            return Ok(());
+        };
+        let num_sessions = input.problem.num_sessions;
+        if let Some(session) = sessions.iter().find(|&&session| session >= num_sessions) {
+            return Err(public_errors::invalid_input_error(
+                format!("{flag} lists session {session}, but the problem has {num_sessions} sessions (0-based)"),
+                Some(flag.to_string()),
+                "solve",
+                vec![format!("0..{}", num_sessions.saturating_sub(1))],
+            ));
+        }
+        let allowed: Vec<u32> = if flag == "--lock-sessions" {
+            (0..num_sessions)
+                .filter(|session| !sessions.contains(session))
+                .collect()
+        } else {
+            sessions
+                .iter()
+                .copied()
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect()
+        };
+        input.solver.allowed_sessions = Some(allowed);
+        Ok(())
+    }
+}
+
+/// `solve` flags about the input's `initial_schedule`.
+#[derive(Args, Clone, Debug, Default)]
+struct WarmStartArgs {
+    /// Fix capacity overflows, misplaced immovable people, and missing people in
+    /// `initial_schedule` before solving, writing each change to stderr as one JSON line
+    #[arg(long, conflicts_with = "resume")]
+    repair_initial_schedule: bool,
+}
+
+impl WarmStartArgs {
+    fn apply(&self, input: &mut ApiInput) -> Result<()> {
+        if !self.repair_initial_schedule {
+            return Ok(());
+        }
+        if input.initial_schedule.is_none() {
+            return Err(public_errors::invalid_input_error(
+                "--repair-initial-schedule needs an input with an initial_schedule",
+                Some("--repair-initial-schedule".to_string()),
+                "solve",
+                vec![],
+            ));
+        }
+        let repair = repair_initial_schedule(input)
+            .map_err(|error| public_errors::map_solver_error(error.to_string(), "solve"))?;
+        eprintln!(
+            "Repaired initial schedule: {} change(s)",
+            repair.repairs.len()
+        );
+        for change in &repair.repairs {
+            eprintln!("{}", serde_json::to_string(change)?);
+        }
+        input.initial_schedule = Some(repair.schedule);
+        Ok(())
+    }
+}
+
+/// `solve` flags that override the `solver` section of the input.
+#[derive(Args, Clone, Debug, Default)]
+struct SolverOverrides {
+    /// Random seed, overriding `solver.seed` from the input so the run can be reproduced
+    #[arg(long, value_name = "U64")]
+    seed: Option<u64>,
+
+    /// Time limit in seconds, overriding `stop_conditions.time_limit_seconds`
+    #[arg(long, value_name = "SECONDS")]
+    time_limit: Option<u64>,
+
+    /// Iteration limit, overriding `stop_conditions.max_iterations`
+    #[arg(long, value_name = "N")]
+    max_iterations: Option<u64>,
+
+    /// Solver family, overriding `solver_type`. Switching families replaces `solver_params`
+    /// with that family's defaults
+    #[arg(long, value_name = "SOLVER")]
+    algorithm: Option<String>,
+
+    /// Initial simulated annealing temperature (solver1 only)
+    #[arg(long, value_name = "TEMP")]
+    initial_temp: Option<f64>,
+}
+
+impl SolverOverrides {
+    fn apply(&self, solver: &mut SolverConfiguration) -> Result<()> {
+        if let Some(algorithm) = &self.algorithm {
+            let kind = SolverKind::parse_config_id(algorithm).map_err(|error| {
+                public_errors::invalid_input_error(
+                    error,
+                    Some("--algorithm".to_string()),
+                    "solve",
+                    available_solver_descriptors()
+                        .iter()
+                        .map(|descriptor| descriptor.kind.canonical_id().to_string())
+                        .collect(),
+                )
+            })?;
+            if solver.solver_params.solver_kind() != kind {
+                solver.solver_params = default_solver_configuration_for(kind).solver_params;
+            }
+            solver.solver_type = kind.canonical_id().to_string();
+        }
+        if self.seed.is_some() {
+            solver.seed = self.seed;
+        }
+        if self.time_limit.is_some() {
+            solver.stop_conditions.time_limit_seconds = self.time_limit;
+        }
+        if self.max_iterations.is_some() {
+            solver.stop_conditions.max_iterations = self.max_iterations;
+        }
+        if let Some(initial_temp) = self.initial_temp {
+            let SolverParams::SimulatedAnnealing(params) = &mut solver.solver_params else {
+                return Err(public_errors::invalid_input_error(
+                    format!(
+                        "--initial-temp only applies to solver1, not {}",
+                        solver.solver_params.solver_kind().canonical_id()
+                    ),
+                    Some("--initial-temp".to_string()),
+                    "solve",
+                    vec![SolverKind::Solver1.canonical_id().to_string()],
+                ));
+            };
+            params.initial_temperature = initial_temp;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    #[default]
+    Json,
+    Pretty,
+    Csv,
+    Markdown,
+    Table,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum ReportFormatArg {
+    Html,
+    Markdown,
+}
+
+impl From<ReportFormatArg> for ReportFormat {
+    fn from(format: ReportFormatArg) -> Self {
+        match format {
+            ReportFormatArg::Html => Self::Html,
+            ReportFormatArg::Markdown => Self::Markdown,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum MatrixFormat {
+    Text,
+    Csv,
+}
+
 #[derive(Clone, Debug, ValueEnum)]
 enum TrajectoryFormat {
     Text,
@@ -535,12 +1020,26 @@ impl BenchmarkSuiteArg {
 }
 
 fn main() {
+    init_tracing();
     if let Err(error) = run() {
         eprintln!("{}", error);
         std::process::exit(1);
     }
 }
 
+/// Sends solver diagnostics to stderr, filtered by `RUST_LOG` (default `info`), so stdout
+/// stays free for command output and the `serve` protocol.
+fn init_tracing() {
+    let filter = tracing_subscriber::EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .without_time()
+        .with_target(false)
+        .init();
+}
+
 fn run() -> Result<()> {
     let raw_args: Vec<String> = std::env::args().collect();
     if cli_help::try_print_contract_help(&raw_args)? {
@@ -558,23 +1057,62 @@ fn run() -> Result<()> {
             input,
             stdin,
             output,
-            pretty,
-        } => cmd_solve(input, stdin, output, pretty),
+            overrides,
+            sessions,
+            warm_start,
+            progress,
+            progress_json,
+            checkpoint,
+            top_k,
+        } => match top_k {
+            Some(top_k) => cmd_solve_top_k(
+                input,
+                stdin,
+                &output,
+                &overrides,
+                &sessions,
+                &warm_start,
+                top_k,
+            ),
+            None => {
+                let progress = match (progress, progress_json) {
+                    (_, true) => ProgressReport::Json,
+                    (true, false) => ProgressReport::Bar,
+                    (false, false) => ProgressReport::Off,
+                };
+                cmd_solve(
+                    input,
+                    stdin,
+                    &output,
+                    &overrides,
+                    &sessions,
+                    &warm_start,
+                    progress,
+                    &checkpoint,
+                )
+            }
+        },
 
-        Commands::Validate { input, stdin } => cmd_validate(input, stdin),
+        Commands::Validate { input, stdin } => match cmd_validate(input, stdin)? {
+            VALIDATE_EXIT_VALID => Ok(()),
+            code => std::process::exit(code),
+        },
 
         Commands::DefaultConfig { pretty } => cmd_default_config(pretty),
         Commands::Recommend {
             input,
             stdin,
             pretty,
-        } => cmd_recommend(input, stdin, pretty),
+            report,
+        } => cmd_recommend(input, stdin, pretty, report),
 
         Commands::Evaluate {
             input,
             stdin,
+            problem,
+            schedule,
             pretty,
-        } => cmd_evaluate(input, stdin, pretty),
+        } => cmd_evaluate(problem.or(input), stdin, schedule.as_deref(), pretty),
 
         Commands::InspectResult {
             input,
@@ -582,6 +1120,66 @@ fn run() -> Result<()> {
             pretty,
         } => cmd_inspect_result(input, stdin, pretty),
 
+        Commands::Compare { a, b, json } => cmd_compare(&a, &b, json),
+
+        Commands::Report {
+            result,
+            format,
+            output,
+        } => cmd_report(&result, format, output),
+
+        Commands::Matrix {
+            result,
+            problem,
+            format,
+        } => cmd_matrix(&result, problem.as_deref(), format),
+
+        Commands::Explain { result, problem } => cmd_explain(&result, problem.as_deref()),
+
+        Commands::People { result, problem } => cmd_people(&result, problem.as_deref()),
+
+        Commands::Serve { stdin } => cmd_serve(stdin),
+
+        Commands::Stats { input, stdin, json } => cmd_stats(input, stdin, json),
+
+        Commands::Convert {
+            input,
+            people,
+            into,
+            out,
+        } => cmd_convert(&input, people, into.as_deref(), &out),
+
+        Commands::Bench {
+            seconds,
+            json,
+            overrides,
+        } => cmd_bench(seconds, json, &overrides),
+
+        Commands::Tune {
+            input,
+            candidates,
+            iterations,
+            seed,
+            output,
+            pretty,
+        } => cmd_tune(
+            &input,
+            TuningBudget {
+                candidates,
+                initial_iterations: iterations,
+            },
+            seed,
+            output,
+            pretty,
+        ),
+
+        Commands::Batch {
+            dir,
+            jobs,
+            pretty,
+            overrides,
+        } => cmd_batch(&dir, jobs, pretty, &overrides),
+
         Commands::Benchmark { command } => cmd_benchmark(command),
 
         Commands::Schema { schema_id, json } => cmd_schema(schema_id, json),
@@ -1196,6 +1794,91 @@ fn print_json_pretty<T: serde::Serialize>(value: &T) -> Result<()> {
     Ok(())
 }
 
+/// Serialization format of an input document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InputFormat {
+    Json,
+    Yaml,
+    Toml,
+}
+
+impl InputFormat {
+    /// YAML for `.yaml` and `.yml` files, TOML for `.toml` files, JSON otherwise (including
+    /// stdin).
+    fn detect(file: Option<&Path>, use_stdin: bool) -> Self {
+        let extension = file
+            .filter(|_| !use_stdin)
+            .and_then(Path::extension)
+            .and_then(|extension| extension.to_str());
+        match extension {
+            Some(extension)
+                if extension.eq_ignore_ascii_case("yaml")
+                    || extension.eq_ignore_ascii_case("yml") =>
+            {
+                Self::Yaml
+            }
+            Some(extension) if extension.eq_ignore_ascii_case("toml") => Self::Toml,
+            _ => Self::Json,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Json => "JSON",
+            Self::Yaml => "YAML",
+            Self::Toml => "TOML",
+        }
+    }
+}
+
+/// Parses `text` as `format`, reporting errors as invalid input at their line and column.
+fn parse_document<T: DeserializeOwned>(
+    text: &str,
+    format: InputFormat,
+    description: &str,
+    operation_id: &str,
+    expected_schema: &str,
+) -> Result<T> {
+    let (message, location) = match format {
+        InputFormat::Json => match serde_json::from_str(text) {
+            Ok(value) => return Ok(value),
+            Err(error) => (
+                error.to_string(),
+                Some(format!("line {}, column {}", error.line(), error.column())),
+            ),
+        },
+        InputFormat::Yaml => match serde_yaml::from_str(text) {
+            Ok(value) => return Ok(value),
+            Err(error) => (
+                error.to_string(),
+                error
+                    .location()
+                    .map(|at| format!("line {}, column {}", at.line(), at.column())),
+            ),
+        },
+        InputFormat::Toml => match from_toml_str(text) {
+            Ok(value) => return Ok(value),
+            Err(error) => (
+                error.message,
+                error
+                    .location
+                    .map(|(line, column)| format!("line {line}, column {column}")),
+            ),
+        },
+    };
+    Err(public_errors::invalid_input_error(
+        format!(
+            "Failed to parse {} {}: {}",
+            description,
+            format.name(),
+            message
+        ),
+        location,
+        operation_id,
+        vec![expected_schema.to_string()],
+    ))
+}
+
 fn read_input(file: Option<PathBuf>, use_stdin: bool, operation_id: &str) -> Result<String> {
     if use_stdin {
         let mut buffer = String::new();
@@ -1293,577 +1976,3132 @@ fn cmd_solver_descriptor(solver_id: &str, json: bool) -> Result<()> {
     Ok(())
 }
 
+/// Reads the `solve` input and applies the command-line overrides to it.
+fn read_solve_input(
+    input: Option<PathBuf>,
+    stdin: bool,
+    overrides: &SolverOverrides,
+    sessions: &SessionScopeArgs,
+    warm_start: &WarmStartArgs,
+) -> Result<ApiInput> {
+    let format = InputFormat::detect(input.as_deref(), stdin);
+    let input_str = read_input(input, stdin, "solve")?;
+    let solve_request: SolveRequest =
+        parse_document(&input_str, format, "input", "solve", "solve-request")?;
+    let mut api_input: ApiInput = solve_request.into();
+    overrides.apply(&mut api_input.solver)?;
+    warm_start.apply(&mut api_input)?;
+    sessions.apply(&mut api_input)?;
+    Ok(api_input)
+}
+
 fn cmd_solve(
     input: Option<PathBuf>,
     stdin: bool,
-    output: Option<PathBuf>,
-    pretty: bool,
+    output: &SolveOutputArgs,
+    overrides: &SolverOverrides,
+    sessions: &SessionScopeArgs,
+    warm_start: &WarmStartArgs,
+    progress: ProgressReport,
+    checkpoint: &CheckpointArgs,
 ) -> Result<()> {
-    let json_str = read_input(input, stdin, "solve")?;
-    let solve_request: SolveRequest = serde_json::from_str(&json_str).map_err(|error| {
-        public_errors::invalid_input_error(
-            format!("Failed to parse input JSON: {}", error),
-            Some(format!("line {}, column {}", error.line(), error.column())),
-            "solve",
-            vec!["solve-request".to_string()],
-        )
-    })?;
-    let api_input: ApiInput = solve_request.into();
+    let (api_input, resume_from) = match &checkpoint.resume {
+        Some(path) => {
+            let mut saved = read_checkpoint_file(path)?;
+            overrides.apply(&mut saved.input.solver)?;
+            sessions.apply(&mut saved.input)?;
+            (saved.input, Some(saved.checkpoint))
+        }
+        None => (
+            read_solve_input(input, stdin, overrides, sessions, warm_start)?,
+            None,
+        ),
+    };
+    let schedule = checkpoint_schedule(checkpoint, &api_input)?;
 
     eprintln!("Running solver...");
-    let result = run_solver(&api_input)
-        .map_err(|error| public_errors::map_solver_error(format!("{:?}", error), "solve"))?;
-
-    let output_json = if pretty {
-        serde_json::to_string_pretty(&result)?
-    } else {
-        serde_json::to_string(&result)?
+    install_interrupt_handler();
+    INTERRUPTED.store(false, Ordering::SeqCst);
+    let progress_bar = (progress == ProgressReport::Bar).then(solve_progress_bar);
+    let telemetry = match &output.telemetry_jsonl {
+        Some(path) => Some(Arc::new(JsonlTelemetryWriter::create(path).map_err(
+            |error| {
+                public_errors::internal_error(
+                    format!("Failed to create telemetry file {:?}: {}", path, error),
+                    "solve",
+                )
+            },
+        )?)),
+        None => None,
+    };
+    let progress_callback = solve_progress_callback(
+        progress_bar.clone(),
+        progress == ProgressReport::Json,
+        telemetry.clone(),
+    );
+    let control = checkpointing_control(progress_callback, schedule, api_input.clone());
+    let result = match &resume_from {
+        Some(checkpoint) => {
+            resume_from_checkpoint_with_control(&api_input, checkpoint, Some(&control))
+        }
+        None => run_solver_with_control(&api_input, Some(&control)),
     };
+    if let Some(progress_bar) = progress_bar {
+        progress_bar.finish_and_clear();
+    }
+    if let (Some(telemetry), Some(path)) = (telemetry, &output.telemetry_jsonl) {
+        match telemetry.finish() {
+            Ok(records) => eprintln!("Telemetry: {records} records written to {:?}", path),
+            Err(error) => eprintln!("Warning: failed to write telemetry to {:?}: {error}", path),
+        }
+    }
+    let result =
+        result.map_err(|error| public_errors::map_solver_error(error.message(), "solve"))?;
+    let interrupted = INTERRUPTED.load(Ordering::SeqCst)
+        && result.stop_reason == Some(StopReason::ProgressCallbackRequestedStop);
+    if interrupted {
+        eprintln!("Interrupted: writing the best schedule found so far");
+    }
 
-    if let Some(output_path) = output {
-        fs::write(&output_path, &output_json).map_err(|error| {
-            public_errors::internal_error(
-                format!("Failed to write output to {:?}: {}", output_path, error),
-                "solve",
-            )
-        })?;
-        eprintln!("Result written to {:?}", output_path);
+    let output_text = render_solve_output(
+        &result,
+        &api_input.problem.people,
+        output.output_format,
+        output.pretty,
+        &output.csv_attributes,
+        interrupted,
+    )?;
+
+    if let Some(output_path) = &output.output {
+        write_solve_output(output_path, &output_text)?;
     } else {
-        println!("{}", output_json);
+        print!("{}", output_text);
+        if matches!(
+            output.output_format,
+            OutputFormat::Json | OutputFormat::Pretty
+        ) {
+            println!();
+        }
     }
 
     Ok(())
 }
 
-fn cmd_validate(input: Option<PathBuf>, stdin: bool) -> Result<()> {
-    let json_str = read_input(input, stdin, "validate-scenario")?;
+/// What `solve --checkpoint-file` writes: the checkpoint together with the input it belongs
+/// to, so `--resume` needs nothing else.
+#[derive(Serialize, Deserialize)]
+struct CheckpointFile {
+    input: ApiInput,
+    checkpoint: SolverCheckpoint,
+}
 
-    let validate_request: ValidateRequest = serde_json::from_str(&json_str).map_err(|error| {
+fn read_checkpoint_file(path: &Path) -> Result<CheckpointFile> {
+    let text = read_input(Some(path.to_path_buf()), false, "solve")?;
+    serde_json::from_str(&text).map_err(|error| {
         public_errors::invalid_input_error(
-            format!("Failed to parse input JSON: {}", error),
-            Some(format!("line {}, column {}", error.line(), error.column())),
-            "validate-scenario",
-            vec!["validate-request".to_string()],
+            format!("Failed to parse checkpoint {:?}: {}", path, error),
+            Some("--resume".to_string()),
+            "solve",
+            vec!["a file written by --checkpoint-file".to_string()],
         )
-    })?;
-    let api_input: ApiInput = validate_request.into();
-
-    use gm_core::solver1::State;
-    match State::new(&api_input) {
-        Ok(_) => {
-            let response = ValidateResponse {
-                valid: true,
-                issues: Vec::new(),
-            };
-            print_json_pretty(&response)?;
-            Ok(())
-        }
-        Err(e) => {
-            let error_text = format!("{:?}", e);
-            let issue = if error_text.contains("unknown variant")
-                || error_text.contains("expected one of")
-            {
-                ValidationIssue {
-                    code: Some("unsupported-constraint-kind".to_string()),
-                    message: error_text,
-                    path: Some("constraints[*].type".to_string()),
-                }
-            } else {
-                ValidationIssue {
-                    code: Some("infeasible-scenario".to_string()),
-                    message: error_text,
-                    path: None,
-                }
-            };
-            let response = ValidateResponse {
-                valid: false,
-                issues: vec![issue],
-            };
-            print_json_pretty(&response)?;
-            Ok(())
-        }
-    }
+    })
 }
 
-fn cmd_default_config(pretty: bool) -> Result<()> {
-    let configuration = default_solver_configuration();
-    let output_json = if pretty {
-        serde_json::to_string_pretty(&configuration)?
-    } else {
-        serde_json::to_string(&configuration)?
-    };
-
-    println!("{}", output_json);
-    Ok(())
+/// Writes through a temporary file, so an interrupted write leaves the previous checkpoint.
+fn write_checkpoint_file(path: &Path, saved: &CheckpointFile) -> Result<()> {
+    let mut temporary = path.as_os_str().to_owned();
+    temporary.push(".tmp");
+    let temporary = PathBuf::from(temporary);
+    fs::write(&temporary, serde_json::to_string(saved)?)
+        .with_context(|| format!("failed to write checkpoint {}", temporary.display()))?;
+    fs::rename(&temporary, path)
+        .with_context(|| format!("failed to replace checkpoint {}", path.display()))
 }
 
-fn cmd_recommend(input: Option<PathBuf>, stdin: bool, pretty: bool) -> Result<()> {
-    let json_str = read_input(input, stdin, "recommend-settings")?;
-    let recommendation_input = parse_recommend_input(&json_str)?;
-
-    eprintln!(
-        "Calculating recommended settings for {}s runtime...",
-        recommendation_input.desired_runtime_seconds
-    );
-
-    let scenario_definition: gm_core::models::ProblemDefinition =
-        (&recommendation_input.scenario).into();
-    let recommended = calculate_recommended_settings(
-        &scenario_definition,
-        &recommendation_input.objectives,
-        &recommendation_input.constraints,
-        recommendation_input.desired_runtime_seconds,
-    )
-    .map_err(|error| {
-        public_errors::map_solver_error(format!("{:?}", error), "recommend-settings")
-    })?;
+/// When and where `solve` saves checkpoints.
+struct CheckpointSchedule {
+    path: PathBuf,
+    every: Duration,
+    last_saved: std::sync::Mutex<Instant>,
+}
 
-    let output_json = if pretty {
-        serde_json::to_string_pretty(&recommended)?
-    } else {
-        serde_json::to_string(&recommended)?
+fn checkpoint_schedule(
+    checkpoint: &CheckpointArgs,
+    api_input: &ApiInput,
+) -> Result<Option<CheckpointSchedule>> {
+    let Some(every) = checkpoint.checkpoint_every else {
+        return Ok(None);
+    };
+    let Some(path) = checkpoint
+        .checkpoint_file
+        .clone()
+        .or_else(|| checkpoint.resume.clone())
+    else {
+        return Err(public_errors::invalid_input_error(
+            "--checkpoint-every needs a --checkpoint-file to write to",
+            Some("--checkpoint-file".to_string()),
+            "solve",
+            vec!["--checkpoint-file run.ckpt".to_string()],
+        ));
     };
+    let solver_kind = api_input.solver.solver_params.solver_kind();
+    if solver_kind != SolverKind::Solver1 {
+        return Err(public_errors::invalid_input_error(
+            format!(
+                "checkpoints are only supported by solver1, not {}",
+                solver_kind.canonical_id()
+            ),
+            Some("--checkpoint-every".to_string()),
+            "solve",
+            vec!["--algorithm solver1".to_string()],
+        ));
+    }
+    Ok(Some(CheckpointSchedule {
+        path,
+        every,
+        last_saved: std::sync::Mutex::new(Instant::now()),
+    }))
+}
 
-    println!("{}", output_json);
-    Ok(())
+/// Wraps `progress_callback` so the run also saves a checkpoint whenever `schedule` is due,
+/// and once more before stopping on Ctrl-C.
+fn checkpointing_control(
+    progress_callback: ProgressCallback,
+    schedule: Option<CheckpointSchedule>,
+    input: ApiInput,
+) -> ProgressControlCallback {
+    Box::new(move |update: &ProgressUpdate| {
+        let keep_going = progress_callback(update);
+        let Some(schedule) = &schedule else {
+            return keep_going.into();
+        };
+        let mut last_saved = schedule
+            .last_saved
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        match &update.checkpoint {
+            Some(checkpoint) => {
+                let saved = CheckpointFile {
+                    input: input.clone(),
+                    checkpoint: checkpoint.clone(),
+                };
+                if let Err(error) = write_checkpoint_file(&schedule.path, &saved) {
+                    eprintln!("Warning: {error:#}");
+                }
+                *last_saved = Instant::now();
+                keep_going.into()
+            }
+            // The update after this one carries the checkpoint to save before stopping.
+            None if !keep_going => ProgressControl::SaveCheckpoint,
+            None if last_saved.elapsed() >= schedule.every => {
+                *last_saved = Instant::now();
+                ProgressControl::SaveCheckpoint
+            }
+            None => ProgressControl::Continue,
+        }
+    })
 }
 
-fn cmd_evaluate(input: Option<PathBuf>, stdin: bool, pretty: bool) -> Result<()> {
-    let json_str = read_input(input, stdin, "evaluate-input")?;
-    let solve_request: SolveRequest = serde_json::from_str(&json_str).map_err(|error| {
-        public_errors::invalid_input_error(
-            format!("Failed to parse input JSON: {}", error),
-            Some(format!("line {}, column {}", error.line(), error.column())),
-            "evaluate-input",
-            vec!["solve-request".to_string()],
+fn write_solve_output(output_path: &Path, output_text: &str) -> Result<()> {
+    fs::write(output_path, output_text).map_err(|error| {
+        public_errors::internal_error(
+            format!("Failed to write output to {:?}: {}", output_path, error),
+            "solve",
         )
     })?;
-    let api_input: ApiInput = solve_request.into();
+    eprintln!("Result written to {:?}", output_path);
+    Ok(())
+}
 
-    if api_input.construction_seed_schedule.is_some() {
+/// Extra solves `--top-k` may run per requested schedule when seeds keep finding
+/// schedules it already has.
+const TOP_K_ATTEMPTS_PER_RESULT: usize = 3;
+
+/// Solves with seeds counting up from the configured one until `top_k` distinct schedules
+/// are found (or the attempts run out), then writes them best first.
+fn cmd_solve_top_k(
+    input: Option<PathBuf>,
+    stdin: bool,
+    output: &SolveOutputArgs,
+    overrides: &SolverOverrides,
+    sessions: &SessionScopeArgs,
+    warm_start: &WarmStartArgs,
+    top_k: usize,
+) -> Result<()> {
+    if top_k == 0 {
         return Err(public_errors::invalid_input_error(
-            "Evaluate does not accept construction_seed_schedule; provide a complete initial_schedule instead",
-            Some("construction_seed_schedule".to_string()),
-            "evaluate-input",
-            vec!["remove construction_seed_schedule".to_string(), "provide initial_schedule".to_string()],
+            "--top-k must be at least 1",
+            Some("--top-k".to_string()),
+            "solve",
+            vec!["--top-k 3".to_string()],
         ));
     }
+    let mut api_input = read_solve_input(input, stdin, overrides, sessions, warm_start)?;
+    let first_seed = api_input.solver.seed.unwrap_or(0);
 
-    if api_input.initial_schedule.is_none() {
-        return Err(public_errors::invalid_input_error(
-            "Evaluate requires initial_schedule in the input",
-            Some("initial_schedule".to_string()),
-            "evaluate-input",
-            vec!["provide initial_schedule".to_string()],
-        ));
+    eprintln!(
+        "Looking for {top_k} distinct schedules in at most {} solves",
+        top_k * TOP_K_ATTEMPTS_PER_RESULT
+    );
+    let mut results = top_k_results(&mut api_input, first_seed, top_k)?;
+    results.sort_by(|a, b| a.final_score.total_cmp(&b.final_score));
+    if results.len() < top_k {
+        eprintln!(
+            "Found {} distinct schedules in {} solves",
+            results.len(),
+            top_k * TOP_K_ATTEMPTS_PER_RESULT
+        );
     }
 
-    let mut eval_input = api_input.clone();
-    eval_input.solver.stop_conditions.max_iterations = Some(0);
+    let template = output
+        .output
+        .clone()
+        .unwrap_or_else(|| PathBuf::from("result.json"));
+    for (rank, result) in results.iter().enumerate() {
+        let output_text = render_solve_output(
+            result,
+            &api_input.problem.people,
+            output.output_format,
+            output.pretty,
+            &output.csv_attributes,
+            false,
+        )?;
+        write_solve_output(&ranked_output_path(&template, rank + 1), &output_text)?;
+    }
+    Ok(())
+}
 
-    let result = run_solver(&eval_input).map_err(|error| {
-        public_errors::map_solver_error(format!("{:?}", error), "evaluate-input")
-    })?;
+fn top_k_results(
+    api_input: &mut ApiInput,
+    first_seed: u64,
+    top_k: usize,
+) -> Result<Vec<SolverResult>> {
+    let mut results: Vec<SolverResult> = Vec::new();
+    let mut seen = HashSet::new();
+    for attempt in 0..top_k * TOP_K_ATTEMPTS_PER_RESULT {
+        if results.len() == top_k {
+            break;
+        }
+        api_input.solver.seed = Some(first_seed.wrapping_add(attempt as u64));
+        eprintln!(
+            "Running solver (seed {})...",
+            first_seed.wrapping_add(attempt as u64)
+        );
+        let result = run_solver(api_input)
+            .map_err(|error| public_errors::map_solver_error(error.message(), "solve"))?;
+        if seen.insert(schedule_partition(&result.schedule)) {
+            results.push(result);
+        }
+    }
+    Ok(results)
+}
 
-    let output_json = if pretty {
-        serde_json::to_string_pretty(&result)?
-    } else {
-        serde_json::to_string(&result)?
+/// Who is grouped with whom in each session, leaving out which group they are in, so
+/// schedules that only relabel groups compare equal.
+fn schedule_partition(schedule: &ApiSchedule) -> BTreeMap<String, BTreeSet<BTreeSet<String>>> {
+    schedule
+        .iter()
+        .map(|(session, groups)| {
+            let members = groups
+                .values()
+                .filter(|people| !people.is_empty())
+                .map(|people| people.iter().cloned().collect())
+                .collect();
+            (session.clone(), members)
+        })
+        .collect()
+}
+
+/// `result.json` with `rank` 2 becomes `result_2.json`.
+fn ranked_output_path(template: &Path, rank: usize) -> PathBuf {
+    let stem = template.file_stem().map_or_else(
+        || "result".into(),
+        |stem| stem.to_string_lossy().into_owned(),
+    );
+    let file_name = match template.extension() {
+        Some(extension) => format!("{stem}_{rank}.{}", extension.to_string_lossy()),
+        None => format!("{stem}_{rank}"),
     };
+    template.with_file_name(file_name)
+}
 
-    println!("{}", output_json);
-    Ok(())
+/// Formats a solve result for `--output-format`. An `interrupted` run is flagged with a
+/// top-level `"interrupted": true` in JSON and a leading note in Markdown and tables; CSV
+/// has no room for the flag.
+fn render_solve_output(
+    result: &SolverResult,
+    people: &[Person],
+    output_format: OutputFormat,
+    pretty: bool,
+    csv_attributes: &[String],
+    interrupted: bool,
+) -> Result<String> {
+    #[derive(Serialize)]
+    struct InterruptedResult<'a> {
+        interrupted: bool,
+        #[serde(flatten)]
+        result: &'a SolverResult,
+    }
+
+    const INTERRUPTED_NOTE: &str =
+        "Interrupted: this is the best schedule found before the solver was stopped.";
+    let pretty = pretty || output_format == OutputFormat::Pretty;
+    Ok(match output_format {
+        OutputFormat::Json | OutputFormat::Pretty => match (interrupted, pretty) {
+            (true, true) => serde_json::to_string_pretty(&InterruptedResult {
+                interrupted,
+                result,
+            })?,
+            (true, false) => serde_json::to_string(&InterruptedResult {
+                interrupted,
+                result,
+            })?,
+            (false, true) => serde_json::to_string_pretty(result)?,
+            (false, false) => serde_json::to_string(result)?,
+        },
+        OutputFormat::Csv => {
+            let attributes = if csv_attributes.is_empty() {
+                let mut keys: Vec<&str> = people
+                    .iter()
+                    .flat_map(|person| person.attributes.keys().map(String::as_str))
+                    .collect();
+                keys.sort_unstable();
+                keys.dedup();
+                keys
+            } else {
+                csv_attributes.iter().map(String::as_str).collect()
+            };
+            result.to_csv(people, &attributes)
+        }
+        OutputFormat::Markdown if interrupted => {
+            format!("> {INTERRUPTED_NOTE}\n\n{}", result.to_markdown())
+        }
+        OutputFormat::Markdown => result.to_markdown(),
+        OutputFormat::Table => {
+            let table = result.display_with(people, &DisplayOptions::default());
+            if interrupted {
+                format!("{INTERRUPTED_NOTE}\n\n{table}")
+            } else {
+                table
+            }
+        }
+    })
 }
 
-fn cmd_inspect_result(input: Option<PathBuf>, stdin: bool, pretty: bool) -> Result<()> {
-    let json_str = read_input(input, stdin, "inspect-result")?;
-    let result: SolverResult = serde_json::from_str(&json_str).map_err(|error| {
-        public_errors::invalid_input_error(
-            format!("Failed to parse result JSON: {}", error),
-            Some(format!("line {}, column {}", error.line(), error.column())),
-            "inspect-result",
-            vec!["solve-response".to_string()],
-        )
-    })?;
+/// Set by Ctrl-C during `solve`; the progress callback then stops the solver.
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Makes Ctrl-C stop a running solve at its next progress update instead of killing the
+/// process, so the best schedule so far is still written. A second Ctrl-C exits at once.
+fn install_interrupt_handler() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let installed = ctrlc::set_handler(|| {
+            if INTERRUPTED.swap(true, Ordering::SeqCst) {
+                std::process::exit(130);
+            }
+            eprintln!("\nStopping the solver; press Ctrl-C again to abort");
+        });
+        if let Err(error) = installed {
+            eprintln!("Warning: Ctrl-C will abort without output: {error}");
+        }
+    });
+}
 
-    let summary = ResultSummary::from(&result);
-    if pretty {
-        print_json_pretty(&summary)?;
-    } else {
-        println!("{}", serde_json::to_string(&summary)?);
-    }
-    Ok(())
+/// How `solve` reports progress on stderr.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ProgressReport {
+    /// No progress output (the default)
+    Off,
+    /// A live progress bar (`--progress`)
+    Bar,
+    /// One [`ProgressLine`] JSON object per update (`--progress-json`)
+    Json,
 }
 
-fn cmd_schema(schema_id: Option<String>, json: bool) -> Result<()> {
-    let Some(schema_id) = schema_id.map(resolve_schema_alias) else {
-        #[derive(Serialize)]
-        struct SchemaListEntry<'a> {
-            id: &'a str,
-            version: &'a str,
+/// A progress update as written by `--progress-json`: the headline numbers of
+/// [`ProgressUpdate`], without its move statistics or schedule.
+#[derive(Serialize)]
+struct ProgressLine {
+    iteration: u64,
+    max_iterations: u64,
+    elapsed_seconds: f64,
+    estimated_remaining_seconds: Option<f64>,
+    current_score: f64,
+    best_score: f64,
+    best_contacts: i32,
+    temperature: f64,
+}
+
+impl From<&ProgressUpdate> for ProgressLine {
+    fn from(update: &ProgressUpdate) -> Self {
+        Self {
+            iteration: update.iteration,
+            max_iterations: update.max_iterations,
+            elapsed_seconds: update.elapsed_seconds,
+            estimated_remaining_seconds: update.estimated_remaining_seconds,
+            current_score: update.current_score,
+            best_score: update.best_score,
+            best_contacts: update.best_contacts,
+            temperature: update.temperature,
         }
+    }
+}
 
-        let entries: Vec<_> = schema_specs()
-            .iter()
-            .map(|spec| SchemaListEntry {
-                id: spec.id,
-                version: spec.version,
-            })
-            .collect();
+/// A stderr progress bar for `solve --progress`, sized once the first update arrives.
+fn solve_progress_bar() -> ProgressBar {
+    let progress_bar = ProgressBar::new(0);
+    progress_bar.set_style(
+        ProgressStyle::with_template("[{elapsed_precise}] {bar:30} {pos}/{len} iterations {msg}")
+            .expect("progress template is valid"),
+    );
+    progress_bar
+}
 
+/// Moves `progress_bar` along with the solver's progress updates, writes them to stderr as
+/// JSON lines when `json` is set, and stops the solver once [`INTERRUPTED`] is set.
+fn solve_progress_callback(
+    progress_bar: Option<ProgressBar>,
+    json: bool,
+    telemetry: Option<Arc<JsonlTelemetryWriter<BufWriter<fs::File>>>>,
+) -> ProgressCallback {
+    Box::new(move |update: &ProgressUpdate| {
+        if let Some(telemetry) = &telemetry {
+            telemetry.record(update);
+        }
+        if let Some(progress_bar) = &progress_bar {
+            progress_bar.set_length(update.max_iterations);
+            progress_bar.set_position(update.iteration);
+            progress_bar.set_message(solve_progress_message(update));
+        }
         if json {
-            print_json_pretty(&entries)?;
-        } else {
-            println!("Known schema ids:");
-            for entry in entries {
-                println!("- {} ({})", entry.id, entry.version);
+            if let Ok(line) = serde_json::to_string(&ProgressLine::from(update)) {
+                eprintln!("{line}");
             }
         }
-        return Ok(());
-    };
+        !INTERRUPTED.load(Ordering::SeqCst)
+    })
+}
 
-    let schema = export_schema(&schema_id).ok_or_else(|| {
-        public_errors::unknown_schema_error(
-            &schema_id,
-            known_schema_ids().into_iter().map(str::to_string).collect(),
-        )
-    })?;
+fn solve_progress_message(update: &ProgressUpdate) -> String {
+    let eta = update
+        .estimated_remaining_seconds
+        .map_or_else(|| "?".to_string(), |seconds| format!("{seconds:.0}s"));
+    format!(
+        "| best {:.2} | T {:.4} | ETA {eta}",
+        update.best_score, update.temperature
+    )
+}
 
-    if json {
-        print_json_pretty(&schema)?;
-    } else {
-        println!("schema: {}", schema_id);
-        println!();
-        println!("{}", serde_json::to_string_pretty(&schema)?);
-    }
+/// Suffix of the result files `batch` writes; inputs ending in it are skipped.
+const BATCH_RESULT_SUFFIX: &str = ".result.json";
 
-    Ok(())
+/// How one scenario of a `batch` run went.
+struct BatchOutcome {
+    file: String,
+    result: Result<BatchSolved>,
 }
 
-fn cmd_capabilities(json: bool) -> Result<()> {
-    #[derive(Serialize)]
-    struct CapabilityEntry<'a> {
-        command_name: &'a str,
-        operation_id: Option<&'a str>,
-        summary: &'a str,
-        kind: Option<String>,
-        input_schema_ids: Vec<&'a str>,
-        output_schema_ids: Vec<&'a str>,
-        related_operation_ids: Vec<&'a str>,
-    }
+struct BatchSolved {
+    final_score: f64,
+    unique_contacts: i32,
+    /// Violated constraints, counted as `explain` lists them; `None` when the result could not
+    /// be rescored.
+    violations: Option<usize>,
+    elapsed: std::time::Duration,
+}
 
-    #[derive(Serialize)]
-    struct CapabilityPayload<'a> {
-        title: &'a str,
-        summary: &'a str,
-        discovery_note: &'a str,
-        operations: Vec<CapabilityEntry<'a>>,
+fn cmd_batch(dir: &Path, jobs: usize, pretty: bool, overrides: &SolverOverrides) -> Result<()> {
+    let mut inputs: Vec<PathBuf> = fs::read_dir(dir)
+        .with_context(|| format!("failed to read batch dir {}", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| is_batch_input(path))
+        .collect();
+    inputs.sort();
+    if inputs.is_empty() {
+        anyhow::bail!(
+            "no .json, .yaml, .yml or .toml scenario files in {}",
+            dir.display()
+        );
     }
 
-    let bootstrap = bootstrap_spec();
-    let operations = crate::contract_surface::public_cli_contract_bindings()
-        .map(|binding| {
-            if let Some(operation_id) = binding.operation_id {
-                let operation = operation_spec(operation_id).expect("bound operation");
-                CapabilityEntry {
-                    command_name: binding.command_name,
-                    operation_id: Some(operation.id),
-                    summary: operation.summary,
-                    kind: Some(format!("{:?}", operation.kind).to_ascii_lowercase()),
-                    input_schema_ids: operation.input_schema_ids.to_vec(),
-                    output_schema_ids: operation.output_schema_ids.to_vec(),
-                    related_operation_ids: operation.related_operation_ids.to_vec(),
-                }
-            } else {
-                CapabilityEntry {
-                    command_name: binding.command_name,
-                    operation_id: None,
-                    summary: binding.note,
-                    kind: None,
-                    input_schema_ids: Vec::new(),
-                    output_schema_ids: Vec::new(),
-                    related_operation_ids: bootstrap.top_level_operation_ids.to_vec(),
+    let next = std::sync::atomic::AtomicUsize::new(0);
+    let outcomes = std::sync::Mutex::new(Vec::with_capacity(inputs.len()));
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, inputs.len()) {
+            scope.spawn(|| {
+                while let Some(path) = inputs.get(next.fetch_add(1, Ordering::SeqCst)) {
+                    eprintln!("Solving {}...", path.display());
+                    let outcome = BatchOutcome {
+                        file: path
+                            .file_name()
+                            .map_or_else(String::new, |name| name.to_string_lossy().into_owned()),
+                        result: solve_batch_input(path, pretty, overrides),
+                    };
+                    outcomes
+                        .lock()
+                        .unwrap_or_else(std::sync::PoisonError::into_inner)
+                        .push(outcome);
                 }
-            }
-        })
-        .collect();
+            });
+        }
+    });
+    let mut outcomes = outcomes
+        .into_inner()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    outcomes.sort_by(|left, right| left.file.cmp(&right.file));
+
+    print!("{}", render_batch_summary(&outcomes));
+    let failed = outcomes
+        .iter()
+        .filter(|outcome| outcome.result.is_err())
+        .count();
+    if failed > 0 {
+        anyhow::bail!("{failed} of {} scenarios failed", outcomes.len());
+    }
+    Ok(())
+}
 
-    let payload = CapabilityPayload {
-        title: bootstrap.title,
-        summary: bootstrap.summary,
-        discovery_note: bootstrap.discovery_note,
-        operations,
+fn is_batch_input(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
     };
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or("");
+    path.is_file()
+        && !name.ends_with(BATCH_RESULT_SUFFIX)
+        && ["json", "yaml", "yml", "toml"]
+            .iter()
+            .any(|known| extension.eq_ignore_ascii_case(known))
+}
 
-    if json {
-        print_json_pretty(&payload)
+/// Solves the scenario at `path` and writes its JSON result to `<stem>.result.json`.
+fn solve_batch_input(
+    path: &Path,
+    pretty: bool,
+    overrides: &SolverOverrides,
+) -> Result<BatchSolved> {
+    let input_str = read_input(Some(path.to_path_buf()), false, "solve")?;
+    let format = InputFormat::detect(Some(path), false);
+    let solve_request: SolveRequest =
+        parse_document(&input_str, format, "input", "solve", "solve-request")?;
+    let mut api_input: ApiInput = solve_request.into();
+    overrides.apply(&mut api_input.solver)?;
+
+    let started = std::time::Instant::now();
+    let result = run_solver(&api_input)
+        .map_err(|error| public_errors::map_solver_error(error.message(), "solve"))?;
+    let elapsed = started.elapsed();
+
+    let output_text = if pretty {
+        serde_json::to_string_pretty(&result)?
     } else {
-        println!("{}", payload.title);
-        println!();
-        println!("{}", payload.summary);
-        println!();
-        for operation in payload.operations {
-            println!("- {}", operation.command_name);
-            if let Some(operation_id) = operation.operation_id {
-                println!("  operation_id: {}", operation_id);
+        serde_json::to_string(&result)?
+    };
+    let stem = path
+        .file_stem()
+        .map_or_else(String::new, |stem| stem.to_string_lossy().into_owned());
+    let output_path = path.with_file_name(format!("{stem}{BATCH_RESULT_SUFFIX}"));
+    fs::write(&output_path, output_text + "\n")
+        .with_context(|| format!("failed to write result {}", output_path.display()))?;
+
+    Ok(BatchSolved {
+        final_score: result.final_score,
+        unique_contacts: result.unique_contacts,
+        violations: result.constraint_violations.as_ref().map(Vec::len),
+        elapsed,
+    })
+}
+
+fn render_batch_summary(outcomes: &[BatchOutcome]) -> String {
+    let width = outcomes
+        .iter()
+        .map(|outcome| outcome.file.chars().count())
+        .chain(std::iter::once("FILE".len()))
+        .max()
+        .unwrap_or(0);
+    let mut out = format!(
+        "{:<width$}  {:>12}  {:>8}  {:>10}  {:>9}\n",
+        "FILE", "SCORE", "CONTACTS", "VIOLATIONS", "TIME (s)"
+    );
+    for outcome in outcomes {
+        match &outcome.result {
+            Ok(solved) => out.push_str(&format!(
+                "{:<width$}  {:>12.2}  {:>8}  {:>10}  {:>9.2}\n",
+                outcome.file,
+                solved.final_score,
+                solved.unique_contacts,
+                solved
+                    .violations
+                    .map_or_else(|| "n/a".to_string(), |count| count.to_string()),
+                solved.elapsed.as_secs_f64()
+            )),
+            Err(error) => {
+                let reason = error.to_string();
+                let reason = reason.lines().next().unwrap_or("");
+                out.push_str(&format!("{:<width$}  FAILED: {reason}\n", outcome.file));
             }
-            println!("  summary: {}", operation.summary);
-            if !operation.input_schema_ids.is_empty() {
-                println!("  input schemas: {}", operation.input_schema_ids.join(", "));
-            }
-            if !operation.output_schema_ids.is_empty() {
-                println!(
-                    "  output schemas: {}",
-                    operation.output_schema_ids.join(", ")
-                );
+        }
+    }
+    out
+}
+
+fn cmd_serve(stdin: bool) -> Result<()> {
+    if !stdin {
+        return Err(public_errors::invalid_input_error(
+            "serve reads requests from stdin; pass --stdin",
+            Some("--stdin".to_string()),
+            "solve",
+            vec!["--stdin".to_string()],
+        ));
+    }
+    serve_lines(io::stdin().lock(), &mut io::stdout().lock())
+}
+
+/// Answers every non-blank line of `requests` with one line on `responses`: the result JSON,
+/// or the public error envelope when the line cannot be parsed or solved. Each response is
+/// flushed before the next request is read, so callers can wait for it; solver logs go to
+/// stderr and never interleave with the responses.
+fn serve_lines(requests: impl io::BufRead, responses: &mut impl io::Write) -> Result<()> {
+    for line in requests.lines() {
+        let line = line.context("failed to read request from stdin")?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let solved = parse_document::<SolveRequest>(
+            &line,
+            InputFormat::Json,
+            "input",
+            "solve",
+            "solve-request",
+        )
+        .and_then(|solve_request| {
+            let api_input: ApiInput = solve_request.into();
+            run_solver(&api_input)
+                .map_err(|error| public_errors::map_solver_error(error.message(), "solve"))
+        });
+        let response = match solved {
+            Ok(result) => serde_json::to_string(&result)?,
+            Err(error) => serde_json::to_string(&public_errors::envelope(&error, "solve"))?,
+        };
+        writeln!(responses, "{response}").context("failed to write response")?;
+        responses.flush().context("failed to write response")?;
+    }
+    Ok(())
+}
+
+fn cmd_stats(input: Option<PathBuf>, stdin: bool, json: bool) -> Result<()> {
+    let format = InputFormat::detect(input.as_deref(), stdin);
+    let input_str = read_input(input, stdin, "validate-scenario")?;
+    let request: ValidateRequest = parse_document(
+        &input_str,
+        format,
+        "input",
+        "validate-scenario",
+        "validate-request",
+    )?;
+    let stats = problem_stats(&request.into());
+    if json {
+        return print_json_pretty(&stats);
+    }
+    print!("{}", render_problem_stats(&stats));
+    Ok(())
+}
+
+fn render_problem_stats(stats: &ProblemStats) -> String {
+    let mut out = format!(
+        "PEOPLE: {}\nGROUPS: {}\nSESSIONS: {}\n",
+        stats.people, stats.groups, stats.num_sessions
+    );
+    let reachable = if stats.total_pairs == 0 {
+        0.0
+    } else {
+        f64::from(stats.max_possible_contacts) / stats.total_pairs as f64 * 100.0
+    };
+    out.push_str(&format!(
+        "MAX POSSIBLE CONTACTS: {} of {} pairs ({reachable:.1}%)\n",
+        stats.max_possible_contacts, stats.total_pairs
+    ));
+
+    out.push_str("\nCAPACITY (attendees / seats, slack):\n");
+    for session in &stats.capacity_slack {
+        out.push_str(&format!(
+            "  session {}: {} / {} ({:+}){}\n",
+            session.session,
+            session.attendees,
+            session.capacity,
+            session.slack,
+            if session.slack < 0 {
+                "  OVER CAPACITY"
+            } else {
+                ""
             }
-            if !operation.related_operation_ids.is_empty() {
-                println!("  related: {}", operation.related_operation_ids.join(", "));
+        ));
+    }
+
+    out.push_str("\nMUST-STAY-TOGETHER CLIQUES:");
+    if stats.clique_sizes.is_empty() {
+        out.push_str(" none\n");
+    } else {
+        let sizes: Vec<String> = stats.clique_sizes.iter().map(u32::to_string).collect();
+        out.push_str(&format!(
+            " {} (sizes {})\n",
+            stats.clique_sizes.len(),
+            sizes.join(", ")
+        ));
+    }
+
+    out.push_str("\nCONSTRAINTS:");
+    if stats.constraint_counts.is_empty() {
+        out.push_str(" none\n");
+    } else {
+        out.push('\n');
+        let width = stats
+            .constraint_counts
+            .keys()
+            .map(String::len)
+            .max()
+            .unwrap_or(0);
+        for (constraint_type, count) in &stats.constraint_counts {
+            out.push_str(&format!("  {constraint_type:<width$}  {count}\n"));
+        }
+    }
+    out
+}
+
+fn cmd_convert(input: &Path, people: bool, into: Option<&Path>, out: &Path) -> Result<()> {
+    let document = if people {
+        people_import_document(input, into)?
+    } else if is_workbook(input) {
+        return Err(public_errors::invalid_input_error(
+            ".xlsx workbooks can only be imported with --people",
+            Some("input".to_string()),
+            "solve",
+            vec![format!(
+                "convert {} --people --out problem.json",
+                input.display()
+            )],
+        ));
+    } else {
+        read_document(input)?
+    };
+    let output_text = match InputFormat::detect(Some(out), false) {
+        InputFormat::Json => serde_json::to_string_pretty(&document)? + "\n",
+        InputFormat::Yaml => serde_yaml::to_string(&document)?,
+        InputFormat::Toml => {
+            return Err(public_errors::invalid_input_error(
+                "TOML is only supported as input; write the output as .json or .yaml",
+                Some("--out".to_string()),
+                "solve",
+                vec![
+                    "--out problem.json".to_string(),
+                    "--out problem.yaml".to_string(),
+                ],
+            ))
+        }
+    };
+    fs::write(out, output_text).with_context(|| format!("failed to write {}", out.display()))?;
+    eprintln!("Converted {:?} to {:?}", input, out);
+    Ok(())
+}
+
+/// Reads a JSON, YAML or TOML document as-is, so converting it keeps every field in order.
+fn read_document(path: &Path) -> Result<serde_yaml::Value> {
+    let input_str = read_input(Some(path.to_path_buf()), false, "solve")?;
+    parse_document(
+        &input_str,
+        InputFormat::detect(Some(path), false),
+        "input",
+        "solve",
+        "solve-request",
+    )
+}
+
+fn is_workbook(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("xlsx"))
+}
+
+/// People and pair constraints from the `.xlsx` workbook at `path`.
+#[cfg(feature = "xlsx")]
+fn import_people_workbook(path: &Path) -> Result<(Vec<Person>, Vec<Constraint>)> {
+    let bytes = fs::read(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let import = import_workbook(&bytes).map_err(|error| {
+        let location = [
+            error.sheet.as_ref().map(|sheet| format!("sheet '{sheet}'")),
+            error.row.map(|row| format!("row {row}")),
+            error
+                .column
+                .as_ref()
+                .map(|column| format!("column '{column}'")),
+        ];
+        public_errors::invalid_input_error(
+            format!("Failed to import workbook: {}", error.message),
+            Some(
+                location
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+            "solve",
+            vec![
+                "People sheet: id,<attribute>,...,sessions".to_string(),
+                "Constraints sheet: type,people,penalty_weight,sessions".to_string(),
+            ],
+        )
+    })?;
+    Ok((import.people, import.constraints))
+}
+
+#[cfg(not(feature = "xlsx"))]
+fn import_people_workbook(path: &Path) -> Result<(Vec<Person>, Vec<Constraint>)> {
+    Err(public_errors::invalid_input_error(
+        format!(
+            "Cannot import {}: this gm-cli was built without the `xlsx` feature",
+            path.display()
+        ),
+        Some("input".to_string()),
+        "solve",
+        vec![
+            "cargo install --path backend/cli --features xlsx".to_string(),
+            "save the sheet as CSV".to_string(),
+        ],
+    ))
+}
+
+/// A problem with the people (and, from a workbook, pair constraints) imported from the CSV
+/// or `.xlsx` file at `path`: `into` with its people replaced, or a scenario with no groups
+/// yet to fill in.
+///
+/// Imported constraints are added to `into`'s constraints, skipping exact duplicates. A scenario
+/// skeleton gets one session more than the highest session the import lists. Fails when one of
+/// `into`'s constraints names a person the import does not list, rather than dropping it.
+fn people_import_document(path: &Path, into: Option<&Path>) -> Result<serde_yaml::Value> {
+    let (people, constraints) = if is_workbook(path) {
+        import_people_workbook(path)?
+    } else {
+        let csv = read_input(Some(path.to_path_buf()), false, "solve")?;
+        let people = people_from_csv(&csv).map_err(|error| {
+            public_errors::invalid_input_error(
+                format!("Failed to import people CSV: {}", error.message),
+                Some(match &error.column {
+                    Some(column) => format!("line {}, column '{column}'", error.line),
+                    None => format!("line {}", error.line),
+                }),
+                "solve",
+                vec!["id,<attribute>,...,sessions".to_string()],
+            )
+        })?;
+        (people, Vec::new())
+    };
+
+    let constraints = constraints
+        .iter()
+        .map(serde_yaml::to_value)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let Some(problem) = into else {
+        let last_session = people
+            .iter()
+            .filter_map(|person| person.sessions.as_ref())
+            .flatten()
+            .copied()
+            .chain(constraints.iter().flat_map(constraint_sessions))
+            .max();
+        let scenario = ScenarioDefinitionContract {
+            people,
+            groups: Vec::new(),
+            num_sessions: last_session.map_or(1, |session| session + 1),
+        };
+        let mut document = serde_yaml::Mapping::new();
+        document.insert("scenario".into(), serde_yaml::to_value(scenario)?);
+        if !constraints.is_empty() {
+            document.insert("constraints".into(), constraints.into());
+        }
+        return Ok(document.into());
+    };
+    let mut document = read_document(problem)?;
+    let Some(scenario) = document
+        .get_mut("scenario")
+        .and_then(serde_yaml::Value::as_mapping_mut)
+    else {
+        return Err(public_errors::invalid_input_error(
+            "--into problem has no scenario object",
+            Some("scenario".to_string()),
+            "solve",
+            vec!["solve-request".to_string()],
+        ));
+    };
+    scenario.insert("people".into(), serde_yaml::to_value(&people)?);
+
+    let mut merged: Vec<serde_yaml::Value> = document
+        .get("constraints")
+        .and_then(serde_yaml::Value::as_sequence)
+        .cloned()
+        .unwrap_or_default();
+    let ids: HashSet<&str> = people.iter().map(|person| person.id.as_str()).collect();
+    let orphaned: Vec<String> = merged
+        .iter()
+        .enumerate()
+        .filter_map(|(index, constraint)| {
+            let missing: Vec<&str> = constraint_people(constraint)
+                .filter(|id| !ids.contains(id))
+                .collect();
+            (!missing.is_empty()).then(|| {
+                format!(
+                    "constraints[{index}] ({}) names {}",
+                    constraint
+                        .get("type")
+                        .and_then(serde_yaml::Value::as_str)
+                        .unwrap_or("unknown type"),
+                    missing.join(", ")
+                )
+            })
+        })
+        .collect();
+    if !orphaned.is_empty() {
+        return Err(public_errors::invalid_input_error(
+            format!(
+                "--into problem has constraints on people the import does not list: {}",
+                orphaned.join("; ")
+            ),
+            Some("constraints".to_string()),
+            "solve",
+            vec![
+                "add those people to the import".to_string(),
+                "remove those constraints from the --into problem".to_string(),
+            ],
+        ));
+    }
+
+    if !constraints.is_empty() {
+        for constraint in constraints {
+            if !merged.contains(&constraint) {
+                merged.push(constraint);
             }
-            println!();
         }
-        Ok(())
+        document
+            .as_mapping_mut()
+            .expect("a document with a scenario is a mapping")
+            .insert("constraints".into(), merged.into());
     }
+    Ok(document)
 }
 
-fn cmd_errors(error_code: Option<String>, json: bool) -> Result<()> {
-    let Some(error_code) = error_code else {
-        if json {
-            return print_json_pretty(&error_specs());
+/// Ids of the people a constraint document names in `people` or `person_id`.
+fn constraint_people(constraint: &serde_yaml::Value) -> impl Iterator<Item = &str> {
+    constraint
+        .get("people")
+        .and_then(serde_yaml::Value::as_sequence)
+        .into_iter()
+        .flatten()
+        .chain(constraint.get("person_id"))
+        .filter_map(serde_yaml::Value::as_str)
+}
+
+/// Session indices a constraint document lists in `sessions`.
+fn constraint_sessions(constraint: &serde_yaml::Value) -> impl Iterator<Item = u32> + '_ {
+    constraint
+        .get("sessions")
+        .and_then(serde_yaml::Value::as_sequence)
+        .into_iter()
+        .flatten()
+        .filter_map(serde_yaml::Value::as_u64)
+        .filter_map(|session| u32::try_from(session).ok())
+}
+
+/// A problem size in the `bench` suite.
+struct BenchCase {
+    name: &'static str,
+    people: u32,
+    groups: u32,
+    group_size: u32,
+    sessions: u32,
+}
+
+const BENCH_CASES: [BenchCase; 4] = [
+    BenchCase {
+        name: "small",
+        people: 12,
+        groups: 3,
+        group_size: 4,
+        sessions: 5,
+    },
+    BenchCase {
+        name: "medium",
+        people: 60,
+        groups: 10,
+        group_size: 6,
+        sessions: 8,
+    },
+    BenchCase {
+        name: "large",
+        people: 240,
+        groups: 24,
+        group_size: 10,
+        sessions: 10,
+    },
+    BenchCase {
+        name: "xlarge",
+        people: 600,
+        groups: 50,
+        group_size: 12,
+        sessions: 12,
+    },
+];
+
+/// Solver family `bench` times unless `--algorithm` picks another. `auto` would mostly
+/// time its constructor on the larger cases.
+const BENCH_DEFAULT_SOLVER: SolverKind = SolverKind::Solver3;
+
+/// Iteration limit high enough that the time limit ends every `bench` run.
+const BENCH_ITERATION_CAP: u64 = 1_000_000_000_000;
+
+/// Timings of one `bench` case.
+#[derive(Serialize)]
+struct BenchRow {
+    case: &'static str,
+    people: u32,
+    sessions: u32,
+    solver: String,
+    iterations: u64,
+    setup_seconds: f64,
+    iterations_per_second: f64,
+    /// Peak resident memory of the whole process so far, where the platform reports it
+    peak_rss_bytes: Option<u64>,
+}
+
+fn cmd_bench(seconds: u64, json: bool, overrides: &SolverOverrides) -> Result<()> {
+    let mut solver = default_solver_configuration_for(BENCH_DEFAULT_SOLVER);
+    solver.seed = Some(42);
+    solver.stop_conditions.max_iterations = Some(BENCH_ITERATION_CAP);
+    solver.stop_conditions.time_limit_seconds = Some(seconds.max(1));
+    solver.stop_conditions.no_improvement_iterations = None;
+    solver.stop_conditions.stop_on_optimal_score = false;
+    overrides.apply(&mut solver)?;
+
+    let mut rows = Vec::new();
+    for case in &BENCH_CASES {
+        if !json {
+            eprintln!("Running {} ({} people)...", case.name, case.people);
         }
-        println!("Known public error codes:");
-        for spec in error_specs() {
-            println!("- {}: {}", spec.code, spec.summary);
+        rows.push(run_bench_case(case, &solver)?);
+    }
+    if json {
+        return print_json_pretty(&rows);
+    }
+    print!("{}", render_bench_rows(&rows));
+    Ok(())
+}
+
+fn run_bench_case(case: &BenchCase, solver: &SolverConfiguration) -> Result<BenchRow> {
+    let mut builder = ApiInput::builder()
+        .people((0..case.people).map(|index| format!("p{index}")))
+        .sessions(case.sessions);
+    for index in 0..case.groups {
+        builder = builder.group(format!("g{index}"), case.group_size);
+    }
+    let mut input = builder.build();
+    input.solver = solver.clone();
+
+    let telemetry = std::sync::Arc::new(std::sync::Mutex::new(None));
+    let observed = std::sync::Arc::clone(&telemetry);
+    let observer: BenchmarkObserver = Box::new(move |event| {
+        if let BenchmarkEvent::RunCompleted(run) = event {
+            *observed.lock().expect("bench telemetry lock") = Some(run.clone());
         }
-        return Ok(());
+    });
+    let started = std::time::Instant::now();
+    let result = run_solver_with_benchmark_observer(&input, Some(&observer))
+        .map_err(|error| public_errors::map_solver_error(error.message(), "solve"))?;
+    let elapsed = started.elapsed().as_secs_f64();
+
+    let telemetry = telemetry.lock().expect("bench telemetry lock").take();
+    let iterations = telemetry.as_ref().map_or_else(
+        || result.iterations_run.unwrap_or(0),
+        |run| run.iterations_completed,
+    );
+    let (setup_seconds, iterations_per_second) = match &telemetry {
+        // Wall time outside the search loop, so building the solver state counts as setup.
+        Some(run) => (
+            (elapsed - run.search_seconds - run.finalization_seconds).max(0.0),
+            run.iterations_per_second,
+        ),
+        None => (0.0, iterations as f64 / elapsed.max(f64::EPSILON)),
+    };
+    Ok(BenchRow {
+        case: case.name,
+        people: case.people,
+        sessions: case.sessions,
+        solver: solver
+            .solver_params
+            .solver_kind()
+            .canonical_id()
+            .to_string(),
+        iterations,
+        setup_seconds,
+        iterations_per_second,
+        peak_rss_bytes: peak_rss_bytes(),
+    })
+}
+
+/// Peak resident set size of this process, from `/proc/self/status` on Linux.
+fn peak_rss_bytes() -> Option<u64> {
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+    let kilobytes = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))?
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    Some(kilobytes * 1024)
+}
+
+fn render_bench_rows(rows: &[BenchRow]) -> String {
+    let mut out = format!(
+        "{:<8} {:>7} {:>8} {:<8} {:>12} {:>10} {:>12} {:>13}\n",
+        "CASE",
+        "PEOPLE",
+        "SESSIONS",
+        "SOLVER",
+        "ITERATIONS",
+        "SETUP (ms)",
+        "ITER/S",
+        "PEAK RSS (MB)"
+    );
+    for row in rows {
+        let peak_rss = row.peak_rss_bytes.map_or_else(
+            || "n/a".to_string(),
+            |bytes| format!("{:.1}", bytes as f64 / (1024.0 * 1024.0)),
+        );
+        out.push_str(&format!(
+            "{:<8} {:>7} {:>8} {:<8} {:>12} {:>10.1} {:>12.0} {:>13}\n",
+            row.case,
+            row.people,
+            row.sessions,
+            row.solver,
+            row.iterations,
+            row.setup_seconds * 1000.0,
+            row.iterations_per_second,
+            peak_rss
+        ));
+    }
+    out
+}
+
+fn cmd_tune(
+    input: &Path,
+    budget: TuningBudget,
+    seed: Option<u64>,
+    output: Option<PathBuf>,
+    pretty: bool,
+) -> Result<()> {
+    let input_str = read_input(Some(input.to_path_buf()), false, "solve")?;
+    let solve_request: SolveRequest = parse_document(
+        &input_str,
+        InputFormat::detect(Some(input), false),
+        "input",
+        "solve",
+        "solve-request",
+    )?;
+    let api_input: ApiInput = solve_request.into();
+
+    let mut tune_input = api_input.clone();
+    tune_input.solver.seed = seed.or(api_input.solver.seed);
+    let mut configuration = auto_tune(&tune_input, budget)
+        .map_err(|error| public_errors::map_solver_error(error.message(), "solve"))?;
+    configuration.seed = api_input.solver.seed;
+    eprintln!(
+        "Raced {} parameter sets from {} iterations each",
+        budget.candidates.max(1),
+        budget.initial_iterations.max(1)
+    );
+
+    let output_text = if pretty {
+        serde_json::to_string_pretty(&configuration)?
+    } else {
+        serde_json::to_string(&configuration)?
+    };
+    match output {
+        Some(output_path) => {
+            fs::write(&output_path, output_text + "\n").with_context(|| {
+                format!("failed to write configuration {}", output_path.display())
+            })?;
+            eprintln!("Configuration written to {:?}", output_path);
+        }
+        None => println!("{output_text}"),
+    }
+    Ok(())
+}
+
+/// `validate` exit status when the input has no validation errors (warnings are allowed).
+const VALIDATE_EXIT_VALID: i32 = 0;
+/// `validate` exit status when the input cannot be read or parsed.
+const VALIDATE_EXIT_PARSE_ERROR: i32 = 2;
+/// `validate` exit status when the input parses but solving it would fail.
+const VALIDATE_EXIT_INVALID: i32 = 3;
+
+/// Prints the input's `ValidationReport` and returns the exit status for it. Read and
+/// parse errors are printed to stderr instead of failing the command.
+fn cmd_validate(input: Option<PathBuf>, stdin: bool) -> Result<i32> {
+    let format = InputFormat::detect(input.as_deref(), stdin);
+    let parsed = read_input(input, stdin, "validate-scenario").and_then(|input_str| {
+        parse_document::<ValidateRequest>(
+            &input_str,
+            format,
+            "input",
+            "validate-scenario",
+            "validate-request",
+        )
+    });
+    let validate_request = match parsed {
+        Ok(validate_request) => validate_request,
+        Err(error) => {
+            eprintln!("{error}");
+            return Ok(VALIDATE_EXIT_PARSE_ERROR);
+        }
+    };
+
+    let report = validate(&validate_request.into());
+    print_json_pretty(&report)?;
+    Ok(if report.is_valid() {
+        VALIDATE_EXIT_VALID
+    } else {
+        VALIDATE_EXIT_INVALID
+    })
+}
+
+fn cmd_default_config(pretty: bool) -> Result<()> {
+    let configuration = default_solver_configuration();
+    let output_json = if pretty {
+        serde_json::to_string_pretty(&configuration)?
+    } else {
+        serde_json::to_string(&configuration)?
+    };
+
+    println!("{}", output_json);
+    Ok(())
+}
+
+fn cmd_recommend(input: Option<PathBuf>, stdin: bool, pretty: bool, report: bool) -> Result<()> {
+    let format = InputFormat::detect(input.as_deref(), stdin);
+    let input_str = read_input(input, stdin, "recommend-settings")?;
+    let recommendation_input = parse_recommend_input(&input_str, format)?;
+
+    eprintln!(
+        "Calculating recommended settings for {}s runtime...",
+        recommendation_input.desired_runtime_seconds
+    );
+
+    let scenario_definition: gm_core::models::ProblemDefinition =
+        (&recommendation_input.scenario).into();
+    if report {
+        let solver_kind = match &recommendation_input.solver {
+            Some(solver) => solver.validate_solver_selection().map_err(|error| {
+                public_errors::invalid_input_error(
+                    error,
+                    Some("solver.solver_type".to_string()),
+                    "recommend-settings",
+                    vec![],
+                )
+            })?,
+            None => default_solver_kind(),
+        };
+        let report = calculate_recommendation_report_for(
+            solver_kind,
+            &scenario_definition,
+            &recommendation_input.objectives,
+            &recommendation_input.constraints,
+            recommendation_input.desired_runtime_seconds,
+        )
+        .map_err(|error| public_errors::map_solver_error(error.message(), "recommend-settings"))?;
+        return if pretty {
+            print_json_pretty(&report)
+        } else {
+            println!("{}", serde_json::to_string(&report)?);
+            Ok(())
+        };
+    }
+
+    let recommended = calculate_recommended_settings(
+        &scenario_definition,
+        &recommendation_input.objectives,
+        &recommendation_input.constraints,
+        recommendation_input.desired_runtime_seconds,
+    )
+    .map_err(|error| public_errors::map_solver_error(error.message(), "recommend-settings"))?;
+
+    let output_json = if pretty {
+        serde_json::to_string_pretty(&recommended)?
+    } else {
+        serde_json::to_string(&recommended)?
     };
 
-    let spec = error_spec(&error_code).ok_or_else(|| {
-        public_errors::unknown_error_code_error(
-            &error_code,
-            error_specs()
+    println!("{}", output_json);
+    Ok(())
+}
+
+/// A `--schedule` file for `evaluate`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ScheduleDocument {
+    /// A document holding the schedule, such as a solve result
+    Wrapped {
+        schedule: ApiSchedule,
+    },
+    Bare(ApiSchedule),
+}
+
+fn cmd_evaluate(
+    input: Option<PathBuf>,
+    stdin: bool,
+    schedule: Option<&Path>,
+    pretty: bool,
+) -> Result<()> {
+    let format = InputFormat::detect(input.as_deref(), stdin);
+    let input_str = read_input(input, stdin, "evaluate-input")?;
+    let solve_request: SolveRequest = parse_document(
+        &input_str,
+        format,
+        "input",
+        "evaluate-input",
+        "solve-request",
+    )?;
+    let mut api_input: ApiInput = solve_request.into();
+
+    if let Some(schedule_path) = schedule {
+        let schedule_str = read_input(Some(schedule_path.to_path_buf()), false, "evaluate-input")?;
+        let document: ScheduleDocument = parse_document(
+            &schedule_str,
+            InputFormat::detect(Some(schedule_path), false),
+            "schedule",
+            "evaluate-input",
+            "solve-response",
+        )?;
+        api_input.initial_schedule = Some(match document {
+            ScheduleDocument::Wrapped { schedule } | ScheduleDocument::Bare(schedule) => schedule,
+        });
+    }
+
+    if api_input.construction_seed_schedule.is_some() {
+        return Err(public_errors::invalid_input_error(
+            "Evaluate does not accept construction_seed_schedule; provide a complete initial_schedule instead",
+            Some("construction_seed_schedule".to_string()),
+            "evaluate-input",
+            vec!["remove construction_seed_schedule".to_string(), "provide initial_schedule".to_string()],
+        ));
+    }
+
+    if api_input.initial_schedule.is_none() {
+        return Err(public_errors::invalid_input_error(
+            "Evaluate requires initial_schedule in the input or a --schedule file",
+            Some("initial_schedule".to_string()),
+            "evaluate-input",
+            vec![
+                "provide initial_schedule".to_string(),
+                "--schedule <FILE>".to_string(),
+            ],
+        ));
+    }
+
+    let mut eval_input = api_input.clone();
+    eval_input.solver.stop_conditions.max_iterations = Some(0);
+
+    let result = run_solver(&eval_input)
+        .map_err(|error| public_errors::map_solver_error(error.message(), "evaluate-input"))?;
+
+    let output_json = if pretty {
+        serde_json::to_string_pretty(&result)?
+    } else {
+        serde_json::to_string(&result)?
+    };
+
+    println!("{}", output_json);
+    Ok(())
+}
+
+fn cmd_inspect_result(input: Option<PathBuf>, stdin: bool, pretty: bool) -> Result<()> {
+    let format = InputFormat::detect(input.as_deref(), stdin);
+    let input_str = read_input(input, stdin, "inspect-result")?;
+    let result: SolverResult = parse_document(
+        &input_str,
+        format,
+        "result",
+        "inspect-result",
+        "solve-response",
+    )?;
+
+    let summary = ResultSummary::from(&result);
+    if pretty {
+        print_json_pretty(&summary)?;
+    } else {
+        println!("{}", serde_json::to_string(&summary)?);
+    }
+    Ok(())
+}
+
+/// Reads a solver result document from `path`.
+fn read_result_file(path: &Path) -> Result<SolverResult> {
+    let input_str = read_input(Some(path.to_path_buf()), false, "inspect-result")?;
+    parse_document(
+        &input_str,
+        InputFormat::detect(Some(path), false),
+        "result",
+        "inspect-result",
+        "solve-response",
+    )
+}
+
+/// Differences between two results, as printed by `compare`.
+#[derive(Serialize)]
+struct ResultComparison {
+    scores: Vec<ValueChange>,
+    /// Weighted penalty per constraint type, or `None` when a result lacks its violation list
+    constraint_penalties: Option<Vec<ValueChange>>,
+    moves: ScheduleDiff,
+}
+
+#[derive(Serialize)]
+struct ValueChange {
+    name: String,
+    a: f64,
+    b: f64,
+    delta: f64,
+}
+
+impl ValueChange {
+    fn new(name: impl Into<String>, a: f64, b: f64) -> Self {
+        Self {
+            name: name.into(),
+            a,
+            b,
+            delta: b - a,
+        }
+    }
+}
+
+fn compare_results(a: &SolverResult, b: &SolverResult) -> ResultComparison {
+    let scores = |result: &SolverResult| {
+        [
+            ("final_score", result.final_score),
+            ("unique_contacts", f64::from(result.unique_contacts)),
+            ("repetition_penalty", f64::from(result.repetition_penalty)),
+            (
+                "attribute_balance_penalty",
+                f64::from(result.attribute_balance_penalty),
+            ),
+            ("constraint_penalty", f64::from(result.constraint_penalty)),
+            (
+                "weighted_repetition_penalty",
+                result.weighted_repetition_penalty,
+            ),
+            (
+                "weighted_constraint_penalty",
+                result.weighted_constraint_penalty,
+            ),
+        ]
+    };
+    let scores = scores(a)
+        .into_iter()
+        .zip(scores(b))
+        .map(|((name, a), (_, b))| ValueChange::new(name, a, b))
+        .collect();
+
+    let constraint_penalties = match (&a.constraint_violations, &b.constraint_violations) {
+        (Some(a), Some(b)) => {
+            let mut by_type: std::collections::BTreeMap<&str, (f64, f64)> = Default::default();
+            for violation in a {
+                by_type.entry(&violation.constraint_type).or_default().0 +=
+                    violation.weighted_penalty;
+            }
+            for violation in b {
+                by_type.entry(&violation.constraint_type).or_default().1 +=
+                    violation.weighted_penalty;
+            }
+            Some(
+                by_type
+                    .into_iter()
+                    .map(|(name, (a, b))| ValueChange::new(name, a, b))
+                    .collect(),
+            )
+        }
+        _ => None,
+    };
+
+    ResultComparison {
+        scores,
+        constraint_penalties,
+        moves: diff_schedules(&a.schedule, &b.schedule),
+    }
+}
+
+fn render_result_comparison(comparison: &ResultComparison) -> String {
+    fn push_changes(out: &mut String, changes: &[ValueChange]) {
+        let width = changes
+            .iter()
+            .map(|change| change.name.len())
+            .max()
+            .unwrap_or(0);
+        for change in changes {
+            out.push_str(&format!(
+                "  {:<width$}  {:>12.2} -> {:>12.2}  ({:+.2})\n",
+                change.name, change.a, change.b, change.delta
+            ));
+        }
+    }
+
+    let mut out = String::from("SCORES (a -> b):\n");
+    push_changes(&mut out, &comparison.scores);
+    out.push_str("\nCONSTRAINT PENALTIES (weighted, a -> b):\n");
+    match &comparison.constraint_penalties {
+        Some(changes) if changes.is_empty() => out.push_str("  none in either result\n"),
+        Some(changes) => push_changes(&mut out, changes),
+        None => out.push_str("  unavailable: a result has no constraint_violations\n"),
+    }
+
+    let moves = &comparison.moves;
+    out.push_str(&format!(
+        "\nMOVES: {} assignments differ ({:.1}% churn)\n",
+        moves.total_moves,
+        moves.churn_rate * 100.0
+    ));
+    for session in moves
+        .sessions
+        .iter()
+        .filter(|session| !session.moves.is_empty())
+    {
+        out.push_str(&format!("  {}:\n", session.session));
+        for person_move in &session.moves {
+            out.push_str(&format!(
+                "    {}: {} -> {}\n",
+                person_move.person_id,
+                person_move.from_group.as_deref().unwrap_or("(unassigned)"),
+                person_move.to_group.as_deref().unwrap_or("(unassigned)")
+            ));
+        }
+    }
+    out
+}
+
+fn cmd_compare(a: &Path, b: &Path, json: bool) -> Result<()> {
+    let comparison = compare_results(&read_result_file(a)?, &read_result_file(b)?);
+    if json {
+        return print_json_pretty(&comparison);
+    }
+    print!("{}", render_result_comparison(&comparison));
+    Ok(())
+}
+
+fn cmd_report(result: &Path, format: ReportFormatArg, output: Option<PathBuf>) -> Result<()> {
+    let report = read_result_file(result)?.to_report(format.into());
+    match output {
+        Some(output_path) => {
+            fs::write(&output_path, report)
+                .with_context(|| format!("failed to write report {}", output_path.display()))?;
+            eprintln!("Report written to {:?}", output_path);
+        }
+        None => print!("{report}"),
+    }
+    Ok(())
+}
+
+fn cmd_matrix(result: &Path, problem: Option<&Path>, format: MatrixFormat) -> Result<()> {
+    let result = read_result_file(result)?;
+    let people = match problem {
+        Some(path) => read_problem_file(path)?.problem.people,
+        None => scheduled_people(&result),
+    };
+    let never_met = render_never_met(&result, &people);
+    match format {
+        MatrixFormat::Text => print!("{}\n{never_met}", result.to_contact_matrix_text(&people)),
+        MatrixFormat::Csv => {
+            print!("{}", result.to_contact_matrix_csv(&people));
+            eprint!("{never_met}");
+        }
+    }
+    Ok(())
+}
+
+/// Reads the `--problem` a result was solved from.
+fn read_problem_file(path: &Path) -> Result<ApiInput> {
+    let input_str = read_input(Some(path.to_path_buf()), false, "solve")?;
+    let solve_request: SolveRequest = parse_document(
+        &input_str,
+        InputFormat::detect(Some(path), false),
+        "problem",
+        "solve",
+        "solve-request",
+    )?;
+    Ok(solve_request.into())
+}
+
+fn cmd_explain(result: &Path, problem: Option<&Path>) -> Result<()> {
+    let result = read_result_file(result)?;
+    let input = problem.map(read_problem_file).transpose()?;
+    let explanation = result.explain_violations(input.as_ref()).ok_or_else(|| {
+        public_errors::invalid_input_error(
+            "The result has no constraint_violations to explain",
+            Some("constraint_violations".to_string()),
+            "inspect-result",
+            vec!["gm-cli evaluate <INPUT> to rescore the schedule".to_string()],
+        )
+    })?;
+    print!("{explanation}");
+    Ok(())
+}
+
+fn cmd_people(result: &Path, problem: Option<&Path>) -> Result<()> {
+    let result = read_result_file(result)?;
+    let input = problem.map(read_problem_file).transpose()?;
+    let people = match &input {
+        Some(input) => input.problem.people.clone(),
+        None => scheduled_people(&result),
+    };
+    print!("{}", result.to_people_text(&people, input.as_ref()));
+    Ok(())
+}
+
+/// Everyone assigned somewhere in `result`, sorted by id, for when no problem is given.
+fn scheduled_people(result: &SolverResult) -> Vec<Person> {
+    let ids: BTreeSet<&String> = result
+        .schedule
+        .values()
+        .flat_map(|groups| groups.values().flatten())
+        .collect();
+    ids.into_iter()
+        .map(|id| Person {
+            id: id.clone(),
+            attributes: HashMap::new(),
+            sessions: None,
+        })
+        .collect()
+}
+
+fn render_never_met(result: &SolverResult, people: &[Person]) -> String {
+    let matrix = result.contact_matrix(people);
+    let pairs: Vec<(&str, &str)> = (0..people.len())
+        .flat_map(|a| (a + 1..people.len()).map(move |b| (a, b)))
+        .filter(|&(a, b)| matrix[a][b] == 0)
+        .map(|(a, b)| (people[a].id.as_str(), people[b].id.as_str()))
+        .collect();
+    let mut out = format!("PAIRS WHO NEVER MET ({}):\n", pairs.len());
+    if pairs.is_empty() {
+        out.push_str("  none\n");
+    }
+    for (a, b) in pairs {
+        out.push_str(&format!("  {a} - {b}\n"));
+    }
+    out
+}
+
+fn cmd_schema(schema_id: Option<String>, json: bool) -> Result<()> {
+    let Some(schema_id) = schema_id.map(resolve_schema_alias) else {
+        #[derive(Serialize)]
+        struct SchemaListEntry<'a> {
+            id: &'a str,
+            version: &'a str,
+        }
+
+        let entries: Vec<_> = schema_specs()
+            .iter()
+            .map(|spec| SchemaListEntry {
+                id: spec.id,
+                version: spec.version,
+            })
+            .collect();
+
+        if json {
+            print_json_pretty(&entries)?;
+        } else {
+            println!("Known schema ids:");
+            for entry in entries {
+                println!("- {} ({})", entry.id, entry.version);
+            }
+        }
+        return Ok(());
+    };
+
+    let schema = export_schema(&schema_id).ok_or_else(|| {
+        public_errors::unknown_schema_error(
+            &schema_id,
+            known_schema_ids().into_iter().map(str::to_string).collect(),
+        )
+    })?;
+
+    if json {
+        print_json_pretty(&schema)?;
+    } else {
+        println!("schema: {}", schema_id);
+        println!();
+        println!("{}", serde_json::to_string_pretty(&schema)?);
+    }
+
+    Ok(())
+}
+
+fn cmd_capabilities(json: bool) -> Result<()> {
+    #[derive(Serialize)]
+    struct CapabilityEntry<'a> {
+        command_name: &'a str,
+        operation_id: Option<&'a str>,
+        summary: &'a str,
+        kind: Option<String>,
+        input_schema_ids: Vec<&'a str>,
+        output_schema_ids: Vec<&'a str>,
+        related_operation_ids: Vec<&'a str>,
+    }
+
+    #[derive(Serialize)]
+    struct CapabilityPayload<'a> {
+        title: &'a str,
+        summary: &'a str,
+        discovery_note: &'a str,
+        operations: Vec<CapabilityEntry<'a>>,
+    }
+
+    let bootstrap = bootstrap_spec();
+    let operations = crate::contract_surface::public_cli_contract_bindings()
+        .map(|binding| {
+            if let Some(operation_id) = binding.operation_id {
+                let operation = operation_spec(operation_id).expect("bound operation");
+                CapabilityEntry {
+                    command_name: binding.command_name,
+                    operation_id: Some(operation.id),
+                    summary: operation.summary,
+                    kind: Some(format!("{:?}", operation.kind).to_ascii_lowercase()),
+                    input_schema_ids: operation.input_schema_ids.to_vec(),
+                    output_schema_ids: operation.output_schema_ids.to_vec(),
+                    related_operation_ids: operation.related_operation_ids.to_vec(),
+                }
+            } else {
+                CapabilityEntry {
+                    command_name: binding.command_name,
+                    operation_id: None,
+                    summary: binding.note,
+                    kind: None,
+                    input_schema_ids: Vec::new(),
+                    output_schema_ids: Vec::new(),
+                    related_operation_ids: bootstrap.top_level_operation_ids.to_vec(),
+                }
+            }
+        })
+        .collect();
+
+    let payload = CapabilityPayload {
+        title: bootstrap.title,
+        summary: bootstrap.summary,
+        discovery_note: bootstrap.discovery_note,
+        operations,
+    };
+
+    if json {
+        print_json_pretty(&payload)
+    } else {
+        println!("{}", payload.title);
+        println!();
+        println!("{}", payload.summary);
+        println!();
+        for operation in payload.operations {
+            println!("- {}", operation.command_name);
+            if let Some(operation_id) = operation.operation_id {
+                println!("  operation_id: {}", operation_id);
+            }
+            println!("  summary: {}", operation.summary);
+            if !operation.input_schema_ids.is_empty() {
+                println!("  input schemas: {}", operation.input_schema_ids.join(", "));
+            }
+            if !operation.output_schema_ids.is_empty() {
+                println!(
+                    "  output schemas: {}",
+                    operation.output_schema_ids.join(", ")
+                );
+            }
+            if !operation.related_operation_ids.is_empty() {
+                println!("  related: {}", operation.related_operation_ids.join(", "));
+            }
+            println!();
+        }
+        Ok(())
+    }
+}
+
+fn cmd_errors(error_code: Option<String>, json: bool) -> Result<()> {
+    let Some(error_code) = error_code else {
+        if json {
+            return print_json_pretty(&error_specs());
+        }
+        println!("Known public error codes:");
+        for spec in error_specs() {
+            println!("- {}: {}", spec.code, spec.summary);
+        }
+        return Ok(());
+    };
+
+    let spec = error_spec(&error_code).ok_or_else(|| {
+        public_errors::unknown_error_code_error(
+            &error_code,
+            error_specs()
+                .iter()
+                .map(|spec| spec.code.to_string())
+                .collect(),
+        )
+    })?;
+    if json {
+        print_json_pretty(spec)
+    } else {
+        println!("error: {}", spec.code);
+        println!("category: {:?}", spec.category);
+        println!("summary: {}", spec.summary);
+        println!("why: {}", spec.why);
+        println!("recovery: {}", spec.recovery);
+        if !spec.related_help_operation_ids.is_empty() {
+            println!(
+                "related help: {}",
+                spec.related_help_operation_ids.join(", ")
+            );
+        }
+        Ok(())
+    }
+}
+
+fn resolve_schema_alias(schema_id: String) -> String {
+    match schema_id.as_str() {
+        "input" => "solve-request".to_string(),
+        "output" => "solve-response".to_string(),
+        "scenario" => "scenario-definition".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn parse_recommend_input(input_str: &str, format: InputFormat) -> Result<RecommendSettingsRequest> {
+    parse_document(
+        input_str,
+        format,
+        "recommend input as recommend-settings-request",
+        "recommend-settings",
+        "recommend-settings-request",
+    )
+}
+
+fn known_schema_ids() -> Vec<&'static str> {
+    schema_specs().iter().map(|spec| spec.id).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gm_contracts::types::PublicErrorEnvelope;
+    use gm_core::models::{Constraint, ConstraintViolation, LoggingOptions};
+    use tempfile::TempDir;
+
+    fn write_sample_run_report(temp: &TempDir) -> PathBuf {
+        let run_path = temp.path().join("run-report.json");
+        fs::write(
+            &run_path,
+            r#"{
+  "schema_version": 1,
+  "suite": {
+    "suite_id": "social-golfer-plateau-time-solver3",
+    "benchmark_mode": "full_solve",
+    "comparison_category": "score_quality",
+    "solver_families": ["solver3"],
+    "class": "stretch",
+    "manifest_path": "backend/benchmarking/suites/social-golfer-plateau-time-solver3.yaml"
+  },
+  "run": {
+    "run_id": "sample-run",
+    "generated_at": "2026-04-13T00:00:00Z",
+    "git": {},
+    "machine": {}
+  },
+  "totals": {
+    "total_cases": 1,
+    "successful_cases": 1,
+    "failed_cases": 0,
+    "total_runtime_seconds": 10.0
+  },
+  "class_rollups": [],
+  "cases": [
+    {
+      "schema_version": 1,
+      "run_id": "sample-run",
+      "generated_at": "2026-04-13T00:00:00Z",
+      "suite_id": "social-golfer-plateau-time-solver3",
+      "benchmark_mode": "full_solve",
+      "suite_class": "stretch",
+      "case_id": "stretch.social-golfer-32x8x10",
+      "case_class": "stretch",
+      "case_manifest_path": "backend/benchmarking/cases/stretch/social_golfer_32x8x10.json",
+      "git": {},
+      "machine": {},
+      "solver": {
+        "solver_family": "solver3",
+        "solver_config_id": "solver3",
+        "display_name": "Solver 3",
+        "seed_policy": "explicit",
+        "capabilities": {
+          "supports_initial_schedule": true,
+          "supports_progress_callback": true,
+          "supports_benchmark_observer": true,
+          "supports_recommended_settings": true,
+          "supports_deterministic_seed": true
+        }
+      },
+      "effective_budget": {
+        "max_iterations": 10000000,
+        "time_limit_seconds": 25,
+        "no_improvement_iterations": null
+      },
+      "artifact_kind": "full_solve",
+      "status": "success",
+      "timing": {
+        "initialization_seconds": 0.0,
+        "search_seconds": 10.0,
+        "finalization_seconds": 0.0,
+        "total_seconds": 10.0
+      },
+      "runtime_seconds": 10.0,
+      "moves": {
+        "swap": {},
+        "transfer": {},
+        "clique_swap": {}
+      },
+      "search_telemetry": {
+        "accepted_uphill_moves": 1,
+        "accepted_downhill_moves": 2,
+        "accepted_neutral_moves": 3,
+        "max_no_improvement_streak": 50,
+        "restart_count": null,
+        "perturbation_count": null,
+        "iterations_per_second": 100.0,
+        "best_score_timeline": [
+          { "iteration": 0, "elapsed_seconds": 0.0, "best_score": 100.0 },
+          { "iteration": 10, "elapsed_seconds": 1.0, "best_score": 80.0 },
+          { "iteration": 100, "elapsed_seconds": 5.0, "best_score": 60.0 },
+          { "iteration": 150, "elapsed_seconds": 7.0, "best_score": 50.0 }
+        ]
+      }
+    }
+  ]
+}
+"#,
+        )
+        .expect("write sample run report");
+        run_path
+    }
+
+    #[test]
+    fn suite_manifest_defaults_to_builtin_path_manifest() {
+        let path = resolve_suite_manifest_path(&BenchmarkSuiteArg::Path, None);
+        assert_eq!(path, PathBuf::from("backend/benchmarking/suites/path.yaml"));
+    }
+
+    #[test]
+    fn baseline_list_works_against_storage_layout() {
+        let temp = TempDir::new().expect("temp dir");
+        let storage = BenchmarkStorage::new(temp.path());
+        storage.ensure_layout().expect("layout");
+        let path = storage.baseline_snapshot_path("benchbox", "path", "baseline-a");
+        fs::create_dir_all(path.parent().unwrap()).expect("mk parent");
+        fs::write(&path, "{}\n").expect("write baseline");
+
+        let baselines = storage
+            .list_baselines(Some("benchbox"), Some("path"))
+            .expect("list baselines");
+        assert_eq!(baselines.len(), 1);
+        assert_eq!(baselines[0].baseline_name, "baseline-a");
+    }
+
+    #[test]
+    fn schema_command_unknown_id_uses_canonical_error_output() {
+        let error = cmd_schema(Some("does-not-exist".to_string()), false)
+            .expect_err("unknown schema should fail")
+            .to_string();
+        assert!(error.contains("error[unknown-schema]"));
+        assert!(error.contains("gm-cli schema --help"));
+    }
+
+    #[test]
+    fn errors_command_unknown_code_uses_canonical_error_output() {
+        let error = cmd_errors(Some("does-not-exist".to_string()), false)
+            .expect_err("unknown error code should fail")
+            .to_string();
+        assert!(error.contains("error[unknown-error-code]"));
+        assert!(error.contains("gm-cli errors --help"));
+    }
+
+    #[test]
+    fn evaluate_without_initial_schedule_uses_canonical_error_output() {
+        let temp = TempDir::new().expect("temp dir");
+        let input_path = temp.path().join("input.json");
+        fs::write(
+            &input_path,
+            r#"{
+  "scenario": {"people": [], "groups": [], "num_sessions": 1},
+  "initial_schedule": null,
+  "objectives": [],
+  "constraints": [],
+  "solver": {
+    "solver_type": "SimulatedAnnealing",
+    "stop_conditions": {"max_iterations": 1, "time_limit_seconds": null, "no_improvement_iterations": null},
+    "solver_params": {"solver_type": "SimulatedAnnealing", "initial_temperature": 1.0, "final_temperature": 0.1, "cooling_schedule": "geometric", "reheat_cycles": 0, "reheat_after_no_improvement": 0},
+    "logging": {},
+    "telemetry": {},
+    "seed": null,
+    "move_policy": null,
+    "allowed_sessions": null
+  }
+}"#,
+        )
+        .expect("write input");
+
+        let error = cmd_evaluate(Some(input_path), false, None, false)
+            .expect_err("evaluate should fail")
+            .to_string();
+        assert!(error.contains("error[invalid-input]"));
+        assert!(error.contains("initial_schedule"));
+        assert!(error.contains("gm-cli evaluate --help"));
+    }
+
+    #[test]
+    fn input_format_follows_the_file_extension() {
+        let detect = |path: &str| InputFormat::detect(Some(Path::new(path)), false);
+
+        assert_eq!(detect("input.json"), InputFormat::Json);
+        assert_eq!(detect("input.yaml"), InputFormat::Yaml);
+        assert_eq!(detect("input.YML"), InputFormat::Yaml);
+        assert_eq!(detect("input.toml"), InputFormat::Toml);
+        assert_eq!(detect("input"), InputFormat::Json);
+        assert_eq!(
+            InputFormat::detect(Some(Path::new("input.yaml")), true),
+            InputFormat::Json
+        );
+    }
+
+    #[test]
+    fn yaml_input_is_parsed_with_comments() {
+        let temp = TempDir::new().expect("temp dir");
+        let input_path = temp.path().join("input.yaml");
+        fs::write(
+            &input_path,
+            r#"# One session, no schedule yet
+scenario:
+  people: []
+  groups: []
+  num_sessions: 1
+initial_schedule: null
+"#,
+        )
+        .expect("write input");
+
+        let error = cmd_evaluate(Some(input_path), false, None, false)
+            .expect_err("evaluate should fail")
+            .to_string();
+        assert!(error.contains("Evaluate requires initial_schedule"));
+    }
+
+    #[test]
+    fn yaml_parse_errors_report_their_location() {
+        let error = parse_document::<SolveRequest>(
+            "scenario:\n  people: []\n  groups: oops\n",
+            InputFormat::Yaml,
+            "input",
+            "solve",
+            "solve-request",
+        )
+        .expect_err("parse should fail")
+        .to_string();
+
+        assert!(error.contains("Failed to parse input YAML"));
+        assert!(error.contains("line 3"));
+    }
+
+    #[test]
+    fn toml_input_converts_to_yaml() {
+        let temp = TempDir::new().expect("temp dir");
+        let input_path = temp.path().join("problem.toml");
+        let output_path = temp.path().join("problem.yaml");
+        fs::write(
+            &input_path,
+            r#"# Two people, one table
+[scenario]
+num_sessions = 1
+people = [{ id = "alice", attributes = {} }, { id = "bob", attributes = {} }]
+groups = [{ id = "g1", size = 2 }]
+"#,
+        )
+        .expect("write input");
+
+        cmd_convert(&input_path, false, None, &output_path).expect("convert should succeed");
+        let converted: SolveRequest =
+            serde_yaml::from_str(&fs::read_to_string(&output_path).expect("read output"))
+                .expect("output is a solve request");
+        assert_eq!(converted.scenario.people.len(), 2);
+
+        let error = cmd_convert(&output_path, false, None, &input_path)
+            .expect_err("TOML output is not supported")
+            .to_string();
+        assert!(error.contains("TOML is only supported as input"));
+    }
+
+    #[test]
+    fn toml_parse_errors_report_their_location() {
+        let error = parse_document::<SolveRequest>(
+            "[scenario]\npeople = ]\n",
+            InputFormat::Toml,
+            "input",
+            "solve",
+            "solve-request",
+        )
+        .expect_err("parse should fail")
+        .to_string();
+
+        assert!(error.contains("Failed to parse input TOML"));
+        assert!(error.contains("line 2, column 10"));
+    }
+
+    #[test]
+    fn solve_writes_csv_with_every_attribute_by_default() {
+        let temp = TempDir::new().expect("temp dir");
+        let input_path = temp.path().join("input.yaml");
+        let output_path = temp.path().join("schedule.csv");
+        fs::write(
+            &input_path,
+            r#"scenario:
+  people:
+    - {id: alice, attributes: {team: red, badge: A1}}
+    - {id: bob, attributes: {team: blue}}
+  groups: [{id: g1, size: 2}]
+  num_sessions: 2
+solver: {solver_type: solver1, stop_conditions: {max_iterations: 10}}
+"#,
+        )
+        .expect("write input");
+
+        cmd_solve(
+            Some(input_path),
+            false,
+            &SolveOutputArgs {
+                output: Some(output_path.clone()),
+                output_format: OutputFormat::Csv,
+                ..SolveOutputArgs::default()
+            },
+            &SolverOverrides::default(),
+            &SessionScopeArgs::default(),
+            &WarmStartArgs::default(),
+            ProgressReport::Off,
+            &CheckpointArgs::default(),
+        )
+        .expect("solve should succeed");
+
+        let csv = fs::read_to_string(output_path).expect("read output");
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "session,group,person,badge,team");
+        assert_eq!(lines.len(), 5);
+        assert!(lines.contains(&"1,g1,alice,A1,red"));
+        assert!(lines.contains(&"1,g1,bob,,blue"));
+    }
+
+    #[test]
+    fn solve_streams_progress_updates_to_a_telemetry_jsonl_file() {
+        let temp = TempDir::new().expect("temp dir");
+        let input_path = temp.path().join("input.yaml");
+        let telemetry_path = temp.path().join("telemetry.jsonl");
+        fs::write(
+            &input_path,
+            r#"scenario:
+  people:
+    - {id: p0, attributes: {}}
+    - {id: p1, attributes: {}}
+    - {id: p2, attributes: {}}
+    - {id: p3, attributes: {}}
+  groups: [{id: g1, size: 2}, {id: g2, size: 2}]
+  num_sessions: 3
+solver: {solver_type: solver1, seed: 3, stop_conditions: {max_iterations: 200, stop_on_optimal_score: false}}
+"#,
+        )
+        .expect("write input");
+
+        cmd_solve(
+            Some(input_path),
+            false,
+            &SolveOutputArgs {
+                output: Some(temp.path().join("result.json")),
+                telemetry_jsonl: Some(telemetry_path.clone()),
+                ..SolveOutputArgs::default()
+            },
+            &SolverOverrides::default(),
+            &SessionScopeArgs::default(),
+            &WarmStartArgs::default(),
+            ProgressReport::Off,
+            &CheckpointArgs::default(),
+        )
+        .expect("solve should succeed");
+
+        let telemetry = fs::read_to_string(telemetry_path).expect("read telemetry");
+        let records: Vec<serde_json::Value> = telemetry
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("each line is JSON"))
+            .collect();
+        let last = records.last().expect("at least the final update");
+        assert_eq!(last["iteration"], 200);
+        assert_eq!(last["stop_reason"], "max_iterations_reached");
+    }
+
+    #[test]
+    fn solve_seed_flag_overrides_the_input_seed() {
+        let temp = TempDir::new().expect("temp dir");
+        let input_path = temp.path().join("input.yaml");
+        fs::write(
+            &input_path,
+            r#"scenario:
+  people:
+    - {id: p0, attributes: {}}
+    - {id: p1, attributes: {}}
+    - {id: p2, attributes: {}}
+    - {id: p3, attributes: {}}
+  groups: [{id: g1, size: 2}, {id: g2, size: 2}]
+  num_sessions: 3
+solver: {solver_type: solver1, seed: 1, stop_conditions: {max_iterations: 200}}
+"#,
+        )
+        .expect("write input");
+
+        // Later runs also report progress, which must not change the result.
+        let solve = |name: &str, progress: ProgressReport| -> SolverResult {
+            let output_path = temp.path().join(name);
+            cmd_solve(
+                Some(input_path.clone()),
+                false,
+                &SolveOutputArgs {
+                    output: Some(output_path.clone()),
+                    ..SolveOutputArgs::default()
+                },
+                &SolverOverrides {
+                    seed: Some(42),
+                    ..SolverOverrides::default()
+                },
+                &SessionScopeArgs::default(),
+                &WarmStartArgs::default(),
+                progress,
+                &CheckpointArgs::default(),
+            )
+            .expect("solve should succeed");
+            serde_json::from_str(&fs::read_to_string(output_path).expect("read output"))
+                .expect("parse result")
+        };
+        let first = solve("first.json", ProgressReport::Off);
+        let second = solve("second.json", ProgressReport::Bar);
+        let third = solve("third.json", ProgressReport::Json);
+
+        assert_eq!(first.effective_seed, Some(42));
+        assert_eq!(first.schedule, second.schedule);
+        assert_eq!(first.schedule, third.schedule);
+    }
+
+    #[test]
+    fn solve_top_k_writes_distinct_schedules_best_first() {
+        let temp = TempDir::new().expect("temp dir");
+        let input_path = temp.path().join("input.yaml");
+        fs::write(
+            &input_path,
+            r#"scenario:
+  people:
+    - {id: p0, attributes: {}}
+    - {id: p1, attributes: {}}
+    - {id: p2, attributes: {}}
+    - {id: p3, attributes: {}}
+    - {id: p4, attributes: {}}
+    - {id: p5, attributes: {}}
+  groups: [{id: g1, size: 3}, {id: g2, size: 3}]
+  num_sessions: 3
+solver: {solver_type: solver1, seed: 7, stop_conditions: {max_iterations: 200}}
+"#,
+        )
+        .expect("write input");
+
+        cmd_solve_top_k(
+            Some(input_path),
+            false,
+            &SolveOutputArgs {
+                output: Some(temp.path().join("best.json")),
+                ..SolveOutputArgs::default()
+            },
+            &SolverOverrides::default(),
+            &SessionScopeArgs::default(),
+            &WarmStartArgs::default(),
+            2,
+        )
+        .expect("top-k solve should succeed");
+
+        let read = |name: &str| -> SolverResult {
+            serde_json::from_str(&fs::read_to_string(temp.path().join(name)).expect("read"))
+                .expect("parse result")
+        };
+        let first = read("best_1.json");
+        let second = read("best_2.json");
+        assert!(first.final_score <= second.final_score);
+        assert_ne!(
+            schedule_partition(&first.schedule),
+            schedule_partition(&second.schedule)
+        );
+        assert!(!temp.path().join("best_3.json").exists());
+    }
+
+    #[test]
+    fn schedule_partition_ignores_group_labels() {
+        let schedule = |g1: &[&str], g2: &[&str]| -> ApiSchedule {
+            let groups = |people: &[&str]| people.iter().map(|id| id.to_string()).collect();
+            HashMap::from([(
+                "session_0".to_string(),
+                HashMap::from([
+                    ("g1".to_string(), groups(g1)),
+                    ("g2".to_string(), groups(g2)),
+                ]),
+            )])
+        };
+
+        assert_eq!(
+            schedule_partition(&schedule(&["p0", "p1"], &["p2", "p3"])),
+            schedule_partition(&schedule(&["p3", "p2"], &["p1", "p0"]))
+        );
+        assert_ne!(
+            schedule_partition(&schedule(&["p0", "p1"], &["p2", "p3"])),
+            schedule_partition(&schedule(&["p0", "p2"], &["p1", "p3"]))
+        );
+    }
+
+    #[test]
+    fn ranked_output_path_inserts_the_rank_before_the_extension() {
+        assert_eq!(
+            ranked_output_path(Path::new("out/result.json"), 2),
+            PathBuf::from("out/result_2.json")
+        );
+        assert_eq!(
+            ranked_output_path(Path::new("schedule"), 1),
+            PathBuf::from("schedule_1")
+        );
+    }
+
+    #[test]
+    fn solve_resumes_a_checkpoint_file_to_the_uninterrupted_result() {
+        let temp = TempDir::new().expect("temp dir");
+        let mut input = ApiInput::builder()
+            .people((0..8).map(|index| format!("p{index}")))
+            .group("g1", 4)
+            .group("g2", 4)
+            .sessions(3)
+            .build();
+        input.solver = default_solver_configuration_for(SolverKind::Solver1);
+        input.solver.seed = Some(5);
+        input.solver.stop_conditions.max_iterations = Some(600);
+        input.solver.stop_conditions.time_limit_seconds = None;
+        input.solver.stop_conditions.no_improvement_iterations = None;
+        input.solver.stop_conditions.stop_on_optimal_score = false;
+        input.solver.telemetry.checkpoint_every_n_iterations = 200;
+        input.solver.logging = LoggingOptions::default();
+
+        let saved = std::sync::Arc::new(std::sync::Mutex::new(None));
+        let sink = std::sync::Arc::clone(&saved);
+        let collect: ProgressCallback = Box::new(move |update: &ProgressUpdate| {
+            if let Some(checkpoint) = &update.checkpoint {
+                sink.lock().unwrap().get_or_insert(checkpoint.clone());
+            }
+            true
+        });
+        let uninterrupted = gm_core::run_solver_with_progress(&input, Some(&collect))
+            .expect("solve should succeed");
+        let checkpoint = saved
+            .lock()
+            .unwrap()
+            .clone()
+            .expect("a checkpoint was emitted");
+
+        let checkpoint_path = temp.path().join("run.ckpt");
+        write_checkpoint_file(&checkpoint_path, &CheckpointFile { input, checkpoint })
+            .expect("write checkpoint");
+        let output_path = temp.path().join("resumed.json");
+        cmd_solve(
+            None,
+            false,
+            &SolveOutputArgs {
+                output: Some(output_path.clone()),
+                ..SolveOutputArgs::default()
+            },
+            &SolverOverrides::default(),
+            &SessionScopeArgs::default(),
+            &WarmStartArgs::default(),
+            ProgressReport::Off,
+            &CheckpointArgs {
+                resume: Some(checkpoint_path),
+                ..CheckpointArgs::default()
+            },
+        )
+        .expect("resume should succeed");
+
+        let resumed: SolverResult =
+            serde_json::from_str(&fs::read_to_string(output_path).expect("read output"))
+                .expect("parse result");
+        assert_eq!(resumed.schedule, uninterrupted.schedule);
+        assert_eq!(resumed.iterations_run, Some(600));
+    }
+
+    #[test]
+    fn checkpoint_intervals_accept_seconds_minutes_and_hours() {
+        assert_eq!(parse_checkpoint_interval("45"), Ok(Duration::from_secs(45)));
+        assert_eq!(
+            parse_checkpoint_interval("60s"),
+            Ok(Duration::from_secs(60))
+        );
+        assert_eq!(
+            parse_checkpoint_interval("10m"),
+            Ok(Duration::from_secs(600))
+        );
+        assert_eq!(
+            parse_checkpoint_interval("2h"),
+            Ok(Duration::from_secs(7200))
+        );
+        assert!(parse_checkpoint_interval("0s").is_err());
+        assert!(parse_checkpoint_interval("5d").is_err());
+        assert!(parse_checkpoint_interval("m").is_err());
+    }
+
+    #[test]
+    fn session_scope_flags_fill_in_allowed_sessions() {
+        let mut input = ApiInput::builder()
+            .people(["alice", "bob"])
+            .group("g1", 2)
+            .sessions(4)
+            .build();
+        SessionScopeArgs {
+            lock_sessions: vec![0, 1],
+            ..SessionScopeArgs::default()
+        }
+        .apply(&mut input)
+        .expect("lock should apply");
+        assert_eq!(input.solver.allowed_sessions, Some(vec![2, 3]));
+
+        SessionScopeArgs {
+            only_sessions: vec![3, 1, 3],
+            ..SessionScopeArgs::default()
+        }
+        .apply(&mut input)
+        .expect("only should apply");
+        assert_eq!(input.solver.allowed_sessions, Some(vec![1, 3]));
+
+        let error = SessionScopeArgs {
+            lock_sessions: vec![4],
+            ..SessionScopeArgs::default()
+        }
+        .apply(&mut input)
+        .expect_err("session 4 is out of range");
+        assert!(error
+            .to_string()
+            .contains("--lock-sessions lists session 4"));
+    }
+
+    #[test]
+    fn repair_flag_fixes_the_initial_schedule() {
+        let mut input = ApiInput::builder()
+            .people(["alice", "bob", "carol"])
+            .group("g1", 2)
+            .group("g2", 2)
+            .sessions(1)
+            .build();
+        input.initial_schedule = Some(HashMap::from([(
+            "session_0".to_string(),
+            HashMap::from([(
+                "g1".to_string(),
+                vec!["alice".to_string(), "bob".to_string()],
+            )]),
+        )]));
+
+        WarmStartArgs::default()
+            .apply(&mut input)
+            .expect("no repair requested");
+        assert!(
+            !input.initial_schedule.as_ref().expect("schedule")["session_0"].contains_key("g2")
+        );
+
+        let repair = WarmStartArgs {
+            repair_initial_schedule: true,
+        };
+        repair.apply(&mut input).expect("repair should apply");
+        assert_eq!(
+            input.initial_schedule.as_ref().expect("schedule")["session_0"]["g2"],
+            vec!["carol".to_string()]
+        );
+
+        input.initial_schedule = None;
+        let error = repair
+            .apply(&mut input)
+            .expect_err("nothing to repair without an initial schedule");
+        assert!(error.to_string().contains("--repair-initial-schedule"));
+    }
+
+    #[test]
+    fn solver_overrides_replace_input_settings() {
+        let mut solver = default_solver_configuration_for(SolverKind::Solver1);
+        SolverOverrides {
+            time_limit: Some(5),
+            max_iterations: Some(1_000),
+            initial_temp: Some(3.5),
+            ..SolverOverrides::default()
+        }
+        .apply(&mut solver)
+        .expect("overrides should apply");
+
+        assert_eq!(solver.stop_conditions.time_limit_seconds, Some(5));
+        assert_eq!(solver.stop_conditions.max_iterations, Some(1_000));
+        let params = solver.simulated_annealing_params().expect("solver1 params");
+        assert_eq!(params.initial_temperature, 3.5);
+
+        SolverOverrides {
+            algorithm: Some("solver3".to_string()),
+            ..SolverOverrides::default()
+        }
+        .apply(&mut solver)
+        .expect("algorithm override should apply");
+        assert_eq!(solver.validate_solver_selection(), Ok(SolverKind::Solver3));
+        assert_eq!(solver.stop_conditions.max_iterations, Some(1_000));
+
+        let error = SolverOverrides {
+            initial_temp: Some(1.0),
+            ..SolverOverrides::default()
+        }
+        .apply(&mut solver)
+        .expect_err("solver3 has no annealing temperature")
+        .to_string();
+        assert!(error.contains("--initial-temp only applies to solver1"));
+    }
+
+    #[test]
+    fn interrupted_solves_are_flagged_in_json_and_markdown() {
+        let input = ApiInput::builder()
+            .people(["alice", "bob"])
+            .group("g1", 2)
+            .sessions(1)
+            .build();
+        let result = run_solver(&input).expect("solve should succeed");
+        let render = |output_format, interrupted| {
+            render_solve_output(&result, &[], output_format, false, &[], interrupted)
+                .expect("render should succeed")
+        };
+
+        let json = render(OutputFormat::Json, true);
+        assert!(json.starts_with("{\"interrupted\":true,"));
+        let parsed: SolverResult = serde_json::from_str(&json).expect("still a SolverResult");
+        assert_eq!(parsed.schedule, result.schedule);
+        assert!(!render(OutputFormat::Json, false).contains("interrupted"));
+        assert!(render(OutputFormat::Markdown, true).starts_with("> Interrupted:"));
+        assert!(render(OutputFormat::Table, true).starts_with("Interrupted:"));
+        assert_eq!(
+            render(OutputFormat::Table, false),
+            result.display_with(&[], &DisplayOptions::default())
+        );
+        let pretty = render(OutputFormat::Pretty, false);
+        assert!(pretty.starts_with("{\n  \""));
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&pretty).unwrap(),
+            serde_json::from_str::<serde_json::Value>(&render(OutputFormat::Json, false)).unwrap()
+        );
+        assert_eq!(
+            render(OutputFormat::Csv, true),
+            render(OutputFormat::Csv, false)
+        );
+    }
+
+    #[test]
+    fn batch_solves_every_scenario_and_skips_previous_results() {
+        let temp = TempDir::new().expect("temp dir");
+        let scenario = r#"scenario:
+  people: [{id: alice, attributes: {}}, {id: bob, attributes: {}}]
+  groups: [{id: g1, size: 2}]
+  num_sessions: 2
+solver: {solver_type: solver1, stop_conditions: {max_iterations: 10}}
+"#;
+        fs::write(temp.path().join("one.yaml"), scenario).expect("write input");
+        fs::write(temp.path().join("two.yml"), scenario).expect("write input");
+        fs::write(temp.path().join("old.result.json"), "not a scenario").expect("write");
+        fs::write(temp.path().join("notes.txt"), "ignored").expect("write");
+
+        cmd_batch(temp.path(), 2, false, &SolverOverrides::default())
+            .expect("batch should succeed");
+
+        for name in ["one.result.json", "two.result.json"] {
+            let text = fs::read_to_string(temp.path().join(name)).expect("result written");
+            let result: SolverResult = serde_json::from_str(&text).expect("parse result");
+            assert_eq!(result.unique_contacts, 1);
+        }
+        assert!(!temp.path().join("old.result.result.json").exists());
+    }
+
+    #[test]
+    fn batch_summary_lists_scores_and_failures() {
+        let outcomes = [
+            BatchOutcome {
+                file: "a.json".to_string(),
+                result: Ok(BatchSolved {
+                    final_score: 12.5,
+                    unique_contacts: 6,
+                    violations: Some(9),
+                    elapsed: std::time::Duration::from_millis(1500),
+                }),
+            },
+            BatchOutcome {
+                file: "old.json".to_string(),
+                result: Ok(BatchSolved {
+                    final_score: 3.0,
+                    unique_contacts: 2,
+                    violations: None,
+                    elapsed: std::time::Duration::from_millis(250),
+                }),
+            },
+            BatchOutcome {
+                file: "broken.json".to_string(),
+                result: Err(anyhow::anyhow!("bad input\nmore detail")),
+            },
+        ];
+
+        assert_eq!(
+            render_batch_summary(&outcomes),
+            "FILE                SCORE  CONTACTS  VIOLATIONS   TIME (s)\n\
+             a.json              12.50         6           9       1.50\n\
+             old.json             3.00         2         n/a       0.25\n\
+             broken.json  FAILED: bad input\n"
+        );
+    }
+
+    #[test]
+    fn compare_reports_score_penalty_and_group_changes() {
+        let input = ApiInput::builder()
+            .people(["alice", "bob"])
+            .group("g1", 2)
+            .group("g2", 2)
+            .sessions(1)
+            .build();
+        let mut a = run_solver(&input).expect("solve should succeed");
+        a.schedule = HashMap::from([(
+            "session_0".to_string(),
+            HashMap::from([(
+                "g1".to_string(),
+                vec!["alice".to_string(), "bob".to_string()],
+            )]),
+        )]);
+        a.constraint_violations = Some(Vec::new());
+        let mut b = a.clone();
+        b.schedule = HashMap::from([(
+            "session_0".to_string(),
+            HashMap::from([
+                ("g1".to_string(), vec!["alice".to_string()]),
+                ("g2".to_string(), vec!["bob".to_string()]),
+            ]),
+        )]);
+        b.final_score = a.final_score + 2.5;
+        b.constraint_violations = Some(vec![ConstraintViolation {
+            constraint_type: "MustStayTogether".to_string(),
+            session: Some(0),
+            people: vec!["alice".to_string(), "bob".to_string()],
+            groups: Vec::new(),
+            weighted_penalty: 1000.0,
+        }]);
+
+        let text = render_result_comparison(&compare_results(&a, &b));
+
+        assert!(text.contains("(+2.50)\n"));
+        assert!(text.contains("  MustStayTogether          0.00 ->      1000.00  (+1000.00)\n"));
+        assert!(text.contains("  session_0:\n    bob: g1 -> g2\n"));
+        assert!(!text.contains("alice:"));
+    }
+
+    #[test]
+    fn matrix_lists_pairs_who_never_met() {
+        let input = ApiInput::builder()
+            .people(["alice", "bob"])
+            .group("g1", 2)
+            .sessions(1)
+            .build();
+        let mut result = run_solver(&input).expect("solve should succeed");
+        result.schedule = HashMap::from([(
+            "session_0".to_string(),
+            HashMap::from([
+                (
+                    "g1".to_string(),
+                    vec!["alice".to_string(), "bob".to_string()],
+                ),
+                ("g2".to_string(), vec!["carol".to_string()]),
+            ]),
+        )]);
+        let people = scheduled_people(&result);
+
+        assert_eq!(
+            people
                 .iter()
-                .map(|spec| spec.code.to_string())
-                .collect(),
-        )
-    })?;
-    if json {
-        print_json_pretty(spec)
-    } else {
-        println!("error: {}", spec.code);
-        println!("category: {:?}", spec.category);
-        println!("summary: {}", spec.summary);
-        println!("why: {}", spec.why);
-        println!("recovery: {}", spec.recovery);
-        if !spec.related_help_operation_ids.is_empty() {
-            println!(
-                "related help: {}",
-                spec.related_help_operation_ids.join(", ")
-            );
-        }
-        Ok(())
+                .map(|person| person.id.as_str())
+                .collect::<Vec<_>>(),
+            ["alice", "bob", "carol"]
+        );
+        assert_eq!(
+            render_never_met(&result, &people),
+            "PAIRS WHO NEVER MET (2):\n  alice - carol\n  bob - carol\n"
+        );
+        assert_eq!(
+            render_never_met(&result, &people[..2]),
+            "PAIRS WHO NEVER MET (0):\n  none\n"
+        );
     }
-}
 
-fn resolve_schema_alias(schema_id: String) -> String {
-    match schema_id.as_str() {
-        "input" => "solve-request".to_string(),
-        "output" => "solve-response".to_string(),
-        "scenario" => "scenario-definition".to_string(),
-        other => other.to_string(),
+    #[test]
+    fn serve_answers_each_request_line_with_a_result_or_an_error() {
+        let request = r#"{"scenario":{"people":[{"id":"a","attributes":{}},{"id":"b","attributes":{}}],"groups":[{"id":"g1","size":2}],"num_sessions":1},"solver":{"solver_type":"solver1","logging":{"log_initial_score_breakdown":true}}}"#;
+        let requests = format!("{request}\n\n{{oops\n{request}\n");
+        let mut responses = Vec::new();
+
+        serve_lines(requests.as_bytes(), &mut responses).expect("serve should finish");
+
+        let lines: Vec<serde_json::Value> = String::from_utf8(responses)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("each response is one JSON line"))
+            .collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0]["unique_contacts"], 1);
+        let envelope: PublicErrorEnvelope =
+            serde_json::from_value(lines[1].clone()).expect("errors are public error envelopes");
+        assert_eq!(envelope.error.code, "invalid-input");
+        assert_eq!(
+            envelope.error.where_path.as_deref(),
+            Some("line 1, column 2")
+        );
+        assert!(envelope.error.recovery.is_some());
+        assert_eq!(lines[2]["schedule"], lines[0]["schedule"]);
     }
-}
 
-fn parse_recommend_input(json_str: &str) -> Result<RecommendSettingsRequest> {
-    serde_json::from_str::<RecommendSettingsRequest>(json_str).map_err(|error| {
-        public_errors::invalid_input_error(
-            format!(
-                "Failed to parse recommend input as recommend-settings-request JSON: {}",
-                error
-            ),
-            Some(format!("line {}, column {}", error.line(), error.column())),
-            "recommend-settings",
-            vec!["recommend-settings-request".to_string()],
+    #[test]
+    fn evaluate_scores_a_separate_schedule_or_result_file() {
+        let temp = TempDir::new().expect("temp dir");
+        let problem_path = temp.path().join("problem.yaml");
+        fs::write(
+            &problem_path,
+            "scenario:\n  people: [{id: a, attributes: {}}, {id: b, attributes: {}}]\n  groups: [{id: g1, size: 2}]\n  num_sessions: 1\n",
         )
-    })
-}
+        .unwrap();
+        let bare_path = temp.path().join("schedule.json");
+        fs::write(&bare_path, r#"{"session_0": {"g1": ["a", "b"]}}"#).unwrap();
+        let result_path = temp.path().join("result.yaml");
+        fs::write(
+            &result_path,
+            "final_score: 1.0\nschedule:\n  session_0:\n    g1: [a, b]\n",
+        )
+        .unwrap();
+
+        cmd_evaluate(Some(problem_path.clone()), false, Some(&bare_path), false)
+            .expect("bare schedule should be scored");
+        cmd_evaluate(Some(problem_path.clone()), false, Some(&result_path), false)
+            .expect("schedule inside a result should be scored");
+        let error = cmd_evaluate(Some(problem_path), false, None, false)
+            .expect_err("a schedule is required")
+            .to_string();
+        assert!(error.contains("--schedule"));
+    }
 
-fn known_schema_ids() -> Vec<&'static str> {
-    schema_specs().iter().map(|spec| spec.id).collect()
-}
+    #[test]
+    fn validate_exit_codes_distinguish_parse_errors_from_invalid_input() {
+        let temp = TempDir::new().expect("temp dir");
+        let write = |name: &str, contents: &str| {
+            let path = temp.path().join(name);
+            fs::write(&path, contents).unwrap();
+            Some(path)
+        };
+        let valid = write(
+            "valid.yaml",
+            "scenario:\n  people: [{id: a, attributes: {}}]\n  groups: [{id: g1, size: 1}]\n  num_sessions: 1\n",
+        );
+        let invalid = write(
+            "invalid.yaml",
+            "scenario:\n  people: [{id: a, attributes: {}, sessions: [4]}]\n  groups: [{id: g1, size: 1}]\n  num_sessions: 1\n",
+        );
+        let malformed = write("malformed.json", "{");
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use tempfile::TempDir;
+        assert_eq!(cmd_validate(valid, false).unwrap(), VALIDATE_EXIT_VALID);
+        assert_eq!(cmd_validate(invalid, false).unwrap(), VALIDATE_EXIT_INVALID);
+        assert_eq!(
+            cmd_validate(malformed, false).unwrap(),
+            VALIDATE_EXIT_PARSE_ERROR
+        );
+    }
 
-    fn write_sample_run_report(temp: &TempDir) -> PathBuf {
-        let run_path = temp.path().join("run-report.json");
-        fs::write(
-            &run_path,
-            r#"{
-  "schema_version": 1,
-  "suite": {
-    "suite_id": "social-golfer-plateau-time-solver3",
-    "benchmark_mode": "full_solve",
-    "comparison_category": "score_quality",
-    "solver_families": ["solver3"],
-    "class": "stretch",
-    "manifest_path": "backend/benchmarking/suites/social-golfer-plateau-time-solver3.yaml"
-  },
-  "run": {
-    "run_id": "sample-run",
-    "generated_at": "2026-04-13T00:00:00Z",
-    "git": {},
-    "machine": {}
-  },
-  "totals": {
-    "total_cases": 1,
-    "successful_cases": 1,
-    "failed_cases": 0,
-    "total_runtime_seconds": 10.0
-  },
-  "class_rollups": [],
-  "cases": [
-    {
-      "schema_version": 1,
-      "run_id": "sample-run",
-      "generated_at": "2026-04-13T00:00:00Z",
-      "suite_id": "social-golfer-plateau-time-solver3",
-      "benchmark_mode": "full_solve",
-      "suite_class": "stretch",
-      "case_id": "stretch.social-golfer-32x8x10",
-      "case_class": "stretch",
-      "case_manifest_path": "backend/benchmarking/cases/stretch/social_golfer_32x8x10.json",
-      "git": {},
-      "machine": {},
-      "solver": {
-        "solver_family": "solver3",
-        "solver_config_id": "solver3",
-        "display_name": "Solver 3",
-        "seed_policy": "explicit",
-        "capabilities": {
-          "supports_initial_schedule": true,
-          "supports_progress_callback": true,
-          "supports_benchmark_observer": true,
-          "supports_recommended_settings": true,
-          "supports_deterministic_seed": true
-        }
-      },
-      "effective_budget": {
-        "max_iterations": 10000000,
-        "time_limit_seconds": 25,
-        "no_improvement_iterations": null
-      },
-      "artifact_kind": "full_solve",
-      "status": "success",
-      "timing": {
-        "initialization_seconds": 0.0,
-        "search_seconds": 10.0,
-        "finalization_seconds": 0.0,
-        "total_seconds": 10.0
-      },
-      "runtime_seconds": 10.0,
-      "moves": {
-        "swap": {},
-        "transfer": {},
-        "clique_swap": {}
-      },
-      "search_telemetry": {
-        "accepted_uphill_moves": 1,
-        "accepted_downhill_moves": 2,
-        "accepted_neutral_moves": 3,
-        "max_no_improvement_streak": 50,
-        "restart_count": null,
-        "perturbation_count": null,
-        "iterations_per_second": 100.0,
-        "best_score_timeline": [
-          { "iteration": 0, "elapsed_seconds": 0.0, "best_score": 100.0 },
-          { "iteration": 10, "elapsed_seconds": 1.0, "best_score": 80.0 },
-          { "iteration": 100, "elapsed_seconds": 5.0, "best_score": 60.0 },
-          { "iteration": 150, "elapsed_seconds": 7.0, "best_score": 50.0 }
-        ]
-      }
+    #[test]
+    fn stats_render_flags_sessions_over_capacity() {
+        let input = ApiInput::builder()
+            .people(["alice", "bob", "carol"])
+            .group("g1", 2)
+            .sessions(1)
+            .constraint(Constraint::MustStayTogether {
+                people: vec!["alice".to_string(), "bob".to_string()],
+                sessions: None,
+            })
+            .build();
+
+        let text = render_problem_stats(&problem_stats(&input));
+
+        assert!(text.starts_with("PEOPLE: 3\nGROUPS: 1\nSESSIONS: 1\n"));
+        assert!(text.contains("  session 0: 3 / 2 (-1)  OVER CAPACITY\n"));
+        assert!(text.contains("MUST-STAY-TOGETHER CLIQUES: 1 (sizes 2)\n"));
+        assert!(text.ends_with("CONSTRAINTS:\n  MustStayTogether  1\n"));
     }
-  ]
-}
+
+    #[test]
+    fn convert_imports_people_csv_and_round_trips_yaml_to_json() {
+        let temp = TempDir::new().expect("temp dir");
+        let csv_path = temp.path().join("people.csv");
+        fs::write(&csv_path, "id,team\nalice,red\nbob,blue\n").unwrap();
+        let problem_path = temp.path().join("problem.yaml");
+        fs::write(
+            &problem_path,
+            r#"scenario:
+  people: []
+  groups: [{id: g1, size: 2}]
+  num_sessions: 2
+solver:
+  solver_type: solver3
 "#,
         )
-        .expect("write sample run report");
-        run_path
+        .unwrap();
+        let yaml_path = temp.path().join("merged.yaml");
+        let json_path = temp.path().join("merged.json");
+
+        cmd_convert(&csv_path, true, Some(&problem_path), &yaml_path).expect("import people");
+        cmd_convert(&yaml_path, false, None, &json_path).expect("convert to JSON");
+
+        let json = fs::read_to_string(&json_path).unwrap();
+        assert!(json.find("\"scenario\"").unwrap() < json.find("\"solver\"").unwrap());
+        let request: SolveRequest = serde_json::from_str(&json).unwrap();
+        assert_eq!(request.scenario.people.len(), 2);
+        assert_eq!(request.scenario.people[1].attributes["team"], "blue");
+        assert_eq!(request.scenario.num_sessions, 2);
+        assert_eq!(request.solver.solver_type, "solver3");
     }
 
     #[test]
-    fn suite_manifest_defaults_to_builtin_path_manifest() {
-        let path = resolve_suite_manifest_path(&BenchmarkSuiteArg::Path, None);
-        assert_eq!(path, PathBuf::from("backend/benchmarking/suites/path.yaml"));
+    fn convert_people_sizes_the_skeleton_and_keeps_existing_constraints() {
+        let temp = TempDir::new().expect("temp dir");
+        let csv_path = temp.path().join("people.csv");
+        fs::write(&csv_path, "id,sessions\nalice,1;3\nbob,\n").unwrap();
+        let skeleton_path = temp.path().join("skeleton.json");
+
+        cmd_convert(&csv_path, true, None, &skeleton_path).expect("import people");
+
+        let skeleton: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&skeleton_path).unwrap()).unwrap();
+        assert_eq!(skeleton["scenario"]["num_sessions"], 4);
+
+        let problem_path = temp.path().join("problem.yaml");
+        let problem = |constraint: &str| {
+            format!(
+                "scenario:\n  people: []\n  groups: [{{id: g1, size: 2}}]\n  num_sessions: 4\n\
+                 constraints:\n  - {constraint}\n"
+            )
+        };
+        fs::write(
+            &problem_path,
+            problem("{type: MustStayApart, people: [alice, bob]}"),
+        )
+        .unwrap();
+        let merged_path = temp.path().join("merged.json");
+        cmd_convert(&csv_path, true, Some(&problem_path), &merged_path).expect("merge people");
+        let merged: SolveRequest =
+            serde_json::from_str(&fs::read_to_string(&merged_path).unwrap()).unwrap();
+        assert_eq!(merged.constraints.len(), 1);
+
+        fs::write(
+            &problem_path,
+            problem("{type: ImmovablePerson, person_id: carol, group_id: g1}"),
+        )
+        .unwrap();
+        let error = cmd_convert(&csv_path, true, Some(&problem_path), &merged_path).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("constraints[0] (ImmovablePerson) names carol"),
+            "{error}"
+        );
     }
 
+    #[cfg(feature = "xlsx")]
     #[test]
-    fn baseline_list_works_against_storage_layout() {
+    fn convert_imports_a_workbook_adding_pair_constraints() {
         let temp = TempDir::new().expect("temp dir");
-        let storage = BenchmarkStorage::new(temp.path());
-        storage.ensure_layout().expect("layout");
-        let path = storage.baseline_snapshot_path("benchbox", "path", "baseline-a");
-        fs::create_dir_all(path.parent().unwrap()).expect("mk parent");
-        fs::write(&path, "{}\n").expect("write baseline");
+        let workbook_path = temp.path().join("attendees.xlsx");
+        fs::write(
+            &workbook_path,
+            include_bytes!("../../core/tests/fixtures/attendees.xlsx"),
+        )
+        .unwrap();
+        let problem_path = temp.path().join("problem.yaml");
+        fs::write(
+            &problem_path,
+            r#"scenario:
+  people: []
+  groups: [{id: g1, size: 2}, {id: g2, size: 2}]
+  num_sessions: 3
+constraints:
+  - {type: MustStayApart, people: [alice, "42"]}
+  - {type: RepeatEncounter, max_allowed_encounters: 1, penalty_function: squared, penalty_weight: 10}
+"#,
+        )
+        .unwrap();
+        let out_path = temp.path().join("problem.json");
 
-        let baselines = storage
-            .list_baselines(Some("benchbox"), Some("path"))
-            .expect("list baselines");
-        assert_eq!(baselines.len(), 1);
-        assert_eq!(baselines[0].baseline_name, "baseline-a");
-    }
+        cmd_convert(&workbook_path, true, Some(&problem_path), &out_path).expect("import");
 
-    #[test]
-    fn schema_command_unknown_id_uses_canonical_error_output() {
-        let error = cmd_schema(Some("does-not-exist".to_string()), false)
-            .expect_err("unknown schema should fail")
-            .to_string();
-        assert!(error.contains("error[unknown-schema]"));
-        assert!(error.contains("gm-cli schema --help"));
+        let request: SolveRequest =
+            serde_json::from_str(&fs::read_to_string(&out_path).unwrap()).unwrap();
+        assert_eq!(request.scenario.people.len(), 4);
+        let types: Vec<_> = request
+            .constraints
+            .iter()
+            .map(|constraint| constraint.type_name())
+            .collect();
+        assert_eq!(
+            types,
+            [
+                "MustStayApart",
+                "RepeatEncounter",
+                "MustStayTogether",
+                "ShouldNotBeTogether",
+                "ShouldStayTogether"
+            ]
+        );
+
+        cmd_convert(&workbook_path, true, Some(&out_path), &out_path).expect("import again");
+        let again: SolveRequest =
+            serde_json::from_str(&fs::read_to_string(&out_path).unwrap()).unwrap();
+        assert_eq!(again.constraints.len(), 5);
+
+        let error = cmd_convert(&workbook_path, false, None, &out_path).unwrap_err();
+        assert!(error.to_string().contains("--people"));
     }
 
     #[test]
-    fn errors_command_unknown_code_uses_canonical_error_output() {
-        let error = cmd_errors(Some("does-not-exist".to_string()), false)
-            .expect_err("unknown error code should fail")
-            .to_string();
-        assert!(error.contains("error[unknown-error-code]"));
-        assert!(error.contains("gm-cli errors --help"));
+    fn bench_case_reports_iterations_and_renders_a_row() {
+        let case = BenchCase {
+            name: "tiny",
+            people: 4,
+            groups: 2,
+            group_size: 2,
+            sessions: 2,
+        };
+        let mut solver = default_solver_configuration_for(BENCH_DEFAULT_SOLVER);
+        solver.stop_conditions.max_iterations = Some(500);
+        solver.stop_conditions.time_limit_seconds = Some(1);
+        solver.stop_conditions.stop_on_optimal_score = false;
+
+        let row = run_bench_case(&case, &solver).expect("bench case should run");
+
+        assert_eq!(row.solver, "solver3");
+        assert!(row.iterations > 0);
+        let text = render_bench_rows(&[row]);
+        assert!(text.starts_with("CASE "));
+        assert!(text
+            .lines()
+            .nth(1)
+            .unwrap()
+            .starts_with("tiny           4        2 solver3"));
     }
 
     #[test]
-    fn evaluate_without_initial_schedule_uses_canonical_error_output() {
+    fn tune_writes_a_simulated_annealing_configuration() {
         let temp = TempDir::new().expect("temp dir");
-        let input_path = temp.path().join("input.json");
+        let input_path = temp.path().join("problem.yaml");
         fs::write(
             &input_path,
-            r#"{
-  "scenario": {"people": [], "groups": [], "num_sessions": 1},
-  "initial_schedule": null,
-  "objectives": [],
-  "constraints": [],
-  "solver": {
-    "solver_type": "SimulatedAnnealing",
-    "stop_conditions": {"max_iterations": 1, "time_limit_seconds": null, "no_improvement_iterations": null},
-    "solver_params": {"solver_type": "SimulatedAnnealing", "initial_temperature": 1.0, "final_temperature": 0.1, "cooling_schedule": "geometric", "reheat_cycles": 0, "reheat_after_no_improvement": 0},
-    "logging": {},
-    "telemetry": {},
-    "seed": null,
-    "move_policy": null,
-    "allowed_sessions": null
-  }
-}"#,
+            r#"scenario:
+  people:
+    - {id: a, attributes: {}}
+    - {id: b, attributes: {}}
+    - {id: c, attributes: {}}
+    - {id: d, attributes: {}}
+  groups: [{id: g1, size: 2}, {id: g2, size: 2}]
+  num_sessions: 2
+solver:
+  solver_type: solver1
+  stop_conditions: {max_iterations: 200}
+  solver_params:
+    solver_type: SimulatedAnnealing
+    initial_temperature: 1.0
+    final_temperature: 0.01
+    cooling_schedule: geometric
+"#,
         )
-        .expect("write input");
+        .unwrap();
+        let output_path = temp.path().join("tuned.json");
 
-        let error = cmd_evaluate(Some(input_path), false, false)
-            .expect_err("evaluate should fail")
-            .to_string();
-        assert!(error.contains("error[invalid-input]"));
-        assert!(error.contains("initial_schedule"));
-        assert!(error.contains("gm-cli evaluate --help"));
+        let budget = TuningBudget {
+            candidates: 4,
+            initial_iterations: 50,
+        };
+        cmd_tune(
+            &input_path,
+            budget,
+            Some(7),
+            Some(output_path.clone()),
+            false,
+        )
+        .expect("tune should succeed");
+
+        let tuned: SolverConfiguration =
+            serde_json::from_str(&fs::read_to_string(output_path).unwrap()).unwrap();
+        assert!(tuned.solver_params.simulated_annealing_params().is_some());
+        assert_eq!(tuned.stop_conditions.max_iterations, Some(200));
+        assert_eq!(tuned.seed, None);
+
+        let mut input: ApiInput =
+            serde_yaml::from_str::<SolveRequest>(&fs::read_to_string(&input_path).unwrap())
+                .unwrap()
+                .into();
+        input.solver.seed = Some(7);
+        let expected = auto_tune(&input, budget).unwrap();
+        assert_eq!(
+            serde_json::to_value(&tuned.solver_params).unwrap(),
+            serde_json::to_value(&expected.solver_params).unwrap()
+        );
+    }
+
+    #[test]
+    fn report_command_writes_the_requested_format() {
+        let temp = TempDir::new().expect("temp dir");
+        let input = ApiInput::builder()
+            .people(["alice", "bob"])
+            .group("g1", 2)
+            .sessions(1)
+            .build();
+        let result = run_solver(&input).expect("solve should succeed");
+        let result_path = temp.path().join("result.json");
+        fs::write(&result_path, serde_json::to_string(&result).unwrap()).unwrap();
+
+        let html_path = temp.path().join("report.html");
+        cmd_report(&result_path, ReportFormatArg::Html, Some(html_path.clone()))
+            .expect("html report should render");
+        let markdown_path = temp.path().join("report.md");
+        cmd_report(
+            &result_path,
+            ReportFormatArg::Markdown,
+            Some(markdown_path.clone()),
+        )
+        .expect("markdown report should render");
+
+        assert!(fs::read_to_string(html_path)
+            .unwrap()
+            .starts_with("<!DOCTYPE html>"));
+        assert!(fs::read_to_string(markdown_path)
+            .unwrap()
+            .starts_with("# Schedule report\n"));
     }
 
     #[test]
@@ -1,11 +1,11 @@
 use crate::contract_surface::binding_for_operation_id;
-use anyhow::anyhow;
 use anyhow::Error;
 use gm_contracts::errors::{
     error_spec, supported_constraint_kind_alternatives, INFEASIBLE_SCENARIO_ERROR, INTERNAL_ERROR,
     INVALID_INPUT_ERROR, UNKNOWN_ERROR_CODE_ERROR, UNKNOWN_SCHEMA_ERROR,
     UNSUPPORTED_CONSTRAINT_KIND_ERROR, UNSUPPORTED_CONSTRAINT_KIND_PATH,
 };
+use gm_contracts::types::{PublicError, PublicErrorEnvelope};
 
 pub fn invalid_input_error(
     message: impl Into<String>,
@@ -89,6 +89,41 @@ pub fn map_solver_error(message: impl Into<String>, related_operation_id: &str)
     infeasible_scenario_error(message, related_operation_id)
 }
 
+/// A public error: displays as the rendered `error[code]: ...` report and keeps the contract
+/// envelope for callers that answer in JSON.
+#[derive(Debug)]
+pub struct PublicErrorReport {
+    envelope: PublicErrorEnvelope,
+    rendered: String,
+}
+
+impl std::fmt::Display for PublicErrorReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.rendered)
+    }
+}
+
+impl std::error::Error for PublicErrorReport {}
+
+/// The contract envelope for `error`; errors raised outside this module become
+/// `internal-error`s of `related_operation_id`.
+pub fn envelope(error: &Error, related_operation_id: &str) -> PublicErrorEnvelope {
+    error
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<PublicErrorReport>())
+        .map(|report| report.envelope.clone())
+        .unwrap_or_else(|| {
+            public_report(
+                INTERNAL_ERROR,
+                error.to_string(),
+                None,
+                Vec::new(),
+                Some(vec![related_operation_id.to_string()]),
+            )
+            .envelope
+        })
+}
+
 fn public_error(
     code: &str,
     message: impl Into<String>,
@@ -96,6 +131,22 @@ fn public_error(
     valid_alternatives: Vec<String>,
     related_operation_override: Option<Vec<String>>,
 ) -> Error {
+    Error::new(public_report(
+        code,
+        message,
+        where_path,
+        valid_alternatives,
+        related_operation_override,
+    ))
+}
+
+fn public_report(
+    code: &str,
+    message: impl Into<String>,
+    where_path: Option<String>,
+    valid_alternatives: Vec<String>,
+    related_operation_override: Option<Vec<String>>,
+) -> PublicErrorReport {
     let spec = error_spec(code).expect("registered public error spec");
     let message = message.into();
     let related_operations: Vec<String> = related_operation_override.unwrap_or_else(|| {
@@ -107,7 +158,7 @@ fn public_error(
 
     let mut rendered = String::new();
     rendered.push_str(&format!("error[{}]: {}\n", spec.code, message));
-    if let Some(where_path) = where_path {
+    if let Some(where_path) = &where_path {
         rendered.push_str(&format!("where: {}\n", where_path));
     }
     rendered.push_str(&format!("why: {}\n", spec.why));
@@ -117,8 +168,8 @@ fn public_error(
     rendered.push_str(&format!("recovery: {}\n", spec.recovery));
     if !related_operations.is_empty() {
         rendered.push_str("see:\n");
-        for operation_id in related_operations {
-            if let Some(binding) = binding_for_operation_id(&operation_id) {
+        for operation_id in &related_operations {
+            if let Some(binding) = binding_for_operation_id(operation_id) {
                 rendered.push_str(&format!("  - gm-cli {} --help\n", binding.command_name));
             } else {
                 rendered.push_str(&format!("  - {}\n", operation_id));
@@ -126,5 +177,19 @@ fn public_error(
         }
     }
 
-    anyhow!(rendered.trim_end().to_string())
+    PublicErrorReport {
+        envelope: PublicErrorEnvelope {
+            error: PublicError {
+                code: spec.code.to_string(),
+                message,
+                where_path,
+                why: Some(spec.why.to_string()),
+                valid_alternatives,
+                recovery: Some(spec.recovery.to_string()),
+                related_help: related_operations,
+                details: None,
+            },
+        },
+        rendered: rendered.trim_end().to_string(),
+    }
 }
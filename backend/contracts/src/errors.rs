@@ -12,6 +12,7 @@ pub const UNKNOWN_ERROR_CODE_ERROR: &str = "unknown-error-code";
 pub const INFEASIBLE_SCENARIO_ERROR: &str = "infeasible-scenario";
 pub const UNSUPPORTED_CONSTRAINT_KIND_ERROR: &str = "unsupported-constraint-kind";
 pub const PERMISSION_DENIED_ERROR: &str = "permission-denied";
+pub const SERVER_BUSY_ERROR: &str = "server-busy";
 pub const INTERNAL_ERROR: &str = "internal-error";
 pub const UNSUPPORTED_CONSTRAINT_KIND_PATH: &str = "constraints[*].type";
 
@@ -93,6 +94,14 @@ const ERROR_SPECS: &[PublicErrorSpec] = &[
         recovery: "Use a permitted read-only affordance, obtain the required credentials/scope, or switch to an allowed workflow.",
         related_help_operation_ids: &[SOLVE_OPERATION_ID, VALIDATE_SCENARIO_OPERATION_ID],
     },
+    PublicErrorSpec {
+        code: SERVER_BUSY_ERROR,
+        category: ErrorCategory::Conflict,
+        summary: "The server is not accepting more work right now.",
+        why: "The solve queue already holds as many waiting jobs as the server is configured to accept.",
+        recovery: "Wait for queued jobs to finish, then resend the request.",
+        related_help_operation_ids: &[SOLVE_OPERATION_ID],
+    },
     PublicErrorSpec {
         code: INTERNAL_ERROR,
         category: ErrorCategory::Internal,
@@ -42,13 +42,17 @@ pub struct SolveRequest {
     /// Optional construction seed schedule for constructor-driven bootstrapping.
     #[serde(default)]
     pub construction_seed_schedule: Option<InitialScheduleContract>,
+    /// Optional encounters from earlier events that pre-seed the contact matrix.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contact_history: Option<gm_core::models::ContactHistory>,
     /// Optimization objectives (defaults to empty list if not specified)
     #[serde(default)]
     pub objectives: Vec<gm_core::models::Objective>,
     /// Constraints that must be satisfied or penalized (defaults to empty list)
     #[serde(default)]
     pub constraints: Vec<gm_core::models::Constraint>,
-    /// Solver algorithm configuration and parameters
+    /// Solver algorithm configuration and parameters (defaults to the automatic solver)
+    #[serde(default)]
     pub solver: SolverConfigurationContract,
 }
 
@@ -206,6 +210,9 @@ pub struct PublicError {
     pub recovery: Option<String>,
     #[serde(default)]
     pub related_help: Vec<String>,
+    /// Input entities a solver error refers to, so a UI can highlight the offending rows.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<gm_core::solver_support::error_details::SolverErrorDetails>,
 }
 
 /// Envelope for public transport-level error responses.
@@ -220,6 +227,7 @@ impl From<SolveRequest> for gm_core::models::ApiInput {
             problem: value.scenario.into(),
             initial_schedule: value.initial_schedule,
             construction_seed_schedule: value.construction_seed_schedule,
+            contact_history: value.contact_history,
             objectives: value.objectives,
             constraints: value.constraints,
             solver: value.solver,
@@ -233,6 +241,7 @@ impl From<&SolveRequest> for gm_core::models::ApiInput {
             problem: value.scenario.clone().into(),
             initial_schedule: value.initial_schedule.clone(),
             construction_seed_schedule: value.construction_seed_schedule.clone(),
+            contact_history: value.contact_history.clone(),
             objectives: value.objectives.clone(),
             constraints: value.constraints.clone(),
             solver: value.solver.clone(),
@@ -246,6 +255,7 @@ impl From<gm_core::models::ApiInput> for SolveRequest {
             scenario: value.problem.into(),
             initial_schedule: value.initial_schedule,
             construction_seed_schedule: value.construction_seed_schedule,
+            contact_history: value.contact_history,
             objectives: value.objectives,
             constraints: value.constraints,
             solver: value.solver,
@@ -259,6 +269,7 @@ impl From<&gm_core::models::ApiInput> for SolveRequest {
             scenario: value.problem.clone().into(),
             initial_schedule: value.initial_schedule.clone(),
             construction_seed_schedule: value.construction_seed_schedule.clone(),
+            contact_history: value.contact_history.clone(),
             objectives: value.objectives.clone(),
             constraints: value.constraints.clone(),
             solver: value.solver.clone(),
@@ -348,11 +359,15 @@ mod tests {
                     id: "g1".to_string(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g2".to_string(),
                     size: 2,
                     session_sizes: Some(vec![2, 2]),
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 2,
@@ -367,6 +382,9 @@ mod tests {
                 time_limit_seconds: Some(5),
                 no_improvement_iterations: Some(25),
                 stop_on_optimal_score: true,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
                 initial_temperature: 1.0,
@@ -374,6 +392,7 @@ mod tests {
                 cooling_schedule: "geometric".to_string(),
                 reheat_after_no_improvement: Some(0),
                 reheat_cycles: Some(0),
+                penalty_ramp: None,
             }),
             logging: Default::default(),
             telemetry: Default::default(),
@@ -391,9 +410,12 @@ mod tests {
                 HashMap::from([("g1".to_string(), vec!["p1".to_string(), "p2".to_string()])]),
             )])),
             construction_seed_schedule: None,
+            contact_history: None,
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".to_string(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![],
             solver: sample_solver_configuration(),
@@ -459,6 +481,21 @@ mod tests {
             move_policy: None,
             stop_reason: Some(StopReason::TimeLimitReached),
             benchmark_telemetry: None,
+            iterations_run: None,
+            time_elapsed_ms: None,
+            final_temperature: None,
+            accepted_moves: None,
+            constraint_violations: None,
+            session_breakdown: None,
+            contact_matrix: None,
+            display_names: None,
+            person_attributes: None,
+            score_history: None,
+            move_statistics: None,
+            constraint_penalties: None,
+            effective_configuration: None,
+            unique_contacts_upper_bound: None,
+            optimality_gap: None,
         };
 
         let summary = ResultSummary::from(&result);
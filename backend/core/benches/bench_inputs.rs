@@ -509,6 +509,8 @@ pub fn make_problem(num_people: u32, group_size: u32, num_sessions: u32) -> Prob
             id: format!("g{i}"),
             size: group_size,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         })
         .collect();
 
@@ -529,9 +531,12 @@ pub fn make_api_input(
         problem,
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints,
         solver: seeded_solver_config(max_iterations, seed),
@@ -546,6 +551,9 @@ pub fn seeded_solver_config(max_iterations: u64, seed: u64) -> SolverConfigurati
             time_limit_seconds: None,
             no_improvement_iterations: None,
             stop_on_optimal_score: false,
+            stop_on_feasible: false,
+            feasible_score_threshold: None,
+            convergence: None,
         },
         solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
             initial_temperature: 10.0,
@@ -553,6 +561,7 @@ pub fn seeded_solver_config(max_iterations: u64, seed: u64) -> SolverConfigurati
             cooling_schedule: "geometric".to_string(),
             reheat_after_no_improvement: Some(0),
             reheat_cycles: Some(0),
+            penalty_ramp: None,
         }),
         logging: Default::default(),
         telemetry: Default::default(),
@@ -619,6 +628,8 @@ fn group(id: &str, size: u32) -> Group {
         id: id.to_string(),
         size,
         session_sizes: None,
+        attributes: HashMap::new(),
+        available_sessions: None,
     }
 }
 
@@ -9,6 +9,7 @@ fn main() {
     let input = ApiInput {
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people: vec![
                 Person {
@@ -47,11 +48,15 @@ fn main() {
                     id: "Team1".to_string(),
                     size: 3,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "Team2".to_string(),
                     size: 3,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 3,
@@ -59,6 +64,8 @@ fn main() {
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![
             Constraint::MustStayTogether {
@@ -83,6 +90,9 @@ fn main() {
                 time_limit_seconds: None,
                 no_improvement_iterations: None,
                 stop_on_optimal_score: true,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
                 initial_temperature: 1.0,
@@ -90,6 +100,7 @@ fn main() {
                 cooling_schedule: "geometric".to_string(),
                 reheat_cycles: Some(0),
                 reheat_after_no_improvement: Some(0), // No reheat
+                penalty_ramp: None,
             }),
             logging: LoggingOptions::default(),
             telemetry: Default::default(),
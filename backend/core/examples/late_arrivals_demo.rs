@@ -9,6 +9,7 @@ fn main() {
     let input = ApiInput {
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people: vec![
                 // Core team - participates in all sessions
@@ -62,11 +63,15 @@ fn main() {
                     id: "Team1".to_string(),
                     size: 4,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "Team2".to_string(),
                     size: 4,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 3,
@@ -74,6 +79,8 @@ fn main() {
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![
             // Alice and Bob must work together when both are present
@@ -89,6 +96,9 @@ fn main() {
                 time_limit_seconds: None,
                 no_improvement_iterations: None,
                 stop_on_optimal_score: true,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
                 initial_temperature: 1.0,
@@ -96,6 +106,7 @@ fn main() {
                 cooling_schedule: "geometric".to_string(),
                 reheat_cycles: Some(0),
                 reheat_after_no_improvement: Some(0), // No reheat
+                penalty_ramp: None,
             }),
             logging: LoggingOptions {
                 display_final_schedule: true,
@@ -8,6 +8,7 @@ fn main() {
     let input = ApiInput {
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people: vec![
                 Person {
@@ -46,11 +47,15 @@ fn main() {
                     id: "Group1".to_string(),
                     size: 3,
                     session_sizes: None,
+                    attributes: std::collections::HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "Group2".to_string(),
                     size: 3,
                     session_sizes: None,
+                    attributes: std::collections::HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 3,
@@ -58,6 +63,8 @@ fn main() {
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: std::collections::HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![
             // Alice and Bob must stay together in sessions 0 and 1 only
@@ -79,6 +86,9 @@ fn main() {
                 time_limit_seconds: None,
                 no_improvement_iterations: None,
                 stop_on_optimal_score: true,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
                 initial_temperature: 1.0,
@@ -86,6 +96,7 @@ fn main() {
                 cooling_schedule: "geometric".to_string(),
                 reheat_cycles: Some(0),
                 reheat_after_no_improvement: Some(0), // No reheat
+                penalty_ramp: None,
             }),
             logging: LoggingOptions {
                 display_final_schedule: true,
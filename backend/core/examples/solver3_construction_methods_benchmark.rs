@@ -114,6 +114,9 @@ fn configure_solver3(input: &mut ApiInput, mode: Solver3ConstructionMode, gamma:
             time_limit_seconds: Some(1),
             no_improvement_iterations: None,
             stop_on_optimal_score: false,
+            stop_on_feasible: false,
+            feasible_score_threshold: None,
+            convergence: None,
         },
         solver_params: SolverParams::Solver3(params),
         logging: Default::default(),
@@ -140,15 +143,20 @@ fn pure_sgp_case(num_groups: usize, group_size: usize, num_sessions: usize) -> A
                     id: format!("g{idx}"),
                     size: group_size as u32,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 })
                 .collect(),
             num_sessions: num_sessions as u32,
         },
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![Constraint::RepeatEncounter(RepeatEncounterParams {
             max_allowed_encounters: 1,
@@ -193,15 +201,20 @@ fn synthetic_partial_attendance_case(
                     id: format!("g{idx}"),
                     size: group_size as u32,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 })
                 .collect(),
             num_sessions: num_sessions as u32,
         },
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![Constraint::RepeatEncounter(RepeatEncounterParams {
             max_allowed_encounters: 1,
@@ -220,6 +233,9 @@ fn solver3_placeholder() -> SolverConfiguration {
             time_limit_seconds: Some(1),
             no_improvement_iterations: None,
             stop_on_optimal_score: false,
+            stop_on_feasible: false,
+            feasible_score_threshold: None,
+            convergence: None,
         },
         solver_params: SolverParams::Solver3(Solver3Params::default()),
         logging: Default::default(),
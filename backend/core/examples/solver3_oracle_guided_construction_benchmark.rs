@@ -87,6 +87,8 @@ fn benchmark_input(mode: Solver3ConstructionMode, max_iterations: u64) -> ApiInp
             id: format!("g{idx}"),
             size: 4,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         })
         .collect::<Vec<_>>();
 
@@ -97,6 +99,9 @@ fn benchmark_input(mode: Solver3ConstructionMode, max_iterations: u64) -> ApiInp
             time_limit_seconds: Some(2),
             no_improvement_iterations: Some(100),
             stop_on_optimal_score: false,
+            stop_on_feasible: false,
+            feasible_score_threshold: None,
+            convergence: None,
         },
         solver_params: SolverParams::Solver3(Solver3Params::default()),
         logging: Default::default(),
@@ -118,9 +123,12 @@ fn benchmark_input(mode: Solver3ConstructionMode, max_iterations: u64) -> ApiInp
         },
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![
             Constraint::RepeatEncounter(RepeatEncounterParams {
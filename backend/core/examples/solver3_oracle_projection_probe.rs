@@ -391,15 +391,20 @@ fn solver6_input(candidate: &TemplateCandidate) -> ApiInput {
                     id: oracle_group_id(idx),
                     size: candidate.group_size as u32,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 })
                 .collect(),
             num_sessions: candidate.sessions.len() as u32,
         },
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![Constraint::RepeatEncounter(RepeatEncounterParams {
             max_allowed_encounters: 1,
@@ -413,6 +418,9 @@ fn solver6_input(candidate: &TemplateCandidate) -> ApiInput {
                 time_limit_seconds: Some(1),
                 no_improvement_iterations: Some(100),
                 stop_on_optimal_score: true,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::Solver6(Solver6Params {
                 exact_construction_handoff_enabled: true,
@@ -681,6 +689,9 @@ fn configure_solver3(input: &mut ApiInput, mode: Solver3ConstructionMode) {
             time_limit_seconds: Some(1),
             no_improvement_iterations: None,
             stop_on_optimal_score: false,
+            stop_on_feasible: false,
+            feasible_score_threshold: None,
+            convergence: None,
         },
         solver_params: SolverParams::Solver3(params),
         logging: Default::default(),
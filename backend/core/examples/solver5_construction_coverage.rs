@@ -728,15 +728,20 @@ fn pure_sgp_input(groups: usize, group_size: usize, weeks: usize) -> ApiInput {
                     id: format!("g{idx}"),
                     size: group_size as u32,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 })
                 .collect(),
             num_sessions: weeks as u32,
         },
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![Constraint::RepeatEncounter(RepeatEncounterParams {
             max_allowed_encounters: 1,
@@ -750,6 +755,9 @@ fn pure_sgp_input(groups: usize, group_size: usize, weeks: usize) -> ApiInput {
                 time_limit_seconds: Some(1),
                 no_improvement_iterations: None,
                 stop_on_optimal_score: true,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::Solver5(gm_core::models::Solver5Params::default()),
             logging: Default::default(),
@@ -0,0 +1,260 @@
+//! Fluent builders for [`ApiInput`] and [`SolverConfiguration`].
+//!
+//! The builders fill in the fields most callers leave at their defaults, so a small problem
+//! fits on a few lines:
+//!
+//! ```no_run
+//! use gm_core::models::ApiInput;
+//!
+//! let input = ApiInput::builder()
+//!     .people(["alice", "bob", "carol", "dave"])
+//!     .group("t1", 2)
+//!     .group("t2", 2)
+//!     .sessions(3)
+//!     .build();
+//! let result = gm_core::run_solver(&input).expect("solve should succeed");
+//! # let _ = result;
+//! ```
+
+use crate::default_solver_configuration_for;
+use crate::models::{
+    ApiInput, ApiSchedule, Constraint, ContactHistory, Group, Objective, Person, ProblemDefinition,
+    SolverConfiguration, SolverKind, TelemetryOptions,
+};
+use std::collections::HashMap;
+
+const DEFAULT_OBJECTIVE: &str = "maximize_unique_contacts";
+
+impl ApiInput {
+    /// Starts building an input with no people, groups, or sessions.
+    pub fn builder() -> ProblemBuilder {
+        ProblemBuilder::default()
+    }
+}
+
+impl SolverConfiguration {
+    /// Starts from the default configuration of `kind`.
+    pub fn builder(kind: SolverKind) -> SolverConfigBuilder {
+        SolverConfigBuilder {
+            config: default_solver_configuration_for(kind),
+        }
+    }
+}
+
+/// Builds an [`ApiInput`] step by step.
+///
+/// Unless overridden, the input maximizes unique contacts with weight `1.0` and uses
+/// [`default_solver_configuration`](crate::default_solver_configuration).
+#[derive(Debug, Clone, Default)]
+pub struct ProblemBuilder {
+    people: Vec<Person>,
+    groups: Vec<Group>,
+    num_sessions: u32,
+    objectives: Vec<Objective>,
+    constraints: Vec<Constraint>,
+    initial_schedule: Option<ApiSchedule>,
+    contact_history: Option<ContactHistory>,
+    solver: SolverConfiguration,
+}
+
+impl ProblemBuilder {
+    /// Adds a person without attributes who attends every session.
+    pub fn person(self, id: impl Into<String>) -> Self {
+        self.person_with_attributes(id, HashMap::<String, String>::new())
+    }
+
+    /// Adds a person with the given attributes who attends every session.
+    pub fn person_with_attributes<K, V>(
+        self,
+        id: impl Into<String>,
+        attributes: impl IntoIterator<Item = (K, V)>,
+    ) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.add_person(Person {
+            id: id.into(),
+            attributes: attributes
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+            sessions: None,
+        })
+    }
+
+    /// Adds one person per id, each without attributes.
+    pub fn people<I>(self, ids: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        ids.into_iter().fold(self, Self::person)
+    }
+
+    /// Adds a fully specified person.
+    pub fn add_person(mut self, person: Person) -> Self {
+        self.people.push(person);
+        self
+    }
+
+    /// Adds a group of `size` that is open in every session.
+    pub fn group(self, id: impl Into<String>, size: u32) -> Self {
+        self.add_group(Group {
+            id: id.into(),
+            size,
+            session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
+        })
+    }
+
+    /// Adds a fully specified group.
+    pub fn add_group(mut self, group: Group) -> Self {
+        self.groups.push(group);
+        self
+    }
+
+    /// Sets the number of sessions.
+    pub fn sessions(mut self, num_sessions: u32) -> Self {
+        self.num_sessions = num_sessions;
+        self
+    }
+
+    /// Adds an objective such as `"maximize_unique_contacts"`, replacing the default one.
+    pub fn objective(self, objective_type: impl Into<String>, weight: f64) -> Self {
+        self.add_objective(Objective {
+            r#type: objective_type.into(),
+            weight,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
+        })
+    }
+
+    /// Adds a fully specified objective, replacing the default one.
+    pub fn add_objective(mut self, objective: Objective) -> Self {
+        self.objectives.push(objective);
+        self
+    }
+
+    /// Adds a constraint.
+    pub fn constraint(mut self, constraint: Constraint) -> Self {
+        self.constraints.push(constraint);
+        self
+    }
+
+    /// Warm-starts the search from `schedule`.
+    pub fn initial_schedule(mut self, schedule: ApiSchedule) -> Self {
+        self.initial_schedule = Some(schedule);
+        self
+    }
+
+    /// Counts meetings from earlier events toward repeat penalties.
+    pub fn contact_history(mut self, history: ContactHistory) -> Self {
+        self.contact_history = Some(history);
+        self
+    }
+
+    /// Replaces the solver configuration, for example with one from [`SolverConfigBuilder`].
+    pub fn solver(mut self, solver: SolverConfiguration) -> Self {
+        self.solver = solver;
+        self
+    }
+
+    /// Finishes the input. It is validated when solved, not here.
+    pub fn build(self) -> ApiInput {
+        let objectives = if self.objectives.is_empty() {
+            vec![Objective {
+                r#type: DEFAULT_OBJECTIVE.to_string(),
+                weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
+            }]
+        } else {
+            self.objectives
+        };
+
+        ApiInput {
+            problem: ProblemDefinition {
+                people: self.people,
+                groups: self.groups,
+                num_sessions: self.num_sessions,
+            },
+            initial_schedule: self.initial_schedule,
+            construction_seed_schedule: None,
+            contact_history: self.contact_history,
+            objectives,
+            constraints: self.constraints,
+            solver: self.solver,
+        }
+    }
+}
+
+/// Adjusts a [`SolverConfiguration`] starting from a solver family's defaults.
+///
+/// # Example
+///
+/// ```
+/// use gm_core::models::{SolverConfiguration, SolverKind};
+///
+/// let config = SolverConfiguration::builder(SolverKind::Solver1)
+///     .seed(7)
+///     .max_iterations(5_000)
+///     .time_limit_seconds(2)
+///     .build();
+/// assert_eq!(config.seed, Some(7));
+/// assert_eq!(config.stop_conditions.max_iterations, Some(5_000));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SolverConfigBuilder {
+    config: SolverConfiguration,
+}
+
+impl SolverConfigBuilder {
+    /// Fixes the random seed so runs are reproducible.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.config.seed = Some(seed);
+        self
+    }
+
+    /// Stops after `max_iterations` iterations.
+    pub fn max_iterations(mut self, max_iterations: u64) -> Self {
+        self.config.stop_conditions.max_iterations = Some(max_iterations);
+        self
+    }
+
+    /// Stops after `seconds` of wall-clock time.
+    pub fn time_limit_seconds(mut self, seconds: u64) -> Self {
+        self.config.stop_conditions.time_limit_seconds = Some(seconds);
+        self
+    }
+
+    /// Stops after `iterations` iterations without improving the best score.
+    pub fn no_improvement_iterations(mut self, iterations: u64) -> Self {
+        self.config.stop_conditions.no_improvement_iterations = Some(iterations);
+        self
+    }
+
+    /// Whether to stop as soon as a perfect score is reached.
+    pub fn stop_on_optimal_score(mut self, stop: bool) -> Self {
+        self.config.stop_conditions.stop_on_optimal_score = stop;
+        self
+    }
+
+    /// Replaces the telemetry options.
+    pub fn telemetry(mut self, telemetry: TelemetryOptions) -> Self {
+        self.config.telemetry = telemetry;
+        self
+    }
+
+    /// Restricts the search to the given 0-based sessions.
+    pub fn allowed_sessions(mut self, sessions: Vec<u32>) -> Self {
+        self.config.allowed_sessions = Some(sessions);
+        self
+    }
+
+    /// Finishes the configuration.
+    pub fn build(self) -> SolverConfiguration {
+        self.config
+    }
+}
@@ -0,0 +1,268 @@
+//! Building the people list from a CSV export.
+//!
+//! The first non-blank row is the header. It must contain an `id` column and may contain a
+//! `sessions` column; every other column becomes a person attribute:
+//!
+//! ```text
+//! id,team,role,sessions
+//! alice,red,lead,
+//! bob,blue,,0;2
+//! "Smith, Carol",red,member,1
+//! ```
+//!
+//! Fields follow RFC 4180: they may be quoted, `""` escapes a quote inside a quoted field,
+//! and quoted fields may span lines. Whitespace around unquoted fields is trimmed. An empty
+//! attribute cell leaves the attribute unset, and an empty `sessions` cell means every session.
+//! Session lists are separated by `;`, `,` or whitespace.
+
+use crate::models::Person;
+use std::collections::{HashMap, HashSet};
+
+const ID_COLUMN: &str = "id";
+const SESSIONS_COLUMN: &str = "sessions";
+
+/// Error importing a CSV document, pointing at the offending line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvImportError {
+    /// 1-based line of the file on which the offending row starts
+    pub line: usize,
+    /// Header name of the offending column, if the error concerns a single cell
+    pub column: Option<String>,
+    /// What is wrong
+    pub message: String,
+}
+
+impl std::fmt::Display for CsvImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}", self.line)?;
+        if let Some(column) = &self.column {
+            write!(f, ", column '{column}'")?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+impl std::error::Error for CsvImportError {}
+
+impl CsvImportError {
+    fn new(line: usize, column: Option<&str>, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column: column.map(str::to_string),
+            message: message.into(),
+        }
+    }
+}
+
+/// Reads people from `csv`, with an `id` column, an optional `sessions` column, and one
+/// attribute per remaining column.
+///
+/// Ids must be non-empty and unique, and every row must have as many fields as the header.
+///
+/// # Example
+///
+/// ```no_run
+/// use gm_core::csv_import::people_from_csv;
+///
+/// let people = people_from_csv("id,team\nalice,red\nbob,blue\n").expect("valid CSV");
+/// assert_eq!(people[1].attributes["team"], "blue");
+/// ```
+pub fn people_from_csv(csv: &str) -> Result<Vec<Person>, CsvImportError> {
+    people_from_rows(
+        parse_rows(csv.strip_prefix('\u{feff}').unwrap_or(csv))?,
+        "line",
+    )
+}
+
+/// Builds people from rows of trimmed fields, each paired with the 1-based line (or
+/// spreadsheet row, named by `unit` in messages) it starts on. The first row is the header.
+pub(crate) fn people_from_rows(
+    rows: Vec<(usize, Vec<String>)>,
+    unit: &str,
+) -> Result<Vec<Person>, CsvImportError> {
+    let mut rows = rows.into_iter();
+    let Some((header_line, header)) = rows.next() else {
+        return Err(CsvImportError::new(1, None, "missing header row"));
+    };
+
+    let mut seen_columns = HashSet::new();
+    for name in &header {
+        if name.is_empty() {
+            return Err(CsvImportError::new(header_line, None, "empty column name"));
+        }
+        if !seen_columns.insert(name.as_str()) {
+            return Err(CsvImportError::new(
+                header_line,
+                Some(name),
+                "duplicate column name",
+            ));
+        }
+    }
+    let id_index = header
+        .iter()
+        .position(|name| name == ID_COLUMN)
+        .ok_or_else(|| CsvImportError::new(header_line, None, "missing 'id' column"))?;
+    let sessions_index = header.iter().position(|name| name == SESSIONS_COLUMN);
+
+    let mut people = Vec::new();
+    let mut line_by_id: HashMap<String, usize> = HashMap::new();
+    for (line, fields) in rows {
+        if fields.len() != header.len() {
+            return Err(CsvImportError::new(
+                line,
+                None,
+                format!(
+                    "expected {} fields as in the header, found {}",
+                    header.len(),
+                    fields.len()
+                ),
+            ));
+        }
+
+        let id = fields[id_index].clone();
+        if id.is_empty() {
+            return Err(CsvImportError::new(
+                line,
+                Some(ID_COLUMN),
+                "empty person id",
+            ));
+        }
+        if let Some(first_line) = line_by_id.insert(id.clone(), line) {
+            return Err(CsvImportError::new(
+                line,
+                Some(ID_COLUMN),
+                format!("duplicate person id '{id}', first used on {unit} {first_line}"),
+            ));
+        }
+
+        let sessions = match sessions_index {
+            Some(index) => parse_sessions(&fields[index], line)?,
+            None => None,
+        };
+        let attributes = header
+            .iter()
+            .zip(fields)
+            .enumerate()
+            .filter(|(index, (_, value))| {
+                *index != id_index && Some(*index) != sessions_index && !value.is_empty()
+            })
+            .map(|(_, (name, value))| (name.clone(), value))
+            .collect();
+
+        people.push(Person {
+            id,
+            attributes,
+            sessions,
+        });
+    }
+    Ok(people)
+}
+
+pub(crate) fn parse_sessions(cell: &str, line: usize) -> Result<Option<Vec<u32>>, CsvImportError> {
+    if cell.is_empty() {
+        return Ok(None);
+    }
+    cell.split(|c: char| c == ';' || c == ',' || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+        .map(|token| {
+            token.parse::<u32>().map_err(|_| {
+                CsvImportError::new(
+                    line,
+                    Some(SESSIONS_COLUMN),
+                    format!("'{token}' is not a session index"),
+                )
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(Some)
+}
+
+/// Splits `csv` into trimmed fields, returning each non-blank row with its starting line.
+fn parse_rows(csv: &str) -> Result<Vec<(usize, Vec<String>)>, CsvImportError> {
+    let mut rows = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut quoted = false;
+    let mut line = 1;
+    let mut row_line = 1;
+    let mut chars = csv.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                '\n' => {
+                    line += 1;
+                    field.push(c);
+                }
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' if field.trim().is_empty() && !quoted => {
+                field.clear();
+                in_quotes = true;
+                quoted = true;
+            }
+            '"' => {
+                return Err(CsvImportError::new(
+                    line,
+                    None,
+                    "unexpected quote inside an unquoted field",
+                ));
+            }
+            ',' => end_field(&mut field, &mut quoted, &mut fields),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => {
+                end_field(&mut field, &mut quoted, &mut fields);
+                push_row(&mut rows, row_line, std::mem::take(&mut fields));
+                line += 1;
+                row_line = line;
+            }
+            _ if quoted => {
+                if !c.is_whitespace() {
+                    return Err(CsvImportError::new(
+                        line,
+                        None,
+                        "unexpected text after a closing quote",
+                    ));
+                }
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(CsvImportError::new(
+            row_line,
+            None,
+            "unterminated quoted field",
+        ));
+    }
+    end_field(&mut field, &mut quoted, &mut fields);
+    push_row(&mut rows, row_line, fields);
+    Ok(rows)
+}
+
+/// Ends the current field; quoted fields keep their whitespace.
+fn end_field(field: &mut String, quoted: &mut bool, fields: &mut Vec<String>) {
+    let value = std::mem::take(field);
+    fields.push(if *quoted {
+        value
+    } else {
+        value.trim().to_string()
+    });
+    *quoted = false;
+}
+
+fn push_row(rows: &mut Vec<(usize, Vec<String>)>, line: usize, fields: Vec<String>) {
+    let blank = fields.len() == 1 && fields[0].is_empty();
+    if !blank {
+        rows.push((line, fields));
+    }
+}
@@ -1,12 +1,14 @@
 use crate::models::{
-    ApiInput, AutoSolveTelemetry, AutoSolverParams, BenchmarkEvent, BenchmarkObserver, Constraint,
-    LoggingOptions, Objective, ProblemDefinition, ProgressCallback, SimulatedAnnealingParams,
-    Solver3ConstructionMode, Solver3Params, Solver4Params, Solver5Params, Solver6Params,
+    ApiInput, ApiSchedule, AutoConstructorOutcome, AutoSolveTelemetry, AutoSolverParams,
+    BenchmarkEvent, BenchmarkObserver, Constraint, LoggingOptions, Objective, ProblemDefinition,
+    ProgressHook, RecommendationReport, SimulatedAnnealingParams, Solver3ConstructionMode,
+    Solver3Params, Solver4Params, Solver5Params, Solver6Params, SolverCheckpoint,
     SolverConfiguration, SolverKind, SolverParams, SolverResult, StopConditions,
     DEFAULT_SOLVER_KIND,
 };
 use crate::runtime_target::runtime_target_iteration_cap;
-use crate::solver1::search::simulated_annealing::SimulatedAnnealing;
+use crate::solver1::search::recommendation::recommend_settings as solver1_recommend_settings;
+use crate::solver1::search::simulated_annealing::{ResumePoint, SimulatedAnnealing};
 use crate::solver1::search::Solver as _;
 use crate::solver1::State;
 use crate::solver3::runtime_state::AutoConstructionPolicy;
@@ -15,6 +17,9 @@ use crate::solver4::{SearchEngine as Solver4SearchEngine, SOLVER4_NOTES};
 use crate::solver5::{SearchEngine as Solver5SearchEngine, SOLVER5_NOTES};
 use crate::solver6::{SearchEngine as Solver6SearchEngine, SOLVER6_NOTES};
 use crate::solver_support::complexity::evaluate_problem_complexity;
+use crate::solver_support::contact_bound::{optimality_gap, unique_contacts_upper_bound};
+use crate::solver_support::contact_counts::schedule_contact_counts;
+use crate::solver_support::memory_estimate::check_memory_limit;
 use crate::solver_support::SolverError;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -37,7 +42,7 @@ pub struct SolverDescriptor {
 #[derive(Clone, Copy)]
 pub struct SolveRequest<'a> {
     pub input: &'a ApiInput,
-    pub progress_callback: Option<&'a ProgressCallback>,
+    pub progress_callback: Option<ProgressHook<'a>>,
     pub benchmark_observer: Option<&'a BenchmarkObserver>,
 }
 
@@ -57,6 +62,26 @@ pub trait SolverEngine {
         &self,
         request: RecommendationRequest<'_>,
     ) -> Result<SolverConfiguration, SolverError>;
+
+    /// The recommended configuration with the measurements and reasoning behind it.
+    ///
+    /// Families that do not probe the scenario report the configuration alone.
+    fn recommendation_report(
+        &self,
+        request: RecommendationRequest<'_>,
+    ) -> Result<RecommendationReport, SolverError> {
+        Ok(RecommendationReport {
+            configuration: self.recommend_configuration(request)?,
+            delta_distribution: None,
+            trials: Vec::new(),
+            iterations_per_second: None,
+            reasoning: vec![format!(
+                "{} does not tune its settings to the scenario; the configuration is its default \
+                 sized for the requested runtime.",
+                self.descriptor().display_name
+            )],
+        })
+    }
 }
 
 const SOLVER1_DESCRIPTOR: SolverDescriptor = SolverDescriptor {
@@ -82,7 +107,7 @@ const AUTO_DESCRIPTOR: SolverDescriptor = SolverDescriptor {
         supports_recommended_settings: true,
         supports_deterministic_seed: true,
     },
-    notes: "Product-default solve policy: always runs solver3 with complexity-derived runtime, bounded constraint-scenario oracle construction, explicit baseline fallback, and runtime-scaled search stopping.",
+    notes: "Product-default solve policy: runs solver3 with complexity-derived runtime, bounded constraint-scenario oracle construction, explicit baseline fallback, and runtime-scaled search stopping; inputs with contact_history run on solver1 with the same runtime budget.",
 };
 
 const SOLVER3_DESCRIPTOR: SolverDescriptor = SolverDescriptor {
@@ -160,6 +185,9 @@ impl SolverEngine for AutoEngine {
 
     fn solve(&self, request: SolveRequest<'_>) -> Result<SolverResult, SolverError> {
         let plan = AutoSolvePlan::from_input(request.input)?;
+        if request.input.contact_history.is_some() {
+            return solve_auto_with_solver1(request, &plan);
+        }
         let solver3_input = auto_solver3_input(request.input, &plan);
         let construction = crate::solver3::RuntimeState::from_input_with_auto_construction(
             &solver3_input,
@@ -193,6 +221,7 @@ impl SolverEngine for AutoEngine {
         ) {
             observer(&BenchmarkEvent::RunCompleted(telemetry));
         }
+        result.effective_configuration = Some(solver3_input.solver);
         Ok(result)
     }
 
@@ -204,6 +233,9 @@ impl SolverEngine for AutoEngine {
                 time_limit_seconds: None,
                 no_improvement_iterations: None,
                 stop_on_optimal_score: true,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::Auto(AutoSolverParams::default()),
             logging: LoggingOptions::default(),
@@ -246,6 +278,9 @@ impl SolverEngine for Solver1Engine {
                 time_limit_seconds: Some(30),
                 no_improvement_iterations: None,
                 stop_on_optimal_score: true,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
                 initial_temperature: 1.0,
@@ -253,6 +288,7 @@ impl SolverEngine for Solver1Engine {
                 cooling_schedule: "geometric".into(),
                 reheat_cycles: Some(0),
                 reheat_after_no_improvement: Some(0),
+                penalty_ramp: None,
             }),
             logging: LoggingOptions {
                 log_frequency: Some(1000),
@@ -275,10 +311,20 @@ impl SolverEngine for Solver1Engine {
         &self,
         request: RecommendationRequest<'_>,
     ) -> Result<SolverConfiguration, SolverError> {
-        Ok(runtime_target_configuration(
-            self.default_configuration(),
-            request.desired_runtime_seconds,
-        ))
+        Ok(self.recommendation_report(request)?.configuration)
+    }
+
+    fn recommendation_report(
+        &self,
+        request: RecommendationRequest<'_>,
+    ) -> Result<RecommendationReport, SolverError> {
+        solver1_recommend_settings(
+            runtime_target_configuration(
+                self.default_configuration(),
+                request.desired_runtime_seconds,
+            ),
+            request,
+        )
     }
 }
 
@@ -305,6 +351,9 @@ impl SolverEngine for Solver3Engine {
                 time_limit_seconds: Some(30),
                 no_improvement_iterations: Some(5_000),
                 stop_on_optimal_score: true,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::Solver3(Solver3Params::default()),
             logging: LoggingOptions::default(),
@@ -344,6 +393,9 @@ impl SolverEngine for Solver4Engine {
                 time_limit_seconds: Some(30),
                 no_improvement_iterations: None,
                 stop_on_optimal_score: true,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::Solver4(Solver4Params::default()),
             logging: LoggingOptions::default(),
@@ -383,6 +435,9 @@ impl SolverEngine for Solver5Engine {
                 time_limit_seconds: Some(1),
                 no_improvement_iterations: None,
                 stop_on_optimal_score: true,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::Solver5(Solver5Params::default()),
             logging: LoggingOptions::default(),
@@ -419,6 +474,9 @@ impl SolverEngine for Solver6Engine {
                 time_limit_seconds: Some(30),
                 no_improvement_iterations: Some(100_000),
                 stop_on_optimal_score: true,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::Solver6(Solver6Params::default()),
             logging: LoggingOptions::default(),
@@ -540,6 +598,9 @@ fn auto_solver3_input(input: &ApiInput, plan: &AutoSolvePlan) -> ApiInput {
             time_limit_seconds: Some(plan.search_budget_seconds.ceil().max(1.0) as u64),
             no_improvement_iterations: None,
             stop_on_optimal_score: true,
+            stop_on_feasible: false,
+            feasible_score_threshold: None,
+            convergence: None,
         },
         solver_params: SolverParams::Solver3(params),
         logging: input.solver.logging.clone(),
@@ -555,12 +616,75 @@ fn auto_solver3_input(input: &ApiInput, plan: &AutoSolvePlan) -> ApiInput {
         problem: input.problem.clone(),
         initial_schedule: input.initial_schedule.clone(),
         construction_seed_schedule: input.construction_seed_schedule.clone(),
+        contact_history: None,
         objectives: input.objectives.clone(),
         constraints: input.constraints.clone(),
         solver,
     }
 }
 
+/// Runs an auto solve on solver1, the only family that models `contact_history`.
+///
+/// solver1 has no budgeted constructor, so the whole auto budget goes to its search.
+fn solve_auto_with_solver1(
+    request: SolveRequest<'_>,
+    plan: &AutoSolvePlan,
+) -> Result<SolverResult, SolverError> {
+    let mut solver = Solver1Engine.default_configuration();
+    solver.stop_conditions = StopConditions {
+        max_iterations: Some(AUTO_SOLVER_MAX_ITERATIONS),
+        time_limit_seconds: Some(plan.total_budget_seconds.ceil().max(1.0) as u64),
+        stop_on_optimal_score: request.input.solver.stop_conditions.stop_on_optimal_score,
+        ..solver.stop_conditions
+    };
+    solver.logging = request.input.solver.logging.clone();
+    solver.telemetry = request.input.solver.telemetry.clone();
+    solver.seed = request.input.solver.seed;
+    solver.move_policy = request.input.solver.move_policy.clone();
+    solver.allowed_sessions = request.input.solver.allowed_sessions.clone();
+    let solver1_input = ApiInput {
+        solver,
+        ..request.input.clone()
+    };
+
+    let mut state = State::new(&solver1_input)?;
+    let mut result = SimulatedAnnealing::new(&solver1_input.solver).solve(
+        &mut state,
+        request.progress_callback,
+        None,
+    )?;
+
+    if let Some(telemetry) = result.benchmark_telemetry.as_mut() {
+        telemetry.auto = Some(AutoSolveTelemetry {
+            selected_solver: SolverKind::Solver1,
+            complexity_model_version: plan.complexity_model_version.clone(),
+            complexity_score: plan.complexity_score,
+            total_budget_seconds: plan.total_budget_seconds,
+            oracle_construction_budget_seconds: 0.0,
+            scaffold_budget_seconds: 0.0,
+            oracle_recombination_budget_seconds: 0.0,
+            search_budget_seconds: plan.total_budget_seconds,
+            constructor_attempt: "solver1_contact_history".to_string(),
+            constructor_outcome: if solver1_input.initial_schedule.is_some() {
+                AutoConstructorOutcome::InitialSchedule
+            } else {
+                AutoConstructorOutcome::Unsupported
+            },
+            constructor_fallback_used: false,
+            constructor_failure: None,
+            constructor_wall_seconds: telemetry.initialization_seconds,
+        });
+    }
+    if let (Some(observer), Some(telemetry)) = (
+        request.benchmark_observer,
+        result.benchmark_telemetry.clone(),
+    ) {
+        observer(&BenchmarkEvent::RunCompleted(telemetry));
+    }
+    result.effective_configuration = Some(solver1_input.solver);
+    Ok(result)
+}
+
 fn auto_complexity_wall_time_seconds(complexity: f64) -> u64 {
     let complexity = if complexity.is_finite() {
         complexity.max(0.0)
@@ -609,7 +733,190 @@ pub fn run_solver_with_engine(request: SolveRequest<'_>) -> Result<SolverResult,
         .solver
         .validate_solver_selection()
         .map_err(SolverError::ValidationError)?;
-    create_solver_engine(kind).solve(request)
+    check_memory_limit(request.input)?;
+    if kind == SolverKind::Solver1 {
+        let result = create_solver_engine(kind).solve(request)?;
+        return Ok(finish_result(request.input, result));
+    }
+
+    // Only solver1 can save checkpoints or reheat on request; elsewhere such an answer stops
+    // the run and is reported instead of being ignored.
+    let refused = std::sync::Mutex::new(None);
+    let progress_callback = match request.progress_callback {
+        Some(ProgressHook::Control(callback)) => Some(ProgressHook::ContinueOrStop {
+            callback,
+            refused: &refused,
+        }),
+        other => other,
+    };
+    let result = create_solver_engine(kind).solve(SolveRequest {
+        progress_callback,
+        ..request
+    })?;
+    if let Some(control) = refused
+        .into_inner()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+    {
+        return Err(SolverError::ValidationError(format!(
+            "{} does not support ProgressControl::{control:?}; use solver1 to save checkpoints \
+             or reheat on request",
+            kind.canonical_id()
+        )));
+    }
+    Ok(finish_result(request.input, result))
+}
+
+/// Continues a solver1 run from a checkpoint that an earlier run of `request.input` emitted.
+pub fn resume_from_checkpoint_with_engine(
+    request: SolveRequest<'_>,
+    checkpoint: &SolverCheckpoint,
+) -> Result<SolverResult, SolverError> {
+    let kind = request
+        .input
+        .solver
+        .validate_solver_selection()
+        .map_err(SolverError::ValidationError)?;
+    check_memory_limit(request.input)?;
+    if kind != SolverKind::Solver1 {
+        return Err(SolverError::ValidationError(format!(
+            "resuming from a checkpoint is only supported by solver1, not {}",
+            kind.canonical_id()
+        )));
+    }
+
+    let state_for = |schedule: &ApiSchedule| {
+        let mut input = request.input.clone();
+        input.initial_schedule = Some(schedule.clone());
+        input.construction_seed_schedule = None;
+        input.solver.seed = Some(checkpoint.effective_seed);
+        if let Some(move_policy) = &checkpoint.move_policy {
+            input.solver.move_policy = Some(move_policy.clone());
+        }
+        State::new(&input)
+    };
+    let resume = ResumePoint {
+        current_state: state_for(&checkpoint.current_schedule)?,
+        best_state: state_for(&checkpoint.best_schedule)?,
+        checkpoint: checkpoint.clone(),
+    };
+    let result = SimulatedAnnealing::new(&request.input.solver).resume(
+        resume,
+        request.progress_callback,
+        request.benchmark_observer,
+    )?;
+    Ok(finish_result(request.input, result))
+}
+
+/// Adds the engine-independent parts of a result.
+fn finish_result(input: &ApiInput, mut result: SolverResult) -> SolverResult {
+    if result.constraint_violations.is_none() || result.session_breakdown.is_none() {
+        rescore_result_diagnostics(input, &mut result);
+    }
+    if input.solver.telemetry.include_contact_matrix {
+        result.contact_matrix = Some(schedule_contact_counts(&result.schedule));
+    }
+    if input.solver.telemetry.include_display_names {
+        result.display_names = Some(
+            input
+                .problem
+                .people
+                .iter()
+                .filter_map(|person| {
+                    let name = person.attributes.get("name")?;
+                    Some((person.id.clone(), name.clone()))
+                })
+                .collect(),
+        );
+    }
+    let echoed_attributes = &input.solver.telemetry.include_person_attributes;
+    if !echoed_attributes.is_empty() {
+        result.person_attributes = Some(
+            input
+                .problem
+                .people
+                .iter()
+                .map(|person| {
+                    let attributes = echoed_attributes
+                        .iter()
+                        .filter_map(|key| Some((key.clone(), person.attributes.get(key)?.clone())))
+                        .collect();
+                    (person.id.clone(), attributes)
+                })
+                .collect(),
+        );
+    }
+    result.effective_configuration = Some(effective_configuration(&input.solver, &result));
+    let bound = unique_contacts_upper_bound(input);
+    result.unique_contacts_upper_bound = Some(bound);
+    result.optimality_gap = Some(optimality_gap(result.unique_contacts, bound));
+    result
+}
+
+/// Resolves the configuration a run actually used from its input and result.
+///
+/// Engines that run a configuration of their own (auto) leave it in
+/// `result.effective_configuration`; otherwise the input's is used. The effective seed and
+/// move policy are filled in, and so is the `max_iterations` an engine falls back to when the
+/// stop conditions leave it unset.
+fn effective_configuration(
+    configuration: &SolverConfiguration,
+    result: &SolverResult,
+) -> SolverConfiguration {
+    let mut effective = result
+        .effective_configuration
+        .clone()
+        .unwrap_or_else(|| configuration.clone());
+    if let Some(seed) = result.effective_seed {
+        effective.seed = Some(seed);
+    }
+    if let Some(move_policy) = &result.move_policy {
+        effective.move_policy = Some(move_policy.clone());
+    }
+    let default_max_iterations = match effective.solver_params.solver_kind() {
+        SolverKind::Solver1 => {
+            Some(crate::solver1::search::simulated_annealing::DEFAULT_MAX_ITERATIONS)
+        }
+        SolverKind::Solver3 => Some(crate::solver3::search::DEFAULT_MAX_ITERATIONS),
+        SolverKind::Solver6 => Some(crate::solver6::DEFAULT_MAX_ITERATIONS),
+        SolverKind::Auto | SolverKind::Solver4 | SolverKind::Solver5 => None,
+    };
+    if let Some(default_max_iterations) = default_max_iterations {
+        effective
+            .stop_conditions
+            .max_iterations
+            .get_or_insert(default_max_iterations);
+    }
+    effective
+}
+
+/// Fills in the violation list and session breakdown of a result from an engine that does
+/// not produce them, by loading its final schedule into a solver1 state. Leaves them `None`
+/// and logs a warning if the schedule cannot be loaded.
+fn rescore_result_diagnostics(input: &ApiInput, result: &mut SolverResult) {
+    let mut rescoring_input = input.clone();
+    rescoring_input.initial_schedule = Some(result.schedule.clone());
+    rescoring_input.construction_seed_schedule = None;
+    let mut solver = default_solver_configuration_for(SolverKind::Solver1);
+    solver.seed = input.solver.seed;
+    rescoring_input.solver = solver;
+
+    let state = match State::new(&rescoring_input) {
+        Ok(state) => state,
+        Err(error) => {
+            tracing::warn!(
+                solver = %input.solver.solver_type,
+                %error,
+                "result schedule could not be rescored; constraint_violations and session_breakdown are left empty"
+            );
+            return;
+        }
+    };
+    let violations = result
+        .constraint_violations
+        .get_or_insert_with(|| state.constraint_violations());
+    if result.session_breakdown.is_none() {
+        result.session_breakdown = Some(state.session_breakdown(violations));
+    }
 }
 
 pub fn default_solver_configuration_for(kind: SolverKind) -> SolverConfiguration {
@@ -623,6 +930,13 @@ pub fn calculate_recommended_settings_for(
     create_solver_engine(kind).recommend_configuration(request)
 }
 
+pub fn calculate_recommendation_report_for(
+    kind: SolverKind,
+    request: RecommendationRequest<'_>,
+) -> Result<RecommendationReport, SolverError> {
+    create_solver_engine(kind).recommendation_report(request)
+}
+
 fn create_solver_engine(kind: SolverKind) -> Box<dyn SolverEngine> {
     match kind {
         SolverKind::Auto => Box::new(AutoEngine),
@@ -673,6 +987,8 @@ mod tests {
                 id: "g0".to_string(),
                 size: 2,
                 session_sizes: None,
+                attributes: HashMap::new(),
+                available_sessions: None,
             }],
             num_sessions: 1,
         }
@@ -707,11 +1023,15 @@ mod tests {
                     id: "g0".to_string(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g1".to_string(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 2,
@@ -731,7 +1051,8 @@ mod tests {
         let descriptor = solver_descriptor(default_solver_kind());
         assert_eq!(descriptor.kind, SolverKind::Auto);
         assert!(descriptor.capabilities.supports_recommended_settings);
-        assert!(descriptor.notes.contains("always runs solver3"));
+        assert!(descriptor.notes.contains("runs solver3"));
+        assert!(descriptor.notes.contains("contact_history run on solver1"));
     }
 
     #[test]
@@ -754,6 +1075,7 @@ mod tests {
         let input = ApiInput {
             initial_schedule: None,
             construction_seed_schedule: None,
+            contact_history: None,
             problem: simple_problem(),
             objectives: vec![],
             constraints: vec![],
@@ -777,6 +1099,7 @@ mod tests {
         let input = ApiInput {
             initial_schedule: None,
             construction_seed_schedule: None,
+            contact_history: None,
             problem: simple_problem(),
             objectives: vec![],
             constraints: vec![],
@@ -802,6 +1125,7 @@ mod tests {
         let input = ApiInput {
             initial_schedule: None,
             construction_seed_schedule: None,
+            contact_history: None,
             problem: simple_problem(),
             objectives: vec![],
             constraints: vec![],
@@ -832,15 +1156,63 @@ mod tests {
         assert!(telemetry.search_budget_seconds >= telemetry.total_budget_seconds * 0.70);
     }
 
+    #[test]
+    fn auto_run_with_contact_history_honours_it_on_solver1() {
+        use crate::models::{ContactHistory, HistoricalContact};
+        let met = |a: &str, b: &str| HistoricalContact {
+            people: vec![a.to_string(), b.to_string()],
+            count: 1,
+            events_ago: 1,
+        };
+        let input = ApiInput {
+            initial_schedule: None,
+            construction_seed_schedule: None,
+            contact_history: Some(ContactHistory {
+                contacts: vec![met("p0", "p1"), met("p2", "p3")],
+                decay_factor: 1.0,
+            }),
+            problem: pure_solver4_problem(),
+            objectives: vec![],
+            constraints: vec![solver4_repeat_constraint()],
+            solver: default_solver_configuration_for(SolverKind::Auto),
+        };
+
+        let result = run_solver_with_engine(SolveRequest {
+            input: &input,
+            progress_callback: None,
+            benchmark_observer: None,
+        })
+        .expect("auto should execute through solver1");
+
+        let telemetry = result
+            .benchmark_telemetry
+            .as_ref()
+            .and_then(|telemetry| telemetry.auto.as_ref())
+            .expect("auto telemetry should be attached");
+        assert_eq!(telemetry.selected_solver, SolverKind::Solver1);
+        assert_eq!(result.final_score, 0.0);
+        for groups in result.schedule.values() {
+            for members in groups.values() {
+                let together = |a: &str, b: &str| {
+                    members.iter().any(|id| id == a) && members.iter().any(|id| id == b)
+                };
+                assert!(!together("p0", "p1") && !together("p2", "p3"));
+            }
+        }
+    }
+
     #[test]
     fn auto_constructor_timeout_falls_back_to_baseline() {
         let input = ApiInput {
             initial_schedule: None,
             construction_seed_schedule: None,
+            contact_history: None,
             problem: simple_problem(),
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".to_string(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![],
             solver: default_solver_configuration_for(SolverKind::Solver3),
@@ -903,6 +1275,7 @@ mod tests {
         let input = ApiInput {
             initial_schedule: None,
             construction_seed_schedule: None,
+            contact_history: None,
             problem: simple_problem(),
             objectives: vec![],
             constraints: vec![],
@@ -971,10 +1344,13 @@ mod tests {
         let input = ApiInput {
             initial_schedule: None,
             construction_seed_schedule: None,
+            contact_history: None,
             problem: simple_problem(),
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".to_string(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![],
             solver: default_solver_configuration_for(SolverKind::Solver3),
@@ -1029,10 +1405,13 @@ mod tests {
         let input = ApiInput {
             initial_schedule: None,
             construction_seed_schedule: None,
+            contact_history: None,
             problem: pure_solver4_problem(),
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".to_string(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![solver4_repeat_constraint()],
             solver: default_solver_configuration_for(SolverKind::Solver4),
@@ -1088,6 +1467,7 @@ mod tests {
         let input = ApiInput {
             initial_schedule: None,
             construction_seed_schedule: None,
+            contact_history: None,
             problem: ProblemDefinition {
                 people: vec![
                     Person {
@@ -1116,11 +1496,15 @@ mod tests {
                         id: "g0".to_string(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                     Group {
                         id: "g1".to_string(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                 ],
                 num_sessions: 3,
@@ -1128,6 +1512,8 @@ mod tests {
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".to_string(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![solver4_repeat_constraint()],
             solver: default_solver_configuration_for(SolverKind::Solver5),
@@ -1184,6 +1570,7 @@ mod tests {
         let input = ApiInput {
             initial_schedule: None,
             construction_seed_schedule: None,
+            contact_history: None,
             problem: ProblemDefinition {
                 people: vec![
                     Person {
@@ -1212,11 +1599,15 @@ mod tests {
                         id: "g0".to_string(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                     Group {
                         id: "g1".to_string(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                 ],
                 num_sessions: 3,
@@ -1224,6 +1615,8 @@ mod tests {
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".to_string(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![solver4_repeat_constraint()],
             solver: default_solver_configuration_for(SolverKind::Solver6),
@@ -1263,6 +1656,7 @@ mod tests {
             let input = ApiInput {
                 initial_schedule: None,
                 construction_seed_schedule: None,
+                contact_history: None,
                 problem,
                 objectives: vec![],
                 constraints,
@@ -1295,6 +1689,7 @@ mod tests {
         let input = ApiInput {
             initial_schedule: None,
             construction_seed_schedule: None,
+            contact_history: None,
             problem: simple_problem(),
             objectives: vec![],
             constraints: vec![],
@@ -0,0 +1,967 @@
+//! Exporting solver results to formats meant for people rather than programs.
+
+use crate::models::{
+    ApiInput, ApiSchedule, BaselineMoveLimitScope, Constraint, ConstraintViolation, DisplayOptions,
+    ItineraryStop, PairMeetingMode, Person, PersonItinerary, PersonMetrics, SessionTime,
+    SolverResult,
+};
+use crate::solver_support::contact_counts::schedule_contact_counts;
+use crate::solver_support::schedule_diff::diff_schedules;
+use crate::solver_support::SolverError;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Fill colors for attribute values in DOT exports, reused cyclically.
+const DOT_PALETTE: [&str; 8] = [
+    "#8dd3c7", "#ffffb3", "#bebada", "#fb8072", "#80b1d3", "#fdb462", "#b3de69", "#fccde5",
+];
+
+/// The groups of one session, sorted by id, with their members.
+pub(crate) type SortedGroups<'a> = Vec<(&'a str, &'a Vec<String>)>;
+
+impl SolverResult {
+    /// Formats the schedule as aligned plain-text tables, one per session.
+    ///
+    /// Equivalent to [`display_with`](Self::display_with) with no people and default
+    /// options.
+    ///
+    /// # Example Output
+    ///
+    /// ```text
+    /// === Session 0 ===
+    /// Group   Person
+    /// ------  -------
+    /// Group1  Alice
+    ///         Bob
+    /// Group2  Charlie
+    ///
+    /// === Session 1 ===
+    /// Group   Person
+    /// ------  -------
+    /// Group1  Alice
+    ///         Charlie
+    /// Group2  Bob
+    /// ```
+    pub fn display(&self) -> String {
+        self.display_with(&[], &DisplayOptions::default())
+    }
+
+    /// Formats the schedule as aligned plain-text tables, one per session, laid out by
+    /// `options`.
+    ///
+    /// Each table has a row per person, with the group named on its first row. People with a
+    /// `name` attribute in `people`, or an entry in `display_names`, are shown as
+    /// `Name (id)`. Attribute columns are looked up in `people`; people or attributes that
+    /// are missing leave an empty cell. Columns line up across all sessions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use gm_core::models::{ApiInput, DisplayOptions};
+    /// # fn example(input: &ApiInput) {
+    /// let result = gm_core::run_solver(input).expect("solve should succeed");
+    /// let options = DisplayOptions {
+    ///     attributes: vec!["team".to_string()],
+    ///     session_labels: vec!["Monday".to_string(), "Tuesday".to_string()],
+    ///     ..DisplayOptions::default()
+    /// };
+    /// println!("{}", result.display_with(&input.problem.people, &options));
+    /// # }
+    /// ```
+    pub fn display_with(&self, people: &[Person], options: &DisplayOptions) -> String {
+        let people_by_id: HashMap<&str, &Person> = people
+            .iter()
+            .map(|person| (person.id.as_str(), person))
+            .collect();
+        let mut person_labels: HashMap<&str, String> = HashMap::new();
+        let names = self.display_names.iter().flatten().chain(
+            people
+                .iter()
+                .filter_map(|person| Some((&person.id, person.attributes.get("name")?))),
+        );
+        for (person_id, name) in names {
+            person_labels.insert(person_id, format!("{name} ({person_id})"));
+        }
+
+        let header: Vec<&str> = ["Group", "Person"]
+            .into_iter()
+            .chain(options.attributes.iter().map(String::as_str))
+            .collect();
+        let tables: Vec<(usize, Vec<Vec<&str>>)> = self
+            .sorted_sessions()
+            .into_iter()
+            .map(|(session, groups)| {
+                let mut rows = Vec::new();
+                for (group_id, members) in groups {
+                    for (index, person_id) in members.iter().enumerate() {
+                        let person = people_by_id.get(person_id.as_str());
+                        let label = person_labels
+                            .get(person_id.as_str())
+                            .map_or(person_id.as_str(), String::as_str);
+                        let mut row = vec![if index == 0 { group_id } else { "" }, label];
+                        row.extend(options.attributes.iter().map(|attribute| {
+                            person
+                                .and_then(|person| person.attributes.get(attribute))
+                                .map_or("", String::as_str)
+                        }));
+                        rows.push(row);
+                    }
+                }
+                (session, rows)
+            })
+            .collect();
+
+        let widths: Vec<usize> = match options.column_width {
+            Some(width) => vec![width.max(1); header.len()],
+            None => (0..header.len())
+                .map(|column| {
+                    std::iter::once(&header)
+                        .chain(tables.iter().flat_map(|(_, rows)| rows))
+                        .map(|row| row[column].chars().count())
+                        .max()
+                        .unwrap_or(0)
+                })
+                .collect(),
+        };
+
+        let mut output = String::new();
+        for (session, rows) in &tables {
+            if !output.is_empty() {
+                output.push('\n');
+            }
+            match options
+                .session_labels
+                .get(*session)
+                .filter(|label| !label.is_empty())
+            {
+                Some(label) => output.push_str(&format!("=== {label} ===\n")),
+                None => output.push_str(&format!("=== Session {session} ===\n")),
+            }
+            if rows.is_empty() {
+                output.push_str("(no assignments)\n");
+                continue;
+            }
+            push_aligned_row(&mut output, &header, &widths);
+            let rules: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+            push_aligned_row(
+                &mut output,
+                &rules.iter().map(String::as_str).collect::<Vec<_>>(),
+                &widths,
+            );
+            for row in rows {
+                push_aligned_row(&mut output, row, &widths);
+            }
+        }
+        output
+    }
+
+    /// Formats the schedule as long-format CSV, one row per assignment.
+    ///
+    /// The header is `session,group,person` followed by `attributes`, which are looked up
+    /// in `people`; people or attributes that are missing leave an empty cell. Sessions are
+    /// written as 0-based indices, and rows are ordered by session, then group. Fields are
+    /// quoted when they contain a comma, quote, or line break.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use gm_core::models::ApiInput;
+    /// # fn example(input: &ApiInput) {
+    /// let result = gm_core::run_solver(input).expect("solve should succeed");
+    /// // session,group,person,team
+    /// // 0,g1,alice,red
+    /// let csv = result.to_csv(&input.problem.people, &["team"]);
+    /// # let _ = csv;
+    /// # }
+    /// ```
+    pub fn to_csv(&self, people: &[Person], attributes: &[&str]) -> String {
+        let people_by_id: HashMap<&str, &Person> = people
+            .iter()
+            .map(|person| (person.id.as_str(), person))
+            .collect();
+
+        let mut output = String::new();
+        let header = ["session", "group", "person"]
+            .into_iter()
+            .chain(attributes.iter().copied());
+        push_csv_row(&mut output, header);
+
+        for (session, groups) in self.sorted_sessions() {
+            let session = session.to_string();
+            for (group_id, members) in groups {
+                for person_id in members {
+                    let person = people_by_id.get(person_id.as_str());
+                    let values = attributes.iter().map(|attribute| {
+                        person
+                            .and_then(|person| person.attributes.get(*attribute))
+                            .map_or("", String::as_str)
+                    });
+                    push_csv_row(
+                        &mut output,
+                        [session.as_str(), group_id, person_id]
+                            .into_iter()
+                            .chain(values),
+                    );
+                }
+            }
+        }
+        output
+    }
+
+    /// Formats the schedule as Markdown, one table per session with a column per group.
+    ///
+    /// Sessions are headed by their 0-based index and groups are sorted by id. Shorter groups
+    /// leave empty cells at the bottom of their column, and `|` in ids is escaped.
+    ///
+    /// # Example Output
+    ///
+    /// ```text
+    /// ## Session 0
+    ///
+    /// | Group1 | Group2 |
+    /// | --- | --- |
+    /// | Alice | Diana |
+    /// | Bob |  |
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        let mut output = String::new();
+        for (session, groups) in self.sorted_sessions() {
+            if !output.is_empty() {
+                output.push('\n');
+            }
+            output.push_str(&format!("## Session {session}\n\n"));
+            if groups.is_empty() {
+                output.push_str("_No groups._\n");
+                continue;
+            }
+
+            push_markdown_row(&mut output, groups.iter().map(|(group_id, _)| *group_id));
+            push_markdown_row(&mut output, groups.iter().map(|_| "---"));
+            let rows = groups
+                .iter()
+                .map(|(_, members)| members.len())
+                .max()
+                .unwrap_or(0);
+            for row in 0..rows {
+                push_markdown_row(
+                    &mut output,
+                    groups
+                        .iter()
+                        .map(|(_, members)| members.get(row).map_or("", String::as_str)),
+                );
+            }
+        }
+        output
+    }
+
+    /// Lists, for each of `people` in order, their group in every session of the schedule.
+    ///
+    /// People who are not scheduled in a session get a stop without a group there.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use gm_core::models::ApiInput;
+    /// # fn example(input: &ApiInput) {
+    /// let result = gm_core::run_solver(input).expect("solve should succeed");
+    /// for itinerary in result.itineraries(&input.problem.people) {
+    ///     println!("{}", itinerary.to_text());
+    /// }
+    /// # }
+    /// ```
+    pub fn itineraries(&self, people: &[Person]) -> Vec<PersonItinerary> {
+        let sessions = self.sorted_sessions();
+        let mut group_by_person: HashMap<(&str, usize), &str> = HashMap::new();
+        for (session, groups) in &sessions {
+            for (group_id, members) in groups {
+                for person_id in *members {
+                    group_by_person.insert((person_id.as_str(), *session), group_id);
+                }
+            }
+        }
+
+        people
+            .iter()
+            .map(|person| PersonItinerary {
+                person_id: person.id.clone(),
+                stops: sessions
+                    .iter()
+                    .map(|(session, _)| ItineraryStop {
+                        session: *session as u32,
+                        group_id: group_by_person
+                            .get(&(person.id.as_str(), *session))
+                            .map(|group_id| group_id.to_string()),
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Formats the realized contact graph in Graphviz DOT format.
+    ///
+    /// Every person in `people` is a node, and people who shared a group are joined by an
+    /// edge labelled with the number of sessions they shared; the edge gets thicker with
+    /// each meeting. With `color_attribute`, nodes are filled by their value of that
+    /// attribute, and people without it stay white. Render with e.g. `neato -Tsvg`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use gm_core::models::ApiInput;
+    /// # fn example(input: &ApiInput) {
+    /// let result = gm_core::run_solver(input).expect("solve should succeed");
+    /// std::fs::write("contacts.dot", result.to_dot(&input.problem.people, Some("team")))
+    ///     .expect("write graph");
+    /// # }
+    /// ```
+    pub fn to_dot(&self, people: &[Person], color_attribute: Option<&str>) -> String {
+        fn value_of<'a>(person: &'a Person, attribute: Option<&str>) -> Option<&'a String> {
+            attribute.and_then(|attribute| person.attributes.get(attribute))
+        }
+        let values: BTreeSet<&String> = people
+            .iter()
+            .filter_map(|person| value_of(person, color_attribute))
+            .collect();
+        let color_of: HashMap<&String, &str> = values
+            .into_iter()
+            .zip(DOT_PALETTE.iter().cycle().copied())
+            .collect();
+
+        let mut output = String::from("graph contacts {\n");
+        output.push_str("  node [shape=ellipse, style=filled, fillcolor=\"white\"];\n");
+        for person in people {
+            output.push_str(&format!("  {}", dot_id(&person.id)));
+            if let Some(value) = value_of(person, color_attribute) {
+                output.push_str(&format!(
+                    " [fillcolor=\"{}\", tooltip={}]",
+                    color_of[value],
+                    dot_id(value)
+                ));
+            }
+            output.push_str(";\n");
+        }
+        for pair in schedule_contact_counts(&self.schedule) {
+            output.push_str(&format!(
+                "  {} -- {} [label=\"{}\", weight={}, penwidth={}];\n",
+                dot_id(&pair.person_a),
+                dot_id(&pair.person_b),
+                pair.count,
+                pair.count,
+                pair.count
+            ));
+        }
+        output.push_str("}\n");
+        output
+    }
+
+    /// Counts how many sessions each pair of `people` shared a group, as a square matrix
+    /// with rows and columns in `people` order. Diagonal entries are zero.
+    pub fn contact_matrix(&self, people: &[Person]) -> Vec<Vec<u32>> {
+        let index_of: HashMap<&str, usize> = people
+            .iter()
+            .enumerate()
+            .map(|(index, person)| (person.id.as_str(), index))
+            .collect();
+        let mut matrix = vec![vec![0; people.len()]; people.len()];
+        for pair in schedule_contact_counts(&self.schedule) {
+            if let (Some(&a), Some(&b)) = (
+                index_of.get(pair.person_a.as_str()),
+                index_of.get(pair.person_b.as_str()),
+            ) {
+                matrix[a][b] = pair.count;
+                matrix[b][a] = pair.count;
+            }
+        }
+        matrix
+    }
+
+    /// Formats [`contact_matrix`](Self::contact_matrix) as CSV, with a header row of person
+    /// ids and each row led by its person. Diagonal cells are left empty.
+    pub fn to_contact_matrix_csv(&self, people: &[Person]) -> String {
+        let mut output = String::new();
+        push_csv_row(
+            &mut output,
+            std::iter::once("person").chain(people.iter().map(|person| person.id.as_str())),
+        );
+        for (row, counts) in self.contact_matrix_cells(people, "").iter().enumerate() {
+            push_csv_row(
+                &mut output,
+                std::iter::once(people[row].id.as_str()).chain(counts.iter().map(String::as_str)),
+            );
+        }
+        output
+    }
+
+    /// Formats [`contact_matrix`](Self::contact_matrix) as an aligned plain-text table, with
+    /// `-` on the diagonal.
+    ///
+    /// # Example Output
+    ///
+    /// ```text
+    ///        alice  bob  carol
+    /// alice  -      2    0
+    /// bob    2      -    1
+    /// carol  0      1    -
+    /// ```
+    pub fn to_contact_matrix_text(&self, people: &[Person]) -> String {
+        let cells = self.contact_matrix_cells(people, "-");
+        let id_width = people
+            .iter()
+            .map(|person| person.id.chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut widths = vec![id_width];
+        widths.extend(people.iter().enumerate().map(|(column, person)| {
+            cells
+                .iter()
+                .map(|row| row[column].len())
+                .chain([person.id.chars().count()])
+                .max()
+                .unwrap_or(0)
+        }));
+
+        let mut output = String::new();
+        let header: Vec<&str> = std::iter::once("")
+            .chain(people.iter().map(|person| person.id.as_str()))
+            .collect();
+        push_aligned_row(&mut output, &header, &widths);
+        for (row, counts) in cells.iter().enumerate() {
+            let line: Vec<&str> = std::iter::once(people[row].id.as_str())
+                .chain(counts.iter().map(String::as_str))
+                .collect();
+            push_aligned_row(&mut output, &line, &widths);
+        }
+        output
+    }
+
+    /// Describes each entry of `constraint_violations` in plain language, grouped under a
+    /// heading per constraint type. Returns `None` when the result carries no violation data.
+    ///
+    /// `input` is optional: with it, repeat, pair-meeting and baseline-move lines state their
+    /// limits next to the observed count, and people are named by their `name` attribute;
+    /// without it the result's `display_names` are used when present.
+    ///
+    /// ```text
+    /// RepeatEncounter (1):
+    ///   Alice and Bob met 3 times; limit is 1 (penalty 4)
+    /// ```
+    pub fn explain_violations(&self, input: Option<&ApiInput>) -> Option<String> {
+        let violations = self.constraint_violations.as_ref()?;
+        if violations.is_empty() {
+            return Some("No violations.\n".to_string());
+        }
+        let explainer = ViolationExplainer::new(self, input);
+        let mut by_type: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+        for violation in violations {
+            by_type
+                .entry(violation.constraint_type.as_str())
+                .or_default()
+                .push(explainer.explain(violation));
+        }
+
+        let mut output = String::new();
+        for (constraint_type, lines) in by_type {
+            output.push_str(&format!("{constraint_type} ({}):\n", lines.len()));
+            for line in lines {
+                output.push_str(&format!("  {line}\n"));
+            }
+        }
+        Some(output)
+    }
+
+    /// Formats a block per person in `people`: their group in each session, their unique and
+    /// repeat contacts, and the violations that involve them, worded as in
+    /// [`explain_violations`](Self::explain_violations).
+    ///
+    /// ```text
+    /// Alice (p0)
+    ///   Groups: 0: g1, 1: g2, 2: -
+    ///   Contacts: 5 unique, 1 repeated
+    ///   Violations:
+    ///     Session 1: Alice and p3 share g2 but must be apart
+    /// ```
+    pub fn to_people_text(&self, people: &[Person], input: Option<&ApiInput>) -> String {
+        let explainer = ViolationExplainer::new(self, input);
+        let metrics: HashMap<String, PersonMetrics> = self
+            .person_metrics()
+            .into_iter()
+            .map(|metrics| (metrics.person_id.clone(), metrics))
+            .collect();
+
+        let mut blocks = Vec::new();
+        for itinerary in self.itineraries(people) {
+            let person_id = itinerary.person_id.as_str();
+            let name = explainer.names.get(person_id).copied().unwrap_or(person_id);
+            let mut block = if name == person_id {
+                format!("{person_id}\n")
+            } else {
+                format!("{name} ({person_id})\n")
+            };
+            let stops: Vec<String> = itinerary
+                .stops
+                .iter()
+                .map(|stop| {
+                    format!(
+                        "{}: {}",
+                        stop.session,
+                        stop.group_id.as_deref().unwrap_or("-")
+                    )
+                })
+                .collect();
+            block.push_str(&format!("  Groups: {}\n", stops.join(", ")));
+            let (unique, repeated) = metrics.get(person_id).map_or((0, 0), |metrics| {
+                (metrics.unique_contacts, metrics.repeat_contacts)
+            });
+            block.push_str(&format!(
+                "  Contacts: {unique} unique, {repeated} repeated\n"
+            ));
+
+            match &self.constraint_violations {
+                None => block.push_str("  Violations: not recorded\n"),
+                Some(violations) => {
+                    let lines: Vec<String> = violations
+                        .iter()
+                        .filter(|violation| violation.people.iter().any(|id| id == person_id))
+                        .map(|violation| explainer.explain(violation))
+                        .collect();
+                    if lines.is_empty() {
+                        block.push_str("  Violations: none\n");
+                    } else {
+                        block.push_str("  Violations:\n");
+                        for line in lines {
+                            block.push_str(&format!("    {line}\n"));
+                        }
+                    }
+                }
+            }
+            blocks.push(block);
+        }
+        blocks.join("\n")
+    }
+
+    fn contact_matrix_cells(&self, people: &[Person], diagonal: &str) -> Vec<Vec<String>> {
+        self.contact_matrix(people)
+            .into_iter()
+            .enumerate()
+            .map(|(row, counts)| {
+                counts
+                    .into_iter()
+                    .enumerate()
+                    .map(|(column, count)| {
+                        if row == column {
+                            diagonal.to_string()
+                        } else {
+                            count.to_string()
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Sessions in index order, each with its groups sorted by id.
+    pub(crate) fn sorted_sessions(&self) -> Vec<(usize, SortedGroups<'_>)> {
+        let mut sessions: Vec<_> = self
+            .schedule
+            .iter()
+            .map(|(session_key, groups)| {
+                let session = session_key
+                    .rsplit('_')
+                    .next()
+                    .and_then(|index| index.parse::<usize>().ok())
+                    .unwrap_or(0);
+                let mut groups: Vec<_> = groups
+                    .iter()
+                    .map(|(group_id, members)| (group_id.as_str(), members))
+                    .collect();
+                groups.sort_by_key(|(group_id, _)| *group_id);
+                (session, groups)
+            })
+            .collect();
+        sessions.sort_by_key(|(session, _)| *session);
+        sessions
+    }
+}
+
+/// What [`SolverResult::explain_violations`] needs to word a violation.
+struct ViolationExplainer<'a> {
+    names: HashMap<&'a str, &'a str>,
+    meetings: HashMap<(String, String), u32>,
+    schedule: &'a ApiSchedule,
+    input: Option<&'a ApiInput>,
+}
+
+impl<'a> ViolationExplainer<'a> {
+    fn new(result: &'a SolverResult, input: Option<&'a ApiInput>) -> Self {
+        let mut names: HashMap<&str, &str> = HashMap::new();
+        if let Some(display_names) = &result.display_names {
+            names.extend(
+                display_names
+                    .iter()
+                    .map(|(id, name)| (id.as_str(), name.as_str())),
+            );
+        }
+        if let Some(input) = input {
+            names.extend(input.problem.people.iter().filter_map(|person| {
+                let name = person.attributes.get("name")?;
+                Some((person.id.as_str(), name.as_str()))
+            }));
+        }
+        let meetings = schedule_contact_counts(&result.schedule)
+            .into_iter()
+            .map(|pair| ((pair.person_a, pair.person_b), pair.count))
+            .collect();
+        Self {
+            names,
+            meetings,
+            schedule: &result.schedule,
+            input,
+        }
+    }
+
+    fn explain(&self, violation: &ConstraintViolation) -> String {
+        let people = self.people(&violation.people);
+        let groups = violation.groups.join(", ");
+        let text = match violation.constraint_type.as_str() {
+            "RepeatEncounter" => {
+                let met = self.meeting_count(&violation.people);
+                match self.repeat_limit() {
+                    Some(limit) => format!("{people} met {}; limit is {limit}", times(met)),
+                    None => format!("{people} met {}, more often than allowed", times(met)),
+                }
+            }
+            "PairMeetingCount" => {
+                let met = self.meeting_count(&violation.people);
+                match self.meeting_target(&violation.people) {
+                    Some(target) => format!("{people} met {}; target is {target}", times(met)),
+                    None => format!("{people} met {}, off their target", times(met)),
+                }
+            }
+            "ShouldNotBeTogether" => format!("{people} share {groups} but should be apart"),
+            "MustStayApart" => format!("{people} share {groups} but must be apart"),
+            "ShouldStayTogether" => {
+                format!("{people} are split across {groups} but should stay together")
+            }
+            "MustStayTogether" => {
+                format!("{people} are split across {groups} but must stay together")
+            }
+            "ImmovablePeople" => match violation.groups.as_slice() {
+                [required, actual] => format!("{people} is in {actual} but is fixed to {required}"),
+                _ => format!("{people} is not in their fixed group"),
+            },
+            "AttributeBalance" => format!("{groups} misses its attribute balance ({people})"),
+            "UniqueGroupComposition" => {
+                format!("{groups} repeats an earlier group of {people}")
+            }
+            "BaselineMoveLimit" => match self.baseline_moves(violation.session) {
+                Some((moved, limit)) => format!(
+                    "{} from the baseline ({people}); limit is {limit}",
+                    moves(moved)
+                ),
+                None => format!("{people} moved from the baseline more than allowed"),
+            },
+            other => format!("{other} is violated by {people} in {groups}"),
+        };
+        let mut line = match violation.session {
+            Some(session) => format!("Session {session}: {text}"),
+            None => text,
+        };
+        if violation.weighted_penalty > 0.0 {
+            line.push_str(&format!(" (penalty {})", violation.weighted_penalty));
+        }
+        line
+    }
+
+    /// "Alice", "Alice and Bob", or "Alice, Bob and Carol".
+    fn people(&self, ids: &[String]) -> String {
+        let names: Vec<&str> = ids
+            .iter()
+            .map(|id| self.names.get(id.as_str()).copied().unwrap_or(id))
+            .collect();
+        match names.split_last() {
+            None => "nobody".to_string(),
+            Some((last, [])) => last.to_string(),
+            Some((last, rest)) => format!("{} and {last}", rest.join(", ")),
+        }
+    }
+
+    fn meeting_count(&self, pair: &[String]) -> u32 {
+        let [a, b] = pair else {
+            return 0;
+        };
+        let key = if a <= b {
+            (a.clone(), b.clone())
+        } else {
+            (b.clone(), a.clone())
+        };
+        self.meetings.get(&key).copied().unwrap_or(0)
+    }
+
+    fn repeat_limit(&self) -> Option<u32> {
+        self.input?
+            .constraints
+            .iter()
+            .find_map(|constraint| match constraint {
+                Constraint::RepeatEncounter(params) => Some(params.max_allowed_encounters),
+                _ => None,
+            })
+    }
+
+    /// Moves from the `initial_schedule` counted against the `BaselineMoveLimit` that
+    /// `session` (or `None` for a total limit) was checked under, and that limit.
+    fn baseline_moves(&self, session: Option<u32>) -> Option<(u32, u32)> {
+        let input = self.input?;
+        let baseline = input.initial_schedule.as_ref()?;
+        let limited = |index: u32, sessions: &Option<Vec<u32>>| {
+            sessions
+                .as_ref()
+                .is_none_or(|sessions| sessions.contains(&index))
+        };
+        let params =
+            input
+                .constraints
+                .iter()
+                .find_map(|constraint| match (constraint, session) {
+                    (Constraint::BaselineMoveLimit(params), None)
+                        if params.scope == BaselineMoveLimitScope::Total =>
+                    {
+                        Some(params)
+                    }
+                    (Constraint::BaselineMoveLimit(params), Some(index))
+                        if params.scope == BaselineMoveLimitScope::PerSession
+                            && limited(index, &params.sessions) =>
+                    {
+                        Some(params)
+                    }
+                    _ => None,
+                })?;
+        let moved = diff_schedules(baseline, self.schedule)
+            .sessions
+            .iter()
+            .filter(|diff| {
+                let index = diff
+                    .session
+                    .rsplit('_')
+                    .next()
+                    .and_then(|index| index.parse::<u32>().ok());
+                index.is_some_and(|index| match session {
+                    Some(session) => index == session,
+                    None => limited(index, &params.sessions),
+                })
+            })
+            .flat_map(|diff| &diff.moves)
+            .filter(|change| change.from_group.is_some() && change.to_group.is_some())
+            .count();
+        Some((moved as u32, params.max_moves))
+    }
+
+    fn meeting_target(&self, pair: &[String]) -> Option<String> {
+        self.input?
+            .constraints
+            .iter()
+            .find_map(|constraint| match constraint {
+                Constraint::PairMeetingCount(params)
+                    if params.people.len() == 2
+                        && pair.iter().all(|id| params.people.contains(id)) =>
+                {
+                    let bound = match params.mode {
+                        PairMeetingMode::AtLeast => "at least",
+                        PairMeetingMode::Exact => "exactly",
+                        PairMeetingMode::AtMost => "at most",
+                    };
+                    Some(format!("{bound} {}", params.target_meetings))
+                }
+                _ => None,
+            })
+    }
+}
+
+fn times(count: u32) -> String {
+    match count {
+        1 => "1 time".to_string(),
+        count => format!("{count} times"),
+    }
+}
+
+fn moves(count: u32) -> String {
+    match count {
+        1 => "1 move".to_string(),
+        count => format!("{count} moves"),
+    }
+}
+
+/// Quotes `id` as a DOT string.
+fn dot_id(id: &str) -> String {
+    format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Writes `cells` padded or truncated to `widths`, two spaces apart, without trailing
+/// whitespace.
+fn push_aligned_row(output: &mut String, cells: &[&str], widths: &[usize]) {
+    let mut line = String::new();
+    for (index, (cell, width)) in cells.iter().zip(widths).enumerate() {
+        if index > 0 {
+            line.push_str("  ");
+        }
+        let length = cell.chars().count();
+        if length > *width {
+            line.extend(cell.chars().take(width - 1));
+            line.push('…');
+        } else {
+            line.push_str(cell);
+            line.extend(std::iter::repeat_n(' ', width - length));
+        }
+    }
+    output.push_str(line.trim_end());
+    output.push('\n');
+}
+
+pub(crate) fn push_markdown_row<'a>(output: &mut String, cells: impl IntoIterator<Item = &'a str>) {
+    output.push('|');
+    for cell in cells {
+        output.push(' ');
+        output.push_str(&cell.replace('|', "\\|"));
+        output.push_str(" |");
+    }
+    output.push('\n');
+}
+
+fn push_csv_row<'a>(output: &mut String, fields: impl IntoIterator<Item = &'a str>) {
+    for (index, field) in fields.into_iter().enumerate() {
+        if index > 0 {
+            output.push(',');
+        }
+        if field.contains([',', '"', '\n', '\r']) {
+            output.push('"');
+            output.push_str(&field.replace('"', "\"\""));
+            output.push('"');
+        } else {
+            output.push_str(field);
+        }
+    }
+    output.push('\n');
+}
+
+impl PersonItinerary {
+    /// Formats the itinerary as plain text, one line per session.
+    ///
+    /// # Example Output
+    ///
+    /// ```text
+    /// Alice
+    ///   Session 0: Group1
+    ///   Session 1: not scheduled
+    /// ```
+    pub fn to_text(&self) -> String {
+        let mut output = format!("{}\n", self.person_id);
+        for stop in &self.stops {
+            let group = stop.group_id.as_deref().unwrap_or("not scheduled");
+            output.push_str(&format!("  Session {}: {group}\n", stop.session));
+        }
+        output
+    }
+
+    /// Formats the itinerary as an iCalendar file with one event per scheduled session.
+    ///
+    /// `session_times` is indexed by session. Each event is titled with the group and
+    /// session, and its `DTSTAMP` is the session start so that the export is reproducible.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if a scheduled session has no entry in `session_times`, or
+    /// if a time is not a UTC date-time like `20261014T090000Z`.
+    pub fn to_ical(&self, session_times: &[SessionTime]) -> Result<String, SolverError> {
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            "PRODID:-//GroupMixer//Itinerary//EN".to_string(),
+            "CALSCALE:GREGORIAN".to_string(),
+        ];
+        for stop in &self.stops {
+            let Some(group_id) = &stop.group_id else {
+                continue;
+            };
+            let time = session_times.get(stop.session as usize).ok_or_else(|| {
+                SolverError::ValidationError(format!(
+                    "no session time given for session {}",
+                    stop.session
+                ))
+            })?;
+            for value in [&time.start, &time.end] {
+                if !is_utc_date_time(value) {
+                    return Err(SolverError::ValidationError(format!(
+                        "session {} time '{value}' is not a UTC date-time like 20261014T090000Z",
+                        stop.session
+                    )));
+                }
+            }
+            lines.extend([
+                "BEGIN:VEVENT".to_string(),
+                format!(
+                    "UID:{}",
+                    ical_text(&format!(
+                        "{}-session-{}@groupmixer",
+                        self.person_id, stop.session
+                    ))
+                ),
+                format!("DTSTAMP:{}", time.start),
+                format!("DTSTART:{}", time.start),
+                format!("DTEND:{}", time.end),
+                format!(
+                    "SUMMARY:{}",
+                    ical_text(&format!("{group_id} (session {})", stop.session))
+                ),
+                format!("LOCATION:{}", ical_text(group_id)),
+                "END:VEVENT".to_string(),
+            ]);
+        }
+        lines.push("END:VCALENDAR".to_string());
+
+        Ok(lines
+            .iter()
+            .map(|line| fold_ical_line(line))
+            .collect::<Vec<_>>()
+            .join(""))
+    }
+}
+
+fn is_utc_date_time(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    bytes.len() == 16
+        && bytes[8] == b'T'
+        && bytes[15] == b'Z'
+        && bytes[..8].iter().all(u8::is_ascii_digit)
+        && bytes[9..15].iter().all(u8::is_ascii_digit)
+}
+
+/// Escapes an iCalendar TEXT value.
+fn ical_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Ends `line` with CRLF, folding it so that no line exceeds 75 octets.
+fn fold_ical_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + 2);
+    let mut octets = 0;
+    for c in line.chars() {
+        if octets + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            octets = 1;
+        }
+        folded.push(c);
+        octets += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
@@ -13,6 +13,7 @@
 //! let input = ApiInput {
 //!     initial_schedule: None,
 //!     construction_seed_schedule: None,
+//!     contact_history: None,
 //!     problem: ProblemDefinition {
 //!         people: vec![
 //!             Person {
@@ -31,6 +32,8 @@
 //!                 id: "Team1".to_string(),
 //!                 size: 2,
 //!                 session_sizes: None,
+//!                 attributes: HashMap::new(),
+//!                 available_sessions: None,
 //!             }
 //!         ],
 //!         num_sessions: 2,
@@ -44,6 +47,9 @@
 //!             time_limit_seconds: None,
 //!             no_improvement_iterations: None,
 //!             stop_on_optimal_score: true,
+//!             stop_on_feasible: false,
+//!             feasible_score_threshold: None,
+//!             convergence: None,
 //!         },
 //!         solver_params: SolverParams::SimulatedAnnealing(
 //!                SimulatedAnnealingParams {
@@ -52,6 +58,7 @@
 //!                 cooling_schedule: "geometric".to_string(),
 //!                 reheat_after_no_improvement: Some(0),
 //!                 reheat_cycles: Some(0),
+//!                 penalty_ramp: None,
 //!             }
 //!         ),
 //!         logging: LoggingOptions::default(),
@@ -73,22 +80,39 @@
 
 use crate::engines::{
     available_solver_descriptors as registry_available_solver_descriptors,
+    calculate_recommendation_report_for as registry_calculate_recommendation_report_for,
     calculate_recommended_settings_for as registry_calculate_recommended_settings_for,
     default_solver_configuration_for as registry_default_solver_configuration_for,
-    default_solver_kind as registry_default_solver_kind, run_solver_with_engine,
-    solver_descriptor as registry_solver_descriptor, RecommendationRequest, SolveRequest,
-    SolverDescriptor,
+    default_solver_kind as registry_default_solver_kind, resume_from_checkpoint_with_engine,
+    run_solver_with_engine, solver_descriptor as registry_solver_descriptor, RecommendationRequest,
+    SolveRequest, SolverDescriptor,
 };
 use crate::models::{
-    ApiInput, BenchmarkObserver, ProblemDefinition, ProgressCallback, SolverConfiguration,
-    SolverKind, SolverResult,
+    ApiInput, ApiSchedule, BenchmarkObserver, ContactHistory, InfeasibilityExplanation,
+    MemoryEstimate, PeopleChangeOptions, Person, ProblemDefinition, ProblemStats, ProgressCallback,
+    ProgressControlCallback, ProgressHook, RecommendationReport, ScheduleDiff, ScoreDelta,
+    SolverCheckpoint, SolverConfiguration, SolverKind, SolverResult, TuningBudget,
+    ValidationReport, WarmStartRepair,
 };
 use crate::models::{Constraint, Objective};
+use crate::solver_support::event_series;
+use crate::solver_support::infeasibility;
+use crate::solver_support::memory_estimate;
+use crate::solver_support::move_evaluation;
+use crate::solver_support::people_changes;
+use crate::solver_support::problem_stats;
+use crate::solver_support::schedule_diff;
+use crate::solver_support::validation_report;
+use crate::solver_support::warm_start_repair;
 use crate::solver_support::SolverError;
 
 pub mod algorithms;
+pub mod builder;
+pub mod csv_import;
 pub mod engines;
+pub mod export;
 pub mod models;
+pub mod report;
 mod runtime_target;
 pub mod solver;
 pub mod solver1;
@@ -96,7 +120,22 @@ pub mod solver3;
 pub mod solver4;
 pub mod solver5;
 pub mod solver6;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod solver_handle;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod solver_iter;
 pub mod solver_support;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod telemetry_jsonl;
+#[cfg(feature = "toml")]
+pub mod toml_import;
+#[cfg(feature = "xlsx")]
+pub mod xlsx_import;
+
+/// The YAML library behind [`ApiInput::from_yaml_str`](models::ApiInput::from_yaml_str), so
+/// front ends read and write other documents in the same dialect without a second copy.
+#[cfg(feature = "yaml")]
+pub use serde_yaml;
 
 /// Runs the optimization solver with the given input configuration.
 ///
@@ -136,6 +175,7 @@ pub mod solver_support;
 /// let input = ApiInput {
 ///     initial_schedule: None,
 ///     construction_seed_schedule: None,
+///     contact_history: None,
 ///     problem: ProblemDefinition {
 ///         people: vec![
 ///             Person {
@@ -162,6 +202,8 @@ pub mod solver_support;
 ///                 id: "Team1".to_string(),
 ///                 size: 2,
 ///                 session_sizes: None,
+///                 attributes: HashMap::new(),
+///                 available_sessions: None,
 ///             },
 ///         ],
 ///         num_sessions: 3,
@@ -170,6 +212,8 @@ pub mod solver_support;
 ///         Objective {
 ///             r#type: "maximize_unique_contacts".to_string(),
 ///             weight: 1.0,
+///             person_weights: HashMap::new(),
+///             session_weights: Vec::new(),
 ///         }
 ///     ],
 ///     constraints: vec![
@@ -186,6 +230,9 @@ pub mod solver_support;
 ///             time_limit_seconds: Some(30),
 ///             no_improvement_iterations: Some(1_000),
 ///             stop_on_optimal_score: true,
+///             stop_on_feasible: false,
+///             feasible_score_threshold: None,
+///             convergence: None,
 ///         },
 ///         solver_params: SolverParams::SimulatedAnnealing(
 ///             SimulatedAnnealingParams {
@@ -194,6 +241,7 @@ pub mod solver_support;
 ///                 cooling_schedule: "geometric".to_string(),
 ///                 reheat_after_no_improvement: Some(0),
 ///                 reheat_cycles: Some(0),
+///                 penalty_ramp: None,
 ///             }
 ///         ),
 ///         logging: LoggingOptions {
@@ -264,12 +312,13 @@ pub fn run_solver(input: &ApiInput) -> Result<SolverResult, SolverError> {
 /// # let input = ApiInput {
 /// #     initial_schedule: None,
 /// #     construction_seed_schedule: None,
+/// #     contact_history: None,
 /// #     problem: ProblemDefinition { people: vec![], groups: vec![], num_sessions: 1 },
 /// #     objectives: vec![], constraints: vec![],
 /// #     solver: SolverConfiguration {
 /// #         solver_type: "SimulatedAnnealing".to_string(),
-/// #         stop_conditions: StopConditions { max_iterations: Some(1000), time_limit_seconds: None, no_improvement_iterations: None, stop_on_optimal_score: true },
-/// #         solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams { initial_temperature: 10.0, final_temperature: 0.1, cooling_schedule: "geometric".to_string(), reheat_after_no_improvement: Some(0), reheat_cycles: Some(0) }),
+/// #         stop_conditions: StopConditions { max_iterations: Some(1000), time_limit_seconds: None, no_improvement_iterations: None, stop_on_optimal_score: true, stop_on_feasible: false, feasible_score_threshold: None, convergence: None },
+/// #         solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams { initial_temperature: 10.0, final_temperature: 0.1, cooling_schedule: "geometric".to_string(), reheat_after_no_improvement: Some(0), reheat_cycles: Some(0), penalty_ramp: None }),
 /// #         logging: LoggingOptions::default(),
 /// #         telemetry: Default::default(),
 /// #         seed: None,
@@ -315,11 +364,454 @@ pub fn run_solver_with_callbacks(
 ) -> Result<SolverResult, SolverError> {
     run_solver_with_engine(SolveRequest {
         input,
-        progress_callback,
+        progress_callback: progress_callback.map(ProgressHook::from),
         benchmark_observer,
     })
 }
 
+/// Runs the optimization solver with a callback that steers the run.
+///
+/// Like [`run_solver_with_progress`], but the callback answers each update with a
+/// [`ProgressControl`](models::ProgressControl): besides stopping, it can ask solver1 to save a
+/// checkpoint or to reheat.
+///
+/// # Errors
+///
+/// Other solver families stop at the first `SaveCheckpoint` or `Reheat` answer and return a
+/// [`SolverError::ValidationError`] naming it.
+///
+/// # Example
+///
+/// ```no_run
+/// use gm_core::models::{ApiInput, ProgressControl, ProgressControlCallback};
+/// use gm_core::run_solver_with_control;
+///
+/// # fn example(input: ApiInput) {
+/// let controller: ProgressControlCallback = Box::new(|update| {
+///     if update.no_improvement_count > 50_000 {
+///         ProgressControl::Reheat
+///     } else {
+///         ProgressControl::Continue
+///     }
+/// });
+/// let result = run_solver_with_control(&input, Some(&controller));
+/// # }
+/// ```
+pub fn run_solver_with_control(
+    input: &ApiInput,
+    progress_callback: Option<&ProgressControlCallback>,
+) -> Result<SolverResult, SolverError> {
+    run_solver_with_engine(SolveRequest {
+        input,
+        progress_callback: progress_callback.map(ProgressHook::from),
+        benchmark_observer: None,
+    })
+}
+
+/// Runs the solver on a blocking thread and streams its progress updates.
+///
+/// Returns the receiving end of the progress stream and a future that resolves to the
+/// result. The solve starts immediately; the stream closes when it finishes. Dropping the
+/// receiver does not stop the run.
+///
+/// Requires the `tokio` feature and must be called from within a Tokio runtime.
+///
+/// # Example
+///
+/// ```no_run
+/// # use gm_core::models::ApiInput;
+/// # async fn example(input: ApiInput) {
+/// let (mut progress, result) = gm_core::run_solver_async(input);
+/// tokio::spawn(async move {
+///     while let Some(update) = progress.recv().await {
+///         println!("iteration {}: {:.2}", update.iteration, update.best_score);
+///     }
+/// });
+/// let result = result.await;
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+pub fn run_solver_async(
+    input: ApiInput,
+) -> (
+    tokio::sync::mpsc::UnboundedReceiver<models::ProgressUpdate>,
+    impl std::future::Future<Output = Result<SolverResult, SolverError>> + Send,
+) {
+    let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+    let handle = tokio::task::spawn_blocking(move || {
+        let progress_callback: ProgressCallback = Box::new(move |update| {
+            // A closed stream only means nobody is listening; keep solving.
+            let _ = sender.send(update.clone());
+            true
+        });
+        run_solver_with_progress(&input, Some(&progress_callback))
+    });
+
+    let result = async move {
+        match handle.await {
+            Ok(result) => result,
+            Err(error) if error.is_panic() => std::panic::resume_unwind(error.into_panic()),
+            Err(error) => Err(SolverError::ValidationError(format!(
+                "solver task did not complete: {error}"
+            ))),
+        }
+    };
+    (receiver, result)
+}
+
+/// Solves `input` on a background thread and yields each new best schedule as it is found.
+///
+/// Consume improvements for as long as they are useful: the latest one is always the best
+/// schedule so far. Dropping the iterator stops the run; [`SolverIter::finish`](solver_iter::SolverIter::finish)
+/// stops it and returns the final [`SolverResult`]. Improvements are taken from progress
+/// updates, so only solver families that report best-schedule snapshots (solver1) yield any.
+/// An invalid input yields nothing, and `finish` returns its error.
+///
+/// # Example
+///
+/// ```no_run
+/// # use gm_core::models::ApiInput;
+/// # fn example(input: &ApiInput) {
+/// for improvement in gm_core::run_solver_iter(input) {
+///     println!("iteration {}: {} unique contacts", improvement.iteration, improvement.unique_contacts);
+///     if improvement.elapsed_seconds > 5.0 {
+///         break;
+///     }
+/// }
+/// # }
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+pub fn run_solver_iter(input: &ApiInput) -> solver_iter::SolverIter {
+    solver_iter::SolverIter::spawn(input)
+}
+
+/// Continues a solver1 run from a checkpoint emitted by an earlier run of the same `input`.
+///
+/// Checkpoints are attached to progress updates when
+/// `solver.telemetry.checkpoint_every_n_iterations` is set. Persist the latest one, and after a
+/// restart pass it here with the unchanged input: the search picks up at the checkpoint's
+/// iteration with the same schedules, RNG stream, and cooling position, and time spent before
+/// the checkpoint counts toward the time limit. Progress updates of the resumed run carry
+/// further checkpoints, so long runs can be resumed repeatedly.
+///
+/// # Example
+///
+/// ```no_run
+/// use gm_core::models::{ApiInput, ProgressCallback, SolverCheckpoint};
+/// use gm_core::{resume_from_checkpoint_with_progress, run_solver_with_progress};
+/// use std::sync::{Arc, Mutex};
+/// # fn example(mut input: ApiInput) {
+/// input.solver.telemetry.checkpoint_every_n_iterations = 100_000;
+///
+/// let latest: Arc<Mutex<Option<SolverCheckpoint>>> = Arc::new(Mutex::new(None));
+/// let sink = Arc::clone(&latest);
+/// let progress: ProgressCallback = Box::new(move |update| {
+///     if let Some(checkpoint) = &update.checkpoint {
+///         // In practice, write it to disk here.
+///         *sink.lock().unwrap() = Some(checkpoint.clone());
+///     }
+///     true
+/// });
+/// let _ = run_solver_with_progress(&input, Some(&progress));
+///
+/// // ... after a restart:
+/// let checkpoint = latest.lock().unwrap().clone();
+/// if let Some(checkpoint) = checkpoint {
+///     let result = resume_from_checkpoint_with_progress(&input, &checkpoint, Some(&progress));
+/// }
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if `input` does not select solver1 or if the checkpoint's schedules do not
+/// fit the input.
+pub fn resume_from_checkpoint(
+    input: &ApiInput,
+    checkpoint: &SolverCheckpoint,
+) -> Result<SolverResult, SolverError> {
+    resume_from_checkpoint_with_progress(input, checkpoint, None)
+}
+
+/// Like [`resume_from_checkpoint`], with progress callbacks for the resumed run.
+pub fn resume_from_checkpoint_with_progress(
+    input: &ApiInput,
+    checkpoint: &SolverCheckpoint,
+    progress_callback: Option<&ProgressCallback>,
+) -> Result<SolverResult, SolverError> {
+    resume_from_checkpoint_with_engine(
+        SolveRequest {
+            input,
+            progress_callback: progress_callback.map(ProgressHook::from),
+            benchmark_observer: None,
+        },
+        checkpoint,
+    )
+}
+
+/// Like [`resume_from_checkpoint`], with a callback that steers the resumed run.
+///
+/// The callback can answer [`ProgressControl::SaveCheckpoint`](models::ProgressControl) to
+/// receive a fresh checkpoint on the next update, so a resumed run can keep saving its state
+/// on a schedule of the caller's choosing.
+pub fn resume_from_checkpoint_with_control(
+    input: &ApiInput,
+    checkpoint: &SolverCheckpoint,
+    progress_callback: Option<&ProgressControlCallback>,
+) -> Result<SolverResult, SolverError> {
+    resume_from_checkpoint_with_engine(
+        SolveRequest {
+            input,
+            progress_callback: progress_callback.map(ProgressHook::from),
+            benchmark_observer: None,
+        },
+        checkpoint,
+    )
+}
+
+/// Plans a sequence of events whose contacts carry over from one event to the next.
+///
+/// Each event (with its own people, groups, and sessions) is solved in order. Before an
+/// event runs, its `contact_history` is replaced by everything the earlier events produced
+/// plus whatever history the event itself supplies, aged by one event per step so that
+/// `decay_factor` fades out older meetings (pairs that faded out entirely are dropped). Pairs
+/// who already met are therefore steered apart for the rest of the season.
+///
+/// Every event after the first carries history, so it must use a solver that supports
+/// `contact_history` (solver1).
+///
+/// # Errors
+///
+/// Returns the first failing event's error wrapped in [`SolverError::InEvent`] with its position
+/// in the series.
+pub fn run_event_series(events: &[ApiInput]) -> Result<Vec<SolverResult>, SolverError> {
+    let mut series_history = ContactHistory::default();
+    let mut results = Vec::with_capacity(events.len());
+
+    for (event_idx, event) in events.iter().enumerate() {
+        let mut input = event.clone();
+        input.contact_history = event_series::contact_history_for_event(&series_history, event);
+        let result = run_solver(&input).map_err(|error| SolverError::InEvent {
+            event: event_idx,
+            source: Box::new(error),
+        })?;
+        series_history =
+            event_series::advance_contact_history(&series_history, event, &result.schedule);
+        results.push(result);
+    }
+
+    Ok(results)
+}
+
+/// Re-solves `previous` after people registered late or cancelled.
+///
+/// `people` is the revised people list of `input`. Everyone who still attends a session keeps
+/// their previous group there unless `options.keep_unaffected_fixed` is turned off; only the
+/// added people, and people who now attend sessions they skipped before, are placed by the
+/// solver. Constraints and contact history naming removed people are pruned to match. See
+/// [`people_change_input`](solver_support::people_changes::people_change_input) for the
+/// exact input this solves.
+///
+/// # Example
+///
+/// ```no_run
+/// use gm_core::models::{PeopleChangeOptions, Person};
+/// # use gm_core::models::ApiInput;
+/// # fn example(input: &ApiInput, late: Person) -> Result<(), gm_core::solver::SolverError> {
+/// let previous = gm_core::run_solver(input)?;
+/// let mut people: Vec<Person> = input.problem.people.clone();
+/// people.retain(|person| person.id != "cancelled");
+/// people.push(late);
+/// let revised =
+///     gm_core::resolve_with_people_changes(input, &previous, &people, &PeopleChangeOptions::default())?;
+/// let churn = gm_core::diff_schedules(&previous.schedule, &revised.schedule);
+/// println!("{} assignments changed", churn.total_moves);
+/// # Ok(())
+/// # }
+/// ```
+pub fn resolve_with_people_changes(
+    input: &ApiInput,
+    previous: &SolverResult,
+    people: &[Person],
+    options: &PeopleChangeOptions,
+) -> Result<SolverResult, SolverError> {
+    run_solver(&people_changes::people_change_input(
+        input,
+        &previous.schedule,
+        people,
+        options,
+    ))
+}
+
+/// Repairs `input.initial_schedule` so it passes warm-start validation.
+///
+/// Fixes groups over capacity, immovable people outside their required group, and attendees
+/// the draft leaves out, and lists every person it moved. See
+/// [`repair_warm_start`](solver_support::warm_start_repair::repair_warm_start) for the exact
+/// rules. Fails if `input` has no `initial_schedule`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use gm_core::models::ApiInput;
+/// # fn example(mut input: ApiInput) -> Result<(), gm_core::solver::SolverError> {
+/// let repair = gm_core::repair_initial_schedule(&input)?;
+/// for change in &repair.repairs {
+///     println!("session {}: moved {} to {}", change.session, change.person_id, change.to_group);
+/// }
+/// input.initial_schedule = Some(repair.schedule);
+/// let result = gm_core::run_solver(&input)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn repair_initial_schedule(input: &ApiInput) -> Result<WarmStartRepair, SolverError> {
+    let schedule = input.initial_schedule.as_ref().ok_or_else(|| {
+        SolverError::ValidationError("input has no initial_schedule to repair".to_string())
+    })?;
+    warm_start_repair::repair_warm_start(input, schedule)
+}
+
+/// Compares two schedules for the same problem, e.g. before and after a re-solve.
+///
+/// For every session that appears in either schedule, the result lists the people whose
+/// group changed (with their old and new group) and the share of people who moved.
+///
+/// # Example
+///
+/// ```no_run
+/// use gm_core::{diff_schedules, run_solver};
+/// # use gm_core::models::ApiInput;
+/// # fn example(input: &ApiInput, revised: &ApiInput) {
+/// let before = run_solver(input).unwrap();
+/// let after = run_solver(revised).unwrap();
+/// let diff = diff_schedules(&before.schedule, &after.schedule);
+/// println!("{} people moved ({:.0}% churn)", diff.total_moves, diff.churn_rate * 100.0);
+/// # }
+/// ```
+pub fn diff_schedules(before: &ApiSchedule, after: &ApiSchedule) -> ScheduleDiff {
+    schedule_diff::diff_schedules(before, after)
+}
+
+/// Checks `input` without solving it, collecting errors and warnings.
+///
+/// Unlike [`run_solver`], this never constructs or loads a schedule, so it is cheap enough to
+/// run on every edit. Errors are the checks a solve would fail on; warnings flag constraints
+/// that are valid but probably not what was meant, such as a constraint naming a session one
+/// of its people does not attend, or a `ShouldStayTogether` already implied by a
+/// `MustStayTogether` clique.
+///
+/// # Example
+///
+/// ```no_run
+/// # use gm_core::models::ApiInput;
+/// # fn example(input: &ApiInput) {
+/// let report = gm_core::validate(input);
+/// for warning in &report.warnings {
+///     println!("warning: {}", warning.message);
+/// }
+/// # }
+/// ```
+pub fn validate(input: &ApiInput) -> ValidationReport {
+    validation_report::validate(input)
+}
+
+/// Explains why `input` cannot be solved by finding a small set of likely conflicting
+/// constraints.
+///
+/// The check is heuristic: it asks solver1's seeded, randomized construction whether it can
+/// build a starting schedule, and that construction can fail on inputs that are in fact
+/// feasible. Returns `None` when a starting schedule is built. Otherwise the construction
+/// fails with every constraint in the returned set and succeeds once any one of them is
+/// dropped, so loosening one of them is the first thing to try; this is not a proof that the
+/// set is infeasible. This re-checks the input once per constraint, so it is meant for error
+/// reporting rather than every edit.
+///
+/// # Example
+///
+/// ```no_run
+/// # use gm_core::models::ApiInput;
+/// # fn example(input: &ApiInput) {
+/// if let Some(explanation) = gm_core::explain_infeasibility(input) {
+///     println!("{}", explanation.message);
+///     println!("likely conflicting: constraints {:?}", explanation.constraint_indices);
+/// }
+/// # }
+/// ```
+pub fn explain_infeasibility(input: &ApiInput) -> Option<InfeasibilityExplanation> {
+    infeasibility::explain_infeasibility(input)
+}
+
+/// Summarizes how large and how tight `input` is, without solving it.
+///
+/// The figures help decide whether a long solve is worthwhile: `max_possible_contacts` is the
+/// same capacity-based bound used for the optimality gap, negative `capacity_slack` means a
+/// session cannot seat everyone, and large cliques limit how freely people can be mixed.
+///
+/// # Example
+///
+/// ```no_run
+/// # use gm_core::models::ApiInput;
+/// # fn example(input: &ApiInput) {
+/// let stats = gm_core::problem_stats(input);
+/// println!("at most {} of {} pairs can meet", stats.max_possible_contacts, stats.total_pairs);
+/// # }
+/// ```
+pub fn problem_stats(input: &ApiInput) -> ProblemStats {
+    problem_stats::problem_stats(input)
+}
+
+/// Estimates how much memory solving `input` takes, without allocating it.
+///
+/// The per-pair tables grow with the square of the number of people. In WebAssembly builds,
+/// solves whose `total_bytes` exceed `limit_bytes` fail with
+/// [`SolverError::MemoryLimitExceeded`] before anything large is allocated, so front ends can
+/// check this while the problem is edited. Native builds report no limit.
+///
+/// # Example
+///
+/// ```no_run
+/// # use gm_core::models::ApiInput;
+/// # fn example(input: &ApiInput) {
+/// let estimate = gm_core::estimate_memory(input);
+/// if estimate.limit_bytes.is_some_and(|limit| estimate.total_bytes > limit) {
+///     println!("too many people to solve");
+/// }
+/// # }
+/// ```
+pub fn estimate_memory(input: &ApiInput) -> MemoryEstimate {
+    memory_estimate::estimate_memory(input)
+}
+
+/// Previews swapping two people within one session of `schedule`, addressed by their IDs.
+///
+/// Front ends call this while the user drags a person onto another to show how the edit would
+/// change the score before committing it. Both schedules are scored from scratch with the
+/// solver's cost model. `feasible` is `false` when the swap would break a hard constraint;
+/// the deltas are still reported so the user sees how much it would cost.
+///
+/// # Example
+///
+/// ```no_run
+/// # use gm_core::models::ApiInput;
+/// # fn example(input: &ApiInput) -> Result<(), gm_core::solver::SolverError> {
+/// let result = gm_core::run_solver(input)?;
+/// let delta = gm_core::evaluate_move(input, &result.schedule, 0, "alice", "bob")?;
+/// if delta.feasible && delta.cost_delta < 0.0 {
+///     println!("swapping alice and bob improves the schedule");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn evaluate_move(
+    input: &ApiInput,
+    schedule: &ApiSchedule,
+    session: usize,
+    person_a: &str,
+    person_b: &str,
+) -> Result<ScoreDelta, SolverError> {
+    move_evaluation::evaluate_move(input, schedule, session, person_a, person_b)
+}
+
 /// Returns the canonical default solver configuration for public callers.
 pub fn default_solver_configuration() -> SolverConfiguration {
     registry_default_solver_configuration_for(registry_default_solver_kind())
@@ -394,6 +886,58 @@ pub fn calculate_recommended_settings_for(
     )
 }
 
+/// Tunes the simulated annealing parameters for `input` with short solves.
+///
+/// Where [`calculate_recommendation_report_for`] derives temperatures from one set of trials,
+/// this samples `budget.candidates` parameter sets around that recommendation (temperatures and
+/// reheat cycles) and races them on `input` by successive halving: each round keeps the better
+/// half and doubles their iterations, until one set is left. Races are seeded, so the same
+/// input and budget always give the same configuration.
+///
+/// The result is a solver1 configuration that keeps the stop conditions, seed, logging, and
+/// telemetry of `input.solver`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use gm_core::models::{ApiInput, TuningBudget};
+/// # fn example(input: &mut ApiInput) -> Result<(), gm_core::solver::SolverError> {
+/// input.solver = gm_core::auto_tune(input, TuningBudget::default())?;
+/// let result = gm_core::run_solver(input)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn auto_tune(
+    input: &ApiInput,
+    budget: TuningBudget,
+) -> Result<SolverConfiguration, SolverError> {
+    solver1::search::tuning::auto_tune(input, budget)
+}
+
+/// Calculates recommended settings for the selected solver family, together with the
+/// measurements and reasoning they were derived from.
+///
+/// Solver 1 samples the cost deltas of random swaps and runs several short annealing trials at
+/// different temperatures; the report carries the delta distribution, the trials, and the
+/// measured iterations per second. Other families report their configuration alone.
+pub fn calculate_recommendation_report_for(
+    kind: SolverKind,
+    problem: &ProblemDefinition,
+    objectives: &[Objective],
+    constraints: &[Constraint],
+    desired_runtime_seconds: u64,
+) -> Result<RecommendationReport, SolverError> {
+    registry_calculate_recommendation_report_for(
+        kind,
+        RecommendationRequest {
+            problem,
+            objectives,
+            constraints,
+            desired_runtime_seconds,
+        },
+    )
+}
+
 #[cfg(test)]
 mod callback_tests {
     use super::*;
@@ -611,17 +1155,22 @@ mod callback_tests {
                 id: "group_1".to_string(),
                 size: 3,
                 session_sizes: None,
+                attributes: HashMap::new(),
+                available_sessions: None,
             },
             Group {
                 id: "group_2".to_string(),
                 size: 3,
                 session_sizes: None,
+                attributes: HashMap::new(),
+                available_sessions: None,
             },
         ];
 
         ApiInput {
             initial_schedule: None,
             construction_seed_schedule: None,
+            contact_history: None,
             problem: ProblemDefinition {
                 people,
                 groups,
@@ -630,6 +1179,8 @@ mod callback_tests {
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".to_string(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![Constraint::RepeatEncounter(RepeatEncounterParams {
                 max_allowed_encounters: 1,
@@ -643,6 +1194,9 @@ mod callback_tests {
                     time_limit_seconds: Some(5),
                     no_improvement_iterations: Some(500),
                     stop_on_optimal_score: true,
+                    stop_on_feasible: false,
+                    feasible_score_threshold: None,
+                    convergence: None,
                 },
                 solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
                     initial_temperature: 10.0,
@@ -650,6 +1204,7 @@ mod callback_tests {
                     cooling_schedule: "geometric".to_string(),
                     reheat_cycles: Some(0),
                     reheat_after_no_improvement: Some(0), // No reheat
+                    penalty_ramp: None,
                 }),
                 logging: LoggingOptions {
                     log_frequency: Some(100),
@@ -691,22 +1246,29 @@ mod callback_tests {
                 id: "group_1".to_string(),
                 size: 4,
                 session_sizes: None,
+                attributes: HashMap::new(),
+                available_sessions: None,
             },
             Group {
                 id: "group_2".to_string(),
                 size: 4,
                 session_sizes: None,
+                attributes: HashMap::new(),
+                available_sessions: None,
             },
             Group {
                 id: "group_3".to_string(),
                 size: 4,
                 session_sizes: None,
+                attributes: HashMap::new(),
+                available_sessions: None,
             },
         ];
 
         ApiInput {
             initial_schedule: None,
             construction_seed_schedule: None,
+            contact_history: None,
             problem: ProblemDefinition {
                 people,
                 groups,
@@ -715,6 +1277,8 @@ mod callback_tests {
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".to_string(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![
                 Constraint::RepeatEncounter(RepeatEncounterParams {
@@ -748,6 +1312,9 @@ mod callback_tests {
                     time_limit_seconds: Some(10),
                     no_improvement_iterations: Some(1000),
                     stop_on_optimal_score: true,
+                    stop_on_feasible: false,
+                    feasible_score_threshold: None,
+                    convergence: None,
                 },
                 solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
                     initial_temperature: 50.0,
@@ -755,6 +1322,7 @@ mod callback_tests {
                     cooling_schedule: "geometric".to_string(),
                     reheat_cycles: Some(0),
                     reheat_after_no_improvement: Some(0), // No reheat
+                    penalty_ramp: None,
                 }),
                 logging: LoggingOptions {
                     log_frequency: Some(200),
@@ -3,19 +3,47 @@
 //! This module contains all the public data structures used to define optimization
 //! problems, configure the solver, and receive results. The API is designed to be
 //! serializable (JSON/YAML) for easy integration with web services and configuration files.
+//!
+//! Fields serialize in snake_case. Multi-word fields also accept their camelCase spelling
+//! (`numSessions`, `penaltyWeight`, ...) on input, so JavaScript clients can pass their own
+//! objects. Tag values such as `"type"` and `"solver_type"` keep their snake_case names.
 
+use crate::solver_support::error_details::SolverErrorDetails;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 pub type ApiSchedule = HashMap<String, HashMap<String, Vec<String>>>;
 
+/// Serializes `schedule` with sessions ordered by their `session_<index>` suffix, then by
+/// key, and groups ordered by id.
+fn serialize_schedule_sorted<S: serde::Serializer>(
+    schedule: &ApiSchedule,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut sessions: Vec<_> = schedule.iter().collect();
+    sessions.sort_by_key(|(session_key, _)| {
+        let index = session_key
+            .rsplit('_')
+            .next()
+            .and_then(|index| index.parse::<usize>().ok());
+        (index, *session_key)
+    });
+    serializer.collect_map(
+        sessions
+            .into_iter()
+            .map(|(session_key, groups)| (session_key, groups.iter().collect::<BTreeMap<_, _>>())),
+    )
+}
+
 /// Complete input specification for the optimization solver.
 ///
 /// This is the root structure that contains all information needed to run
 /// an optimization: the problem definition (people, groups, sessions),
 /// optimization objectives, constraints to satisfy, and solver configuration.
 ///
+/// [`ApiInput::builder`] fills in the usual defaults; the literal below spells everything out.
+///
 /// # Example
 ///
 /// ```no_run
@@ -25,6 +53,7 @@ pub type ApiSchedule = HashMap<String, HashMap<String, Vec<String>>>;
 /// let input = ApiInput {
 ///     initial_schedule: None,
 ///     construction_seed_schedule: None,
+///     contact_history: None,
 ///     problem: ProblemDefinition {
 ///         people: vec![
 ///             Person {
@@ -38,6 +67,8 @@ pub type ApiSchedule = HashMap<String, HashMap<String, Vec<String>>>;
 ///                 id: "Team1".to_string(),
 ///                 size: 4,
 ///                 session_sizes: None,
+///                 attributes: HashMap::new(),
+///                 available_sessions: None,
 ///             }
 ///         ],
 ///         num_sessions: 3,
@@ -46,6 +77,8 @@ pub type ApiSchedule = HashMap<String, HashMap<String, Vec<String>>>;
 ///         Objective {
 ///             r#type: "maximize_unique_contacts".to_string(),
 ///             weight: 1.0,
+///             person_weights: HashMap::new(),
+///             session_weights: Vec::new(),
 ///         }
 ///     ],
 ///     constraints: vec![],
@@ -56,6 +89,9 @@ pub type ApiSchedule = HashMap<String, HashMap<String, Vec<String>>>;
 ///             time_limit_seconds: None,
 ///             no_improvement_iterations: None,
 ///             stop_on_optimal_score: true,
+///             stop_on_feasible: false,
+///             feasible_score_threshold: None,
+///             convergence: None,
 ///         },
 ///         solver_params: SolverParams::SimulatedAnnealing(
 ///             SimulatedAnnealingParams {
@@ -64,6 +100,7 @@ pub type ApiSchedule = HashMap<String, HashMap<String, Vec<String>>>;
 ///                 cooling_schedule: "geometric".to_string(),
 ///                 reheat_after_no_improvement: Some(0),
 ///                 reheat_cycles: Some(0),
+///                 penalty_ramp: None,
 ///             }
 ///         ),
 ///         logging: LoggingOptions::default(),
@@ -85,25 +122,181 @@ pub struct ApiInput {
     /// The solver does not silently repair or complete this schedule.
     ///
     /// Format: schedule["session_{i}"][group_id] = [person_ids]
-    #[serde(default)]
+    #[serde(default, alias = "initialSchedule")]
     pub initial_schedule: Option<ApiSchedule>,
     /// Optional construction seed schedule for constructor-driven bootstrapping.
     ///
     /// Unlike `initial_schedule`, this field may be partial or advisory. It is
     /// consumed by the shared construction heuristic, which must complete it into
     /// a full valid schedule or fail explicitly.
-    #[serde(default)]
+    #[serde(default, alias = "constructionSeedSchedule")]
     pub construction_seed_schedule: Option<ApiSchedule>,
+    /// Optional encounters from earlier events that pre-seed the contact matrix.
+    ///
+    /// Historical encounters count toward `RepeatEncounter` limits, and pairs that
+    /// already met are not rewarded again as new unique contacts.
+    ///
+    /// Only solver1 models history: the auto engine runs such inputs on solver1 and the
+    /// other families reject them.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "contactHistory"
+    )]
+    pub contact_history: Option<ContactHistory>,
     /// Optimization objectives (defaults to empty list if not specified)
     #[serde(default)]
     pub objectives: Vec<Objective>,
     /// Constraints that must be satisfied or penalized (defaults to empty list)
     #[serde(default)]
     pub constraints: Vec<Constraint>,
-    /// Solver algorithm configuration and parameters
+    /// Solver algorithm configuration and parameters (defaults to the automatic solver)
+    #[serde(default)]
     pub solver: SolverConfiguration,
 }
 
+#[cfg(feature = "yaml")]
+impl ApiInput {
+    /// Parses an input written in YAML, using the same field names and defaults as JSON.
+    ///
+    /// Requires the `yaml` feature. YAML allows comments, which helps when editing large
+    /// problems by hand.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use gm_core::models::ApiInput;
+    ///
+    /// let input = ApiInput::from_yaml_str(
+    ///     "
+    /// problem:
+    ///   people:
+    ///     - {id: alice, attributes: {}}
+    ///     - {id: bob, attributes: {}}
+    ///     - {id: carol, attributes: {}}
+    ///     - {id: dave, attributes: {}}
+    ///   groups: [{id: t1, size: 2}, {id: t2, size: 2}]
+    ///   num_sessions: 3 # one per day
+    /// ",
+    /// )
+    /// .expect("input should parse");
+    /// # let _ = input;
+    /// ```
+    pub fn from_yaml_str(yaml: &str) -> Result<Self, serde_yaml::Error> {
+        serde_yaml::from_str(yaml)
+    }
+}
+
+#[cfg(feature = "toml")]
+impl ApiInput {
+    /// Parses an input written in TOML, using the same field names and defaults as JSON.
+    ///
+    /// Requires the `toml` feature. See [`toml_import`](crate::toml_import) for how TOML
+    /// values map onto the input.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use gm_core::models::ApiInput;
+    ///
+    /// let input = ApiInput::from_toml_str(
+    ///     r#"
+    /// [problem]
+    /// num_sessions = 3 # one per day
+    /// groups = [{ id = "t1", size = 2 }, { id = "t2", size = 2 }]
+    ///
+    /// [[problem.people]]
+    /// id = "alice"
+    /// attributes = {}
+    ///
+    /// [[problem.people]]
+    /// id = "bob"
+    /// attributes = {}
+    /// "#,
+    /// )
+    /// .expect("input should parse");
+    /// # let _ = input;
+    /// ```
+    pub fn from_toml_str(toml: &str) -> Result<Self, crate::toml_import::TomlImportError> {
+        crate::toml_import::from_toml_str(toml)
+    }
+}
+
+/// Encounters people already had before this schedule, e.g. at previous events.
+///
+/// # Example
+///
+/// ```no_run
+/// use gm_core::models::{ContactHistory, HistoricalContact};
+///
+/// // Alice and Bob met twice at the last event and once at the event before;
+/// // the older encounter counts half as much.
+/// let history = ContactHistory {
+///     contacts: vec![
+///         HistoricalContact {
+///             people: vec!["Alice".to_string(), "Bob".to_string()],
+///             count: 2,
+///             events_ago: 1,
+///         },
+///         HistoricalContact {
+///             people: vec!["Alice".to_string(), "Bob".to_string()],
+///             count: 1,
+///             events_ago: 2,
+///         },
+///     ],
+///     decay_factor: 0.5,
+/// };
+/// ```
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct ContactHistory {
+    /// Prior encounters per pair. Entries for the same pair are summed.
+    #[serde(default)]
+    pub contacts: Vec<HistoricalContact>,
+    /// Weight multiplier applied per event of age, in `(0, 1]`.
+    ///
+    /// An encounter `events_ago` events back counts as
+    /// `decay_factor^(events_ago - 1)` encounters. Defaults to `1.0` (no decay).
+    ///
+    /// A pair counts as having met while its summed weight rounds to at least one
+    /// encounter; below one half it is a new unique contact again.
+    #[serde(default = "default_contact_decay_factor", alias = "decayFactor")]
+    pub decay_factor: f64,
+}
+
+impl Default for ContactHistory {
+    fn default() -> Self {
+        Self {
+            contacts: Vec::new(),
+            decay_factor: default_contact_decay_factor(),
+        }
+    }
+}
+
+fn default_contact_decay_factor() -> f64 {
+    1.0
+}
+
+/// Number of prior encounters between two people.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct HistoricalContact {
+    /// Exactly two person IDs; both must exist in `problem.people`
+    pub people: Vec<String>,
+    /// How many times the pair has already met (defaults to 1)
+    #[serde(default = "default_historical_contact_count")]
+    pub count: u32,
+    /// How many events ago the encounters happened; `1` is the most recent event (default)
+    #[serde(default = "default_historical_contact_events_ago", alias = "eventsAgo")]
+    pub events_ago: u32,
+}
+
+fn default_historical_contact_count() -> u32 {
+    1
+}
+
+fn default_historical_contact_events_ago() -> u32 {
+    1
+}
+
 /// Defines the core optimization problem: people, groups, and sessions.
 ///
 /// This structure specifies the fundamental elements that need to be scheduled:
@@ -116,6 +309,7 @@ pub struct ProblemDefinition {
     /// List of all available groups with their capacity limits
     pub groups: Vec<Group>,
     /// Total number of scheduling sessions (time periods)
+    #[serde(alias = "numSessions")]
     pub num_sessions: u32,
 }
 
@@ -172,15 +366,26 @@ pub struct Person {
 /// When `session_sizes` is provided, it overrides that default on a
 /// per-session basis while keeping the same logical group ID across sessions.
 ///
+/// Groups can carry free-form `attributes` (for example a room's floor or
+/// whether it is wheelchair accessible) that `GroupAttributeRequirement`
+/// constraints match against.
+///
+/// `available_sessions` lists the sessions in which the group exists at all
+/// (for example a room that is only bookable in the morning). The group is
+/// closed, with capacity `0`, in every other session.
+///
 /// # Example
 ///
 /// ```no_run
 /// use gm_core::models::Group;
+/// use std::collections::HashMap;
 ///
 /// let team = Group {
 ///     id: "Development Team".to_string(),
 ///     size: 6, // Can hold up to 6 people
 ///     session_sizes: None,
+///     attributes: HashMap::from([("accessible".to_string(), "true".to_string())]),
+///     available_sessions: None,
 /// };
 /// ```
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
@@ -196,8 +401,45 @@ pub struct Group {
     /// entries. A value of `0` can be used to model a group that is closed in a
     /// specific session while preserving stable group IDs across the whole
     /// problem.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "sessionSizes"
+    )]
     pub session_sizes: Option<Vec<u32>>,
+    /// Key-value attributes describing this group (e.g., `{"accessible": "true"}`)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub attributes: HashMap<String, String>,
+    /// Optional list of 0-based sessions in which this group is open.
+    ///
+    /// If `None`, the group is open in every session. Outside the listed
+    /// sessions the group has no capacity, no one is assigned to it, and warm
+    /// starts may omit it.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "availableSessions"
+    )]
+    pub available_sessions: Option<Vec<u32>>,
+}
+
+impl Group {
+    /// Returns this group's capacity in `session_idx`.
+    ///
+    /// Honors `session_sizes` and reports `0` for sessions excluded by
+    /// `available_sessions`. Returns `None` when `session_sizes` has no entry
+    /// for `session_idx`, which solves reject as a length mismatch.
+    pub fn capacity_for_session(&self, session_idx: usize) -> Option<u32> {
+        if let Some(available_sessions) = &self.available_sessions {
+            if !available_sessions.contains(&(session_idx as u32)) {
+                return Some(0);
+            }
+        }
+        match &self.session_sizes {
+            Some(sizes) => sizes.get(session_idx).copied(),
+            None => Some(self.size),
+        }
+    }
 }
 
 /// Defines an optimization objective with its weight.
@@ -208,23 +450,53 @@ pub struct Group {
 /// # Supported Objective Types
 ///
 /// - `"maximize_unique_contacts"`: Maximize the number of unique person-to-person interactions
+/// - `"balance_group_sizes"`: Keep group sizes within each session as even as possible when
+///   capacity exceeds headcount (penalizes the squared deviation from the session's mean size)
+///
+/// `maximize_unique_contacts` also accepts `person_weights` to make meetings involving
+/// designated people (VIPs, speakers) count more: a new contact between two people is worth
+/// the product of their weights, and unlisted people default to `1.0`.
+///
+/// It also accepts `session_weights`, one per session, to make some sessions matter less
+/// (or more) than others. Each pair's contact credit and repetition penalty are shared
+/// equally among the sessions the pair met in, and each share is scaled by that session's
+/// weight, so repeats placed in low-weight sessions hurt least.
 ///
 /// # Example
 ///
 /// ```no_run
 /// use gm_core::models::Objective;
+/// use std::collections::HashMap;
 ///
 /// let objective = Objective {
 ///     r#type: "maximize_unique_contacts".to_string(),
 ///     weight: 1.0,
+///     person_weights: HashMap::from([("Keynote Speaker".to_string(), 3.0)]),
+///     // The third (wrap-up) session counts half as much as the morning sessions.
+///     session_weights: vec![1.0, 1.0, 0.5],
 /// };
 /// ```
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 pub struct Objective {
     /// The type of objective to optimize for
-    pub r#type: String, // "maximize_unique_contacts"
+    pub r#type: String, // "maximize_unique_contacts" | "balance_group_sizes"
     /// Weight of this objective in the overall optimization function
     pub weight: f64,
+    /// Optional per-person contact weights for `maximize_unique_contacts` (default `1.0`)
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        alias = "personWeights"
+    )]
+    pub person_weights: HashMap<String, f64>,
+    /// Optional per-session weights for `maximize_unique_contacts`, one entry per session;
+    /// they also scale the repetition penalty (empty = every session weighs `1.0`)
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        alias = "sessionWeights"
+    )]
+    pub session_weights: Vec<f64>,
 }
 
 /// Represents a constraint that must be satisfied or penalized during optimization.
@@ -245,6 +517,12 @@ pub struct Objective {
 /// - **MustStayApart**: Requires certain people to be in different groups
 /// - **ShouldStayTogether**: Prefers certain people to be in the same group (soft)
 /// - **ShouldNotBeTogether**: Discourages certain people from being in the same group (soft)
+/// - **BaselineMoveLimit**: Caps how many people may change groups relative to `initial_schedule`
+/// - **GroupAttributeRequirement**: Restricts people to groups whose attributes match (hard)
+/// - **UniqueGroupComposition**: Penalizes the exact same set of people forming a group in more
+///   than one session
+/// - **TrackGrouping**: Organizes groups into tracks and keeps each person in one track for the
+///   whole event (hard)
 ///
 /// # Examples
 ///
@@ -319,7 +597,7 @@ pub enum Constraint {
         /// List of person IDs that should be together
         people: Vec<String>,
         /// Penalty weight when the people are not together
-        #[serde(default = "default_constraint_weight")]
+        #[serde(default = "default_constraint_weight", alias = "penaltyWeight")]
         penalty_weight: f64,
         /// Optional list of session indices where this constraint applies.
         /// If `None`, applies to all sessions.
@@ -331,7 +609,7 @@ pub enum Constraint {
         /// List of person IDs that should not be together
         people: Vec<String>,
         /// Penalty weight for violations (higher = more important)
-        #[serde(default = "default_constraint_weight")]
+        #[serde(default = "default_constraint_weight", alias = "penaltyWeight")]
         penalty_weight: f64,
         /// Optional list of session indices where this constraint applies.
         /// If `None`, applies to all sessions.
@@ -342,10 +620,39 @@ pub enum Constraint {
     ImmovablePeople(ImmovablePeopleParams),
     /// Constrains a pair's meeting count across a fixed subset of sessions
     PairMeetingCount(PairMeetingCountParams),
+    /// Caps how many people may change groups relative to `initial_schedule` (hard constraint)
+    BaselineMoveLimit(BaselineMoveLimitParams),
+    /// Penalizes the same set of people forming a group in more than one session
+    UniqueGroupComposition(UniqueGroupCompositionParams),
+    /// Restricts people to groups whose attributes match (hard constraint)
+    GroupAttributeRequirement(GroupAttributeRequirementParams),
+    /// Splits groups into tracks and keeps each person in one track for the whole event (hard constraint)
+    TrackGrouping(TrackGroupingParams),
+}
+
+impl Constraint {
+    /// The constraint's `type` tag, e.g. `"MustStayTogether"`.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Constraint::RepeatEncounter(_) => "RepeatEncounter",
+            Constraint::AttributeBalance(_) => "AttributeBalance",
+            Constraint::ImmovablePerson(_) => "ImmovablePerson",
+            Constraint::MustStayTogether { .. } => "MustStayTogether",
+            Constraint::MustStayApart { .. } => "MustStayApart",
+            Constraint::ShouldStayTogether { .. } => "ShouldStayTogether",
+            Constraint::ShouldNotBeTogether { .. } => "ShouldNotBeTogether",
+            Constraint::ImmovablePeople(_) => "ImmovablePeople",
+            Constraint::PairMeetingCount(_) => "PairMeetingCount",
+            Constraint::BaselineMoveLimit(_) => "BaselineMoveLimit",
+            Constraint::UniqueGroupComposition(_) => "UniqueGroupComposition",
+            Constraint::GroupAttributeRequirement(_) => "GroupAttributeRequirement",
+            Constraint::TrackGrouping(_) => "TrackGrouping",
+        }
+    }
 }
 
 /// Default penalty weight for constraints that don't specify one
-fn default_constraint_weight() -> f64 {
+pub(crate) fn default_constraint_weight() -> f64 {
     1000.0
 }
 
@@ -370,14 +677,146 @@ pub struct PairMeetingCountParams {
     /// Sessions to consider for counting meetings (must be within problem.sessions)
     pub sessions: Vec<u32>,
     /// Target number of meetings within the provided sessions (0..=sessions.len())
-    #[serde(alias = "min_meetings")]
+    #[serde(alias = "min_meetings", alias = "targetMeetings")]
     pub target_meetings: u32,
     /// Penalty mode: at_least (default), exact, or at_most
     #[serde(default)]
     pub mode: PairMeetingMode,
     /// Linear penalty weight
-    #[serde(default = "default_constraint_weight")]
+    #[serde(default = "default_constraint_weight", alias = "penaltyWeight")]
+    pub penalty_weight: f64,
+}
+
+/// How a `BaselineMoveLimit` cap is counted across its sessions.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum BaselineMoveLimitScope {
+    /// The cap applies to the sum of moved people across all covered sessions
+    #[default]
+    Total,
+    /// The cap applies to each covered session independently
+    PerSession,
+}
+
+/// Hard cap on how far the solver may drift from the baseline `initial_schedule`.
+///
+/// A person counts as moved in a session when they participate in that session
+/// and their group differs from the group `initial_schedule` placed them in.
+/// The limit is enforced through move feasibility, so the search never
+/// proposes a schedule that exceeds it. Requires `initial_schedule`.
+///
+/// # Example
+///
+/// ```no_run
+/// use gm_core::models::{BaselineMoveLimitParams, BaselineMoveLimitScope};
+///
+/// // Allow at most 3 people to change groups in each session
+/// let params = BaselineMoveLimitParams {
+///     max_moves: 3,
+///     scope: BaselineMoveLimitScope::PerSession,
+///     sessions: None,
+/// };
+/// ```
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct BaselineMoveLimitParams {
+    /// Maximum number of moved people allowed within the scope
+    #[serde(alias = "maxMoves")]
+    pub max_moves: u32,
+    /// Whether `max_moves` applies to all covered sessions combined (default) or to each one
+    #[serde(default)]
+    pub scope: BaselineMoveLimitScope,
+    /// Optional list of session indices covered by the limit.
+    /// If `None`, applies to all sessions.
+    #[serde(default)]
+    pub sessions: Option<Vec<u32>>,
+}
+
+/// Soft constraint against the same group forming twice.
+///
+/// A group's composition is the set of participating people in it; groups with fewer than
+/// two participants are ignored. Every extra session in which an already-seen composition
+/// appears counts as one violation, even if no pair exceeds its repeat-encounter limit.
+/// Only the covered sessions are compared with each other.
+///
+/// # Example
+///
+/// ```no_run
+/// use gm_core::models::UniqueGroupCompositionParams;
+///
+/// let params = UniqueGroupCompositionParams {
+///     penalty_weight: 1000.0,
+///     sessions: None,
+/// };
+/// ```
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct UniqueGroupCompositionParams {
+    /// Penalty weight per repeated group composition
+    #[serde(default = "default_constraint_weight", alias = "penaltyWeight")]
     pub penalty_weight: f64,
+    /// Optional list of session indices to compare.
+    /// If `None`, applies to all sessions.
+    #[serde(default)]
+    pub sessions: Option<Vec<u32>>,
+}
+
+/// Hard constraint restricting people to groups with matching attributes.
+///
+/// Each listed person may only be placed in groups whose `attributes` contain every
+/// `required_attributes` entry with the same value, for example a wheelchair user who must
+/// be seated in accessible rooms. Several requirements for the same person combine. Moves
+/// that would violate a requirement are never proposed.
+///
+/// # Example
+///
+/// ```no_run
+/// use gm_core::models::GroupAttributeRequirementParams;
+/// use std::collections::HashMap;
+///
+/// let params = GroupAttributeRequirementParams {
+///     people: vec!["Alice".to_string()],
+///     required_attributes: HashMap::from([("accessible".to_string(), "true".to_string())]),
+///     sessions: None,
+/// };
+/// ```
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct GroupAttributeRequirementParams {
+    /// IDs of the people who have the requirement
+    pub people: Vec<String>,
+    /// Group attributes an allowed group must have, as key-value pairs
+    #[serde(alias = "requiredAttributes")]
+    pub required_attributes: HashMap<String, String>,
+    /// Optional list of session indices where the requirement applies.
+    /// If `None`, applies to all sessions.
+    #[serde(default)]
+    pub sessions: Option<Vec<u32>>,
+}
+
+/// Hard constraint adding a track level above groups (for example conference breakouts
+/// containing tables).
+///
+/// Every group names its track through the `track_attribute` group attribute. Each person
+/// is assigned to one track for the whole event and then rotates between the tables (groups)
+/// of that track from session to session. People whose own `attributes` carry the same key
+/// are placed in that track; everyone else is assigned to tracks by the solver, keeping
+/// `MustStayTogether` cliques in one track and balancing tracks against their capacity.
+///
+/// Constraints can target either level: group-level constraints work as usual, while
+/// `GroupAttributeRequirement` on `track_attribute` pins people to a track.
+///
+/// # Example
+///
+/// ```no_run
+/// use gm_core::models::TrackGroupingParams;
+///
+/// let params = TrackGroupingParams {
+///     track_attribute: "track".to_string(),
+/// };
+/// ```
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct TrackGroupingParams {
+    /// Group (and optional person) attribute key naming the track
+    #[serde(alias = "trackAttribute")]
+    pub track_attribute: String,
 }
 
 /// Parameters for the RepeatEncounter constraint.
@@ -401,10 +840,13 @@ pub struct PairMeetingCountParams {
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 pub struct RepeatEncounterParams {
     /// Maximum number of times two people can be in the same group
+    #[serde(alias = "maxAllowedEncounters")]
     pub max_allowed_encounters: u32,
     /// Penalty function type: "squared" for quadratic penalties, "linear" for linear penalties
+    #[serde(alias = "penaltyFunction")]
     pub penalty_function: String, // "squared" or "linear"
     /// Weight of the penalty applied for constraint violations
+    #[serde(alias = "penaltyWeight")]
     pub penalty_weight: f64,
 }
 
@@ -439,12 +881,16 @@ pub struct RepeatEncounterParams {
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 pub struct AttributeBalanceParams {
     /// ID of the group where this balance constraint applies
+    #[serde(alias = "groupId")]
     pub group_id: String,
     /// The attribute key to balance (e.g., "gender", "department")
+    #[serde(alias = "attributeKey")]
     pub attribute_key: String,
     /// Desired count for each attribute value (e.g., {"male": 2, "female": 2})
+    #[serde(alias = "desiredValues")]
     pub desired_values: HashMap<String, u32>,
     /// Weight of the penalty applied for balance violations
+    #[serde(alias = "penaltyWeight")]
     pub penalty_weight: f64,
     /// How to interpret desired counts. `Exact` penalizes deviation in either direction,
     /// `AtLeast` penalizes only shortfalls (overshoot is not penalized).
@@ -487,8 +933,10 @@ pub enum AttributeBalanceMode {
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 pub struct ImmovablePersonParams {
     /// ID of the person who must be fixed in place
+    #[serde(alias = "personId")]
     pub person_id: String,
     /// ID of the group where this person must be placed
+    #[serde(alias = "groupId")]
     pub group_id: String,
     /// List of session indices where this person must be in the specified group.
     /// If `None`, applies to all sessions.
@@ -506,6 +954,7 @@ pub struct ImmovablePeopleParams {
     /// IDs of the people who must be fixed in place
     pub people: Vec<String>,
     /// ID of the group where these people must be placed
+    #[serde(alias = "groupId")]
     pub group_id: String,
     /// List of session indices where these people must be in the specified group.
     /// If `None`, applies to all sessions.
@@ -530,6 +979,9 @@ pub struct ImmovablePeopleParams {
 ///         time_limit_seconds: Some(60),
 ///         no_improvement_iterations: Some(5_000),
 ///         stop_on_optimal_score: true,
+///         stop_on_feasible: false,
+///         feasible_score_threshold: None,
+///         convergence: None,
 ///     },
 ///     solver_params: SolverParams::SimulatedAnnealing(
 ///         SimulatedAnnealingParams {
@@ -538,6 +990,7 @@ pub struct ImmovablePeopleParams {
 ///             cooling_schedule: "geometric".to_string(),
 ///             reheat_after_no_improvement: Some(0),
 ///             reheat_cycles: Some(0),
+///             penalty_ramp: None,
 ///         }
 ///     ),
 ///     logging: LoggingOptions {
@@ -552,19 +1005,27 @@ pub struct ImmovablePeopleParams {
 ///     allowed_sessions: None,
 /// };
 /// ```
+///
+/// Every field may be omitted from JSON. A missing `solver_type` is taken from `solver_params`
+/// (or is `"auto"` when both are missing), and missing `solver_params` or `stop_conditions` fall
+/// back to that solver family's defaults.
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+#[serde(from = "SolverConfigurationInput")]
 pub struct SolverConfiguration {
     /// Compatibility-facing solver identifier accepted at the current public parse boundary.
     ///
     /// Internally, `gm-core` resolves this string into a typed `SolverKind` so solver-family
     /// selection is explicit even while the public contract is still migrating away from the
     /// legacy string-only shape.
+    #[serde(alias = "solverType")]
     pub solver_type: String,
     /// Conditions that determine when to stop optimization
+    #[serde(alias = "stopConditions")]
     pub stop_conditions: StopConditions,
     /// Algorithm-specific parameters.
     ///
     /// This must describe the same solver family as `solver_type`.
+    #[serde(alias = "solverParams")]
     pub solver_params: SolverParams,
     /// Logging and output preferences (defaults to minimal logging)
     #[serde(default)]
@@ -583,15 +1044,87 @@ pub struct SolverConfiguration {
     /// Optional move-policy override controlling which move families may run and how they are selected.
     ///
     /// When omitted, the solver preserves the current adaptive mixed-search behavior.
-    #[serde(default)]
+    #[serde(default, alias = "movePolicy")]
     pub move_policy: Option<MovePolicy>,
     /// Optional allow-list of session indices that the solver is allowed to modify during iterations.
     /// If present, the solver will only generate moves within these sessions, leaving others unchanged.
     /// Session indices are 0-based.
-    #[serde(default)]
+    #[serde(default, alias = "allowedSessions")]
     pub allowed_sessions: Option<Vec<u32>>,
 }
 
+impl Default for SolverConfiguration {
+    fn default() -> Self {
+        crate::default_solver_configuration()
+    }
+}
+
+/// JSON shape of [`SolverConfiguration`] before missing fields are filled in.
+#[derive(Deserialize, JsonSchema)]
+struct SolverConfigurationInput {
+    /// Solver identifier such as `"auto"`, `"solver1"`, or `"solver3"`. Defaults to the family
+    /// of `solver_params`, or to `"auto"`.
+    #[serde(default, alias = "solverType")]
+    solver_type: Option<String>,
+    /// Conditions that determine when to stop optimization. Defaults to the solver family's.
+    #[serde(default, alias = "stopConditions")]
+    stop_conditions: Option<StopConditions>,
+    /// Algorithm-specific parameters. Defaults to the solver family's.
+    #[serde(default, alias = "solverParams")]
+    solver_params: Option<SolverParams>,
+    /// Logging and output preferences (defaults to minimal logging)
+    #[serde(default)]
+    logging: LoggingOptions,
+    /// Telemetry options controlling what is emitted via progress updates.
+    #[serde(default)]
+    telemetry: TelemetryOptions,
+    /// Optional seed used to make solver runs reproducible.
+    #[serde(default)]
+    seed: Option<u64>,
+    /// Optional move-policy override controlling which move families may run and how they are selected.
+    #[serde(default, alias = "movePolicy")]
+    move_policy: Option<MovePolicy>,
+    /// Optional allow-list of 0-based session indices that the solver may modify.
+    #[serde(default, alias = "allowedSessions")]
+    allowed_sessions: Option<Vec<u32>>,
+}
+
+impl From<SolverConfigurationInput> for SolverConfiguration {
+    fn from(input: SolverConfigurationInput) -> Self {
+        let kind = match (&input.solver_type, &input.solver_params) {
+            (Some(solver_type), _) => SolverKind::parse_config_id(solver_type).ok(),
+            (None, Some(params)) => Some(params.solver_kind()),
+            (None, None) => Some(DEFAULT_SOLVER_KIND),
+        };
+        // An unknown `solver_type` is kept as written so that validation can report it.
+        let defaults = kind.map(crate::default_solver_configuration_for);
+
+        Self {
+            solver_type: input.solver_type.unwrap_or_else(|| {
+                kind.unwrap_or(DEFAULT_SOLVER_KIND)
+                    .canonical_id()
+                    .to_string()
+            }),
+            stop_conditions: input.stop_conditions.unwrap_or_else(|| {
+                defaults
+                    .as_ref()
+                    .map(|defaults| defaults.stop_conditions.clone())
+                    .unwrap_or_default()
+            }),
+            solver_params: input.solver_params.unwrap_or_else(|| {
+                defaults
+                    .map(|defaults| defaults.solver_params)
+                    .unwrap_or_default()
+            }),
+            logging: input.logging,
+            telemetry: input.telemetry,
+            seed: input.seed,
+            move_policy: input.move_policy,
+            allowed_sessions: input.allowed_sessions,
+        }
+    }
+}
+
 /// Typed solver-family identifier used internally by `gm-core`.
 ///
 /// The repo is preparing for multiple solver families, but the wider public contract still uses
@@ -705,7 +1238,8 @@ impl SolverConfiguration {
         SolverKind::parse_config_id(&self.solver_type)
     }
 
-    /// Validates that `solver_type` and `solver_params` describe the same solver family.
+    /// Validates that `solver_type` and `solver_params` describe the same solver family, and
+    /// that the stop conditions only use what that family supports.
     pub fn validate_solver_selection(&self) -> Result<SolverKind, String> {
         let declared_kind = self.solver_kind()?;
         let params_kind = self.solver_params.solver_kind();
@@ -718,6 +1252,14 @@ impl SolverConfiguration {
                 params_kind.canonical_id()
             ));
         }
+        if declared_kind != SolverKind::Solver1 {
+            if let Some(field) = self.stop_conditions.solver1_only_field() {
+                return Err(format!(
+                    "{} does not support stop_conditions.{field}; use solver1 for this input",
+                    declared_kind.canonical_id()
+                ));
+            }
+        }
 
         Ok(declared_kind)
     }
@@ -780,6 +1322,7 @@ pub enum MoveSelectionMode {
 pub struct MoveFamilyWeights {
     pub swap: f64,
     pub transfer: f64,
+    #[serde(alias = "cliqueSwap")]
     pub clique_swap: f64,
 }
 
@@ -802,12 +1345,12 @@ pub struct MovePolicy {
     /// Optional allow-list of move families for this run.
     ///
     /// When omitted, all move families are allowed.
-    #[serde(default)]
+    #[serde(default, alias = "allowedFamilies")]
     pub allowed_families: Option<Vec<MoveFamily>>,
     /// Optional single-family override used for path tests and diagnostics.
     ///
     /// When present, the search loop will only attempt the specified move family.
-    #[serde(default)]
+    #[serde(default, alias = "forcedFamily")]
     pub forced_family: Option<MoveFamily>,
     /// Optional explicit family weights used when `mode` is `weighted`.
     #[serde(default)]
@@ -916,14 +1459,83 @@ pub struct TelemetryOptions {
     ///
     /// This can be expensive for large problems (it clones and serializes schedules), so it is
     /// disabled by default.
-    #[serde(default)]
+    #[serde(default, alias = "emitBestSchedule")]
     pub emit_best_schedule: bool,
 
     /// Include a schedule snapshot every N progress callbacks (when enabled).
     ///
     /// Values <= 1 mean \"every callback\".
-    #[serde(default)]
+    #[serde(default, alias = "bestScheduleEveryNCallbacks")]
     pub best_schedule_every_n_callbacks: u64,
+
+    /// When true, snapshots after the first are sent as a `best_schedule_diff` against the
+    /// previous snapshot instead of in full. The final progress update always carries the full
+    /// schedule.
+    #[serde(default, alias = "bestScheduleAsDiff")]
+    pub best_schedule_as_diff: bool,
+
+    /// When true, the result carries the pairwise meeting counts of the final schedule in
+    /// `contact_matrix`.
+    #[serde(default, alias = "includeContactMatrix")]
+    pub include_contact_matrix: bool,
+
+    /// When true, the result carries the `name` attribute of every person that has one in
+    /// `display_names`.
+    #[serde(default, alias = "includeDisplayNames")]
+    pub include_display_names: bool,
+
+    /// Person attributes to echo per person in the result's `person_attributes`, so that
+    /// renderers need not join the result against the input. Empty (the default) leaves
+    /// `person_attributes` out.
+    #[serde(default, alias = "includePersonAttributes")]
+    pub include_person_attributes: Vec<String>,
+
+    /// When true, the result carries a sampled `score_history` trace of the search.
+    #[serde(default, alias = "recordScoreHistory")]
+    pub record_score_history: bool,
+
+    /// Record a score history point every N iterations (when enabled).
+    ///
+    /// Values <= 1 mean \"every iteration\".
+    #[serde(default, alias = "scoreHistoryEveryNIterations")]
+    pub score_history_every_n_iterations: u64,
+
+    /// When true, the search samples per-operator proposed/accepted counts, the uphill
+    /// acceptance rate, and the temperature into `move_statistics` on progress updates and on
+    /// the result (solver1 only).
+    #[serde(default, alias = "recordMoveStatistics")]
+    pub record_move_statistics: bool,
+
+    /// Close a move statistics window every N iterations (when enabled).
+    ///
+    /// Values <= 1 mean \"every iteration\".
+    #[serde(default, alias = "moveStatisticsEveryNIterations")]
+    pub move_statistics_every_n_iterations: u64,
+
+    /// When true, the search samples the weighted penalty of every soft constraint into
+    /// `constraint_penalties` on progress updates and on the result (solver1 only).
+    #[serde(default, alias = "recordConstraintPenalties")]
+    pub record_constraint_penalties: bool,
+
+    /// Sample constraint penalties every N iterations (when enabled).
+    ///
+    /// Each sample walks the whole schedule. Values <= 1 mean \"every iteration\".
+    #[serde(default, alias = "constraintPenaltiesEveryNIterations")]
+    pub constraint_penalties_every_n_iterations: u64,
+
+    /// Attach a resumable `checkpoint` to a progress update every N iterations (solver1 only).
+    ///
+    /// `0` (the default) disables checkpoints.
+    #[serde(default, alias = "checkpointEveryNIterations")]
+    pub checkpoint_every_n_iterations: u64,
+
+    /// Re-score the current schedule from scratch every N iterations and log the first score
+    /// component whose incrementally maintained value has drifted (solver1 only).
+    ///
+    /// Each check copies the whole search state, so this is a debugging aid. `0` (the default)
+    /// disables it.
+    #[serde(default, alias = "driftCheckEveryNIterations")]
+    pub drift_check_every_n_iterations: u64,
 }
 
 impl Default for TelemetryOptions {
@@ -933,6 +1545,18 @@ impl Default for TelemetryOptions {
             // A safe default in case someone enables telemetry without tuning.
             // (Progress callbacks are time-based; snapshots can be large.)
             best_schedule_every_n_callbacks: 5,
+            best_schedule_as_diff: false,
+            include_contact_matrix: false,
+            include_display_names: false,
+            include_person_attributes: Vec::new(),
+            record_score_history: false,
+            score_history_every_n_iterations: 100,
+            record_move_statistics: false,
+            move_statistics_every_n_iterations: 1000,
+            record_constraint_penalties: false,
+            constraint_penalties_every_n_iterations: 1000,
+            checkpoint_every_n_iterations: 0,
+            drift_check_every_n_iterations: 0,
         }
     }
 }
@@ -954,23 +1578,120 @@ impl Default for TelemetryOptions {
 ///     time_limit_seconds: Some(30),
 ///     no_improvement_iterations: Some(1_000),
 ///     stop_on_optimal_score: true,
+///     stop_on_feasible: false,
+///     feasible_score_threshold: None,
+///     convergence: None,
 /// };
 /// ```
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 pub struct StopConditions {
     /// Maximum number of optimization iterations before stopping
+    #[serde(default, alias = "maxIterations")]
     pub max_iterations: Option<u64>,
-    /// Maximum time in seconds before stopping
+    /// Maximum time in seconds before stopping. solver1 does not count time spent inside the
+    /// progress callback, such as a paused [`SolverHandle`](crate::solver_handle::SolverHandle).
+    #[serde(default, alias = "timeLimitSeconds")]
     pub time_limit_seconds: Option<u64>,
     /// Stop if no improvement found for this many iterations
+    #[serde(default, alias = "noImprovementIterations")]
     pub no_improvement_iterations: Option<u64>,
     /// Stop immediately when the best-known score reaches the theoretical optimum of zero.
     ///
     /// Defaults to `true` for user-facing solve runs. Benchmark lanes can disable this so
     /// fixed-budget measurements continue consuming their configured search budget even when an
     /// optimal state is discovered early.
-    #[serde(default = "default_stop_on_optimal_score")]
+    #[serde(
+        default = "default_stop_on_optimal_score",
+        alias = "stopOnOptimalScore"
+    )]
     pub stop_on_optimal_score: bool,
+    /// Stop once the best schedule violates no hard constraint (`MustStayTogether`,
+    /// `MustStayApart`, `ImmovablePeople`) and its score is at or below
+    /// `feasible_score_threshold`, for runs where a valid schedule matters more than maximal
+    /// mixing (solver1 only; other families reject it).
+    #[serde(default, alias = "stopOnFeasible")]
+    pub stop_on_feasible: bool,
+    /// Highest best score at which `stop_on_feasible` stops the run; `None` stops on the first
+    /// feasible best schedule (solver1 only; other families reject it)
+    #[serde(default, alias = "feasibleScoreThreshold")]
+    pub feasible_score_threshold: Option<f64>,
+    /// Stop once the search has converged, judged by improvement, acceptance rate, and
+    /// temperature (solver1 only; other families reject it).
+    #[serde(default)]
+    pub convergence: Option<ConvergenceStop>,
+}
+
+impl StopConditions {
+    /// The first stop condition set here that only solver1 implements.
+    fn solver1_only_field(&self) -> Option<&'static str> {
+        if self.stop_on_feasible {
+            Some("stop_on_feasible")
+        } else if self.feasible_score_threshold.is_some() {
+            Some("feasible_score_threshold")
+        } else {
+            self.convergence.is_some().then_some("convergence")
+        }
+    }
+}
+
+impl Default for StopConditions {
+    /// No iteration, time, or stagnation limit; stops early only on an optimal score.
+    fn default() -> Self {
+        Self {
+            max_iterations: None,
+            time_limit_seconds: None,
+            no_improvement_iterations: None,
+            stop_on_optimal_score: default_stop_on_optimal_score(),
+            stop_on_feasible: false,
+            feasible_score_threshold: None,
+            convergence: None,
+        }
+    }
+}
+
+/// Convergence test of [`StopConditions::convergence`].
+///
+/// Checked at the end of every window of `window_iterations` iterations. The run stops with
+/// [`StopReason::Converged`] when, over that window, the best score did not improve, at most
+/// `max_acceptance_rate` of the proposed worsening moves were accepted, and the temperature is
+/// at or below `max_temperature`. Neutral moves are left out of the rate because a converged
+/// search can still drift across a plateau. Unlike `no_improvement_iterations`, a hot search that is still
+/// wandering keeps running even if it has not found a better schedule for a while.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+pub struct ConvergenceStop {
+    /// Iterations per window (default 5000)
+    #[serde(
+        default = "default_convergence_window_iterations",
+        alias = "windowIterations"
+    )]
+    pub window_iterations: u64,
+    /// Highest share of worsening moves accepted in a converged window (default 0.01)
+    #[serde(
+        default = "default_convergence_max_acceptance_rate",
+        alias = "maxAcceptanceRate"
+    )]
+    pub max_acceptance_rate: f64,
+    /// Highest temperature a converged search may have; `None` accepts any temperature
+    #[serde(default, alias = "maxTemperature")]
+    pub max_temperature: Option<f64>,
+}
+
+fn default_convergence_window_iterations() -> u64 {
+    5_000
+}
+
+fn default_convergence_max_acceptance_rate() -> f64 {
+    0.01
+}
+
+impl Default for ConvergenceStop {
+    fn default() -> Self {
+        Self {
+            window_iterations: default_convergence_window_iterations(),
+            max_acceptance_rate: default_convergence_max_acceptance_rate(),
+            max_temperature: None,
+        }
+    }
 }
 
 pub const OPTIMAL_SCORE_TOLERANCE: f64 = 1e-9;
@@ -1014,6 +1735,12 @@ pub enum SolverParams {
     Solver6(Solver6Params),
 }
 
+impl Default for SolverParams {
+    fn default() -> Self {
+        Self::Auto(AutoSolverParams::default())
+    }
+}
+
 impl SolverParams {
     pub fn solver_kind(&self) -> SolverKind {
         match self {
@@ -1125,12 +1852,13 @@ pub enum Solver6CacheWritePolicy {
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
 pub struct Solver6CacheParams {
     /// Directory containing one cache entry per pure-SGP shape.
+    #[serde(alias = "rootPath")]
     pub root_path: String,
     /// Explicit miss behavior when a compatible entry is absent.
-    #[serde(default)]
+    #[serde(default, alias = "missPolicy")]
     pub miss_policy: Solver6CacheMissPolicy,
     /// Explicit write behavior after a live solve or resumed incumbent improves the cache.
-    #[serde(default)]
+    #[serde(default, alias = "writePolicy")]
     pub write_policy: Solver6CacheWritePolicy,
 }
 
@@ -1143,25 +1871,36 @@ pub struct Solver6CacheParams {
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 pub struct Solver6Params {
     /// Whether exact pure-SGP requests should first be handed to solver5.
-    #[serde(default = "default_solver6_exact_construction_handoff_enabled")]
+    #[serde(
+        default = "default_solver6_exact_construction_handoff_enabled",
+        alias = "exactConstructionHandoffEnabled"
+    )]
     pub exact_construction_handoff_enabled: bool,
     /// Seed-family selection for the reserved hybrid pipeline.
-    #[serde(default)]
+    #[serde(default, alias = "seedStrategy")]
     pub seed_strategy: Solver6SeedStrategy,
     /// Repeat-penalty model for the reserved hybrid pipeline.
-    #[serde(default)]
+    #[serde(default, alias = "pairRepeatPenaltyModel")]
     pub pair_repeat_penalty_model: Solver6PairRepeatPenaltyModel,
     /// Search-driver selection for the reserved hybrid pipeline.
-    #[serde(default)]
+    #[serde(default, alias = "searchStrategy")]
     pub search_strategy: Solver6SearchStrategy,
     /// Optional progressive incumbent cache used as an explicit solver6-native cache.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cache: Option<Solver6CacheParams>,
     /// Optional hard deadline for seed construction on cache misses, in seconds.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "seedTimeLimitSeconds"
+    )]
     pub seed_time_limit_seconds: Option<u64>,
     /// Optional local-search deadline, in seconds. Local-search timeout returns the incumbent.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "localSearchTimeLimitSeconds"
+    )]
     pub local_search_time_limit_seconds: Option<u64>,
 }
 
@@ -1195,16 +1934,16 @@ pub struct Solver3Params {
     #[serde(default)]
     pub construction: Solver3ConstructionParams,
     /// Outer search-driver mode for solver3.
-    #[serde(default)]
+    #[serde(default, alias = "searchDriver")]
     pub search_driver: Solver3SearchDriverParams,
     /// Local-improver mode used by the selected driver.
-    #[serde(default)]
+    #[serde(default, alias = "localImprover")]
     pub local_improver: Solver3LocalImproverParams,
     /// Correctness-lane controls for sampled runtime validation during real search runs.
-    #[serde(default)]
+    #[serde(default, alias = "correctnessLane")]
     pub correctness_lane: Solver3CorrectnessLaneParams,
     /// Experimental hotspot-guidance controls for proposal-generation research.
-    #[serde(default)]
+    #[serde(default, alias = "hotspotGuidance")]
     pub hotspot_guidance: Solver3HotspotGuidanceParams,
 }
 
@@ -1225,13 +1964,16 @@ pub enum Solver4Mode {
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 pub struct Solver4DiagnosticsParams {
     /// When true, record solver4-specific paper-trace diagnostics in benchmark telemetry.
-    #[serde(default)]
+    #[serde(default, alias = "capturePaperTrace")]
     pub capture_paper_trace: bool,
     /// Record at most one trace point every N iterations in greedy-local-search mode.
-    #[serde(default = "default_solver4_trace_every_n_iterations")]
+    #[serde(
+        default = "default_solver4_trace_every_n_iterations",
+        alias = "traceEveryNIterations"
+    )]
     pub trace_every_n_iterations: u64,
     /// When paper-trace capture is enabled, include the greedy initial schedule in telemetry.
-    #[serde(default)]
+    #[serde(default, alias = "includeInitialScheduleInTrace")]
     pub include_initial_schedule_in_trace: bool,
 }
 
@@ -1266,7 +2008,11 @@ pub struct Solver4Params {
     #[serde(default = "default_solver4_gamma")]
     pub gamma: f64,
     /// Optional Section 5 backtracking pattern such as `3`, `2-2`, `4`, or `3-2-2-1`.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "backtrackingPattern"
+    )]
     pub backtracking_pattern: Option<String>,
     /// Optional solver4-specific trace capture.
     #[serde(default)]
@@ -1299,14 +2045,14 @@ pub struct Solver3ConstructionParams {
     #[serde(default)]
     pub mode: Solver3ConstructionMode,
     /// Parameters for the freedom-aware randomized greedy constructor.
-    #[serde(default)]
+    #[serde(default, alias = "freedomAware")]
     pub freedom_aware: Solver3FreedomAwareConstructionParams,
     /// Internal benchmark/research controls for the constraint-aware projection prototype.
     ///
     /// This is intentionally skipped at the public JSON boundary so product callers do not get a
     /// user-facing oracle/projection knob. Internal benchmark policy code may still set it after
     /// loading a scenario.
-    #[serde(skip)]
+    #[serde(skip, alias = "constraintAwareProjection")]
     pub constraint_aware_projection: Solver3ConstraintAwareProjectionParams,
 }
 
@@ -1318,7 +2064,11 @@ pub struct Solver3ConstraintAwareProjectionParams {
     pub enabled: bool,
     /// Relabeling wall-clock budget in seconds. When omitted, the constructor passes its remaining
     /// internal budget to the relabeler.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "relabelingTimeoutSeconds"
+    )]
     pub relabeling_timeout_seconds: Option<f64>,
 }
 
@@ -1415,19 +2165,19 @@ pub struct Solver3SearchDriverParams {
     pub mode: Solver3SearchDriverMode,
     /// Optional solver3 wall-clock stagnation stop: after a best score is found at search time T,
     /// stop when no further improvement appears within `(T * runtime_scale_factor) + grace_seconds`.
-    #[serde(default)]
+    #[serde(default, alias = "runtimeScaledNoImprovementStop")]
     pub runtime_scaled_no_improvement_stop: Solver3RuntimeScaledNoImprovementStopParams,
     /// Config for the steady-state memetic outer driver.
-    #[serde(default)]
+    #[serde(default, alias = "steadyStateMemetic")]
     pub steady_state_memetic: Solver3SteadyStateMemeticParams,
     /// Config for the rare donor-session transplant outer driver.
-    #[serde(default)]
+    #[serde(default, alias = "donorSessionTransplant")]
     pub donor_session_transplant: Solver3DonorSessionTransplantParams,
     /// Config for the rare session-aligned path relinking outer driver.
-    #[serde(default)]
+    #[serde(default, alias = "sessionAlignedPathRelinking")]
     pub session_aligned_path_relinking: Solver3SessionAlignedPathRelinkingParams,
     /// Config for the rare multi-root balanced session inheritance outer driver.
-    #[serde(default)]
+    #[serde(default, alias = "multiRootBalancedSessionInheritance")]
     pub multi_root_balanced_session_inheritance: Solver3MultiRootBalancedSessionInheritanceParams,
 }
 
@@ -1438,10 +2188,16 @@ pub struct Solver3RuntimeScaledNoImprovementStopParams {
     #[serde(default)]
     pub enabled: bool,
     /// Multiplier applied to the current incumbent runtime before adding grace seconds.
-    #[serde(default = "default_solver3_runtime_scaled_no_improvement_runtime_scale_factor")]
+    #[serde(
+        default = "default_solver3_runtime_scaled_no_improvement_runtime_scale_factor",
+        alias = "runtimeScaleFactor"
+    )]
     pub runtime_scale_factor: f64,
     /// Additive grace seconds after the scaled current incumbent runtime.
-    #[serde(default = "default_solver3_runtime_scaled_no_improvement_grace_seconds")]
+    #[serde(
+        default = "default_solver3_runtime_scaled_no_improvement_grace_seconds",
+        alias = "graceSeconds"
+    )]
     pub grace_seconds: f64,
 }
 
@@ -1468,49 +2224,75 @@ fn default_solver3_runtime_scaled_no_improvement_grace_seconds() -> f64 {
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 pub struct Solver3MultiRootBalancedSessionInheritanceParams {
     /// Number of unrelated search roots incubated before cross-root recombination.
-    #[serde(default = "default_solver3_multi_root_inheritance_root_count")]
+    #[serde(
+        default = "default_solver3_multi_root_inheritance_root_count",
+        alias = "rootCount"
+    )]
     pub root_count: u32,
     /// Maximum elites retained per unrelated root.
-    #[serde(default = "default_solver3_multi_root_inheritance_archive_size_per_root")]
+    #[serde(
+        default = "default_solver3_multi_root_inheritance_archive_size_per_root",
+        alias = "archiveSizePerRoot"
+    )]
     pub archive_size_per_root: u32,
     /// Recombination only becomes eligible after this many non-improving iterations.
-    #[serde(default = "default_solver3_multi_root_inheritance_no_improvement_window")]
+    #[serde(
+        default = "default_solver3_multi_root_inheritance_no_improvement_window",
+        alias = "recombinationNoImprovementWindow"
+    )]
     pub recombination_no_improvement_window: u32,
     /// Minimum number of iterations between recombination events.
-    #[serde(default = "default_solver3_multi_root_inheritance_cooldown_window")]
+    #[serde(
+        default = "default_solver3_multi_root_inheritance_cooldown_window",
+        alias = "recombinationCooldownWindow"
+    )]
     pub recombination_cooldown_window: u32,
     /// Optional non-binding safety cap on inheritance events in a single run.
-    #[serde(default)]
+    #[serde(default, alias = "maxRecombinationEventsPerRun")]
     pub max_recombination_events_per_run: Option<u32>,
     /// Maximum score delta from the current best elite allowed for either parent.
-    #[serde(default = "default_solver3_multi_root_inheritance_parent_score_delta")]
+    #[serde(
+        default = "default_solver3_multi_root_inheritance_parent_score_delta",
+        alias = "maxParentScoreDeltaFromBest"
+    )]
     pub max_parent_score_delta_from_best: f64,
     /// Minimum cross-root session disagreement required before parent pairing is considered.
-    #[serde(default = "default_solver3_multi_root_inheritance_min_session_disagreement")]
+    #[serde(
+        default = "default_solver3_multi_root_inheritance_min_session_disagreement",
+        alias = "minCrossRootSessionDisagreement"
+    )]
     pub min_cross_root_session_disagreement: u32,
     /// Target share of differing aligned sessions inherited from parent A.
     ///
     /// The initial operator is explicitly balanced and currently requires this to remain `0.5`.
-    #[serde(default = "default_solver3_multi_root_inheritance_parent_a_share")]
+    #[serde(
+        default = "default_solver3_multi_root_inheritance_parent_a_share",
+        alias = "parentADifferingSessionShare"
+    )]
     pub parent_a_differing_session_share: f64,
     /// Adaptive raw-child quality gate applied before child polish.
-    #[serde(default)]
+    #[serde(default, alias = "adaptiveRawChildRetention")]
     pub adaptive_raw_child_retention: Solver3AdaptiveRawChildRetentionParams,
     /// Optionally certify a swap-local optimum exactly before inheritance fires.
-    #[serde(default)]
+    #[serde(default, alias = "swapLocalOptimumCertificationEnabled")]
     pub swap_local_optimum_certification_enabled: bool,
     /// Post-child local-polish iteration budget granted per full stagnation window.
-    #[serde(default = "default_solver3_multi_root_inheritance_child_polish_iterations_per_window")]
+    #[serde(
+        default = "default_solver3_multi_root_inheritance_child_polish_iterations_per_window",
+        alias = "childPolishIterationsPerStagnationWindow"
+    )]
     pub child_polish_iterations_per_stagnation_window: u32,
     /// Post-child local-polish no-improvement budget granted per full stagnation window.
     #[serde(
         default = "default_solver3_multi_root_inheritance_child_polish_no_improvement_iterations_per_window"
     )]
+    #[serde(alias = "childPolishNoImprovementIterationsPerStagnationWindow")]
     pub child_polish_no_improvement_iterations_per_stagnation_window: u32,
     /// Maximum number of stagnation windows that contribute to a single child-polish budget.
     #[serde(
         default = "default_solver3_multi_root_inheritance_child_polish_max_stagnation_windows"
     )]
+    #[serde(alias = "childPolishMaxStagnationWindows")]
     pub child_polish_max_stagnation_windows: u32,
 }
 
@@ -1546,36 +2328,52 @@ impl Default for Solver3MultiRootBalancedSessionInheritanceParams {
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 pub struct Solver3DonorSessionTransplantParams {
     /// Maximum number of elites retained in the small archive.
-    #[serde(default = "default_solver3_donor_session_archive_size")]
+    #[serde(
+        default = "default_solver3_donor_session_archive_size",
+        alias = "archiveSize"
+    )]
     pub archive_size: u32,
     /// Recombination only becomes eligible after this many non-improving iterations.
-    #[serde(default = "default_solver3_donor_session_no_improvement_window")]
+    #[serde(
+        default = "default_solver3_donor_session_no_improvement_window",
+        alias = "recombinationNoImprovementWindow"
+    )]
     pub recombination_no_improvement_window: u32,
     /// Minimum number of iterations between recombination events.
-    #[serde(default = "default_solver3_donor_session_cooldown_window")]
+    #[serde(
+        default = "default_solver3_donor_session_cooldown_window",
+        alias = "recombinationCooldownWindow"
+    )]
     pub recombination_cooldown_window: u32,
     /// Optional non-binding safety cap on recombination events in a single run.
     ///
     /// `None` means the trigger is governed only by stagnation, cooldown, donor availability,
     /// and child quality rather than by a fixed per-run event ceiling.
-    #[serde(default)]
+    #[serde(default, alias = "maxRecombinationEventsPerRun")]
     pub max_recombination_events_per_run: Option<u32>,
     /// Adaptive raw-child quality gate applied before post-transplant polish.
-    #[serde(default)]
+    #[serde(default, alias = "adaptiveRawChildRetention")]
     pub adaptive_raw_child_retention: Solver3AdaptiveRawChildRetentionParams,
     /// Optionally certify a swap-local optimum exactly before donor recombination fires.
-    #[serde(default)]
+    #[serde(default, alias = "swapLocalOptimumCertificationEnabled")]
     pub swap_local_optimum_certification_enabled: bool,
     /// Post-transplant child-polish iteration budget granted per full stagnation window.
-    #[serde(default = "default_solver3_donor_session_child_polish_iterations_per_window")]
+    #[serde(
+        default = "default_solver3_donor_session_child_polish_iterations_per_window",
+        alias = "childPolishIterationsPerStagnationWindow"
+    )]
     pub child_polish_iterations_per_stagnation_window: u32,
     /// Post-transplant child-polish no-improvement budget granted per full stagnation window.
     #[serde(
         default = "default_solver3_donor_session_child_polish_no_improvement_iterations_per_window"
     )]
+    #[serde(alias = "childPolishNoImprovementIterationsPerStagnationWindow")]
     pub child_polish_no_improvement_iterations_per_stagnation_window: u32,
     /// Maximum number of stagnation windows that contribute to a single child-polish budget.
-    #[serde(default = "default_solver3_donor_session_child_polish_max_stagnation_windows")]
+    #[serde(
+        default = "default_solver3_donor_session_child_polish_max_stagnation_windows",
+        alias = "childPolishMaxStagnationWindows"
+    )]
     pub child_polish_max_stagnation_windows: u32,
 }
 
@@ -1603,45 +2401,70 @@ impl Default for Solver3DonorSessionTransplantParams {
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 pub struct Solver3SessionAlignedPathRelinkingParams {
     /// Operator variant used inside the path-relinking research driver.
-    #[serde(default)]
+    #[serde(default, alias = "operatorVariant")]
     pub operator_variant: Solver3PathRelinkingOperatorVariant,
     /// Maximum number of elites retained in the small archive.
-    #[serde(default = "default_solver3_path_relinking_archive_size")]
+    #[serde(
+        default = "default_solver3_path_relinking_archive_size",
+        alias = "archiveSize"
+    )]
     pub archive_size: u32,
     /// Relinking only becomes eligible after this many non-improving iterations.
-    #[serde(default = "default_solver3_path_relinking_no_improvement_window")]
+    #[serde(
+        default = "default_solver3_path_relinking_no_improvement_window",
+        alias = "recombinationNoImprovementWindow"
+    )]
     pub recombination_no_improvement_window: u32,
     /// Minimum number of iterations between relinking events.
-    #[serde(default = "default_solver3_path_relinking_cooldown_window")]
+    #[serde(
+        default = "default_solver3_path_relinking_cooldown_window",
+        alias = "recombinationCooldownWindow"
+    )]
     pub recombination_cooldown_window: u32,
     /// Optional non-binding safety cap on relinking events in a single run.
-    #[serde(default)]
+    #[serde(default, alias = "maxPathEventsPerRun")]
     pub max_path_events_per_run: Option<u32>,
     /// Maximum number of aligned donor sessions imported during one relinking event.
-    #[serde(default = "default_solver3_path_relinking_max_session_imports_per_event")]
+    #[serde(
+        default = "default_solver3_path_relinking_max_session_imports_per_event",
+        alias = "maxSessionImportsPerEvent"
+    )]
     pub max_session_imports_per_event: u32,
     /// Stop walking the corridor after this many non-improving path steps.
-    #[serde(default = "default_solver3_path_relinking_step_no_improvement_limit")]
+    #[serde(
+        default = "default_solver3_path_relinking_step_no_improvement_limit",
+        alias = "pathStepNoImprovementLimit"
+    )]
     pub path_step_no_improvement_limit: u32,
     /// Minimum aligned session structural distance required to consider an import step.
-    #[serde(default = "default_solver3_path_relinking_min_session_distance")]
+    #[serde(
+        default = "default_solver3_path_relinking_min_session_distance",
+        alias = "minAlignedSessionDistanceForRelinking"
+    )]
     pub min_aligned_session_distance_for_relinking: u32,
     /// Adaptive raw-child quality gate applied before step polish.
-    #[serde(default)]
+    #[serde(default, alias = "adaptiveRawChildRetention")]
     pub adaptive_raw_child_retention: Solver3AdaptiveRawChildRetentionParams,
     /// Optionally certify a swap-local optimum exactly before relinking fires.
-    #[serde(default)]
+    #[serde(default, alias = "swapLocalOptimumCertificationEnabled")]
     pub swap_local_optimum_certification_enabled: bool,
     /// Post-step child-polish iteration budget granted per full stagnation window.
-    #[serde(default = "default_solver3_path_relinking_child_polish_iterations_per_window")]
+    #[serde(
+        default = "default_solver3_path_relinking_child_polish_iterations_per_window",
+        alias = "childPolishIterationsPerStagnationWindow"
+    )]
     pub child_polish_iterations_per_stagnation_window: u32,
     /// Post-step child-polish no-improvement budget granted per full stagnation window.
     #[serde(
         default = "default_solver3_path_relinking_child_polish_no_improvement_iterations_per_window"
     )]
+    #[serde(alias = "childPolishNoImprovementIterationsPerStagnationWindow")]
     pub child_polish_no_improvement_iterations_per_stagnation_window: u32,
     /// Maximum number of stagnation windows that contribute to a single step-polish budget.
-    #[serde(default = "default_solver3_path_relinking_child_polish_max_stagnation_windows")]
+    #[serde(
+        default = "default_solver3_path_relinking_child_polish_max_stagnation_windows",
+        alias = "childPolishMaxStagnationWindows"
+    )]
     pub child_polish_max_stagnation_windows: u32,
 }
 
@@ -1676,13 +2499,22 @@ impl Default for Solver3SessionAlignedPathRelinkingParams {
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 pub struct Solver3AdaptiveRawChildRetentionParams {
     /// Fraction of raw transplanted children to retain for polish after warmup.
-    #[serde(default = "default_solver3_donor_session_raw_child_keep_ratio")]
+    #[serde(
+        default = "default_solver3_donor_session_raw_child_keep_ratio",
+        alias = "keepRatio"
+    )]
     pub keep_ratio: f64,
     /// Number of raw child samples collected before the percentile gate becomes active.
-    #[serde(default = "default_solver3_donor_session_raw_child_warmup_samples")]
+    #[serde(
+        default = "default_solver3_donor_session_raw_child_warmup_samples",
+        alias = "warmupSamples"
+    )]
     pub warmup_samples: u32,
     /// Rolling history length used for percentile-based gating.
-    #[serde(default = "default_solver3_donor_session_raw_child_history_limit")]
+    #[serde(
+        default = "default_solver3_donor_session_raw_child_history_limit",
+        alias = "historyLimit"
+    )]
     pub history_limit: u32,
 }
 
@@ -1700,22 +2532,40 @@ impl Default for Solver3AdaptiveRawChildRetentionParams {
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 pub struct Solver3SteadyStateMemeticParams {
     /// Small fixed population size maintained by the memetic outer loop.
-    #[serde(default = "default_solver3_memetic_population_size")]
+    #[serde(
+        default = "default_solver3_memetic_population_size",
+        alias = "populationSize"
+    )]
     pub population_size: u32,
     /// Tournament size used for parent selection.
-    #[serde(default = "default_solver3_memetic_parent_tournament_size")]
+    #[serde(
+        default = "default_solver3_memetic_parent_tournament_size",
+        alias = "parentTournamentSize"
+    )]
     pub parent_tournament_size: u32,
     /// Minimum number of same-session swaps applied during macro mutation.
-    #[serde(default = "default_solver3_memetic_mutation_swaps_min")]
+    #[serde(
+        default = "default_solver3_memetic_mutation_swaps_min",
+        alias = "mutationSwapsMin"
+    )]
     pub mutation_swaps_min: u32,
     /// Maximum number of same-session swaps applied during macro mutation.
-    #[serde(default = "default_solver3_memetic_mutation_swaps_max")]
+    #[serde(
+        default = "default_solver3_memetic_mutation_swaps_max",
+        alias = "mutationSwapsMax"
+    )]
     pub mutation_swaps_max: u32,
     /// Per-child bounded local-polish iteration cap.
-    #[serde(default = "default_solver3_memetic_child_polish_max_iterations")]
+    #[serde(
+        default = "default_solver3_memetic_child_polish_max_iterations",
+        alias = "childPolishMaxIterations"
+    )]
     pub child_polish_max_iterations: u32,
     /// Per-child early-stop cap after this many non-improving local-improver iterations.
-    #[serde(default = "default_solver3_memetic_child_polish_no_improvement_iterations")]
+    #[serde(
+        default = "default_solver3_memetic_child_polish_no_improvement_iterations",
+        alias = "childPolishNoImprovementIterations"
+    )]
     pub child_polish_no_improvement_iterations: u32,
 }
 
@@ -1740,7 +2590,7 @@ pub struct Solver3LocalImproverParams {
     #[serde(default)]
     pub mode: Solver3LocalImproverMode,
     /// Config for the SGP-shaped swapped-pair tabu improver.
-    #[serde(default)]
+    #[serde(default, alias = "sgpWeekPairTabu")]
     pub sgp_week_pair_tabu: Solver3SgpWeekPairTabuParams,
 }
 
@@ -1748,31 +2598,52 @@ pub struct Solver3LocalImproverParams {
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 pub struct Solver3SgpWeekPairTabuParams {
     /// How tabu tenure should be derived from the configured base interval.
-    #[serde(default)]
+    #[serde(default, alias = "tenureMode")]
     pub tenure_mode: Solver3SgpWeekPairTabuTenureMode,
     /// Dynamic tenure lower bound in accepted-move iterations.
-    #[serde(default = "default_solver3_sgp_week_pair_tabu_tenure_min")]
+    #[serde(
+        default = "default_solver3_sgp_week_pair_tabu_tenure_min",
+        alias = "tenureMin"
+    )]
     pub tenure_min: u32,
     /// Dynamic tenure upper bound in accepted-move iterations.
-    #[serde(default = "default_solver3_sgp_week_pair_tabu_tenure_max")]
+    #[serde(
+        default = "default_solver3_sgp_week_pair_tabu_tenure_max",
+        alias = "tenureMax"
+    )]
     pub tenure_max: u32,
     /// Bounded sampler retry cap for tabooed raw swap proposals.
-    #[serde(default = "default_solver3_sgp_week_pair_tabu_retry_cap")]
+    #[serde(
+        default = "default_solver3_sgp_week_pair_tabu_retry_cap",
+        alias = "retryCap"
+    )]
     pub retry_cap: u32,
     /// Whether tabu aspiration may override a tabooed move after preview.
-    #[serde(default = "default_solver3_sgp_week_pair_tabu_aspiration")]
+    #[serde(
+        default = "default_solver3_sgp_week_pair_tabu_aspiration",
+        alias = "aspirationEnabled"
+    )]
     pub aspiration_enabled: bool,
     /// Reference participant count for `session_participant_scaled` tenure.
-    #[serde(default = "default_solver3_sgp_week_pair_tabu_session_scale_reference_participants")]
+    #[serde(
+        default = "default_solver3_sgp_week_pair_tabu_session_scale_reference_participants",
+        alias = "sessionScaleReferenceParticipants"
+    )]
     pub session_scale_reference_participants: u32,
     /// No-improvement window for `reactive_no_improvement_scaled` tenure.
-    #[serde(default = "default_solver3_sgp_week_pair_tabu_reactive_window")]
+    #[serde(
+        default = "default_solver3_sgp_week_pair_tabu_reactive_window",
+        alias = "reactiveNoImprovementWindow"
+    )]
     pub reactive_no_improvement_window: u32,
     /// Maximum multiplier for `reactive_no_improvement_scaled` tenure.
-    #[serde(default = "default_solver3_sgp_week_pair_tabu_reactive_max_multiplier")]
+    #[serde(
+        default = "default_solver3_sgp_week_pair_tabu_reactive_max_multiplier",
+        alias = "reactiveMaxMultiplier"
+    )]
     pub reactive_max_multiplier: u32,
     /// Whether swap sampling should be restricted to repeat-conflict positions when conflicts exist.
-    #[serde(default)]
+    #[serde(default, alias = "conflictRestrictedSwapSamplingEnabled")]
     pub conflict_restricted_swap_sampling_enabled: bool,
 }
 
@@ -1797,7 +2668,7 @@ impl Default for Solver3SgpWeekPairTabuParams {
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default)]
 pub struct Solver3HotspotGuidanceParams {
     /// Repeat-encounter-guided swap proposal controls.
-    #[serde(default)]
+    #[serde(default, alias = "repeatGuidedSwaps")]
     pub repeat_guided_swaps: Solver3RepeatGuidedSwapParams,
 }
 
@@ -1809,11 +2680,17 @@ pub struct Solver3RepeatGuidedSwapParams {
     pub enabled: bool,
 
     /// Probability that a swap proposal attempt will use the guided path.
-    #[serde(default = "default_solver3_repeat_guided_swap_probability")]
+    #[serde(
+        default = "default_solver3_repeat_guided_swap_probability",
+        alias = "guidedProposalProbability"
+    )]
     pub guided_proposal_probability: f64,
 
     /// Maximum number of exact swap previews evaluated for one guided attempt.
-    #[serde(default = "default_solver3_repeat_guided_swap_preview_budget")]
+    #[serde(
+        default = "default_solver3_repeat_guided_swap_preview_budget",
+        alias = "candidatePreviewBudget"
+    )]
     pub candidate_preview_budget: u32,
 }
 
@@ -1842,7 +2719,10 @@ pub struct Solver3CorrectnessLaneParams {
     /// Run sampled correctness checks every N accepted moves.
     ///
     /// Values must be >= 1.
-    #[serde(default = "default_solver3_correctness_lane_sample_every_accepted_moves")]
+    #[serde(
+        default = "default_solver3_correctness_lane_sample_every_accepted_moves",
+        alias = "sampleEveryAcceptedMoves"
+    )]
     pub sample_every_accepted_moves: u64,
 }
 
@@ -2055,15 +2935,19 @@ fn default_solver3_sgp_week_pair_tabu_reactive_max_multiplier() -> u32 {
 ///     cooling_schedule: "geometric".to_string(), // Exponential temperature decay
 ///     reheat_after_no_improvement: Some(0), // Reheat after 1000 iterations without improvement (0 = no reheat)
 ///     reheat_cycles: Some(0), // Reheat after 1000 iterations without improvement (0 = no reheat)
+///     penalty_ramp: None, // Judge moves with full penalty weights from the first iteration
 /// };
 /// ```
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 pub struct SimulatedAnnealingParams {
     /// Starting temperature (higher values allow more random moves initially)
+    #[serde(alias = "initialTemperature")]
     pub initial_temperature: f64,
     /// Ending temperature (lower values focus on local improvements)
+    #[serde(alias = "finalTemperature")]
     pub final_temperature: f64,
     /// Temperature reduction schedule: "geometric" for exponential decay, "linear" for linear decay
+    #[serde(alias = "coolingSchedule")]
     pub cooling_schedule: String, // "geometric", "linear", etc
     /// Fixed reheat cycles: split the total iterations into this many cycles.
     /// For each cycle, temperature cools from `initial_temperature` down to `final_temperature`,
@@ -2072,7 +2956,7 @@ pub struct SimulatedAnnealingParams {
     /// Semantics:
     /// - `Some(0)` or `None`: disabled (use default behavior)
     /// - `Some(N>0)`: enable cycle-based reheating with N cycles across `max_iterations`
-    #[serde(default)]
+    #[serde(default, alias = "reheatCycles")]
     pub reheat_cycles: Option<u64>,
     /// Optional reheat threshold: number of iterations without improvement before reheating.
     /// When reached, temperature is reset to initial_temperature and the cooling schedule is recalculated
@@ -2082,8 +2966,79 @@ pub struct SimulatedAnnealingParams {
     /// - `Some(0)`: disable reheating explicitly
     /// - `Some(N>0)`: reheat after N iterations without improvement
     /// - `None` (unspecified): default to the smaller of `max_iterations/10` or `no_improvement_iterations/2` (if set)
-    #[serde(default)]
+    #[serde(default, alias = "reheatAfterNoImprovement")]
     pub reheat_after_no_improvement: Option<u64>,
+    /// Optional penalty ramp: start with reduced constraint penalties and tighten them over
+    /// the run. `None` keeps full penalty weights throughout.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "penaltyRamp"
+    )]
+    pub penalty_ramp: Option<PenaltyRampParams>,
+}
+
+/// Lagrangian-style ramp of constraint penalties during simulated annealing.
+///
+/// While ramping, moves are accepted or rejected as if every penalty term (repetition,
+/// attribute balance, and constraint penalties) were multiplied by a scale that grows
+/// linearly from `initial_scale` to `1.0` over the first `ramp_fraction` of the run. This
+/// lets the search settle on high-contact structures before tightening into feasibility.
+/// Reported scores and the best solution are always measured with full weights.
+///
+/// # Example
+///
+/// ```no_run
+/// use gm_core::models::PenaltyRampParams;
+///
+/// // Start at 10% penalty strength and reach full strength halfway through the run.
+/// let ramp = PenaltyRampParams {
+///     initial_scale: 0.1,
+///     ramp_fraction: 0.5,
+/// };
+/// ```
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+pub struct PenaltyRampParams {
+    /// Penalty scale at the start of the run, in `[0, 1]`
+    #[serde(default = "default_penalty_ramp_initial_scale", alias = "initialScale")]
+    pub initial_scale: f64,
+    /// Fraction of the run (iterations or time limit, whichever is further along) after
+    /// which penalties are at full strength, in `(0, 1]`
+    #[serde(default = "default_penalty_ramp_fraction", alias = "rampFraction")]
+    pub ramp_fraction: f64,
+}
+
+impl PenaltyRampParams {
+    /// Penalty scale to apply once `run_progress` (in `[0, 1]`) of the run has elapsed.
+    pub fn scale_at(&self, run_progress: f64) -> f64 {
+        let ramp_progress = (run_progress / self.ramp_fraction).clamp(0.0, 1.0);
+        self.initial_scale + (1.0 - self.initial_scale) * ramp_progress
+    }
+
+    /// Checks that both parameters are within their documented ranges.
+    pub fn validate(&self) -> Result<(), String> {
+        if !(0.0..=1.0).contains(&self.initial_scale) {
+            return Err(format!(
+                "penalty_ramp.initial_scale must be in [0, 1], got {}",
+                self.initial_scale
+            ));
+        }
+        if !(self.ramp_fraction > 0.0 && self.ramp_fraction <= 1.0) {
+            return Err(format!(
+                "penalty_ramp.ramp_fraction must be in (0, 1], got {}",
+                self.ramp_fraction
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn default_penalty_ramp_initial_scale() -> f64 {
+    0.1
+}
+
+fn default_penalty_ramp_fraction() -> f64 {
+    0.5
 }
 
 /// Configuration options for logging and output during optimization.
@@ -2092,6 +3047,10 @@ pub struct SimulatedAnnealingParams {
 /// the optimization process. Useful for debugging, monitoring progress,
 /// and understanding the solver's behavior.
 ///
+/// Output goes through [`tracing`](https://docs.rs/tracing) events inside `setup`,
+/// `anneal`, and `reheat` spans; nothing is printed unless the caller installs a
+/// subscriber.
+///
 /// # Example
 ///
 /// ```no_run
@@ -2120,36 +3079,36 @@ pub struct SimulatedAnnealingParams {
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default)]
 pub struct LoggingOptions {
     /// How often to log progress (every N iterations). `None` disables progress logging.
-    #[serde(default)]
+    #[serde(default, alias = "logFrequency")]
     pub log_frequency: Option<u64>,
     /// Whether to log the initial state and configuration
-    #[serde(default)]
+    #[serde(default, alias = "logInitialState")]
     pub log_initial_state: bool,
     /// Whether to log the total optimization time and final score
-    #[serde(default)]
+    #[serde(default, alias = "logDurationAndScore")]
     pub log_duration_and_score: bool,
     /// Whether to display the final schedule in a human-readable format
-    #[serde(default)]
+    #[serde(default, alias = "displayFinalSchedule")]
     pub display_final_schedule: bool,
     /// Whether to log a detailed breakdown of the initial score
-    #[serde(default)]
+    #[serde(default, alias = "logInitialScoreBreakdown")]
     pub log_initial_score_breakdown: bool,
     /// Whether to log a detailed breakdown of the final score
-    #[serde(default)]
+    #[serde(default, alias = "logFinalScoreBreakdown")]
     pub log_final_score_breakdown: bool,
     /// Whether to log the reason why optimization stopped
-    #[serde(default)]
+    #[serde(default, alias = "logStopCondition")]
     pub log_stop_condition: bool,
 
     /// When enabled, the solver performs invariant checks after each applied move.
     /// This is expensive and intended only for debugging.
-    #[serde(default)]
+    #[serde(default, alias = "debugValidateInvariants")]
     pub debug_validate_invariants: bool,
 
     /// When enabled alongside `debug_validate_invariants`, the solver will include
     /// detailed context (attempted move description and before/after schedules)
     /// in any invariant violation error.
-    #[serde(default)]
+    #[serde(default, alias = "debugDumpInvariantContext")]
     pub debug_dump_invariant_context: bool,
 }
 
@@ -2164,96 +3123,143 @@ pub struct ProgressUpdate {
     /// Current iteration number (0-based)
     pub iteration: u64,
     /// Total iterations planned for the run, or the current runtime-budget estimate when the solver is pacing to a time target.
+    #[serde(alias = "maxIterations")]
     pub max_iterations: u64,
     /// Current temperature (for simulated annealing)
     pub temperature: f64,
     /// Current solution cost/score
+    #[serde(alias = "currentScore")]
     pub current_score: f64,
     /// Best solution cost/score found so far
+    #[serde(alias = "bestScore")]
     pub best_score: f64,
     /// Number of unique contacts in current solution
+    #[serde(alias = "currentContacts")]
     pub current_contacts: i32,
     /// Number of unique contacts in best solution
+    #[serde(alias = "bestContacts")]
     pub best_contacts: i32,
     /// Current repetition penalty
+    #[serde(alias = "repetitionPenalty")]
     pub repetition_penalty: i32,
     /// Time elapsed since solver started (in seconds)
+    #[serde(alias = "elapsedSeconds")]
     pub elapsed_seconds: f64,
     /// Number of iterations without improvement
+    #[serde(alias = "noImprovementCount")]
     pub no_improvement_count: u64,
+    /// Estimated seconds until the run reaches its iteration or time limit, from the observed
+    /// throughput. Absent until there is throughput to extrapolate from; an early stop on
+    /// `no_improvement_iterations` can end the run sooner.
+    #[serde(default, alias = "estimatedRemainingSeconds")]
+    pub estimated_remaining_seconds: Option<f64>,
+    /// Search phase the solver is in at the time of the update.
+    #[serde(default)]
+    pub phase: Option<SolverPhase>,
 
     // === Move Type Statistics ===
     /// Number of clique swap moves attempted
+    #[serde(alias = "cliqueSwapsTried")]
     pub clique_swaps_tried: u64,
     /// Number of clique swap moves accepted
+    #[serde(alias = "cliqueSwapsAccepted")]
     pub clique_swaps_accepted: u64,
     /// Number of clique swap moves rejected
+    #[serde(alias = "cliqueSwapsRejected")]
     pub clique_swaps_rejected: u64,
     /// Number of single person transfer moves attempted
+    #[serde(alias = "transfersTried")]
     pub transfers_tried: u64,
     /// Number of single person transfer moves accepted
+    #[serde(alias = "transfersAccepted")]
     pub transfers_accepted: u64,
     /// Number of single person transfer moves rejected
+    #[serde(alias = "transfersRejected")]
     pub transfers_rejected: u64,
     /// Number of regular person swap moves attempted
+    #[serde(alias = "swapsTried")]
     pub swaps_tried: u64,
     /// Number of regular person swap moves accepted
+    #[serde(alias = "swapsAccepted")]
     pub swaps_accepted: u64,
     /// Number of regular person swap moves rejected
+    #[serde(alias = "swapsRejected")]
     pub swaps_rejected: u64,
 
     // === Acceptance and Quality Metrics ===
     /// Overall acceptance rate (accepted moves / total moves)
+    #[serde(alias = "overallAcceptanceRate")]
     pub overall_acceptance_rate: f64,
     /// Recent acceptance rate (last 100 moves)
+    #[serde(alias = "recentAcceptanceRate")]
     pub recent_acceptance_rate: f64,
     /// Average score change for attempted moves
+    #[serde(alias = "avgAttemptedMoveDelta")]
     pub avg_attempted_move_delta: f64,
     /// Average score change for accepted moves
+    #[serde(alias = "avgAcceptedMoveDelta")]
     pub avg_accepted_move_delta: f64,
     /// Biggest score increase that was accepted
+    #[serde(alias = "biggestAcceptedIncrease")]
     pub biggest_accepted_increase: f64,
     /// Biggest score increase that was attempted
+    #[serde(alias = "biggestAttemptedIncrease")]
     pub biggest_attempted_increase: f64,
 
     // === Current State Breakdown ===
     /// Current repetition penalty (weighted)
+    #[serde(alias = "currentRepetitionPenalty")]
     pub current_repetition_penalty: f64,
     /// Current attribute balance penalty
+    #[serde(alias = "currentBalancePenalty")]
     pub current_balance_penalty: f64,
     /// Current constraint penalty (weighted)
+    #[serde(alias = "currentConstraintPenalty")]
     pub current_constraint_penalty: f64,
     /// Best repetition penalty achieved so far
+    #[serde(alias = "bestRepetitionPenalty")]
     pub best_repetition_penalty: f64,
     /// Best attribute balance penalty achieved so far
+    #[serde(alias = "bestBalancePenalty")]
     pub best_balance_penalty: f64,
     /// Best constraint penalty achieved so far
+    #[serde(alias = "bestConstraintPenalty")]
     pub best_constraint_penalty: f64,
 
     // === Algorithm State Information ===
     /// Number of reheats performed so far
+    #[serde(alias = "reheatsPerformed")]
     pub reheats_performed: u64,
     /// Iterations since last reheat
+    #[serde(alias = "iterationsSinceLastReheat")]
     pub iterations_since_last_reheat: u64,
     /// Number of local optima escapes (accepted worse moves)
+    #[serde(alias = "localOptimaEscapes")]
     pub local_optima_escapes: u64,
     /// Average time per iteration in milliseconds
+    #[serde(alias = "avgTimePerIterationMs")]
     pub avg_time_per_iteration_ms: f64,
     /// Progress through cooling schedule (0.0 to 1.0)
+    #[serde(alias = "coolingProgress")]
     pub cooling_progress: f64,
 
     // === Move Type Success Rates ===
     /// Success rate for clique swap moves (accepted/tried)
+    #[serde(alias = "cliqueSwapSuccessRate")]
     pub clique_swap_success_rate: f64,
     /// Success rate for transfer moves (accepted/tried)
+    #[serde(alias = "transferSuccessRate")]
     pub transfer_success_rate: f64,
     /// Success rate for swap moves (accepted/tried)
+    #[serde(alias = "swapSuccessRate")]
     pub swap_success_rate: f64,
 
     // === Advanced Analytics ===
     /// Score variance over recent window (indicates exploration level)
+    #[serde(alias = "scoreVariance")]
     pub score_variance: f64,
     /// Search efficiency (improvement per unit time)
+    #[serde(alias = "searchEfficiency")]
     pub search_efficiency: f64,
 
     // === Optional snapshot of the current best schedule ===
@@ -2261,15 +3267,122 @@ pub struct ProgressUpdate {
     #[serde(default)]
     pub best_schedule:
         Option<std::collections::HashMap<String, std::collections::HashMap<String, Vec<String>>>>,
+    /// Changes to the best-known schedule since the previous snapshot, sent in place of
+    /// `best_schedule` when `TelemetryOptions::best_schedule_as_diff` is set.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "bestScheduleDiff"
+    )]
+    pub best_schedule_diff: Option<ScheduleDiff>,
     /// Effective seed used for the current run.
-    #[serde(default)]
+    #[serde(default, alias = "effectiveSeed")]
     pub effective_seed: Option<u64>,
     /// Effective move policy used for the current run.
-    #[serde(default)]
+    #[serde(default, alias = "movePolicy")]
     pub move_policy: Option<MovePolicy>,
     /// Explicit stop reason. Present on final progress updates and absent on intermediate updates.
-    #[serde(default)]
+    #[serde(default, alias = "stopReason")]
     pub stop_reason: Option<StopReason>,
+    /// Resumable search state, on updates sent at a checkpoint iteration (see
+    /// `TelemetryOptions::checkpoint_every_n_iterations`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkpoint: Option<SolverCheckpoint>,
+    /// Move statistics windows closed since the previous progress update, when
+    /// `TelemetryOptions::record_move_statistics` is set.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "moveStatistics"
+    )]
+    pub move_statistics: Option<Vec<MoveStatisticsPoint>>,
+    /// Constraint penalty samples taken since the previous progress update, when
+    /// `TelemetryOptions::record_constraint_penalties` is set.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "constraintPenalties"
+    )]
+    pub constraint_penalties: Option<Vec<ConstraintPenaltyPoint>>,
+}
+
+/// Search state of an in-progress solver1 run, taken between two iterations.
+///
+/// Pass it to [`resume_from_checkpoint`](crate::resume_from_checkpoint) together with the
+/// original input to continue the run after a restart. The contact matrix and other caches
+/// are rebuilt from the schedules, so only the schedules and the search bookkeeping are
+/// stored: the move policy, the annealing temperature, reheat and stagnation counters, the
+/// partial convergence window, and the RNG position. Move statistics and timing telemetry
+/// start over in the resumed run.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+pub struct SolverCheckpoint {
+    /// Effective seed of the run
+    #[serde(alias = "effectiveSeed")]
+    pub effective_seed: u64,
+    /// Iterations completed before the checkpoint
+    #[serde(alias = "iterationsCompleted")]
+    pub iterations_completed: u64,
+    /// Search time elapsed before the checkpoint, counted toward the time limit
+    #[serde(alias = "elapsedSeconds")]
+    pub elapsed_seconds: f64,
+    /// Working schedule of the search
+    #[serde(alias = "currentSchedule")]
+    pub current_schedule: ApiSchedule,
+    /// Best schedule found so far
+    #[serde(alias = "bestSchedule")]
+    pub best_schedule: ApiSchedule,
+    /// Cost of `best_schedule`
+    #[serde(alias = "bestCost")]
+    pub best_cost: f64,
+    /// Iterations since the last improvement
+    #[serde(alias = "noImprovementCount")]
+    pub no_improvement_count: u64,
+    /// Longest streak of iterations without improvement so far
+    #[serde(alias = "maxNoImprovementStreak")]
+    pub max_no_improvement_streak: u64,
+    /// Reheats performed so far
+    #[serde(alias = "reheatsPerformed")]
+    pub reheats_performed: u64,
+    /// Iteration of the most recent reheat
+    #[serde(alias = "lastReheatIteration")]
+    pub last_reheat_iteration: u64,
+    /// Elapsed search time at the most recent reheat
+    #[serde(alias = "lastReheatElapsedSeconds")]
+    pub last_reheat_elapsed_seconds: f64,
+    /// Position of the search RNG stream, in 32-bit words
+    #[serde(alias = "rngWordPos")]
+    pub rng_word_pos: u64,
+    /// Move policy of the run; the resumed run uses it instead of the input's
+    #[serde(default, alias = "movePolicy")]
+    pub move_policy: Option<MovePolicy>,
+    /// Annealing temperature of the last iteration before the checkpoint
+    #[serde(default)]
+    pub temperature: Option<f64>,
+    /// Worsening moves proposed in the unfinished `convergence` window
+    #[serde(default, alias = "convergenceWindowUphillProposed")]
+    pub convergence_window_uphill_proposed: u64,
+    /// Worsening moves accepted in the unfinished `convergence` window
+    #[serde(default, alias = "convergenceWindowUphillAccepted")]
+    pub convergence_window_uphill_accepted: u64,
+}
+
+/// A new best schedule found during a run, as yielded by
+/// [`run_solver_iter`](crate::run_solver_iter).
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct Improvement {
+    /// Iteration of the progress update that first reported this schedule
+    pub iteration: u64,
+    /// Time elapsed since the solver started (in seconds)
+    #[serde(alias = "elapsedSeconds")]
+    pub elapsed_seconds: f64,
+    /// Cost of `schedule`; lower than that of every earlier improvement
+    #[serde(alias = "bestScore")]
+    pub best_score: f64,
+    /// Number of unique contacts in `schedule`
+    #[serde(alias = "uniqueContacts")]
+    pub unique_contacts: i32,
+    /// The best schedule found so far
+    pub schedule: ApiSchedule,
 }
 
 /// Callback function type for receiving progress updates during solver execution.
@@ -2285,6 +3398,113 @@ pub struct ProgressUpdate {
 /// to request early termination.
 pub type ProgressCallback = Box<dyn Fn(&ProgressUpdate) -> bool + Send>;
 
+/// What a [`ProgressControlCallback`] asks the solver to do after a progress update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressControl {
+    /// Keep solving.
+    Continue,
+    /// Stop and return the best result so far, like a [`ProgressCallback`] returning `false`.
+    Stop,
+    /// Attach a `checkpoint` to the next progress update. Only solver1 supports this; other
+    /// solver families stop the run and fail with a validation error.
+    SaveCheckpoint,
+    /// Restart cooling from the initial temperature at the next iteration. Only solver1
+    /// supports this; other solver families stop the run and fail with a validation error.
+    Reheat,
+}
+
+impl From<bool> for ProgressControl {
+    fn from(keep_going: bool) -> Self {
+        if keep_going {
+            Self::Continue
+        } else {
+            Self::Stop
+        }
+    }
+}
+
+/// Progress callback that steers the run through its [`ProgressControl`] return value.
+pub type ProgressControlCallback = Box<dyn Fn(&ProgressUpdate) -> ProgressControl + Send>;
+
+/// Either kind of progress callback, as passed to the solver engines.
+#[derive(Clone, Copy)]
+pub enum ProgressHook<'a> {
+    Flag(&'a ProgressCallback),
+    Control(&'a ProgressControlCallback),
+    /// A control callback for an engine that can only continue or stop. Any other answer
+    /// stops the run and is recorded in `refused`.
+    ContinueOrStop {
+        callback: &'a ProgressControlCallback,
+        refused: &'a std::sync::Mutex<Option<ProgressControl>>,
+    },
+}
+
+impl ProgressHook<'_> {
+    /// Delivers `update` and returns what the callback asked for.
+    pub fn report(self, update: &ProgressUpdate) -> ProgressControl {
+        match self {
+            Self::Flag(callback) => callback(update).into(),
+            Self::Control(callback) => callback(update),
+            Self::ContinueOrStop { callback, refused } => match callback(update) {
+                control @ (ProgressControl::Continue | ProgressControl::Stop) => control,
+                control => {
+                    *refused
+                        .lock()
+                        .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(control);
+                    ProgressControl::Stop
+                }
+            },
+        }
+    }
+}
+
+impl<'a> From<&'a ProgressCallback> for ProgressHook<'a> {
+    fn from(callback: &'a ProgressCallback) -> Self {
+        Self::Flag(callback)
+    }
+}
+
+impl<'a> From<&'a ProgressControlCallback> for ProgressHook<'a> {
+    fn from(callback: &'a ProgressControlCallback) -> Self {
+        Self::Control(callback)
+    }
+}
+
+/// Coarse phase of a running search, reported in [`ProgressUpdate::phase`].
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SolverPhase {
+    /// No search move has been evaluated yet.
+    Initializing,
+    /// Cooling from the initial temperature, before any reheat.
+    Annealing,
+    /// Cooling again after at least one reheat.
+    Reheating,
+    /// The low-temperature tail of the cooling schedule, where the search is close to greedy.
+    Polishing,
+}
+
+impl SolverPhase {
+    /// Share of the current cooling pass after which the search counts as polishing.
+    const POLISHING_COOLING_PROGRESS: f64 = 0.9;
+
+    pub(crate) fn from_search_progress(
+        completed_iterations: u64,
+        reheats_performed: u64,
+        cooling_progress: f64,
+    ) -> Self {
+        if completed_iterations == 0 {
+            Self::Initializing
+        } else if cooling_progress >= Self::POLISHING_COOLING_PROGRESS {
+            Self::Polishing
+        } else if reheats_performed > 0 {
+            Self::Reheating
+        } else {
+            Self::Annealing
+        }
+    }
+}
+
 /// Explicit reason why a solver run stopped.
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -2295,6 +3515,10 @@ pub enum StopReason {
     NoImprovementTimeLimitReached,
     ProgressCallbackRequestedStop,
     OptimalScoreReached,
+    /// The search met [`StopConditions::convergence`].
+    Converged,
+    /// The search met [`StopConditions::stop_on_feasible`].
+    FeasibleReached,
 }
 
 /// Per-move-family benchmark telemetry summary.
@@ -2304,17 +3528,17 @@ pub struct MoveFamilyBenchmarkTelemetry {
     pub attempts: u64,
     #[serde(default)]
     pub accepted: u64,
-    #[serde(default)]
+    #[serde(default, alias = "improvingAccepts")]
     pub improving_accepts: u64,
     #[serde(default)]
     pub rejected: u64,
-    #[serde(default)]
+    #[serde(default, alias = "previewSeconds")]
     pub preview_seconds: f64,
-    #[serde(default)]
+    #[serde(default, alias = "applySeconds")]
     pub apply_seconds: f64,
-    #[serde(default)]
+    #[serde(default, alias = "fullRecalculationCount")]
     pub full_recalculation_count: u64,
-    #[serde(default)]
+    #[serde(default, alias = "fullRecalculationSeconds")]
     pub full_recalculation_seconds: f64,
 }
 
@@ -2322,7 +3546,9 @@ pub struct MoveFamilyBenchmarkTelemetry {
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
 pub struct BestScoreTimelinePoint {
     pub iteration: u64,
+    #[serde(alias = "elapsedSeconds")]
     pub elapsed_seconds: f64,
+    #[serde(alias = "bestScore")]
     pub best_score: f64,
 }
 
@@ -2333,59 +3559,59 @@ pub struct MoveFamilyBenchmarkTelemetrySummary {
     pub swap: MoveFamilyBenchmarkTelemetry,
     #[serde(default)]
     pub transfer: MoveFamilyBenchmarkTelemetry,
-    #[serde(default)]
+    #[serde(default, alias = "cliqueSwap")]
     pub clique_swap: MoveFamilyBenchmarkTelemetry,
 }
 
 /// Benchmark telemetry for repeat-guided swap proposal behavior.
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Default)]
 pub struct RepeatGuidedSwapBenchmarkTelemetry {
-    #[serde(default)]
+    #[serde(default, alias = "guidedAttempts")]
     pub guided_attempts: u64,
-    #[serde(default)]
+    #[serde(default, alias = "guidedSuccesses")]
     pub guided_successes: u64,
-    #[serde(default)]
+    #[serde(default, alias = "guidedFallbackToRandom")]
     pub guided_fallback_to_random: u64,
-    #[serde(default)]
+    #[serde(default, alias = "guidedPreviewedCandidates")]
     pub guided_previewed_candidates: u64,
 }
 
 /// Benchmark telemetry for the steady-state memetic outer driver.
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Default)]
 pub struct MemeticBenchmarkTelemetry {
-    #[serde(default)]
+    #[serde(default, alias = "populationSize")]
     pub population_size: u32,
-    #[serde(default)]
+    #[serde(default, alias = "parentTournamentSize")]
     pub parent_tournament_size: u32,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishLocalImproverMode")]
     pub child_polish_local_improver_mode: Option<Solver3LocalImproverMode>,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishMaxIterations")]
     pub child_polish_max_iterations: u64,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishNoImprovementIterations")]
     pub child_polish_no_improvement_iterations: u64,
-    #[serde(default)]
+    #[serde(default, alias = "offspringAttempted")]
     pub offspring_attempted: u64,
-    #[serde(default)]
+    #[serde(default, alias = "offspringPolished")]
     pub offspring_polished: u64,
-    #[serde(default)]
+    #[serde(default, alias = "offspringReplaced")]
     pub offspring_replaced: u64,
-    #[serde(default)]
+    #[serde(default, alias = "offspringDiscarded")]
     pub offspring_discarded: u64,
-    #[serde(default)]
+    #[serde(default, alias = "mutationAttemptedSwaps")]
     pub mutation_attempted_swaps: u64,
-    #[serde(default)]
+    #[serde(default, alias = "mutationAppliedSwaps")]
     pub mutation_applied_swaps: u64,
-    #[serde(default)]
+    #[serde(default, alias = "mutationLengthSum")]
     pub mutation_length_sum: u64,
-    #[serde(default)]
+    #[serde(default, alias = "mutationLengthMin")]
     pub mutation_length_min: Option<u64>,
-    #[serde(default)]
+    #[serde(default, alias = "mutationLengthMax")]
     pub mutation_length_max: Option<u64>,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishIterations")]
     pub child_polish_iterations: u64,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishImprovingMoves")]
     pub child_polish_improving_moves: u64,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishSeconds")]
     pub child_polish_seconds: f64,
 }
 
@@ -2410,216 +3636,219 @@ pub enum DonorSessionViabilityTierTelemetry {
 /// One donor/session choice made during donor-session recombination.
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Default)]
 pub struct DonorSessionChoiceTelemetry {
-    #[serde(default)]
+    #[serde(default, alias = "donorArchiveIdx")]
     pub donor_archive_idx: u32,
-    #[serde(default)]
+    #[serde(default, alias = "sessionIdx")]
     pub session_idx: u32,
-    #[serde(default)]
+    #[serde(default, alias = "sessionDisagreementCount")]
     pub session_disagreement_count: u32,
-    #[serde(default)]
+    #[serde(default, alias = "candidatePool")]
     pub candidate_pool: DonorCandidatePoolTelemetry,
-    #[serde(default)]
+    #[serde(default, alias = "sessionViabilityTier")]
     pub session_viability_tier: DonorSessionViabilityTierTelemetry,
-    #[serde(default)]
+    #[serde(default, alias = "conflictBurdenDelta")]
     pub conflict_burden_delta: i64,
-    #[serde(default)]
+    #[serde(default, alias = "preRecombinationIncumbentScore")]
     pub pre_recombination_incumbent_score: f64,
-    #[serde(default)]
+    #[serde(default, alias = "donorScore")]
     pub donor_score: f64,
-    #[serde(default)]
+    #[serde(default, alias = "rawChildScore")]
     pub raw_child_score: f64,
-    #[serde(default)]
+    #[serde(default, alias = "rawChildDelta")]
     pub raw_child_delta: f64,
-    #[serde(default)]
+    #[serde(default, alias = "adaptiveDiscardThreshold")]
     pub adaptive_discard_threshold: Option<f64>,
-    #[serde(default)]
+    #[serde(default, alias = "retainedForPolish")]
     pub retained_for_polish: bool,
-    #[serde(default)]
+    #[serde(default, alias = "stagnationWindowsAtTrigger")]
     pub stagnation_windows_at_trigger: u64,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishBudgetIterations")]
     pub child_polish_budget_iterations: Option<u64>,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishBudgetNoImprovementIterations")]
     pub child_polish_budget_no_improvement_iterations: Option<u64>,
-    #[serde(default)]
+    #[serde(default, alias = "postPolishBestScore")]
     pub post_polish_best_score: Option<f64>,
-    #[serde(default)]
+    #[serde(default, alias = "rawToPolishedDelta")]
     pub raw_to_polished_delta: Option<f64>,
-    #[serde(default)]
+    #[serde(default, alias = "incumbentToPolishedDelta")]
     pub incumbent_to_polished_delta: Option<f64>,
-    #[serde(default)]
+    #[serde(default, alias = "becameNewIncumbent")]
     pub became_new_incumbent: Option<bool>,
-    #[serde(default)]
+    #[serde(default, alias = "setNewBestPostPolishScore")]
     pub set_new_best_post_polish_score: Option<bool>,
-    #[serde(default)]
+    #[serde(default, alias = "polishStopReason")]
     pub polish_stop_reason: Option<StopReason>,
-    #[serde(default)]
+    #[serde(default, alias = "polishIterationsCompleted")]
     pub polish_iterations_completed: Option<u64>,
 }
 
 /// Benchmark telemetry for the donor-session transplant outer driver.
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Default)]
 pub struct DonorSessionTransplantBenchmarkTelemetry {
-    #[serde(default)]
+    #[serde(default, alias = "archiveSize")]
     pub archive_size: u32,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishLocalImproverMode")]
     pub child_polish_local_improver_mode: Option<Solver3LocalImproverMode>,
-    #[serde(default)]
+    #[serde(default, alias = "rawChildKeepRatio")]
     pub raw_child_keep_ratio: f64,
-    #[serde(default)]
+    #[serde(default, alias = "rawChildWarmupSamples")]
     pub raw_child_warmup_samples: u32,
-    #[serde(default)]
+    #[serde(default, alias = "rawChildHistoryLimit")]
     pub raw_child_history_limit: u32,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishIterationsPerStagnationWindow")]
     pub child_polish_iterations_per_stagnation_window: u64,
-    #[serde(default)]
+    #[serde(
+        default,
+        alias = "childPolishNoImprovementIterationsPerStagnationWindow"
+    )]
     pub child_polish_no_improvement_iterations_per_stagnation_window: u64,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishMaxStagnationWindows")]
     pub child_polish_max_stagnation_windows: u64,
-    #[serde(default)]
+    #[serde(default, alias = "swapLocalOptimumCertificationEnabled")]
     pub swap_local_optimum_certification_enabled: bool,
-    #[serde(default)]
+    #[serde(default, alias = "archiveAdditions")]
     pub archive_additions: u64,
-    #[serde(default)]
+    #[serde(default, alias = "archiveExactDuplicateReplacements")]
     pub archive_exact_duplicate_replacements: u64,
-    #[serde(default)]
+    #[serde(default, alias = "archiveNearDuplicateReplacements")]
     pub archive_near_duplicate_replacements: u64,
-    #[serde(default)]
+    #[serde(default, alias = "archiveRedundantEvictions")]
     pub archive_redundant_evictions: u64,
-    #[serde(default)]
+    #[serde(default, alias = "archiveRejectedExactDuplicates")]
     pub archive_rejected_exact_duplicates: u64,
-    #[serde(default)]
+    #[serde(default, alias = "archiveRejectedNearDuplicates")]
     pub archive_rejected_near_duplicates: u64,
-    #[serde(default)]
+    #[serde(default, alias = "archiveRejectedNotCompetitive")]
     pub archive_rejected_not_competitive: u64,
-    #[serde(default)]
+    #[serde(default, alias = "triggerBlockedNotArmed")]
     pub trigger_blocked_not_armed: u64,
-    #[serde(default)]
+    #[serde(default, alias = "triggerBlockedEventCap")]
     pub trigger_blocked_event_cap: u64,
-    #[serde(default)]
+    #[serde(default, alias = "triggerArmedNoViableDonor")]
     pub trigger_armed_no_viable_donor: u64,
-    #[serde(default)]
+    #[serde(default, alias = "triggerArmedNoViableSession")]
     pub trigger_armed_no_viable_session: u64,
-    #[serde(default)]
+    #[serde(default, alias = "recombinationEventsFired")]
     pub recombination_events_fired: u64,
-    #[serde(default)]
+    #[serde(default, alias = "rawChildrenEvaluated")]
     pub raw_children_evaluated: u64,
-    #[serde(default)]
+    #[serde(default, alias = "rawChildDeltaSum")]
     pub raw_child_delta_sum: f64,
-    #[serde(default)]
+    #[serde(default, alias = "rawChildDeltaMin")]
     pub raw_child_delta_min: Option<f64>,
-    #[serde(default)]
+    #[serde(default, alias = "rawChildDeltaMax")]
     pub raw_child_delta_max: Option<f64>,
-    #[serde(default)]
+    #[serde(default, alias = "adaptiveDiscardThreshold")]
     pub adaptive_discard_threshold: Option<f64>,
-    #[serde(default)]
+    #[serde(default, alias = "donorChoices")]
     pub donor_choices: Vec<DonorSessionChoiceTelemetry>,
-    #[serde(default)]
+    #[serde(default, alias = "immediateDiscards")]
     pub immediate_discards: u64,
-    #[serde(default)]
+    #[serde(default, alias = "polishedChildren")]
     pub polished_children: u64,
-    #[serde(default)]
+    #[serde(default, alias = "polishedChildrenKept")]
     pub polished_children_kept: u64,
-    #[serde(default)]
+    #[serde(default, alias = "polishedChildrenDiscarded")]
     pub polished_children_discarded: u64,
-    #[serde(default)]
+    #[serde(default, alias = "bestPostPolishScore")]
     pub best_post_polish_score: Option<f64>,
-    #[serde(default)]
+    #[serde(default, alias = "postPolishScoreSum")]
     pub post_polish_score_sum: f64,
-    #[serde(default)]
+    #[serde(default, alias = "postPolishScoreMin")]
     pub post_polish_score_min: Option<f64>,
-    #[serde(default)]
+    #[serde(default, alias = "postPolishScoreMax")]
     pub post_polish_score_max: Option<f64>,
-    #[serde(default)]
+    #[serde(default, alias = "polishedChildVsRawDeltaSum")]
     pub polished_child_vs_raw_delta_sum: f64,
-    #[serde(default)]
+    #[serde(default, alias = "polishedChildVsRawDeltaMin")]
     pub polished_child_vs_raw_delta_min: Option<f64>,
-    #[serde(default)]
+    #[serde(default, alias = "polishedChildVsRawDeltaMax")]
     pub polished_child_vs_raw_delta_max: Option<f64>,
-    #[serde(default)]
+    #[serde(default, alias = "polishedChildVsIncumbentDeltaSum")]
     pub polished_child_vs_incumbent_delta_sum: f64,
-    #[serde(default)]
+    #[serde(default, alias = "polishedChildVsIncumbentDeltaMin")]
     pub polished_child_vs_incumbent_delta_min: Option<f64>,
-    #[serde(default)]
+    #[serde(default, alias = "polishedChildVsIncumbentDeltaMax")]
     pub polished_child_vs_incumbent_delta_max: Option<f64>,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishBudgetIterationsSum")]
     pub child_polish_budget_iterations_sum: u64,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishBudgetNoImprovementIterationsSum")]
     pub child_polish_budget_no_improvement_iterations_sum: u64,
-    #[serde(default)]
+    #[serde(default, alias = "certificationScansAttempted")]
     pub certification_scans_attempted: u64,
-    #[serde(default)]
+    #[serde(default, alias = "certificationScansCompleted")]
     pub certification_scans_completed: u64,
-    #[serde(default)]
+    #[serde(default, alias = "certificationFoundImprovingSwap")]
     pub certification_found_improving_swap: u64,
-    #[serde(default)]
+    #[serde(default, alias = "certifiedSwapLocalOptima")]
     pub certified_swap_local_optima: u64,
-    #[serde(default)]
+    #[serde(default, alias = "certificationScanSwapPreviews")]
     pub certification_scan_swap_previews: u64,
-    #[serde(default)]
+    #[serde(default, alias = "certificationScanSeconds")]
     pub certification_scan_seconds: f64,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishIterations")]
     pub child_polish_iterations: u64,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishImprovingMoves")]
     pub child_polish_improving_moves: u64,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishSeconds")]
     pub child_polish_seconds: f64,
 }
 
 /// One aligned session-import step evaluated during a path-relinking event.
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Default)]
 pub struct SessionAlignedPathRelinkingStepTelemetry {
-    #[serde(default)]
+    #[serde(default, alias = "baseSessionIdx")]
     pub base_session_idx: Option<u32>,
-    #[serde(default)]
+    #[serde(default, alias = "donorSessionIdx")]
     pub donor_session_idx: Option<u32>,
-    #[serde(default)]
+    #[serde(default, alias = "structuralDistance")]
     pub structural_distance: Option<u32>,
-    #[serde(default)]
+    #[serde(default, alias = "macroMutationSwapsApplied")]
     pub macro_mutation_swaps_applied: Option<u32>,
-    #[serde(default)]
+    #[serde(default, alias = "rawChildScore")]
     pub raw_child_score: f64,
-    #[serde(default)]
+    #[serde(default, alias = "rawChildDelta")]
     pub raw_child_delta: f64,
-    #[serde(default)]
+    #[serde(default, alias = "postPolishBestScore")]
     pub post_polish_best_score: Option<f64>,
-    #[serde(default)]
+    #[serde(default, alias = "rawToPolishedDelta")]
     pub raw_to_polished_delta: Option<f64>,
-    #[serde(default)]
+    #[serde(default, alias = "incumbentToPostPolishDelta")]
     pub incumbent_to_post_polish_delta: Option<f64>,
-    #[serde(default)]
+    #[serde(default, alias = "polishStopReason")]
     pub polish_stop_reason: Option<StopReason>,
-    #[serde(default)]
+    #[serde(default, alias = "polishIterationsCompleted")]
     pub polish_iterations_completed: Option<u64>,
-    #[serde(default)]
+    #[serde(default, alias = "becameEventBest")]
     pub became_event_best: Option<bool>,
 }
 
 /// One session-aligned path-relinking event between incumbent and donor elite.
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Default)]
 pub struct SessionAlignedPathRelinkingEventTelemetry {
-    #[serde(default)]
+    #[serde(default, alias = "donorArchiveIdx")]
     pub donor_archive_idx: u32,
-    #[serde(default)]
+    #[serde(default, alias = "donorScore")]
     pub donor_score: f64,
-    #[serde(default)]
+    #[serde(default, alias = "baseIncumbentScore")]
     pub base_incumbent_score: f64,
-    #[serde(default)]
+    #[serde(default, alias = "alignmentTotalCost")]
     pub alignment_total_cost: u32,
-    #[serde(default)]
+    #[serde(default, alias = "differingSessionCount")]
     pub differing_session_count: u32,
-    #[serde(default)]
+    #[serde(default, alias = "stepsAttempted")]
     pub steps_attempted: u32,
-    #[serde(default)]
+    #[serde(default, alias = "rawStepsDiscardedBeforePolish")]
     pub raw_steps_discarded_before_polish: u32,
-    #[serde(default)]
+    #[serde(default, alias = "polishedSteps")]
     pub polished_steps: u32,
-    #[serde(default)]
+    #[serde(default, alias = "bestPostPolishEventScore")]
     pub best_post_polish_event_score: Option<f64>,
-    #[serde(default)]
+    #[serde(default, alias = "becameNewIncumbent")]
     pub became_new_incumbent: bool,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishIterations")]
     pub child_polish_iterations: u64,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishSeconds")]
     pub child_polish_seconds: f64,
     #[serde(default)]
     pub steps: Vec<SessionAlignedPathRelinkingStepTelemetry>,
@@ -2628,176 +3857,182 @@ pub struct SessionAlignedPathRelinkingEventTelemetry {
 /// Benchmark telemetry for the session-aligned path relinking outer driver.
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Default)]
 pub struct SessionAlignedPathRelinkingBenchmarkTelemetry {
-    #[serde(default)]
+    #[serde(default, alias = "operatorVariant")]
     pub operator_variant: Solver3PathRelinkingOperatorVariant,
-    #[serde(default)]
+    #[serde(default, alias = "archiveSize")]
     pub archive_size: u32,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishLocalImproverMode")]
     pub child_polish_local_improver_mode: Option<Solver3LocalImproverMode>,
-    #[serde(default)]
+    #[serde(default, alias = "rawChildKeepRatio")]
     pub raw_child_keep_ratio: f64,
-    #[serde(default)]
+    #[serde(default, alias = "rawChildWarmupSamples")]
     pub raw_child_warmup_samples: u32,
-    #[serde(default)]
+    #[serde(default, alias = "rawChildHistoryLimit")]
     pub raw_child_history_limit: u32,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishIterationsPerStagnationWindow")]
     pub child_polish_iterations_per_stagnation_window: u64,
-    #[serde(default)]
+    #[serde(
+        default,
+        alias = "childPolishNoImprovementIterationsPerStagnationWindow"
+    )]
     pub child_polish_no_improvement_iterations_per_stagnation_window: u64,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishMaxStagnationWindows")]
     pub child_polish_max_stagnation_windows: u64,
-    #[serde(default)]
+    #[serde(default, alias = "swapLocalOptimumCertificationEnabled")]
     pub swap_local_optimum_certification_enabled: bool,
-    #[serde(default)]
+    #[serde(default, alias = "guideSelectionFailures")]
     pub guide_selection_failures: u64,
-    #[serde(default)]
+    #[serde(default, alias = "pathEventsFired")]
     pub path_events_fired: u64,
-    #[serde(default)]
+    #[serde(default, alias = "pathEventsKept")]
     pub path_events_kept: u64,
-    #[serde(default)]
+    #[serde(default, alias = "alignmentCostSum")]
     pub alignment_cost_sum: u64,
-    #[serde(default)]
+    #[serde(default, alias = "differingSessionCountSum")]
     pub differing_session_count_sum: u64,
-    #[serde(default)]
+    #[serde(default, alias = "stepsAttempted")]
     pub steps_attempted: u64,
-    #[serde(default)]
+    #[serde(default, alias = "rawStepsDiscardedBeforePolish")]
     pub raw_steps_discarded_before_polish: u64,
-    #[serde(default)]
+    #[serde(default, alias = "polishedSteps")]
     pub polished_steps: u64,
-    #[serde(default)]
+    #[serde(default, alias = "bestPostPolishScore")]
     pub best_post_polish_score: Option<f64>,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishIterations")]
     pub child_polish_iterations: u64,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishSeconds")]
     pub child_polish_seconds: f64,
-    #[serde(default)]
+    #[serde(default, alias = "eventSummaries")]
     pub event_summaries: Vec<SessionAlignedPathRelinkingEventTelemetry>,
 }
 
 /// One multi-root balanced inheritance event between two unrelated parents.
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Default)]
 pub struct MultiRootBalancedSessionInheritanceEventTelemetry {
-    #[serde(default)]
+    #[serde(default, alias = "parentARootId")]
     pub parent_a_root_id: u64,
-    #[serde(default)]
+    #[serde(default, alias = "parentBRootId")]
     pub parent_b_root_id: u64,
-    #[serde(default)]
+    #[serde(default, alias = "parentAScore")]
     pub parent_a_score: f64,
-    #[serde(default)]
+    #[serde(default, alias = "parentBScore")]
     pub parent_b_score: f64,
-    #[serde(default)]
+    #[serde(default, alias = "alignmentTotalCost")]
     pub alignment_total_cost: u32,
-    #[serde(default)]
+    #[serde(default, alias = "agreedSessionCount")]
     pub agreed_session_count: u32,
-    #[serde(default)]
+    #[serde(default, alias = "differingSessionCount")]
     pub differing_session_count: u32,
-    #[serde(default)]
+    #[serde(default, alias = "inheritedFromParentASessions")]
     pub inherited_from_parent_a_sessions: u32,
-    #[serde(default)]
+    #[serde(default, alias = "inheritedFromParentBSessions")]
     pub inherited_from_parent_b_sessions: u32,
-    #[serde(default)]
+    #[serde(default, alias = "rawChildScore")]
     pub raw_child_score: f64,
-    #[serde(default)]
+    #[serde(default, alias = "postPolishBestScore")]
     pub post_polish_best_score: Option<f64>,
-    #[serde(default)]
+    #[serde(default, alias = "childBeatsParentA")]
     pub child_beats_parent_a: Option<bool>,
-    #[serde(default)]
+    #[serde(default, alias = "childBeatsParentB")]
     pub child_beats_parent_b: Option<bool>,
-    #[serde(default)]
+    #[serde(default, alias = "childBeatsBothParents")]
     pub child_beats_both_parents: Option<bool>,
-    #[serde(default)]
+    #[serde(default, alias = "becameNewIncumbent")]
     pub became_new_incumbent: bool,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishIterations")]
     pub child_polish_iterations: u64,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishSeconds")]
     pub child_polish_seconds: f64,
 }
 
 /// Benchmark telemetry for the multi-root balanced session inheritance outer driver.
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Default)]
 pub struct MultiRootBalancedSessionInheritanceBenchmarkTelemetry {
-    #[serde(default)]
+    #[serde(default, alias = "rootCount")]
     pub root_count: u32,
-    #[serde(default)]
+    #[serde(default, alias = "archiveSizePerRoot")]
     pub archive_size_per_root: u32,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishLocalImproverMode")]
     pub child_polish_local_improver_mode: Option<Solver3LocalImproverMode>,
-    #[serde(default)]
+    #[serde(default, alias = "rawChildKeepRatio")]
     pub raw_child_keep_ratio: f64,
-    #[serde(default)]
+    #[serde(default, alias = "rawChildWarmupSamples")]
     pub raw_child_warmup_samples: u32,
-    #[serde(default)]
+    #[serde(default, alias = "rawChildHistoryLimit")]
     pub raw_child_history_limit: u32,
-    #[serde(default)]
+    #[serde(default, alias = "maxParentScoreDeltaFromBest")]
     pub max_parent_score_delta_from_best: f64,
-    #[serde(default)]
+    #[serde(default, alias = "minCrossRootSessionDisagreement")]
     pub min_cross_root_session_disagreement: u32,
-    #[serde(default)]
+    #[serde(default, alias = "parentADifferingSessionShare")]
     pub parent_a_differing_session_share: f64,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishIterationsPerStagnationWindow")]
     pub child_polish_iterations_per_stagnation_window: u64,
-    #[serde(default)]
+    #[serde(
+        default,
+        alias = "childPolishNoImprovementIterationsPerStagnationWindow"
+    )]
     pub child_polish_no_improvement_iterations_per_stagnation_window: u64,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishMaxStagnationWindows")]
     pub child_polish_max_stagnation_windows: u64,
-    #[serde(default)]
+    #[serde(default, alias = "swapLocalOptimumCertificationEnabled")]
     pub swap_local_optimum_certification_enabled: bool,
-    #[serde(default)]
+    #[serde(default, alias = "rootsIncubated")]
     pub roots_incubated: u64,
-    #[serde(default)]
+    #[serde(default, alias = "parentPairSelectionFailures")]
     pub parent_pair_selection_failures: u64,
-    #[serde(default)]
+    #[serde(default, alias = "inheritanceEventsFired")]
     pub inheritance_events_fired: u64,
-    #[serde(default)]
+    #[serde(default, alias = "inheritanceEventsKept")]
     pub inheritance_events_kept: u64,
-    #[serde(default)]
+    #[serde(default, alias = "alignmentCostSum")]
     pub alignment_cost_sum: u64,
-    #[serde(default)]
+    #[serde(default, alias = "agreedSessionCountSum")]
     pub agreed_session_count_sum: u64,
-    #[serde(default)]
+    #[serde(default, alias = "differingSessionCountSum")]
     pub differing_session_count_sum: u64,
-    #[serde(default)]
+    #[serde(default, alias = "inheritedFromParentASessionsSum")]
     pub inherited_from_parent_a_sessions_sum: u64,
-    #[serde(default)]
+    #[serde(default, alias = "inheritedFromParentBSessionsSum")]
     pub inherited_from_parent_b_sessions_sum: u64,
-    #[serde(default)]
+    #[serde(default, alias = "childrenBeatingParentA")]
     pub children_beating_parent_a: u64,
-    #[serde(default)]
+    #[serde(default, alias = "childrenBeatingParentB")]
     pub children_beating_parent_b: u64,
-    #[serde(default)]
+    #[serde(default, alias = "childrenBeatingBothParents")]
     pub children_beating_both_parents: u64,
-    #[serde(default)]
+    #[serde(default, alias = "bestPostPolishScore")]
     pub best_post_polish_score: Option<f64>,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishIterations")]
     pub child_polish_iterations: u64,
-    #[serde(default)]
+    #[serde(default, alias = "childPolishSeconds")]
     pub child_polish_seconds: f64,
-    #[serde(default)]
+    #[serde(default, alias = "eventSummaries")]
     pub event_summaries: Vec<MultiRootBalancedSessionInheritanceEventTelemetry>,
 }
 
 /// Benchmark telemetry for the SGP week-pair tabu local improver.
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Default)]
 pub struct SgpWeekPairTabuBenchmarkTelemetry {
-    #[serde(default)]
+    #[serde(default, alias = "rawTabuHits")]
     pub raw_tabu_hits: u64,
-    #[serde(default)]
+    #[serde(default, alias = "prefilterSkips")]
     pub prefilter_skips: u64,
-    #[serde(default)]
+    #[serde(default, alias = "retryExhaustions")]
     pub retry_exhaustions: u64,
-    #[serde(default)]
+    #[serde(default, alias = "hardBlocks")]
     pub hard_blocks: u64,
-    #[serde(default)]
+    #[serde(default, alias = "aspirationPreviewSurfaces")]
     pub aspiration_preview_surfaces: u64,
-    #[serde(default)]
+    #[serde(default, alias = "aspirationOverrides")]
     pub aspiration_overrides: u64,
-    #[serde(default)]
+    #[serde(default, alias = "recordedSwaps")]
     pub recorded_swaps: u64,
-    #[serde(default)]
+    #[serde(default, alias = "realizedTenureSum")]
     pub realized_tenure_sum: u64,
-    #[serde(default)]
+    #[serde(default, alias = "realizedTenureMin")]
     pub realized_tenure_min: Option<u64>,
-    #[serde(default)]
+    #[serde(default, alias = "realizedTenureMax")]
     pub realized_tenure_max: Option<u64>,
 }
 
@@ -2806,37 +4041,37 @@ pub struct SgpWeekPairTabuBenchmarkTelemetry {
 pub struct Solver4PaperTracePoint {
     #[serde(default)]
     pub iteration: u64,
-    #[serde(default)]
+    #[serde(default, alias = "elapsedSeconds")]
     pub elapsed_seconds: f64,
-    #[serde(default)]
+    #[serde(default, alias = "currentConflictPositions")]
     pub current_conflict_positions: u64,
-    #[serde(default)]
+    #[serde(default, alias = "bestConflictPositions")]
     pub best_conflict_positions: u64,
-    #[serde(default)]
+    #[serde(default, alias = "conflictPositionsByWeek")]
     pub conflict_positions_by_week: Vec<u32>,
 }
 
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Default)]
 pub struct Solver4GraspCandidateTrace {
-    #[serde(default)]
+    #[serde(default, alias = "candidateIndex")]
     pub candidate_index: u32,
     #[serde(default)]
     pub gamma: f64,
-    #[serde(default)]
+    #[serde(default, alias = "initializationSeconds")]
     pub initialization_seconds: f64,
-    #[serde(default)]
+    #[serde(default, alias = "searchSeconds")]
     pub search_seconds: f64,
-    #[serde(default)]
+    #[serde(default, alias = "initialConflictPositions")]
     pub initial_conflict_positions: u64,
-    #[serde(default)]
+    #[serde(default, alias = "bestConflictPositions")]
     pub best_conflict_positions: u64,
     #[serde(default)]
     pub solved: bool,
-    #[serde(default)]
+    #[serde(default, alias = "iterationsCompleted")]
     pub iterations_completed: u64,
-    #[serde(default)]
+    #[serde(default, alias = "stopReason")]
     pub stop_reason: Option<StopReason>,
-    #[serde(default)]
+    #[serde(default, alias = "selectedForContinuation")]
     pub selected_for_continuation: bool,
 }
 
@@ -2845,19 +4080,19 @@ pub struct Solver4GraspCandidateTrace {
 pub struct Solver4PaperTrace {
     #[serde(default)]
     pub mode: Option<Solver4Mode>,
-    #[serde(default)]
+    #[serde(default, alias = "backtrackingPattern")]
     pub backtracking_pattern: Option<String>,
-    #[serde(default)]
+    #[serde(default, alias = "initialSchedule")]
     pub initial_schedule: Option<ApiSchedule>,
-    #[serde(default)]
+    #[serde(default, alias = "initialConflictPositions")]
     pub initial_conflict_positions: Option<u64>,
-    #[serde(default)]
+    #[serde(default, alias = "initialConflictPositionsByWeek")]
     pub initial_conflict_positions_by_week: Vec<u32>,
-    #[serde(default)]
+    #[serde(default, alias = "graspCandidates")]
     pub grasp_candidates: Vec<Solver4GraspCandidateTrace>,
-    #[serde(default)]
+    #[serde(default, alias = "continuationCandidateIndex")]
     pub continuation_candidate_index: Option<u32>,
-    #[serde(default)]
+    #[serde(default, alias = "continuationGamma")]
     pub continuation_gamma: Option<f64>,
     #[serde(default)]
     pub points: Vec<Solver4PaperTracePoint>,
@@ -2866,71 +4101,112 @@ pub struct Solver4PaperTrace {
 /// End-of-run benchmark telemetry intended for regression / benchmark artifacts.
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
 pub struct SolverBenchmarkTelemetry {
+    #[serde(alias = "effectiveSeed")]
     pub effective_seed: u64,
+    #[serde(alias = "movePolicy")]
     pub move_policy: MovePolicy,
+    #[serde(alias = "stopReason")]
     pub stop_reason: StopReason,
+    #[serde(alias = "iterationsCompleted")]
     pub iterations_completed: u64,
+    #[serde(alias = "noImprovementCount")]
     pub no_improvement_count: u64,
-    #[serde(default)]
+    #[serde(default, alias = "maxNoImprovementStreak")]
     pub max_no_improvement_streak: u64,
+    #[serde(alias = "reheatsPerformed")]
     pub reheats_performed: u64,
-    #[serde(default)]
+    #[serde(default, alias = "acceptedUphillMoves")]
     pub accepted_uphill_moves: u64,
-    #[serde(default)]
+    #[serde(default, alias = "acceptedDownhillMoves")]
     pub accepted_downhill_moves: u64,
-    #[serde(default)]
+    #[serde(default, alias = "acceptedNeutralMoves")]
     pub accepted_neutral_moves: u64,
-    #[serde(default)]
+    #[serde(default, alias = "restartCount")]
     pub restart_count: Option<u64>,
-    #[serde(default)]
+    #[serde(default, alias = "perturbationCount")]
     pub perturbation_count: Option<u64>,
+    #[serde(alias = "initialScore")]
     pub initial_score: f64,
+    #[serde(alias = "bestScore")]
     pub best_score: f64,
+    #[serde(alias = "finalScore")]
     pub final_score: f64,
+    #[serde(alias = "initializationSeconds")]
     pub initialization_seconds: f64,
+    #[serde(alias = "searchSeconds")]
     pub search_seconds: f64,
+    #[serde(alias = "finalizationSeconds")]
     pub finalization_seconds: f64,
+    #[serde(alias = "totalSeconds")]
     pub total_seconds: f64,
-    #[serde(default)]
+    #[serde(default, alias = "iterationsPerSecond")]
     pub iterations_per_second: f64,
-    #[serde(default)]
+    #[serde(default, alias = "bestScoreTimeline")]
     pub best_score_timeline: Vec<BestScoreTimelinePoint>,
-    #[serde(default)]
+    #[serde(default, alias = "repeatGuidedSwaps")]
     pub repeat_guided_swaps: RepeatGuidedSwapBenchmarkTelemetry,
-    #[serde(default)]
+    #[serde(default, alias = "sgpWeekPairTabu")]
     pub sgp_week_pair_tabu: Option<SgpWeekPairTabuBenchmarkTelemetry>,
     #[serde(default)]
     pub memetic: Option<MemeticBenchmarkTelemetry>,
-    #[serde(default)]
+    #[serde(default, alias = "donorSessionTransplant")]
     pub donor_session_transplant: Option<DonorSessionTransplantBenchmarkTelemetry>,
-    #[serde(default)]
+    #[serde(default, alias = "sessionAlignedPathRelinking")]
     pub session_aligned_path_relinking: Option<SessionAlignedPathRelinkingBenchmarkTelemetry>,
     #[serde(default)]
     pub multi_root_balanced_session_inheritance:
         Option<MultiRootBalancedSessionInheritanceBenchmarkTelemetry>,
-    #[serde(default)]
+    #[serde(default, alias = "solver4PaperTrace")]
     pub solver4_paper_trace: Option<Solver4PaperTrace>,
     #[serde(default)]
     pub auto: Option<AutoSolveTelemetry>,
     pub moves: MoveFamilyBenchmarkTelemetrySummary,
 }
 
+impl SolverBenchmarkTelemetry {
+    /// Total accepted moves across the improving, neutral, and uphill buckets.
+    pub fn accepted_moves(&self) -> u64 {
+        self.accepted_downhill_moves + self.accepted_neutral_moves + self.accepted_uphill_moves
+    }
+
+    /// Total run duration rounded to whole milliseconds.
+    pub fn total_milliseconds(&self) -> u64 {
+        (self.total_seconds * 1000.0).round() as u64
+    }
+}
+
 /// Auto-solver construction/search budgeting telemetry.
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
 pub struct AutoSolveTelemetry {
+    #[serde(alias = "selectedSolver")]
     pub selected_solver: SolverKind,
+    #[serde(alias = "complexityModelVersion")]
     pub complexity_model_version: String,
+    #[serde(alias = "complexityScore")]
     pub complexity_score: f64,
+    #[serde(alias = "totalBudgetSeconds")]
     pub total_budget_seconds: f64,
+    #[serde(alias = "oracleConstructionBudgetSeconds")]
     pub oracle_construction_budget_seconds: f64,
+    #[serde(alias = "scaffoldBudgetSeconds")]
     pub scaffold_budget_seconds: f64,
+    #[serde(alias = "oracleRecombinationBudgetSeconds")]
     pub oracle_recombination_budget_seconds: f64,
+    #[serde(alias = "searchBudgetSeconds")]
     pub search_budget_seconds: f64,
+    #[serde(alias = "constructorAttempt")]
     pub constructor_attempt: String,
+    #[serde(alias = "constructorOutcome")]
     pub constructor_outcome: AutoConstructorOutcome,
+    #[serde(alias = "constructorFallbackUsed")]
     pub constructor_fallback_used: bool,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "constructorFailure"
+    )]
     pub constructor_failure: Option<String>,
+    #[serde(alias = "constructorWallSeconds")]
     pub constructor_wall_seconds: f64,
 }
 
@@ -2964,14 +4240,85 @@ pub enum BenchmarkEvent {
 /// Initial benchmark metadata emitted before the search loop starts.
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
 pub struct BenchmarkRunStarted {
+    #[serde(alias = "effectiveSeed")]
     pub effective_seed: u64,
+    #[serde(alias = "movePolicy")]
     pub move_policy: MovePolicy,
+    #[serde(alias = "initialScore")]
     pub initial_score: f64,
 }
 
 /// Callback for benchmark-oriented observer events.
 pub type BenchmarkObserver = Box<dyn Fn(&BenchmarkEvent) + Send>;
 
+/// Recommended settings for a scenario together with the measurements behind them.
+///
+/// Solver families that tune their settings to the scenario run short trials first; the others
+/// return their runtime-sized configuration with empty measurements.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct RecommendationReport {
+    /// The recommended configuration
+    pub configuration: SolverConfiguration,
+    /// Cost deltas of random swaps sampled on a constructed schedule
+    #[serde(default, alias = "deltaDistribution")]
+    pub delta_distribution: Option<CostDeltaDistribution>,
+    /// Short annealing runs at different temperatures, in the order they ran
+    #[serde(default)]
+    pub trials: Vec<RecommendationTrial>,
+    /// Search speed over all trials
+    #[serde(default, alias = "iterationsPerSecond")]
+    pub iterations_per_second: Option<f64>,
+    /// How each recommended value was chosen, one sentence per entry
+    #[serde(default)]
+    pub reasoning: Vec<String>,
+}
+
+/// Distribution of the cost change of sampled moves.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+pub struct CostDeltaDistribution {
+    /// Moves sampled
+    pub samples: u32,
+    /// Moves that lower the cost
+    pub improving: u32,
+    /// Moves that leave the cost unchanged
+    pub neutral: u32,
+    /// Moves that raise the cost
+    pub worsening: u32,
+    /// 10th percentile of the worsening deltas; `None` when no sampled move worsened the cost
+    #[serde(default, alias = "worseningP10")]
+    pub worsening_p10: Option<f64>,
+    /// Median of the worsening deltas
+    #[serde(default, alias = "worseningMedian")]
+    pub worsening_median: Option<f64>,
+    /// 90th percentile of the worsening deltas
+    #[serde(default, alias = "worseningP90")]
+    pub worsening_p90: Option<f64>,
+    /// Largest worsening delta
+    #[serde(default, alias = "worseningMax")]
+    pub worsening_max: Option<f64>,
+}
+
+/// One trial run of a [`RecommendationReport`].
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+pub struct RecommendationTrial {
+    /// Constant annealing temperature of the trial
+    pub temperature: f64,
+    /// Iterations the trial completed
+    pub iterations: u64,
+    /// Seconds spent in the search loop
+    #[serde(alias = "searchSeconds")]
+    pub search_seconds: f64,
+    /// Share of proposed worsening moves that were accepted; `None` when none were proposed
+    #[serde(default, alias = "uphillAcceptanceRate")]
+    pub uphill_acceptance_rate: Option<f64>,
+    /// Cost of the schedule the trial started from
+    #[serde(alias = "initialCost")]
+    pub initial_cost: f64,
+    /// Best cost the trial reached
+    #[serde(alias = "bestCost")]
+    pub best_cost: f64,
+}
+
 /// The result returned by the optimization solver.
 ///
 /// Contains the optimized schedule along with detailed scoring information
@@ -2998,12 +4345,13 @@ pub type BenchmarkObserver = Box<dyn Fn(&BenchmarkEvent) + Send>;
 /// # let input = ApiInput {
 /// #     initial_schedule: None,
 /// #     construction_seed_schedule: None,
+/// #     contact_history: None,
 /// #     problem: ProblemDefinition { people: vec![], groups: vec![], num_sessions: 1 },
 /// #     objectives: vec![], constraints: vec![],
 /// #     solver: SolverConfiguration {
 /// #         solver_type: "SimulatedAnnealing".to_string(),
-/// #         stop_conditions: StopConditions { max_iterations: Some(1000), time_limit_seconds: None, no_improvement_iterations: None, stop_on_optimal_score: true },
-/// #         solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams { initial_temperature: 10.0, final_temperature: 0.1, cooling_schedule: "geometric".to_string(), reheat_after_no_improvement: Some(0), reheat_cycles: Some(0) }),
+/// #         stop_conditions: StopConditions { max_iterations: Some(1000), time_limit_seconds: None, no_improvement_iterations: None, stop_on_optimal_score: true, stop_on_feasible: false, feasible_score_threshold: None, convergence: None },
+/// #         solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams { initial_temperature: 10.0, final_temperature: 0.1, cooling_schedule: "geometric".to_string(), reheat_after_no_improvement: Some(0), reheat_cycles: Some(0), penalty_ramp: None }),
 /// #         logging: LoggingOptions::default(),
 /// #         telemetry: Default::default(),
 /// #         seed: None,
@@ -3034,121 +4382,585 @@ pub type BenchmarkObserver = Box<dyn Fn(&BenchmarkEvent) + Send>;
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 pub struct SolverResult {
     /// Overall optimization score (higher is better)
+    #[serde(alias = "finalScore")]
     pub final_score: f64,
-    /// The optimized schedule: `schedule[session][group] = [people]`
+    /// The optimized schedule: `schedule[session][group] = [people]`. Serializes with
+    /// sessions in index order and groups sorted by id, so equal schedules serialize equally.
+    #[serde(serialize_with = "serialize_schedule_sorted")]
     pub schedule: std::collections::HashMap<String, std::collections::HashMap<String, Vec<String>>>,
     /// Number of unique person-to-person contacts achieved
+    #[serde(alias = "uniqueContacts")]
     pub unique_contacts: i32,
     /// Penalty points for exceeding repeat encounter limits (unweighted for backwards compatibility)
+    #[serde(alias = "repetitionPenalty")]
     pub repetition_penalty: i32,
     /// Penalty points for attribute balance violations
+    #[serde(alias = "attributeBalancePenalty")]
     pub attribute_balance_penalty: i32,
     /// Total penalty points for constraint violations (unweighted for backwards compatibility)
+    #[serde(alias = "constraintPenalty")]
     pub constraint_penalty: i32,
     /// Number of iterations without improvement at the end of the run
+    #[serde(alias = "noImprovementCount")]
     pub no_improvement_count: u64,
     /// Weighted repetition penalty (actual penalty value used in cost calculation)
+    #[serde(alias = "weightedRepetitionPenalty")]
     pub weighted_repetition_penalty: f64,
     /// Weighted constraint penalty (actual penalty value used in cost calculation)
+    #[serde(alias = "weightedConstraintPenalty")]
     pub weighted_constraint_penalty: f64,
     /// Effective seed used for this run.
-    #[serde(default)]
+    #[serde(default, alias = "effectiveSeed")]
     pub effective_seed: Option<u64>,
     /// Effective move policy used for this run.
-    #[serde(default)]
+    #[serde(default, alias = "movePolicy")]
     pub move_policy: Option<MovePolicy>,
     /// Explicit stop reason for this run.
-    #[serde(default)]
+    #[serde(default, alias = "stopReason")]
     pub stop_reason: Option<StopReason>,
     /// Benchmark-oriented end-of-run telemetry.
-    #[serde(default)]
+    #[serde(default, alias = "benchmarkTelemetry")]
     pub benchmark_telemetry: Option<SolverBenchmarkTelemetry>,
+    /// Number of search iterations performed, when the solver runs an iterative search.
+    #[serde(default, alias = "iterationsRun")]
+    pub iterations_run: Option<u64>,
+    /// Wall-clock duration of the run in milliseconds, when measured.
+    #[serde(default, alias = "timeElapsedMs")]
+    pub time_elapsed_ms: Option<u64>,
+    /// Annealing temperature at the last iteration, for temperature-driven searches.
+    #[serde(default, alias = "finalTemperature")]
+    pub final_temperature: Option<f64>,
+    /// Number of moves accepted during the search (improving, neutral, and uphill).
+    #[serde(default, alias = "acceptedMoves")]
+    pub accepted_moves: Option<u64>,
+    /// Every violated constraint in `schedule`, for front-ends that highlight problems.
+    /// `None` when the final schedule could not be rescored.
+    #[serde(default, alias = "constraintViolations")]
+    pub constraint_violations: Option<Vec<ConstraintViolation>>,
+    /// Score contributions of each session in `schedule`, in session order.
+    /// `None` when the final schedule could not be rescored.
+    #[serde(default, alias = "sessionBreakdown")]
+    pub session_breakdown: Option<Vec<SessionScoreBreakdown>>,
+    /// Pairwise meeting counts of `schedule`, one entry per pair that met at least once.
+    /// Only filled in when `telemetry.include_contact_matrix` is set.
+    #[serde(default, alias = "contactMatrix")]
+    pub contact_matrix: Option<Vec<PairContactCount>>,
+    /// Person ID to `name` attribute, for people that have one. Only filled in when
+    /// `telemetry.include_display_names` is set.
+    #[serde(default, alias = "displayNames")]
+    pub display_names: Option<std::collections::HashMap<String, String>>,
+    /// Person ID to the attributes named in `telemetry.include_person_attributes`, for every
+    /// person. Attributes a person does not have are left out of their map.
+    #[serde(default, alias = "personAttributes")]
+    pub person_attributes:
+        Option<std::collections::HashMap<String, std::collections::HashMap<String, String>>>,
+    /// Sampled cost trace of the search, in iteration order. Only filled in when
+    /// `telemetry.record_score_history` is set and the solver family records one (solver1).
+    #[serde(default, alias = "scoreHistory")]
+    pub score_history: Option<Vec<ScoreHistoryPoint>>,
+    /// Move statistics of the search, one window per sample in iteration order. Only filled in
+    /// when `telemetry.record_move_statistics` is set and the solver family records them
+    /// (solver1).
+    #[serde(default, alias = "moveStatistics")]
+    pub move_statistics: Option<Vec<MoveStatisticsPoint>>,
+    /// Sampled weighted penalty of each soft constraint over the search, in iteration order.
+    /// Only filled in when `telemetry.record_constraint_penalties` is set and the solver family
+    /// records them (solver1).
+    #[serde(default, alias = "constraintPenalties")]
+    pub constraint_penalties: Option<Vec<ConstraintPenaltyPoint>>,
+    /// The solver configuration this run used, with the effective seed, move policy, and
+    /// defaulted `max_iterations` filled in. For `auto` runs it is the configuration of the
+    /// solver family auto picked. Passing it back as `solver` together with the same problem
+    /// reproduces the run (for `auto`, up to its construction budgets).
+    #[serde(default, alias = "effectiveConfiguration")]
+    pub effective_configuration: Option<SolverConfiguration>,
+    /// Most unique contacts any schedule of this problem could reach, from group capacities and
+    /// session attendance alone. Constraints are ignored, so the bound may not be attainable.
+    #[serde(default, alias = "uniqueContactsUpperBound")]
+    pub unique_contacts_upper_bound: Option<u32>,
+    /// `(unique_contacts_upper_bound - unique_contacts) / unique_contacts_upper_bound`, in
+    /// `[0, 1]`. `0.0` means the schedule meets the bound and more runtime cannot add contacts.
+    #[serde(default, alias = "optimalityGap")]
+    pub optimality_gap: Option<f64>,
+}
+
+/// A single violated constraint in a result schedule.
+///
+/// Soft constraints report the amount they add to `final_score`. Hard constraints
+/// (`MustStayTogether`, `MustStayApart`, `BaselineMoveLimit`, `GroupAttributeRequirement`,
+/// `TrackGrouping`) carry no weight, so they report `0.0`. Repeat encounters, attribute
+/// balance, and uneven group sizes under the `balance_group_sizes` objective (reported with
+/// that objective type) are listed as well, even though their cost is accounted in
+/// `weighted_repetition_penalty`, `attribute_balance_penalty`, and the group-fill penalty.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+pub struct ConstraintViolation {
+    /// Constraint kind, matching the `type` tag of [`Constraint`] (e.g. `"ShouldNotBeTogether"`)
+    #[serde(alias = "constraintType")]
+    pub constraint_type: String,
+    /// Session index of the violation, or `None` when it spans sessions
+    #[serde(default)]
+    pub session: Option<u32>,
+    /// People involved in the violation
+    #[serde(default)]
+    pub people: Vec<String>,
+    /// Groups involved in the violation
+    #[serde(default)]
+    pub groups: Vec<String>,
+    /// Weighted contribution of this violation to the score
+    #[serde(alias = "weightedPenalty")]
+    pub weighted_penalty: f64,
 }
 
-impl SolverResult {
-    /// Formats the schedule as a human-readable string.
-    ///
-    /// This method converts the nested HashMap schedule structure into a
-    /// nicely formatted string that shows the group assignments for each session.
-    /// Sessions and groups are sorted for consistent output.
-    ///
-    /// # Example Output
-    ///
-    /// ```text
-    /// ========== SESSION_0 ==========
-    /// Group1: Alice, Bob, Charlie
-    /// Group2: Diana, Eve, Frank
-    ///
-    /// ========== SESSION_1 ==========
-    /// Group1: Alice, Diana, Grace
-    /// Group2: Bob, Eve, Henry
-    ///
-    /// ```
-    ///
-    /// # Returns
-    ///
-    /// A formatted string showing all group assignments across all sessions.
-    ///
-    /// # Example
-    ///
-    /// ```no_run
-    /// use gm_core::{run_solver, models::*};
-    /// # use std::collections::HashMap;
-    /// # let input = ApiInput {
-    /// #     initial_schedule: None,
-    /// #     construction_seed_schedule: None,
-    /// #     problem: ProblemDefinition { people: vec![], groups: vec![], num_sessions: 1 },
-    /// #     objectives: vec![], constraints: vec![],
-    /// #     solver: SolverConfiguration {
-    /// #         solver_type: "SimulatedAnnealing".to_string(),
-    /// #         stop_conditions: StopConditions { max_iterations: Some(1000), time_limit_seconds: None, no_improvement_iterations: None, stop_on_optimal_score: true },
-    /// #         solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams { initial_temperature: 10.0, final_temperature: 0.1, cooling_schedule: "geometric".to_string(), reheat_after_no_improvement: Some(0), reheat_cycles: Some(0) }),
-    /// #         logging: LoggingOptions::default(),
-    /// #         telemetry: Default::default(),
-    /// #         seed: None,
-    /// #         move_policy: None,
-    /// #         allowed_sessions: None,
-    /// #     },
-    /// # };
-    ///
-    /// match run_solver(&input) {
-    ///     Ok(result) => {
-    ///         // Print the formatted schedule
-    ///         println!("{}", result.display());
-    ///     }
-    ///     Err(e) => eprintln!("Error: {:?}", e),
-    /// }
-    /// ```
-    pub fn display(&self) -> String {
-        let mut output = String::new();
-
-        let mut sorted_sessions: Vec<_> = self.schedule.keys().collect();
-        sorted_sessions.sort_by_key(|a| {
-            a.split('_')
-                .next_back()
-                .unwrap_or("0")
-                .parse::<usize>()
-                .unwrap_or(0)
-        });
-
-        for session_key in sorted_sessions {
-            output.push_str(&format!(
-                "========== {} ==========\n",
-                session_key.to_uppercase()
-            ));
-            if let Some(groups) = self.schedule.get(session_key) {
-                let mut sorted_groups: Vec<_> = groups.keys().collect();
-                sorted_groups.sort();
-
-                for group_key in sorted_groups {
-                    if let Some(people) = groups.get(group_key) {
-                        let people_list = people.join(", ");
-                        output.push_str(&format!("{group_key}: {people_list}\n"));
-                    }
-                }
-            }
-            output.push('\n');
+/// One sample of a search's cost trace.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+pub struct ScoreHistoryPoint {
+    /// Iterations completed when the sample was taken
+    pub iteration: u64,
+    /// Cost of the current (working) schedule
+    #[serde(alias = "currentCost")]
+    pub current_cost: f64,
+    /// Best cost found so far
+    #[serde(alias = "bestCost")]
+    pub best_cost: f64,
+    /// Annealing temperature, for temperature-driven searches
+    #[serde(default)]
+    pub temperature: Option<f64>,
+}
+
+/// Moves of one operator proposed and accepted within a move statistics window.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OperatorMoveCounts {
+    pub proposed: u64,
+    pub accepted: u64,
+}
+
+/// Move statistics of the iterations since the previous sample, up to `iteration`.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+pub struct MoveStatisticsPoint {
+    /// Iterations completed when the window closed
+    pub iteration: u64,
+    /// Annealing temperature at the end of the window, for temperature-driven searches
+    #[serde(default)]
+    pub temperature: Option<f64>,
+    #[serde(default)]
+    pub swap: OperatorMoveCounts,
+    #[serde(default)]
+    pub transfer: OperatorMoveCounts,
+    #[serde(default, alias = "cliqueSwap")]
+    pub clique_swap: OperatorMoveCounts,
+    /// Proposed moves that would have increased the cost
+    #[serde(default, alias = "uphillProposed")]
+    pub uphill_proposed: u64,
+    /// Uphill moves the acceptance criterion let through
+    #[serde(default, alias = "uphillAccepted")]
+    pub uphill_accepted: u64,
+    /// `uphill_accepted / uphill_proposed`, or `None` when no uphill move was proposed
+    #[serde(default, alias = "uphillAcceptanceRate")]
+    pub uphill_acceptance_rate: Option<f64>,
+}
+
+/// Weighted penalty of one input constraint in a schedule.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+pub struct ConstraintPenalty {
+    /// Position of the constraint in the input's `constraints`. Constraints added to a built
+    /// state continue the numbering.
+    #[serde(alias = "constraintIndex")]
+    pub constraint_index: u32,
+    #[serde(alias = "weightedPenalty")]
+    pub weighted_penalty: f64,
+}
+
+/// Weighted penalties of the soft constraints in the current schedule at one iteration.
+///
+/// Covers `RepeatEncounter`, `AttributeBalance`, `ShouldNotBeTogether`, `ShouldStayTogether`,
+/// `PairMeetingCount`, and `UniqueGroupComposition`, including those with no penalty; hard
+/// constraints are left out.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+pub struct ConstraintPenaltyPoint {
+    /// Iterations completed when the sample was taken
+    pub iteration: u64,
+    /// One entry per soft constraint, by ascending `constraint_index`
+    pub penalties: Vec<ConstraintPenalty>,
+}
+
+/// Number of sessions two people shared a group in a result schedule.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+pub struct PairContactCount {
+    /// First person ID; the lexicographically smaller of the pair
+    #[serde(alias = "personA")]
+    pub person_a: String,
+    /// Second person ID
+    #[serde(alias = "personB")]
+    pub person_b: String,
+    /// Sessions the pair spent in the same group
+    pub count: u32,
+}
+
+/// Score contributions of one session of a result schedule.
+///
+/// Pairs are attributed chronologically: the session where a pair first meets gains the
+/// contact, and each later meeting adds the increase in repetition penalty it causes.
+/// Contact history counts as meetings before the first session, so a history that already
+/// exceeds the repeat limit is charged to no session.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+pub struct SessionScoreBreakdown {
+    /// Session index
+    pub session: u32,
+    /// Pairs meeting for the first time in this session
+    #[serde(alias = "uniqueContactsGained")]
+    pub unique_contacts_gained: u32,
+    /// Weighted repetition penalty added by repeat meetings in this session, after history
+    /// decay and `session_weights`. Without session weights, the sessions sum to
+    /// `weighted_repetition_penalty` less the penalty of the history alone.
+    #[serde(alias = "repetitionPenalty")]
+    pub repetition_penalty: f64,
+    /// Weighted penalty of this session's constraint violations, keyed by constraint type.
+    /// Violations that span sessions (such as `PairMeetingCount`) are not included.
+    #[serde(default, alias = "constraintPenalties")]
+    pub constraint_penalties: HashMap<String, f64>,
+}
+
+/// One person's group in every session, as returned by [`SolverResult::itineraries`].
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+pub struct PersonItinerary {
+    /// Person ID
+    #[serde(alias = "personId")]
+    pub person_id: String,
+    /// One entry per session of the schedule, in session order
+    pub stops: Vec<ItineraryStop>,
+}
+
+/// Where a person is during one session.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+pub struct ItineraryStop {
+    /// 0-based session index
+    pub session: u32,
+    /// Assigned group, or `None` if the person is not scheduled in this session
+    #[serde(alias = "groupId")]
+    pub group_id: Option<String>,
+}
+
+/// When a session takes place, for calendar exports.
+///
+/// Both times are iCalendar UTC date-times such as `20261014T090000Z`.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+pub struct SessionTime {
+    /// Start of the session
+    pub start: String,
+    /// End of the session
+    pub end: String,
+}
+
+/// Layout options for [`SolverResult::display_with`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DisplayOptions {
+    /// Fixed width of every column in characters, truncating longer cells with `…`.
+    /// `None` sizes each column to its widest cell.
+    pub column_width: Option<usize>,
+    /// Person attributes to show as extra columns after the person
+    pub attributes: Vec<String>,
+    /// Session headers, indexed by session. Sessions without a non-empty label are
+    /// headed `Session <index>`.
+    pub session_labels: Vec<String>,
+}
+
+/// Output format of [`SolverResult::to_report`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// A Markdown document
+    Markdown,
+    /// A self-contained HTML page with inline styles and an SVG convergence chart
+    Html,
+}
+
+/// How one person fared in a result schedule, as returned by [`SolverResult::person_metrics`].
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+pub struct PersonMetrics {
+    /// Person ID
+    #[serde(alias = "personId")]
+    pub person_id: String,
+    /// Sessions the person is assigned to a group in
+    pub sessions: u32,
+    /// Distinct people the person shared a group with
+    #[serde(alias = "uniqueContacts")]
+    pub unique_contacts: u32,
+    /// People the person shared a group with in more than one session
+    #[serde(alias = "repeatContacts")]
+    pub repeat_contacts: u32,
+}
+
+/// Differences between two schedules of the same problem, as returned by
+/// [`diff_schedules`](crate::diff_schedules).
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+pub struct ScheduleDiff {
+    /// Per-session changes, in session order
+    pub sessions: Vec<SessionDiff>,
+    /// Number of assignments that differ, summed over all sessions
+    #[serde(alias = "totalMoves")]
+    pub total_moves: u32,
+    /// Share of all assignments that differ, in `[0, 1]`
+    #[serde(alias = "churnRate")]
+    pub churn_rate: f64,
+}
+
+/// Changes within one session between two schedules.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+pub struct SessionDiff {
+    /// Session key, e.g. `"session_0"`
+    pub session: String,
+    /// People whose group changed, ordered by person ID
+    pub moves: Vec<PersonMove>,
+    /// Number of people whose group changed
+    #[serde(alias = "peopleMoved")]
+    pub people_moved: u32,
+    /// Share of the people assigned in this session (in either schedule) who moved
+    #[serde(alias = "churnRate")]
+    pub churn_rate: f64,
+}
+
+/// A person's group in the old and the new schedule of one session.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+pub struct PersonMove {
+    /// Person ID
+    #[serde(alias = "personId")]
+    pub person_id: String,
+    /// Group in the old schedule, or `None` if the person was not assigned
+    #[serde(alias = "fromGroup")]
+    pub from_group: Option<String>,
+    /// Group in the new schedule, or `None` if the person is no longer assigned
+    #[serde(alias = "toGroup")]
+    pub to_group: Option<String>,
+}
+
+/// A warm start made valid by [`repair_initial_schedule`](crate::repair_initial_schedule).
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+pub struct WarmStartRepair {
+    /// The repaired schedule, listing every session and every open group
+    pub schedule: ApiSchedule,
+    /// Every change made to the draft, in session order
+    pub repairs: Vec<ScheduleRepair>,
+}
+
+/// One change [`repair_initial_schedule`](crate::repair_initial_schedule) made to a draft.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleRepair {
+    /// Why the person was moved
+    pub kind: ScheduleRepairKind,
+    /// Session index of the change
+    pub session: u32,
+    /// Person ID
+    #[serde(alias = "personId")]
+    pub person_id: String,
+    /// Group the draft placed the person in, or `None` if it left them out
+    #[serde(alias = "fromGroup")]
+    pub from_group: Option<String>,
+    /// Group the person was placed in
+    #[serde(alias = "toGroup")]
+    pub to_group: String,
+}
+
+/// Problem fixed by a [`ScheduleRepair`].
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduleRepairKind {
+    /// The person was moved to the group an `ImmovablePerson`/`ImmovablePeople` constraint
+    /// requires
+    DisplacedImmovable,
+    /// The person was moved out of a group holding more people than its capacity
+    CapacityOverflow,
+    /// The person attends the session but the draft did not place them
+    MissingPerson,
+}
+
+/// Size and tightness figures of a problem, from [`problem_stats`](crate::problem_stats).
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+pub struct ProblemStats {
+    /// Number of people
+    pub people: u32,
+    /// Number of groups
+    pub groups: u32,
+    /// Number of sessions
+    #[serde(alias = "numSessions")]
+    pub num_sessions: u32,
+    /// Pairs of people, i.e. the unique contacts if everyone could meet everyone
+    #[serde(alias = "totalPairs")]
+    pub total_pairs: u64,
+    /// Unique contacts no schedule can exceed given group capacities and attendance
+    #[serde(alias = "maxPossibleContacts")]
+    pub max_possible_contacts: u32,
+    /// Seats against attendees for every session
+    #[serde(alias = "capacitySlack")]
+    pub capacity_slack: Vec<SessionCapacity>,
+    /// Sizes of `MustStayTogether` cliques, largest first. Cliques sharing a person are
+    /// merged, and session scopes are ignored.
+    #[serde(alias = "cliqueSizes")]
+    pub clique_sizes: Vec<u32>,
+    /// Number of constraints of each type, keyed by the constraint's `type` tag
+    #[serde(alias = "constraintCounts")]
+    pub constraint_counts: BTreeMap<String, u32>,
+}
+
+/// Estimated working memory of a solve, from [`estimate_memory`](crate::estimate_memory).
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+pub struct MemoryEstimate {
+    /// Bytes of per-pair tables (contact counts and session-weighted contacts), which grow
+    /// with the square of the number of people
+    #[serde(alias = "pairTableBytes")]
+    pub pair_table_bytes: u64,
+    /// Bytes of the schedule, person locations, and attendance
+    #[serde(alias = "scheduleBytes")]
+    pub schedule_bytes: u64,
+    /// Estimated peak bytes for the whole solve, including the copies the search keeps
+    #[serde(alias = "totalBytes")]
+    pub total_bytes: u64,
+    /// Largest `total_bytes` a solve is started with; `None` where solves have no limit,
+    /// which is everywhere but WebAssembly builds
+    #[serde(alias = "limitBytes")]
+    pub limit_bytes: Option<u64>,
+}
+
+/// Seats and attendees of one session in [`ProblemStats`].
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+pub struct SessionCapacity {
+    /// 0-based session index
+    pub session: u32,
+    /// People attending the session
+    pub attendees: u32,
+    /// Seats across the groups open in the session
+    pub capacity: u32,
+    /// Seats left empty when everyone is seated; negative when attendees do not fit
+    pub slack: i64,
+}
+
+/// Errors and warnings found by [`validate`](crate::validate) without solving.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq, Default)]
+pub struct ValidationReport {
+    /// Problems that make the input unsolvable
+    pub errors: Vec<ValidationFinding>,
+    /// Solvable but suspicious input, such as constraints that can never take effect
+    pub warnings: Vec<ValidationFinding>,
+}
+
+impl ValidationReport {
+    /// Whether solving the input can succeed, i.e. there are no errors.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// One error or warning of a [`ValidationReport`].
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+pub struct ValidationFinding {
+    /// Human-readable description
+    pub message: String,
+    /// What the finding is about; `details.kind` names its type, e.g. `"redundant_constraint"`
+    pub details: SolverErrorDetails,
+}
+
+/// A small set of likely conflicting constraints, from
+/// [`explain_infeasibility`](crate::explain_infeasibility).
+///
+/// The set comes from solver1's randomized construction failing, not from an exact check, so
+/// the constraints are likely but not proven to be unsatisfiable together.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct InfeasibilityExplanation {
+    /// The error the construction reports with only these constraints
+    pub message: String,
+    /// Entities named by that error; constraint indices refer to the original input
+    pub details: SolverErrorDetails,
+    /// Positions in the input's `constraints` of the likely conflicting constraints, in input
+    /// order. Empty when construction fails even without constraints, e.g. too few seats.
+    #[serde(alias = "constraintIndices")]
+    pub constraint_indices: Vec<usize>,
+    /// The likely conflicting constraints themselves
+    pub constraints: Vec<Constraint>,
+}
+
+/// How [`resolve_with_people_changes`](crate::resolve_with_people_changes) treats people who
+/// were neither added nor removed.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+pub struct PeopleChangeOptions {
+    /// Keep everyone who still attends a session in their previous group for that session
+    /// (default). When `false`, the previous schedule is only a starting point and the search
+    /// may move anyone.
+    #[serde(
+        default = "default_keep_unaffected_fixed",
+        alias = "keepUnaffectedFixed"
+    )]
+    pub keep_unaffected_fixed: bool,
+}
+
+fn default_keep_unaffected_fixed() -> bool {
+    true
+}
+
+impl Default for PeopleChangeOptions {
+    fn default() -> Self {
+        Self {
+            keep_unaffected_fixed: default_keep_unaffected_fixed(),
         }
-        output
     }
 }
+
+/// How much searching [`auto_tune`](crate::auto_tune) may do.
+///
+/// Successive halving runs every candidate for `initial_iterations`, keeps the better half,
+/// doubles the iterations, and repeats until one candidate is left. Each round costs about
+/// `candidates * initial_iterations` iterations, over `log2(candidates)` rounds.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, PartialEq)]
+pub struct TuningBudget {
+    /// Parameter sets tried in the first round (default 8)
+    #[serde(default = "default_tuning_candidates")]
+    pub candidates: u32,
+    /// Iterations each candidate runs in the first round (default 2000)
+    #[serde(
+        default = "default_tuning_initial_iterations",
+        alias = "initialIterations"
+    )]
+    pub initial_iterations: u64,
+}
+
+fn default_tuning_candidates() -> u32 {
+    8
+}
+
+fn default_tuning_initial_iterations() -> u64 {
+    2_000
+}
+
+impl Default for TuningBudget {
+    fn default() -> Self {
+        Self {
+            candidates: default_tuning_candidates(),
+            initial_iterations: default_tuning_initial_iterations(),
+        }
+    }
+}
+
+/// Effect of swapping two people within one session, from
+/// [`evaluate_move`](crate::evaluate_move).
+///
+/// Each field is the value after the swap minus the value before, in the units of the matching
+/// [`SolverResult`] field. Costs are minimized, so a negative `cost_delta` is an improvement.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+pub struct ScoreDelta {
+    /// Whether the solver itself could make this swap. `false` when it would break a hard
+    /// constraint such as `MustStayTogether`, `MustStayApart`, or `ImmovablePerson`; the other
+    /// fields still describe the swapped schedule.
+    pub feasible: bool,
+    /// Change of the total cost the solver minimizes
+    #[serde(alias = "costDelta")]
+    pub cost_delta: f64,
+    #[serde(alias = "uniqueContactsDelta")]
+    pub unique_contacts_delta: i32,
+    #[serde(alias = "repetitionPenaltyDelta")]
+    pub repetition_penalty_delta: i32,
+    #[serde(alias = "attributeBalancePenaltyDelta")]
+    pub attribute_balance_penalty_delta: f64,
+    #[serde(alias = "constraintPenaltyDelta")]
+    pub constraint_penalty_delta: i32,
+    #[serde(alias = "weightedRepetitionPenaltyDelta")]
+    pub weighted_repetition_penalty_delta: f64,
+    #[serde(alias = "weightedConstraintPenaltyDelta")]
+    pub weighted_constraint_penalty_delta: f64,
+}
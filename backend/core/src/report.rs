@@ -0,0 +1,428 @@
+//! Shareable reports of a solver result, for organizers rather than developers.
+//!
+//! A report is assembled once as a list of sections and then rendered as Markdown or as a
+//! self-contained HTML page, so both formats always carry the same content.
+
+use crate::export::push_markdown_row;
+use crate::models::{PersonMetrics, ReportFormat, SolverResult, StopReason};
+use crate::solver_support::contact_counts::schedule_contact_counts;
+use std::collections::BTreeMap;
+
+/// Size of the convergence chart in HTML reports, in pixels.
+const CHART_WIDTH: f64 = 640.0;
+const CHART_HEIGHT: f64 = 220.0;
+const CHART_PADDING: f64 = 40.0;
+
+struct Section {
+    title: &'static str,
+    blocks: Vec<Block>,
+}
+
+enum Block {
+    Text(String),
+    Subheading(String),
+    Table {
+        header: Vec<String>,
+        rows: Vec<Vec<String>>,
+    },
+    /// `(iteration, cost)` samples, drawn as a line in HTML and left out of Markdown
+    Chart(Vec<(f64, f64)>),
+}
+
+impl SolverResult {
+    /// Per-person contact metrics of the schedule, ordered by person ID.
+    ///
+    /// Only people assigned to a group in at least one session are listed.
+    pub fn person_metrics(&self) -> Vec<PersonMetrics> {
+        fn entry<'a>(
+            metrics: &'a mut BTreeMap<String, PersonMetrics>,
+            person_id: &str,
+        ) -> &'a mut PersonMetrics {
+            metrics
+                .entry(person_id.to_string())
+                .or_insert_with(|| PersonMetrics {
+                    person_id: person_id.to_string(),
+                    sessions: 0,
+                    unique_contacts: 0,
+                    repeat_contacts: 0,
+                })
+        }
+
+        let mut metrics = BTreeMap::new();
+        for groups in self.schedule.values() {
+            for person_id in groups.values().flatten() {
+                entry(&mut metrics, person_id).sessions += 1;
+            }
+        }
+        for pair in schedule_contact_counts(&self.schedule) {
+            for person_id in [&pair.person_a, &pair.person_b] {
+                let person = entry(&mut metrics, person_id);
+                person.unique_contacts += 1;
+                if pair.count > 1 {
+                    person.repeat_contacts += 1;
+                }
+            }
+        }
+        metrics.into_values().collect()
+    }
+
+    /// Formats a report with a summary, the schedule, per-person metrics, the constraint
+    /// violations, and the convergence trace, for sharing with people who do not read JSON.
+    ///
+    /// People are shown as `Name (id)` when `display_names` has their name. The convergence
+    /// section needs `score_history`, which is recorded when
+    /// `telemetry.record_score_history` is set.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use gm_core::models::{ApiInput, ReportFormat};
+    /// # fn example(input: &ApiInput) {
+    /// let result = gm_core::run_solver(input).expect("solve should succeed");
+    /// std::fs::write("report.html", result.to_report(ReportFormat::Html)).expect("write report");
+    /// # }
+    /// ```
+    pub fn to_report(&self, format: ReportFormat) -> String {
+        let sections = self.report_sections();
+        match format {
+            ReportFormat::Markdown => render_markdown(&sections),
+            ReportFormat::Html => render_html(&sections),
+        }
+    }
+
+    fn report_sections(&self) -> Vec<Section> {
+        let label = |person_id: &str| match self
+            .display_names
+            .as_ref()
+            .and_then(|names| names.get(person_id))
+        {
+            Some(name) => format!("{name} ({person_id})"),
+            None => person_id.to_string(),
+        };
+
+        let mut summary = vec![
+            [
+                "Final score".to_string(),
+                format!("{:.2}", self.final_score),
+            ],
+            [
+                "Unique contacts".to_string(),
+                match self.unique_contacts_upper_bound {
+                    Some(bound) => format!("{} of at most {bound}", self.unique_contacts),
+                    None => self.unique_contacts.to_string(),
+                },
+            ],
+            [
+                "Repetition penalty".to_string(),
+                self.repetition_penalty.to_string(),
+            ],
+            [
+                "Attribute balance penalty".to_string(),
+                self.attribute_balance_penalty.to_string(),
+            ],
+            [
+                "Constraint penalty".to_string(),
+                self.constraint_penalty.to_string(),
+            ],
+        ];
+        if let Some(reason) = self.stop_reason {
+            summary.push([
+                "Stopped because".to_string(),
+                stop_reason_text(reason).to_string(),
+            ]);
+        }
+        if let Some(iterations) = self.iterations_run {
+            summary.push(["Iterations".to_string(), iterations.to_string()]);
+        }
+        if let Some(elapsed_ms) = self.time_elapsed_ms {
+            summary.push([
+                "Run time".to_string(),
+                format!("{:.1} s", elapsed_ms as f64 / 1000.0),
+            ]);
+        }
+
+        let mut schedule = Vec::new();
+        for (session, groups) in self.sorted_sessions() {
+            schedule.push(Block::Subheading(format!("Session {session}")));
+            if groups.is_empty() {
+                schedule.push(Block::Text("No groups.".to_string()));
+                continue;
+            }
+            let rows = groups
+                .iter()
+                .map(|(_, members)| members.len())
+                .max()
+                .unwrap_or(0);
+            schedule.push(Block::Table {
+                header: groups
+                    .iter()
+                    .map(|(group_id, _)| group_id.to_string())
+                    .collect(),
+                rows: (0..rows)
+                    .map(|row| {
+                        groups
+                            .iter()
+                            .map(|(_, members)| {
+                                members.get(row).map_or(String::new(), |id| label(id))
+                            })
+                            .collect()
+                    })
+                    .collect(),
+            });
+        }
+
+        let people = Block::Table {
+            header: ["Person", "Sessions", "Unique contacts", "Repeat contacts"]
+                .map(String::from)
+                .to_vec(),
+            rows: self
+                .person_metrics()
+                .into_iter()
+                .map(|metrics| {
+                    vec![
+                        label(&metrics.person_id),
+                        metrics.sessions.to_string(),
+                        metrics.unique_contacts.to_string(),
+                        metrics.repeat_contacts.to_string(),
+                    ]
+                })
+                .collect(),
+        };
+
+        let violations = match &self.constraint_violations {
+            None => Block::Text("Not available for this result.".to_string()),
+            Some(violations) if violations.is_empty() => {
+                Block::Text("No constraints are violated.".to_string())
+            }
+            Some(violations) => Block::Table {
+                header: ["Constraint", "Session", "People", "Groups", "Penalty"]
+                    .map(String::from)
+                    .to_vec(),
+                rows: violations
+                    .iter()
+                    .map(|violation| {
+                        vec![
+                            violation.constraint_type.clone(),
+                            violation
+                                .session
+                                .map_or_else(|| "all".to_string(), |session| session.to_string()),
+                            violation
+                                .people
+                                .iter()
+                                .map(|id| label(id))
+                                .collect::<Vec<_>>()
+                                .join(", "),
+                            violation.groups.join(", "),
+                            format!("{:.2}", violation.weighted_penalty),
+                        ]
+                    })
+                    .collect(),
+            },
+        };
+
+        let convergence = match &self.score_history {
+            Some(history) if !history.is_empty() => vec![
+                Block::Chart(
+                    history
+                        .iter()
+                        .map(|point| (point.iteration as f64, point.best_cost))
+                        .collect(),
+                ),
+                Block::Table {
+                    header: ["Iteration", "Best cost", "Current cost"]
+                        .map(String::from)
+                        .to_vec(),
+                    rows: history
+                        .iter()
+                        .map(|point| {
+                            vec![
+                                point.iteration.to_string(),
+                                format!("{:.2}", point.best_cost),
+                                format!("{:.2}", point.current_cost),
+                            ]
+                        })
+                        .collect(),
+                },
+            ],
+            _ => vec![Block::Text(
+                "No score history was recorded; set telemetry.record_score_history to include it."
+                    .to_string(),
+            )],
+        };
+
+        vec![
+            Section {
+                title: "Summary",
+                blocks: vec![Block::Table {
+                    header: vec!["Metric".to_string(), "Value".to_string()],
+                    rows: summary.into_iter().map(Vec::from).collect(),
+                }],
+            },
+            Section {
+                title: "Schedule",
+                blocks: schedule,
+            },
+            Section {
+                title: "People",
+                blocks: vec![people],
+            },
+            Section {
+                title: "Constraint violations",
+                blocks: vec![violations],
+            },
+            Section {
+                title: "Convergence",
+                blocks: convergence,
+            },
+        ]
+    }
+}
+
+fn stop_reason_text(reason: StopReason) -> &'static str {
+    match reason {
+        StopReason::MaxIterationsReached => "iteration limit reached",
+        StopReason::TimeLimitReached => "time limit reached",
+        StopReason::NoImprovementLimitReached => "no improvement for too many iterations",
+        StopReason::NoImprovementTimeLimitReached => "no improvement for too long",
+        StopReason::ProgressCallbackRequestedStop => "stopped by the caller",
+        StopReason::OptimalScoreReached => "optimal score reached",
+        StopReason::Converged => "search converged",
+        StopReason::FeasibleReached => "feasible schedule reached",
+    }
+}
+
+fn render_markdown(sections: &[Section]) -> String {
+    let mut output = String::from("# Schedule report\n");
+    for section in sections {
+        output.push_str(&format!("\n## {}\n", section.title));
+        for block in &section.blocks {
+            match block {
+                Block::Text(text) => output.push_str(&format!("\n{text}\n")),
+                Block::Subheading(heading) => output.push_str(&format!("\n### {heading}\n")),
+                Block::Table { header, rows } => {
+                    output.push('\n');
+                    push_markdown_row(&mut output, header.iter().map(String::as_str));
+                    push_markdown_row(&mut output, header.iter().map(|_| "---"));
+                    for row in rows {
+                        push_markdown_row(&mut output, row.iter().map(String::as_str));
+                    }
+                }
+                Block::Chart(_) => {}
+            }
+        }
+    }
+    output
+}
+
+fn render_html(sections: &[Section]) -> String {
+    let mut output = String::from(concat!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n",
+        "<title>Schedule report</title>\n<style>\n",
+        "body { font-family: system-ui, sans-serif; margin: 2rem auto; max-width: 60rem; }\n",
+        "table { border-collapse: collapse; margin: 0.5rem 0 1rem; }\n",
+        "th, td { border: 1px solid #ccc; padding: 0.25rem 0.75rem; text-align: left; }\n",
+        "th { background: #f3f3f3; }\n",
+        "</style>\n</head>\n<body>\n<h1>Schedule report</h1>\n",
+    ));
+    for section in sections {
+        output.push_str(&format!("<h2>{}</h2>\n", html_text(section.title)));
+        for block in &section.blocks {
+            match block {
+                Block::Text(text) => output.push_str(&format!("<p>{}</p>\n", html_text(text))),
+                Block::Subheading(heading) => {
+                    output.push_str(&format!("<h3>{}</h3>\n", html_text(heading)));
+                }
+                Block::Table { header, rows } => push_html_table(&mut output, header, rows),
+                Block::Chart(points) => push_svg_chart(&mut output, points),
+            }
+        }
+    }
+    output.push_str("</body>\n</html>\n");
+    output
+}
+
+fn push_html_table(output: &mut String, header: &[String], rows: &[Vec<String>]) {
+    output.push_str("<table>\n<tr>");
+    for cell in header {
+        output.push_str(&format!("<th>{}</th>", html_text(cell)));
+    }
+    output.push_str("</tr>\n");
+    for row in rows {
+        output.push_str("<tr>");
+        for cell in row {
+            output.push_str(&format!("<td>{}</td>", html_text(cell)));
+        }
+        output.push_str("</tr>\n");
+    }
+    output.push_str("</table>\n");
+}
+
+/// Draws `points` as a line chart, with the first and last x and the y range labelled.
+fn push_svg_chart(output: &mut String, points: &[(f64, f64)]) {
+    let (min_x, max_x) = bounds(points.iter().map(|(x, _)| *x));
+    let (min_y, max_y) = bounds(points.iter().map(|(_, y)| *y));
+    let scale = |value: f64, min: f64, max: f64, length: f64| {
+        if max > min {
+            (value - min) / (max - min) * length
+        } else {
+            length / 2.0
+        }
+    };
+    let plot_width = CHART_WIDTH - 2.0 * CHART_PADDING;
+    let plot_height = CHART_HEIGHT - 2.0 * CHART_PADDING;
+    let polyline = points
+        .iter()
+        .map(|(x, y)| {
+            format!(
+                "{:.1},{:.1}",
+                CHART_PADDING + scale(*x, min_x, max_x, plot_width),
+                CHART_HEIGHT - CHART_PADDING - scale(*y, min_y, max_y, plot_height)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let bottom = CHART_HEIGHT - CHART_PADDING;
+    let right = CHART_WIDTH - CHART_PADDING;
+    output.push_str(&format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{CHART_WIDTH}\" height=\"{CHART_HEIGHT}\" \
+         role=\"img\" aria-label=\"Best cost by iteration\">\n"
+    ));
+    output.push_str(&format!(
+        "<polyline fill=\"none\" stroke=\"#999\" points=\"{CHART_PADDING},{CHART_PADDING} \
+         {CHART_PADDING},{bottom} {right},{bottom}\"/>\n"
+    ));
+    output.push_str(&format!(
+        "<polyline fill=\"none\" stroke=\"#1f77b4\" stroke-width=\"2\" points=\"{polyline}\"/>\n"
+    ));
+    output.push_str(&format!(
+        "<text x=\"{CHART_PADDING}\" y=\"{}\" font-size=\"12\">{min_x}</text>\n",
+        bottom + 16.0
+    ));
+    output.push_str(&format!(
+        "<text x=\"{right}\" y=\"{}\" font-size=\"12\" text-anchor=\"end\">iteration {max_x}</text>\n",
+        bottom + 16.0
+    ));
+    output.push_str(&format!(
+        "<text x=\"{}\" y=\"{CHART_PADDING}\" font-size=\"12\" text-anchor=\"end\">{max_y:.2}</text>\n",
+        CHART_PADDING - 4.0
+    ));
+    output.push_str(&format!(
+        "<text x=\"{}\" y=\"{bottom}\" font-size=\"12\" text-anchor=\"end\">{min_y:.2}</text>\n",
+        CHART_PADDING - 4.0
+    ));
+    output.push_str("</svg>\n");
+}
+
+fn bounds(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+        (min.min(value), max.max(value))
+    })
+}
+
+fn html_text(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -59,10 +59,31 @@ pub(crate) fn displayed_total_iterations(
     )
 }
 
+pub(crate) fn estimated_remaining_seconds(
+    completed_iterations: u64,
+    configured_max_iterations: u64,
+    elapsed_seconds: f64,
+    time_limit_seconds: Option<u64>,
+) -> Option<f64> {
+    let remaining_by_time = time_limit_seconds
+        .filter(|limit| *limit > 0)
+        .map(|limit| (limit as f64 - elapsed_seconds).max(0.0));
+    let remaining_by_iterations = (completed_iterations > 0 && elapsed_seconds > 0.0).then(|| {
+        configured_max_iterations.saturating_sub(completed_iterations) as f64 * elapsed_seconds
+            / completed_iterations as f64
+    });
+
+    match (remaining_by_time, remaining_by_iterations) {
+        (Some(by_time), Some(by_iterations)) => Some(by_time.min(by_iterations)),
+        (by_time, by_iterations) => by_time.or(by_iterations),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
-        displayed_total_iterations, estimated_total_iterations, runtime_target_iteration_cap,
+        displayed_total_iterations, estimated_remaining_seconds, estimated_total_iterations,
+        runtime_target_iteration_cap,
     };
     use crate::models::StopReason;
 
@@ -95,4 +116,21 @@ mod tests {
         );
         assert_eq!(displayed, 8_765);
     }
+
+    #[test]
+    fn estimated_remaining_seconds_takes_the_nearer_of_both_limits() {
+        assert_eq!(
+            estimated_remaining_seconds(1_000, 4_000, 2.0, None),
+            Some(6.0)
+        );
+        assert_eq!(
+            estimated_remaining_seconds(1_000, 4_000, 2.0, Some(5)),
+            Some(3.0)
+        );
+        assert_eq!(estimated_remaining_seconds(0, 4_000, 0.0, None), None);
+        assert_eq!(
+            estimated_remaining_seconds(0, 4_000, 0.0, Some(5)),
+            Some(5.0)
+        );
+    }
 }
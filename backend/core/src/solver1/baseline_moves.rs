@@ -0,0 +1,160 @@
+//! Baseline move-limit bookkeeping for the solver state.
+//!
+//! `BaselineMoveLimit` constraints cap how many people may sit in a different group than
+//! `initial_schedule` placed them in. The cap is hard: move evaluation treats any move that
+//! would exceed it as infeasible, so the search never leaves the allowed neighbourhood of
+//! the baseline.
+
+use super::constraint_index::ResolvedBaselineMoveLimit;
+use super::State;
+use crate::models::{ApiInput, BaselineMoveLimitScope, Constraint};
+use crate::solver_support::SolverError;
+
+impl State {
+    pub(crate) fn resolve_baseline_move_limits(
+        &mut self,
+        input: &ApiInput,
+    ) -> Result<(), SolverError> {
+        let num_sessions = self.num_sessions as usize;
+
+        for constraint in &input.constraints {
+            let Constraint::BaselineMoveLimit(params) = constraint else {
+                continue;
+            };
+
+            if input.initial_schedule.is_none() {
+                return Err(SolverError::ValidationError(
+                    "BaselineMoveLimit requires an initial_schedule to measure moves against"
+                        .to_string(),
+                ));
+            }
+
+            let mut sessions = match &params.sessions {
+                Some(sessions) => {
+                    let mut resolved = Vec::with_capacity(sessions.len());
+                    for &session in sessions {
+                        let session_idx = session as usize;
+                        if session_idx >= num_sessions {
+                            return Err(SolverError::InvalidSession {
+                                session: session_idx,
+                                num_sessions,
+                                referenced_by: "BaselineMoveLimit".to_string(),
+                            });
+                        }
+                        resolved.push(session_idx);
+                    }
+                    resolved
+                }
+                None => (0..num_sessions).collect(),
+            };
+            sessions.sort_unstable();
+            sessions.dedup();
+
+            self.baseline_move_limits.push(ResolvedBaselineMoveLimit {
+                sessions,
+                max_moves: params.max_moves,
+                scope: params.scope,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Snapshots the current schedule as the baseline that move limits are measured against.
+    ///
+    /// Called once the incumbent `initial_schedule` has been loaded; does nothing when no
+    /// `BaselineMoveLimit` constraint is present.
+    pub(crate) fn capture_baseline_groups(&mut self) {
+        if self.baseline_move_limits.is_empty() {
+            return;
+        }
+
+        let people_count = self.person_idx_to_id.len();
+        self.baseline_groups = (0..self.num_sessions as usize)
+            .map(|day| {
+                (0..people_count)
+                    .map(|person_idx| {
+                        self.person_participation[person_idx][day]
+                            .then(|| self.locations[day][person_idx].0)
+                    })
+                    .collect()
+            })
+            .collect();
+    }
+
+    pub(crate) fn recalculate_baseline_moved_counts(&mut self) {
+        for count in &mut self.baseline_moved_counts {
+            *count = 0;
+        }
+        if self.baseline_groups.is_empty() {
+            return;
+        }
+
+        for (day, baseline) in self.baseline_groups.iter().enumerate() {
+            self.baseline_moved_counts[day] = baseline
+                .iter()
+                .enumerate()
+                .filter(|&(person_idx, baseline_group)| {
+                    baseline_group.is_some_and(|group| self.locations[day][person_idx].0 != group)
+                })
+                .count() as u32;
+        }
+    }
+
+    /// Returns how the moved-people count of `day` changes if each `(person, new_group)` is applied.
+    pub(crate) fn baseline_move_delta(&self, day: usize, moves: &[(usize, usize)]) -> i32 {
+        if self.baseline_groups.is_empty() {
+            return 0;
+        }
+
+        moves
+            .iter()
+            .map(|&(person_idx, new_group)| {
+                let Some(baseline_group) = self.baseline_groups[day][person_idx] else {
+                    return 0;
+                };
+                let was_moved = self.locations[day][person_idx].0 != baseline_group;
+                let will_be_moved = new_group != baseline_group;
+                will_be_moved as i32 - was_moved as i32
+            })
+            .sum()
+    }
+
+    /// Checks whether applying `moves` in `day` keeps every covering move limit satisfied.
+    pub(crate) fn baseline_move_limits_allow(&self, day: usize, moves: &[(usize, usize)]) -> bool {
+        if self.baseline_move_limits.is_empty() {
+            return true;
+        }
+
+        let delta = self.baseline_move_delta(day, moves);
+        if delta <= 0 {
+            return true;
+        }
+
+        self.baseline_move_limits.iter().all(|limit| {
+            if limit.sessions.binary_search(&day).is_err() {
+                return true;
+            }
+            let used = self.baseline_moves_used(limit, day);
+            used as i64 + delta as i64 <= limit.max_moves as i64
+        })
+    }
+
+    pub(crate) fn record_baseline_move_delta(&mut self, day: usize, delta: i32) {
+        if delta != 0 {
+            let count = &mut self.baseline_moved_counts[day];
+            *count = (*count as i32 + delta) as u32;
+        }
+    }
+
+    pub(crate) fn baseline_moves_used(&self, limit: &ResolvedBaselineMoveLimit, day: usize) -> u32 {
+        match limit.scope {
+            BaselineMoveLimitScope::Total => limit
+                .sessions
+                .iter()
+                .map(|&session| self.baseline_moved_counts[session])
+                .sum(),
+            BaselineMoveLimitScope::PerSession => self.baseline_moved_counts[day],
+        }
+    }
+}
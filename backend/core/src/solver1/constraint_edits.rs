@@ -0,0 +1,320 @@
+//! Adding and removing soft pair constraints on a built state.
+//!
+//! Interactive front ends tweak constraints between short solves. Soft pair constraints only
+//! change how a schedule is scored, so they can be edited in place: the pair tables are
+//! updated, the edited pairs are counted against the current schedule, and the cost is
+//! refreshed from the cached violation counts. The contact matrix is left untouched.
+
+use super::State;
+use crate::models::{Constraint, PairMeetingCountParams};
+use crate::solver_support::SolverError;
+
+impl State {
+    /// Adds `constraint` and rescores the current schedule.
+    ///
+    /// Supports `ShouldNotBeTogether`, `ShouldStayTogether`, and `PairMeetingCount`. Other
+    /// kinds change which schedules are feasible or how they are constructed; build a new
+    /// state with [`State::new`] for them. On error the state is unchanged.
+    pub fn add_constraint(&mut self, constraint: &Constraint) -> Result<(), SolverError> {
+        match constraint {
+            Constraint::ShouldNotBeTogether {
+                people,
+                penalty_weight,
+                sessions,
+            } => {
+                let first = self.soft_apart_pairs.len();
+                let resolved = self
+                    .resolve_should_not_be_together(people, *penalty_weight, sessions)
+                    .and_then(|()| self.check_soft_apart_against_together(first));
+                if let Err(error) = resolved {
+                    self.soft_apart_pairs.truncate(first);
+                    self.soft_apart_pair_weights.truncate(first);
+                    self.soft_apart_pair_sessions.truncate(first);
+                    return Err(error);
+                }
+                for pair_idx in first..self.soft_apart_pairs.len() {
+                    let violations = self.soft_apart_pair_violation_count(pair_idx);
+                    self.soft_apart_pair_violations.push(violations);
+                }
+                let source = self.constraint_sources.next_index;
+                self.constraint_sources
+                    .soft_apart_pairs
+                    .resize(self.soft_apart_pairs.len(), source);
+            }
+            Constraint::ShouldStayTogether {
+                people,
+                penalty_weight,
+                sessions,
+            } => {
+                let first = self.should_together_pairs.len();
+                if let Err(error) =
+                    self.resolve_should_stay_together(people, *penalty_weight, sessions)
+                {
+                    self.should_together_pairs.truncate(first);
+                    self.should_together_weights.truncate(first);
+                    self.should_together_sessions.truncate(first);
+                    return Err(error);
+                }
+                for pair_idx in first..self.should_together_pairs.len() {
+                    let violations = self.should_together_violation_count(pair_idx);
+                    self.should_together_violations.push(violations);
+                }
+                let source = self.constraint_sources.next_index;
+                self.constraint_sources
+                    .should_together_pairs
+                    .resize(self.should_together_pairs.len(), source);
+            }
+            Constraint::PairMeetingCount(params) => {
+                self.resolve_pair_meeting_count(params)?;
+                let pair_idx = self.pairmin_pairs.len() - 1;
+                self.pairmin_counts[pair_idx] = self.pair_meeting_count(pair_idx);
+                self.constraint_sources
+                    .pair_meetings
+                    .push(self.constraint_sources.next_index);
+            }
+            _ => return Err(unsupported_edit(constraint)),
+        }
+
+        self.constraint_sources.next_index += 1;
+        self.refresh_constraint_scores("State::add_constraint");
+        Ok(())
+    }
+
+    /// Removes a constraint previously added with the same value and rescores the schedule.
+    ///
+    /// The constraint is matched by its people, weight, and sessions, so it must equal the
+    /// one passed to [`State::new`] or [`State::add_constraint`]. Supports the same kinds as
+    /// [`State::add_constraint`]. On error the state is unchanged.
+    pub fn remove_constraint(&mut self, constraint: &Constraint) -> Result<(), SolverError> {
+        match constraint {
+            Constraint::ShouldNotBeTogether {
+                people,
+                penalty_weight,
+                sessions,
+            } => {
+                let sessions = self.constraint_sessions(sessions, "ShouldNotBeTogether")?;
+                let mut indices = Vec::new();
+                for (left, right) in self.constraint_pairs(people, "ShouldNotBeTogether")? {
+                    let pair_idx = (0..self.soft_apart_pairs.len())
+                        .find(|pair_idx| {
+                            !indices.contains(pair_idx)
+                                && self.soft_apart_pairs[*pair_idx] == (left, right)
+                                && self.soft_apart_pair_weights[*pair_idx] == *penalty_weight
+                                && self.soft_apart_pair_sessions[*pair_idx] == sessions
+                        })
+                        .ok_or_else(|| constraint_not_found(constraint))?;
+                    indices.push(pair_idx);
+                }
+                indices.sort_unstable_by(|a, b| b.cmp(a));
+                for pair_idx in indices {
+                    self.soft_apart_pairs.remove(pair_idx);
+                    self.soft_apart_pair_weights.remove(pair_idx);
+                    self.soft_apart_pair_sessions.remove(pair_idx);
+                    self.soft_apart_pair_violations.remove(pair_idx);
+                    self.constraint_sources.soft_apart_pairs.remove(pair_idx);
+                }
+            }
+            Constraint::ShouldStayTogether {
+                people,
+                penalty_weight,
+                sessions,
+            } => {
+                let sessions = self.constraint_sessions(sessions, "ShouldStayTogether")?;
+                let mut indices = Vec::new();
+                for (left, right) in self.constraint_pairs(people, "ShouldStayTogether")? {
+                    let pair_idx = (0..self.should_together_pairs.len())
+                        .find(|pair_idx| {
+                            !indices.contains(pair_idx)
+                                && self.should_together_pairs[*pair_idx] == (left, right)
+                                && self.should_together_weights[*pair_idx] == *penalty_weight
+                                && self.should_together_sessions[*pair_idx] == sessions
+                        })
+                        .ok_or_else(|| constraint_not_found(constraint))?;
+                    indices.push(pair_idx);
+                }
+                indices.sort_unstable_by(|a, b| b.cmp(a));
+                for pair_idx in indices {
+                    self.should_together_pairs.remove(pair_idx);
+                    self.should_together_weights.remove(pair_idx);
+                    self.should_together_sessions.remove(pair_idx);
+                    self.should_together_violations.remove(pair_idx);
+                    self.constraint_sources
+                        .should_together_pairs
+                        .remove(pair_idx);
+                }
+            }
+            Constraint::PairMeetingCount(params) => {
+                let pair_idx = self
+                    .find_pair_meeting_count(params)?
+                    .ok_or_else(|| constraint_not_found(constraint))?;
+                self.pairmin_pairs.remove(pair_idx);
+                self.pairmin_sessions.remove(pair_idx);
+                self.pairmin_required.remove(pair_idx);
+                self.pairmin_weights.remove(pair_idx);
+                self.pairmin_counts.remove(pair_idx);
+                self.pairmin_modes.remove(pair_idx);
+                self.constraint_sources.pair_meetings.remove(pair_idx);
+            }
+            _ => return Err(unsupported_edit(constraint)),
+        }
+
+        self.refresh_constraint_scores("State::remove_constraint");
+        Ok(())
+    }
+
+    fn refresh_constraint_scores(&mut self, _context: &str) {
+        self.build_pair_constraint_index();
+        self._update_constraint_penalty_total();
+        self.refresh_cost_from_caches();
+        #[cfg(feature = "cache-drift-assertions")]
+        self.debug_assert_no_cache_drift_if_enabled(_context);
+    }
+
+    /// Rejects soft-apart pairs from `first` on that overlap a should-together pair.
+    fn check_soft_apart_against_together(&self, first: usize) -> Result<(), SolverError> {
+        for pair_idx in first..self.soft_apart_pairs.len() {
+            let (left, right) = self.soft_apart_pairs[pair_idx];
+            let pair = Self::canonical_pair(left, right);
+            let conflict =
+                self.should_together_pairs
+                    .iter()
+                    .enumerate()
+                    .any(|(together_idx, &(a, b))| {
+                        Self::canonical_pair(a, b) == pair
+                            && Self::sessions_overlap(
+                                self.should_together_sessions[together_idx].as_deref(),
+                                self.soft_apart_pair_sessions[pair_idx].as_deref(),
+                            )
+                    });
+            if conflict {
+                return Err(SolverError::ConflictingConstraints {
+                    constraint: "ShouldNotBeTogether".to_string(),
+                    conflicts_with: "ShouldStayTogether".to_string(),
+                    people: vec![
+                        self.person_idx_to_id[left].clone(),
+                        self.person_idx_to_id[right].clone(),
+                    ],
+                    session: None,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolves the pairs of a pair constraint in the order its resolver stores them.
+    fn constraint_pairs(
+        &self,
+        people: &[String],
+        label: &str,
+    ) -> Result<Vec<(usize, usize)>, SolverError> {
+        let indices = people
+            .iter()
+            .map(|person_id| {
+                self.person_id_to_idx
+                    .get(person_id)
+                    .copied()
+                    .ok_or_else(|| SolverError::UnknownPersonId {
+                        person_id: person_id.clone(),
+                        referenced_by: label.to_string(),
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut pairs = Vec::new();
+        for (i, &left) in indices.iter().enumerate() {
+            for &right in &indices[i + 1..] {
+                pairs.push((left, right));
+            }
+        }
+        Ok(pairs)
+    }
+
+    fn constraint_sessions(
+        &self,
+        sessions: &Option<Vec<u32>>,
+        label: &str,
+    ) -> Result<Option<Vec<usize>>, SolverError> {
+        Self::normalize_constraint_sessions(sessions, self.num_sessions as usize, label)
+    }
+
+    fn find_pair_meeting_count(
+        &self,
+        params: &PairMeetingCountParams,
+    ) -> Result<Option<usize>, SolverError> {
+        let pairs = self.constraint_pairs(&params.people, "PairMeetingCount")?;
+        let [pair] = pairs.as_slice() else {
+            return Ok(None);
+        };
+        let mut sessions: Vec<usize> = if params.sessions.is_empty() {
+            (0..self.num_sessions as usize).collect()
+        } else {
+            params
+                .sessions
+                .iter()
+                .map(|&session| session as usize)
+                .collect()
+        };
+        sessions.sort_unstable();
+        sessions.dedup();
+
+        Ok((0..self.pairmin_pairs.len()).find(|&pair_idx| {
+            self.pairmin_pairs[pair_idx] == *pair
+                && self.pairmin_sessions[pair_idx] == sessions
+                && self.pairmin_required[pair_idx] == params.target_meetings
+                && self.pairmin_weights[pair_idx] == params.penalty_weight
+                && self.pairmin_modes[pair_idx] == params.mode
+        }))
+    }
+
+    /// Whether both people of a pair attend `session` and share a group in it.
+    fn pair_meets(&self, (left, right): (usize, usize), session: usize) -> Option<bool> {
+        if !self.person_participation[left][session] || !self.person_participation[right][session] {
+            return None;
+        }
+        Some(self.locations[session][left].0 == self.locations[session][right].0)
+    }
+
+    fn sessions_of(&self, sessions: &Option<Vec<usize>>) -> Vec<usize> {
+        match sessions {
+            Some(sessions) => sessions.clone(),
+            None => (0..self.num_sessions as usize).collect(),
+        }
+    }
+
+    fn soft_apart_pair_violation_count(&self, pair_idx: usize) -> i32 {
+        let pair = self.soft_apart_pairs[pair_idx];
+        self.sessions_of(&self.soft_apart_pair_sessions[pair_idx])
+            .into_iter()
+            .filter(|&session| self.pair_meets(pair, session) == Some(true))
+            .count() as i32
+    }
+
+    fn should_together_violation_count(&self, pair_idx: usize) -> i32 {
+        let pair = self.should_together_pairs[pair_idx];
+        self.sessions_of(&self.should_together_sessions[pair_idx])
+            .into_iter()
+            .filter(|&session| self.pair_meets(pair, session) == Some(false))
+            .count() as i32
+    }
+
+    fn pair_meeting_count(&self, pair_idx: usize) -> u32 {
+        let pair = self.pairmin_pairs[pair_idx];
+        self.pairmin_sessions[pair_idx]
+            .iter()
+            .filter(|&&session| self.pair_meets(pair, session) == Some(true))
+            .count() as u32
+    }
+}
+
+fn unsupported_edit(constraint: &Constraint) -> SolverError {
+    SolverError::ValidationError(format!(
+        "{} constraints cannot be edited on an existing state; rebuild it with State::new",
+        constraint.type_name()
+    ))
+}
+
+fn constraint_not_found(constraint: &Constraint) -> SolverError {
+    SolverError::ValidationError(format!(
+        "{} constraint to remove does not match any constraint of this state",
+        constraint.type_name()
+    ))
+}
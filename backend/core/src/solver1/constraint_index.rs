@@ -1,4 +1,4 @@
-use crate::models::AttributeBalanceMode;
+use crate::models::{AttributeBalanceMode, BaselineMoveLimitScope};
 
 #[derive(Debug, Clone)]
 pub(crate) struct ResolvedAttributeBalanceConstraint {
@@ -8,6 +8,62 @@ pub(crate) struct ResolvedAttributeBalanceConstraint {
     pub(crate) mode: AttributeBalanceMode,
 }
 
+#[derive(Debug, Clone)]
+pub(crate) struct ResolvedBaselineMoveLimit {
+    pub(crate) sessions: Vec<usize>,
+    pub(crate) max_moves: u32,
+    pub(crate) scope: BaselineMoveLimitScope,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct ResolvedUniqueGroupComposition {
+    pub(crate) covered_sessions: Vec<bool>,
+    pub(crate) penalty_weight: f64,
+}
+
+/// Pair-constraint indices that involve one person, so move deltas skip unrelated pairs.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PersonPairConstraints {
+    pub(crate) soft_apart: Vec<usize>,
+    /// `(partner, soft-apart pair index)` for every entry of `soft_apart`, sorted, so the pairs
+    /// linking this person to a given partner can be found without a scan.
+    pub(crate) soft_apart_partners: Vec<(usize, usize)>,
+    pub(crate) should_together: Vec<usize>,
+    pub(crate) pair_meeting: Vec<usize>,
+}
+
+impl PersonPairConstraints {
+    /// Soft-apart pair indices linking this person to `partner`.
+    #[inline]
+    pub(crate) fn soft_apart_with(&self, partner: usize) -> impl Iterator<Item = usize> + '_ {
+        let start = self
+            .soft_apart_partners
+            .partition_point(|&(other, _)| other < partner);
+        self.soft_apart_partners[start..]
+            .iter()
+            .take_while(move |&&(other, _)| other == partner)
+            .map(|&(_, pair_idx)| pair_idx)
+    }
+}
+
+/// Yields `first`, then the indices of `second` whose pair does not include `skip`.
+///
+/// Merges the pair-constraint lists of two moved people without visiting a pair that
+/// links them twice.
+pub(crate) fn merged_pair_indices<'a>(
+    first: &'a [usize],
+    second: &'a [usize],
+    pairs: &'a [(usize, usize)],
+    skip: usize,
+) -> impl Iterator<Item = usize> + 'a {
+    first.iter().copied().chain(
+        second
+            .iter()
+            .copied()
+            .filter(move |&pair_idx| pairs[pair_idx].0 != skip && pairs[pair_idx].1 != skip),
+    )
+}
+
 #[inline]
 pub(crate) fn flat_slot(width: usize, day: usize, idx: usize) -> usize {
     day * width + idx
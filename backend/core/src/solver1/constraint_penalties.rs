@@ -0,0 +1,110 @@
+//! Weighted penalty of each input constraint, for penalty time series in telemetry.
+//!
+//! The search folds soft constraints into per-kind caches indexed by internal entries (one
+//! per pair, one per resolved balance constraint). [`ConstraintSources`] maps those entries
+//! back to the position of the input constraint they came from, so the cached penalties can
+//! be summed per input constraint without rescoring the schedule.
+
+use super::State;
+use crate::models::{ApiInput, Constraint, ConstraintPenalty};
+use std::collections::BTreeMap;
+
+/// Input constraint index behind each internal soft-constraint entry.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ConstraintSources {
+    /// Parallel to `State::soft_apart_pairs`
+    pub(crate) soft_apart_pairs: Vec<usize>,
+    /// Parallel to `State::should_together_pairs`
+    pub(crate) should_together_pairs: Vec<usize>,
+    /// Parallel to `State::pairmin_pairs`
+    pub(crate) pair_meetings: Vec<usize>,
+    /// Parallel to `State::attribute_balance_constraints`
+    pub(crate) attribute_balance: Vec<usize>,
+    pub(crate) repeat_encounter: Option<usize>,
+    pub(crate) unique_group_composition: Option<usize>,
+    /// Index given to the next constraint added with `State::add_constraint`
+    pub(crate) next_index: usize,
+}
+
+impl ConstraintSources {
+    /// Sources of the constraints resolved once per input; pair entries are filled in as
+    /// their constraints are resolved.
+    pub(crate) fn from_input(input: &ApiInput) -> Self {
+        let position =
+            |matches: fn(&Constraint) -> bool| input.constraints.iter().position(matches);
+        Self {
+            attribute_balance: input
+                .constraints
+                .iter()
+                .enumerate()
+                .filter(|(_, constraint)| matches!(constraint, Constraint::AttributeBalance(_)))
+                .map(|(constraint_idx, _)| constraint_idx)
+                .collect(),
+            repeat_encounter: position(|c| matches!(c, Constraint::RepeatEncounter(_))),
+            unique_group_composition: position(|c| {
+                matches!(c, Constraint::UniqueGroupComposition(_))
+            }),
+            next_index: input.constraints.len(),
+            ..Self::default()
+        }
+    }
+}
+
+impl State {
+    /// Weighted penalty of every soft constraint in the current schedule, by input index.
+    ///
+    /// Every soft constraint gets an entry, including those with no penalty. Hard constraints
+    /// and immovable placements (scored as a flat penalty) are left out.
+    pub fn constraint_penalties(&self) -> Vec<ConstraintPenalty> {
+        let sources = &self.constraint_sources;
+        let mut penalties: BTreeMap<usize, f64> = BTreeMap::new();
+
+        for (pair_idx, &source) in sources.soft_apart_pairs.iter().enumerate() {
+            *penalties.entry(source).or_default() += self.soft_apart_pair_violations[pair_idx]
+                as f64
+                * self.soft_apart_pair_weights[pair_idx];
+        }
+        for (pair_idx, &source) in sources.should_together_pairs.iter().enumerate() {
+            *penalties.entry(source).or_default() += self.should_together_violations[pair_idx]
+                as f64
+                * self.should_together_weights[pair_idx];
+        }
+        for (pair_idx, &source) in sources.pair_meetings.iter().enumerate() {
+            *penalties.entry(source).or_default() +=
+                self.pairmin_penalty(pair_idx, self.pairmin_counts[pair_idx]);
+        }
+
+        let mut attribute_balance = vec![0.0; self.resolved_attribute_balance_constraints.len()];
+        for (day, day_schedule) in self.schedule.iter().enumerate() {
+            for (group_idx, members) in day_schedule.iter().enumerate() {
+                for &constraint_idx in
+                    self.attribute_balance_constraint_indices_for_group_session(day, group_idx)
+                {
+                    attribute_balance[constraint_idx] += self
+                        .calculate_group_attribute_penalty_for_constraint_members(
+                            members,
+                            constraint_idx,
+                        );
+                }
+            }
+        }
+        for (&source, penalty) in sources.attribute_balance.iter().zip(attribute_balance) {
+            *penalties.entry(source).or_default() += penalty;
+        }
+
+        if let Some(source) = sources.repeat_encounter {
+            penalties.insert(source, self.weighted_repetition_penalty());
+        }
+        if let Some(source) = sources.unique_group_composition {
+            penalties.insert(source, self.weighted_group_composition_penalty());
+        }
+
+        penalties
+            .into_iter()
+            .map(|(constraint_idx, weighted_penalty)| ConstraintPenalty {
+                constraint_index: constraint_idx as u32,
+                weighted_penalty,
+            })
+            .collect()
+    }
+}
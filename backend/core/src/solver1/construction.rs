@@ -4,16 +4,20 @@
 //! preprocessing logic that converts API input into the internal solver state.
 
 use super::{
-    constraint_index::{flat_slot, ResolvedAttributeBalanceConstraint},
-    Dsu, RepeatPenaltyFunction, SolverError, State,
+    constraint_index::{flat_slot, PersonPairConstraints, ResolvedAttributeBalanceConstraint},
+    constraint_penalties::ConstraintSources,
+    group_fill::BALANCE_GROUP_SIZES_OBJECTIVE,
+    group_members_schedule, ContactMatrix, Dsu, GroupMembers, RepeatPenaltyFunction, SolverError,
+    State,
 };
-use crate::models::{ApiInput, Constraint, PairMeetingMode};
+use crate::models::{ApiInput, Constraint, PairMeetingCountParams, PairMeetingMode};
 use crate::solver_support::construction::{
     apply_baseline_construction_heuristic, apply_construction_seed_schedule,
     BaselineConstructionContext,
 };
+use crate::solver_support::memory_estimate::check_memory_limit;
 use crate::solver_support::validation::{
-    validate_schedule_as_incumbent, validate_schedule_input_mode,
+    validate_available_sessions, validate_schedule_as_incumbent, validate_schedule_input_mode,
 };
 use rand::{rng, RngExt};
 use std::collections::{HashMap, HashSet};
@@ -44,7 +48,7 @@ impl State {
     /// - Per-session group capacity is insufficient for participating people
     /// - Person or group IDs are not unique
     /// - Referenced IDs in constraints don't exist
-    /// - Cliques are too large to fit in any group
+    /// - Cliques are too large to fit in any group ([`SolverError::CliqueTooLarge`])
     /// - Contradictory constraints are specified
     ///
     /// # Performance Notes
@@ -65,6 +69,7 @@ impl State {
     /// let input = ApiInput {
     ///     initial_schedule: None,
     ///     construction_seed_schedule: None,
+    ///     contact_history: None,
     ///     problem: ProblemDefinition {
     ///         people: vec![
     ///             Person {
@@ -83,6 +88,8 @@ impl State {
     ///                 id: "Team1".to_string(),
     ///                 size: 2,
     ///                 session_sizes: None,
+    ///                 attributes: HashMap::new(),
+    ///                 available_sessions: None,
     ///             }
     ///         ],
     ///         num_sessions: 2,
@@ -96,6 +103,9 @@ impl State {
     ///             time_limit_seconds: None,
     ///             no_improvement_iterations: None,
     ///             stop_on_optimal_score: true,
+    ///             stop_on_feasible: false,
+    ///             feasible_score_threshold: None,
+    ///             convergence: None,
     ///         },
     ///         solver_params: SolverParams::SimulatedAnnealing(
     ///             SimulatedAnnealingParams {
@@ -104,6 +114,7 @@ impl State {
     ///                 cooling_schedule: "geometric".to_string(),
     ///                 reheat_after_no_improvement: Some(0),
     ///                 reheat_cycles: Some(0),
+    ///                 penalty_ramp: None,
     ///             }
     ///         ),
     ///         logging: LoggingOptions::default(),
@@ -125,7 +136,54 @@ impl State {
     /// }
     /// ```
     pub fn new(input: &ApiInput) -> Result<Self, SolverError> {
+        let _setup = tracing::info_span!("setup").entered();
+        let mut state = Self::preprocess(input)?;
+
+        if let Some(initial_schedule) = &input.initial_schedule {
+            state.schedule = group_members_schedule(
+                validate_schedule_as_incumbent(input, initial_schedule)?.schedule,
+            );
+        } else {
+            let mut schedule =
+                vec![vec![Vec::new(); state.group_idx_to_id.len()]; state.schedule.len()];
+            let mut construction_context = BaselineConstructionContext {
+                effective_seed: state.effective_seed,
+                group_idx_to_id: &state.group_idx_to_id,
+                person_idx_to_id: &state.person_idx_to_id,
+                effective_group_capacities: &state.effective_group_capacities,
+                person_participation: &state.person_participation,
+                immovable_people: &state.immovable_people,
+                cliques: &state.cliques,
+                clique_sessions: &state.clique_sessions,
+                hard_apart_partners_by_person_session: &state.hard_apart_partners_by_person_session,
+                allowed_groups_by_person_session: &state.allowed_groups_by_person_session,
+                schedule: &mut schedule,
+            };
+            apply_construction_seed_schedule(&mut construction_context, input)?;
+            apply_baseline_construction_heuristic(&mut construction_context)?;
+            state.schedule = group_members_schedule(schedule);
+        }
+
+        state._recalculate_locations_from_schedule();
+        state.validate_track_grouping()?;
+        state.validate_group_attribute_requirements()?;
+        state.capture_baseline_groups();
+        state._recalculate_scores();
+        #[cfg(feature = "debug-invariant-checks")]
+        state.debug_validate_hard_constraints_if_enabled("State::new");
+        #[cfg(feature = "cache-drift-assertions")]
+        state.debug_assert_no_cache_drift_if_enabled("State::new");
+
+        Ok(state)
+    }
+
+    /// Validates `input` and builds the solver state without a schedule.
+    ///
+    /// Everything [`State::new`] checks before constructing or loading a schedule is checked
+    /// here; every group of the returned state's `schedule` is empty.
+    pub(crate) fn preprocess(input: &ApiInput) -> Result<Self, SolverError> {
         validate_schedule_input_mode(input)?;
+        check_memory_limit(input)?;
         // --- Pre-validation ---
         let people_count = input.problem.people.len();
         let group_count = input.problem.groups.len();
@@ -146,10 +204,11 @@ impl State {
                 .count();
             let session_capacity = session_total_capacities[session_idx];
             if people_in_session > session_capacity {
-                return Err(SolverError::ValidationError(format!(
-                    "Not enough group capacity in session {}. People: {}, Capacity: {}",
-                    session_idx, people_in_session, session_capacity
-                )));
+                return Err(SolverError::InsufficientCapacity {
+                    session: session_idx,
+                    people: people_in_session,
+                    capacity: session_capacity,
+                });
             }
         }
 
@@ -230,6 +289,16 @@ impl State {
         let group_idx_to_id: Vec<String> =
             input.problem.groups.iter().map(|g| g.id.clone()).collect();
 
+        let contact_history = Self::resolve_contact_history(input, &person_id_to_idx)?;
+        let max_historical_contacts = contact_history
+            .contacts
+            .iter()
+            .map(|&(_, _, count)| count)
+            .max()
+            .unwrap_or(0);
+        let contact_weights = Self::resolve_contact_weights(input, &person_id_to_idx)?;
+        let session_weights = Self::resolve_session_weights(input)?;
+
         // --- Build Attribute Mappings ---
         let mut attr_key_to_idx = HashMap::new();
         let mut attr_val_to_idx: Vec<HashMap<String, usize>> = Vec::new();
@@ -302,6 +371,11 @@ impl State {
         {
             w_contacts = objective.weight;
         }
+        let w_group_fill = input
+            .objectives
+            .iter()
+            .find(|o| o.r#type == BALANCE_GROUP_SIZES_OBJECTIVE)
+            .map_or(0.0, |objective| objective.weight);
 
         let repeat_constraints: Vec<_> = input
             .constraints
@@ -327,7 +401,7 @@ impl State {
                 .map_err(SolverError::ValidationError)?;
         }
 
-        let schedule = vec![vec![vec![]; group_count]; num_sessions];
+        let schedule = vec![vec![GroupMembers::new(); group_count]; num_sessions];
         let locations = vec![vec![(0, 0); people_count]; num_sessions];
 
         // Calculate baseline score to prevent negative scores from unique contacts metric
@@ -344,7 +418,13 @@ impl State {
         } else {
             0
         };
-        let baseline_score = max_possible_unique_contacts as f64 * w_contacts;
+        // Pairs that already met in `contact_history` stay counted as unique contacts
+        // regardless of the schedule, so they are offset here as well.
+        let baseline_score = ((max_possible_unique_contacts + contact_history.contacts.len())
+            as f64
+            + Self::max_vip_contact_bonus(&contact_weights))
+            * w_contacts
+            * Self::max_session_weight(&session_weights);
 
         let mut state = Self {
             person_id_to_idx,
@@ -376,7 +456,21 @@ impl State {
             soft_apart_pairs: vec![], // To be populated
             hard_apart_pairs: vec![], // To be populated
             should_together_pairs: vec![], // To be populated
+            pair_constraints_by_person: Vec::new(),
             immovable_people: HashMap::new(), // To be populated
+            baseline_move_limits: Vec::new(), // To be populated
+            baseline_groups: Vec::new(),      // Captured once the incumbent schedule is loaded
+            baseline_moved_counts: vec![0; num_sessions],
+            allowed_groups_by_person_session: Vec::new(),
+            track_names: Vec::new(),
+            track_of_group: Vec::new(),
+            person_tracks: Vec::new(),
+            track_candidates: Vec::new(),
+            untracked_allowed_groups: Vec::new(),
+            track_swap_pools: Vec::new(),
+            unique_group_composition: None,
+            group_composition_counts: HashMap::new(),
+            repeated_group_compositions: 0,
             clique_sessions: vec![], // To be populated by preprocessing
             soft_apart_pair_sessions: vec![], // To be populated by preprocessing
             hard_apart_pair_sessions: vec![], // To be populated by preprocessing
@@ -385,10 +479,23 @@ impl State {
             person_participation,
             num_sessions: input.problem.num_sessions,
             allowed_sessions,
-            contact_matrix: vec![vec![0; people_count]; people_count],
+            contact_matrix: ContactMatrix::new(
+                people_count,
+                input
+                    .problem
+                    .num_sessions
+                    .saturating_add(max_historical_contacts),
+            ),
+            historical_contacts: contact_history.contacts,
+            historical_fractions_by_person: contact_history.fractions_by_person,
+            history_decay_correction: 0.0,
             unique_contacts: 0,
+            vip_contact_bonus: 0.0,
+            session_weighted_contacts: Vec::new(),
+            session_weight_correction: 0.0,
             repetition_penalty: 0,
             attribute_balance_penalty: 0.0,
+            group_fill_penalty: 0.0,
             constraint_penalty: 0,
             weighted_constraint_penalty: 0.0,
             clique_violations: Vec::new(), // Will be resized after constraint preprocessing
@@ -397,6 +504,9 @@ impl State {
             should_together_violations: Vec::new(), // Will be resized after constraint preprocessing
             immovable_violations: 0,
             w_contacts,
+            contact_weights,
+            session_weights,
+            w_group_fill,
             w_repetition,
             repeat_encounter_limit,
             repeat_penalty_function,
@@ -409,38 +519,18 @@ impl State {
             pairmin_weights: Vec::new(),
             pairmin_counts: Vec::new(),
             pairmin_modes: Vec::new(),
+            constraint_sources: ConstraintSources::from_input(input),
             baseline_score,
             current_cost: 0.0,
         };
 
         state._preprocess_and_validate_constraints(input)?;
         state.build_attribute_balance_constraint_indexes()?;
-
-        if let Some(initial_schedule) = &input.initial_schedule {
-            state.schedule = validate_schedule_as_incumbent(input, initial_schedule)?.schedule;
-        } else {
-            let mut construction_context = BaselineConstructionContext {
-                effective_seed: state.effective_seed,
-                group_idx_to_id: &state.group_idx_to_id,
-                person_idx_to_id: &state.person_idx_to_id,
-                effective_group_capacities: &state.effective_group_capacities,
-                person_participation: &state.person_participation,
-                immovable_people: &state.immovable_people,
-                cliques: &state.cliques,
-                clique_sessions: &state.clique_sessions,
-                hard_apart_partners_by_person_session: &state.hard_apart_partners_by_person_session,
-                schedule: &mut state.schedule,
-            };
-            apply_construction_seed_schedule(&mut construction_context, input)?;
-            apply_baseline_construction_heuristic(&mut construction_context)?;
-        }
-
-        state._recalculate_locations_from_schedule();
-        state._recalculate_scores();
-        #[cfg(feature = "debug-invariant-checks")]
-        state.debug_validate_hard_constraints_if_enabled("State::new");
-        #[cfg(feature = "cache-drift-assertions")]
-        state.debug_assert_no_cache_drift_if_enabled("State::new");
+        state.build_pair_constraint_index();
+        state.resolve_baseline_move_limits(input)?;
+        state.resolve_unique_group_composition(input)?;
+        state.resolve_group_attribute_requirements(input)?;
+        state.resolve_track_grouping(input)?;
 
         Ok(state)
     }
@@ -457,12 +547,11 @@ impl State {
                     if session_idx < num_sessions {
                         person_participation[person_idx][session_idx] = true;
                     } else {
-                        return Err(SolverError::ValidationError(format!(
-                            "Person '{}' has invalid session index: {} (max: {})",
-                            person.id,
-                            session,
-                            num_sessions.saturating_sub(1)
-                        )));
+                        return Err(SolverError::InvalidSession {
+                            session: session_idx,
+                            num_sessions,
+                            referenced_by: format!("person '{}'", person.id),
+                        });
                     }
                 }
             } else {
@@ -493,6 +582,7 @@ impl State {
         let mut session_total_capacities = vec![0; num_sessions];
         let mut session_max_group_capacities = vec![0; num_sessions];
 
+        validate_available_sessions(input)?;
         for (group_idx, group) in input.problem.groups.iter().enumerate() {
             if let Some(session_sizes) = &group.session_sizes {
                 if session_sizes.len() != num_sessions {
@@ -506,11 +596,7 @@ impl State {
             }
 
             for session_idx in 0..num_sessions {
-                let capacity = group
-                    .session_sizes
-                    .as_ref()
-                    .map(|sizes| sizes[session_idx] as usize)
-                    .unwrap_or(group.size as usize);
+                let capacity = group.capacity_for_session(session_idx).unwrap_or(0) as usize;
                 effective_group_capacities[session_idx * input.problem.groups.len() + group_idx] =
                     capacity;
                 session_total_capacities[session_idx] += capacity;
@@ -527,6 +613,39 @@ impl State {
         ))
     }
 
+    /// Rejects cliques with more attending members than the largest group of a session they
+    /// are active in, before placement runs into them.
+    fn validate_clique_sizes(&self) -> Result<(), SolverError> {
+        for (clique_idx, members) in self.cliques.iter().enumerate() {
+            for session_idx in 0..self.num_sessions as usize {
+                if self.clique_sessions[clique_idx]
+                    .as_ref()
+                    .is_some_and(|sessions| !sessions.contains(&session_idx))
+                {
+                    continue;
+                }
+                let attending: Vec<usize> = members
+                    .iter()
+                    .copied()
+                    .filter(|&member| self.person_participation[member][session_idx])
+                    .collect();
+                let max_group_size = self.session_max_group_capacities[session_idx];
+                if attending.len() > max_group_size {
+                    return Err(SolverError::CliqueTooLarge {
+                        people: attending
+                            .iter()
+                            .map(|&member| self.person_idx_to_id[member].clone())
+                            .collect(),
+                        size: attending.len(),
+                        max_group_size,
+                        session: session_idx,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn build_attribute_balance_constraint_indexes(&mut self) -> Result<(), SolverError> {
         let group_count = self.group_idx_to_id.len();
         let num_sessions = self.num_sessions as usize;
@@ -540,10 +659,10 @@ impl State {
                 (0..group_count).collect()
             } else {
                 vec![*self.group_id_to_idx.get(&params.group_id).ok_or_else(|| {
-                    SolverError::ValidationError(format!(
-                        "AttributeBalance references unknown group '{}'",
-                        params.group_id
-                    ))
+                    SolverError::UnknownGroupId {
+                        group_id: params.group_id.to_string(),
+                        referenced_by: "AttributeBalance".to_string(),
+                    }
                 })?]
             };
             let attr_idx = *self
@@ -581,11 +700,11 @@ impl State {
                     for &session in sessions {
                         let day = session as usize;
                         if day >= num_sessions {
-                            return Err(SolverError::ValidationError(format!(
-                                "AttributeBalance references invalid session {} (max: {})",
-                                session,
-                                num_sessions.saturating_sub(1)
-                            )));
+                            return Err(SolverError::InvalidSession {
+                                session: day,
+                                num_sessions,
+                                referenced_by: "AttributeBalance".to_string(),
+                            });
                         }
                         for &group_idx in &target_group_indices {
                             let slot = flat_slot(group_count, day, group_idx);
@@ -610,7 +729,30 @@ impl State {
     }
 
     #[inline]
-    fn canonical_pair(left: usize, right: usize) -> (usize, usize) {
+    /// Rebuilds [`State::pair_constraints_by_person`] from the current pair constraint lists.
+    pub(super) fn build_pair_constraint_index(&mut self) {
+        let mut index = vec![PersonPairConstraints::default(); self.person_idx_to_id.len()];
+        for (pair_idx, &(left, right)) in self.soft_apart_pairs.iter().enumerate() {
+            index[left].soft_apart.push(pair_idx);
+            index[right].soft_apart.push(pair_idx);
+            index[left].soft_apart_partners.push((right, pair_idx));
+            index[right].soft_apart_partners.push((left, pair_idx));
+        }
+        for person in &mut index {
+            person.soft_apart_partners.sort_unstable();
+        }
+        for (pair_idx, &(left, right)) in self.should_together_pairs.iter().enumerate() {
+            index[left].should_together.push(pair_idx);
+            index[right].should_together.push(pair_idx);
+        }
+        for (pair_idx, &(left, right)) in self.pairmin_pairs.iter().enumerate() {
+            index[left].pair_meeting.push(pair_idx);
+            index[right].pair_meeting.push(pair_idx);
+        }
+        self.pair_constraints_by_person = index;
+    }
+
+    pub(super) fn canonical_pair(left: usize, right: usize) -> (usize, usize) {
         if left < right {
             (left, right)
         } else {
@@ -618,7 +760,7 @@ impl State {
         }
     }
 
-    fn normalize_constraint_sessions(
+    pub(super) fn normalize_constraint_sessions(
         sessions: &Option<Vec<u32>>,
         num_sessions: usize,
         label: &str,
@@ -648,13 +790,244 @@ impl State {
     }
 
     #[inline]
-    fn sessions_overlap(left: Option<&[usize]>, right: Option<&[usize]>) -> bool {
+    pub(super) fn sessions_overlap(left: Option<&[usize]>, right: Option<&[usize]>) -> bool {
         match (left, right) {
             (None, _) | (_, None) => true,
             (Some(left), Some(right)) => left.iter().any(|session| right.contains(session)),
         }
     }
 
+    /// Adds the soft-apart pairs of a `ShouldNotBeTogether` constraint.
+    pub(super) fn resolve_should_not_be_together(
+        &mut self,
+        people: &[String],
+        penalty_weight: f64,
+        constraint_sessions: &Option<Vec<u32>>,
+    ) -> Result<(), SolverError> {
+        let num_sessions = self.num_sessions as usize;
+        for i in 0..people.len() {
+            for j in (i + 1)..people.len() {
+                let compiled_sessions = Self::normalize_constraint_sessions(
+                    constraint_sessions,
+                    num_sessions,
+                    "ShouldNotBeTogether",
+                )?;
+                let p1_idx = *self.person_id_to_idx.get(&people[i]).ok_or_else(|| {
+                    SolverError::UnknownPersonId {
+                        person_id: people[i].to_string(),
+                        referenced_by: "ShouldNotBeTogether".to_string(),
+                    }
+                })?;
+                let p2_idx = *self.person_id_to_idx.get(&people[j]).ok_or_else(|| {
+                    SolverError::UnknownPersonId {
+                        person_id: people[j].to_string(),
+                        referenced_by: "ShouldNotBeTogether".to_string(),
+                    }
+                })?;
+
+                // Check for conflict with cliques
+                if let (Some(c1), Some(c2)) = (
+                    self.person_to_clique_id[0][p1_idx],
+                    self.person_to_clique_id[0][p2_idx],
+                ) {
+                    if c1 == c2 {
+                        return Err(SolverError::ConflictingConstraints {
+                            constraint: "ShouldNotBeTogether".to_string(),
+                            conflicts_with: "MustStayTogether".to_string(),
+                            people: vec![people[i].clone(), people[j].clone()],
+                            session: None,
+                        });
+                    }
+                }
+
+                // Conflict check: if the two people are in the same hard clique for any session where both the clique and the ShouldNotBeTogether apply
+                for session_idx in 0..num_sessions {
+                    // Skip session if this ShouldNotBeTogether does not apply
+                    if let Some(cs) = constraint_sessions {
+                        if !cs.contains(&(session_idx as u32)) {
+                            continue;
+                        }
+                    }
+
+                    if let (Some(c1), Some(c2)) = (
+                        self.person_to_clique_id[session_idx][p1_idx],
+                        self.person_to_clique_id[session_idx][p2_idx],
+                    ) {
+                        if c1 == c2 {
+                            return Err(SolverError::ConflictingConstraints {
+                                constraint: "ShouldNotBeTogether".to_string(),
+                                conflicts_with: "MustStayTogether".to_string(),
+                                people: vec![people[i].clone(), people[j].clone()],
+                                session: Some(session_idx),
+                            });
+                        }
+                    }
+                }
+
+                self.soft_apart_pairs.push((p1_idx, p2_idx));
+                self.soft_apart_pair_weights.push(penalty_weight);
+                self.soft_apart_pair_sessions.push(compiled_sessions);
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds the should-together pairs of a `ShouldStayTogether` constraint.
+    pub(super) fn resolve_should_stay_together(
+        &mut self,
+        people: &[String],
+        penalty_weight: f64,
+        constraint_sessions: &Option<Vec<u32>>,
+    ) -> Result<(), SolverError> {
+        let num_sessions = self.num_sessions as usize;
+        for i in 0..people.len() {
+            for j in (i + 1)..people.len() {
+                let compiled_sessions = Self::normalize_constraint_sessions(
+                    constraint_sessions,
+                    num_sessions,
+                    "ShouldStayTogether",
+                )?;
+                let p1_idx = *self.person_id_to_idx.get(&people[i]).ok_or_else(|| {
+                    SolverError::UnknownPersonId {
+                        person_id: people[i].to_string(),
+                        referenced_by: "ShouldStayTogether".to_string(),
+                    }
+                })?;
+                let p2_idx = *self.person_id_to_idx.get(&people[j]).ok_or_else(|| {
+                    SolverError::UnknownPersonId {
+                        person_id: people[j].to_string(),
+                        referenced_by: "ShouldStayTogether".to_string(),
+                    }
+                })?;
+
+                let pair = Self::canonical_pair(p1_idx, p2_idx);
+
+                if self
+                    .hard_apart_pairs
+                    .iter()
+                    .enumerate()
+                    .any(|(pair_idx, &hard_pair)| {
+                        hard_pair == pair
+                            && Self::sessions_overlap(
+                                self.hard_apart_pair_sessions[pair_idx].as_deref(),
+                                compiled_sessions.as_deref(),
+                            )
+                    })
+                {
+                    return Err(SolverError::ConflictingConstraints {
+                        constraint: "ShouldStayTogether".to_string(),
+                        conflicts_with: "MustStayApart".to_string(),
+                        people: vec![people[i].clone(), people[j].clone()],
+                        session: None,
+                    });
+                }
+
+                // Conflict check with existing ShouldNotBeTogether pairs
+                if let Some((fp_idx, _)) = self
+                    .soft_apart_pairs
+                    .iter()
+                    .enumerate()
+                    .find(|(_, &(a, b))| Self::canonical_pair(a, b) == pair)
+                {
+                    if Self::sessions_overlap(
+                        self.soft_apart_pair_sessions[fp_idx].as_deref(),
+                        compiled_sessions.as_deref(),
+                    ) {
+                        return Err(SolverError::ConflictingConstraints {
+                            constraint: "ShouldStayTogether".to_string(),
+                            conflicts_with: "ShouldNotBeTogether".to_string(),
+                            people: vec![people[i].clone(), people[j].clone()],
+                            session: None,
+                        });
+                    }
+                }
+
+                // If these two are in a hard clique together anywhere applicable, it's redundant but not invalid
+                // We still allow it; scoring will naturally give zero penalty when together.
+
+                self.should_together_pairs.push((p1_idx, p2_idx));
+                self.should_together_weights.push(penalty_weight);
+                self.should_together_sessions.push(compiled_sessions);
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds the tracked pair of a `PairMeetingCount` constraint, with a meeting count of zero.
+    pub(super) fn resolve_pair_meeting_count(
+        &mut self,
+        params: &PairMeetingCountParams,
+    ) -> Result<(), SolverError> {
+        let num_sessions = self.num_sessions as usize;
+        // Validate exactly two people
+        if params.people.len() != 2 {
+            return Err(SolverError::ValidationError(
+                "PairMeetingCount requires exactly two people".to_string(),
+            ));
+        }
+        let p1_idx = *self
+            .person_id_to_idx
+            .get(&params.people[0])
+            .ok_or_else(|| SolverError::UnknownPersonId {
+                person_id: params.people[0].to_string(),
+                referenced_by: "PairMeetingCount".to_string(),
+            })?;
+        let p2_idx = *self
+            .person_id_to_idx
+            .get(&params.people[1])
+            .ok_or_else(|| SolverError::UnknownPersonId {
+                person_id: params.people[1].to_string(),
+                referenced_by: "PairMeetingCount".to_string(),
+            })?;
+        // Map and validate sessions (empty => all sessions)
+        let mut sess: Vec<usize> = if params.sessions.is_empty() {
+            (0..num_sessions).collect()
+        } else {
+            let mut tmp: Vec<usize> = Vec::with_capacity(params.sessions.len());
+            for &s in &params.sessions {
+                let si = s as usize;
+                if si >= num_sessions {
+                    return Err(SolverError::ValidationError(format!(
+                        "PairMeetingCount references invalid session {}",
+                        s
+                    )));
+                }
+                tmp.push(si);
+            }
+            tmp
+        };
+        sess.sort_unstable();
+        sess.dedup();
+        let n = sess.len() as u32;
+        if params.target_meetings > n {
+            return Err(SolverError::ValidationError(format!(
+                "PairMeetingCount target_meetings={} exceeds number of sessions in subset {}",
+                params.target_meetings, n
+            )));
+        }
+        // Feasibility: both must co-participate in at least min_meetings among subset
+        let feasible_sessions = sess
+            .iter()
+            .filter(|&&s| {
+                self.person_participation[p1_idx][s] && self.person_participation[p2_idx][s]
+            })
+            .count() as u32;
+        if params.mode == PairMeetingMode::AtLeast && params.target_meetings > feasible_sessions {
+            return Err(SolverError::ValidationError(format!(
+                "PairMeetingCount target_meetings={} exceeds feasible co-participation {} for the pair",
+                params.target_meetings, feasible_sessions
+            )));
+        }
+
+        self.pairmin_pairs.push((p1_idx, p2_idx));
+        self.pairmin_sessions.push(sess);
+        self.pairmin_required.push(params.target_meetings);
+        self.pairmin_weights.push(params.penalty_weight);
+        self.pairmin_counts.push(0);
+        self.pairmin_modes.push(params.mode);
+        Ok(())
+    }
+
     fn _preprocess_and_validate_constraints(
         &mut self,
         input: &ApiInput,
@@ -770,6 +1143,7 @@ impl State {
                 None => None,
             })
             .collect();
+        self.validate_clique_sizes()?;
 
         // --- Process `ShouldNotBeTogether` (Soft-Apart Pairs) ---
         self.hard_apart_pairs.clear();
@@ -784,17 +1158,17 @@ impl State {
                 for i in 0..people.len() {
                     for j in (i + 1)..people.len() {
                         let left_idx = *self.person_id_to_idx.get(&people[i]).ok_or_else(|| {
-                            SolverError::ValidationError(format!(
-                                "MustStayApart references unknown person '{}'",
-                                people[i]
-                            ))
+                            SolverError::UnknownPersonId {
+                                person_id: people[i].to_string(),
+                                referenced_by: "MustStayApart".to_string(),
+                            }
                         })?;
                         let right_idx =
                             *self.person_id_to_idx.get(&people[j]).ok_or_else(|| {
-                                SolverError::ValidationError(format!(
-                                    "MustStayApart references unknown person '{}'",
-                                    people[j]
-                                ))
+                                SolverError::UnknownPersonId {
+                                    person_id: people[j].to_string(),
+                                    referenced_by: "MustStayApart".to_string(),
+                                }
                             })?;
 
                         for session_idx in 0..num_sessions {
@@ -809,14 +1183,12 @@ impl State {
                                 self.person_to_clique_id[session_idx][right_idx],
                             ) {
                                 if left_clique == right_clique {
-                                    let clique_member_ids: Vec<String> = self.cliques[left_clique]
-                                        .iter()
-                                        .map(|&idx| self.display_person_by_idx(idx))
-                                        .collect();
-                                    return Err(SolverError::ValidationError(format!(
-                                        "MustStayApart conflicts with MustStayTogether in session {}: people {:?} share clique {:?}",
-                                        session_idx, people, clique_member_ids
-                                    )));
+                                    return Err(SolverError::ConflictingConstraints {
+                                        constraint: "MustStayApart".to_string(),
+                                        conflicts_with: "MustStayTogether".to_string(),
+                                        people: vec![people[i].clone(), people[j].clone()],
+                                        session: Some(session_idx),
+                                    });
                                 }
                             }
                         }
@@ -859,156 +1231,32 @@ impl State {
             partners.dedup();
         }
 
-        for constraint in &input.constraints {
+        for (constraint_idx, constraint) in input.constraints.iter().enumerate() {
             if let Constraint::ShouldNotBeTogether {
                 people,
                 penalty_weight,
-                sessions: constraint_sessions,
+                sessions,
             } = constraint
             {
-                for i in 0..people.len() {
-                    for j in (i + 1)..people.len() {
-                        let compiled_sessions = Self::normalize_constraint_sessions(
-                            constraint_sessions,
-                            num_sessions,
-                            "ShouldNotBeTogether",
-                        )?;
-                        let p1_idx = *self.person_id_to_idx.get(&people[i]).ok_or_else(|| {
-                            SolverError::ValidationError(format!(
-                                "ShouldNotBeTogether references unknown person {}",
-                                self.display_person_id(&people[i])
-                            ))
-                        })?;
-                        let p2_idx = *self.person_id_to_idx.get(&people[j]).ok_or_else(|| {
-                            SolverError::ValidationError(format!(
-                                "ShouldNotBeTogether references unknown person {}",
-                                self.display_person_id(&people[j])
-                            ))
-                        })?;
-
-                        // Check for conflict with cliques
-                        if let (Some(c1), Some(c2)) = (
-                            self.person_to_clique_id[0][p1_idx],
-                            self.person_to_clique_id[0][p2_idx],
-                        ) {
-                            if c1 == c2 {
-                                let clique_member_ids: Vec<String> = self.cliques[c1]
-                                    .iter()
-                                    .map(|&idx| self.display_person_by_idx(idx))
-                                    .collect();
-                                return Err(SolverError::ValidationError(format!(
-                                    "ShouldNotBeTogether constraint conflicts with MustStayTogether: people {:?} are in the same clique {:?}",
-                                    people, clique_member_ids
-                                )));
-                            }
-                        }
-
-                        // Conflict check: if the two people are in the same hard clique for any session where both the clique and the ShouldNotBeTogether apply
-                        for session_idx in 0..num_sessions {
-                            // Skip session if this ShouldNotBeTogether does not apply
-                            if let Some(cs) = constraint_sessions {
-                                if !cs.contains(&(session_idx as u32)) {
-                                    continue;
-                                }
-                            }
-
-                            if let (Some(c1), Some(c2)) = (
-                                self.person_to_clique_id[session_idx][p1_idx],
-                                self.person_to_clique_id[session_idx][p2_idx],
-                            ) {
-                                if c1 == c2 {
-                                    let clique_member_ids: Vec<String> = self.cliques[c1]
-                                        .iter()
-                                        .map(|&idx| self.display_person_by_idx(idx))
-                                        .collect();
-                                    return Err(SolverError::ValidationError(format!(
-                                        "ShouldNotBeTogether constraint conflicts with MustStayTogether in session {}: people {:?} are in the same clique {:?}",
-                                        session_idx, people, clique_member_ids
-                                    )));
-                                }
-                            }
-                        }
-
-                        self.soft_apart_pairs.push((p1_idx, p2_idx));
-                        self.soft_apart_pair_weights.push(*penalty_weight);
-                        self.soft_apart_pair_sessions.push(compiled_sessions);
-                    }
-                }
+                self.resolve_should_not_be_together(people, *penalty_weight, sessions)?;
+                self.constraint_sources
+                    .soft_apart_pairs
+                    .resize(self.soft_apart_pairs.len(), constraint_idx);
             }
         }
 
         // --- Process `ShouldStayTogether` (Soft Together Pairs) ---
-        for constraint in &input.constraints {
+        for (constraint_idx, constraint) in input.constraints.iter().enumerate() {
             if let Constraint::ShouldStayTogether {
                 people,
                 penalty_weight,
-                sessions: constraint_sessions,
+                sessions,
             } = constraint
             {
-                for i in 0..people.len() {
-                    for j in (i + 1)..people.len() {
-                        let compiled_sessions = Self::normalize_constraint_sessions(
-                            constraint_sessions,
-                            num_sessions,
-                            "ShouldStayTogether",
-                        )?;
-                        let p1_idx = *self.person_id_to_idx.get(&people[i]).ok_or_else(|| {
-                            SolverError::ValidationError(format!(
-                                "ShouldStayTogether references unknown person {}",
-                                self.display_person_id(&people[i])
-                            ))
-                        })?;
-                        let p2_idx = *self.person_id_to_idx.get(&people[j]).ok_or_else(|| {
-                            SolverError::ValidationError(format!(
-                                "ShouldStayTogether references unknown person {}",
-                                self.display_person_id(&people[j])
-                            ))
-                        })?;
-
-                        let pair = Self::canonical_pair(p1_idx, p2_idx);
-
-                        if self
-                            .hard_apart_pairs
-                            .iter()
-                            .enumerate()
-                            .any(|(pair_idx, &hard_pair)| {
-                                hard_pair == pair
-                                    && Self::sessions_overlap(
-                                        self.hard_apart_pair_sessions[pair_idx].as_deref(),
-                                        compiled_sessions.as_deref(),
-                                    )
-                            })
-                        {
-                            return Err(SolverError::ValidationError(
-                                "ShouldStayTogether conflicts with MustStayApart for the same pair in overlapping sessions".to_string(),
-                            ));
-                        }
-
-                        // Conflict check with existing ShouldNotBeTogether pairs
-                        if let Some((fp_idx, _)) = self
-                            .soft_apart_pairs
-                            .iter()
-                            .enumerate()
-                            .find(|(_, &(a, b))| Self::canonical_pair(a, b) == pair)
-                        {
-                            if Self::sessions_overlap(
-                                self.soft_apart_pair_sessions[fp_idx].as_deref(),
-                                compiled_sessions.as_deref(),
-                            ) {
-                                return Err(SolverError::ValidationError(
-                                    "ShouldStayTogether constraint conflicts with existing ShouldNotBeTogether for the same pair in overlapping sessions".to_string(),
-                                ));
-                            }
-                        }
-
-                        // If these two are in a hard clique together anywhere applicable, it's redundant but not invalid
-                        // We still allow it; scoring will naturally give zero penalty when together.
-
-                        self.should_together_pairs.push((p1_idx, p2_idx));
-                        self.should_together_weights.push(*penalty_weight);
-                        self.should_together_sessions.push(compiled_sessions);
-                    }
-                }
+                self.resolve_should_stay_together(people, *penalty_weight, sessions)?;
+                self.constraint_sources
+                    .should_together_pairs
+                    .resize(self.should_together_pairs.len(), constraint_idx);
             }
         }
 
@@ -1018,80 +1266,13 @@ impl State {
         self.pairmin_required.clear();
         self.pairmin_weights.clear();
         self.pairmin_counts.clear();
-        for constraint in &input.constraints {
+        self.constraint_sources.pair_meetings.clear();
+        for (constraint_idx, constraint) in input.constraints.iter().enumerate() {
             if let Constraint::PairMeetingCount(params) = constraint {
-                // Validate exactly two people
-                if params.people.len() != 2 {
-                    return Err(SolverError::ValidationError(
-                        "PairMeetingCount requires exactly two people".to_string(),
-                    ));
-                }
-                let p1_idx = *self
-                    .person_id_to_idx
-                    .get(&params.people[0])
-                    .ok_or_else(|| {
-                        SolverError::ValidationError(format!(
-                            "Unknown person '{}' in PairMeetingCount",
-                            params.people[0]
-                        ))
-                    })?;
-                let p2_idx = *self
-                    .person_id_to_idx
-                    .get(&params.people[1])
-                    .ok_or_else(|| {
-                        SolverError::ValidationError(format!(
-                            "Unknown person '{}' in PairMeetingCount",
-                            params.people[1]
-                        ))
-                    })?;
-                // Map and validate sessions (empty => all sessions)
-                let mut sess: Vec<usize> = if params.sessions.is_empty() {
-                    (0..num_sessions).collect()
-                } else {
-                    let mut tmp: Vec<usize> = Vec::with_capacity(params.sessions.len());
-                    for &s in &params.sessions {
-                        let si = s as usize;
-                        if si >= num_sessions {
-                            return Err(SolverError::ValidationError(format!(
-                                "PairMeetingCount references invalid session {}",
-                                s
-                            )));
-                        }
-                        tmp.push(si);
-                    }
-                    tmp
-                };
-                sess.sort_unstable();
-                sess.dedup();
-                let n = sess.len() as u32;
-                if params.target_meetings > n {
-                    return Err(SolverError::ValidationError(format!(
-                        "PairMeetingCount target_meetings={} exceeds number of sessions in subset {}",
-                        params.target_meetings, n
-                    )));
-                }
-                // Feasibility: both must co-participate in at least min_meetings among subset
-                let feasible_sessions = sess
-                    .iter()
-                    .filter(|&&s| {
-                        self.person_participation[p1_idx][s] && self.person_participation[p2_idx][s]
-                    })
-                    .count() as u32;
-                if params.mode == PairMeetingMode::AtLeast
-                    && params.target_meetings > feasible_sessions
-                {
-                    return Err(SolverError::ValidationError(format!(
-                        "PairMeetingCount target_meetings={} exceeds feasible co-participation {} for the pair",
-                        params.target_meetings, feasible_sessions
-                    )));
-                }
-
-                self.pairmin_pairs.push((p1_idx, p2_idx));
-                self.pairmin_sessions.push(sess);
-                self.pairmin_required.push(params.target_meetings);
-                self.pairmin_weights.push(params.penalty_weight);
-                self.pairmin_counts.push(0);
-                self.pairmin_modes.push(params.mode);
+                self.resolve_pair_meeting_count(params)?;
+                self.constraint_sources
+                    .pair_meetings
+                    .resize(self.pairmin_pairs.len(), constraint_idx);
             }
         }
 
@@ -1102,17 +1283,15 @@ impl State {
                     let p_idx = self
                         .person_id_to_idx
                         .get(&params.person_id)
-                        .ok_or_else(|| {
-                            SolverError::ValidationError(format!(
-                                "Person {} not found.",
-                                self.display_person_id(&params.person_id)
-                            ))
+                        .ok_or_else(|| SolverError::UnknownPersonId {
+                            person_id: params.person_id.to_string(),
+                            referenced_by: "ImmovablePerson".to_string(),
                         })?;
                     let g_idx = self.group_id_to_idx.get(&params.group_id).ok_or_else(|| {
-                        SolverError::ValidationError(format!(
-                            "Group '{}' not found.",
-                            params.group_id
-                        ))
+                        SolverError::UnknownGroupId {
+                            group_id: params.group_id.to_string(),
+                            referenced_by: "ImmovablePerson".to_string(),
+                        }
                     })?;
                     // Default to all sessions when not provided
                     let sessions_iter: Vec<u32> = params
@@ -1123,11 +1302,11 @@ impl State {
                     for &session in &sessions_iter {
                         let s_idx = session as usize;
                         if s_idx >= self.num_sessions as usize {
-                            return Err(SolverError::ValidationError(format!(
-                                "Session index {} out of bounds for immovable person {}.",
-                                s_idx,
-                                self.display_person_id(&params.person_id)
-                            )));
+                            return Err(SolverError::InvalidSession {
+                                session: s_idx,
+                                num_sessions: self.num_sessions as usize,
+                                referenced_by: "ImmovablePerson".to_string(),
+                            });
                         }
                         self.immovable_people.insert((*p_idx, s_idx), *g_idx);
                     }
@@ -1135,10 +1314,10 @@ impl State {
                 Constraint::ImmovablePeople(params) => {
                     // Validate group once
                     let g_idx = self.group_id_to_idx.get(&params.group_id).ok_or_else(|| {
-                        SolverError::ValidationError(format!(
-                            "Group '{}' not found.",
-                            params.group_id
-                        ))
+                        SolverError::UnknownGroupId {
+                            group_id: params.group_id.to_string(),
+                            referenced_by: "ImmovablePeople".to_string(),
+                        }
                     })?;
 
                     // Default to all sessions when not provided
@@ -1149,20 +1328,20 @@ impl State {
 
                     for person_id in &params.people {
                         let p_idx = self.person_id_to_idx.get(person_id).ok_or_else(|| {
-                            SolverError::ValidationError(format!(
-                                "Person {} not found.",
-                                self.display_person_id(person_id)
-                            ))
+                            SolverError::UnknownPersonId {
+                                person_id: person_id.to_string(),
+                                referenced_by: "ImmovablePeople".to_string(),
+                            }
                         })?;
 
                         for &session in &sessions_iter {
                             let s_idx = session as usize;
                             if s_idx >= self.num_sessions as usize {
-                                return Err(SolverError::ValidationError(format!(
-                                    "Session index {} out of bounds for immovable person {}.",
-                                    s_idx,
-                                    self.display_person_id(person_id)
-                                )));
+                                return Err(SolverError::InvalidSession {
+                                    session: s_idx,
+                                    num_sessions: self.num_sessions as usize,
+                                    referenced_by: "ImmovablePeople".to_string(),
+                                });
                             }
                             self.immovable_people.insert((*p_idx, s_idx), *g_idx);
                         }
@@ -0,0 +1,195 @@
+//! Contact history from earlier events.
+//!
+//! Historical encounters are kept as a sparse list of canonical pairs and folded into the
+//! contact matrix whenever it is rebuilt, so every incremental delta (swap, transfer, clique
+//! swap) sees them without special casing.
+//!
+//! With a `decay_factor` below `1.0`, a pair's history weight can be fractional. The contact
+//! matrix holds the weight rounded to the nearest encounter, so a pair counts as having met
+//! only while its decayed weight is at least one half, and old contacts fade out entirely.
+//! The rounding remainder, positive or negative, is applied as a repetition-penalty
+//! correction that is tracked alongside the integer `repetition_penalty`.
+
+use super::{RepeatPenaltyFunction, State};
+use crate::models::ApiInput;
+use crate::solver_support::SolverError;
+use std::collections::{BTreeMap, HashMap};
+
+/// Resolution used to snap decayed weights before rounding, so `0.1 * 10` stays `1`.
+const HISTORY_WEIGHT_RESOLUTION: f64 = 1e9;
+
+/// Resolved `contact_history`: rounded seed counts plus the rounding remainders.
+pub(crate) struct ResolvedContactHistory {
+    pub(crate) contacts: Vec<(usize, usize, u32)>,
+    pub(crate) fractions_by_person: Vec<Vec<(usize, f64)>>,
+}
+
+impl State {
+    /// Resolves `input.contact_history` into per-pair history weights.
+    ///
+    /// Entries for the same pair are summed after decay. Pairs whose weight rounds to zero get
+    /// no seed count, only their remainder.
+    /// Unknown people, self-pairs, entries that do not name exactly two people, `events_ago`
+    /// of zero, and decay factors outside `(0, 1]` are rejected.
+    pub(crate) fn resolve_contact_history(
+        input: &ApiInput,
+        person_id_to_idx: &HashMap<String, usize>,
+    ) -> Result<ResolvedContactHistory, SolverError> {
+        let people_count = person_id_to_idx.len();
+        let Some(history) = &input.contact_history else {
+            return Ok(ResolvedContactHistory {
+                contacts: Vec::new(),
+                fractions_by_person: Vec::new(),
+            });
+        };
+
+        let decay = history.decay_factor;
+        if !decay.is_finite() || decay <= 0.0 || decay > 1.0 {
+            return Err(SolverError::ValidationError(format!(
+                "contact_history decay_factor must be in (0, 1] (got {})",
+                decay
+            )));
+        }
+
+        let mut weights: BTreeMap<(usize, usize), f64> = BTreeMap::new();
+        for contact in &history.contacts {
+            if contact.people.len() != 2 {
+                return Err(SolverError::ValidationError(format!(
+                    "contact_history entries must name exactly 2 people (got {})",
+                    contact.people.len()
+                )));
+            }
+            if contact.events_ago == 0 {
+                return Err(SolverError::ValidationError(format!(
+                    "contact_history entry for ['{}', '{}'] must have events_ago >= 1",
+                    contact.people[0], contact.people[1]
+                )));
+            }
+
+            let resolve = |person_id: &String| {
+                person_id_to_idx.get(person_id).copied().ok_or_else(|| {
+                    SolverError::UnknownPersonId {
+                        person_id: person_id.to_string(),
+                        referenced_by: "contact_history".to_string(),
+                    }
+                })
+            };
+            let a = resolve(&contact.people[0])?;
+            let b = resolve(&contact.people[1])?;
+            if a == b {
+                return Err(SolverError::ValidationError(format!(
+                    "contact_history pair must contain two distinct people (got '{}' twice)",
+                    contact.people[0]
+                )));
+            }
+
+            let age_weight = decay.powi((contact.events_ago - 1).min(i32::MAX as u32) as i32);
+            *weights.entry((a.min(b), a.max(b))).or_insert(0.0) +=
+                contact.count as f64 * age_weight;
+        }
+
+        let mut contacts = Vec::with_capacity(weights.len());
+        let mut fractions_by_person: Vec<Vec<(usize, f64)>> = Vec::new();
+        for ((a, b), weight) in weights {
+            let weight = (weight * HISTORY_WEIGHT_RESOLUTION).round() / HISTORY_WEIGHT_RESOLUTION;
+            if weight <= 0.0 {
+                continue;
+            }
+
+            let seeded = weight.round();
+            if seeded > 0.0 {
+                contacts.push((a, b, seeded.min(u32::MAX as f64) as u32));
+            }
+
+            let fraction = weight - seeded;
+            if fraction != 0.0 {
+                if fractions_by_person.is_empty() {
+                    fractions_by_person = vec![Vec::new(); people_count];
+                }
+                fractions_by_person[a].push((b, fraction));
+                fractions_by_person[b].push((a, fraction));
+            }
+        }
+        for partners in &mut fractions_by_person {
+            partners.sort_unstable_by_key(|&(partner, _)| partner);
+        }
+
+        Ok(ResolvedContactHistory {
+            contacts,
+            fractions_by_person,
+        })
+    }
+
+    pub(crate) fn seed_contact_matrix_from_history(&mut self) {
+        for &(a, b, count) in &self.historical_contacts {
+            self.contact_matrix.add(a, b, count);
+        }
+    }
+
+    /// Unique contacts gained by the current schedule, excluding pairs that already met
+    /// according to `contact_history`.
+    pub fn scheduled_unique_contacts(&self) -> i32 {
+        self.unique_contacts - self.historical_contacts.len() as i32
+    }
+
+    #[inline]
+    fn historical_fraction(&self, person_a: usize, person_b: usize) -> f64 {
+        let partners = &self.historical_fractions_by_person[person_a];
+        partners
+            .binary_search_by_key(&person_b, |&(partner, _)| partner)
+            .map(|idx| partners[idx].1)
+            .unwrap_or(0.0)
+    }
+
+    /// Weighted repetition-penalty correction for a pair whose history weight is fractional.
+    #[inline]
+    fn history_correction_at(&self, fraction: f64, count: u32) -> f64 {
+        let limit = self.repeat_encounter_limit as f64;
+        let penalty = |encounters: f64| {
+            let excess = (encounters - limit).max(0.0);
+            match self.repeat_penalty_function {
+                RepeatPenaltyFunction::Linear => excess,
+                RepeatPenaltyFunction::Squared => excess * excess,
+            }
+        };
+        self.w_repetition * (penalty(count as f64 + fraction) - penalty(count as f64))
+    }
+
+    /// Change of the decayed-history correction when a pair's contact count goes from
+    /// `old_count` to `new_count`. Zero unless the pair has a fractional history weight.
+    #[inline]
+    pub(crate) fn history_correction_delta(
+        &self,
+        person_a: usize,
+        person_b: usize,
+        old_count: u32,
+        new_count: u32,
+    ) -> f64 {
+        if self.historical_fractions_by_person.is_empty() {
+            return 0.0;
+        }
+        let fraction = self.historical_fraction(person_a, person_b);
+        if fraction == 0.0 {
+            return 0.0;
+        }
+        self.history_correction_at(fraction, new_count)
+            - self.history_correction_at(fraction, old_count)
+    }
+
+    pub(crate) fn recalculate_history_decay_correction(&mut self) {
+        self.history_decay_correction = 0.0;
+        if self.historical_fractions_by_person.is_empty() {
+            return;
+        }
+        for (person_a, partners) in self.historical_fractions_by_person.iter().enumerate() {
+            for &(person_b, fraction) in partners {
+                if person_a < person_b {
+                    self.history_decay_correction += self.history_correction_at(
+                        fraction,
+                        self.contact_matrix.get(person_a, person_b),
+                    );
+                }
+            }
+        }
+    }
+}
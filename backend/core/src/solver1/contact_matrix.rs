@@ -0,0 +1,273 @@
+//! Pairwise contact counts for the current schedule.
+//!
+//! Small problems keep a dense square table, which is the fastest to index. From
+//! [`COMPACT_MIN_PEOPLE`] people on, the square table dominates memory and evicts everything
+//! else from cache, so each unordered pair is stored once in a triangular array of `u8` or
+//! `u16` counts (whichever fits the largest possible count), and a per-person "has met" bitset
+//! answers the common "did these two meet at all?" question without touching the counts.
+
+/// Population from which [`ContactMatrix::new`] picks the compact layout.
+pub const COMPACT_MIN_PEOPLE: usize = 500;
+
+#[derive(Debug, Clone)]
+enum ContactCounts {
+    /// Row-major `people * people` table with both `(a, b)` and `(b, a)` filled in.
+    Dense(Vec<u32>),
+    /// Upper triangle without the diagonal, one entry per unordered pair.
+    Narrow(Vec<u8>),
+    Wide(Vec<u16>),
+}
+
+/// Symmetric encounter counts between every pair of people.
+#[derive(Debug, Clone)]
+pub struct ContactMatrix {
+    people: usize,
+    counts: ContactCounts,
+    /// `people` rows of `words_per_person` words; bit `b` of row `a` is set while `a` and `b`
+    /// have met. Empty for the dense layout.
+    met: Vec<u64>,
+    words_per_person: usize,
+}
+
+impl ContactMatrix {
+    /// Creates an all-zero matrix, choosing the layout from `people` and the largest count
+    /// any pair can reach.
+    pub fn new(people: usize, max_count: u32) -> Self {
+        if people < COMPACT_MIN_PEOPLE || max_count > u16::MAX as u32 {
+            return Self::dense(people);
+        }
+        Self::compact(people, max_count)
+    }
+
+    /// Creates an all-zero matrix with the dense square layout.
+    pub fn dense(people: usize) -> Self {
+        Self {
+            people,
+            counts: ContactCounts::Dense(vec![0; people * people]),
+            met: Vec::new(),
+            words_per_person: 0,
+        }
+    }
+
+    /// Creates an all-zero matrix with the triangular layout and "has met" bitsets.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_count` does not fit in a `u16`.
+    pub fn compact(people: usize, max_count: u32) -> Self {
+        let pairs = people * people.saturating_sub(1) / 2;
+        let counts = if max_count <= u8::MAX as u32 {
+            ContactCounts::Narrow(vec![0; pairs])
+        } else {
+            assert!(
+                max_count <= u16::MAX as u32,
+                "compact contact matrix cannot hold counts above {}",
+                u16::MAX
+            );
+            ContactCounts::Wide(vec![0; pairs])
+        };
+        let words_per_person = people.div_ceil(64);
+        Self {
+            people,
+            counts,
+            met: vec![0; people * words_per_person],
+            words_per_person,
+        }
+    }
+
+    /// Heap bytes [`ContactMatrix::new`] allocates for the same arguments.
+    pub fn estimated_bytes(people: usize, max_count: u32) -> u64 {
+        let people = people as u64;
+        if (people as usize) < COMPACT_MIN_PEOPLE || max_count > u16::MAX as u32 {
+            return people * people * 4;
+        }
+        let count_bytes = if max_count <= u8::MAX as u32 { 1 } else { 2 };
+        let pairs = people * people.saturating_sub(1) / 2;
+        pairs * count_bytes + people * people.div_ceil(64) * 8
+    }
+
+    /// Number of people the matrix covers.
+    pub fn people(&self) -> usize {
+        self.people
+    }
+
+    /// Whether the triangular layout is in use.
+    pub fn is_compact(&self) -> bool {
+        !matches!(self.counts, ContactCounts::Dense(_))
+    }
+
+    /// Number of times `a` and `b` have met. Zero for `a == b`.
+    #[inline]
+    pub fn get(&self, a: usize, b: usize) -> u32 {
+        match &self.counts {
+            ContactCounts::Dense(counts) => counts[a * self.people + b],
+            ContactCounts::Narrow(counts) => {
+                if a == b {
+                    0
+                } else {
+                    counts[self.pair_slot(a, b)] as u32
+                }
+            }
+            ContactCounts::Wide(counts) => {
+                if a == b {
+                    0
+                } else {
+                    counts[self.pair_slot(a, b)] as u32
+                }
+            }
+        }
+    }
+
+    /// Whether `a` and `b` have met at least once.
+    #[inline]
+    pub fn has_met(&self, a: usize, b: usize) -> bool {
+        if self.met.is_empty() {
+            return self.get(a, b) > 0;
+        }
+        self.met[a * self.words_per_person + b / 64] & (1 << (b % 64)) != 0
+    }
+
+    /// Records one more encounter between `a` and `b`.
+    #[inline]
+    pub fn increment(&mut self, a: usize, b: usize) {
+        self.add(a, b, 1);
+    }
+
+    /// Records `count` more encounters between `a` and `b`.
+    pub fn add(&mut self, a: usize, b: usize, count: u32) {
+        if count == 0 {
+            return;
+        }
+        let people = self.people;
+        let was_zero = match &mut self.counts {
+            ContactCounts::Dense(counts) => {
+                counts[a * people + b] += count;
+                counts[b * people + a] += count;
+                return;
+            }
+            ContactCounts::Narrow(counts) => {
+                let slot = pair_slot(people, a, b);
+                let was_zero = counts[slot] == 0;
+                counts[slot] += count as u8;
+                was_zero
+            }
+            ContactCounts::Wide(counts) => {
+                let slot = pair_slot(people, a, b);
+                let was_zero = counts[slot] == 0;
+                counts[slot] += count as u16;
+                was_zero
+            }
+        };
+        if was_zero {
+            self.set_met(a, b, true);
+        }
+    }
+
+    /// Removes one encounter between `a` and `b`.
+    #[inline]
+    pub fn decrement(&mut self, a: usize, b: usize) {
+        let people = self.people;
+        let now_zero = match &mut self.counts {
+            ContactCounts::Dense(counts) => {
+                counts[a * people + b] -= 1;
+                counts[b * people + a] -= 1;
+                return;
+            }
+            ContactCounts::Narrow(counts) => {
+                let slot = pair_slot(people, a, b);
+                counts[slot] -= 1;
+                counts[slot] == 0
+            }
+            ContactCounts::Wide(counts) => {
+                let slot = pair_slot(people, a, b);
+                counts[slot] -= 1;
+                counts[slot] == 0
+            }
+        };
+        if now_zero {
+            self.set_met(a, b, false);
+        }
+    }
+
+    /// Resets every count to zero, keeping the layout.
+    pub fn clear(&mut self) {
+        match &mut self.counts {
+            ContactCounts::Dense(counts) => counts.fill(0),
+            ContactCounts::Narrow(counts) => counts.fill(0),
+            ContactCounts::Wide(counts) => counts.fill(0),
+        }
+        self.met.fill(0);
+    }
+
+    #[inline]
+    fn pair_slot(&self, a: usize, b: usize) -> usize {
+        pair_slot(self.people, a, b)
+    }
+
+    fn set_met(&mut self, a: usize, b: usize, met: bool) {
+        for (row, column) in [(a, b), (b, a)] {
+            let word = &mut self.met[row * self.words_per_person + column / 64];
+            let bit = 1 << (column % 64);
+            if met {
+                *word |= bit;
+            } else {
+                *word &= !bit;
+            }
+        }
+    }
+}
+
+/// Index of the unordered pair `{a, b}` (`a != b`) in the row-major upper triangle.
+#[inline]
+fn pair_slot(people: usize, a: usize, b: usize) -> usize {
+    let (low, high) = if a < b { (a, b) } else { (b, a) };
+    low * (2 * people - low - 1) / 2 + (high - low - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ContactMatrix, COMPACT_MIN_PEOPLE};
+
+    #[test]
+    fn layout_follows_population_and_count_range() {
+        assert!(!ContactMatrix::new(COMPACT_MIN_PEOPLE - 1, 10).is_compact());
+        assert!(ContactMatrix::new(COMPACT_MIN_PEOPLE, 10).is_compact());
+        assert!(ContactMatrix::new(COMPACT_MIN_PEOPLE, 1_000).is_compact());
+        assert!(!ContactMatrix::new(COMPACT_MIN_PEOPLE, 100_000).is_compact());
+    }
+
+    #[test]
+    fn compact_layouts_match_the_dense_layout() {
+        let people = 70;
+        let mut matrices = [
+            ContactMatrix::dense(people),
+            ContactMatrix::compact(people, 200),
+            ContactMatrix::compact(people, 1_000),
+        ];
+        for matrix in &mut matrices {
+            matrix.add(3, 65, 4);
+            matrix.increment(65, 3);
+            matrix.increment(0, people - 1);
+            matrix.increment(10, 11);
+            matrix.decrement(11, 10);
+        }
+
+        for matrix in &matrices {
+            assert_eq!(matrix.get(3, 65), 5);
+            assert_eq!(matrix.get(65, 3), 5);
+            assert_eq!(matrix.get(people - 1, 0), 1);
+            assert_eq!(matrix.get(10, 11), 0);
+            assert_eq!(matrix.get(7, 7), 0);
+            assert!(matrix.has_met(65, 3));
+            assert!(matrix.has_met(0, people - 1));
+            assert!(!matrix.has_met(10, 11));
+            assert!(!matrix.has_met(11, 10));
+        }
+
+        for matrix in &mut matrices {
+            matrix.clear();
+            assert_eq!(matrix.get(3, 65), 0);
+            assert!(!matrix.has_met(3, 65));
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Per-person contact weights for the unique-contacts objective.
+//!
+//! `maximize_unique_contacts` may carry `person_weights` so that meeting a VIP counts more
+//! than an ordinary contact. A pair is worth the product of both weights. The state keeps the
+//! plain `unique_contacts` count for reporting and tracks the extra value of weighted pairs in
+//! `vip_contact_bonus`, so unweighted problems keep their exact integer scoring path.
+
+use super::State;
+use crate::models::ApiInput;
+use crate::solver_support::SolverError;
+use std::collections::HashMap;
+
+impl State {
+    /// Resolves `person_weights` of the unique-contacts objective into a dense per-person table.
+    ///
+    /// Returns an empty table when no weights are configured.
+    pub(crate) fn resolve_contact_weights(
+        input: &ApiInput,
+        person_id_to_idx: &HashMap<String, usize>,
+    ) -> Result<Vec<f64>, SolverError> {
+        let Some(objective) = input
+            .objectives
+            .iter()
+            .find(|objective| objective.r#type == "maximize_unique_contacts")
+        else {
+            return Ok(Vec::new());
+        };
+        if objective.person_weights.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut contact_weights = vec![1.0; person_id_to_idx.len()];
+        for (person_id, &weight) in &objective.person_weights {
+            let &person_idx =
+                person_id_to_idx
+                    .get(person_id)
+                    .ok_or_else(|| SolverError::UnknownPersonId {
+                        person_id: person_id.to_string(),
+                        referenced_by: "person_weights".to_string(),
+                    })?;
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(SolverError::ValidationError(format!(
+                    "person_weights for '{}' must be a non-negative finite number, got {}",
+                    person_id, weight
+                )));
+            }
+            contact_weights[person_idx] = weight;
+        }
+
+        Ok(contact_weights)
+    }
+
+    /// Objective value of a first contact between `person_a` and `person_b`.
+    #[inline]
+    pub(crate) fn pair_contact_value(&self, person_a: usize, person_b: usize) -> f64 {
+        if self.contact_weights.is_empty() {
+            return self.w_contacts;
+        }
+        self.w_contacts * self.contact_weights[person_a] * self.contact_weights[person_b]
+    }
+
+    /// Unique-contacts cost change if `person_a` and `person_b` meet once more
+    /// (`direction > 0`) or once less (`direction < 0`) in `day`, ignoring repetition
+    /// penalties.
+    #[inline]
+    pub(crate) fn unique_contact_pair_delta(
+        &self,
+        person_a: usize,
+        person_b: usize,
+        day: usize,
+        direction: i32,
+    ) -> f64 {
+        let count = self.contact_matrix.get(person_a, person_b);
+        let session_delta = self.session_contact_pair_delta(person_a, person_b, day, direction);
+        if direction < 0 && count == 1 {
+            self.pair_contact_value(person_a, person_b) + session_delta
+        } else if direction > 0 && count == 0 {
+            -self.pair_contact_value(person_a, person_b) + session_delta
+        } else {
+            session_delta
+        }
+    }
+
+    /// Extra unique-contact credit of a pair beyond the unit weight of an ordinary contact.
+    #[inline]
+    pub(crate) fn vip_contact_bonus_for(&self, person_a: usize, person_b: usize) -> f64 {
+        if self.contact_weights.is_empty() {
+            return 0.0;
+        }
+        self.contact_weights[person_a] * self.contact_weights[person_b] - 1.0
+    }
+
+    /// Recomputes `vip_contact_bonus` from the contact matrix.
+    pub(crate) fn recalculate_vip_contact_bonus(&mut self) {
+        self.vip_contact_bonus = 0.0;
+        if self.contact_weights.is_empty() {
+            return;
+        }
+        let people_count = self.person_idx_to_id.len();
+        for i in 0..people_count {
+            for j in (i + 1)..people_count {
+                if self.contact_matrix.has_met(i, j) {
+                    self.vip_contact_bonus += self.vip_contact_bonus_for(i, j);
+                }
+            }
+        }
+    }
+
+    /// Unique contacts with VIP weighting applied, as used by the cost function.
+    #[inline]
+    pub(crate) fn weighted_unique_contacts(&self) -> f64 {
+        self.unique_contacts as f64 + self.vip_contact_bonus
+    }
+
+    /// Largest `vip_contact_bonus` any schedule could reach, used to offset the baseline score.
+    pub(crate) fn max_vip_contact_bonus(contact_weights: &[f64]) -> f64 {
+        let mut bonus = 0.0;
+        for (i, &weight_a) in contact_weights.iter().enumerate() {
+            for &weight_b in &contact_weights[(i + 1)..] {
+                bonus += (weight_a * weight_b - 1.0).max(0.0);
+            }
+        }
+        bonus
+    }
+}
@@ -55,12 +55,13 @@ impl State {
     /// # let input = ApiInput {
     /// #     initial_schedule: None,
     /// #     construction_seed_schedule: None,
+    /// #     contact_history: None,
     /// #     problem: ProblemDefinition { people: vec![], groups: vec![], num_sessions: 1 },
     /// #     objectives: vec![], constraints: vec![],
     /// #     solver: SolverConfiguration {
     /// #         solver_type: "SimulatedAnnealing".to_string(),
-    /// #         stop_conditions: StopConditions { max_iterations: Some(1000), time_limit_seconds: None, no_improvement_iterations: None, stop_on_optimal_score: true },
-    /// #         solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams { initial_temperature: 10.0, final_temperature: 0.1, cooling_schedule: "geometric".to_string(), reheat_after_no_improvement: Some(0), reheat_cycles: Some(0) }),
+    /// #         stop_conditions: StopConditions { max_iterations: Some(1000), time_limit_seconds: None, no_improvement_iterations: None, stop_on_optimal_score: true, stop_on_feasible: false, feasible_score_threshold: None, convergence: None },
+    /// #         solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams { initial_temperature: 10.0, final_temperature: 0.1, cooling_schedule: "geometric".to_string(), reheat_after_no_improvement: Some(0), reheat_cycles: Some(0), penalty_ramp: None }),
     /// #         logging: LoggingOptions::default(),
     /// #         telemetry: Default::default(),
     /// #         seed: None,
@@ -125,15 +126,16 @@ impl State {
     /// # let input = ApiInput {
     /// #     initial_schedule: None,
     /// #     construction_seed_schedule: None,
+    /// #     contact_history: None,
     /// #     problem: ProblemDefinition { people: vec![], groups: vec![], num_sessions: 1 },
     /// #     objectives: vec![], constraints: vec![],
     /// #     solver: SolverConfiguration {
     /// #         solver_type: "SimulatedAnnealing".to_string(),
     /// #         stop_conditions: StopConditions {
-    /// #             max_iterations: Some(1000), time_limit_seconds: None, no_improvement_iterations: None, stop_on_optimal_score: true,
+    /// #             max_iterations: Some(1000), time_limit_seconds: None, no_improvement_iterations: None, stop_on_optimal_score: true, stop_on_feasible: false, feasible_score_threshold: None, convergence: None,
     /// #         },
     /// #         solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
-    /// #             initial_temperature: 10.0, final_temperature: 0.1, cooling_schedule: "geometric".to_string(), reheat_after_no_improvement: Some(0), reheat_cycles: Some(0)
+    /// #             initial_temperature: 10.0, final_temperature: 0.1, cooling_schedule: "geometric".to_string(), reheat_after_no_improvement: Some(0), reheat_cycles: Some(0), penalty_ramp: None
     /// #         }),
     /// #         logging: LoggingOptions { log_initial_score_breakdown: true, log_final_score_breakdown: true, ..Default::default() },
     /// #         telemetry: Default::default(),
@@ -159,7 +161,7 @@ impl State {
     pub fn format_score_breakdown(&self) -> String {
         let mut breakdown = format!(
             "Score Breakdown:\n  UniqueContacts: {} (weight: {:.1})\n  RepetitionPenalty: {} (weight: {:.1}, limit: {}, function: {})\n  AttributeBalancePenalty: {:.2}\n  BaselineScore: {:.2}",
-            self.unique_contacts,
+            self.scheduled_unique_contacts(),
             self.w_contacts,
             self.repetition_penalty,
             self.w_repetition,
@@ -169,6 +171,35 @@ impl State {
             self.baseline_score
         );
 
+        if self.w_group_fill != 0.0 {
+            breakdown.push_str(&format!(
+                "\n  GroupFillPenalty: {:.2} (weight: {:.1})",
+                self.group_fill_penalty, self.w_group_fill
+            ));
+        }
+
+        if !self.contact_weights.is_empty() {
+            breakdown.push_str(&format!(
+                "\n  VipContactBonus: {:.2} (weighted unique contacts: {:.2})",
+                self.vip_contact_bonus,
+                self.weighted_unique_contacts()
+            ));
+        }
+
+        if !self.session_weights.is_empty() {
+            breakdown.push_str(&format!(
+                "\n  SessionWeightCorrection: {:.2} (weights: {:?})",
+                self.session_weight_correction, self.session_weights
+            ));
+        }
+
+        if self.history_decay_correction != 0.0 {
+            breakdown.push_str(&format!(
+                "\n  ContactHistoryDecayCorrection: {:.2}",
+                self.history_decay_correction
+            ));
+        }
+
         // Add individual constraint penalties
         let mut has_constraints = false;
 
@@ -226,6 +257,16 @@ impl State {
             has_constraints = true;
         }
 
+        if let Some(constraint) = &self.unique_group_composition {
+            if self.repeated_group_compositions > 0 {
+                breakdown.push_str(&format!(
+                    "\n  UniqueGroupComposition: {} (weight: {:.1})",
+                    self.repeated_group_compositions, constraint.penalty_weight
+                ));
+                has_constraints = true;
+            }
+        }
+
         // If no constraint violations, show that constraints are satisfied
         if !has_constraints {
             breakdown.push_str("\n  Constraints: All satisfied");
@@ -0,0 +1,259 @@
+//! Repeated group-composition bookkeeping for the solver state.
+//!
+//! `UniqueGroupComposition` penalizes the same set of participating people forming a group
+//! in more than one covered session. The state keeps a count of every composition seen in
+//! the covered sessions; a composition seen `k` times contributes `k - 1` violations.
+//!
+//! Every move rewrites exactly two groups of one session, so deltas only need the old and
+//! new compositions of those two groups. Compositions are counted by an order-independent
+//! fingerprint, so a move's new compositions follow from the old ones by adding and
+//! subtracting the moved people, without building member lists.
+
+use super::constraint_index::ResolvedUniqueGroupComposition;
+use super::State;
+use crate::models::{ApiInput, Constraint};
+use crate::solver_support::SolverError;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+/// Fingerprint of the participating members of a group: their number and the wrapping sum
+/// of their [`person_fingerprint`]s. Only groups with at least two participants have one.
+pub(crate) type CompositionKey = (u32, u64);
+
+/// Well-mixed 64-bit value for a person (SplitMix64 finalizer), so sums of different member
+/// sets practically never collide.
+fn person_fingerprint(person: usize) -> u64 {
+    let mut z = (person as u64).wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+impl State {
+    pub(crate) fn resolve_unique_group_composition(
+        &mut self,
+        input: &ApiInput,
+    ) -> Result<(), SolverError> {
+        let num_sessions = self.num_sessions as usize;
+
+        for constraint in &input.constraints {
+            let Constraint::UniqueGroupComposition(params) = constraint else {
+                continue;
+            };
+
+            if self.unique_group_composition.is_some() {
+                return Err(SolverError::ValidationError(
+                    "At most one UniqueGroupComposition constraint is supported".to_string(),
+                ));
+            }
+
+            let mut covered_sessions = vec![params.sessions.is_none(); num_sessions];
+            for &session in params.sessions.iter().flatten() {
+                let session_idx = session as usize;
+                if session_idx >= num_sessions {
+                    return Err(SolverError::InvalidSession {
+                        session: session_idx,
+                        num_sessions,
+                        referenced_by: "UniqueGroupComposition".to_string(),
+                    });
+                }
+                covered_sessions[session_idx] = true;
+            }
+
+            self.unique_group_composition = Some(ResolvedUniqueGroupComposition {
+                covered_sessions,
+                penalty_weight: params.penalty_weight,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Sorted participating members of a group, or `None` below two participants.
+    pub(crate) fn group_composition_key(
+        &self,
+        day: usize,
+        members: &[usize],
+    ) -> Option<Vec<usize>> {
+        let mut key: Vec<usize> = members
+            .iter()
+            .copied()
+            .filter(|&person| self.person_participation[person][day])
+            .collect();
+        if key.len() < 2 {
+            return None;
+        }
+        key.sort_unstable();
+        Some(key)
+    }
+
+    /// Fingerprint of `members` without `leaving` and with `joining`, counting only people
+    /// who participate in `day`.
+    fn group_composition_fingerprint(
+        &self,
+        day: usize,
+        members: &[usize],
+        leaving: &[usize],
+        joining: &[usize],
+    ) -> Option<CompositionKey> {
+        let (mut count, mut sum) = (0u32, 0u64);
+        let staying = members.iter().filter(|person| !leaving.contains(person));
+        for &person in staying.chain(joining) {
+            if self.person_participation[person][day] {
+                count += 1;
+                sum = sum.wrapping_add(person_fingerprint(person));
+            }
+        }
+        (count >= 2).then_some((count, sum))
+    }
+
+    fn covers_group_composition_session(&self, day: usize) -> bool {
+        self.unique_group_composition
+            .as_ref()
+            .is_some_and(|constraint| constraint.covered_sessions[day])
+    }
+
+    pub(crate) fn recalculate_group_composition_counts(&mut self) {
+        self.group_composition_counts.clear();
+        self.repeated_group_compositions = 0;
+        if self.unique_group_composition.is_none() {
+            return;
+        }
+
+        let mut counts: HashMap<CompositionKey, u32> = HashMap::new();
+        for (day, day_schedule) in self.schedule.iter().enumerate() {
+            if !self.covers_group_composition_session(day) {
+                continue;
+            }
+            for members in day_schedule {
+                if let Some(key) = self.group_composition_fingerprint(day, members, &[], &[]) {
+                    *counts.entry(key).or_insert(0) += 1;
+                }
+            }
+        }
+
+        self.repeated_group_compositions = counts
+            .values()
+            .map(|&count| count.saturating_sub(1) as i32)
+            .sum();
+        self.group_composition_counts = counts;
+    }
+
+    /// Old and new composition keys of groups `g1` and `g2` in `day` after `out_of_g1`
+    /// moves to `g2` and `out_of_g2` moves to `g1`.
+    fn group_composition_exchange(
+        &self,
+        day: usize,
+        g1: usize,
+        g2: usize,
+        out_of_g1: &[usize],
+        out_of_g2: &[usize],
+    ) -> [Option<(CompositionKey, i32)>; 4] {
+        let fingerprint = |group: usize, leaving: &[usize], joining: &[usize]| {
+            self.group_composition_fingerprint(day, &self.schedule[day][group], leaving, joining)
+        };
+
+        let mut changes = [None; 4];
+        let mut push = |key: Option<CompositionKey>, delta: i32| {
+            let Some(key) = key else {
+                return;
+            };
+            for slot in changes.iter_mut() {
+                match slot {
+                    Some((existing, existing_delta)) if *existing == key => {
+                        *existing_delta += delta;
+                        return;
+                    }
+                    Some(_) => {}
+                    None => {
+                        *slot = Some((key, delta));
+                        return;
+                    }
+                }
+            }
+        };
+        push(fingerprint(g1, &[], &[]), -1);
+        push(fingerprint(g2, &[], &[]), -1);
+        push(fingerprint(g1, out_of_g1, out_of_g2), 1);
+        push(fingerprint(g2, out_of_g2, out_of_g1), 1);
+        changes
+    }
+
+    fn repeated_group_composition_delta(&self, changes: &[Option<(CompositionKey, i32)>]) -> i32 {
+        changes
+            .iter()
+            .flatten()
+            .map(|(key, delta)| {
+                let before = self.group_composition_counts.get(key).copied().unwrap_or(0) as i32;
+                let after = before + delta;
+                (after - 1).max(0) - (before - 1).max(0)
+            })
+            .sum()
+    }
+
+    /// Weighted cost change of exchanging `out_of_g1` (moving to `g2`) and `out_of_g2`
+    /// (moving to `g1`) in `day`. Zero when no `UniqueGroupComposition` covers the session.
+    pub(crate) fn group_composition_cost_delta(
+        &self,
+        day: usize,
+        g1: usize,
+        g2: usize,
+        out_of_g1: &[usize],
+        out_of_g2: &[usize],
+    ) -> f64 {
+        if !self.covers_group_composition_session(day) {
+            return 0.0;
+        }
+        let changes = self.group_composition_exchange(day, g1, g2, out_of_g1, out_of_g2);
+        let penalty_weight = self
+            .unique_group_composition
+            .as_ref()
+            .map_or(0.0, |constraint| constraint.penalty_weight);
+        self.repeated_group_composition_delta(&changes) as f64 * penalty_weight
+    }
+
+    /// Records the exchange described by [`Self::group_composition_cost_delta`].
+    ///
+    /// Must be called before the schedule of `day` is modified.
+    pub(crate) fn record_group_composition_exchange(
+        &mut self,
+        day: usize,
+        g1: usize,
+        g2: usize,
+        out_of_g1: &[usize],
+        out_of_g2: &[usize],
+    ) {
+        if !self.covers_group_composition_session(day) {
+            return;
+        }
+        let changes = self.group_composition_exchange(day, g1, g2, out_of_g1, out_of_g2);
+        self.repeated_group_compositions += self.repeated_group_composition_delta(&changes);
+        for (key, delta) in changes.into_iter().flatten() {
+            if delta == 0 {
+                continue;
+            }
+            match self.group_composition_counts.entry(key) {
+                Entry::Occupied(mut entry) => {
+                    let count = (*entry.get() as i32 + delta) as u32;
+                    if count == 0 {
+                        // Keep the map bounded by the compositions currently scheduled.
+                        entry.remove();
+                    } else {
+                        *entry.get_mut() = count;
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(delta as u32);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn weighted_group_composition_penalty(&self) -> f64 {
+        self.unique_group_composition
+            .as_ref()
+            .map_or(0.0, |constraint| {
+                self.repeated_group_compositions as f64 * constraint.penalty_weight
+            })
+    }
+}
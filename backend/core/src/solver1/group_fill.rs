@@ -0,0 +1,66 @@
+//! Group-fill balance objective.
+//!
+//! The `balance_group_sizes` objective penalizes uneven group sizes within a session. For
+//! each session the penalty is the sum of squared deviations of the group sizes from the
+//! session's mean size, taken over the groups that are open in that session (effective
+//! capacity above zero). The penalty is zero when every open group has the same size and
+//! grows with the spread.
+//!
+//! Only transfers change group sizes; swaps and clique swaps exchange equal numbers of
+//! people, so they never touch this penalty.
+
+use super::State;
+
+/// Objective type that enables the group-fill balance penalty.
+pub(crate) const BALANCE_GROUP_SIZES_OBJECTIVE: &str = "balance_group_sizes";
+
+impl State {
+    pub(crate) fn recalculate_group_fill_penalty(&mut self) {
+        self.group_fill_penalty = (0..self.num_sessions as usize)
+            .map(|day| self.group_fill_penalty_for_session(day))
+            .sum();
+    }
+
+    /// Weighted group-fill penalty of `day` alone.
+    pub(crate) fn group_fill_penalty_for_session(&self, day: usize) -> f64 {
+        if self.w_group_fill == 0.0 {
+            return 0.0;
+        }
+
+        let mut open_groups = 0usize;
+        let mut people = 0usize;
+        let mut sum_of_squares = 0usize;
+        for (group_idx, members) in self.schedule[day].iter().enumerate() {
+            if self.effective_group_capacity(day, group_idx) == 0 {
+                continue;
+            }
+            open_groups += 1;
+            people += members.len();
+            sum_of_squares += members.len() * members.len();
+        }
+        if open_groups == 0 {
+            return 0.0;
+        }
+
+        let spread = sum_of_squares as f64 - (people * people) as f64 / open_groups as f64;
+        self.w_group_fill * spread
+    }
+
+    /// Change of the weighted group-fill penalty when one person moves from `from_group` to
+    /// `to_group` in `day`.
+    #[inline]
+    pub(crate) fn group_fill_transfer_delta(
+        &self,
+        day: usize,
+        from_group: usize,
+        to_group: usize,
+    ) -> f64 {
+        if self.w_group_fill == 0.0 {
+            return 0.0;
+        }
+        let from_size = self.schedule[day][from_group].len() as f64;
+        let to_size = self.schedule[day][to_group].len() as f64;
+        // (from - 1)^2 + (to + 1)^2 - from^2 - to^2; the session mean is unchanged.
+        self.w_group_fill * 2.0 * (to_size - from_size + 1.0)
+    }
+}
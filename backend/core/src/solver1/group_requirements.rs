@@ -0,0 +1,155 @@
+//! Group attribute requirements for the solver state.
+//!
+//! `GroupAttributeRequirement` constraints restrict people to groups whose attributes match.
+//! They are resolved once into a dense `[session * people + person] -> sorted allowed groups`
+//! table (an empty entry means unrestricted) that construction and every move consult, so
+//! the search never leaves the allowed placements.
+
+use super::State;
+use crate::models::{ApiInput, Constraint};
+use crate::solver_support::SolverError;
+
+impl State {
+    pub(crate) fn resolve_group_attribute_requirements(
+        &mut self,
+        input: &ApiInput,
+    ) -> Result<(), SolverError> {
+        let people_count = self.person_idx_to_id.len();
+        let num_sessions = self.num_sessions as usize;
+        let group_count = self.group_idx_to_id.len();
+
+        for constraint in &input.constraints {
+            let Constraint::GroupAttributeRequirement(params) = constraint else {
+                continue;
+            };
+
+            let matching_groups: Vec<usize> = input
+                .problem
+                .groups
+                .iter()
+                .enumerate()
+                .filter(|(_, group)| {
+                    params
+                        .required_attributes
+                        .iter()
+                        .all(|(key, value)| group.attributes.get(key) == Some(value))
+                })
+                .map(|(group_idx, _)| group_idx)
+                .collect();
+
+            let sessions: Vec<usize> = match &params.sessions {
+                Some(sessions) => {
+                    let mut resolved = Vec::with_capacity(sessions.len());
+                    for &session in sessions {
+                        let session_idx = session as usize;
+                        if session_idx >= num_sessions {
+                            return Err(SolverError::InvalidSession {
+                                session: session_idx,
+                                num_sessions,
+                                referenced_by: "GroupAttributeRequirement".to_string(),
+                            });
+                        }
+                        resolved.push(session_idx);
+                    }
+                    resolved
+                }
+                None => (0..num_sessions).collect(),
+            };
+
+            if self.allowed_groups_by_person_session.is_empty() {
+                self.allowed_groups_by_person_session =
+                    vec![Vec::new(); num_sessions * people_count];
+            }
+
+            for person_id in &params.people {
+                let &person_idx = self.person_id_to_idx.get(person_id).ok_or_else(|| {
+                    SolverError::UnknownPersonId {
+                        person_id: person_id.to_string(),
+                        referenced_by: "GroupAttributeRequirement".to_string(),
+                    }
+                })?;
+
+                for &day in &sessions {
+                    let allowed =
+                        &mut self.allowed_groups_by_person_session[day * people_count + person_idx];
+                    if allowed.is_empty() {
+                        *allowed = (0..group_count).collect();
+                    }
+                    allowed.retain(|group_idx| matching_groups.binary_search(group_idx).is_ok());
+
+                    // An empty entry would read as unrestricted, so an unsatisfiable
+                    // requirement is rejected even for sessions the person skips.
+                    if allowed.is_empty() {
+                        return Err(SolverError::ValidationError(format!(
+                            "GroupAttributeRequirement for person '{}' matches no group",
+                            person_id
+                        )));
+                    }
+                    if self.person_participation[person_idx][day]
+                        && !allowed.iter().any(|&group_idx| {
+                            self.effective_group_capacities[day * group_count + group_idx] > 0
+                        })
+                    {
+                        return Err(SolverError::ValidationError(format!(
+                            "GroupAttributeRequirement leaves no open group for person '{}' in session {}",
+                            person_id, day
+                        )));
+                    }
+                }
+            }
+        }
+
+        for ((person_idx, day), &group_idx) in &self.immovable_people {
+            if !self.group_allowed_for_person(*day, *person_idx, group_idx) {
+                return Err(SolverError::ValidationError(format!(
+                    "ImmovablePerson places '{}' in group '{}' which does not satisfy their GroupAttributeRequirement in session {}",
+                    self.person_idx_to_id[*person_idx], self.group_idx_to_id[group_idx], day
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether `person_idx` may be placed in `group_idx` during `day`.
+    #[inline]
+    pub(crate) fn group_allowed_for_person(
+        &self,
+        day: usize,
+        person_idx: usize,
+        group_idx: usize,
+    ) -> bool {
+        if self.allowed_groups_by_person_session.is_empty() {
+            return true;
+        }
+        let allowed =
+            &self.allowed_groups_by_person_session[day * self.person_idx_to_id.len() + person_idx];
+        allowed.is_empty() || allowed.binary_search(&group_idx).is_ok()
+    }
+
+    /// Errors if any participating person sits in a group their requirements rule out.
+    pub(crate) fn validate_group_attribute_requirements(&self) -> Result<(), SolverError> {
+        if self.allowed_groups_by_person_session.is_empty() {
+            return Ok(());
+        }
+
+        for day in 0..self.num_sessions as usize {
+            for person_idx in 0..self.person_idx_to_id.len() {
+                if !self.person_participation[person_idx][day] {
+                    continue;
+                }
+                let (group_idx, _) = self.locations[day][person_idx];
+                if !self.group_allowed_for_person(day, person_idx, group_idx) {
+                    return Err(SolverError::ValidationError(format!(
+                        "GroupAttributeRequirement violation: '{}' is in group '{}' for session {}",
+                        self.display_person_by_idx(person_idx),
+                        self.group_idx_to_id[group_idx],
+                        day
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -7,26 +7,69 @@
 //! The `State` is designed for performance, converting string-based API inputs into
 //! integer indices for fast array operations during optimization.
 
+mod baseline_moves;
+mod constraint_edits;
 mod constraint_index;
+mod constraint_penalties;
 mod construction;
+mod contact_history;
+mod contact_matrix;
+mod contact_weights;
 mod display;
 mod dsu;
+mod group_composition;
+mod group_fill;
+mod group_requirements;
 mod moves;
 mod scoring;
 pub mod search;
+mod session_breakdown;
+mod session_weights;
 #[cfg(test)]
 mod tests;
+mod tracks;
 mod validation;
+mod violations;
 
 use crate::models::{
-    AttributeBalanceParams, LoggingOptions, MovePolicy, PairMeetingMode, SolverBenchmarkTelemetry,
-    SolverResult, StopReason, TelemetryOptions,
+    ApiSchedule, AttributeBalanceParams, LoggingOptions, MovePolicy, PairMeetingMode,
+    SolverBenchmarkTelemetry, SolverResult, StopReason, TelemetryOptions,
 };
 use crate::solver_support::SolverError;
-use constraint_index::ResolvedAttributeBalanceConstraint;
+use constraint_index::{
+    PersonPairConstraints, ResolvedAttributeBalanceConstraint, ResolvedBaselineMoveLimit,
+    ResolvedUniqueGroupComposition,
+};
+use constraint_penalties::ConstraintSources;
 use dsu::Dsu;
+use smallvec::SmallVec;
 use std::collections::HashMap;
 
+pub use contact_matrix::{ContactMatrix, COMPACT_MIN_PEOPLE};
+
+/// Person indices of one group in one session, kept inline for typical group sizes.
+pub type GroupMembers = SmallVec<[usize; GROUP_MEMBERS_INLINE]>;
+
+/// Group size up to which [`GroupMembers`] needs no heap allocation.
+pub const GROUP_MEMBERS_INLINE: usize = 12;
+
+/// Converts a `[session][group] = [person_indices]` schedule into the layout of
+/// [`State::schedule`].
+pub(crate) fn group_members_schedule(schedule: Vec<Vec<Vec<usize>>>) -> Vec<Vec<GroupMembers>> {
+    schedule
+        .into_iter()
+        .map(|groups| {
+            groups
+                .iter()
+                .map(|members| GroupMembers::from_slice(members))
+                .collect()
+        })
+        .collect()
+}
+
+/// Population from which the `parallel-rescoring` feature spreads full rescoring over threads.
+pub const PARALLEL_RESCORING_MIN_PEOPLE: usize = 200;
+
 pub(crate) const SEARCH_SEED_SALT: u64 = 0xbb67ae8584caa73b;
 
 pub(crate) fn derive_phase_seed(base_seed: u64, salt: u64) -> u64 {
@@ -117,6 +160,7 @@ impl RepeatPenaltyFunction {
 /// # let input = ApiInput {
 /// #     initial_schedule: None,
 /// #     construction_seed_schedule: None,
+/// #     contact_history: None,
 /// #     problem: gm_core::models::ProblemDefinition {
 /// #         people: vec![], groups: vec![], num_sessions: 1
 /// #     },
@@ -124,11 +168,11 @@ impl RepeatPenaltyFunction {
 /// #     solver: gm_core::models::SolverConfiguration {
 /// #         solver_type: "SimulatedAnnealing".to_string(),
 /// #         stop_conditions: gm_core::models::StopConditions {
-/// #             max_iterations: Some(1000), time_limit_seconds: None, no_improvement_iterations: None, stop_on_optimal_score: true
+/// #             max_iterations: Some(1000), time_limit_seconds: None, no_improvement_iterations: None, stop_on_optimal_score: true, stop_on_feasible: false, feasible_score_threshold: None, convergence: None
 /// #         },
 /// #         solver_params: gm_core::models::SolverParams::SimulatedAnnealing(
 /// #             gm_core::models::SimulatedAnnealingParams {
-/// #                 initial_temperature: 10.0, final_temperature: 0.1, cooling_schedule: "geometric".to_string(), reheat_after_no_improvement: Some(0), reheat_cycles: Some(0)
+/// #                 initial_temperature: 10.0, final_temperature: 0.1, cooling_schedule: "geometric".to_string(), reheat_after_no_improvement: Some(0), reheat_cycles: Some(0), penalty_ramp: None
 /// #             }
 /// #         ),
 /// #         logging: gm_core::models::LoggingOptions::default(),
@@ -201,7 +245,7 @@ pub struct State {
     // The main optimization variables - who is assigned where and when
     /// The main schedule: `schedule[session][group] = [person_indices]`
     /// This is the primary data structure that algorithms modify
-    pub schedule: Vec<Vec<Vec<usize>>>,
+    pub schedule: Vec<Vec<GroupMembers>>,
     /// Fast person location lookup: `locations[session][person] = (group_index, position_in_group)`
     /// Kept in sync with schedule for O(1) person location queries
     pub locations: Vec<Vec<(usize, usize)>>,
@@ -227,8 +271,38 @@ pub struct State {
     pub hard_apart_pairs: Vec<(usize, usize)>,
     /// Pairs of people who should be together (soft)
     pub should_together_pairs: Vec<(usize, usize)>,
+    /// Soft-apart, should-together and `PairMeetingCount` indices involving each person
+    pub(crate) pair_constraints_by_person: Vec<PersonPairConstraints>,
     /// Immovable person assignments: `(person_index, session_index) -> group_index`
     pub immovable_people: HashMap<(usize, usize), usize>,
+    /// Resolved `BaselineMoveLimit` constraints (hard, enforced via move feasibility)
+    pub(crate) baseline_move_limits: Vec<ResolvedBaselineMoveLimit>,
+    /// Baseline group per `[session][person]` from `initial_schedule` (empty without move limits)
+    pub baseline_groups: Vec<Vec<Option<usize>>>,
+    /// Number of participating people per session whose group differs from the baseline
+    pub baseline_moved_counts: Vec<u32>,
+    /// Dense `[session * people + person] -> sorted allowed groups` from
+    /// `GroupAttributeRequirement` and `TrackGrouping` (empty entry = unrestricted; empty
+    /// table = no requirements)
+    pub allowed_groups_by_person_session: Vec<Vec<usize>>,
+    /// Sorted track names from `TrackGrouping` (empty without tracks)
+    pub(crate) track_names: Vec<String>,
+    /// Track index of each group (empty without tracks)
+    pub(crate) track_of_group: Vec<usize>,
+    /// Current track of each person (empty without tracks)
+    pub(crate) person_tracks: Vec<usize>,
+    /// Tracks each person may be in, by track index (empty without tracks)
+    pub(crate) track_candidates: Vec<Vec<bool>>,
+    /// `allowed_groups_by_person_session` before the track restriction (empty without tracks)
+    pub(crate) untracked_allowed_groups: Vec<Vec<usize>>,
+    /// People who may swap tracks, pooled by identical session attendance
+    pub(crate) track_swap_pools: Vec<Vec<usize>>,
+    /// Resolved `UniqueGroupComposition` constraint, if any (soft)
+    pub(crate) unique_group_composition: Option<ResolvedUniqueGroupComposition>,
+    /// Occurrences of each group composition fingerprint within the covered sessions
+    pub(crate) group_composition_counts: HashMap<group_composition::CompositionKey, u32>,
+    /// Number of extra occurrences of already-seen group compositions
+    pub repeated_group_compositions: i32,
     /// Which sessions each clique constraint applies to (None = all sessions)
     pub clique_sessions: Vec<Option<Vec<usize>>>,
     /// Which sessions each soft-apart pair constraint applies to (None = all sessions)
@@ -250,14 +324,32 @@ pub struct State {
 
     // === SCORING DATA ===
     // Current optimization scores, updated incrementally for performance
-    /// Contact matrix: `contact_matrix[person1][person2] = number_of_encounters`
-    pub contact_matrix: Vec<Vec<u32>>,
+    /// Contact matrix: `contact_matrix.get(person1, person2) = number_of_encounters`
+    /// (including encounters pre-seeded from `contact_history`)
+    pub contact_matrix: ContactMatrix,
+    /// Sorted `(person_a, person_b, count)` encounters from `contact_history`, with decayed
+    /// weights rounded up
+    pub historical_contacts: Vec<(usize, usize, u32)>,
+    /// Per-person sorted `(partner, fraction)` for pairs whose decayed history weight is
+    /// fractional (`fraction` in `(-1, 0)`); empty when no pair needs a correction
+    pub(crate) historical_fractions_by_person: Vec<Vec<(usize, f64)>>,
+    /// Weighted repetition-penalty correction for fractional history weights
+    pub history_decay_correction: f64,
     /// Current number of unique person-to-person contacts
     pub unique_contacts: i32,
+    /// Extra unique-contact credit from `person_weights` beyond one per met pair
+    pub vip_contact_bonus: f64,
+    /// Per-pair sum of the session weights of every meeting, history included (empty when
+    /// `session_weights` is not configured)
+    pub(crate) session_weighted_contacts: Vec<Vec<f64>>,
+    /// Cost difference of `session_weights` against unweighted contacts and repetitions
+    pub session_weight_correction: f64,
     /// Current penalty for exceeding repeat encounter limits
     pub repetition_penalty: i32,
     /// Current penalty for attribute balance violations
     pub attribute_balance_penalty: f64,
+    /// Current weighted penalty for uneven group sizes (`balance_group_sizes` objective)
+    pub group_fill_penalty: f64,
     /// Total constraint penalty (sum of individual constraint penalties)
     pub constraint_penalty: i32,
     /// Weighted constraint penalty (actual penalty value used in cost calculation)
@@ -280,6 +372,12 @@ pub struct State {
     // Weights for different components of the objective function
     /// Weight for maximizing unique contacts (from objectives)
     pub w_contacts: f64,
+    /// Per-person contact weights from `person_weights` (empty when unweighted)
+    pub(crate) contact_weights: Vec<f64>,
+    /// Per-session weights from `session_weights` (empty when unweighted)
+    pub(crate) session_weights: Vec<f64>,
+    /// Weight for balancing group sizes per session (from objectives)
+    pub w_group_fill: f64,
     /// Weight for repeat encounter penalties (from constraints)
     pub w_repetition: f64,
     /// Maximum allowed pair encounters before repetition penalties begin.
@@ -305,6 +403,8 @@ pub struct State {
     pub pairmin_counts: Vec<u32>,
     /// Penalty modes per constraint
     pub pairmin_modes: Vec<PairMeetingMode>,
+    /// Input constraint behind each soft-constraint entry, for per-constraint penalties
+    pub(crate) constraint_sources: ConstraintSources,
 
     /// Baseline score to prevent negative scores from unique contacts metric
     pub baseline_score: f64,
@@ -328,68 +428,121 @@ impl State {
         self.validate_no_duplicate_assignments()
             .unwrap_or_else(|error| panic!("cache drift check failed in {context}: {error}"));
 
+        if let Some(drift) = self.first_cache_drift() {
+            panic!("cache drift in {context}: {drift}");
+        }
+    }
+
+    /// Re-scores a copy of the state from scratch and describes the first incrementally
+    /// maintained component that differs from the recalculated value, if any.
+    pub(crate) fn first_cache_drift(&self) -> Option<String> {
         let mut recalculated = self.clone();
         recalculated._recalculate_scores();
 
-        assert_eq!(
-            self.unique_contacts, recalculated.unique_contacts,
-            "cache drift in {context}: unique_contacts cached={} recalculated={}",
-            self.unique_contacts, recalculated.unique_contacts
-        );
-        assert_eq!(
-            self.repetition_penalty, recalculated.repetition_penalty,
-            "cache drift in {context}: repetition_penalty cached={} recalculated={}",
-            self.repetition_penalty, recalculated.repetition_penalty
-        );
-        assert_eq!(
-            self.constraint_penalty, recalculated.constraint_penalty,
-            "cache drift in {context}: constraint_penalty cached={} recalculated={}",
-            self.constraint_penalty, recalculated.constraint_penalty
-        );
-        assert_eq!(
-            self.clique_violations, recalculated.clique_violations,
-            "cache drift in {context}: clique_violations mismatch"
-        );
-        assert_eq!(
-            self.soft_apart_pair_violations, recalculated.soft_apart_pair_violations,
-            "cache drift in {context}: soft_apart_pair_violations mismatch"
-        );
-        assert_eq!(
-            self.hard_apart_pair_violations, recalculated.hard_apart_pair_violations,
-            "cache drift in {context}: hard_apart_pair_violations mismatch"
-        );
-        assert_eq!(
-            self.should_together_violations, recalculated.should_together_violations,
-            "cache drift in {context}: should_together_violations mismatch"
-        );
-        assert_eq!(
-            self.immovable_violations, recalculated.immovable_violations,
-            "cache drift in {context}: immovable_violations cached={} recalculated={}",
-            self.immovable_violations, recalculated.immovable_violations
-        );
-        assert_eq!(
-            self.pairmin_counts, recalculated.pairmin_counts,
-            "cache drift in {context}: pairmin_counts mismatch"
-        );
-        assert!(
-            (self.attribute_balance_penalty - recalculated.attribute_balance_penalty).abs() < 1e-9,
-            "cache drift in {context}: attribute_balance_penalty cached={} recalculated={}",
-            self.attribute_balance_penalty,
-            recalculated.attribute_balance_penalty
-        );
-        assert!(
-            (self.weighted_constraint_penalty - recalculated.weighted_constraint_penalty).abs()
-                < 1e-9,
-            "cache drift in {context}: weighted_constraint_penalty cached={} recalculated={}",
-            self.weighted_constraint_penalty,
-            recalculated.weighted_constraint_penalty
-        );
-        assert!(
-            (self.current_cost - recalculated.current_cost).abs() < 1e-9,
-            "cache drift in {context}: current_cost cached={} recalculated={}",
-            self.current_cost,
-            recalculated.current_cost
-        );
+        fn exact<T: PartialEq + std::fmt::Debug>(
+            name: &str,
+            cached: &T,
+            recalculated: &T,
+        ) -> Option<String> {
+            (cached != recalculated)
+                .then(|| format!("{name} cached={cached:?} recalculated={recalculated:?}"))
+        }
+        fn close(name: &str, cached: f64, recalculated: f64, tolerance: f64) -> Option<String> {
+            ((cached - recalculated).abs() >= tolerance)
+                .then(|| format!("{name} cached={cached} recalculated={recalculated}"))
+        }
+
+        [
+            exact(
+                "unique_contacts",
+                &self.unique_contacts,
+                &recalculated.unique_contacts,
+            ),
+            exact(
+                "repetition_penalty",
+                &self.repetition_penalty,
+                &recalculated.repetition_penalty,
+            ),
+            exact(
+                "constraint_penalty",
+                &self.constraint_penalty,
+                &recalculated.constraint_penalty,
+            ),
+            exact(
+                "clique_violations",
+                &self.clique_violations,
+                &recalculated.clique_violations,
+            ),
+            exact(
+                "soft_apart_pair_violations",
+                &self.soft_apart_pair_violations,
+                &recalculated.soft_apart_pair_violations,
+            ),
+            exact(
+                "hard_apart_pair_violations",
+                &self.hard_apart_pair_violations,
+                &recalculated.hard_apart_pair_violations,
+            ),
+            exact(
+                "should_together_violations",
+                &self.should_together_violations,
+                &recalculated.should_together_violations,
+            ),
+            exact(
+                "immovable_violations",
+                &self.immovable_violations,
+                &recalculated.immovable_violations,
+            ),
+            exact(
+                "repeated_group_compositions",
+                &self.repeated_group_compositions,
+                &recalculated.repeated_group_compositions,
+            ),
+            exact(
+                "pairmin_counts",
+                &self.pairmin_counts,
+                &recalculated.pairmin_counts,
+            ),
+            close(
+                "vip_contact_bonus",
+                self.vip_contact_bonus,
+                recalculated.vip_contact_bonus,
+                1e-9,
+            ),
+            close(
+                "session_weight_correction",
+                self.session_weight_correction,
+                recalculated.session_weight_correction,
+                1e-6,
+            ),
+            close(
+                "attribute_balance_penalty",
+                self.attribute_balance_penalty,
+                recalculated.attribute_balance_penalty,
+                1e-9,
+            ),
+            close(
+                "group_fill_penalty",
+                self.group_fill_penalty,
+                recalculated.group_fill_penalty,
+                1e-9,
+            ),
+            close(
+                "weighted_constraint_penalty",
+                self.weighted_constraint_penalty,
+                recalculated.weighted_constraint_penalty,
+                1e-9,
+            ),
+            close(
+                "current_cost",
+                self.current_cost,
+                recalculated.current_cost,
+                1e-9,
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .next()
     }
 
     #[inline]
@@ -459,6 +612,12 @@ impl State {
             .penalty_for_excess(count.saturating_sub(self.repeat_encounter_limit))
     }
 
+    /// Weighted repetition penalty, including the decayed contact-history correction.
+    #[inline]
+    pub fn weighted_repetition_penalty(&self) -> f64 {
+        self.repetition_penalty as f64 * self.w_repetition + self.history_decay_correction
+    }
+
     /// Returns a human-friendly identifier for a person index.
     /// If the person has a `name` attribute, this returns "{name} ({id})"; otherwise just the ID.
     pub fn display_person_by_idx(&self, person_idx: usize) -> String {
@@ -499,8 +658,8 @@ impl State {
 
     pub fn _recalculate_scores(&mut self) {
         // Reset contact matrix
-        let people_count = self.person_idx_to_id.len();
-        self.contact_matrix = vec![vec![0; people_count]; people_count];
+        self.contact_matrix.clear();
+        self.seed_contact_matrix_from_history();
 
         // Calculate contacts only between participating people
         for (day_idx, day_schedule) in self.schedule.iter().enumerate() {
@@ -514,48 +673,173 @@ impl State {
                         if self.person_participation[person1][day_idx]
                             && self.person_participation[person2][day_idx]
                         {
-                            self.contact_matrix[person1][person2] += 1;
-                            self.contact_matrix[person2][person1] += 1;
+                            self.contact_matrix.increment(person1, person2);
                         }
                     }
                 }
             }
         }
 
-        // Calculate unique contacts (count pairs with at least 1 contact)
-        self.unique_contacts = 0;
-        for i in 0..people_count {
-            for j in (i + 1)..people_count {
-                if self.contact_matrix[i][j] > 0 {
-                    self.unique_contacts += 1;
-                }
-            }
-        }
+        // Unique contacts (pairs with at least 1 contact) and repetition penalty
+        (self.unique_contacts, self.repetition_penalty) = self.pair_contact_totals();
 
-        // Calculate repetition penalty (squared penalty for multiple contacts)
-        self.repetition_penalty = 0;
-        for i in 0..people_count {
-            for j in (i + 1)..people_count {
-                self.repetition_penalty +=
-                    self.repetition_penalty_for_contact_count(self.contact_matrix[i][j]);
-            }
-        }
+        self.recalculate_vip_contact_bonus();
+
+        self.recalculate_history_decay_correction();
+        self.recalculate_session_weighted_contacts();
 
         // Recalculate attribute balance penalty
         self._recalculate_attribute_balance_penalty();
+        self.recalculate_group_fill_penalty();
 
         // Recalculate constraint penalties
         self._recalculate_constraint_penalty();
 
         self.recalculate_pairmin_counts();
+        self.recalculate_baseline_moved_counts();
+        self.recalculate_group_composition_counts();
 
         // Keep the legacy unweighted constraint counter consistent with calculate_cost()
         self._update_constraint_penalty_total();
 
-        self.current_cost = self.calculate_cost();
+        // Every cache was just rebuilt, so the cost is a sum over them; the schedule-wide
+        // rescan of `calculate_cost` is only repeated as a cross-check in debug builds.
+        self.refresh_cost_from_caches();
+        #[cfg(debug_assertions)]
+        {
+            let cost_from_caches = self.current_cost;
+            let rescanned_cost = self.calculate_cost();
+            debug_assert!(
+                (rescanned_cost - cost_from_caches).abs() <= 1e-6 * rescanned_cost.abs().max(1.0),
+                "cost from caches {cost_from_caches} differs from rescanned cost {rescanned_cost}"
+            );
+        }
     }
 
+    /// Unique-contact count and repetition penalty summed over every pair of people.
+    ///
+    /// With the `parallel-rescoring` feature, populations of at least
+    /// [`PARALLEL_RESCORING_MIN_PEOPLE`] are summed row by row on the rayon pool.
+    fn pair_contact_totals(&self) -> (i32, i32) {
+        let people_count = self.person_idx_to_id.len();
+        let row_totals = |i: usize| {
+            let mut unique_contacts = 0;
+            let mut repetition_penalty = 0;
+            for j in (i + 1)..people_count {
+                if self.contact_matrix.has_met(i, j) {
+                    unique_contacts += 1;
+                }
+                repetition_penalty +=
+                    self.repetition_penalty_for_contact_count(self.contact_matrix.get(i, j));
+            }
+            (unique_contacts, repetition_penalty)
+        };
+        let add = |a: (i32, i32), b: (i32, i32)| (a.0 + b.0, a.1 + b.1);
+
+        #[cfg(feature = "parallel-rescoring")]
+        if people_count >= PARALLEL_RESCORING_MIN_PEOPLE {
+            use rayon::prelude::*;
+            return (0..people_count)
+                .into_par_iter()
+                .map(row_totals)
+                .reduce(|| (0, 0), add);
+        }
+
+        (0..people_count).map(row_totals).fold((0, 0), add)
+    }
+
+    /// Recomputes `weighted_constraint_penalty` from the cached violation counts, then
+    /// `current_cost`.
     pub(crate) fn refresh_cost_from_caches(&mut self) {
+        self.weighted_constraint_penalty = self.weighted_constraint_penalty_from_caches();
+        self.refresh_current_cost();
+    }
+
+    /// Sums `current_cost` from the cached score components, trusting
+    /// `weighted_constraint_penalty` as maintained by the move.
+    pub(crate) fn refresh_current_cost(&mut self) {
+        self.current_cost = self.weighted_repetition_penalty()
+            + self.attribute_balance_penalty
+            + self.group_fill_penalty
+            + self.weighted_constraint_penalty
+            - (self.weighted_unique_contacts() * self.w_contacts)
+            + self.session_weight_correction
+            + self.baseline_score;
+    }
+
+    /// Soft-apart pairs active on `day` that link `person` to another member of `group`,
+    /// ignoring `skip`.
+    ///
+    /// Walks whichever is shorter, the person's soft-apart partners or the group, so people
+    /// with thousands of exclusions cost no more than the group size.
+    pub(crate) fn soft_apart_pairs_in_group(
+        &self,
+        day: usize,
+        person: usize,
+        group: usize,
+        skip: usize,
+    ) -> SmallVec<[usize; 4]> {
+        let constraints = &self.pair_constraints_by_person[person];
+        let members = &self.schedule[day][group];
+        let applies = |pair_idx: usize| {
+            self.soft_apart_pair_sessions[pair_idx]
+                .as_ref()
+                .is_none_or(|sessions| sessions.contains(&day))
+        };
+
+        let mut pairs = SmallVec::new();
+        if constraints.soft_apart_partners.len() <= members.len() {
+            for &(partner, pair_idx) in &constraints.soft_apart_partners {
+                if partner != skip
+                    && self.person_participation[partner][day]
+                    && self.locations[day][partner].0 == group
+                    && applies(pair_idx)
+                {
+                    pairs.push(pair_idx);
+                }
+            }
+        } else {
+            for &member in members {
+                if member == person || member == skip {
+                    continue;
+                }
+                pairs.extend(
+                    constraints
+                        .soft_apart_with(member)
+                        .filter(|&idx| applies(idx)),
+                );
+            }
+        }
+        pairs
+    }
+
+    /// Total weight of [`State::soft_apart_pairs_in_group`].
+    pub(crate) fn soft_apart_weight_in_group(
+        &self,
+        day: usize,
+        person: usize,
+        group: usize,
+        skip: usize,
+    ) -> f64 {
+        self.soft_apart_pairs_in_group(day, person, group, skip)
+            .iter()
+            .map(|&pair_idx| self.soft_apart_pair_weights[pair_idx])
+            .sum()
+    }
+
+    /// Weighted penalty of pair-meeting constraint `idx` when its pair meets `have` times.
+    #[inline]
+    pub(crate) fn pairmin_penalty(&self, idx: usize, have: u32) -> f64 {
+        let target = self.pairmin_required[idx] as i32;
+        let have = have as i32;
+        (match self.pairmin_modes[idx] {
+            PairMeetingMode::AtLeast => (target - have).max(0) as f64,
+            PairMeetingMode::Exact => (have - target).abs() as f64,
+            PairMeetingMode::AtMost => (have - target).max(0) as f64,
+        }) * self.pairmin_weights[idx]
+    }
+
+    fn weighted_constraint_penalty_from_caches(&self) -> f64 {
         let mut weighted_constraint_penalty = 0.0;
 
         for (idx, violations) in self.soft_apart_pair_violations.iter().enumerate() {
@@ -567,25 +851,11 @@ impl State {
         }
 
         for idx in 0..self.pairmin_pairs.len() {
-            let target = self.pairmin_required[idx] as i32;
-            let have = self.pairmin_counts[idx] as i32;
-            let penalty = match self.pairmin_modes[idx] {
-                PairMeetingMode::AtLeast => (target - have).max(0) as f64,
-                PairMeetingMode::Exact => (have - target).abs() as f64,
-                PairMeetingMode::AtMost => (have - target).max(0) as f64,
-            } * self.pairmin_weights[idx];
-
-            weighted_constraint_penalty += penalty;
+            weighted_constraint_penalty += self.pairmin_penalty(idx, self.pairmin_counts[idx]);
         }
 
         weighted_constraint_penalty += self.immovable_violations as f64 * 1000.0;
-
-        self.weighted_constraint_penalty = weighted_constraint_penalty;
-        self.current_cost = (self.repetition_penalty as f64 * self.w_repetition)
-            + self.attribute_balance_penalty
-            + self.weighted_constraint_penalty
-            - (self.unique_contacts as f64 * self.w_contacts)
-            + self.baseline_score;
+        weighted_constraint_penalty + self.weighted_group_composition_penalty()
     }
 
     pub(crate) fn recalculate_pairmin_counts(&mut self) {
@@ -608,6 +878,34 @@ impl State {
         }
     }
 
+    /// Converts the current schedule into the ID-keyed API format.
+    pub fn api_schedule(&self) -> ApiSchedule {
+        self.api_schedule_for(self.schedule.iter())
+    }
+
+    /// Converts `schedule`, given session by session as groups of person indices, into the
+    /// ID-keyed API format.
+    pub(crate) fn api_schedule_for<'a>(
+        &self,
+        schedule: impl Iterator<Item = &'a Vec<GroupMembers>>,
+    ) -> ApiSchedule {
+        let mut schedule_output = HashMap::new();
+        for (day, day_schedule) in schedule.enumerate() {
+            let session_key = format!("session_{}", day);
+            let mut group_map = HashMap::new();
+            for (group_idx, group) in day_schedule.iter().enumerate() {
+                let group_key = self.group_idx_to_id[group_idx].clone();
+                let person_ids = group
+                    .iter()
+                    .map(|&p_idx| self.person_idx_to_id[p_idx].clone())
+                    .collect();
+                group_map.insert(group_key, person_ids);
+            }
+            schedule_output.insert(session_key, group_map);
+        }
+        schedule_output
+    }
+
     /// Converts the current state to an API result format.
     ///
     /// This method transforms the internal integer-based representation back to
@@ -645,12 +943,13 @@ impl State {
     /// # let input = ApiInput {
     /// #     initial_schedule: None,
     /// #     construction_seed_schedule: None,
+    /// #     contact_history: None,
     /// #     problem: ProblemDefinition { people: vec![], groups: vec![], num_sessions: 1 },
     /// #     objectives: vec![], constraints: vec![],
     /// #     solver: SolverConfiguration {
     /// #         solver_type: "SimulatedAnnealing".to_string(),
-    /// #         stop_conditions: StopConditions { max_iterations: Some(1000), time_limit_seconds: None, no_improvement_iterations: None, stop_on_optimal_score: true },
-    /// #         solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams { initial_temperature: 10.0, final_temperature: 0.1, cooling_schedule: "geometric".to_string(), reheat_after_no_improvement: Some(0), reheat_cycles: Some(0)}),
+    /// #         stop_conditions: StopConditions { max_iterations: Some(1000), time_limit_seconds: None, no_improvement_iterations: None, stop_on_optimal_score: true, stop_on_feasible: false, feasible_score_threshold: None, convergence: None },
+    /// #         solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams { initial_temperature: 10.0, final_temperature: 0.1, cooling_schedule: "geometric".to_string(), reheat_after_no_improvement: Some(0), reheat_cycles: Some(0), penalty_ramp: None }),
     /// #         logging: LoggingOptions::default(),
     /// #         telemetry: Default::default(),
     /// #         seed: None,
@@ -685,29 +984,18 @@ impl State {
         stop_reason: Option<StopReason>,
         benchmark_telemetry: Option<SolverBenchmarkTelemetry>,
     ) -> SolverResult {
-        let mut schedule_output = HashMap::new();
-        for (day, day_schedule) in self.schedule.iter().enumerate() {
-            let session_key = format!("session_{}", day);
-            let mut group_map = HashMap::new();
-            for (group_idx, group) in day_schedule.iter().enumerate() {
-                let group_key = self.group_idx_to_id[group_idx].clone();
-                let person_ids = group
-                    .iter()
-                    .map(|&p_idx| self.person_idx_to_id[p_idx].clone())
-                    .collect();
-                group_map.insert(group_key, person_ids);
-            }
-            schedule_output.insert(session_key, group_map);
-        }
+        let schedule_output = self.api_schedule();
 
         // Use the already calculated weighted penalties
-        let weighted_repetition_penalty = self.repetition_penalty as f64 * self.w_repetition;
+        let weighted_repetition_penalty = self.weighted_repetition_penalty();
         let weighted_constraint_penalty = self.weighted_constraint_penalty;
+        let constraint_violations = self.constraint_violations();
+        let session_breakdown = self.session_breakdown(&constraint_violations);
 
         SolverResult {
             final_score,
             schedule: schedule_output,
-            unique_contacts: self.unique_contacts,
+            unique_contacts: self.scheduled_unique_contacts(),
             repetition_penalty: self.repetition_penalty,
             attribute_balance_penalty: self.attribute_balance_penalty as i32,
             constraint_penalty: self.constraint_penalty,
@@ -717,7 +1005,28 @@ impl State {
             effective_seed: Some(self.effective_seed),
             move_policy: Some(self.move_policy.clone()),
             stop_reason,
+            iterations_run: benchmark_telemetry
+                .as_ref()
+                .map(|telemetry| telemetry.iterations_completed),
+            time_elapsed_ms: benchmark_telemetry
+                .as_ref()
+                .map(SolverBenchmarkTelemetry::total_milliseconds),
+            final_temperature: None,
+            accepted_moves: benchmark_telemetry
+                .as_ref()
+                .map(SolverBenchmarkTelemetry::accepted_moves),
             benchmark_telemetry,
+            constraint_violations: Some(constraint_violations),
+            session_breakdown: Some(session_breakdown),
+            contact_matrix: None,
+            display_names: None,
+            person_attributes: None,
+            score_history: None,
+            move_statistics: None,
+            constraint_penalties: None,
+            effective_configuration: None,
+            unique_contacts_upper_bound: None,
+            optimality_gap: None,
         }
     }
 
@@ -881,6 +1190,11 @@ impl State {
             }
         }
 
+        // === REPEATED GROUP COMPOSITIONS ===
+        // Counted incrementally by the moves; see `group_composition`.
+        violation_count += self.repeated_group_compositions;
+        self.weighted_constraint_penalty += self.weighted_group_composition_penalty();
+
         // Verify the unweighted count matches our cached value
         debug_assert_eq!(
             violation_count, self.constraint_penalty,
@@ -888,10 +1202,12 @@ impl State {
             violation_count, self.constraint_penalty
         );
 
-        (self.repetition_penalty as f64 * self.w_repetition)
+        self.weighted_repetition_penalty()
             + self.attribute_balance_penalty
+            + self.group_fill_penalty
             + self.weighted_constraint_penalty
-            - (self.unique_contacts as f64 * self.w_contacts)
+            - (self.weighted_unique_contacts() * self.w_contacts)
+            + self.session_weight_correction
             + self.baseline_score
     }
 }
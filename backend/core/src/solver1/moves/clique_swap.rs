@@ -4,7 +4,8 @@
 //! stay together (a clique) to a different group, exchanging them with non-clique
 //! members from the target group.
 
-use super::super::State;
+use super::super::constraint_index::PersonPairConstraints;
+use super::super::{GroupMembers, State};
 use crate::models::PairMeetingMode;
 
 impl State {
@@ -83,6 +84,9 @@ impl State {
                     return false;
                 }
             }
+            if !self.group_allowed_for_person(day, member, to_group) {
+                return false;
+            }
         }
 
         let mut seen_targets = std::collections::HashSet::with_capacity(target_people.len());
@@ -92,6 +96,7 @@ impl State {
                 || !self.person_participation[person][day]
                 || self.locations[day][person].0 != to_group
                 || self.person_to_clique_id[day][person].is_some()
+                || !self.group_allowed_for_person(day, person, from_group)
             {
                 return false;
             }
@@ -108,38 +113,45 @@ impl State {
 
     fn update_contact_cache_for_clique_swap_pair(
         &mut self,
+        day: usize,
         person_a: usize,
         person_b: usize,
         delta: i32,
     ) {
-        let old_count = self.contact_matrix[person_a][person_b];
+        let old_count = self.contact_matrix.get(person_a, person_b);
 
         if delta < 0 {
             if old_count == 0 {
                 return;
             }
 
-            self.contact_matrix[person_a][person_b] -= 1;
-            self.contact_matrix[person_b][person_a] -= 1;
+            self.contact_matrix.decrement(person_a, person_b);
 
             if old_count == 1 {
                 self.unique_contacts -= 1;
+                self.vip_contact_bonus -= self.vip_contact_bonus_for(person_a, person_b);
             }
 
             let old_penalty = self.repetition_penalty_for_contact_count(old_count);
             let new_penalty = self.repetition_penalty_for_contact_count(old_count - 1);
             self.repetition_penalty += new_penalty - old_penalty;
+            self.history_decay_correction +=
+                self.history_correction_delta(person_b, person_a, old_count, old_count - 1);
+            self.record_session_weighted_contact(person_a, person_b, day, old_count, old_count - 1);
         } else {
-            self.contact_matrix[person_a][person_b] += 1;
-            self.contact_matrix[person_b][person_a] += 1;
+            self.contact_matrix.increment(person_a, person_b);
 
             if old_count == 0 {
                 self.unique_contacts += 1;
+                self.vip_contact_bonus += self.vip_contact_bonus_for(person_a, person_b);
             }
 
             let old_penalty = self.repetition_penalty_for_contact_count(old_count);
             let new_penalty = self.repetition_penalty_for_contact_count(old_count + 1);
             self.repetition_penalty += new_penalty - old_penalty;
+            self.history_decay_correction +=
+                self.history_correction_delta(person_b, person_a, old_count, old_count + 1);
+            self.record_session_weighted_contact(person_a, person_b, day, old_count, old_count + 1);
         }
     }
 
@@ -148,7 +160,7 @@ impl State {
         old_to: &[usize],
         active_members: &[usize],
         target_people: &[usize],
-    ) -> (Vec<usize>, Vec<usize>, Vec<usize>, Vec<usize>) {
+    ) -> (Vec<usize>, Vec<usize>, GroupMembers, GroupMembers) {
         let source_remaining: Vec<usize> = old_from
             .iter()
             .copied()
@@ -160,15 +172,28 @@ impl State {
             .filter(|person| !target_people.contains(person))
             .collect();
 
-        let mut new_from = source_remaining.clone();
+        let mut new_from = GroupMembers::from_slice(&source_remaining);
         new_from.extend_from_slice(target_people);
 
-        let mut new_to = target_remaining.clone();
+        let mut new_to = GroupMembers::from_slice(&target_remaining);
         new_to.extend_from_slice(active_members);
 
         (source_remaining, target_remaining, new_from, new_to)
     }
 
+    fn clique_swap_baseline_moves(
+        active_members: &[usize],
+        target_people: &[usize],
+        from_group: usize,
+        to_group: usize,
+    ) -> Vec<(usize, usize)> {
+        active_members
+            .iter()
+            .map(|&member| (member, to_group))
+            .chain(target_people.iter().map(|&person| (person, from_group)))
+            .collect()
+    }
+
     fn clique_swap_has_hard_apart_conflict(
         &self,
         day: usize,
@@ -181,13 +206,33 @@ impl State {
             || self.block_has_hard_apart_conflict(day, target_people, source_remaining)
     }
 
+    /// Sorted, deduplicated indices of the pair constraints selected by `select` for `people`.
+    fn clique_swap_pair_constraints<'a>(
+        &'a self,
+        people: &[usize],
+        select: impl Fn(&'a PersonPairConstraints) -> &'a Vec<usize>,
+    ) -> Vec<usize> {
+        let mut indices: Vec<usize> = people
+            .iter()
+            .flat_map(|&person| {
+                select(&self.pair_constraints_by_person[person])
+                    .iter()
+                    .copied()
+            })
+            .collect();
+        indices.sort_unstable();
+        indices.dedup();
+        indices
+    }
+
     fn contact_delta_for_clique_swap_pair(
         &self,
+        day: usize,
         person_a: usize,
         person_b: usize,
         direction: i32,
     ) -> f64 {
-        let count = self.contact_matrix[person_a][person_b];
+        let count = self.contact_matrix.get(person_a, person_b);
 
         if direction < 0 && count == 0 {
             return 0.0;
@@ -198,13 +243,15 @@ impl State {
         let new_penalty = self.repetition_penalty_for_contact_count(new_count);
 
         let mut delta_cost = self.w_repetition * (new_penalty - old_penalty) as f64;
+        delta_cost += self.history_correction_delta(person_a, person_b, count, new_count);
+        delta_cost += self.session_weight_pair_delta(person_a, person_b, day, direction);
 
         if direction < 0 && count == 1 {
-            delta_cost += self.w_contacts;
+            delta_cost += self.pair_contact_value(person_a, person_b);
         }
 
         if direction > 0 && count == 0 {
-            delta_cost -= self.w_contacts;
+            delta_cost -= self.pair_contact_value(person_a, person_b);
         }
 
         delta_cost
@@ -246,6 +293,9 @@ impl State {
                     return false;
                 }
             }
+            if !self.group_allowed_for_person(day, member, to_group) {
+                return false;
+            }
         }
 
         // Need at least as many non-clique movable people in target group as active clique size
@@ -253,6 +303,49 @@ impl State {
         non_clique_people_in_to_group.len() >= active_members.len()
     }
 
+    /// Objective-only part of [`Self::calculate_clique_swap_cost_delta`]: the unique-contacts
+    /// change without any penalty terms, used to rescale penalties during a penalty ramp.
+    pub(crate) fn calculate_clique_swap_objective_delta(
+        &self,
+        day: usize,
+        clique_idx: usize,
+        from_group: usize,
+        to_group: usize,
+        target_people: &[usize],
+    ) -> f64 {
+        let active_members = self.active_clique_members_for_group(day, clique_idx, from_group);
+        let (source_remaining, target_remaining, _, _) = Self::clique_swap_group_members_after(
+            &self.schedule[day][from_group],
+            &self.schedule[day][to_group],
+            &active_members,
+            target_people,
+        );
+
+        let mut delta = 0.0;
+        for (movers, left_behind, joined) in [
+            (
+                active_members.as_slice(),
+                &source_remaining,
+                &target_remaining,
+            ),
+            (target_people, &target_remaining, &source_remaining),
+        ] {
+            for &person in movers {
+                for &other in left_behind {
+                    if self.person_participation[other][day] {
+                        delta += self.unique_contact_pair_delta(person, other, day, -1);
+                    }
+                }
+                for &other in joined {
+                    if self.person_participation[other][day] {
+                        delta += self.unique_contact_pair_delta(person, other, day, 1);
+                    }
+                }
+            }
+        }
+        delta
+    }
+
     /// Calculate the cost delta for swapping a clique with non-clique people
     pub fn calculate_clique_swap_cost_delta(
         &self,
@@ -295,17 +388,35 @@ impl State {
             return f64::INFINITY;
         }
 
-        let mut delta_cost = 0.0;
+        if !self.baseline_move_limits.is_empty() {
+            let baseline_moves = Self::clique_swap_baseline_moves(
+                &active_members,
+                target_people,
+                from_group,
+                to_group,
+            );
+            if !self.baseline_move_limits_allow(day, &baseline_moves) {
+                return f64::INFINITY;
+            }
+        }
+
+        let mut delta_cost = self.group_composition_cost_delta(
+            day,
+            from_group,
+            to_group,
+            &active_members,
+            target_people,
+        );
 
         for &member in &active_members {
             for &other in &source_remaining {
                 if self.person_participation[other][day] {
-                    delta_cost += self.contact_delta_for_clique_swap_pair(member, other, -1);
+                    delta_cost += self.contact_delta_for_clique_swap_pair(day, member, other, -1);
                 }
             }
             for &other in &target_remaining {
                 if self.person_participation[other][day] {
-                    delta_cost += self.contact_delta_for_clique_swap_pair(member, other, 1);
+                    delta_cost += self.contact_delta_for_clique_swap_pair(day, member, other, 1);
                 }
             }
         }
@@ -313,12 +424,12 @@ impl State {
         for &person in target_people {
             for &other in &target_remaining {
                 if self.person_participation[other][day] {
-                    delta_cost += self.contact_delta_for_clique_swap_pair(person, other, -1);
+                    delta_cost += self.contact_delta_for_clique_swap_pair(day, person, other, -1);
                 }
             }
             for &other in &source_remaining {
                 if self.person_participation[other][day] {
-                    delta_cost += self.contact_delta_for_clique_swap_pair(person, other, 1);
+                    delta_cost += self.contact_delta_for_clique_swap_pair(day, person, other, 1);
                 }
             }
         }
@@ -333,7 +444,14 @@ impl State {
             }
         };
 
-        for (pair_idx, &(person_a, person_b)) in self.soft_apart_pairs.iter().enumerate() {
+        let moved_people: Vec<usize> = active_members
+            .iter()
+            .chain(target_people)
+            .copied()
+            .collect();
+
+        for pair_idx in self.clique_swap_pair_constraints(&moved_people, |c| &c.soft_apart) {
+            let (person_a, person_b) = self.soft_apart_pairs[pair_idx];
             if let Some(ref sessions) = self.soft_apart_pair_sessions[pair_idx] {
                 if !sessions.contains(&day) {
                     continue;
@@ -346,14 +464,6 @@ impl State {
                 continue;
             }
 
-            if !active_members.contains(&person_a)
-                && !active_members.contains(&person_b)
-                && !target_people.contains(&person_a)
-                && !target_people.contains(&person_b)
-            {
-                continue;
-            }
-
             let were_together = self.locations[day][person_a].0 == self.locations[day][person_b].0;
             let are_together =
                 moved_person_group_after(person_a) == moved_person_group_after(person_b);
@@ -365,7 +475,8 @@ impl State {
             }
         }
 
-        for (pair_idx, &(person_a, person_b)) in self.should_together_pairs.iter().enumerate() {
+        for pair_idx in self.clique_swap_pair_constraints(&moved_people, |c| &c.should_together) {
+            let (person_a, person_b) = self.should_together_pairs[pair_idx];
             if let Some(ref sessions) = self.should_together_sessions[pair_idx] {
                 if !sessions.contains(&day) {
                     continue;
@@ -378,14 +489,6 @@ impl State {
                 continue;
             }
 
-            if !active_members.contains(&person_a)
-                && !active_members.contains(&person_b)
-                && !target_people.contains(&person_a)
-                && !target_people.contains(&person_b)
-            {
-                continue;
-            }
-
             let was_violation = self.locations[day][person_a].0 != self.locations[day][person_b].0;
             let is_violation =
                 moved_person_group_after(person_a) != moved_person_group_after(person_b);
@@ -397,7 +500,8 @@ impl State {
             }
         }
 
-        for (pair_idx, &(person_a, person_b)) in self.pairmin_pairs.iter().enumerate() {
+        for pair_idx in self.clique_swap_pair_constraints(&moved_people, |c| &c.pair_meeting) {
+            let (person_a, person_b) = self.pairmin_pairs[pair_idx];
             if !self.pairmin_sessions[pair_idx].contains(&day) {
                 continue;
             }
@@ -408,14 +512,6 @@ impl State {
                 continue;
             }
 
-            if !active_members.contains(&person_a)
-                && !active_members.contains(&person_b)
-                && !target_people.contains(&person_a)
-                && !target_people.contains(&person_b)
-            {
-                continue;
-            }
-
             let were_together = self.locations[day][person_a].0 == self.locations[day][person_b].0;
             let are_together =
                 moved_person_group_after(person_a) == moved_person_group_after(person_b);
@@ -525,6 +621,27 @@ impl State {
             return;
         }
 
+        if !self.baseline_move_limits.is_empty() {
+            let baseline_moves = Self::clique_swap_baseline_moves(
+                &active_members,
+                target_people,
+                from_group,
+                to_group,
+            );
+            if !self.baseline_move_limits_allow(day, &baseline_moves) {
+                return;
+            }
+            let baseline_delta = self.baseline_move_delta(day, &baseline_moves);
+            self.record_baseline_move_delta(day, baseline_delta);
+        }
+        self.record_group_composition_exchange(
+            day,
+            from_group,
+            to_group,
+            &active_members,
+            target_people,
+        );
+
         let current_groups: Vec<usize> = self.locations[day]
             .iter()
             .map(|&(group_idx, _)| group_idx)
@@ -542,12 +659,12 @@ impl State {
         for &member in &active_members {
             for &other in &source_remaining {
                 if self.person_participation[other][day] {
-                    self.update_contact_cache_for_clique_swap_pair(member, other, -1);
+                    self.update_contact_cache_for_clique_swap_pair(day, member, other, -1);
                 }
             }
             for &other in &target_remaining {
                 if self.person_participation[other][day] {
-                    self.update_contact_cache_for_clique_swap_pair(member, other, 1);
+                    self.update_contact_cache_for_clique_swap_pair(day, member, other, 1);
                 }
             }
         }
@@ -555,17 +672,23 @@ impl State {
         for &person in target_people {
             for &other in &target_remaining {
                 if self.person_participation[other][day] {
-                    self.update_contact_cache_for_clique_swap_pair(person, other, -1);
+                    self.update_contact_cache_for_clique_swap_pair(day, person, other, -1);
                 }
             }
             for &other in &source_remaining {
                 if self.person_participation[other][day] {
-                    self.update_contact_cache_for_clique_swap_pair(person, other, 1);
+                    self.update_contact_cache_for_clique_swap_pair(day, person, other, 1);
                 }
             }
         }
 
-        for (pair_idx, &(person_a, person_b)) in self.soft_apart_pairs.iter().enumerate() {
+        let moved_people: Vec<usize> = active_members
+            .iter()
+            .chain(target_people)
+            .copied()
+            .collect();
+        for pair_idx in self.clique_swap_pair_constraints(&moved_people, |c| &c.soft_apart) {
+            let (person_a, person_b) = self.soft_apart_pairs[pair_idx];
             if let Some(ref sessions) = self.soft_apart_pair_sessions[pair_idx] {
                 if !sessions.contains(&day) {
                     continue;
@@ -578,14 +701,6 @@ impl State {
                 continue;
             }
 
-            if !active_members.contains(&person_a)
-                && !active_members.contains(&person_b)
-                && !target_people.contains(&person_a)
-                && !target_people.contains(&person_b)
-            {
-                continue;
-            }
-
             let were_together = self.locations[day][person_a].0 == self.locations[day][person_b].0;
             let are_together =
                 moved_person_group_after(person_a) == moved_person_group_after(person_b);
@@ -672,18 +787,18 @@ impl State {
                     let mut seen = std::collections::HashSet::new();
                     for &p in &new_from {
                         if !seen.insert(p) {
-                            eprintln!(
-                                "[DEBUG] Duplicate in new_from: {}",
-                                self.display_person_by_idx(p)
+                            tracing::debug!(
+                                person = %self.display_person_by_idx(p),
+                                "duplicate in clique swap new_from"
                             );
                         }
                     }
                     seen.clear();
                     for &p in &new_to {
                         if !seen.insert(p) {
-                            eprintln!(
-                                "[DEBUG] Duplicate in new_to: {}",
-                                self.display_person_by_idx(p)
+                            tracing::debug!(
+                                person = %self.display_person_by_idx(p),
+                                "duplicate in clique swap new_to"
                             );
                         }
                     }
@@ -748,13 +863,13 @@ impl State {
             if self.logging.debug_validate_invariants {
                 if let Err(e) = self.validate_no_duplicate_assignments() {
                     if self.logging.debug_dump_invariant_context {
-                        eprintln!(
-                            "[DEBUG] Invariant failed after clique swap day={} from={} to={} moved_clique_size={} target_size={}",
+                        tracing::debug!(
                             day,
-                            self.group_idx_to_id[from_group],
-                            self.group_idx_to_id[to_group],
-                            active_members.len(),
-                            target_people.len()
+                            from = %self.group_idx_to_id[from_group],
+                            to = %self.group_idx_to_id[to_group],
+                            moved_clique_size = active_members.len(),
+                            target_size = target_people.len(),
+                            "invariant failed after clique swap"
                         );
                     }
                     // Surface error up-stack on next algorithm-side check
@@ -3,6 +3,7 @@
 //! This module implements the swap move - exchanging two people between different groups
 //! within the same session. This is the fundamental move operation for the optimization.
 
+use super::super::constraint_index::merged_pair_indices;
 use super::super::State;
 use crate::models::PairMeetingMode;
 
@@ -22,7 +23,7 @@ impl State {
                 continue;
             }
 
-            let count = self.contact_matrix[person_idx][member];
+            let count = self.contact_matrix.get(person_idx, member);
             if direction < 0 && count == 0 {
                 continue;
             }
@@ -31,18 +32,49 @@ impl State {
             let old_penalty = self.repetition_penalty_for_contact_count(count);
             let new_penalty = self.repetition_penalty_for_contact_count(new_count);
             delta_cost += self.w_repetition * (new_penalty - old_penalty) as f64;
+            delta_cost += self.history_correction_delta(person_idx, member, count, new_count);
+            delta_cost += self.session_weight_pair_delta(person_idx, member, day, direction);
 
             if direction < 0 && count == 1 {
-                delta_cost += self.w_contacts;
+                delta_cost += self.pair_contact_value(person_idx, member);
             }
             if direction > 0 && count == 0 {
-                delta_cost -= self.w_contacts;
+                delta_cost -= self.pair_contact_value(person_idx, member);
             }
         }
 
         delta_cost
     }
 
+    /// Objective-only part of [`Self::calculate_swap_cost_delta`]: the unique-contacts change
+    /// without any penalty terms, used to rescale penalties during a penalty ramp.
+    pub(crate) fn calculate_swap_objective_delta(
+        &self,
+        day: usize,
+        p1_idx: usize,
+        p2_idx: usize,
+    ) -> f64 {
+        let (g1_idx, _) = self.locations[day][p1_idx];
+        let (g2_idx, _) = self.locations[day][p2_idx];
+        let g1_members = &self.schedule[day][g1_idx];
+        let g2_members = &self.schedule[day][g2_idx];
+
+        let mut delta = 0.0;
+        for (person_idx, members, excluded, direction) in [
+            (p1_idx, g1_members, p1_idx, -1),
+            (p1_idx, g2_members, p2_idx, 1),
+            (p2_idx, g2_members, p2_idx, -1),
+            (p2_idx, g1_members, p1_idx, 1),
+        ] {
+            for &member in members {
+                if member != excluded && self.person_participation[member][day] {
+                    delta += self.unique_contact_pair_delta(person_idx, member, day, direction);
+                }
+            }
+        }
+        delta
+    }
+
     /// Calculates the change in the total cost function if a swap were to be performed.
     ///
     /// This is the core method for evaluating potential moves during optimization.
@@ -90,12 +122,13 @@ impl State {
     /// # let input = ApiInput {
     /// #     initial_schedule: None,
     /// #     construction_seed_schedule: None,
+    /// #     contact_history: None,
     /// #     problem: ProblemDefinition { people: vec![], groups: vec![], num_sessions: 1 },
     /// #     objectives: vec![], constraints: vec![],
     /// #     solver: SolverConfiguration {
     /// #         solver_type: "SimulatedAnnealing".to_string(),
-    /// #         stop_conditions: StopConditions { max_iterations: Some(1000), time_limit_seconds: None, no_improvement_iterations: None, stop_on_optimal_score: true },
-    /// #         solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams { initial_temperature: 10.0, final_temperature: 0.1, cooling_schedule: "geometric".to_string(), reheat_after_no_improvement: Some(0), reheat_cycles: Some(0) }),
+    /// #         stop_conditions: StopConditions { max_iterations: Some(1000), time_limit_seconds: None, no_improvement_iterations: None, stop_on_optimal_score: true, stop_on_feasible: false, feasible_score_threshold: None, convergence: None },
+    /// #         solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams { initial_temperature: 10.0, final_temperature: 0.1, cooling_schedule: "geometric".to_string(), reheat_after_no_improvement: Some(0), reheat_cycles: Some(0), penalty_ramp: None }),
     /// #         logging: LoggingOptions::default(),
     /// #         telemetry: Default::default(),
     /// #         seed: None,
@@ -185,7 +218,18 @@ impl State {
             return f64::INFINITY;
         }
 
-        let mut delta_cost = 0.0;
+        if !self.group_allowed_for_person(day, p1_idx, g2_idx)
+            || !self.group_allowed_for_person(day, p2_idx, g1_idx)
+        {
+            return f64::INFINITY;
+        }
+
+        if !self.baseline_move_limits_allow(day, &[(p1_idx, g2_idx), (p2_idx, g1_idx)]) {
+            return f64::INFINITY;
+        }
+
+        let mut delta_cost =
+            self.group_composition_cost_delta(day, g1_idx, g2_idx, &[p1_idx], &[p2_idx]);
 
         // --- Contact/Repetition Delta ---
         let g1_members = &self.schedule[day][g1_idx];
@@ -243,41 +287,24 @@ impl State {
         // No clique weight based delta; cliques are enforced by move feasibility
 
         // Constraint Delta - Soft-Apart Pairs
-        for (pair_idx, &(p1, p2)) in self.soft_apart_pairs.iter().enumerate() {
-            // Check if this soft-apart pair applies to this session
-            if let Some(ref sessions) = self.soft_apart_pair_sessions[pair_idx] {
-                if !sessions.contains(&day) {
-                    continue; // Skip this constraint for this session
-                }
-            }
-
-            // Check if both people are participating in this session
-            if !self.person_participation[p1][day] || !self.person_participation[p2][day] {
-                continue; // Skip if either person is not participating
-            }
-
-            let p1_is_swapped = p1_idx == p1 || p2_idx == p1;
-            let p2_is_swapped = p1_idx == p2 || p2_idx == p2;
-
-            // If the pair is not involved in the swap, no change
-            if !p1_is_swapped && !p2_is_swapped {
-                continue;
-            }
+        // Each mover leaves its partners in the old group and joins those in the new one; the
+        // pair of movers itself stays apart.
+        delta_cost += self.soft_apart_weight_in_group(day, p1_idx, g2_idx, p2_idx)
+            - self.soft_apart_weight_in_group(day, p1_idx, g1_idx, p2_idx)
+            + self.soft_apart_weight_in_group(day, p2_idx, g1_idx, p1_idx)
+            - self.soft_apart_weight_in_group(day, p2_idx, g2_idx, p1_idx);
 
-            let pair_weight = self.soft_apart_pair_weights[pair_idx];
-
-            let were_together = self.locations[day][p1].0 == self.locations[day][p2].0;
-            let are_together = group_after_swap(p1) == group_after_swap(p2);
-
-            if were_together && !are_together {
-                delta_cost -= pair_weight;
-            } else if !were_together && are_together {
-                delta_cost += pair_weight;
-            }
-        }
+        let p1_constraints = &self.pair_constraints_by_person[p1_idx];
+        let p2_constraints = &self.pair_constraints_by_person[p2_idx];
 
         // Constraint Delta - ShouldStayTogether pairs
-        for (pair_idx, &(person1, person2)) in self.should_together_pairs.iter().enumerate() {
+        for pair_idx in merged_pair_indices(
+            &p1_constraints.should_together,
+            &p2_constraints.should_together,
+            &self.should_together_pairs,
+            p1_idx,
+        ) {
+            let (person1, person2) = self.should_together_pairs[pair_idx];
             // Check if this should-together pair applies to this session
             if let Some(ref sessions) = self.should_together_sessions[pair_idx] {
                 if !sessions.contains(&day) {
@@ -309,14 +336,16 @@ impl State {
         }
 
         // Constraint Delta - PairMeetingCount
-        for (cidx, &(a, b)) in self.pairmin_pairs.iter().enumerate() {
+        for cidx in merged_pair_indices(
+            &p1_constraints.pair_meeting,
+            &p2_constraints.pair_meeting,
+            &self.pairmin_pairs,
+            p1_idx,
+        ) {
+            let (a, b) = self.pairmin_pairs[cidx];
             if !self.pairmin_sessions[cidx].contains(&day) {
                 continue;
             }
-            // Only if swap involves either endpoint at this day
-            if a != p1_idx && a != p2_idx && b != p1_idx && b != p2_idx {
-                continue;
-            }
             // Determine before-after sameness for the pair on this day
             let (a_g_before, _) = self.locations[day][a];
             let (b_g_before, _) = self.locations[day][b];
@@ -430,12 +459,13 @@ impl State {
     /// # let input = ApiInput {
     /// #     initial_schedule: None,   
     /// #     construction_seed_schedule: None,
+    /// #     contact_history: None,
     /// #     problem: ProblemDefinition { people: vec![], groups: vec![], num_sessions: 1 },
     /// #     objectives: vec![], constraints: vec![],
     /// #     solver: SolverConfiguration {
     /// #         solver_type: "SimulatedAnnealing".to_string(),
-    /// #         stop_conditions: StopConditions { max_iterations: Some(1000), time_limit_seconds: None, no_improvement_iterations: None, stop_on_optimal_score: true },
-    /// #         solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams { initial_temperature: 10.0, final_temperature: 0.1, cooling_schedule: "geometric".to_string(), reheat_after_no_improvement: Some(0), reheat_cycles: Some(0) }),
+    /// #         stop_conditions: StopConditions { max_iterations: Some(1000), time_limit_seconds: None, no_improvement_iterations: None, stop_on_optimal_score: true, stop_on_feasible: false, feasible_score_threshold: None, convergence: None },
+    /// #         solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams { initial_temperature: 10.0, final_temperature: 0.1, cooling_schedule: "geometric".to_string(), reheat_after_no_improvement: Some(0), reheat_cycles: Some(0), penalty_ramp: None }),
     /// #         logging: LoggingOptions::default(),
     /// #         telemetry: Default::default(),
     /// #         seed: None,
@@ -466,7 +496,6 @@ impl State {
     /// ## 2. Contact Matrix Update
     /// - Decrements contacts for old group pairings
     /// - Increments contacts for new group pairings
-    /// - Updates symmetric entries (contact_matrix[i][j] and contact_matrix[j][i])
     ///
     /// ## 3. Score Recalculation
     /// - Counts unique contacts (pairs with at least 1 encounter)
@@ -483,12 +512,13 @@ impl State {
     /// # let input = ApiInput {
     /// #     initial_schedule: None,
     /// #     construction_seed_schedule: None,
+    /// #     contact_history: None,
     /// #     problem: ProblemDefinition { people: vec![], groups: vec![], num_sessions: 1 },
     /// #     objectives: vec![], constraints: vec![],
     /// #     solver: SolverConfiguration {
     /// #         solver_type: "SimulatedAnnealing".to_string(),
-    /// #         stop_conditions: StopConditions { max_iterations: Some(1000), time_limit_seconds: None, no_improvement_iterations: None, stop_on_optimal_score: true },
-    /// #         solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams { initial_temperature: 10.0, final_temperature: 0.1, cooling_schedule: "geometric".to_string(), reheat_after_no_improvement: Some(0), reheat_cycles: Some(0) }),
+    /// #         stop_conditions: StopConditions { max_iterations: Some(1000), time_limit_seconds: None, no_improvement_iterations: None, stop_on_optimal_score: true, stop_on_feasible: false, feasible_score_threshold: None, convergence: None },
+    /// #         solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams { initial_temperature: 10.0, final_temperature: 0.1, cooling_schedule: "geometric".to_string(), reheat_after_no_improvement: Some(0), reheat_cycles: Some(0), penalty_ramp: None }),
     /// #         logging: LoggingOptions::default(),
     /// #         telemetry: Default::default(),
     /// #         seed: None,
@@ -518,15 +548,9 @@ impl State {
     /// # Ok::<(), gm_core::solver::SolverError>(())
     /// ```
     pub fn apply_swap(&mut self, day: usize, p1_idx: usize, p2_idx: usize) {
-        #[cfg(feature = "debug-attr-balance-tracing")]
-        let debug_attr_balance = std::env::var_os("DEBUG_ATTR_BALANCE").is_some();
-
         // Verify both people are participating in this session
         if !self.person_participation[p1_idx][day] || !self.person_participation[p2_idx][day] {
-            eprintln!(
-                "Warning: Attempted to swap non-participating people in session {}",
-                day
-            );
+            tracing::warn!(session = day, "attempted to swap non-participating people");
             return; // Skip invalid swap
         }
 
@@ -536,6 +560,7 @@ impl State {
         if g1_idx == g2_idx {
             return; // Same group, no swap needed
         }
+        let group_composition_penalty_before = self.weighted_group_composition_penalty();
 
         if self
             .first_hard_apart_conflict_in_group_excluding(
@@ -557,6 +582,20 @@ impl State {
             return;
         }
 
+        if !self.group_allowed_for_person(day, p1_idx, g2_idx)
+            || !self.group_allowed_for_person(day, p2_idx, g1_idx)
+        {
+            return;
+        }
+
+        let baseline_moves = [(p1_idx, g2_idx), (p2_idx, g1_idx)];
+        if !self.baseline_move_limits_allow(day, &baseline_moves) {
+            return;
+        }
+        let baseline_delta = self.baseline_move_delta(day, &baseline_moves);
+        self.record_baseline_move_delta(day, baseline_delta);
+        self.record_group_composition_exchange(day, g1_idx, g2_idx, &[p1_idx], &[p2_idx]);
+
         // === TAKE OWNERSHIP OF AFFECTED GROUPS ===
         let (mut old_g1_members, mut old_g2_members) = {
             let day_schedule = &mut self.schedule[day];
@@ -578,20 +617,29 @@ impl State {
         // Remove old contacts for p1 with participating members in g1
         for &member in &old_g1_members {
             if member != p1_idx && self.person_participation[member][day] {
-                let old_count = self.contact_matrix[p1_idx][member];
+                let old_count = self.contact_matrix.get(p1_idx, member);
                 if old_count > 0 {
-                    self.contact_matrix[p1_idx][member] -= 1;
-                    self.contact_matrix[member][p1_idx] -= 1;
+                    self.contact_matrix.decrement(p1_idx, member);
 
                     // Update unique contacts count
                     if old_count == 1 {
                         self.unique_contacts -= 1; // No longer any contact
+                        self.vip_contact_bonus -= self.vip_contact_bonus_for(p1_idx, member);
                     }
 
                     // Update repetition penalty
                     let old_penalty = self.repetition_penalty_for_contact_count(old_count);
                     let new_penalty = self.repetition_penalty_for_contact_count(old_count - 1);
                     self.repetition_penalty += new_penalty - old_penalty;
+                    self.history_decay_correction +=
+                        self.history_correction_delta(member, p1_idx, old_count, old_count - 1);
+                    self.record_session_weighted_contact(
+                        p1_idx,
+                        member,
+                        day,
+                        old_count,
+                        old_count - 1,
+                    );
                 }
             }
         }
@@ -599,39 +647,51 @@ impl State {
         // Add new contacts for p1 with participating members in g2
         for &member in &old_g2_members {
             if member != p2_idx && self.person_participation[member][day] {
-                let old_count = self.contact_matrix[p1_idx][member];
-                self.contact_matrix[p1_idx][member] += 1;
-                self.contact_matrix[member][p1_idx] += 1;
+                let old_count = self.contact_matrix.get(p1_idx, member);
+                self.contact_matrix.increment(p1_idx, member);
 
                 // Update unique contacts count
                 if old_count == 0 {
                     self.unique_contacts += 1; // New unique contact
+                    self.vip_contact_bonus += self.vip_contact_bonus_for(p1_idx, member);
                 }
 
                 // Update repetition penalty
                 let old_penalty = self.repetition_penalty_for_contact_count(old_count);
                 let new_penalty = self.repetition_penalty_for_contact_count(old_count + 1);
                 self.repetition_penalty += new_penalty - old_penalty;
+                self.history_decay_correction +=
+                    self.history_correction_delta(member, p1_idx, old_count, old_count + 1);
+                self.record_session_weighted_contact(p1_idx, member, day, old_count, old_count + 1);
             }
         }
 
         // Remove old contacts for p2 with participating members in g2
         for &member in &old_g2_members {
             if member != p2_idx && self.person_participation[member][day] {
-                let old_count = self.contact_matrix[p2_idx][member];
+                let old_count = self.contact_matrix.get(p2_idx, member);
                 if old_count > 0 {
-                    self.contact_matrix[p2_idx][member] -= 1;
-                    self.contact_matrix[member][p2_idx] -= 1;
+                    self.contact_matrix.decrement(p2_idx, member);
 
                     // Update unique contacts count
                     if old_count == 1 {
                         self.unique_contacts -= 1; // No longer any contact
+                        self.vip_contact_bonus -= self.vip_contact_bonus_for(p2_idx, member);
                     }
 
                     // Update repetition penalty
                     let old_penalty = self.repetition_penalty_for_contact_count(old_count);
                     let new_penalty = self.repetition_penalty_for_contact_count(old_count - 1);
                     self.repetition_penalty += new_penalty - old_penalty;
+                    self.history_decay_correction +=
+                        self.history_correction_delta(member, p2_idx, old_count, old_count - 1);
+                    self.record_session_weighted_contact(
+                        p2_idx,
+                        member,
+                        day,
+                        old_count,
+                        old_count - 1,
+                    );
                 }
             }
         }
@@ -639,19 +699,22 @@ impl State {
         // Add new contacts for p2 with participating members in g1
         for &member in &old_g1_members {
             if member != p1_idx && self.person_participation[member][day] {
-                let old_count = self.contact_matrix[p2_idx][member];
-                self.contact_matrix[p2_idx][member] += 1;
-                self.contact_matrix[member][p2_idx] += 1;
+                let old_count = self.contact_matrix.get(p2_idx, member);
+                self.contact_matrix.increment(p2_idx, member);
 
                 // Update unique contacts count
                 if old_count == 0 {
                     self.unique_contacts += 1; // New unique contact
+                    self.vip_contact_bonus += self.vip_contact_bonus_for(p2_idx, member);
                 }
 
                 // Update repetition penalty
                 let old_penalty = self.repetition_penalty_for_contact_count(old_count);
                 let new_penalty = self.repetition_penalty_for_contact_count(old_count + 1);
                 self.repetition_penalty += new_penalty - old_penalty;
+                self.history_decay_correction +=
+                    self.history_correction_delta(member, p2_idx, old_count, old_count + 1);
+                self.record_session_weighted_contact(p2_idx, member, day, old_count, old_count + 1);
             }
         }
 
@@ -689,12 +752,10 @@ impl State {
 
         // === UPDATE ATTRIBUTE BALANCE PENALTY ===
         #[cfg(feature = "debug-attr-balance-tracing")]
-        if debug_attr_balance {
-            println!(
-                "DEBUG: apply_swap - before attribute balance update: {}",
-                self.attribute_balance_penalty
-            );
-        }
+        tracing::trace!(
+            attribute_balance_penalty = self.attribute_balance_penalty,
+            "apply_swap: before attribute balance update"
+        );
 
         for (constraint_idx, old_penalty) in g1_attr_deltas {
             let new_penalty = self.calculate_group_attribute_penalty_for_constraint_members(
@@ -704,16 +765,14 @@ impl State {
             let delta_penalty = new_penalty - old_penalty;
 
             #[cfg(feature = "debug-attr-balance-tracing")]
-            if debug_attr_balance && delta_penalty.abs() > 0.001 {
-                println!(
-                    "DEBUG: apply_swap - resolved g1 constraint idx {}:",
-                    constraint_idx
-                );
-                println!(
-                    "  old_penalty: {}, new_penalty: {}",
-                    old_penalty, new_penalty
+            if delta_penalty.abs() > 0.001 {
+                tracing::trace!(
+                    constraint_idx,
+                    old_penalty,
+                    new_penalty,
+                    delta_penalty,
+                    "apply_swap: resolved g1 constraint penalty changed"
                 );
-                println!("  delta_penalty: {}", delta_penalty);
             }
 
             self.attribute_balance_penalty += delta_penalty;
@@ -727,77 +786,25 @@ impl State {
         }
 
         #[cfg(feature = "debug-attr-balance-tracing")]
-        if debug_attr_balance {
-            println!(
-                "DEBUG: apply_swap - after attribute balance update: {}",
-                self.attribute_balance_penalty
-            );
-        }
+        tracing::trace!(
+            attribute_balance_penalty = self.attribute_balance_penalty,
+            "apply_swap: after attribute balance update"
+        );
 
         // === UPDATE CONSTRAINT PENALTIES (THIS WAS MISSING!) ===
 
-        // Update soft-apart pair violations
-        for (pair_idx, &(person_a, person_b)) in self.soft_apart_pairs.iter().enumerate() {
-            // Check if this soft-apart pair applies to this session
-            if let Some(ref sessions) = self.soft_apart_pair_sessions[pair_idx] {
-                if !sessions.contains(&day) {
-                    continue; // Skip this constraint for this session
-                }
-            }
-
-            // Check if both people are participating in this session
-            if !self.person_participation[person_a][day]
-                || !self.person_participation[person_b][day]
-            {
-                continue; // Skip if either person is not participating
+        // Update soft-apart pair violations (schedule and locations already reflect the swap)
+        for (person, group_before, group_after, other) in [
+            (p1_idx, g1_idx, g2_idx, p2_idx),
+            (p2_idx, g2_idx, g1_idx, p1_idx),
+        ] {
+            for pair_idx in self.soft_apart_pairs_in_group(day, person, group_before, other) {
+                self.soft_apart_pair_violations[pair_idx] -= 1;
+                self.weighted_constraint_penalty -= self.soft_apart_pair_weights[pair_idx];
             }
-
-            // Check if this swap affects this soft-apart pair
-            if (person_a == p1_idx || person_a == p2_idx)
-                || (person_b == p1_idx || person_b == p2_idx)
-            {
-                // Check if they were together before the swap (use original group assignments)
-                let a_group_before = if person_a == p1_idx {
-                    g1_idx
-                } else if person_a == p2_idx {
-                    g2_idx
-                } else {
-                    self.locations[day][person_a].0
-                };
-                let b_group_before = if person_b == p1_idx {
-                    g1_idx
-                } else if person_b == p2_idx {
-                    g2_idx
-                } else {
-                    self.locations[day][person_b].0
-                };
-                let were_together_before = a_group_before == b_group_before;
-
-                // Check if they are together after the swap (use new group assignments)
-                let a_group_after = if person_a == p1_idx {
-                    g2_idx
-                } else if person_a == p2_idx {
-                    g1_idx
-                } else {
-                    self.locations[day][person_a].0
-                };
-                let b_group_after = if person_b == p1_idx {
-                    g2_idx
-                } else if person_b == p2_idx {
-                    g1_idx
-                } else {
-                    self.locations[day][person_b].0
-                };
-                let are_together_after = a_group_after == b_group_after;
-
-                // Update the violation count
-                if were_together_before && !are_together_after {
-                    // They were together before but not after - violation removed
-                    self.soft_apart_pair_violations[pair_idx] -= 1;
-                } else if !were_together_before && are_together_after {
-                    // They were not together before but are after - violation added
-                    self.soft_apart_pair_violations[pair_idx] += 1;
-                }
+            for pair_idx in self.soft_apart_pairs_in_group(day, person, group_after, other) {
+                self.soft_apart_pair_violations[pair_idx] += 1;
+                self.weighted_constraint_penalty += self.soft_apart_pair_weights[pair_idx];
             }
         }
 
@@ -855,8 +862,10 @@ impl State {
 
             if was_violation_before && !is_violation_after {
                 self.should_together_violations[pair_idx] -= 1;
+                self.weighted_constraint_penalty -= self.should_together_weights[pair_idx];
             } else if !was_violation_before && is_violation_after {
                 self.should_together_violations[pair_idx] += 1;
+                self.weighted_constraint_penalty += self.should_together_weights[pair_idx];
             }
         }
 
@@ -927,8 +936,10 @@ impl State {
 
                     if was_violation_before && !is_violation_after {
                         self.immovable_violations -= 1; // Violation fixed
+                        self.weighted_constraint_penalty -= 1000.0;
                     } else if !was_violation_before && is_violation_after {
                         self.immovable_violations += 1; // New violation
+                        self.weighted_constraint_penalty += 1000.0;
                     }
                 } else if *person_idx == p2_idx {
                     // p2 moved from g2 to g1
@@ -937,8 +948,10 @@ impl State {
 
                     if was_violation_before && !is_violation_after {
                         self.immovable_violations -= 1; // Violation fixed
+                        self.weighted_constraint_penalty -= 1000.0;
                     } else if !was_violation_before && is_violation_after {
                         self.immovable_violations += 1; // New violation
+                        self.weighted_constraint_penalty += 1000.0;
                     }
                 }
             }
@@ -993,16 +1006,28 @@ impl State {
             if were_same == are_same {
                 continue;
             }
-            if are_same {
-                self.pairmin_counts[cidx] += 1;
+            let have_before = self.pairmin_counts[cidx];
+            let have_after = if are_same {
+                have_before + 1
             } else {
-                self.pairmin_counts[cidx] -= 1;
-            }
+                have_before - 1
+            };
+            self.pairmin_counts[cidx] = have_after;
+            self.weighted_constraint_penalty +=
+                self.pairmin_penalty(cidx, have_after) - self.pairmin_penalty(cidx, have_before);
         }
+        self.weighted_constraint_penalty +=
+            self.weighted_group_composition_penalty() - group_composition_penalty_before;
 
         // Update the legacy constraint_penalty field for backward compatibility
         self._update_constraint_penalty_total();
-        self.refresh_cost_from_caches();
+        debug_assert!(
+            (self.weighted_constraint_penalty - self.weighted_constraint_penalty_from_caches())
+                .abs()
+                < 1e-6,
+            "apply_swap drifted from the cached constraint violations"
+        );
+        self.refresh_current_cost();
         #[cfg(feature = "debug-invariant-checks")]
         self.debug_validate_hard_constraints_if_enabled("apply_swap");
         #[cfg(feature = "cache-drift-assertions")]
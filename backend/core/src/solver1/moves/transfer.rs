@@ -4,7 +4,7 @@
 //! to another when there's available capacity, without requiring an exchange.
 //! This enables optimization with variable group sizes.
 
-use super::super::State;
+use super::super::{GroupMembers, State};
 use crate::models::PairMeetingMode;
 
 impl State {
@@ -54,6 +54,8 @@ impl State {
     /// - Source group would have at least 1 person remaining
     /// - Target group has available capacity
     /// - Source and target groups are different
+    /// - Target group satisfies the person's `GroupAttributeRequirement`s
+    /// - No `BaselineMoveLimit` would be exceeded
     pub fn is_transfer_feasible(
         &self,
         day: usize,
@@ -104,16 +106,49 @@ impl State {
             return false;
         }
 
+        // Target group must satisfy the person's group attribute requirements
+        if !self.group_allowed_for_person(day, person_idx, to_group) {
+            return false;
+        }
+
+        // Moving must not push any baseline move limit over its cap
+        if !self.baseline_move_limits_allow(day, &[(person_idx, to_group)]) {
+            return false;
+        }
+
         true
     }
 
+    /// Objective-only part of [`Self::calculate_transfer_cost_delta`]: unique contacts and
+    /// group fill without any penalty terms, used to rescale penalties during a penalty ramp.
+    pub(crate) fn calculate_transfer_objective_delta(
+        &self,
+        day: usize,
+        person_idx: usize,
+        from_group: usize,
+        to_group: usize,
+    ) -> f64 {
+        let mut delta = self.group_fill_transfer_delta(day, from_group, to_group);
+        for &member in &self.schedule[day][from_group] {
+            if member != person_idx && self.person_participation[member][day] {
+                delta += self.unique_contact_pair_delta(person_idx, member, day, -1);
+            }
+        }
+        for &member in &self.schedule[day][to_group] {
+            if self.person_participation[member][day] {
+                delta += self.unique_contact_pair_delta(person_idx, member, day, 1);
+            }
+        }
+        delta
+    }
+
     /// Calculate the cost delta for a single-person transfer.
     ///
     /// Similar to swap delta but simpler since only one person moves.
     /// Calculates changes in:
     /// - Contact counts and unique contacts
     /// - Repetition penalties  
-    /// - Attribute balance penalties
+    /// - Attribute balance and group-fill penalties
     /// - Constraint violations
     pub fn calculate_transfer_cost_delta(
         &self,
@@ -143,16 +178,18 @@ impl State {
                 continue;
             }
 
-            let count = self.contact_matrix[person_idx][member];
+            let count = self.contact_matrix.get(person_idx, member);
             if count > 0 {
                 // Repetition penalty change: (new_penalty - old_penalty)
                 let old_penalty = self.repetition_penalty_for_contact_count(count);
                 let new_penalty = self.repetition_penalty_for_contact_count(count - 1);
                 delta_cost += self.w_repetition * (new_penalty - old_penalty) as f64;
+                delta_cost += self.history_correction_delta(person_idx, member, count, count - 1);
+                delta_cost += self.session_weight_pair_delta(person_idx, member, day, -1);
 
                 if count == 1 {
                     // Unique contacts: losing one, so cost increases
-                    delta_cost += self.w_contacts;
+                    delta_cost += self.pair_contact_value(person_idx, member);
                 }
             }
         }
@@ -164,18 +201,25 @@ impl State {
                 continue;
             }
 
-            let count = self.contact_matrix[person_idx][member];
+            let count = self.contact_matrix.get(person_idx, member);
             // Repetition penalty change: (new_penalty - old_penalty)
             let old_penalty = self.repetition_penalty_for_contact_count(count);
             let new_penalty = self.repetition_penalty_for_contact_count(count + 1);
             delta_cost += self.w_repetition * (new_penalty - old_penalty) as f64;
+            delta_cost += self.history_correction_delta(person_idx, member, count, count + 1);
+            delta_cost += self.session_weight_pair_delta(person_idx, member, day, 1);
 
             if count == 0 {
                 // Unique contacts: gaining one, so cost decreases
-                delta_cost -= self.w_contacts;
+                delta_cost -= self.pair_contact_value(person_idx, member);
             }
         }
 
+        // === GROUP FILL / COMPOSITION DELTA ===
+        delta_cost += self.group_fill_transfer_delta(day, from_group, to_group);
+        delta_cost +=
+            self.group_composition_cost_delta(day, from_group, to_group, &[person_idx], &[]);
+
         // === ATTRIBUTE BALANCE DELTA ===
         let group_after_transfer = |other_person: usize| {
             if other_person == person_idx {
@@ -185,10 +229,9 @@ impl State {
             }
         };
 
-        let from_attr_constraints = self
-            .attribute_balance_constraint_indices_for_group_session(day, from_group)
-            .to_vec();
-        for constraint_idx in from_attr_constraints {
+        for &constraint_idx in
+            self.attribute_balance_constraint_indices_for_group_session(day, from_group)
+        {
             let old_penalty = self.calculate_group_attribute_penalty_for_constraint_members(
                 from_group_members,
                 constraint_idx,
@@ -202,10 +245,9 @@ impl State {
                 );
             delta_cost += new_penalty - old_penalty;
         }
-        let to_attr_constraints = self
-            .attribute_balance_constraint_indices_for_group_session(day, to_group)
-            .to_vec();
-        for constraint_idx in to_attr_constraints {
+        for &constraint_idx in
+            self.attribute_balance_constraint_indices_for_group_session(day, to_group)
+        {
             let old_penalty = self.calculate_group_attribute_penalty_for_constraint_members(
                 to_group_members,
                 constraint_idx,
@@ -222,40 +264,13 @@ impl State {
 
         // === CONSTRAINT PENALTY DELTA ===
         // Check soft-apart pairs
-        for (pair_idx, &(person1, person2)) in self.soft_apart_pairs.iter().enumerate() {
-            // Check if this constraint applies to this session
-            if let Some(ref sessions) = self.soft_apart_pair_sessions[pair_idx] {
-                if !sessions.contains(&day) {
-                    continue;
-                }
-            }
-
-            let pair_weight = self.soft_apart_pair_weights[pair_idx];
-
-            // Check if the transferring person is part of this soft-apart pair
-            if person_idx != person1 && person_idx != person2 {
-                continue;
-            }
-
-            let other_person = if person_idx == person1 {
-                person2
-            } else {
-                person1
-            };
-
-            let currently_together =
-                self.locations[day][person_idx].0 == self.locations[day][other_person].0;
-            let will_be_together =
-                group_after_transfer(person_idx) == group_after_transfer(other_person);
-
-            let old_penalty = if currently_together { pair_weight } else { 0.0 };
-            let new_penalty = if will_be_together { pair_weight } else { 0.0 };
-
-            delta_cost += new_penalty - old_penalty;
-        }
+        delta_cost += self.soft_apart_weight_in_group(day, person_idx, to_group, person_idx)
+            - self.soft_apart_weight_in_group(day, person_idx, from_group, person_idx);
 
+        let person_constraints = &self.pair_constraints_by_person[person_idx];
         // Check should-stay-together pairs
-        for (pair_idx, &(other1, other2)) in self.should_together_pairs.iter().enumerate() {
+        for &pair_idx in &person_constraints.should_together {
+            let (other1, other2) = self.should_together_pairs[pair_idx];
             // Check if this constraint applies to this session
             if let Some(ref sessions) = self.should_together_sessions[pair_idx] {
                 if !sessions.contains(&day) {
@@ -265,11 +280,6 @@ impl State {
 
             let weight = self.should_together_weights[pair_idx];
 
-            // Only consider if moving person is part of this pair
-            if person_idx != other1 && person_idx != other2 {
-                continue;
-            }
-
             let other_person = if person_idx == other1 { other2 } else { other1 };
 
             let currently_together =
@@ -283,14 +293,11 @@ impl State {
         }
 
         // Check PairMeetingCount constraints (only those including this day and where moving person is one endpoint)
-        for (cidx, &(a, b)) in self.pairmin_pairs.iter().enumerate() {
+        for &cidx in &person_constraints.pair_meeting {
+            let (a, b) = self.pairmin_pairs[cidx];
             if !self.pairmin_sessions[cidx].contains(&day) {
                 continue;
             }
-            // Moving person must be one endpoint
-            if person_idx != a && person_idx != b {
-                continue;
-            }
             // Identify the other endpoint
             let other = if person_idx == a { b } else { a };
 
@@ -340,7 +347,7 @@ impl State {
     /// Moves a person from one group to another and updates all internal state:
     /// - Schedule and locations
     /// - Contact matrix and scores
-    /// - Attribute balance penalties
+    /// - Attribute balance and group-fill penalties
     /// - Constraint violations
     pub fn apply_transfer(
         &mut self,
@@ -351,10 +358,15 @@ impl State {
     ) {
         // Verify the transfer is feasible
         if !self.is_transfer_feasible(day, person_idx, from_group, to_group) {
-            eprintln!("Warning: Attempted infeasible transfer");
+            tracing::warn!("attempted infeasible transfer");
             return;
         }
 
+        let baseline_delta = self.baseline_move_delta(day, &[(person_idx, to_group)]);
+        self.record_baseline_move_delta(day, baseline_delta);
+        self.group_fill_penalty += self.group_fill_transfer_delta(day, from_group, to_group);
+        self.record_group_composition_exchange(day, from_group, to_group, &[person_idx], &[]);
+
         // === TAKE OWNERSHIP OF AFFECTED GROUPS ===
         let old_from = std::mem::take(&mut self.schedule[day][from_group]);
         let old_to = std::mem::take(&mut self.schedule[day][to_group]);
@@ -362,20 +374,29 @@ impl State {
         // Remove contacts with old group members
         for &member in &old_from {
             if member != person_idx && self.person_participation[member][day] {
-                let old_count = self.contact_matrix[person_idx][member];
+                let old_count = self.contact_matrix.get(person_idx, member);
                 if old_count > 0 {
-                    self.contact_matrix[person_idx][member] -= 1;
-                    self.contact_matrix[member][person_idx] -= 1;
+                    self.contact_matrix.decrement(person_idx, member);
 
                     // Update unique contacts count
                     if old_count == 1 {
                         self.unique_contacts -= 1; // Lost a unique contact
+                        self.vip_contact_bonus -= self.vip_contact_bonus_for(person_idx, member);
                     }
 
                     // Update repetition penalty
                     let old_penalty = self.repetition_penalty_for_contact_count(old_count);
                     let new_penalty = self.repetition_penalty_for_contact_count(old_count - 1);
                     self.repetition_penalty += new_penalty - old_penalty;
+                    self.history_decay_correction +=
+                        self.history_correction_delta(member, person_idx, old_count, old_count - 1);
+                    self.record_session_weighted_contact(
+                        person_idx,
+                        member,
+                        day,
+                        old_count,
+                        old_count - 1,
+                    );
                 }
             }
         }
@@ -383,19 +404,28 @@ impl State {
         // Add contacts with new group members
         for &member in &old_to {
             if self.person_participation[member][day] {
-                let old_count = self.contact_matrix[person_idx][member];
-                self.contact_matrix[person_idx][member] += 1;
-                self.contact_matrix[member][person_idx] += 1;
+                let old_count = self.contact_matrix.get(person_idx, member);
+                self.contact_matrix.increment(person_idx, member);
 
                 // Update unique contacts count
                 if old_count == 0 {
                     self.unique_contacts += 1; // Gained a unique contact
+                    self.vip_contact_bonus += self.vip_contact_bonus_for(person_idx, member);
                 }
 
                 // Update repetition penalty
                 let old_penalty = self.repetition_penalty_for_contact_count(old_count);
                 let new_penalty = self.repetition_penalty_for_contact_count(old_count + 1);
                 self.repetition_penalty += new_penalty - old_penalty;
+                self.history_decay_correction +=
+                    self.history_correction_delta(member, person_idx, old_count, old_count + 1);
+                self.record_session_weighted_contact(
+                    person_idx,
+                    member,
+                    day,
+                    old_count,
+                    old_count + 1,
+                );
             }
         }
 
@@ -423,7 +453,7 @@ impl State {
 
         // === UPDATE SCHEDULE AND LOCATIONS ===
         // Deterministic rebuild to avoid duplicates
-        let new_from: Vec<usize> = old_from
+        let new_from: GroupMembers = old_from
             .iter()
             .copied()
             .filter(|&p| p != person_idx)
@@ -436,18 +466,18 @@ impl State {
             let mut seen = std::collections::HashSet::new();
             for &p in &new_from {
                 if !seen.insert(p) {
-                    eprintln!(
-                        "[DEBUG] Duplicate in transfer new_from: {}",
-                        self.display_person_by_idx(p)
+                    tracing::debug!(
+                        person = %self.display_person_by_idx(p),
+                        "duplicate in transfer new_from"
                     );
                 }
             }
             seen.clear();
             for &p in &new_to {
                 if !seen.insert(p) {
-                    eprintln!(
-                        "[DEBUG] Duplicate in transfer new_to: {}",
-                        self.display_person_by_idx(p)
+                    tracing::debug!(
+                        person = %self.display_person_by_idx(p),
+                        "duplicate in transfer new_to"
                     );
                 }
             }
@@ -502,35 +532,11 @@ impl State {
         // change here.
 
         // Update soft-apart pair violations incrementally.
-        for (pair_idx, &(person_a, person_b)) in self.soft_apart_pairs.iter().enumerate() {
-            if let Some(ref sessions) = self.soft_apart_pair_sessions[pair_idx] {
-                if !sessions.contains(&day) {
-                    continue;
-                }
-            }
-
-            if person_idx != person_a && person_idx != person_b {
-                continue;
-            }
-
-            let other_person = if person_idx == person_a {
-                person_b
-            } else {
-                person_a
-            };
-
-            if !self.person_participation[other_person][day] {
-                continue;
-            }
-
-            let were_together = before_group_of(person_idx) == before_group_of(other_person);
-            let are_together = after_group_of(person_idx) == after_group_of(other_person);
-
-            if were_together && !are_together {
-                self.soft_apart_pair_violations[pair_idx] -= 1;
-            } else if !were_together && are_together {
-                self.soft_apart_pair_violations[pair_idx] += 1;
-            }
+        for pair_idx in self.soft_apart_pairs_in_group(day, person_idx, from_group, person_idx) {
+            self.soft_apart_pair_violations[pair_idx] -= 1;
+        }
+        for pair_idx in self.soft_apart_pairs_in_group(day, person_idx, to_group, person_idx) {
+            self.soft_apart_pair_violations[pair_idx] += 1;
         }
 
         // Update should-together violations incrementally.
@@ -600,12 +606,12 @@ impl State {
             if self.logging.debug_validate_invariants {
                 if let Err(e) = self.validate_no_duplicate_assignments() {
                     if self.logging.debug_dump_invariant_context {
-                        eprintln!(
-                            "[DEBUG] Invariant failed after transfer day={} person={} from={} to={}",
+                        tracing::debug!(
                             day,
-                            self.display_person_by_idx(person_idx),
-                            self.group_idx_to_id[from_group],
-                            self.group_idx_to_id[to_group]
+                            person = %self.display_person_by_idx(person_idx),
+                            from = %self.group_idx_to_id[from_group],
+                            to = %self.group_idx_to_id[to_group],
+                            "invariant failed after transfer"
                         );
                     }
                     let _ = e;
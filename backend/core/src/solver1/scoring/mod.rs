@@ -45,12 +45,23 @@ impl State {
     }
 
     fn calculate_penalty_from_resolved_counts(&self, counts: &[u32], constraint_idx: usize) -> f64 {
+        self.resolved_attribute_penalty(constraint_idx, |value_idx| {
+            counts.get(value_idx).copied().unwrap_or(0)
+        })
+    }
+
+    /// Penalty of `constraint_idx` given the number of members with each desired value.
+    fn resolved_attribute_penalty(
+        &self,
+        constraint_idx: usize,
+        count_of: impl Fn(usize) -> u32,
+    ) -> f64 {
         use crate::models::AttributeBalanceMode;
 
         let constraint = &self.resolved_attribute_balance_constraints[constraint_idx];
         let mut penalty = 0.0;
         for &(value_idx, desired_count) in &constraint.desired_counts {
-            let actual_count = counts.get(value_idx).copied().unwrap_or(0);
+            let actual_count = count_of(value_idx);
             let diff = match constraint.mode {
                 AttributeBalanceMode::Exact => (actual_count as i32 - desired_count as i32).abs(),
                 AttributeBalanceMode::AtLeast => {
@@ -65,12 +76,7 @@ impl State {
 
     pub(crate) fn _recalculate_attribute_balance_penalty(&mut self) {
         #[cfg(feature = "debug-attr-balance-tracing")]
-        let debug_attr_balance = std::env::var_os("DEBUG_ATTR_BALANCE").is_some();
-
-        #[cfg(feature = "debug-attr-balance-tracing")]
-        if debug_attr_balance {
-            println!("DEBUG: _recalculate_attribute_balance_penalty - starting recalculation");
-        }
+        tracing::trace!("recalculating attribute balance penalty");
 
         self.attribute_balance_penalty = 0.0;
         let group_count = self.group_idx_to_id.len();
@@ -84,17 +90,16 @@ impl State {
                         self.calculate_penalty_from_resolved_counts(&counts, constraint_idx);
 
                     #[cfg(feature = "debug-attr-balance-tracing")]
-                    if debug_attr_balance && weighted_penalty > 0.001 {
-                        println!(
-                            "DEBUG: _recalculate - day {}, group {} ({}), resolved constraint idx {}:",
-                            day_idx,
-                            group_idx,
-                            self.group_idx_to_id[group_idx],
-                            constraint_idx
+                    if weighted_penalty > 0.001 {
+                        tracing::trace!(
+                            day = day_idx,
+                            group = %self.group_idx_to_id[group_idx],
+                            constraint_idx,
+                            ?group_people,
+                            value_counts = ?counts,
+                            weighted_penalty,
+                            "attribute balance penalty"
                         );
-                        println!("  group_people: {:?}", group_people);
-                        println!("  value_counts: {:?}", counts);
-                        println!("  weighted_penalty: {}", weighted_penalty);
                     }
 
                     self.attribute_balance_penalty += weighted_penalty;
@@ -103,12 +108,10 @@ impl State {
         }
 
         #[cfg(feature = "debug-attr-balance-tracing")]
-        if debug_attr_balance {
-            println!(
-                "DEBUG: _recalculate_attribute_balance_penalty - final result: {}",
-                self.attribute_balance_penalty
-            );
-        }
+        tracing::trace!(
+            attribute_balance_penalty = self.attribute_balance_penalty,
+            "recalculated attribute balance penalty"
+        );
     }
 
     pub(crate) fn _recalculate_constraint_penalty(&mut self) {
@@ -128,37 +131,25 @@ impl State {
         self.immovable_violations = 0;
 
         // Calculate soft-apart pair violations
-        for (day_idx, day_schedule) in self.schedule.iter().enumerate() {
-            for group in day_schedule {
-                for (pair_idx, &(p1, p2)) in self.soft_apart_pairs.iter().enumerate() {
-                    // Check if this soft-apart pair applies to this session
-                    if let Some(ref sessions) = self.soft_apart_pair_sessions[pair_idx] {
-                        if !sessions.contains(&day_idx) {
-                            continue; // Skip this constraint for this session
-                        }
+        for day_idx in 0..self.schedule.len() {
+            for (pair_idx, &(p1, p2)) in self.soft_apart_pairs.iter().enumerate() {
+                // Check if this soft-apart pair applies to this session
+                if let Some(ref sessions) = self.soft_apart_pair_sessions[pair_idx] {
+                    if !sessions.contains(&day_idx) {
+                        continue; // Skip this constraint for this session
                     }
-                    // If sessions is None, apply to all sessions
+                }
+                // If sessions is None, apply to all sessions
 
-                    // Check if both people are participating in this session
-                    if !self.person_participation[p1][day_idx]
-                        || !self.person_participation[p2][day_idx]
-                    {
-                        continue; // Skip if either person is not participating
-                    }
+                // Check if both people are participating in this session
+                if !self.person_participation[p1][day_idx]
+                    || !self.person_participation[p2][day_idx]
+                {
+                    continue; // Skip if either person is not participating
+                }
 
-                    let mut p1_in = false;
-                    let mut p2_in = false;
-                    for &member in group {
-                        if member == p1 {
-                            p1_in = true;
-                        }
-                        if member == p2 {
-                            p2_in = true;
-                        }
-                    }
-                    if p1_in && p2_in {
-                        self.soft_apart_pair_violations[pair_idx] += 1;
-                    }
+                if self.locations[day_idx][p1].0 == self.locations[day_idx][p2].0 {
+                    self.soft_apart_pair_violations[pair_idx] += 1;
                 }
             }
         }
@@ -283,6 +274,15 @@ impl State {
         cnt
     }
 
+    /// Violations of `MustStayApart`, `MustStayTogether`, and `ImmovablePeople` in the current
+    /// schedule. Other hard constraints are enforced by move feasibility and never violated.
+    #[inline]
+    pub(crate) fn hard_violation_count(&self) -> i32 {
+        self.hard_apart_pair_violations.iter().sum::<i32>()
+            + self.clique_violations.iter().sum::<i32>()
+            + self.immovable_violations
+    }
+
     #[inline]
     pub(crate) fn _update_constraint_penalty_total(&mut self) {
         self.constraint_penalty = self.soft_apart_pair_violations.iter().sum::<i32>()
@@ -290,6 +290,7 @@ impl State {
             + self.clique_violations.iter().sum::<i32>()
             + self.should_together_violations.iter().sum::<i32>()
             + self.immovable_violations
+            + self.repeated_group_compositions
             + self._pairmin_violation_count();
     }
 
@@ -311,11 +312,17 @@ impl State {
         group_members: &[usize],
         constraint_idx: usize,
     ) -> f64 {
-        let constraint = &self.resolved_attribute_balance_constraints[constraint_idx];
-        let counts = self.get_attribute_counts(group_members, constraint.attr_idx);
-        self.calculate_penalty_from_resolved_counts(&counts, constraint_idx)
+        self.calculate_group_attribute_penalty_for_constraint_members_with_edit(
+            group_members,
+            constraint_idx,
+            None,
+            None,
+        )
     }
 
+    /// Penalty of `constraint_idx` for `group_members` without `removed_person` and with
+    /// `added_person`. Counts only the constrained values, so it does not allocate; move
+    /// deltas call it for every candidate.
     pub(crate) fn calculate_group_attribute_penalty_for_constraint_members_with_edit(
         &self,
         group_members: &[usize],
@@ -323,24 +330,19 @@ impl State {
         removed_person: Option<usize>,
         added_person: Option<usize>,
     ) -> f64 {
-        let constraint = &self.resolved_attribute_balance_constraints[constraint_idx];
-        let mut counts = self.get_attribute_counts(group_members, constraint.attr_idx);
-
-        if let Some(person_idx) = removed_person {
-            let value_idx = self.person_attributes[person_idx][constraint.attr_idx];
-            if value_idx != usize::MAX {
-                counts[value_idx] -= 1;
-            }
-        }
-
-        if let Some(person_idx) = added_person {
-            let value_idx = self.person_attributes[person_idx][constraint.attr_idx];
-            if value_idx != usize::MAX {
-                counts[value_idx] += 1;
-            }
-        }
-
-        self.calculate_penalty_from_resolved_counts(&counts, constraint_idx)
+        let attr_idx = self.resolved_attribute_balance_constraints[constraint_idx].attr_idx;
+        let has_value = |person_idx: usize, value_idx: usize| {
+            self.person_attributes[person_idx][attr_idx] == value_idx
+        };
+        self.resolved_attribute_penalty(constraint_idx, |value_idx| {
+            let members = group_members
+                .iter()
+                .filter(|&&person_idx| has_value(person_idx, value_idx))
+                .count() as u32;
+            let removed = removed_person.is_some_and(|person_idx| has_value(person_idx, value_idx));
+            let added = added_person.is_some_and(|person_idx| has_value(person_idx, value_idx));
+            members - removed as u32 + added as u32
+        })
     }
 
     #[inline]
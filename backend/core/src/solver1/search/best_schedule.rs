@@ -0,0 +1,89 @@
+//! Undo log from the current search schedule back to the best one seen.
+//!
+//! Every solver1 move changes a single session, so the best schedule is the current schedule
+//! with the sessions touched since the last improvement put back. The log keeps each touched
+//! session's groups as they were at the best, saved just before the first move into that
+//! session. Recording a new best drops the saved sessions instead of cloning the whole state,
+//! and the best state is rebuilt once when the search finishes.
+
+use crate::models::ApiSchedule;
+use crate::solver1::{GroupMembers, State};
+
+pub(crate) struct BestScheduleLog {
+    /// Groups of each session at the best schedule, for sessions changed since then.
+    saved_sessions: Vec<Option<Vec<GroupMembers>>>,
+    /// Sessions with an entry in `saved_sessions`.
+    touched_sessions: Vec<usize>,
+    /// Scheduled unique contacts of the best schedule.
+    best_unique_contacts: i32,
+}
+
+impl BestScheduleLog {
+    /// Starts a log whose best schedule is the current schedule of `state`.
+    pub(crate) fn new(state: &State) -> Self {
+        Self {
+            saved_sessions: vec![None; state.schedule.len()],
+            touched_sessions: Vec::new(),
+            best_unique_contacts: state.scheduled_unique_contacts(),
+        }
+    }
+
+    /// Starts a log from `current` back to `best`, which must share its input.
+    pub(crate) fn between(current: &State, best: &State) -> Self {
+        let mut log = Self::new(current);
+        for (session, groups) in best.schedule.iter().enumerate() {
+            if current.schedule[session] != *groups {
+                log.saved_sessions[session] = Some(groups.clone());
+                log.touched_sessions.push(session);
+            }
+        }
+        log.best_unique_contacts = best.scheduled_unique_contacts();
+        log
+    }
+
+    /// Saves `session` of `state` if it still matches the best schedule. Call before applying
+    /// a move to that session.
+    #[inline]
+    pub(crate) fn before_move(&mut self, state: &State, session: usize) {
+        if self.saved_sessions[session].is_none() {
+            self.saved_sessions[session] = Some(state.schedule[session].clone());
+            self.touched_sessions.push(session);
+        }
+    }
+
+    /// Makes the current schedule of `state` the best one.
+    pub(crate) fn record_best(&mut self, state: &State) {
+        for session in self.touched_sessions.drain(..) {
+            self.saved_sessions[session] = None;
+        }
+        self.best_unique_contacts = state.scheduled_unique_contacts();
+    }
+
+    /// Scheduled unique contacts of the best schedule.
+    pub(crate) fn best_unique_contacts(&self) -> i32 {
+        self.best_unique_contacts
+    }
+
+    /// The best schedule, given the current state.
+    pub(crate) fn best_api_schedule(&self, current: &State) -> ApiSchedule {
+        current.api_schedule_for(
+            current
+                .schedule
+                .iter()
+                .zip(&self.saved_sessions)
+                .map(|(groups, saved)| saved.as_ref().unwrap_or(groups)),
+        )
+    }
+
+    /// Turns `state` back into the best state, with every score recalculated.
+    pub(crate) fn restore(mut self, state: &mut State) {
+        for session in self.touched_sessions.drain(..) {
+            if let Some(groups) = self.saved_sessions[session].take() {
+                state.schedule[session] = groups;
+            }
+        }
+        state._recalculate_locations_from_schedule();
+        state.sync_tracks_with_schedule();
+        state._recalculate_scores();
+    }
+}
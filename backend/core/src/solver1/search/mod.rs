@@ -3,18 +3,21 @@
 //! This trait and module tree are solver-family-specific. Shared engine registry and
 //! public selection logic live outside `solver1/`.
 
-use crate::models::{BenchmarkObserver, ProgressCallback, SolverResult};
+use crate::models::{BenchmarkObserver, ProgressHook, SolverResult};
 use crate::solver1::State;
 use crate::solver_support::SolverError;
 
+mod best_schedule;
+pub(crate) mod recommendation;
 pub mod simulated_annealing;
+pub(crate) mod tuning;
 
 /// A trait implemented by `solver1` search strategies.
 pub trait Solver {
     fn solve(
         &self,
         state: &mut State,
-        progress_callback: Option<&ProgressCallback>,
+        progress_callback: Option<ProgressHook<'_>>,
         benchmark_observer: Option<&BenchmarkObserver>,
     ) -> Result<SolverResult, SolverError>;
 }
@@ -0,0 +1,262 @@
+//! Scenario-tuned settings recommendation for simulated annealing.
+//!
+//! Good annealing temperatures depend on how much a typical move changes the cost, which varies
+//! by orders of magnitude between scenarios. The recommendation measures it instead of guessing:
+//! it samples the cost delta of random swaps on a constructed schedule, derives a starting
+//! temperature from the worsening deltas, runs a few short constant-temperature trials around
+//! it, and keeps the temperature whose measured uphill acceptance is closest to the target.
+
+use super::simulated_annealing::{static_move_candidates_for_day, SimulatedAnnealing};
+use super::Solver as _;
+use crate::engines::RecommendationRequest;
+use crate::models::{
+    ApiInput, CostDeltaDistribution, LoggingOptions, RecommendationReport, RecommendationTrial,
+    SimulatedAnnealingParams, SolverConfiguration, SolverParams, TelemetryOptions,
+};
+use crate::solver1::State;
+use crate::solver_support::SolverError;
+use rand::{RngExt, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+
+/// Fixed seed so the same scenario always gets the same recommendation.
+const RECOMMENDATION_SEED: u64 = 0x5eed_5e77;
+const DELTA_SAMPLES: usize = 500;
+const TRIAL_ITERATIONS: u64 = 2_000;
+/// Trial temperatures, as multiples of the temperature derived from the delta distribution.
+const TRIAL_TEMPERATURE_SCALES: [f64; 3] = [0.5, 1.0, 2.0];
+/// Uphill acceptance the search should start with.
+const TARGET_INITIAL_UPHILL_ACCEPTANCE: f64 = 0.8;
+/// Acceptance of a small (10th percentile) worsening move at the end of the search.
+const TARGET_FINAL_SMALL_UPHILL_ACCEPTANCE: f64 = 0.01;
+
+/// Recommends annealing settings for `request`, starting from `configuration`.
+///
+/// `configuration` supplies everything the trials do not measure (stop conditions, cooling
+/// schedule, reheats); only the temperatures are replaced.
+pub(crate) fn recommend_settings(
+    mut configuration: SolverConfiguration,
+    request: RecommendationRequest<'_>,
+) -> Result<RecommendationReport, SolverError> {
+    let SolverParams::SimulatedAnnealing(defaults) = configuration.solver_params.clone() else {
+        return Err(SolverError::ValidationError(
+            "solver1 recommendations need simulated annealing parameters".to_string(),
+        ));
+    };
+    let mut trial_configuration = configuration.clone();
+    trial_configuration.stop_conditions.max_iterations = Some(TRIAL_ITERATIONS);
+    trial_configuration.stop_conditions.time_limit_seconds = Some(1);
+    trial_configuration
+        .stop_conditions
+        .no_improvement_iterations = None;
+    trial_configuration.stop_conditions.stop_on_optimal_score = false;
+    trial_configuration.logging = LoggingOptions::default();
+    trial_configuration.telemetry = TelemetryOptions {
+        record_move_statistics: true,
+        move_statistics_every_n_iterations: TRIAL_ITERATIONS,
+        ..TelemetryOptions::default()
+    };
+    trial_configuration.seed = Some(RECOMMENDATION_SEED);
+
+    let state = State::new(&ApiInput {
+        initial_schedule: None,
+        construction_seed_schedule: None,
+        contact_history: None,
+        problem: request.problem.clone(),
+        objectives: request.objectives.to_vec(),
+        constraints: request.constraints.to_vec(),
+        solver: trial_configuration.clone(),
+    })?;
+
+    let mut reasoning = Vec::new();
+    let delta_distribution = sample_swap_deltas(&state);
+    let base_temperature = match delta_distribution.worsening_median {
+        Some(median) => {
+            let temperature = median / -TARGET_INITIAL_UPHILL_ACCEPTANCE.ln();
+            reasoning.push(format!(
+                "{} of {} sampled swaps worsen the cost, with a median increase of {median:.3}; \
+                 accepting that increase {:.0}% of the time needs a temperature of {temperature:.3}.",
+                delta_distribution.worsening,
+                delta_distribution.samples,
+                TARGET_INITIAL_UPHILL_ACCEPTANCE * 100.0
+            ));
+            Some(temperature)
+        }
+        None => {
+            reasoning.push(format!(
+                "None of the {} sampled swaps worsen the cost, so the default temperatures are kept.",
+                delta_distribution.samples
+            ));
+            None
+        }
+    };
+
+    let trial_temperatures: Vec<f64> = match base_temperature {
+        Some(base) => TRIAL_TEMPERATURE_SCALES
+            .iter()
+            .map(|scale| base * scale)
+            .collect(),
+        None => vec![defaults.initial_temperature],
+    };
+    let mut trials = Vec::with_capacity(trial_temperatures.len());
+    for temperature in trial_temperatures {
+        trials.push(run_trial(&state, &trial_configuration, temperature)?);
+    }
+
+    let total_iterations: u64 = trials.iter().map(|trial| trial.iterations).sum();
+    let total_seconds: f64 = trials.iter().map(|trial| trial.search_seconds).sum();
+    let iterations_per_second =
+        (total_seconds > 0.0).then(|| total_iterations as f64 / total_seconds);
+
+    let mut initial_temperature = defaults.initial_temperature;
+    if let Some(base) = base_temperature {
+        let closest = trials
+            .iter()
+            .filter_map(|trial| {
+                trial
+                    .uphill_acceptance_rate
+                    .map(|rate| (trial, (rate - TARGET_INITIAL_UPHILL_ACCEPTANCE).abs()))
+            })
+            .min_by(|(_, left), (_, right)| left.total_cmp(right));
+        initial_temperature = match closest {
+            Some((trial, _)) => {
+                reasoning.push(format!(
+                    "Of the trial temperatures, {:.3} came closest to the target with {:.0}% of \
+                     worsening moves accepted, so the search starts there.",
+                    trial.temperature,
+                    trial.uphill_acceptance_rate.unwrap_or_default() * 100.0
+                ));
+                trial.temperature
+            }
+            None => {
+                reasoning.push(
+                    "The trials proposed no worsening moves, so the search starts at the \
+                     temperature derived from the sampled deltas."
+                        .to_string(),
+                );
+                base
+            }
+        };
+    }
+
+    let mut final_temperature = defaults.final_temperature.min(initial_temperature / 10.0);
+    if let Some(p10) = delta_distribution.worsening_p10 {
+        final_temperature =
+            (p10 / -TARGET_FINAL_SMALL_UPHILL_ACCEPTANCE.ln()).min(initial_temperature / 10.0);
+        reasoning.push(format!(
+            "The search cools to {final_temperature:.4}, where a small (10th percentile) \
+             increase of {p10:.3} is accepted at most {:.0}% of the time.",
+            TARGET_FINAL_SMALL_UPHILL_ACCEPTANCE * 100.0
+        ));
+    }
+
+    let time_limit_seconds = configuration.stop_conditions.time_limit_seconds;
+    match (iterations_per_second, time_limit_seconds) {
+        (Some(rate), Some(seconds)) => reasoning.push(format!(
+            "The trials ran at {rate:.0} iterations per second, so a {seconds}s run should \
+             complete about {:.0} iterations.",
+            rate * seconds as f64
+        )),
+        _ => reasoning.push(
+            "The trials were too short to measure the search speed; the time limit bounds the run."
+                .to_string(),
+        ),
+    }
+
+    configuration.solver_params = SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
+        initial_temperature,
+        final_temperature,
+        ..defaults
+    });
+    Ok(RecommendationReport {
+        configuration,
+        delta_distribution: Some(delta_distribution),
+        trials,
+        iterations_per_second,
+        reasoning,
+    })
+}
+
+/// Cost deltas of random swaps between people in different groups, without applying them.
+fn sample_swap_deltas(state: &State) -> CostDeltaDistribution {
+    let mut rng = ChaCha12Rng::seed_from_u64(RECOMMENDATION_SEED);
+    let movable_people_by_day: Vec<Vec<usize>> = (0..state.num_sessions as usize)
+        .map(|day| static_move_candidates_for_day(state, day))
+        .collect();
+    let swappable_days: Vec<usize> = (0..movable_people_by_day.len())
+        .filter(|&day| movable_people_by_day[day].len() >= 2)
+        .collect();
+
+    let mut deltas = Vec::with_capacity(DELTA_SAMPLES);
+    if !swappable_days.is_empty() {
+        // Most random pairs share a group only in tiny scenarios; bound the attempts anyway.
+        for _ in 0..DELTA_SAMPLES * 4 {
+            if deltas.len() == DELTA_SAMPLES {
+                break;
+            }
+            let day = swappable_days[rng.random_range(0..swappable_days.len())];
+            let people = &movable_people_by_day[day];
+            let p1_idx = people[rng.random_range(0..people.len())];
+            let p2_idx = people[rng.random_range(0..people.len())];
+            if state.locations[day][p1_idx].0 == state.locations[day][p2_idx].0 {
+                continue;
+            }
+            let delta = state.calculate_swap_cost_delta(day, p1_idx, p2_idx);
+            if delta.is_finite() {
+                deltas.push(delta);
+            }
+        }
+    }
+
+    let mut worsening: Vec<f64> = deltas.iter().copied().filter(|&d| d > 0.0).collect();
+    worsening.sort_by(f64::total_cmp);
+    let percentile = |q: f64| {
+        (!worsening.is_empty())
+            .then(|| worsening[((worsening.len() - 1) as f64 * q).round() as usize])
+    };
+    CostDeltaDistribution {
+        samples: deltas.len() as u32,
+        improving: deltas.iter().filter(|&&d| d < 0.0).count() as u32,
+        neutral: deltas.iter().filter(|&&d| d == 0.0).count() as u32,
+        worsening: worsening.len() as u32,
+        worsening_p10: percentile(0.1),
+        worsening_median: percentile(0.5),
+        worsening_p90: percentile(0.9),
+        worsening_max: worsening.last().copied(),
+    }
+}
+
+/// Anneals a copy of `state` at a constant `temperature` for the trial budget.
+fn run_trial(
+    state: &State,
+    configuration: &SolverConfiguration,
+    temperature: f64,
+) -> Result<RecommendationTrial, SolverError> {
+    let mut solver = SimulatedAnnealing::new(configuration);
+    solver.initial_temperature = temperature;
+    solver.final_temperature = temperature;
+    let result = solver.solve(&mut state.clone(), None, None)?;
+
+    let (uphill_proposed, uphill_accepted) =
+        result
+            .move_statistics
+            .iter()
+            .flatten()
+            .fold((0, 0), |(proposed, accepted), window| {
+                (
+                    proposed + window.uphill_proposed,
+                    accepted + window.uphill_accepted,
+                )
+            });
+    let telemetry = result
+        .benchmark_telemetry
+        .expect("simulated annealing always reports benchmark telemetry");
+    Ok(RecommendationTrial {
+        temperature,
+        iterations: telemetry.iterations_completed,
+        search_seconds: telemetry.search_seconds,
+        uphill_acceptance_rate: (uphill_proposed > 0)
+            .then(|| uphill_accepted as f64 / uphill_proposed as f64),
+        initial_cost: telemetry.initial_score,
+        best_cost: telemetry.best_score,
+    })
+}
@@ -23,14 +23,18 @@
 //! This provides smooth temperature decay from high exploration to low exploitation.
 
 use crate::models::{
-    BenchmarkEvent, BenchmarkObserver, BenchmarkRunStarted, BestScoreTimelinePoint, MoveFamily,
-    MoveFamilyBenchmarkTelemetry, MoveFamilyBenchmarkTelemetrySummary, MovePolicy,
-    MoveSelectionMode, ProgressCallback, ProgressUpdate, SolverBenchmarkTelemetry,
-    SolverConfiguration, SolverResult, StopReason,
+    BenchmarkEvent, BenchmarkObserver, BenchmarkRunStarted, BestScoreTimelinePoint,
+    ConstraintPenaltyPoint, ConvergenceStop, MoveFamily, MoveFamilyBenchmarkTelemetry,
+    MoveFamilyBenchmarkTelemetrySummary, MovePolicy, MoveSelectionMode, MoveStatisticsPoint,
+    OperatorMoveCounts, PenaltyRampParams, ProgressControl, ProgressHook, ProgressUpdate,
+    ScoreHistoryPoint, SolverBenchmarkTelemetry, SolverCheckpoint, SolverConfiguration,
+    SolverPhase, SolverResult, StopReason,
 };
-use crate::runtime_target::displayed_total_iterations;
+use crate::runtime_target::{displayed_total_iterations, estimated_remaining_seconds};
+use crate::solver1::search::best_schedule::BestScheduleLog;
 use crate::solver1::search::Solver;
 use crate::solver1::{derive_phase_seed, State, SEARCH_SEED_SALT};
+use crate::solver_support::schedule_diff::diff_schedules;
 use crate::solver_support::SolverError;
 use rand::{RngExt, SeedableRng};
 use rand_chacha::ChaCha12Rng;
@@ -105,13 +109,66 @@ fn select_clique_source_group(state: &State, clique: &[usize], day: usize) -> Op
     best_group
 }
 
-fn static_move_candidates_for_day(state: &State, day: usize) -> Vec<usize> {
+pub(super) fn static_move_candidates_for_day(state: &State, day: usize) -> Vec<usize> {
     (0..state.person_idx_to_id.len())
+        .filter(|&person_idx| state.person_participation[person_idx][day])
         .filter(|&person_idx| !state.immovable_people.contains_key(&(person_idx, day)))
         .filter(|&person_idx| state.person_to_clique_id[day][person_idx].is_none())
         .collect()
 }
 
+/// Sessions a move may change: the allowed sessions that have at least one movable person.
+///
+/// Every move family needs a movable person (clique swaps exchange the clique for movable
+/// people of the target group), so sessions without one are never sampled.
+fn mutable_sessions(state: &State, movable_people_by_day: &[Vec<usize>]) -> Vec<usize> {
+    let has_movable_people = |day: &usize| {
+        movable_people_by_day
+            .get(*day)
+            .is_some_and(|people| !people.is_empty())
+    };
+    match &state.allowed_sessions {
+        Some(allowed) => allowed
+            .iter()
+            .map(|&day| day as usize)
+            .filter(has_movable_people)
+            .collect(),
+        None => (0..movable_people_by_day.len())
+            .filter(has_movable_people)
+            .collect(),
+    }
+}
+
+/// Cost of `state` when it may become the new best.
+///
+/// The moves keep `current_cost` up to date incrementally, so release builds use it as is.
+/// Debug builds also re-score a copy from scratch and fail on drift.
+fn verified_best_candidate_cost(
+    state: &State,
+    telemetry: &mut MoveFamilyBenchmarkTelemetry,
+) -> f64 {
+    #[cfg(debug_assertions)]
+    {
+        let recalc_started_at = get_current_time();
+        let mut recalculated = state.clone();
+        recalculated._recalculate_scores();
+        let recalculated_cost = recalculated.calculate_cost();
+        telemetry.full_recalculation_count += 1;
+        telemetry.full_recalculation_seconds +=
+            get_elapsed_seconds_between(recalc_started_at, get_current_time());
+        debug_assert!(
+            (recalculated_cost - state.current_cost).abs()
+                <= 1e-6 * recalculated_cost.abs().max(1.0),
+            "incremental cost {} drifted from recalculated cost {}",
+            state.current_cost,
+            recalculated_cost
+        );
+    }
+    #[cfg(not(debug_assertions))]
+    let _ = telemetry;
+    state.current_cost
+}
+
 fn choose_target_group(
     num_groups: usize,
     excluded_group: usize,
@@ -171,11 +228,38 @@ fn sample_non_clique_swap_targets(
 
     (selected.len() == required_count).then_some(selected)
 }
+/// Share of swap moves tried as whole-event track swaps when `TrackGrouping` allows them.
+const TRACK_SWAP_SHARE: f64 = 0.05;
+
+/// `max_iterations` of a run whose stop conditions leave it unset.
+pub(crate) const DEFAULT_MAX_ITERATIONS: u64 = 100_000;
+
+/// Picks two people of one track-swap pool whose tracks can be exchanged.
+fn sample_track_swap(state: &State, rng: &mut ChaCha12Rng) -> Option<(usize, usize)> {
+    let pool = &state.track_swap_pools[rng.random_range(0..state.track_swap_pools.len())];
+    let p1_idx = pool[rng.random_range(0..pool.len())];
+    let p2_idx = pool[rng.random_range(0..pool.len())];
+    state
+        .track_swap_feasible(p1_idx, p2_idx)
+        .then_some((p1_idx, p2_idx))
+}
+
 #[cfg(target_arch = "wasm32")]
 fn get_elapsed_seconds_between(start: f64, end: f64) -> f64 {
     (end - start) / 1000.0 // Convert milliseconds to seconds
 }
 
+/// Closes the previous `reheat` span, if any, and enters a new one under `anneal`.
+fn enter_reheat_span(
+    guard: &mut Option<tracing::span::EnteredSpan>,
+    anneal: &tracing::Span,
+    reheat: u64,
+    iteration: u64,
+) {
+    guard.take();
+    *guard = Some(tracing::info_span!(parent: anneal, "reheat", reheat, iteration).entered());
+}
+
 fn cooling_progress_since_reheat(
     iterations_since_last_reheat: u64,
     remaining_iterations: u64,
@@ -235,6 +319,7 @@ struct AlgorithmMetrics {
 
     // Escape tracking
     local_optima_escapes: u64,
+    uphill_moves_tried: u64,
 
     // Best penalty breakdown tracking
     best_repetition_penalty: f64,
@@ -245,6 +330,44 @@ struct AlgorithmMetrics {
     initial_score: f64,
 }
 
+/// Cumulative move counts of a run; consecutive snapshots bound a move statistics window.
+#[derive(Clone, Copy, Default)]
+struct MoveCounts {
+    swap: OperatorMoveCounts,
+    transfer: OperatorMoveCounts,
+    clique_swap: OperatorMoveCounts,
+    uphill_proposed: u64,
+    uphill_accepted: u64,
+}
+
+impl MoveCounts {
+    /// The window from `previous` to `self`, closed at `iteration`.
+    fn window_since(
+        &self,
+        previous: &MoveCounts,
+        iteration: u64,
+        temperature: Option<f64>,
+    ) -> MoveStatisticsPoint {
+        let operator = |now: OperatorMoveCounts, before: OperatorMoveCounts| OperatorMoveCounts {
+            proposed: now.proposed - before.proposed,
+            accepted: now.accepted - before.accepted,
+        };
+        let uphill_proposed = self.uphill_proposed - previous.uphill_proposed;
+        let uphill_accepted = self.uphill_accepted - previous.uphill_accepted;
+        MoveStatisticsPoint {
+            iteration,
+            temperature,
+            swap: operator(self.swap, previous.swap),
+            transfer: operator(self.transfer, previous.transfer),
+            clique_swap: operator(self.clique_swap, previous.clique_swap),
+            uphill_proposed,
+            uphill_accepted,
+            uphill_acceptance_rate: (uphill_proposed > 0)
+                .then(|| uphill_accepted as f64 / uphill_proposed as f64),
+        }
+    }
+}
+
 impl AlgorithmMetrics {
     fn new(initial_score: f64) -> Self {
         Self {
@@ -263,6 +386,7 @@ impl AlgorithmMetrics {
             recent_scores: vec![initial_score; 50], // Last 50 scores for variance
             score_index: 0,
             local_optima_escapes: 0,
+            uphill_moves_tried: 0,
             best_repetition_penalty: f64::INFINITY,
             best_balance_penalty: f64::INFINITY,
             best_constraint_penalty: f64::INFINITY,
@@ -300,6 +424,9 @@ impl AlgorithmMetrics {
         if delta > self.biggest_attempted_increase {
             self.biggest_attempted_increase = delta;
         }
+        if delta > 0.0 {
+            self.uphill_moves_tried += 1;
+        }
 
         // Track accepted moves
         if accepted {
@@ -319,6 +446,25 @@ impl AlgorithmMetrics {
         self.recent_index = (self.recent_index + 1) % self.recent_acceptances.len();
     }
 
+    fn move_counts(&self) -> MoveCounts {
+        MoveCounts {
+            swap: OperatorMoveCounts {
+                proposed: self.swaps_tried,
+                accepted: self.swaps_accepted,
+            },
+            transfer: OperatorMoveCounts {
+                proposed: self.transfers_tried,
+                accepted: self.transfers_accepted,
+            },
+            clique_swap: OperatorMoveCounts {
+                proposed: self.clique_swaps_tried,
+                accepted: self.clique_swaps_accepted,
+            },
+            uphill_proposed: self.uphill_moves_tried,
+            uphill_accepted: self.local_optima_escapes,
+        }
+    }
+
     fn update_score(&mut self, new_score: f64) {
         // Update recent scores for variance calculation
         self.recent_scores[self.score_index] = new_score;
@@ -612,7 +758,9 @@ impl MoveFamilySelector {
 /// Stop conditions are configured through `StopConditions`:
 /// - `max_iterations`: Maximum optimization iterations
 /// - `time_limit_seconds`: Wall-clock time limit
-/// - `no_improvement_iterations`: Early stopping on convergence
+/// - `no_improvement_iterations`: Early stopping after a run of iterations without improvement
+/// - `convergence`: Early stopping once improvement, acceptance, and temperature have all settled
+/// - `stop_on_feasible`: Early stopping once the best schedule satisfies every hard constraint
 ///
 /// # Example Usage
 ///
@@ -631,6 +779,9 @@ impl MoveFamilySelector {
 ///         time_limit_seconds: Some(30),
 ///         no_improvement_iterations: Some(1000),
 ///         stop_on_optimal_score: true,
+///         stop_on_feasible: false,
+///         feasible_score_threshold: None,
+///         convergence: None,
 ///     },
 ///     solver_params: SolverParams::SimulatedAnnealing(
 ///         SimulatedAnnealingParams {
@@ -639,6 +790,7 @@ impl MoveFamilySelector {
 ///             cooling_schedule: "geometric".to_string(),
 ///             reheat_after_no_improvement: Some(0),
 ///             reheat_cycles: Some(0),
+///             penalty_ramp: None,
 ///         }
 ///     ),
 ///     logging: LoggingOptions {
@@ -658,6 +810,7 @@ impl MoveFamilySelector {
 /// # let input = ApiInput {
 /// #     initial_schedule: None,
 /// #     construction_seed_schedule: None,
+/// #     contact_history: None,
 /// #     problem: ProblemDefinition {
 /// #         people: vec![],
 /// #         groups: vec![],
@@ -711,11 +864,19 @@ pub struct SimulatedAnnealing {
     pub no_improvement_iterations: Option<u64>,
     /// Whether the solver should stop immediately once the best-known score reaches zero.
     pub stop_on_optimal_score: bool,
+    /// Whether the solver should stop once the best schedule violates no hard constraint
+    pub stop_on_feasible: bool,
+    /// Highest best score at which `stop_on_feasible` applies (`None` = any score)
+    pub feasible_score_threshold: Option<f64>,
     /// When > 0, split the total iterations into this many cycles; each cycle cools from
     /// initial_temperature to final_temperature, then reheats at the boundary
     pub reheat_cycles: u64,
     /// Optional reheat threshold: number of iterations without improvement before reheating (0 = disabled)
     pub reheat_after_no_improvement: u64,
+    /// Optional schedule that scales penalty terms in the acceptance test up to full strength
+    pub penalty_ramp: Option<PenaltyRampParams>,
+    /// Optional early stop once the search has converged
+    pub convergence: Option<ConvergenceStop>,
 }
 
 impl SimulatedAnnealing {
@@ -745,6 +906,9 @@ impl SimulatedAnnealing {
     ///         time_limit_seconds: None,
     ///         no_improvement_iterations: Some(5000),
     ///         stop_on_optimal_score: true,
+    ///         stop_on_feasible: false,
+    ///         feasible_score_threshold: None,
+    ///         convergence: None,
     ///     },
     ///     solver_params: SolverParams::SimulatedAnnealing(
     ///         SimulatedAnnealingParams {
@@ -753,6 +917,7 @@ impl SimulatedAnnealing {
     ///             cooling_schedule: "geometric".to_string(),
     ///             reheat_cycles: Some(0),
     ///             reheat_after_no_improvement: Some(0),
+    ///             penalty_ramp: None,
     ///         }
     ///     ),
     ///     logging: LoggingOptions::default(),
@@ -770,7 +935,10 @@ impl SimulatedAnnealing {
         let sa_params = params.simulated_annealing_params().expect(
             "simulated annealing engine should only be constructed after solver selection validation",
         );
-        let max_iterations = params.stop_conditions.max_iterations.unwrap_or(100_000);
+        let max_iterations = params
+            .stop_conditions
+            .max_iterations
+            .unwrap_or(DEFAULT_MAX_ITERATIONS);
         let no_improvement_iterations = params.stop_conditions.no_improvement_iterations;
 
         // Determine cycle-based reheating
@@ -800,10 +968,58 @@ impl SimulatedAnnealing {
             time_limit_seconds: params.stop_conditions.time_limit_seconds,
             no_improvement_iterations,
             stop_on_optimal_score: params.stop_conditions.stop_on_optimal_score,
+            stop_on_feasible: params.stop_conditions.stop_on_feasible,
+            feasible_score_threshold: params.stop_conditions.feasible_score_threshold,
             reheat_cycles,
             reheat_after_no_improvement,
+            penalty_ramp: sa_params.penalty_ramp.clone(),
+            convergence: params.stop_conditions.convergence.clone(),
         }
     }
+
+    /// Iterations into the current cooling pass at `iteration`, and the length of that pass.
+    ///
+    /// With `reheat_cycles`, a pass ends at the next cycle boundary; a reheat requested by the
+    /// progress callback restarts it from `last_reheat_iteration`.
+    fn cooling_window(
+        &self,
+        iteration: u64,
+        cycle_length: u64,
+        last_reheat_iteration: u64,
+    ) -> (u64, u64) {
+        if self.reheat_cycles > 0 && cycle_length > 0 {
+            let cycle_index = iteration / cycle_length;
+            let pass_start = (cycle_index * cycle_length).max(last_reheat_iteration);
+            (
+                iteration - pass_start,
+                (cycle_index + 1) * cycle_length - pass_start,
+            )
+        } else {
+            (
+                iteration - last_reheat_iteration,
+                self.max_iterations - last_reheat_iteration,
+            )
+        }
+    }
+
+    /// Penalty scale for the acceptance test at iteration `i`, or `None` when penalties
+    /// are already at full strength.
+    fn penalty_scale(&self, i: u64, elapsed_seconds: f64) -> Option<f64> {
+        let ramp = self.penalty_ramp.as_ref()?;
+        let iteration_progress = i as f64 / self.max_iterations.max(1) as f64;
+        let time_progress = self
+            .time_limit_seconds
+            .filter(|&limit| limit > 0)
+            .map_or(0.0, |limit| elapsed_seconds / limit as f64);
+        let scale = ramp.scale_at(iteration_progress.max(time_progress));
+        (scale < 1.0).then_some(scale)
+    }
+}
+
+/// Delta used by the acceptance test: the objective part at full weight plus the penalty
+/// part (everything else in `delta_cost`) scaled by `penalty_scale`.
+fn ramped_acceptance_delta(delta_cost: f64, objective_delta: f64, penalty_scale: f64) -> f64 {
+    objective_delta + penalty_scale * (delta_cost - objective_delta)
 }
 
 impl Solver for SimulatedAnnealing {
@@ -899,12 +1115,13 @@ impl Solver for SimulatedAnnealing {
     /// # let input = ApiInput {
     /// #     initial_schedule: None,
     /// #     construction_seed_schedule: None,
+    /// #     contact_history: None,
     /// #     problem: ProblemDefinition { people: vec![], groups: vec![], num_sessions: 1 },
     /// #     objectives: vec![], constraints: vec![],
     /// #     solver: SolverConfiguration {
     /// #         solver_type: "SimulatedAnnealing".to_string(),
-    /// #         stop_conditions: StopConditions { max_iterations: Some(1000), time_limit_seconds: None, no_improvement_iterations: None, stop_on_optimal_score: true },
-    /// #         solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams { initial_temperature: 10.0, final_temperature: 0.1, cooling_schedule: "geometric".to_string(), reheat_after_no_improvement: Some(0), reheat_cycles: Some(0) }),
+    /// #         stop_conditions: StopConditions { max_iterations: Some(1000), time_limit_seconds: None, no_improvement_iterations: None, stop_on_optimal_score: true, stop_on_feasible: false, feasible_score_threshold: None, convergence: None },
+    /// #         solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams { initial_temperature: 10.0, final_temperature: 0.1, cooling_schedule: "geometric".to_string(), reheat_after_no_improvement: Some(0), reheat_cycles: Some(0), penalty_ramp: None }),
     /// #         logging: LoggingOptions::default(),
     /// #         telemetry: Default::default(),
     /// #         seed: None,
@@ -977,29 +1194,135 @@ impl Solver for SimulatedAnnealing {
     fn solve(
         &self,
         state: &mut State,
-        progress_callback: Option<&ProgressCallback>,
+        progress_callback: Option<ProgressHook<'_>>,
         benchmark_observer: Option<&BenchmarkObserver>,
     ) -> Result<SolverResult, SolverError> {
+        self.run_search(state, progress_callback, benchmark_observer, None)
+    }
+}
+
+/// A run to continue from a [`SolverCheckpoint`], with the states rebuilt from its schedules.
+pub(crate) struct ResumePoint {
+    pub(crate) checkpoint: SolverCheckpoint,
+    pub(crate) current_state: State,
+    pub(crate) best_state: State,
+}
+
+impl SimulatedAnnealing {
+    /// Continues a run from a checkpoint taken by an earlier run with the same input.
+    pub(crate) fn resume(
+        &self,
+        resume: ResumePoint,
+        progress_callback: Option<ProgressHook<'_>>,
+        benchmark_observer: Option<&BenchmarkObserver>,
+    ) -> Result<SolverResult, SolverError> {
+        let mut state = resume.current_state.clone();
+        self.run_search(
+            &mut state,
+            progress_callback,
+            benchmark_observer,
+            Some(resume),
+        )
+    }
+
+    fn run_search(
+        &self,
+        state: &mut State,
+        progress_callback: Option<ProgressHook<'_>>,
+        benchmark_observer: Option<&BenchmarkObserver>,
+        resume: Option<ResumePoint>,
+    ) -> Result<SolverResult, SolverError> {
+        if let Some(ramp) = &self.penalty_ramp {
+            ramp.validate().map_err(SolverError::ValidationError)?;
+        }
+        let anneal_span = tracing::info_span!("anneal", seed = state.effective_seed);
+        let _anneal_guard = anneal_span.enter();
         let start_time = get_start_time();
         let mut rng =
             ChaCha12Rng::seed_from_u64(derive_phase_seed(state.effective_seed, SEARCH_SEED_SALT));
         let mut current_state = state.clone();
-        let mut best_state = state.clone();
-        let mut best_cost = state.calculate_cost();
+        let mut best_log = BestScheduleLog::new(&current_state);
+        let mut best_cost = state.current_cost;
+        let mut best_is_feasible = state.hard_violation_count() == 0;
         let mut no_improvement_counter = 0;
         let mut max_no_improvement_streak = 0u64;
+        // Reheating state
+        let mut reheat_count = 0;
+        let mut last_reheat_iteration = 0u64;
+        let mut last_reheat_elapsed_seconds = 0.0_f64;
+        let mut first_iteration = 0u64;
+        let mut elapsed_before_resume = 0.0_f64;
+        // Time spent inside the progress callback, e.g. while a `SolverHandle` is paused. It is
+        // not charged to the time limit or the time-based cooling schedule.
+        let mut callback_blocked_seconds = 0.0_f64;
+        let mut initial_temperature = self.initial_temperature;
+        // Worsening moves (proposed, accepted) of a convergence window a checkpoint interrupted.
+        let mut convergence_carry = (0u64, 0u64);
+        if let Some(resume) = resume {
+            let checkpoint = resume.checkpoint;
+            rng.set_word_pos(u128::from(checkpoint.rng_word_pos));
+            best_log = BestScheduleLog::between(&resume.current_state, &resume.best_state);
+            current_state = resume.current_state;
+            best_cost = checkpoint.best_cost;
+            best_is_feasible = resume.best_state.hard_violation_count() == 0;
+            no_improvement_counter = checkpoint.no_improvement_count;
+            max_no_improvement_streak = checkpoint.max_no_improvement_streak;
+            reheat_count = checkpoint.reheats_performed;
+            last_reheat_iteration = checkpoint.last_reheat_iteration;
+            last_reheat_elapsed_seconds = checkpoint.last_reheat_elapsed_seconds;
+            first_iteration = checkpoint.iterations_completed;
+            elapsed_before_resume = checkpoint.elapsed_seconds;
+            initial_temperature = checkpoint.temperature.unwrap_or(initial_temperature);
+            convergence_carry = (
+                checkpoint.convergence_window_uphill_proposed,
+                checkpoint.convergence_window_uphill_accepted,
+            );
+        }
+        let checkpoint_every = if progress_callback.is_some() {
+            state.telemetry.checkpoint_every_n_iterations
+        } else {
+            0
+        };
         let mut last_callback_time = get_start_time();
         let mut progress_callback_count: u64 = 0;
-        let mut final_iteration = 0;
-        let mut iterations_completed = 0u64;
+        let mut last_best_schedule_snapshot = None;
+        let mut checkpoint_requested = false;
+        let mut reheat_requested = false;
+        let mut reheat_guard = None;
+        let mut final_iteration = first_iteration;
+        let mut iterations_completed = first_iteration;
         let mut stop_reason = StopReason::MaxIterationsReached;
         let mut accepted_uphill_moves = 0u64;
         let mut accepted_downhill_moves = 0u64;
         let mut accepted_neutral_moves = 0u64;
+        let mut final_temperature = (first_iteration > 0).then_some(initial_temperature);
+        let score_history_every = state
+            .telemetry
+            .record_score_history
+            .then(|| state.telemetry.score_history_every_n_iterations.max(1));
+        let mut score_history = Vec::new();
+        let move_statistics_every = state
+            .telemetry
+            .record_move_statistics
+            .then(|| state.telemetry.move_statistics_every_n_iterations.max(1));
+        let mut move_statistics = Vec::new();
+        let mut move_statistics_window_start = MoveCounts::default();
+        let mut move_statistics_reported = 0;
+        let constraint_penalties_every = state.telemetry.record_constraint_penalties.then(|| {
+            state
+                .telemetry
+                .constraint_penalties_every_n_iterations
+                .max(1)
+        });
+        let mut constraint_penalties = Vec::new();
+        let mut convergence_window_start = MoveCounts::default();
+        let mut constraint_penalties_reported = 0;
+        let mut drift_check_every = (state.telemetry.drift_check_every_n_iterations > 0)
+            .then_some(state.telemetry.drift_check_every_n_iterations);
         let initialization_finished_at = get_current_time();
 
         if state.logging.log_initial_score_breakdown {
-            println!(
+            tracing::info!(
                 "Initial state score breakdown: {}",
                 state.format_score_breakdown()
             );
@@ -1015,27 +1338,43 @@ impl Solver for SimulatedAnnealing {
         let movable_people_by_day: Vec<Vec<usize>> = (0..current_state.num_sessions as usize)
             .map(|day| static_move_candidates_for_day(&current_state, day))
             .collect();
+        let mutable_sessions = mutable_sessions(&current_state, &movable_people_by_day);
+        // A track swap changes every session the pair attends, so it needs all of them mutable.
+        let track_swaps_enabled = !current_state.track_swap_pools.is_empty()
+            && mutable_sessions.len() == current_state.num_sessions as usize;
 
-        // Track reheating state
-        let mut reheat_count = 0;
-        let mut last_reheat_iteration = 0u64;
-        let mut last_reheat_elapsed_seconds = 0.0_f64;
         let cycle_length = if self.reheat_cycles > 0 {
             // Avoid division by zero; ensure at least length 1
             (self.max_iterations / self.reheat_cycles).max(1)
         } else {
             0
         };
-        let mut prev_cycle_index: Option<u64> = None;
+        let mut prev_cycle_index: Option<u64> =
+            (first_iteration > 0 && cycle_length > 0).then(|| (first_iteration - 1) / cycle_length);
 
         // Initialize algorithm metrics (convert start_time to f64 for cross-platform compatibility)
-        let initial_score = state.calculate_cost();
+        let initial_score = state.current_cost;
         let mut best_score_timeline = vec![BestScoreTimelinePoint {
             iteration: 0,
             elapsed_seconds: 0.0,
             best_score: initial_score,
         }];
 
+        if score_history_every.is_some() {
+            score_history.push(ScoreHistoryPoint {
+                iteration: first_iteration,
+                current_cost: initial_score,
+                best_cost,
+                temperature: Some(initial_temperature),
+            });
+        }
+        if constraint_penalties_every.is_some() {
+            constraint_penalties.push(ConstraintPenaltyPoint {
+                iteration: first_iteration,
+                penalties: current_state.constraint_penalties(),
+            });
+        }
+
         let mut metrics = AlgorithmMetrics::new(initial_score);
         let mut benchmark_moves = BenchmarkMoveTelemetry::default();
 
@@ -1054,10 +1393,44 @@ impl Solver for SimulatedAnnealing {
         }
 
         if stop_reason != StopReason::OptimalScoreReached {
-            for i in 0..self.max_iterations {
+            for i in first_iteration..self.max_iterations {
+                // Taken before this iteration touches any search state.
+                let checkpoint_due = std::mem::take(&mut checkpoint_requested)
+                    || (checkpoint_every > 0
+                        && i > first_iteration
+                        && i.is_multiple_of(checkpoint_every));
+                let checkpoint = checkpoint_due.then(|| {
+                    let window =
+                        metrics
+                            .move_counts()
+                            .window_since(&convergence_window_start, i, None);
+                    SolverCheckpoint {
+                        effective_seed: state.effective_seed,
+                        iterations_completed: i,
+                        elapsed_seconds: elapsed_before_resume + get_elapsed_seconds(start_time)
+                            - callback_blocked_seconds,
+                        current_schedule: current_state.api_schedule(),
+                        best_schedule: best_log.best_api_schedule(&current_state),
+                        best_cost,
+                        no_improvement_count: no_improvement_counter,
+                        max_no_improvement_streak,
+                        reheats_performed: reheat_count,
+                        last_reheat_iteration,
+                        last_reheat_elapsed_seconds,
+                        rng_word_pos: rng.get_word_pos() as u64,
+                        move_policy: Some(state.move_policy.clone()),
+                        temperature: final_temperature,
+                        convergence_window_uphill_proposed: convergence_carry.0
+                            + window.uphill_proposed,
+                        convergence_window_uphill_accepted: convergence_carry.1
+                            + window.uphill_accepted,
+                    }
+                });
+
                 final_iteration = i;
                 iterations_completed = i + 1;
-                let elapsed_since_start = get_elapsed_seconds(start_time);
+                let elapsed_since_start = elapsed_before_resume + get_elapsed_seconds(start_time)
+                    - callback_blocked_seconds;
 
                 // Two reheating modes:
                 // 1) Fixed cycle-based reheats if reheat_cycles > 0
@@ -1071,8 +1444,9 @@ impl Solver for SimulatedAnnealing {
                             last_reheat_iteration = cycle_index * cycle_length;
                             last_reheat_elapsed_seconds = elapsed_since_start;
                             no_improvement_counter = 0;
+                            enter_reheat_span(&mut reheat_guard, &anneal_span, reheat_count, i);
                             if state.logging.log_stop_condition {
-                                println!(
+                                tracing::info!(
                                     "Reheating (cycle) #{} at iteration {} (cycle {} of {})",
                                     reheat_count,
                                     i,
@@ -1093,26 +1467,36 @@ impl Solver for SimulatedAnnealing {
                     last_reheat_iteration = i;
                     last_reheat_elapsed_seconds = elapsed_since_start;
                     no_improvement_counter = 0; // Reset the no improvement counter
+                    enter_reheat_span(&mut reheat_guard, &anneal_span, reheat_count, i);
 
                     if state.logging.log_stop_condition {
-                        println!(
+                        tracing::info!(
                             "Reheating #{} at iteration {}: no improvement for {} iterations",
-                            reheat_count, i, self.reheat_after_no_improvement
+                            reheat_count,
+                            i,
+                            self.reheat_after_no_improvement
+                        );
+                    }
+                }
+                if std::mem::take(&mut reheat_requested) && last_reheat_iteration != i {
+                    reheat_count += 1;
+                    last_reheat_iteration = i;
+                    last_reheat_elapsed_seconds = elapsed_since_start;
+                    no_improvement_counter = 0;
+                    enter_reheat_span(&mut reheat_guard, &anneal_span, reheat_count, i);
+
+                    if state.logging.log_stop_condition {
+                        tracing::info!(
+                            "Reheating #{} at iteration {}: requested by the progress callback",
+                            reheat_count,
+                            i
                         );
                     }
                 }
 
                 // Calculate temperature with potential reheat adjustment
                 let (iterations_since_last_reheat, remaining_for_cooling) =
-                    if self.reheat_cycles > 0 && cycle_length > 0 {
-                        let cycle_index = i / cycle_length;
-                        let cycle_start = cycle_index * cycle_length;
-                        (i - cycle_start, cycle_length)
-                    } else {
-                        let iterations_since_last_reheat = i - last_reheat_iteration;
-                        let remaining_iterations = self.max_iterations - last_reheat_iteration;
-                        (iterations_since_last_reheat, remaining_iterations)
-                    };
+                    self.cooling_window(i, cycle_length, last_reheat_iteration);
 
                 let elapsed_since_last_reheat =
                     (elapsed_since_start - last_reheat_elapsed_seconds).max(0.0);
@@ -1130,6 +1514,8 @@ impl Solver for SimulatedAnnealing {
                     self.final_temperature,
                     cooling_progress,
                 );
+                final_temperature = Some(temperature);
+                let penalty_scale = self.penalty_scale(i, elapsed_since_start);
 
                 let mut improvement_found = false;
 
@@ -1142,16 +1528,15 @@ impl Solver for SimulatedAnnealing {
                     // Call on first iteration or after sufficient time has passed
                     // Add minimum 50ms gap to prevent excessive callbacks
                     // NOTE: We don't call on last iteration here because we send a final callback after recalculation
-                    if i == 0 || elapsed_since_last_callback >= 0.1 {
+                    if i == first_iteration
+                        || elapsed_since_last_callback >= 0.1
+                        || checkpoint.is_some()
+                    {
                         progress_callback_count += 1;
                         let current_cost = current_state.current_cost;
                         let elapsed = elapsed_since_start;
-                        let iterations_since_last_reheat =
-                            if self.reheat_cycles > 0 && cycle_length > 0 {
-                                i - ((i / cycle_length) * cycle_length)
-                            } else {
-                                i - last_reheat_iteration
-                            };
+                        let (iterations_since_last_reheat, remaining_iterations_for_progress) =
+                            self.cooling_window(i, cycle_length, last_reheat_iteration);
 
                         // Calculate dynamic metrics
                         let (
@@ -1166,12 +1551,6 @@ impl Solver for SimulatedAnnealing {
                             search_efficiency,
                         ) = metrics.calculate_metrics(elapsed);
 
-                        let remaining_iterations_for_progress =
-                            if self.reheat_cycles > 0 && cycle_length > 0 {
-                                cycle_length
-                            } else {
-                                self.max_iterations - last_reheat_iteration
-                            };
                         let elapsed_since_last_reheat =
                             (elapsed - last_reheat_elapsed_seconds).max(0.0);
                         let remaining_time_for_progress = self
@@ -1197,6 +1576,19 @@ impl Solver for SimulatedAnnealing {
                         } else {
                             false
                         };
+                        let (best_schedule, best_schedule_diff) = if !include_best_schedule {
+                            (None, None)
+                        } else if !state.telemetry.best_schedule_as_diff {
+                            (Some(best_log.best_api_schedule(&current_state)), None)
+                        } else {
+                            let schedule = best_log.best_api_schedule(&current_state);
+                            match last_best_schedule_snapshot.replace(schedule.clone()) {
+                                Some(previous) => {
+                                    (None, Some(diff_schedules(&previous, &schedule)))
+                                }
+                                None => (Some(schedule), None),
+                            }
+                        };
 
                         let progress = ProgressUpdate {
                             // Basic progress information
@@ -1211,11 +1603,22 @@ impl Solver for SimulatedAnnealing {
                             temperature,
                             current_score: current_cost,
                             best_score: best_cost,
-                            current_contacts: current_state.unique_contacts,
-                            best_contacts: best_state.unique_contacts,
+                            current_contacts: current_state.scheduled_unique_contacts(),
+                            best_contacts: best_log.best_unique_contacts(),
                             repetition_penalty: current_state.repetition_penalty,
                             elapsed_seconds: elapsed,
                             no_improvement_count: no_improvement_counter,
+                            estimated_remaining_seconds: estimated_remaining_seconds(
+                                i,
+                                self.max_iterations,
+                                elapsed,
+                                self.time_limit_seconds,
+                            ),
+                            phase: Some(SolverPhase::from_search_progress(
+                                i,
+                                reheat_count,
+                                cooling_progress,
+                            )),
 
                             // Move type statistics
                             clique_swaps_tried: metrics.clique_swaps_tried,
@@ -1239,8 +1642,7 @@ impl Solver for SimulatedAnnealing {
                             biggest_attempted_increase: metrics.biggest_attempted_increase,
 
                             // Current state breakdown
-                            current_repetition_penalty: current_state.repetition_penalty as f64
-                                * current_state.w_repetition,
+                            current_repetition_penalty: current_state.weighted_repetition_penalty(),
                             current_balance_penalty: current_state.attribute_balance_penalty,
                             current_constraint_penalty: current_state.weighted_constraint_penalty,
                             best_repetition_penalty: metrics.best_repetition_penalty,
@@ -1263,29 +1665,43 @@ impl Solver for SimulatedAnnealing {
                             score_variance,
                             search_efficiency,
                             // Optional best schedule snapshot (expensive; gated by telemetry settings)
-                            best_schedule: if include_best_schedule {
-                                Some(
-                                    best_state
-                                        .to_solver_result(best_cost, no_improvement_counter)
-                                        .schedule,
-                                )
-                            } else {
-                                None
-                            },
+                            best_schedule,
+                            best_schedule_diff,
                             effective_seed: Some(state.effective_seed),
                             move_policy: Some(state.move_policy.clone()),
                             stop_reason: None,
+                            checkpoint,
+                            move_statistics: move_statistics_every.map(|_| {
+                                let points = move_statistics[move_statistics_reported..].to_vec();
+                                move_statistics_reported = move_statistics.len();
+                                points
+                            }),
+                            constraint_penalties: constraint_penalties_every.map(|_| {
+                                let points =
+                                    constraint_penalties[constraint_penalties_reported..].to_vec();
+                                constraint_penalties_reported = constraint_penalties.len();
+                                points
+                            }),
                         };
 
-                        // If callback returns false, stop early
-                        if !callback(&progress) {
-                            stop_reason = StopReason::ProgressCallbackRequestedStop;
-                            if state.logging.log_stop_condition {
-                                println!(
-                                    "Stopping early: progress callback requested termination."
-                                );
+                        let report_started_at = get_current_time();
+                        let control = callback.report(&progress);
+                        callback_blocked_seconds +=
+                            get_elapsed_seconds_between(report_started_at, get_current_time());
+                        match control {
+                            ProgressControl::Continue => {}
+                            ProgressControl::Stop => {
+                                stop_reason = StopReason::ProgressCallbackRequestedStop;
+                                if state.logging.log_stop_condition {
+                                    tracing::info!(
+                                        "Stopping early: progress callback requested termination."
+                                    );
+                                }
+                                break;
                             }
-                            break;
+                            // Both take effect at the top of the next iteration.
+                            ProgressControl::SaveCheckpoint => checkpoint_requested = true,
+                            ProgressControl::Reheat => reheat_requested = true,
                         }
 
                         // Only update callback time if we actually called the callback
@@ -1293,16 +1709,11 @@ impl Solver for SimulatedAnnealing {
                     }
                 }
 
-                // --- Choose a random move (respect allowed sessions if configured) ---
-                let day = if let Some(ref allowed) = state.allowed_sessions {
-                    if allowed.is_empty() {
-                        continue;
-                    }
-                    let idx = rng.random_range(0..allowed.len());
-                    allowed[idx] as usize
-                } else {
-                    rng.random_range(0..current_state.num_sessions as usize)
-                };
+                // --- Choose a random move in a session that can change ---
+                if mutable_sessions.is_empty() {
+                    continue;
+                }
+                let day = mutable_sessions[rng.random_range(0..mutable_sessions.len())];
 
                 // Use pre-calculated move probabilities for performance
                 let transfer_probability = transfer_probabilities[day];
@@ -1313,7 +1724,75 @@ impl Solver for SimulatedAnnealing {
                     &mut rng,
                 );
 
-                if chosen_family == MoveFamily::CliqueSwap {
+                let track_swap = if chosen_family == MoveFamily::Swap
+                    && track_swaps_enabled
+                    && rng.random::<f64>() < TRACK_SWAP_SHARE
+                {
+                    sample_track_swap(&current_state, &mut rng)
+                } else {
+                    None
+                };
+
+                if let Some((p1_idx, p2_idx)) = track_swap {
+                    // === TRACK SWAP ===
+                    // Applied up front and undone on rejection: each session's delta depends on
+                    // the sessions already swapped.
+                    for session in 0..current_state.num_sessions as usize {
+                        if current_state.person_participation[p1_idx][session] {
+                            best_log.before_move(&current_state, session);
+                        }
+                    }
+                    let current_cost = current_state.current_cost;
+                    let preview_started_at = get_current_time();
+                    let (delta_cost, objective_delta) =
+                        current_state.apply_track_swap(p1_idx, p2_idx);
+                    let preview_seconds =
+                        get_elapsed_seconds_between(preview_started_at, get_current_time());
+                    let next_cost = current_cost + delta_cost;
+                    let telemetry = benchmark_moves.family_mut(MoveFamily::Swap);
+                    telemetry.attempts += 1;
+                    telemetry.preview_seconds += preview_seconds;
+
+                    let acceptance_delta = penalty_scale.map_or(delta_cost, |scale| {
+                        ramped_acceptance_delta(delta_cost, objective_delta, scale)
+                    });
+                    let move_accepted = acceptance_delta < 0.0
+                        || rng.random::<f64>() < (-acceptance_delta / temperature).exp();
+
+                    if move_accepted {
+                        current_state.current_cost = next_cost;
+                        if next_cost < best_cost {
+                            let verified_cost =
+                                verified_best_candidate_cost(&current_state, telemetry);
+                            if verified_cost < best_cost {
+                                best_cost = verified_cost;
+                                best_log.record_best(&current_state);
+                                no_improvement_counter = 0;
+                                improvement_found = true;
+                                best_score_timeline.push(BestScoreTimelinePoint {
+                                    iteration: i + 1,
+                                    elapsed_seconds: get_elapsed_seconds(start_time),
+                                    best_score: best_cost,
+                                });
+                            }
+                        }
+                        telemetry.accepted += 1;
+                        if delta_cost < 0.0 {
+                            telemetry.improving_accepts += 1;
+                            accepted_downhill_moves += 1;
+                        } else if delta_cost > 0.0 {
+                            accepted_uphill_moves += 1;
+                        } else {
+                            accepted_neutral_moves += 1;
+                        }
+                    } else {
+                        current_state.apply_track_swap(p1_idx, p2_idx);
+                        current_state.current_cost = current_cost;
+                        telemetry.rejected += 1;
+                    }
+
+                    metrics.record_swap(delta_cost, move_accepted);
+                } else if chosen_family == MoveFamily::CliqueSwap {
                     // === CLIQUE SWAP ===
                     // --- Attempt Clique Swap ---
                     let clique_idx = rng.random_range(0..current_state.cliques.len());
@@ -1378,14 +1857,26 @@ impl Solver for SimulatedAnnealing {
 
                             //let current_cost = current_state.current_cost;
                             //let next_cost = current_cost + delta_cost;
+                            let acceptance_delta = penalty_scale.map_or(delta_cost, |scale| {
+                                let objective_delta = current_state
+                                    .calculate_clique_swap_objective_delta(
+                                        day,
+                                        clique_idx,
+                                        current_group,
+                                        target_group,
+                                        &target_people,
+                                    );
+                                ramped_acceptance_delta(delta_cost, objective_delta, scale)
+                            });
 
                             // Accept or reject the clique swap
-                            let move_accepted = delta_cost < 0.0
-                                || rng.random::<f64>() < (-delta_cost / temperature).exp();
+                            let move_accepted = acceptance_delta < 0.0
+                                || rng.random::<f64>() < (-acceptance_delta / temperature).exp();
 
                             if move_accepted {
                                 let prev_cost = current_state.current_cost;
                                 let apply_started_at = get_current_time();
+                                best_log.before_move(&current_state, day);
                                 current_state.apply_clique_swap(
                                     day,
                                     clique_idx,
@@ -1411,15 +1902,15 @@ impl Solver for SimulatedAnnealing {
                                             current_state.validate_no_duplicate_assignments()
                                         {
                                             if state.logging.debug_dump_invariant_context {
-                                                println!("INVARIANT VIOLATION CONTEXT:");
-                                                println!("  Iteration: {}", i);
-                                                println!("  Move: CLIQUE_SWAP day={} clique_idx={} from_group={} to_group={} swapped_out={}",
-                                                day,
-                                                clique_idx,
-                                                state.group_idx_to_id[current_group].clone(),
-                                                state.group_idx_to_id[target_group].clone(),
-                                                target_people.len()
-                                            );
+                                                tracing::error!(
+                                                    iteration = i,
+                                                    day,
+                                                    clique_idx,
+                                                    from_group = %state.group_idx_to_id[current_group],
+                                                    to_group = %state.group_idx_to_id[target_group],
+                                                    swapped_out = target_people.len(),
+                                                    "invariant violation after clique swap"
+                                                );
                                                 for (g_idx, g) in current_state.schedule[day]
                                                     .iter()
                                                     .enumerate()
@@ -1431,12 +1922,11 @@ impl Solver for SimulatedAnnealing {
                                                             current_state.display_person_by_idx(pid)
                                                         })
                                                         .collect();
-                                                    println!(
-                                                        "    group {} ({}): {:?}",
-                                                        g_idx,
-                                                        current_state.group_idx_to_id[g_idx]
-                                                            .clone(),
-                                                        names
+                                                    tracing::error!(
+                                                        group = g_idx,
+                                                        group_id = %current_state.group_idx_to_id[g_idx],
+                                                        members = ?names,
+                                                        "group after move"
                                                     );
                                                 }
                                             }
@@ -1447,7 +1937,7 @@ impl Solver for SimulatedAnnealing {
 
                                 if actual_current_cost < best_cost {
                                     best_cost = actual_current_cost;
-                                    best_state = current_state.clone();
+                                    best_log.record_best(&current_state);
                                     no_improvement_counter = 0;
                                     improvement_found = true;
                                     best_score_timeline.push(BestScoreTimelinePoint {
@@ -1507,12 +1997,21 @@ impl Solver for SimulatedAnnealing {
                             telemetry.attempts += 1;
                             telemetry.preview_seconds += preview_seconds;
 
+                            let acceptance_delta = penalty_scale.map_or(delta_cost, |scale| {
+                                let objective_delta = current_state
+                                    .calculate_transfer_objective_delta(
+                                        day, person_idx, from_group, to_group,
+                                    );
+                                ramped_acceptance_delta(delta_cost, objective_delta, scale)
+                            });
+
                             // Accept or reject the transfer
-                            let move_accepted = delta_cost < 0.0
-                                || rng.random::<f64>() < (-delta_cost / temperature).exp();
+                            let move_accepted = acceptance_delta < 0.0
+                                || rng.random::<f64>() < (-acceptance_delta / temperature).exp();
 
                             if move_accepted {
                                 let apply_started_at = get_current_time();
+                                best_log.before_move(&current_state, day);
                                 current_state.apply_transfer(day, person_idx, from_group, to_group);
                                 telemetry.apply_seconds += get_elapsed_seconds_between(
                                     apply_started_at,
@@ -1529,15 +2028,14 @@ impl Solver for SimulatedAnnealing {
                                             current_state.validate_no_duplicate_assignments()
                                         {
                                             if state.logging.debug_dump_invariant_context {
-                                                println!("INVARIANT VIOLATION CONTEXT:");
-                                                println!("  Iteration: {}", i);
-                                                println!(
-                                                "  Move: TRANSFER day={} person={} from={} to={}",
-                                                day,
-                                                current_state.display_person_by_idx(person_idx),
-                                                state.group_idx_to_id[from_group].clone(),
-                                                state.group_idx_to_id[to_group].clone()
-                                            );
+                                                tracing::error!(
+                                                    iteration = i,
+                                                    day,
+                                                    person = %current_state.display_person_by_idx(person_idx),
+                                                    from = %state.group_idx_to_id[from_group],
+                                                    to = %state.group_idx_to_id[to_group],
+                                                    "invariant violation after transfer"
+                                                );
                                                 for (g_idx, g) in current_state.schedule[day]
                                                     .iter()
                                                     .enumerate()
@@ -1549,12 +2047,11 @@ impl Solver for SimulatedAnnealing {
                                                             current_state.display_person_by_idx(pid)
                                                         })
                                                         .collect();
-                                                    println!(
-                                                        "    group {} ({}): {:?}",
-                                                        g_idx,
-                                                        current_state.group_idx_to_id[g_idx]
-                                                            .clone(),
-                                                        names
+                                                    tracing::error!(
+                                                        group = g_idx,
+                                                        group_id = %current_state.group_idx_to_id[g_idx],
+                                                        members = ?names,
+                                                        "group after move"
                                                     );
                                                 }
                                             }
@@ -1564,20 +2061,11 @@ impl Solver for SimulatedAnnealing {
                                 }
 
                                 if next_cost < best_cost {
-                                    // Recalculate to eliminate any incremental drift before
-                                    // recording a new best and to keep telemetry consistent.
-                                    let recalc_started_at = get_current_time();
-                                    current_state._recalculate_scores();
-                                    let verified_cost = current_state.calculate_cost();
-                                    telemetry.full_recalculation_count += 1;
-                                    telemetry.full_recalculation_seconds +=
-                                        get_elapsed_seconds_between(
-                                            recalc_started_at,
-                                            get_current_time(),
-                                        );
+                                    let verified_cost =
+                                        verified_best_candidate_cost(&current_state, telemetry);
                                     if verified_cost < best_cost {
                                         best_cost = verified_cost;
-                                        best_state = current_state.clone();
+                                        best_log.record_best(&current_state);
                                         no_improvement_counter = 0;
                                         improvement_found = true;
                                         best_score_timeline.push(BestScoreTimelinePoint {
@@ -1633,19 +2121,27 @@ impl Solver for SimulatedAnnealing {
                     telemetry.attempts += 1;
                     telemetry.preview_seconds += preview_seconds;
 
-                    let move_accepted =
-                        delta_cost < 0.0 || rng.random::<f64>() < (-delta_cost / temperature).exp();
+                    let acceptance_delta = penalty_scale.map_or(delta_cost, |scale| {
+                        let objective_delta =
+                            current_state.calculate_swap_objective_delta(day, p1_idx, p2_idx);
+                        ramped_acceptance_delta(delta_cost, objective_delta, scale)
+                    });
+
+                    let move_accepted = acceptance_delta < 0.0
+                        || rng.random::<f64>() < (-acceptance_delta / temperature).exp();
 
                     if move_accepted {
                         // Debug: For zero temperature, we should only accept improving moves
-                        if temperature == 0.0 && delta_cost >= 0.0 {
-                            println!("WARNING: Hill climbing violation!");
-                            println!("  temperature: {}", temperature);
-                            println!("  delta_cost: {}", delta_cost);
-                            println!("  accepted non-improving move with zero temperature");
+                        if temperature == 0.0 && acceptance_delta >= 0.0 {
+                            tracing::warn!(
+                                temperature,
+                                delta_cost,
+                                "hill climbing violation: accepted non-improving move with zero temperature"
+                            );
                         }
 
                         let apply_started_at = get_current_time();
+                        best_log.before_move(&current_state, day);
                         current_state.apply_swap(day, p1_idx, p2_idx);
                         telemetry.apply_seconds +=
                             get_elapsed_seconds_between(apply_started_at, get_current_time());
@@ -1657,15 +2153,13 @@ impl Solver for SimulatedAnnealing {
                             if state.logging.debug_validate_invariants {
                                 if let Err(e) = current_state.validate_no_duplicate_assignments() {
                                     if state.logging.debug_dump_invariant_context {
-                                        println!("INVARIANT VIOLATION CONTEXT:");
-                                        println!("  Iteration: {}", i);
-                                        println!(
-                                            "  Move: SWAP day={} p1={} p2={}",
+                                        tracing::error!(
+                                            iteration = i,
                                             day,
-                                            current_state.display_person_by_idx(p1_idx),
-                                            current_state.display_person_by_idx(p2_idx)
+                                            p1 = %current_state.display_person_by_idx(p1_idx),
+                                            p2 = %current_state.display_person_by_idx(p2_idx),
+                                            "invariant violation after swap"
                                         );
-                                        println!("  After move (first 3 groups of day):");
                                         for (g_idx, g) in
                                             current_state.schedule[day].iter().enumerate().take(3)
                                         {
@@ -1675,11 +2169,11 @@ impl Solver for SimulatedAnnealing {
                                                     current_state.display_person_by_idx(pid)
                                                 })
                                                 .collect();
-                                            println!(
-                                                "    group {} ({}): {:?}",
-                                                g_idx,
-                                                current_state.group_idx_to_id[g_idx].clone(),
-                                                names
+                                            tracing::error!(
+                                                group = g_idx,
+                                                group_id = %current_state.group_idx_to_id[g_idx],
+                                                members = ?names,
+                                                "group after move"
                                             );
                                         }
                                     }
@@ -1689,17 +2183,11 @@ impl Solver for SimulatedAnnealing {
                         }
 
                         if next_cost < best_cost {
-                            // Recalculate to eliminate any incremental drift before
-                            // recording a new best and to keep telemetry consistent.
-                            let recalc_started_at = get_current_time();
-                            current_state._recalculate_scores();
-                            let verified_cost = current_state.calculate_cost();
-                            telemetry.full_recalculation_count += 1;
-                            telemetry.full_recalculation_seconds +=
-                                get_elapsed_seconds_between(recalc_started_at, get_current_time());
+                            let verified_cost =
+                                verified_best_candidate_cost(&current_state, telemetry);
                             if verified_cost < best_cost {
                                 best_cost = verified_cost;
-                                best_state = current_state.clone();
+                                best_log.record_best(&current_state);
                                 no_improvement_counter = 0;
                                 improvement_found = true;
                                 best_score_timeline.push(BestScoreTimelinePoint {
@@ -1744,7 +2232,9 @@ impl Solver for SimulatedAnnealing {
                 // }
 
                 // --- Stop Conditions ---
-                if !improvement_found {
+                if improvement_found {
+                    best_is_feasible = current_state.hard_violation_count() == 0;
+                } else {
                     no_improvement_counter += 1;
                     max_no_improvement_streak =
                         max_no_improvement_streak.max(no_improvement_counter);
@@ -1754,7 +2244,22 @@ impl Solver for SimulatedAnnealing {
                 {
                     stop_reason = StopReason::OptimalScoreReached;
                     if state.logging.log_stop_condition {
-                        println!("Stopping early: optimal score of 0 reached.");
+                        tracing::info!("Stopping early: optimal score of 0 reached.");
+                    }
+                    break;
+                }
+
+                if self.stop_on_feasible
+                    && best_is_feasible
+                    && self
+                        .feasible_score_threshold
+                        .is_none_or(|threshold| best_cost <= threshold)
+                {
+                    stop_reason = StopReason::FeasibleReached;
+                    if state.logging.log_stop_condition {
+                        tracing::info!(
+                            "Stopping early: feasible schedule with score {best_cost:.2} reached."
+                        );
                     }
                     break;
                 }
@@ -1763,9 +2268,9 @@ impl Solver for SimulatedAnnealing {
                     if no_improvement_counter >= no_improvement_limit {
                         stop_reason = StopReason::NoImprovementLimitReached;
                         if state.logging.log_stop_condition {
-                            println!(
-                            "Stopping early: no improvement for {no_improvement_limit} iterations."
-                        );
+                            tracing::info!(
+                                "Stopping early: no improvement for {no_improvement_limit} iterations."
+                            );
                         }
                         break;
                     }
@@ -1775,16 +2280,100 @@ impl Solver for SimulatedAnnealing {
                     if elapsed_since_start >= time_limit as f64 {
                         stop_reason = StopReason::TimeLimitReached;
                         if state.logging.log_stop_condition {
-                            println!("Stopping early: time limit of {time_limit} seconds reached.");
+                            tracing::info!(
+                                "Stopping early: time limit of {time_limit} seconds reached."
+                            );
                         }
                         break;
                     }
                 }
 
+                if let Some(convergence) = &self.convergence {
+                    let window = convergence.window_iterations.max(1);
+                    if iterations_completed.is_multiple_of(window) {
+                        let counts = metrics.move_counts();
+                        let window_moves = counts.window_since(
+                            &convergence_window_start,
+                            iterations_completed,
+                            None,
+                        );
+                        let (carried_proposed, carried_accepted) =
+                            std::mem::take(&mut convergence_carry);
+                        let uphill_proposed = window_moves.uphill_proposed + carried_proposed;
+                        let uphill_accepted = window_moves.uphill_accepted + carried_accepted;
+                        // Neutral moves stay acceptable on a plateau; only worsening ones count.
+                        let acceptance_rate = if uphill_proposed > 0 {
+                            uphill_accepted as f64 / uphill_proposed as f64
+                        } else {
+                            0.0
+                        };
+                        convergence_window_start = counts;
+                        if no_improvement_counter >= window
+                            && acceptance_rate <= convergence.max_acceptance_rate
+                            && convergence
+                                .max_temperature
+                                .is_none_or(|max_temperature| temperature <= max_temperature)
+                        {
+                            stop_reason = StopReason::Converged;
+                            if state.logging.log_stop_condition {
+                                tracing::info!(
+                                    "Stopping early: converged with {:.2}% of worsening moves accepted over the last {window} iterations at temperature {temperature:.4}.",
+                                    acceptance_rate * 100.0
+                                );
+                            }
+                            break;
+                        }
+                    }
+                }
+
+                if drift_check_every.is_some_and(|every| iterations_completed.is_multiple_of(every))
+                {
+                    if let Some(drift) = current_state.first_cache_drift() {
+                        tracing::warn!(
+                            iteration = iterations_completed,
+                            "score drift detected: {drift}"
+                        );
+                        // Later components usually drift as a consequence; report only the first.
+                        drift_check_every = None;
+                    }
+                }
+
+                if score_history_every
+                    .is_some_and(|every| iterations_completed.is_multiple_of(every))
+                {
+                    score_history.push(ScoreHistoryPoint {
+                        iteration: iterations_completed,
+                        current_cost: current_state.current_cost,
+                        best_cost,
+                        temperature: Some(temperature),
+                    });
+                }
+
+                if move_statistics_every
+                    .is_some_and(|every| iterations_completed.is_multiple_of(every))
+                {
+                    let counts = metrics.move_counts();
+                    move_statistics.push(counts.window_since(
+                        &move_statistics_window_start,
+                        iterations_completed,
+                        Some(temperature),
+                    ));
+                    move_statistics_window_start = counts;
+                }
+
+                if constraint_penalties_every
+                    .is_some_and(|every| iterations_completed.is_multiple_of(every))
+                {
+                    constraint_penalties.push(ConstraintPenaltyPoint {
+                        iteration: iterations_completed,
+                        penalties: current_state.constraint_penalties(),
+                    });
+                }
+
                 // Update algorithm metrics (delta tracking handled in individual move blocks)
                 metrics.update_score(current_state.current_cost);
                 metrics.update_best_penalties(
-                    current_state.repetition_penalty as f64 * current_state.w_repetition,
+                    current_state.weighted_repetition_penalty(),
                     current_state.attribute_balance_penalty,
                     current_state.weighted_constraint_penalty,
                 );
@@ -1793,13 +2382,54 @@ impl Solver for SimulatedAnnealing {
 
         let search_finished_at = get_current_time();
 
+        // Runs that stop early end between samples; close the trace with the last iteration.
+        if score_history_every.is_some()
+            && score_history
+                .last()
+                .is_some_and(|point| point.iteration < iterations_completed)
+        {
+            score_history.push(ScoreHistoryPoint {
+                iteration: iterations_completed,
+                current_cost: current_state.current_cost,
+                best_cost,
+                temperature: final_temperature,
+            });
+        }
+        if move_statistics_every.is_some()
+            && move_statistics
+                .last()
+                .map_or(first_iteration, |point| point.iteration)
+                < iterations_completed
+        {
+            move_statistics.push(metrics.move_counts().window_since(
+                &move_statistics_window_start,
+                iterations_completed,
+                final_temperature,
+            ));
+        }
+        if constraint_penalties_every.is_some()
+            && constraint_penalties
+                .last()
+                .is_some_and(|point| point.iteration < iterations_completed)
+        {
+            constraint_penalties.push(ConstraintPenaltyPoint {
+                iteration: iterations_completed,
+                penalties: current_state.constraint_penalties(),
+            });
+        }
+
+        let mut best_state = current_state;
+        best_log.restore(&mut best_state);
+
         // Validate that our incremental tracking matches full recalculation
         let recalculated_cost = best_state.calculate_cost();
         if (recalculated_cost - best_cost).abs() > 0.001 {
-            println!("WARNING: Algorithm inconsistency detected!");
-            println!("  tracked best_cost: {}", best_cost);
-            println!("  recalculated cost: {}", recalculated_cost);
-            println!("  difference: {}", (recalculated_cost - best_cost).abs());
+            tracing::warn!(
+                tracked_best_cost = best_cost,
+                recalculated_cost,
+                difference = (recalculated_cost - best_cost).abs(),
+                "algorithm inconsistency detected"
+            );
             // Keep telemetry consistent in the final callback by reporting the
             // recalculated value. We avoid mutating best_cost here to prevent
             // an unused assignment warning and because subsequent logic uses
@@ -1861,17 +2491,10 @@ impl Solver for SimulatedAnnealing {
         // IMPORTANT: This must happen AFTER _recalculate_scores() to ensure accurate scores
         if let Some(callback) = &progress_callback {
             let (iterations_since_last_reheat, remaining_for_cooling) =
-                if self.reheat_cycles > 0 && cycle_length > 0 {
-                    let cycle_index = final_iteration / cycle_length;
-                    let cycle_start = cycle_index * cycle_length;
-                    (final_iteration - cycle_start, cycle_length)
-                } else {
-                    let iterations_since_last_reheat = final_iteration - last_reheat_iteration;
-                    let remaining_iterations = self.max_iterations - last_reheat_iteration;
-                    (iterations_since_last_reheat, remaining_iterations)
-                };
+                self.cooling_window(final_iteration, cycle_length, last_reheat_iteration);
 
-            let elapsed = get_elapsed_seconds(start_time);
+            let elapsed =
+                elapsed_before_resume + get_elapsed_seconds(start_time) - callback_blocked_seconds;
             let elapsed_since_last_reheat = (elapsed - last_reheat_elapsed_seconds).max(0.0);
             let remaining_time_for_cooling = self
                 .time_limit_seconds
@@ -1920,11 +2543,17 @@ impl Solver for SimulatedAnnealing {
                 current_score: final_cost, // Use the recalculated final_cost
                 // Report the tracked best_cost (kept consistent when recording bests)
                 best_score: best_cost,
-                current_contacts: best_state.unique_contacts, // These are now recalculated
-                best_contacts: best_state.unique_contacts,    // These are now recalculated
-                repetition_penalty: best_state.repetition_penalty, // This is now recalculated
+                current_contacts: best_state.scheduled_unique_contacts(), // These are now recalculated
+                best_contacts: best_state.scheduled_unique_contacts(), // These are now recalculated
+                repetition_penalty: best_state.repetition_penalty,     // This is now recalculated
                 elapsed_seconds: elapsed,
                 no_improvement_count: no_improvement_counter,
+                estimated_remaining_seconds: Some(0.0),
+                phase: Some(SolverPhase::from_search_progress(
+                    iterations_completed,
+                    reheat_count,
+                    cooling_progress,
+                )),
 
                 // Move type statistics
                 clique_swaps_tried: metrics.clique_swaps_tried,
@@ -1946,8 +2575,7 @@ impl Solver for SimulatedAnnealing {
                 biggest_attempted_increase: metrics.biggest_attempted_increase,
 
                 // Current state breakdown
-                current_repetition_penalty: best_state.repetition_penalty as f64
-                    * best_state.w_repetition,
+                current_repetition_penalty: best_state.weighted_repetition_penalty(),
                 current_balance_penalty: best_state.attribute_balance_penalty,
                 current_constraint_penalty: best_state.weighted_constraint_penalty,
                 best_repetition_penalty: metrics.best_repetition_penalty,
@@ -1978,13 +2606,19 @@ impl Solver for SimulatedAnnealing {
                 } else {
                     None
                 },
+                best_schedule_diff: None,
                 effective_seed: Some(state.effective_seed),
                 move_policy: Some(state.move_policy.clone()),
                 stop_reason: Some(stop_reason),
+                checkpoint: None,
+                move_statistics: move_statistics_every
+                    .map(|_| move_statistics[move_statistics_reported..].to_vec()),
+                constraint_penalties: constraint_penalties_every
+                    .map(|_| constraint_penalties[constraint_penalties_reported..].to_vec()),
             };
 
             // Call the callback one final time (ignore return value since we're done)
-            callback(&final_progress);
+            callback.report(&final_progress);
         }
 
         // Update the state parameter with the final optimized state
@@ -1994,23 +2628,33 @@ impl Solver for SimulatedAnnealing {
         let elapsed = total_seconds;
 
         if state.logging.log_duration_and_score {
-            println!("Solver finished in {elapsed:.2} seconds. Final score: {final_cost:.2}");
+            tracing::info!("Solver finished in {elapsed:.2} seconds. Final score: {final_cost:.2}");
         }
 
         if state.logging.log_final_score_breakdown {
-            println!("Final {}", best_state.format_score_breakdown());
+            tracing::info!("Final {}", best_state.format_score_breakdown());
         }
 
         best_state.validate_scores();
-        let result = best_state.to_solver_result_with_metadata(
+        let mut result = best_state.to_solver_result_with_metadata(
             final_cost,
             no_improvement_counter,
             Some(stop_reason),
             Some(benchmark_telemetry.clone()),
         );
+        result.final_temperature = final_temperature;
+        if score_history_every.is_some() {
+            result.score_history = Some(score_history);
+        }
+        if move_statistics_every.is_some() {
+            result.move_statistics = Some(move_statistics);
+        }
+        if constraint_penalties_every.is_some() {
+            result.constraint_penalties = Some(constraint_penalties);
+        }
 
         if state.logging.display_final_schedule {
-            println!("{}", result.display());
+            tracing::info!("Final schedule:\n{}", result.display());
         }
 
         // Calculate algorithm metrics for potential logging (metrics are available in progress callbacks)
@@ -2024,13 +2668,15 @@ impl Solver for SimulatedAnnealing {
 #[cfg(test)]
 mod tests {
     use super::{
-        cooling_progress_since_reheat, select_clique_source_group, temperature_for_cooling_progress,
+        cooling_progress_since_reheat, mutable_sessions, select_clique_source_group,
+        static_move_candidates_for_day, temperature_for_cooling_progress,
     };
     use crate::models::{
-        ApiInput, Constraint, Group, Objective, Person, ProblemDefinition,
+        ApiInput, Constraint, Group, ImmovablePersonParams, Objective, Person, ProblemDefinition,
         SimulatedAnnealingParams, SolverConfiguration, SolverParams, StopConditions,
     };
     use crate::solver1::State;
+    use smallvec::smallvec;
     use std::collections::HashMap;
 
     fn person(id: &str) -> Person {
@@ -2049,6 +2695,9 @@ mod tests {
                 time_limit_seconds: None,
                 no_improvement_iterations: Some(0),
                 stop_on_optimal_score: true,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
                 initial_temperature: 10.0,
@@ -2056,6 +2705,7 @@ mod tests {
                 cooling_schedule: "geometric".to_string(),
                 reheat_after_no_improvement: Some(0),
                 reheat_cycles: Some(0),
+                penalty_ramp: None,
             }),
             logging: Default::default(),
             telemetry: Default::default(),
@@ -2085,6 +2735,7 @@ mod tests {
         let input = ApiInput {
             initial_schedule: None,
             construction_seed_schedule: None,
+            contact_history: None,
             problem: ProblemDefinition {
                 people: vec![person("p0"), person("p1"), person("p2"), person("p3")],
                 groups: vec![
@@ -2092,11 +2743,15 @@ mod tests {
                         id: "g0".to_string(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                     Group {
                         id: "g1".to_string(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                 ],
                 num_sessions: 1,
@@ -2104,6 +2759,8 @@ mod tests {
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".to_string(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![Constraint::MustStayTogether {
                 people: vec!["p0".to_string(), "p1".to_string()],
@@ -2118,8 +2775,8 @@ mod tests {
         let p2 = state.person_id_to_idx["p2"];
         let p3 = state.person_id_to_idx["p3"];
 
-        state.schedule[0][0] = vec![p0, p2];
-        state.schedule[0][1] = vec![p1, p3];
+        state.schedule[0][0] = smallvec![p0, p2];
+        state.schedule[0][1] = smallvec![p1, p3];
         state.locations[0][p0] = (0, 0);
         state.locations[0][p2] = (0, 1);
         state.locations[0][p1] = (1, 0);
@@ -2128,4 +2785,70 @@ mod tests {
         let clique = &state.cliques[0];
         assert_eq!(select_clique_source_group(&state, clique, 0), Some(0));
     }
+
+    #[test]
+    fn mutable_sessions_skip_pinned_and_disallowed_sessions() {
+        let pin = |person_id: &str, group_id: &str| {
+            Constraint::ImmovablePerson(ImmovablePersonParams {
+                person_id: person_id.to_string(),
+                group_id: group_id.to_string(),
+                sessions: Some(vec![0]),
+            })
+        };
+        let mut input = ApiInput {
+            initial_schedule: None,
+            construction_seed_schedule: None,
+            contact_history: None,
+            problem: ProblemDefinition {
+                people: vec![person("p0"), person("p1"), person("p2"), person("p3")],
+                groups: vec![
+                    Group {
+                        id: "g0".to_string(),
+                        size: 2,
+                        session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
+                    },
+                    Group {
+                        id: "g1".to_string(),
+                        size: 2,
+                        session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
+                    },
+                ],
+                num_sessions: 3,
+            },
+            objectives: vec![Objective {
+                r#type: "maximize_unique_contacts".to_string(),
+                weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
+            }],
+            constraints: vec![
+                pin("p0", "g0"),
+                pin("p1", "g0"),
+                pin("p2", "g1"),
+                pin("p3", "g1"),
+            ],
+            solver: deterministic_solver_config(),
+        };
+
+        let movable_people = |state: &State| -> Vec<Vec<usize>> {
+            (0..3)
+                .map(|day| static_move_candidates_for_day(state, day))
+                .collect()
+        };
+
+        let state = State::new(&input).expect("state should build");
+        assert!(movable_people(&state)[0].is_empty());
+        assert_eq!(
+            mutable_sessions(&state, &movable_people(&state)),
+            vec![1, 2]
+        );
+
+        input.solver.allowed_sessions = Some(vec![0, 2]);
+        let state = State::new(&input).expect("state should build");
+        assert_eq!(mutable_sessions(&state, &movable_people(&state)), vec![2]);
+    }
 }
@@ -0,0 +1,155 @@
+//! Simulated annealing parameter tuning by successive halving.
+//!
+//! The recommendation in [`super::recommendation`] picks temperatures from one set of short
+//! trials. Tuning goes further: it samples parameter sets around that recommendation, races
+//! them on the actual input with short solves, and keeps the better half each round while the
+//! survivors get twice the iterations, so the budget is spent on the promising candidates.
+
+use super::recommendation::recommend_settings;
+use super::simulated_annealing::SimulatedAnnealing;
+use super::Solver as _;
+use crate::engines::{default_solver_configuration_for, RecommendationRequest};
+use crate::models::{
+    ApiInput, LoggingOptions, SimulatedAnnealingParams, SolverConfiguration, SolverKind,
+    SolverParams, StopConditions, TelemetryOptions, TuningBudget,
+};
+use crate::solver1::State;
+use crate::solver_support::SolverError;
+use rand::{RngExt, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+
+/// Seed of the candidate sampling and the races when the input has none.
+const TUNING_SEED: u64 = 0x7e5e_ed00;
+/// Initial temperatures are sampled log-uniformly within this factor of the recommendation.
+const INITIAL_TEMPERATURE_SPREAD: f64 = 4.0;
+/// Final temperatures are sampled log-uniformly within this factor of the recommendation.
+const FINAL_TEMPERATURE_SPREAD: f64 = 10.0;
+const REHEAT_CYCLE_CHOICES: [u64; 3] = [0, 2, 4];
+
+/// Tunes the simulated annealing parameters of `input` within `budget`.
+///
+/// The returned configuration keeps the stop conditions, seed, logging, telemetry, and move
+/// policy of `input.solver`; only the annealing parameters are tuned. Inputs configured for
+/// another solver family get solver1 defaults for everything else.
+pub(crate) fn auto_tune(
+    input: &ApiInput,
+    budget: TuningBudget,
+) -> Result<SolverConfiguration, SolverError> {
+    let configuration = match input.solver.validate_solver_selection() {
+        Ok(SolverKind::Solver1) => input.solver.clone(),
+        _ => {
+            let defaults = default_solver_configuration_for(SolverKind::Solver1);
+            SolverConfiguration {
+                solver_type: defaults.solver_type,
+                solver_params: defaults.solver_params,
+                ..input.solver.clone()
+            }
+        }
+    };
+    let seed = configuration.seed.unwrap_or(TUNING_SEED);
+
+    let recommended = recommend_settings(
+        configuration.clone(),
+        RecommendationRequest {
+            problem: &input.problem,
+            objectives: &input.objectives,
+            constraints: &input.constraints,
+            desired_runtime_seconds: configuration
+                .stop_conditions
+                .time_limit_seconds
+                .unwrap_or(1),
+        },
+    )?
+    .configuration;
+    let SolverParams::SimulatedAnnealing(centre) = recommended.solver_params else {
+        unreachable!("solver1 recommendations use simulated annealing parameters");
+    };
+
+    let mut rng = ChaCha12Rng::seed_from_u64(seed);
+    let mut candidates = vec![centre.clone()];
+    while candidates.len() < budget.candidates.max(1) as usize {
+        candidates.push(sample_candidate(&centre, &mut rng));
+    }
+
+    let mut race_input = input.clone();
+    race_input.solver = SolverConfiguration {
+        logging: LoggingOptions::default(),
+        telemetry: TelemetryOptions::default(),
+        seed: Some(seed),
+        ..configuration.clone()
+    };
+    let state = State::new(&race_input)?;
+
+    let mut iterations = budget.initial_iterations.max(1);
+    let mut round = 0u32;
+    while candidates.len() > 1 {
+        let mut scored = Vec::with_capacity(candidates.len());
+        for candidate in candidates {
+            let cost = race(&state, &race_input.solver, &candidate, iterations)?;
+            scored.push((cost, candidate));
+        }
+        // Stable sort: on ties the earlier candidate (the recommendation first) wins.
+        scored.sort_by(|(left, _), (right, _)| left.total_cmp(right));
+        tracing::debug!(
+            round,
+            iterations,
+            candidates = scored.len(),
+            best_cost = scored[0].0,
+            "auto-tune round finished"
+        );
+        scored.truncate(scored.len().div_ceil(2));
+        candidates = scored.into_iter().map(|(_, candidate)| candidate).collect();
+        iterations = iterations.saturating_mul(2);
+        round += 1;
+    }
+
+    let winner = candidates
+        .pop()
+        .expect("the race always keeps one candidate");
+    Ok(SolverConfiguration {
+        solver_params: SolverParams::SimulatedAnnealing(winner),
+        ..configuration
+    })
+}
+
+/// A parameter set near `centre`, sampled log-uniformly per temperature.
+fn sample_candidate(
+    centre: &SimulatedAnnealingParams,
+    rng: &mut ChaCha12Rng,
+) -> SimulatedAnnealingParams {
+    let mut log_uniform =
+        |value: f64, spread: f64| value * spread.powf(rng.random_range(-1.0..=1.0));
+    let initial_temperature = log_uniform(centre.initial_temperature, INITIAL_TEMPERATURE_SPREAD);
+    let final_temperature = log_uniform(centre.final_temperature, FINAL_TEMPERATURE_SPREAD)
+        .min(initial_temperature / 10.0);
+    let reheat_cycles = REHEAT_CYCLE_CHOICES[rng.random_range(0..REHEAT_CYCLE_CHOICES.len())];
+    SimulatedAnnealingParams {
+        initial_temperature,
+        final_temperature,
+        reheat_cycles: Some(reheat_cycles),
+        ..centre.clone()
+    }
+}
+
+/// Best cost `params` reach from `state` in `iterations`.
+fn race(
+    state: &State,
+    configuration: &SolverConfiguration,
+    params: &SimulatedAnnealingParams,
+    iterations: u64,
+) -> Result<f64, SolverError> {
+    let solver = SimulatedAnnealing::new(&SolverConfiguration {
+        stop_conditions: StopConditions {
+            max_iterations: Some(iterations),
+            time_limit_seconds: None,
+            no_improvement_iterations: None,
+            stop_on_optimal_score: true,
+            stop_on_feasible: false,
+            feasible_score_threshold: None,
+            convergence: None,
+        },
+        solver_params: SolverParams::SimulatedAnnealing(params.clone()),
+        ..configuration.clone()
+    });
+    Ok(solver.solve(&mut state.clone(), None, None)?.final_score)
+}
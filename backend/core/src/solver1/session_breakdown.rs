@@ -0,0 +1,98 @@
+//! Per-session score breakdown for result reporting.
+//!
+//! Contacts and repetition penalties are properties of the whole schedule, so they are
+//! attributed chronologically by replaying the sessions in order on top of the contact
+//! history. Each meeting is charged the change it causes in the pair's weighted repetition
+//! penalty, including the decayed-history and session-weight corrections, so the sessions
+//! add up to the schedule's repetition cost. Constraint penalties come from the itemized
+//! violations of the same schedule.
+
+use super::State;
+use crate::models::{ConstraintViolation, SessionScoreBreakdown};
+use std::collections::HashMap;
+
+impl State {
+    /// Breaks the current schedule's score down by session.
+    pub fn session_breakdown(
+        &self,
+        violations: &[ConstraintViolation],
+    ) -> Vec<SessionScoreBreakdown> {
+        // Replay on a copy of the contact matrix, so large problems keep the compact layout.
+        let mut counts = self.contact_matrix.clone();
+        counts.clear();
+        // Session weight sums of the pairs met so far; history meetings weigh `1.0`.
+        let mut weighted: HashMap<(usize, usize), f64> = HashMap::new();
+        for &(a, b, count) in &self.historical_contacts {
+            counts.add(a, b, count);
+            if !self.session_weights.is_empty() {
+                weighted.insert((a.min(b), a.max(b)), count as f64);
+            }
+        }
+
+        let mut breakdown: Vec<SessionScoreBreakdown> = self
+            .schedule
+            .iter()
+            .enumerate()
+            .map(|(day, day_schedule)| {
+                let mut unique_contacts_gained = 0;
+                let mut repetition_penalty = 0.0;
+                for group in day_schedule {
+                    for (idx, &person_a) in group.iter().enumerate() {
+                        if !self.person_participation[person_a][day] {
+                            continue;
+                        }
+                        for &person_b in &group[idx + 1..] {
+                            if !self.person_participation[person_b][day] {
+                                continue;
+                            }
+                            let count = counts.get(person_a, person_b);
+                            if count == 0 {
+                                unique_contacts_gained += 1;
+                            }
+                            repetition_penalty += (self
+                                .repetition_penalty_for_contact_count(count + 1)
+                                - self.repetition_penalty_for_contact_count(count))
+                                as f64
+                                * self.w_repetition
+                                + self.history_correction_delta(
+                                    person_a,
+                                    person_b,
+                                    count,
+                                    count + 1,
+                                );
+                            if !self.session_weights.is_empty() {
+                                let pair_weight = weighted
+                                    .entry((person_a.min(person_b), person_a.max(person_b)))
+                                    .or_insert(0.0);
+                                let new_weight = *pair_weight + self.session_weights[day];
+                                repetition_penalty += self
+                                    .session_repetition_correction_at(count + 1, new_weight)
+                                    - self.session_repetition_correction_at(count, *pair_weight);
+                                *pair_weight = new_weight;
+                            }
+                            counts.increment(person_a, person_b);
+                        }
+                    }
+                }
+                SessionScoreBreakdown {
+                    session: day as u32,
+                    unique_contacts_gained,
+                    repetition_penalty,
+                    constraint_penalties: HashMap::new(),
+                }
+            })
+            .collect();
+
+        for violation in violations {
+            let Some(session) = violation.session else {
+                continue;
+            };
+            *breakdown[session as usize]
+                .constraint_penalties
+                .entry(violation.constraint_type.clone())
+                .or_insert(0.0) += violation.weighted_penalty;
+        }
+
+        breakdown
+    }
+}
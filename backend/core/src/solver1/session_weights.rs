@@ -0,0 +1,222 @@
+//! Per-session weights for the unique-contacts objective and the repetition penalty.
+//!
+//! `maximize_unique_contacts` may carry `session_weights`. A pair that met `count` times
+//! shares its contact credit and its repetition penalty equally among those meetings, and
+//! each share is scaled by the weight of the session it happened in (history meetings weigh
+//! `1.0`). The state keeps the per-pair sum of meeting weights and tracks the difference to
+//! the unweighted terms in `session_weight_correction`, so the integer contact bookkeeping
+//! stays untouched.
+
+use super::State;
+use crate::models::ApiInput;
+use crate::solver_support::SolverError;
+
+impl State {
+    /// Resolves `session_weights` of the unique-contacts objective.
+    ///
+    /// Returns an empty table when no weights are configured.
+    pub(crate) fn resolve_session_weights(input: &ApiInput) -> Result<Vec<f64>, SolverError> {
+        let Some(objective) = input
+            .objectives
+            .iter()
+            .find(|objective| objective.r#type == "maximize_unique_contacts")
+        else {
+            return Ok(Vec::new());
+        };
+        if objective.session_weights.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let num_sessions = input.problem.num_sessions as usize;
+        if objective.session_weights.len() != num_sessions {
+            return Err(SolverError::ValidationError(format!(
+                "session_weights must have one entry per session (expected {}, got {})",
+                num_sessions,
+                objective.session_weights.len()
+            )));
+        }
+        for (session_idx, &weight) in objective.session_weights.iter().enumerate() {
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(SolverError::ValidationError(format!(
+                    "session_weights for session {} must be a non-negative finite number, got {}",
+                    session_idx, weight
+                )));
+            }
+        }
+
+        Ok(objective.session_weights.clone())
+    }
+
+    /// Scale applied to the baseline score so weighted contacts cannot push it below zero.
+    pub(crate) fn max_session_weight(session_weights: &[f64]) -> f64 {
+        session_weights.iter().copied().fold(1.0, f64::max)
+    }
+
+    /// Unique-contacts part of the correction for a pair with `count` meetings whose
+    /// session weights sum to `weighted`.
+    #[inline]
+    fn session_contact_correction_at(
+        &self,
+        person_a: usize,
+        person_b: usize,
+        count: u32,
+        weighted: f64,
+    ) -> f64 {
+        if count == 0 {
+            return 0.0;
+        }
+        -(weighted / count as f64 - 1.0) * self.pair_contact_value(person_a, person_b)
+    }
+
+    /// Repetition part of the correction for a pair with `count` meetings whose session
+    /// weights sum to `weighted`.
+    #[inline]
+    pub(crate) fn session_repetition_correction_at(&self, count: u32, weighted: f64) -> f64 {
+        if count == 0 {
+            return 0.0;
+        }
+        (weighted / count as f64 - 1.0)
+            * self.w_repetition
+            * self.repetition_penalty_for_contact_count(count) as f64
+    }
+
+    /// Contact count and weight sum of a pair after it meets once more (`direction > 0`) or
+    /// once less (`direction < 0`) in `day`, or `None` if there is nothing to remove.
+    #[inline]
+    fn session_weighted_transition(
+        &self,
+        person_a: usize,
+        person_b: usize,
+        day: usize,
+        direction: i32,
+    ) -> Option<(u32, f64, u32, f64)> {
+        let count = self.contact_matrix.get(person_a, person_b);
+        let weighted = self.session_weighted_contacts[person_a][person_b];
+        let weight = self.session_weights[day];
+        if direction < 0 {
+            if count == 0 {
+                return None;
+            }
+            let new_weighted = if count == 1 { 0.0 } else { weighted - weight };
+            Some((count, weighted, count - 1, new_weighted))
+        } else {
+            Some((count, weighted, count + 1, weighted + weight))
+        }
+    }
+
+    /// Unique-contacts part of the correction change for one pair meeting change in `day`.
+    #[inline]
+    pub(crate) fn session_contact_pair_delta(
+        &self,
+        person_a: usize,
+        person_b: usize,
+        day: usize,
+        direction: i32,
+    ) -> f64 {
+        if self.session_weights.is_empty() {
+            return 0.0;
+        }
+        let Some((count, weighted, new_count, new_weighted)) =
+            self.session_weighted_transition(person_a, person_b, day, direction)
+        else {
+            return 0.0;
+        };
+        self.session_contact_correction_at(person_a, person_b, new_count, new_weighted)
+            - self.session_contact_correction_at(person_a, person_b, count, weighted)
+    }
+
+    /// Full correction change (contact credit and repetition) for one pair meeting change
+    /// in `day`.
+    #[inline]
+    pub(crate) fn session_weight_pair_delta(
+        &self,
+        person_a: usize,
+        person_b: usize,
+        day: usize,
+        direction: i32,
+    ) -> f64 {
+        if self.session_weights.is_empty() {
+            return 0.0;
+        }
+        let Some((count, weighted, new_count, new_weighted)) =
+            self.session_weighted_transition(person_a, person_b, day, direction)
+        else {
+            return 0.0;
+        };
+        self.session_contact_correction_at(person_a, person_b, new_count, new_weighted)
+            - self.session_contact_correction_at(person_a, person_b, count, weighted)
+            + self.session_repetition_correction_at(new_count, new_weighted)
+            - self.session_repetition_correction_at(count, weighted)
+    }
+
+    /// Updates the weight sums and the correction after a pair's contact count went from
+    /// `old_count` to `new_count` through a meeting change in `day`.
+    pub(crate) fn record_session_weighted_contact(
+        &mut self,
+        person_a: usize,
+        person_b: usize,
+        day: usize,
+        old_count: u32,
+        new_count: u32,
+    ) {
+        if self.session_weights.is_empty() {
+            return;
+        }
+        let weighted = self.session_weighted_contacts[person_a][person_b];
+        let new_weighted = if new_count == 0 {
+            0.0
+        } else if new_count > old_count {
+            weighted + self.session_weights[day]
+        } else {
+            weighted - self.session_weights[day]
+        };
+        self.session_weight_correction +=
+            self.session_contact_correction_at(person_a, person_b, new_count, new_weighted)
+                - self.session_contact_correction_at(person_a, person_b, old_count, weighted)
+                + self.session_repetition_correction_at(new_count, new_weighted)
+                - self.session_repetition_correction_at(old_count, weighted);
+        self.session_weighted_contacts[person_a][person_b] = new_weighted;
+        self.session_weighted_contacts[person_b][person_a] = new_weighted;
+    }
+
+    /// Rebuilds the per-pair weight sums and `session_weight_correction` from the schedule.
+    pub(crate) fn recalculate_session_weighted_contacts(&mut self) {
+        self.session_weight_correction = 0.0;
+        if self.session_weights.is_empty() {
+            return;
+        }
+
+        let people_count = self.person_idx_to_id.len();
+        let mut weighted = vec![vec![0.0; people_count]; people_count];
+        for &(a, b, count) in &self.historical_contacts {
+            weighted[a][b] += count as f64;
+            weighted[b][a] += count as f64;
+        }
+        for (day, day_schedule) in self.schedule.iter().enumerate() {
+            let weight = self.session_weights[day];
+            for group in day_schedule {
+                for (idx, &person_a) in group.iter().enumerate() {
+                    if !self.person_participation[person_a][day] {
+                        continue;
+                    }
+                    for &person_b in &group[idx + 1..] {
+                        if self.person_participation[person_b][day] {
+                            weighted[person_a][person_b] += weight;
+                            weighted[person_b][person_a] += weight;
+                        }
+                    }
+                }
+            }
+        }
+
+        for (i, row) in weighted.iter().enumerate() {
+            for (j, &pair_weight) in row.iter().enumerate().skip(i + 1) {
+                let count = self.contact_matrix.get(i, j);
+                self.session_weight_correction +=
+                    self.session_contact_correction_at(i, j, count, pair_weight)
+                        + self.session_repetition_correction_at(count, pair_weight);
+            }
+        }
+        self.session_weighted_contacts = weighted;
+    }
+}
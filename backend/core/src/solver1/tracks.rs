@@ -0,0 +1,388 @@
+//! Two-level track grouping for the solver state.
+//!
+//! `TrackGrouping` partitions groups into tracks through a group attribute. Every person starts
+//! in a single track (their own track attribute, an immovable placement, a warm start, or a
+//! capacity-balanced choice) and is restricted to that track's groups via
+//! `allowed_groups_by_person_session`, so construction and single-session moves stay inside the
+//! track. People whose track is not forced can change track later through a track swap, which
+//! exchanges the places of two such people in every session.
+
+use super::dsu::Dsu;
+use super::State;
+use crate::models::{ApiInput, Constraint};
+use crate::solver_support::SolverError;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+impl State {
+    pub(crate) fn resolve_track_grouping(&mut self, input: &ApiInput) -> Result<(), SolverError> {
+        let mut track_constraints =
+            input
+                .constraints
+                .iter()
+                .filter_map(|constraint| match constraint {
+                    Constraint::TrackGrouping(params) => Some(params),
+                    _ => None,
+                });
+        let Some(params) = track_constraints.next() else {
+            return Ok(());
+        };
+        if track_constraints.next().is_some() {
+            return Err(SolverError::ValidationError(
+                "At most one TrackGrouping constraint is supported".to_string(),
+            ));
+        }
+
+        let key = &params.track_attribute;
+        let people_count = self.person_idx_to_id.len();
+        let num_sessions = self.num_sessions as usize;
+        let group_count = self.group_idx_to_id.len();
+
+        let mut group_tracks = Vec::with_capacity(group_count);
+        for group in &input.problem.groups {
+            let track = group.attributes.get(key).ok_or_else(|| {
+                SolverError::ValidationError(format!(
+                    "TrackGrouping requires group '{}' to define attribute '{}'",
+                    group.id, key
+                ))
+            })?;
+            group_tracks.push(track.clone());
+        }
+        let mut track_names = group_tracks.clone();
+        track_names.sort();
+        track_names.dedup();
+        let track_count = track_names.len();
+        let track_of_group: Vec<usize> = group_tracks
+            .iter()
+            .map(|track| {
+                track_names
+                    .binary_search(track)
+                    .expect("track names are deduped")
+            })
+            .collect();
+
+        let mut track_capacities = vec![vec![0usize; num_sessions]; track_count];
+        for (group_idx, &track_idx) in track_of_group.iter().enumerate() {
+            for (day, capacity) in track_capacities[track_idx].iter_mut().enumerate() {
+                *capacity += self.effective_group_capacity(day, group_idx);
+            }
+        }
+
+        // Clique members always share a group, so they share a track for the whole event.
+        let mut dsu = Dsu::new(people_count);
+        for clique in &self.cliques {
+            for pair in clique.windows(2) {
+                dsu.union(pair[0], pair[1]);
+            }
+        }
+
+        let mut candidates = vec![vec![true; track_count]; people_count];
+        for person_idx in 0..people_count {
+            let root = dsu.find(person_idx);
+            let mut allowed_tracks = vec![true; track_count];
+
+            if let Some(track) = input.problem.people[person_idx].attributes.get(key) {
+                let track_idx = track_names.binary_search(track).map_err(|_| {
+                    SolverError::ValidationError(format!(
+                        "TrackGrouping: person '{}' requests unknown track '{}'",
+                        self.person_idx_to_id[person_idx], track
+                    ))
+                })?;
+                for (idx, allowed) in allowed_tracks.iter_mut().enumerate() {
+                    *allowed &= idx == track_idx;
+                }
+            }
+
+            for day in 0..num_sessions {
+                if !self.person_participation[person_idx][day] {
+                    continue;
+                }
+                if let Some(&group_idx) = self.immovable_people.get(&(person_idx, day)) {
+                    let track_idx = track_of_group[group_idx];
+                    for (idx, allowed) in allowed_tracks.iter_mut().enumerate() {
+                        *allowed &= idx == track_idx;
+                    }
+                }
+                if !self.allowed_groups_by_person_session.is_empty() {
+                    let groups =
+                        &self.allowed_groups_by_person_session[day * people_count + person_idx];
+                    if !groups.is_empty() {
+                        let mut reachable = vec![false; track_count];
+                        for &group_idx in groups {
+                            reachable[track_of_group[group_idx]] = true;
+                        }
+                        for (allowed, reachable) in allowed_tracks.iter_mut().zip(reachable) {
+                            *allowed &= reachable;
+                        }
+                    }
+                }
+            }
+
+            for (candidate, allowed) in candidates[root].iter_mut().zip(allowed_tracks) {
+                *candidate &= allowed;
+            }
+        }
+
+        // A warm start (or construction seed) decides the track of the people it places, by
+        // their earliest placement.
+        let mut preferred_tracks: Vec<Option<usize>> = vec![None; people_count];
+        if let Some(schedule) = input
+            .initial_schedule
+            .as_ref()
+            .or(input.construction_seed_schedule.as_ref())
+        {
+            for day in 0..num_sessions {
+                let Some(groups) = schedule.get(&format!("session_{day}")) else {
+                    continue;
+                };
+                for (group_idx, group_id) in self.group_idx_to_id.iter().enumerate() {
+                    let Some(members) = groups.get(group_id) else {
+                        continue;
+                    };
+                    for member in members {
+                        if let Some(&person_idx) = self.person_id_to_idx.get(member) {
+                            let root = dsu.find(person_idx);
+                            preferred_tracks[root].get_or_insert(track_of_group[group_idx]);
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut components: Vec<Vec<usize>> = vec![Vec::new(); people_count];
+        for person_idx in 0..people_count {
+            components[dsu.find(person_idx)].push(person_idx);
+        }
+        let mut roots: Vec<usize> = (0..people_count)
+            .filter(|&root| !components[root].is_empty())
+            .collect();
+        // Forced tracks first, then warm-started people, then the largest components.
+        roots.sort_by_key(|&root| {
+            let forced = candidates[root].iter().filter(|&&allowed| allowed).count() == 1;
+            let preferred = preferred_tracks[root].is_some();
+            (!forced, !preferred, Reverse(components[root].len()))
+        });
+
+        let mut track_loads = vec![vec![0usize; num_sessions]; track_count];
+        let mut person_tracks = vec![0usize; people_count];
+        for root in roots {
+            let members = &components[root];
+            let demand: Vec<usize> = (0..num_sessions)
+                .map(|day| {
+                    members
+                        .iter()
+                        .filter(|&&person_idx| self.person_participation[person_idx][day])
+                        .count()
+                })
+                .collect();
+            let slack = |track_idx: usize| -> Option<usize> {
+                let mut min_slack = usize::MAX;
+                for day in 0..num_sessions {
+                    let free = track_capacities[track_idx][day]
+                        .checked_sub(track_loads[track_idx][day] + demand[day])?;
+                    if demand[day] > 0 {
+                        min_slack = min_slack.min(free);
+                    }
+                }
+                Some(min_slack)
+            };
+
+            let first_member = self.display_person_by_idx(members[0]);
+            if !candidates[root].iter().any(|&allowed| allowed) {
+                return Err(SolverError::ValidationError(format!(
+                    "TrackGrouping leaves no track satisfying every requirement of person '{}'",
+                    first_member
+                )));
+            }
+
+            let chosen = preferred_tracks[root]
+                .filter(|&track_idx| candidates[root][track_idx] && slack(track_idx).is_some())
+                .or_else(|| {
+                    (0..track_count)
+                        .filter(|&track_idx| candidates[root][track_idx])
+                        .filter_map(|track_idx| slack(track_idx).map(|free| (free, track_idx)))
+                        .max_by_key(|&(free, track_idx)| (free, Reverse(track_idx)))
+                        .map(|(_, track_idx)| track_idx)
+                })
+                .ok_or_else(|| {
+                    SolverError::ValidationError(format!(
+                        "TrackGrouping cannot fit person '{}' into any track with enough capacity",
+                        first_member
+                    ))
+                })?;
+
+            for day in 0..num_sessions {
+                track_loads[chosen][day] += demand[day];
+            }
+            for &person_idx in members {
+                person_tracks[person_idx] = chosen;
+            }
+        }
+
+        if self.allowed_groups_by_person_session.is_empty() {
+            self.allowed_groups_by_person_session = vec![Vec::new(); num_sessions * people_count];
+        }
+        self.untracked_allowed_groups = self.allowed_groups_by_person_session.clone();
+        self.track_names = track_names;
+        self.track_of_group = track_of_group;
+        self.person_tracks = person_tracks;
+        for person_idx in 0..people_count {
+            self.restrict_to_track(person_idx);
+            for day in 0..num_sessions {
+                if self.person_participation[person_idx][day]
+                    && !self.allowed_groups_by_person_session[day * people_count + person_idx]
+                        .iter()
+                        .any(|&group_idx| {
+                            self.effective_group_capacities[day * group_count + group_idx] > 0
+                        })
+                {
+                    return Err(SolverError::ValidationError(format!(
+                        "TrackGrouping leaves no open group for person '{}' in session {}",
+                        self.display_person_by_idx(person_idx),
+                        day
+                    )));
+                }
+            }
+        }
+
+        // People outside cliques with a choice of track may later swap tracks with another
+        // such person attending the same sessions.
+        let mut pools: BTreeMap<&[bool], Vec<usize>> = BTreeMap::new();
+        let mut track_candidates = Vec::with_capacity(people_count);
+        for person_idx in 0..people_count {
+            let root = dsu.find(person_idx);
+            let choices = candidates[root].iter().filter(|&&allowed| allowed).count();
+            if components[root].len() == 1 && choices > 1 {
+                pools
+                    .entry(self.person_participation[person_idx].as_slice())
+                    .or_default()
+                    .push(person_idx);
+            }
+            track_candidates.push(candidates[root].clone());
+        }
+        let track_swap_pools: Vec<Vec<usize>> =
+            pools.into_values().filter(|pool| pool.len() >= 2).collect();
+        self.track_swap_pools = track_swap_pools;
+        self.track_candidates = track_candidates;
+        Ok(())
+    }
+
+    /// Restricts `person_idx` to the groups of their current track in every session.
+    fn restrict_to_track(&mut self, person_idx: usize) {
+        let people_count = self.person_idx_to_id.len();
+        let group_count = self.group_idx_to_id.len();
+        let track_idx = self.person_tracks[person_idx];
+        for day in 0..self.num_sessions as usize {
+            let slot = day * people_count + person_idx;
+            let untracked = &self.untracked_allowed_groups[slot];
+            let allowed: Vec<usize> = if untracked.is_empty() {
+                (0..group_count)
+                    .filter(|&group_idx| self.track_of_group[group_idx] == track_idx)
+                    .collect()
+            } else {
+                untracked
+                    .iter()
+                    .copied()
+                    .filter(|&group_idx| self.track_of_group[group_idx] == track_idx)
+                    .collect()
+            };
+            self.allowed_groups_by_person_session[slot] = allowed;
+        }
+    }
+
+    /// Whether swapping the places of `p1_idx` and `p2_idx` in every session moves each into the
+    /// other's track without breaking their other group requirements.
+    ///
+    /// Both must come from the same `track_swap_pools` entry, so they attend the same sessions.
+    pub(crate) fn track_swap_feasible(&self, p1_idx: usize, p2_idx: usize) -> bool {
+        let (t1, t2) = (self.person_tracks[p1_idx], self.person_tracks[p2_idx]);
+        if t1 == t2 || !self.track_candidates[p1_idx][t2] || !self.track_candidates[p2_idx][t1] {
+            return false;
+        }
+        let people_count = self.person_idx_to_id.len();
+        let untracked_allows = |day: usize, person_idx: usize, group_idx: usize| {
+            let allowed = &self.untracked_allowed_groups[day * people_count + person_idx];
+            allowed.is_empty() || allowed.binary_search(&group_idx).is_ok()
+        };
+        (0..self.num_sessions as usize)
+            .filter(|&day| self.person_participation[p1_idx][day])
+            .all(|day| {
+                untracked_allows(day, p1_idx, self.locations[day][p2_idx].0)
+                    && untracked_allows(day, p2_idx, self.locations[day][p1_idx].0)
+            })
+    }
+
+    /// Swaps the places of `p1_idx` and `p2_idx` in every session they attend, which swaps
+    /// their tracks. Applying it twice restores the state.
+    ///
+    /// Returns the change of the cost and of its objective part.
+    pub(crate) fn apply_track_swap(&mut self, p1_idx: usize, p2_idx: usize) -> (f64, f64) {
+        self.person_tracks.swap(p1_idx, p2_idx);
+        self.restrict_to_track(p1_idx);
+        self.restrict_to_track(p2_idx);
+
+        let mut delta_cost = 0.0;
+        let mut objective_delta = 0.0;
+        for day in 0..self.num_sessions as usize {
+            if !self.person_participation[p1_idx][day] {
+                continue;
+            }
+            delta_cost += self.calculate_swap_cost_delta(day, p1_idx, p2_idx);
+            objective_delta += self.calculate_swap_objective_delta(day, p1_idx, p2_idx);
+            self.apply_swap(day, p1_idx, p2_idx);
+        }
+        (delta_cost, objective_delta)
+    }
+
+    /// Re-derives each person's track from the schedule after it was replaced wholesale.
+    pub(crate) fn sync_tracks_with_schedule(&mut self) {
+        if self.person_tracks.is_empty() {
+            return;
+        }
+        for person_idx in 0..self.person_idx_to_id.len() {
+            let Some(day) = (0..self.num_sessions as usize)
+                .find(|&day| self.person_participation[person_idx][day])
+            else {
+                continue;
+            };
+            let track_idx = self.track_of_group[self.locations[day][person_idx].0];
+            if self.person_tracks[person_idx] != track_idx {
+                self.person_tracks[person_idx] = track_idx;
+                self.restrict_to_track(person_idx);
+            }
+        }
+    }
+
+    /// Errors if any participating person changes track between sessions.
+    pub(crate) fn validate_track_grouping(&self) -> Result<(), SolverError> {
+        if self.track_of_group.is_empty() {
+            return Ok(());
+        }
+
+        for person_idx in 0..self.person_idx_to_id.len() {
+            let mut first_track: Option<(usize, usize)> = None;
+            for day in 0..self.num_sessions as usize {
+                if !self.person_participation[person_idx][day] {
+                    continue;
+                }
+                let track_idx = self.track_of_group[self.locations[day][person_idx].0];
+                match first_track {
+                    None => first_track = Some((day, track_idx)),
+                    Some((first_day, first_idx)) if first_idx != track_idx => {
+                        return Err(SolverError::ValidationError(format!(
+                            "TrackGrouping violation: '{}' is in track '{}' in session {} but track '{}' in session {}",
+                            self.display_person_by_idx(person_idx),
+                            self.track_names[first_idx],
+                            first_day,
+                            self.track_names[track_idx],
+                            day
+                        )));
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,398 @@
+//! Itemized constraint violations for result reporting.
+//!
+//! The search only needs aggregate penalties, so this walks the final schedule once and
+//! lists each violation with the people, groups, and session involved. The per-item
+//! penalties mirror `calculate_cost`.
+
+use super::group_fill::BALANCE_GROUP_SIZES_OBJECTIVE;
+use super::State;
+use crate::models::{BaselineMoveLimitScope, ConstraintViolation, PairMeetingMode};
+
+impl State {
+    /// Lists every violated constraint in the current schedule.
+    pub fn constraint_violations(&self) -> Vec<ConstraintViolation> {
+        let mut violations = Vec::new();
+        self.push_repeat_encounter_violations(&mut violations);
+        self.push_pair_violations(&mut violations);
+        self.push_clique_violations(&mut violations);
+        self.push_pair_meeting_violations(&mut violations);
+        self.push_immovable_violations(&mut violations);
+        self.push_attribute_balance_violations(&mut violations);
+        self.push_group_composition_violations(&mut violations);
+        self.push_baseline_move_violations(&mut violations);
+        self.push_allowed_group_violations(&mut violations);
+        self.push_track_violations(&mut violations);
+        self.push_group_fill_violations(&mut violations);
+        violations
+    }
+
+    fn people_ids(&self, people: &[usize]) -> Vec<String> {
+        people
+            .iter()
+            .map(|&person_idx| self.person_idx_to_id[person_idx].clone())
+            .collect()
+    }
+
+    fn group_ids(&self, groups: &[usize]) -> Vec<String> {
+        groups
+            .iter()
+            .map(|&group_idx| self.group_idx_to_id[group_idx].clone())
+            .collect()
+    }
+
+    fn push_repeat_encounter_violations(&self, violations: &mut Vec<ConstraintViolation>) {
+        if self.w_repetition == 0.0 {
+            return;
+        }
+        let people_count = self.person_idx_to_id.len();
+        for person_a in 0..people_count {
+            for person_b in (person_a + 1)..people_count {
+                let penalty = self.repetition_penalty_for_contact_count(
+                    self.contact_matrix.get(person_a, person_b),
+                );
+                if penalty > 0 {
+                    violations.push(ConstraintViolation {
+                        constraint_type: "RepeatEncounter".to_string(),
+                        session: None,
+                        people: self.people_ids(&[person_a, person_b]),
+                        groups: Vec::new(),
+                        weighted_penalty: penalty as f64 * self.w_repetition,
+                    });
+                }
+            }
+        }
+    }
+
+    fn push_pair_violations(&self, violations: &mut Vec<ConstraintViolation>) {
+        let applies = |sessions: &Option<Vec<usize>>, day: usize| {
+            sessions
+                .as_ref()
+                .is_none_or(|sessions| sessions.contains(&day))
+        };
+
+        for day in 0..self.num_sessions as usize {
+            let both_present = |p1: usize, p2: usize| {
+                self.person_participation[p1][day] && self.person_participation[p2][day]
+            };
+            let group_of = |person: usize| self.locations[day][person].0;
+
+            for (pair_idx, &(p1, p2)) in self.soft_apart_pairs.iter().enumerate() {
+                if applies(&self.soft_apart_pair_sessions[pair_idx], day)
+                    && both_present(p1, p2)
+                    && group_of(p1) == group_of(p2)
+                {
+                    violations.push(ConstraintViolation {
+                        constraint_type: "ShouldNotBeTogether".to_string(),
+                        session: Some(day as u32),
+                        people: self.people_ids(&[p1, p2]),
+                        groups: self.group_ids(&[group_of(p1)]),
+                        weighted_penalty: self.soft_apart_pair_weights[pair_idx],
+                    });
+                }
+            }
+
+            for (pair_idx, &(p1, p2)) in self.should_together_pairs.iter().enumerate() {
+                if applies(&self.should_together_sessions[pair_idx], day)
+                    && both_present(p1, p2)
+                    && group_of(p1) != group_of(p2)
+                {
+                    violations.push(ConstraintViolation {
+                        constraint_type: "ShouldStayTogether".to_string(),
+                        session: Some(day as u32),
+                        people: self.people_ids(&[p1, p2]),
+                        groups: self.group_ids(&[group_of(p1), group_of(p2)]),
+                        weighted_penalty: self.should_together_weights[pair_idx],
+                    });
+                }
+            }
+
+            for (pair_idx, &(p1, p2)) in self.hard_apart_pairs.iter().enumerate() {
+                if applies(&self.hard_apart_pair_sessions[pair_idx], day)
+                    && both_present(p1, p2)
+                    && group_of(p1) == group_of(p2)
+                {
+                    violations.push(ConstraintViolation {
+                        constraint_type: "MustStayApart".to_string(),
+                        session: Some(day as u32),
+                        people: self.people_ids(&[p1, p2]),
+                        groups: self.group_ids(&[group_of(p1)]),
+                        weighted_penalty: 0.0,
+                    });
+                }
+            }
+        }
+    }
+
+    fn push_clique_violations(&self, violations: &mut Vec<ConstraintViolation>) {
+        for (clique_idx, clique) in self.cliques.iter().enumerate() {
+            for day in 0..self.num_sessions as usize {
+                if let Some(sessions) = &self.clique_sessions[clique_idx] {
+                    if !sessions.contains(&day) {
+                        continue;
+                    }
+                }
+                let participating: Vec<usize> = clique
+                    .iter()
+                    .copied()
+                    .filter(|&member| self.person_participation[member][day])
+                    .collect();
+                let mut groups: Vec<usize> = participating
+                    .iter()
+                    .map(|&member| self.locations[day][member].0)
+                    .collect();
+                groups.sort_unstable();
+                groups.dedup();
+                if groups.len() > 1 {
+                    violations.push(ConstraintViolation {
+                        constraint_type: "MustStayTogether".to_string(),
+                        session: Some(day as u32),
+                        people: self.people_ids(&participating),
+                        groups: self.group_ids(&groups),
+                        weighted_penalty: 0.0,
+                    });
+                }
+            }
+        }
+    }
+
+    fn push_pair_meeting_violations(&self, violations: &mut Vec<ConstraintViolation>) {
+        for (idx, &(p1, p2)) in self.pairmin_pairs.iter().enumerate() {
+            let target = self.pairmin_required[idx] as i32;
+            let have = self.pairmin_counts[idx] as i32;
+            let penalty = match self.pairmin_modes[idx] {
+                PairMeetingMode::AtLeast => (target - have).max(0) as f64,
+                PairMeetingMode::Exact => (have - target).abs() as f64,
+                PairMeetingMode::AtMost => (have - target).max(0) as f64,
+            } * self.pairmin_weights[idx];
+            if penalty > 0.0 {
+                violations.push(ConstraintViolation {
+                    constraint_type: "PairMeetingCount".to_string(),
+                    session: None,
+                    people: self.people_ids(&[p1, p2]),
+                    groups: Vec::new(),
+                    weighted_penalty: penalty,
+                });
+            }
+        }
+    }
+
+    fn push_immovable_violations(&self, violations: &mut Vec<ConstraintViolation>) {
+        let mut misplaced: Vec<(usize, usize, usize, usize)> = self
+            .immovable_people
+            .iter()
+            .filter(|(&(person_idx, day), _)| self.person_participation[person_idx][day])
+            .filter_map(|(&(person_idx, day), &required_group)| {
+                let actual_group = self.locations[day][person_idx].0;
+                (actual_group != required_group).then_some((
+                    day,
+                    person_idx,
+                    required_group,
+                    actual_group,
+                ))
+            })
+            .collect();
+        // The placements come from a HashMap; sort for stable output.
+        misplaced.sort_unstable();
+        for (day, person_idx, required_group, actual_group) in misplaced {
+            violations.push(ConstraintViolation {
+                constraint_type: "ImmovablePeople".to_string(),
+                session: Some(day as u32),
+                people: self.people_ids(&[person_idx]),
+                groups: self.group_ids(&[required_group, actual_group]),
+                weighted_penalty: 1000.0,
+            });
+        }
+    }
+
+    fn push_attribute_balance_violations(&self, violations: &mut Vec<ConstraintViolation>) {
+        for (day, day_schedule) in self.schedule.iter().enumerate() {
+            for (group_idx, members) in day_schedule.iter().enumerate() {
+                for &constraint_idx in
+                    self.attribute_balance_constraint_indices_for_group_session(day, group_idx)
+                {
+                    let penalty = self.calculate_group_attribute_penalty_for_constraint_members(
+                        members,
+                        constraint_idx,
+                    );
+                    if penalty > 0.0 {
+                        violations.push(ConstraintViolation {
+                            constraint_type: "AttributeBalance".to_string(),
+                            session: Some(day as u32),
+                            people: self.people_ids(members),
+                            groups: self.group_ids(&[group_idx]),
+                            weighted_penalty: penalty,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    fn push_group_composition_violations(&self, violations: &mut Vec<ConstraintViolation>) {
+        let Some(constraint) = &self.unique_group_composition else {
+            return;
+        };
+        let mut seen = std::collections::HashSet::new();
+        for (day, day_schedule) in self.schedule.iter().enumerate() {
+            if !constraint.covered_sessions[day] {
+                continue;
+            }
+            for (group_idx, members) in day_schedule.iter().enumerate() {
+                let Some(key) = self.group_composition_key(day, members) else {
+                    continue;
+                };
+                // The first occurrence is free; every later one is a repeat.
+                if !seen.insert(key.clone()) {
+                    violations.push(ConstraintViolation {
+                        constraint_type: "UniqueGroupComposition".to_string(),
+                        session: Some(day as u32),
+                        people: self.people_ids(&key),
+                        groups: self.group_ids(&[group_idx]),
+                        weighted_penalty: constraint.penalty_weight,
+                    });
+                }
+            }
+        }
+    }
+
+    fn push_baseline_move_violations(&self, violations: &mut Vec<ConstraintViolation>) {
+        if self.baseline_groups.is_empty() {
+            return;
+        }
+        let moved_people = |day: usize| -> Vec<usize> {
+            self.baseline_groups[day]
+                .iter()
+                .enumerate()
+                .filter(|&(person_idx, baseline_group)| {
+                    baseline_group.is_some_and(|group| self.locations[day][person_idx].0 != group)
+                })
+                .map(|(person_idx, _)| person_idx)
+                .collect()
+        };
+
+        for limit in &self.baseline_move_limits {
+            match limit.scope {
+                BaselineMoveLimitScope::Total => {
+                    if self.baseline_moves_used(limit, 0) <= limit.max_moves {
+                        continue;
+                    }
+                    let mut people: Vec<usize> = limit
+                        .sessions
+                        .iter()
+                        .flat_map(|&day| moved_people(day))
+                        .collect();
+                    people.sort_unstable();
+                    people.dedup();
+                    violations.push(ConstraintViolation {
+                        constraint_type: "BaselineMoveLimit".to_string(),
+                        session: None,
+                        people: self.people_ids(&people),
+                        groups: Vec::new(),
+                        weighted_penalty: 0.0,
+                    });
+                }
+                BaselineMoveLimitScope::PerSession => {
+                    for &day in &limit.sessions {
+                        if self.baseline_moved_counts[day] <= limit.max_moves {
+                            continue;
+                        }
+                        violations.push(ConstraintViolation {
+                            constraint_type: "BaselineMoveLimit".to_string(),
+                            session: Some(day as u32),
+                            people: self.people_ids(&moved_people(day)),
+                            groups: Vec::new(),
+                            weighted_penalty: 0.0,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    /// People placed outside the groups their `GroupAttributeRequirement` allows.
+    fn push_allowed_group_violations(&self, violations: &mut Vec<ConstraintViolation>) {
+        // With tracks, the table also holds the track restriction, reported separately.
+        let allowed_groups = if self.track_of_group.is_empty() {
+            &self.allowed_groups_by_person_session
+        } else {
+            &self.untracked_allowed_groups
+        };
+        if allowed_groups.is_empty() {
+            return;
+        }
+        let people_count = self.person_idx_to_id.len();
+        for day in 0..self.num_sessions as usize {
+            for person_idx in 0..people_count {
+                if !self.person_participation[person_idx][day] {
+                    continue;
+                }
+                let allowed = &allowed_groups[day * people_count + person_idx];
+                let group_idx = self.locations[day][person_idx].0;
+                if !allowed.is_empty() && allowed.binary_search(&group_idx).is_err() {
+                    violations.push(ConstraintViolation {
+                        constraint_type: "GroupAttributeRequirement".to_string(),
+                        session: Some(day as u32),
+                        people: self.people_ids(&[person_idx]),
+                        groups: self.group_ids(&[group_idx]),
+                        weighted_penalty: 0.0,
+                    });
+                }
+            }
+        }
+    }
+
+    /// People who sit in groups of more than one track across their sessions.
+    fn push_track_violations(&self, violations: &mut Vec<ConstraintViolation>) {
+        if self.track_of_group.is_empty() {
+            return;
+        }
+        for person_idx in 0..self.person_idx_to_id.len() {
+            let groups: Vec<usize> = (0..self.num_sessions as usize)
+                .filter(|&day| self.person_participation[person_idx][day])
+                .map(|day| self.locations[day][person_idx].0)
+                .collect();
+            let Some(&first_group) = groups.first() else {
+                continue;
+            };
+            let first_track = self.track_of_group[first_group];
+            if groups
+                .iter()
+                .all(|&group_idx| self.track_of_group[group_idx] == first_track)
+            {
+                continue;
+            }
+            let mut groups = groups;
+            groups.sort_unstable();
+            groups.dedup();
+            violations.push(ConstraintViolation {
+                constraint_type: "TrackGrouping".to_string(),
+                session: None,
+                people: self.people_ids(&[person_idx]),
+                groups: self.group_ids(&groups),
+                weighted_penalty: 0.0,
+            });
+        }
+    }
+
+    /// Sessions whose open groups differ in size under the `balance_group_sizes` objective.
+    fn push_group_fill_violations(&self, violations: &mut Vec<ConstraintViolation>) {
+        if self.w_group_fill == 0.0 {
+            return;
+        }
+        for day in 0..self.num_sessions as usize {
+            let penalty = self.group_fill_penalty_for_session(day);
+            if penalty > 0.0 {
+                let open_groups: Vec<usize> = (0..self.group_idx_to_id.len())
+                    .filter(|&group_idx| self.effective_group_capacity(day, group_idx) > 0)
+                    .collect();
+                violations.push(ConstraintViolation {
+                    constraint_type: BALANCE_GROUP_SIZES_OBJECTIVE.to_string(),
+                    session: Some(day as u32),
+                    people: Vec::new(),
+                    groups: self.group_ids(&open_groups),
+                    weighted_penalty: penalty,
+                });
+            }
+        }
+    }
+}
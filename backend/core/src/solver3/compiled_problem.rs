@@ -15,7 +15,8 @@ use crate::models::{
     SolverKind,
 };
 use crate::solver_support::validation::{
-    validate_schedule_as_construction_seed, validate_schedule_input_mode,
+    validate_available_sessions, validate_schedule_as_construction_seed,
+    validate_schedule_input_mode,
 };
 use crate::solver_support::SolverError;
 
@@ -247,6 +248,7 @@ impl CompiledProblem {
         }
 
         validate_schedule_input_mode(input)?;
+        reject_unsupported_features(input)?;
 
         let num_people = input.problem.people.len();
         let num_groups = input.problem.groups.len();
@@ -320,26 +322,17 @@ impl CompiledProblem {
             &cliques,
             &person_participation,
             &session_max_group_capacities,
+            &person_idx_to_id,
         )?;
         validate_cliques_against_immovable(&cliques, &person_participation, &immovable_lookup)?;
 
-        let hard_apart_pairs = compile_hard_apart_pairs(
-            input,
-            &person_id_to_idx,
-            &person_to_clique_id,
-            &cliques,
-            num_sessions,
-        )?;
+        let hard_apart_pairs =
+            compile_hard_apart_pairs(input, &person_id_to_idx, &person_to_clique_id, num_sessions)?;
         let hard_apart_pairs_by_person =
             build_pair_adjacency(num_people, &hard_apart_pairs, |c| c.people);
 
-        let soft_apart_pairs = compile_soft_apart_pairs(
-            input,
-            &person_id_to_idx,
-            &person_to_clique_id,
-            &cliques,
-            num_sessions,
-        )?;
+        let soft_apart_pairs =
+            compile_soft_apart_pairs(input, &person_id_to_idx, &person_to_clique_id, num_sessions)?;
         let soft_apart_pairs_by_person =
             build_pair_adjacency(num_people, &soft_apart_pairs, |c| c.people);
 
@@ -541,6 +534,65 @@ impl CompiledProblem {
     }
 }
 
+/// Rejects inputs and constraint families that `solver3` does not model instead of silently
+/// ignoring them.
+fn reject_unsupported_features(input: &ApiInput) -> Result<(), SolverError> {
+    if input.contact_history.is_some() {
+        return Err(SolverError::ValidationError(
+            "solver3 does not support contact_history; use solver1 for this input".to_string(),
+        ));
+    }
+
+    if input
+        .objectives
+        .iter()
+        .any(|objective| !objective.person_weights.is_empty())
+    {
+        return Err(SolverError::ValidationError(
+            "solver3 does not support objective person_weights; use solver1 for this input"
+                .to_string(),
+        ));
+    }
+
+    if input
+        .objectives
+        .iter()
+        .any(|objective| !objective.session_weights.is_empty())
+    {
+        return Err(SolverError::ValidationError(
+            "solver3 does not support objective session_weights; use solver1 for this input"
+                .to_string(),
+        ));
+    }
+
+    if input
+        .objectives
+        .iter()
+        .any(|objective| objective.r#type == "balance_group_sizes")
+    {
+        return Err(SolverError::ValidationError(
+            "solver3 does not support the balance_group_sizes objective; use solver1 for this input"
+                .to_string(),
+        ));
+    }
+
+    for constraint in &input.constraints {
+        let unsupported = match constraint {
+            Constraint::BaselineMoveLimit(_) => Some("BaselineMoveLimit"),
+            Constraint::UniqueGroupComposition(_) => Some("UniqueGroupComposition"),
+            Constraint::GroupAttributeRequirement(_) => Some("GroupAttributeRequirement"),
+            Constraint::TrackGrouping(_) => Some("TrackGrouping"),
+            _ => None,
+        };
+        if let Some(kind) = unsupported {
+            return Err(SolverError::ValidationError(format!(
+                "solver3 does not support {kind} constraints; use solver1 for this input"
+            )));
+        }
+    }
+    Ok(())
+}
+
 fn build_session_participant_counts(person_participation: &[Vec<bool>]) -> Vec<usize> {
     let num_sessions = person_participation
         .first()
@@ -636,6 +688,7 @@ fn build_effective_group_capacities(
     let mut session_total = vec![0usize; num_sessions];
     let mut session_max = vec![0usize; num_sessions];
 
+    validate_available_sessions(input)?;
     for (gidx, group) in input.problem.groups.iter().enumerate() {
         if let Some(ss) = &group.session_sizes {
             if ss.len() != num_sessions {
@@ -648,11 +701,7 @@ fn build_effective_group_capacities(
             }
         }
         for sidx in 0..num_sessions {
-            let cap = group
-                .session_sizes
-                .as_ref()
-                .map(|ss| ss[sidx] as usize)
-                .unwrap_or(group.size as usize);
+            let cap = group.capacity_for_session(sidx).unwrap_or(0) as usize;
             let slot = sidx * num_groups + gidx;
             effective[slot] = cap;
             session_total[sidx] += cap;
@@ -670,10 +719,11 @@ fn validate_session_capacities(
     for (sidx, &cap) in session_total_capacities.iter().enumerate() {
         let participants = person_participation.iter().filter(|pp| pp[sidx]).count();
         if participants > cap {
-            return Err(SolverError::ValidationError(format!(
-                "not enough group capacity in session {}: {} people, {} capacity",
-                sidx, participants, cap
-            )));
+            return Err(SolverError::InsufficientCapacity {
+                session: sidx,
+                people: participants,
+                capacity: cap,
+            });
         }
     }
     Ok(())
@@ -731,16 +781,16 @@ fn compile_immovable_assignments(
         match constraint {
             Constraint::ImmovablePerson(params) => {
                 let &pidx = person_id_to_idx.get(&params.person_id).ok_or_else(|| {
-                    SolverError::ValidationError(format!(
-                        "ImmovablePerson: unknown person '{}'",
-                        params.person_id
-                    ))
+                    SolverError::UnknownPersonId {
+                        person_id: params.person_id.to_string(),
+                        referenced_by: "ImmovablePerson".to_string(),
+                    }
                 })?;
                 let &gidx = group_id_to_idx.get(&params.group_id).ok_or_else(|| {
-                    SolverError::ValidationError(format!(
-                        "ImmovablePerson: unknown group '{}'",
-                        params.group_id
-                    ))
+                    SolverError::UnknownGroupId {
+                        group_id: params.group_id.to_string(),
+                        referenced_by: "ImmovablePerson".to_string(),
+                    }
                 })?;
                 let sessions = params
                     .sessions
@@ -763,10 +813,10 @@ fn compile_immovable_assignments(
             }
             Constraint::ImmovablePeople(params) => {
                 let &gidx = group_id_to_idx.get(&params.group_id).ok_or_else(|| {
-                    SolverError::ValidationError(format!(
-                        "ImmovablePeople: unknown group '{}'",
-                        params.group_id
-                    ))
+                    SolverError::UnknownGroupId {
+                        group_id: params.group_id.to_string(),
+                        referenced_by: "ImmovablePeople".to_string(),
+                    }
                 })?;
                 let sessions = params
                     .sessions
@@ -774,10 +824,10 @@ fn compile_immovable_assignments(
                     .unwrap_or_else(|| (0..num_sessions as u32).collect());
                 for person_id in &params.people {
                     let &pidx = person_id_to_idx.get(person_id).ok_or_else(|| {
-                        SolverError::ValidationError(format!(
-                            "ImmovablePeople: unknown person '{}'",
-                            person_id
-                        ))
+                        SolverError::UnknownPersonId {
+                            person_id: person_id.to_string(),
+                            referenced_by: "ImmovablePeople".to_string(),
+                        }
                     })?;
                     for &s in &sessions {
                         let sidx = s as usize;
@@ -830,16 +880,16 @@ fn compile_cliques(
                 }
                 for window in people.windows(2) {
                     let &left = person_id_to_idx.get(&window[0]).ok_or_else(|| {
-                        SolverError::ValidationError(format!(
-                            "MustStayTogether: unknown person '{}'",
-                            window[0]
-                        ))
+                        SolverError::UnknownPersonId {
+                            person_id: window[0].to_string(),
+                            referenced_by: "MustStayTogether".to_string(),
+                        }
                     })?;
                     let &right = person_id_to_idx.get(&window[1]).ok_or_else(|| {
-                        SolverError::ValidationError(format!(
-                            "MustStayTogether: unknown person '{}'",
-                            window[1]
-                        ))
+                        SolverError::UnknownPersonId {
+                            person_id: window[1].to_string(),
+                            referenced_by: "MustStayTogether".to_string(),
+                        }
                     })?;
                     dsu.union(left, right);
                 }
@@ -913,6 +963,7 @@ fn validate_cliques_against_capacities(
     cliques: &[CompiledClique],
     person_participation: &[Vec<bool>],
     session_max_group_capacities: &[usize],
+    person_idx_to_id: &[String],
 ) -> Result<(), SolverError> {
     for clique in cliques {
         for (sidx, &max_cap) in session_max_group_capacities.iter().enumerate() {
@@ -929,10 +980,17 @@ fn validate_cliques_against_capacities(
                 .filter(|&&m| person_participation[m][sidx])
                 .count();
             if participating > max_cap {
-                return Err(SolverError::ValidationError(format!(
-                    "MustStayTogether clique of size {} cannot fit in any group for session {}",
-                    participating, sidx
-                )));
+                return Err(SolverError::CliqueTooLarge {
+                    people: clique
+                        .members
+                        .iter()
+                        .filter(|&&m| person_participation[m][sidx])
+                        .map(|&m| person_idx_to_id[m].clone())
+                        .collect(),
+                    size: participating,
+                    max_group_size: max_cap,
+                    session: sidx,
+                });
             }
         }
     }
@@ -983,7 +1041,6 @@ fn compile_soft_apart_pairs(
     input: &ApiInput,
     person_id_to_idx: &HashMap<String, usize>,
     person_to_clique_id: &[Vec<Option<usize>>],
-    cliques: &[CompiledClique],
     num_sessions: usize,
 ) -> Result<Vec<CompiledPairConstraint>, SolverError> {
     let mut pairs = Vec::new();
@@ -998,16 +1055,16 @@ fn compile_soft_apart_pairs(
             for left_idx in 0..people.len() {
                 for right_idx in (left_idx + 1)..people.len() {
                     let &lp = person_id_to_idx.get(&people[left_idx]).ok_or_else(|| {
-                        SolverError::ValidationError(format!(
-                            "ShouldNotBeTogether: unknown person '{}'",
-                            people[left_idx]
-                        ))
+                        SolverError::UnknownPersonId {
+                            person_id: people[left_idx].to_string(),
+                            referenced_by: "ShouldNotBeTogether".to_string(),
+                        }
                     })?;
                     let &rp = person_id_to_idx.get(&people[right_idx]).ok_or_else(|| {
-                        SolverError::ValidationError(format!(
-                            "ShouldNotBeTogether: unknown person '{}'",
-                            people[right_idx]
-                        ))
+                        SolverError::UnknownPersonId {
+                            person_id: people[right_idx].to_string(),
+                            referenced_by: "ShouldNotBeTogether".to_string(),
+                        }
                     })?;
 
                     // Validate no conflict with MustStayTogether cliques.
@@ -1021,15 +1078,15 @@ fn compile_soft_apart_pairs(
                             (person_to_clique_id[sidx][lp], person_to_clique_id[sidx][rp])
                         {
                             if lc == rc {
-                                let members = cliques[lc]
-                                    .members
-                                    .iter()
-                                    .map(|&m| input.problem.people[m].id.clone())
-                                    .collect::<Vec<_>>();
-                                return Err(SolverError::ValidationError(format!(
-                                    "ShouldNotBeTogether conflicts with MustStayTogether in session {}: people {:?} share clique {:?}",
-                                    sidx, people, members
-                                )));
+                                return Err(SolverError::ConflictingConstraints {
+                                    constraint: "ShouldNotBeTogether".to_string(),
+                                    conflicts_with: "MustStayTogether".to_string(),
+                                    people: vec![
+                                        people[left_idx].clone(),
+                                        people[right_idx].clone(),
+                                    ],
+                                    session: Some(sidx),
+                                });
                             }
                         }
                     }
@@ -1053,7 +1110,6 @@ fn compile_hard_apart_pairs(
     input: &ApiInput,
     person_id_to_idx: &HashMap<String, usize>,
     person_to_clique_id: &[Vec<Option<usize>>],
-    cliques: &[CompiledClique],
     num_sessions: usize,
 ) -> Result<Vec<CompiledHardApartPairConstraint>, SolverError> {
     let mut pairs = Vec::new();
@@ -1065,16 +1121,16 @@ fn compile_hard_apart_pairs(
             for left_idx in 0..people.len() {
                 for right_idx in (left_idx + 1)..people.len() {
                     let &lp = person_id_to_idx.get(&people[left_idx]).ok_or_else(|| {
-                        SolverError::ValidationError(format!(
-                            "MustStayApart: unknown person '{}'",
-                            people[left_idx]
-                        ))
+                        SolverError::UnknownPersonId {
+                            person_id: people[left_idx].to_string(),
+                            referenced_by: "MustStayApart".to_string(),
+                        }
                     })?;
                     let &rp = person_id_to_idx.get(&people[right_idx]).ok_or_else(|| {
-                        SolverError::ValidationError(format!(
-                            "MustStayApart: unknown person '{}'",
-                            people[right_idx]
-                        ))
+                        SolverError::UnknownPersonId {
+                            person_id: people[right_idx].to_string(),
+                            referenced_by: "MustStayApart".to_string(),
+                        }
                     })?;
 
                     for sidx in active_sessions(compiled_sessions.as_deref(), num_sessions) {
@@ -1082,15 +1138,15 @@ fn compile_hard_apart_pairs(
                             (person_to_clique_id[sidx][lp], person_to_clique_id[sidx][rp])
                         {
                             if lc == rc {
-                                let members = cliques[lc]
-                                    .members
-                                    .iter()
-                                    .map(|&m| input.problem.people[m].id.clone())
-                                    .collect::<Vec<_>>();
-                                return Err(SolverError::ValidationError(format!(
-                                    "MustStayApart conflicts with MustStayTogether in session {}: people {:?} share clique {:?}",
-                                    sidx, people, members
-                                )));
+                                return Err(SolverError::ConflictingConstraints {
+                                    constraint: "MustStayApart".to_string(),
+                                    conflicts_with: "MustStayTogether".to_string(),
+                                    people: vec![
+                                        people[left_idx].clone(),
+                                        people[right_idx].clone(),
+                                    ],
+                                    session: Some(sidx),
+                                });
                             }
                         }
                     }
@@ -1130,16 +1186,16 @@ fn compile_should_together_pairs(
             for left_idx in 0..people.len() {
                 for right_idx in (left_idx + 1)..people.len() {
                     let &lp = person_id_to_idx.get(&people[left_idx]).ok_or_else(|| {
-                        SolverError::ValidationError(format!(
-                            "ShouldStayTogether: unknown person '{}'",
-                            people[left_idx]
-                        ))
+                        SolverError::UnknownPersonId {
+                            person_id: people[left_idx].to_string(),
+                            referenced_by: "ShouldStayTogether".to_string(),
+                        }
                     })?;
                     let &rp = person_id_to_idx.get(&people[right_idx]).ok_or_else(|| {
-                        SolverError::ValidationError(format!(
-                            "ShouldStayTogether: unknown person '{}'",
-                            people[right_idx]
-                        ))
+                        SolverError::UnknownPersonId {
+                            person_id: people[right_idx].to_string(),
+                            referenced_by: "ShouldStayTogether".to_string(),
+                        }
                     })?;
 
                     let compiled_sessions = normalize_session_list(sessions, num_sessions)?;
@@ -1152,9 +1208,12 @@ fn compile_should_together_pairs(
                                 compiled_sessions.as_deref(),
                             )
                     }) {
-                        return Err(SolverError::ValidationError(
-                            "ShouldStayTogether conflicts with MustStayApart for the same pair in overlapping sessions".into(),
-                        ));
+                        return Err(SolverError::ConflictingConstraints {
+                            constraint: "ShouldStayTogether".to_string(),
+                            conflicts_with: "MustStayApart".to_string(),
+                            people: vec![people[left_idx].clone(), people[right_idx].clone()],
+                            session: None,
+                        });
                     }
 
                     if soft_apart_pairs.iter().any(|fp| {
@@ -1164,9 +1223,12 @@ fn compile_should_together_pairs(
                                 compiled_sessions.as_deref(),
                             )
                     }) {
-                        return Err(SolverError::ValidationError(
-                            "ShouldStayTogether conflicts with ShouldNotBeTogether for the same pair in overlapping sessions".into(),
-                        ));
+                        return Err(SolverError::ConflictingConstraints {
+                            constraint: "ShouldStayTogether".to_string(),
+                            conflicts_with: "ShouldNotBeTogether".to_string(),
+                            people: vec![people[left_idx].clone(), people[right_idx].clone()],
+                            session: None,
+                        });
                     }
 
                     pairs.push(CompiledPairConstraint {
@@ -1198,16 +1260,16 @@ fn compile_pair_meeting_constraints(
                 ));
             }
             let &lp = person_id_to_idx.get(&params.people[0]).ok_or_else(|| {
-                SolverError::ValidationError(format!(
-                    "PairMeetingCount: unknown person '{}'",
-                    params.people[0]
-                ))
+                SolverError::UnknownPersonId {
+                    person_id: params.people[0].to_string(),
+                    referenced_by: "PairMeetingCount".to_string(),
+                }
             })?;
             let &rp = person_id_to_idx.get(&params.people[1]).ok_or_else(|| {
-                SolverError::ValidationError(format!(
-                    "PairMeetingCount: unknown person '{}'",
-                    params.people[1]
-                ))
+                SolverError::UnknownPersonId {
+                    person_id: params.people[1].to_string(),
+                    referenced_by: "PairMeetingCount".to_string(),
+                }
             })?;
 
             let mut sessions = if params.sessions.is_empty() {
@@ -1286,10 +1348,10 @@ fn compile_attribute_balance_constraints(
             (0..num_groups).collect::<Vec<_>>()
         } else {
             vec![*group_id_to_idx.get(&params.group_id).ok_or_else(|| {
-                SolverError::ValidationError(format!(
-                    "AttributeBalance: unknown group '{}'",
-                    params.group_id
-                ))
+                SolverError::UnknownGroupId {
+                    group_id: params.group_id.to_string(),
+                    referenced_by: "AttributeBalance".to_string(),
+                }
             })?]
         };
 
@@ -574,6 +574,7 @@ impl RuntimeState {
             cliques: &cliques,
             clique_sessions: &clique_sessions,
             hard_apart_partners_by_person_session: &hard_apart_partners_by_person_session,
+            allowed_groups_by_person_session: &[],
             schedule: &mut schedule,
         };
 
@@ -1288,6 +1289,9 @@ fn constraint_scenario_warmup_solver_configuration(
             time_limit_seconds: Some(budget_seconds.ceil().max(1.0) as u64),
             no_improvement_iterations: None,
             stop_on_optimal_score: true,
+            stop_on_feasible: false,
+            feasible_score_threshold: None,
+            convergence: None,
         },
         solver_params: SolverParams::Solver3(Solver3Params::default()),
         logging: Default::default(),
@@ -64,6 +64,9 @@ mod tests {
                 time_limit_seconds: None,
                 no_improvement_iterations: None,
                 stop_on_optimal_score: true,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::Solver3(Solver3Params::default()),
             logging: Default::default(),
@@ -89,20 +92,27 @@ mod tests {
                         id: "g0".into(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                     Group {
                         id: "g1".into(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                 ],
                 num_sessions: 1,
             },
             initial_schedule: None,
             construction_seed_schedule: None,
+            contact_history: None,
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".into(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![],
             solver: solver3_config(),
@@ -125,11 +135,15 @@ mod tests {
                         id: "g0".into(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                     Group {
                         id: "g1".into(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                 ],
                 num_sessions: 2,
@@ -151,9 +165,12 @@ mod tests {
                 ),
             ])),
             construction_seed_schedule: None,
+            contact_history: None,
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".into(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![crate::models::Constraint::RepeatEncounter(
                 crate::models::RepeatEncounterParams {
@@ -182,11 +199,15 @@ mod tests {
                         id: "g0".into(),
                         size: 3,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                     Group {
                         id: "g1".into(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                 ],
                 num_sessions: 1,
@@ -202,9 +223,12 @@ mod tests {
                 ]),
             )])),
             construction_seed_schedule: None,
+            contact_history: None,
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".into(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![
                 Constraint::MustStayTogether {
@@ -238,11 +262,15 @@ mod tests {
                         id: "g0".into(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                     Group {
                         id: "g1".into(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                 ],
                 num_sessions: 2,
@@ -264,9 +292,12 @@ mod tests {
                 ),
             ])),
             construction_seed_schedule: None,
+            contact_history: None,
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".into(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![crate::models::Constraint::RepeatEncounter(
                 crate::models::RepeatEncounterParams {
@@ -12,7 +12,8 @@ use super::validation::{
     ensure_search_driver_feature_available, validate_multi_root_balanced_session_inheritance,
 };
 
-const DEFAULT_MAX_ITERATIONS: u64 = 10_000;
+/// `max_iterations` of a run whose stop conditions leave it unset.
+pub(crate) const DEFAULT_MAX_ITERATIONS: u64 = 10_000;
 
 #[derive(Debug, Clone)]
 pub(crate) struct SearchRunContext {
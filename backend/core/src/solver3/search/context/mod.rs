@@ -10,7 +10,9 @@ pub(crate) use config::{
     AdaptiveRawChildRetentionConfig, DonorSessionTransplantConfig,
     MultiRootBalancedSessionInheritanceConfig, SessionAlignedPathRelinkingConfig,
 };
-pub(crate) use config::{RuntimeScaledNoImprovementStopConfig, SearchRunContext};
+pub(crate) use config::{
+    RuntimeScaledNoImprovementStopConfig, SearchRunContext, DEFAULT_MAX_ITERATIONS,
+};
 pub(crate) use policy_memory::{IteratedLocalSearchMemory, SearchPolicyMemory};
 pub(crate) use progress::SearchProgressState;
 
@@ -40,6 +42,9 @@ mod tests {
                 time_limit_seconds: Some(9),
                 no_improvement_iterations: Some(17),
                 stop_on_optimal_score: true,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::Solver3(Solver3Params::default()),
             logging: Default::default(),
@@ -65,20 +70,27 @@ mod tests {
                         id: "g0".into(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                     Group {
                         id: "g1".into(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                 ],
                 num_sessions: 2,
             },
             initial_schedule: None,
             construction_seed_schedule: None,
+            contact_history: None,
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".into(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![],
             solver: solver3_config(),
@@ -101,20 +113,27 @@ mod tests {
                         id: "g0".into(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                     Group {
                         id: "g1".into(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                 ],
                 num_sessions: 2,
             },
             initial_schedule: None,
             construction_seed_schedule: None,
+            contact_history: None,
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".into(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![],
             solver: solver3_config(),
@@ -5,9 +5,9 @@ use crate::models::{
     MoveFamily, MoveFamilyBenchmarkTelemetry, MoveFamilyBenchmarkTelemetrySummary,
     MultiRootBalancedSessionInheritanceBenchmarkTelemetry, ProgressUpdate,
     RepeatGuidedSwapBenchmarkTelemetry, SessionAlignedPathRelinkingBenchmarkTelemetry,
-    SgpWeekPairTabuBenchmarkTelemetry, SolverBenchmarkTelemetry, StopReason,
+    SgpWeekPairTabuBenchmarkTelemetry, SolverBenchmarkTelemetry, SolverPhase, StopReason,
 };
-use crate::runtime_target::displayed_total_iterations;
+use crate::runtime_target::{displayed_total_iterations, estimated_remaining_seconds};
 
 use super::super::super::runtime_state::RuntimeState;
 use super::super::acceptance;
@@ -265,15 +265,23 @@ impl SearchProgressState {
                 / self.recent_acceptance.len() as f64
         };
 
+        let time_limit_seconds = run_context
+            .time_limit_seconds
+            .map(|seconds| seconds.ceil() as u64);
+        let cooling_progress = acceptance::cooling_progress(
+            iteration,
+            run_context.max_iterations,
+            elapsed_seconds,
+            time_limit_seconds,
+        );
+
         ProgressUpdate {
             iteration: completed_iterations,
             max_iterations: displayed_total_iterations(
                 completed_iterations,
                 run_context.max_iterations,
                 elapsed_seconds,
-                run_context
-                    .time_limit_seconds
-                    .map(|seconds| seconds.ceil() as u64),
+                time_limit_seconds,
                 stop_reason,
             ),
             temperature,
@@ -284,6 +292,21 @@ impl SearchProgressState {
             repetition_penalty: self.current_state.repetition_penalty_raw,
             elapsed_seconds,
             no_improvement_count: self.no_improvement_count,
+            estimated_remaining_seconds: if stop_reason.is_some() {
+                Some(0.0)
+            } else {
+                estimated_remaining_seconds(
+                    completed_iterations,
+                    run_context.max_iterations,
+                    elapsed_seconds,
+                    time_limit_seconds,
+                )
+            },
+            phase: Some(SolverPhase::from_search_progress(
+                completed_iterations,
+                0,
+                cooling_progress,
+            )),
             clique_swaps_tried: self.move_metrics.clique_swap.attempts,
             clique_swaps_accepted: self.move_metrics.clique_swap.accepted,
             clique_swaps_rejected: self.move_metrics.clique_swap.rejected,
@@ -313,14 +336,7 @@ impl SearchProgressState {
             } else {
                 elapsed_seconds * 1000.0 / completed_iterations as f64
             },
-            cooling_progress: acceptance::cooling_progress(
-                iteration,
-                run_context.max_iterations,
-                elapsed_seconds,
-                run_context
-                    .time_limit_seconds
-                    .map(|seconds| seconds.ceil() as u64),
-            ),
+            cooling_progress,
             clique_swap_success_rate: ratio(
                 self.move_metrics.clique_swap.accepted,
                 self.move_metrics.clique_swap.attempts,
@@ -341,9 +357,13 @@ impl SearchProgressState {
                 0.0
             },
             best_schedule: None,
+            best_schedule_diff: None,
             effective_seed: Some(run_context.effective_seed),
             move_policy: Some(run_context.move_policy.clone()),
             stop_reason,
+            checkpoint: None,
+            move_statistics: None,
+            constraint_penalties: None,
         }
     }
 
@@ -1,7 +1,7 @@
 use rand::{rng, RngExt};
 
 use crate::models::{
-    BenchmarkObserver, ProgressCallback, Solver3LocalImproverMode, Solver3SearchDriverMode,
+    BenchmarkObserver, ProgressHook, Solver3LocalImproverMode, Solver3SearchDriverMode,
     SolverConfiguration, SolverResult,
 };
 use crate::solver_support::SolverError;
@@ -25,7 +25,7 @@ impl SearchEngine {
     pub fn solve(
         &self,
         state: &mut RuntimeState,
-        progress_callback: Option<&ProgressCallback>,
+        progress_callback: Option<ProgressHook<'_>>,
         benchmark_observer: Option<&BenchmarkObserver>,
     ) -> Result<SolverResult, SolverError> {
         self.solve_with_time_limit_override(state, progress_callback, benchmark_observer, None)
@@ -34,7 +34,7 @@ impl SearchEngine {
     pub(crate) fn solve_with_time_limit_override(
         &self,
         state: &mut RuntimeState,
-        progress_callback: Option<&ProgressCallback>,
+        progress_callback: Option<ProgressHook<'_>>,
         benchmark_observer: Option<&BenchmarkObserver>,
         time_limit_seconds: Option<f64>,
     ) -> Result<SolverResult, SolverError> {
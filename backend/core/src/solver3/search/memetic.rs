@@ -10,7 +10,7 @@ use rand_chacha::ChaCha12Rng;
 
 use crate::models::{
     BenchmarkEvent, BenchmarkObserver, BenchmarkRunStarted, MemeticBenchmarkTelemetry, MoveFamily,
-    ProgressCallback, SolverResult, StopReason,
+    ProgressControl, ProgressHook, SolverResult, StopReason,
 };
 use crate::solver_support::SolverError;
 
@@ -84,7 +84,7 @@ impl PopulationMember {
 pub(crate) fn run(
     state: &mut RuntimeState,
     run_context: SearchRunContext,
-    progress_callback: Option<&ProgressCallback>,
+    progress_callback: Option<ProgressHook<'_>>,
     benchmark_observer: Option<&BenchmarkObserver>,
 ) -> Result<SolverResult, SolverError> {
     let total_started_at = get_current_time();
@@ -256,7 +256,7 @@ pub(crate) fn run(
                         elapsed_after_child,
                         None,
                     );
-                    if !(callback)(&progress) {
+                    if callback.report(&progress) == ProgressControl::Stop {
                         stop_reason = StopReason::ProgressCallbackRequestedStop;
                         let final_progress = search.to_progress_update(
                             &run_context,
@@ -265,7 +265,7 @@ pub(crate) fn run(
                             elapsed_after_child,
                             Some(stop_reason),
                         );
-                        let _ = (callback)(&final_progress);
+                        let _ = callback.report(&final_progress);
                         final_progress_emitted = true;
                         break;
                     }
@@ -300,7 +300,7 @@ pub(crate) fn run(
                 final_elapsed,
                 Some(stop_reason),
             );
-            let _ = (callback)(&final_progress);
+            let _ = callback.report(&final_progress);
         }
     }
 
@@ -15,7 +15,7 @@ mod archive;
 mod memetic;
 #[cfg(not(feature = "solver3-experimental-memetic"))]
 mod memetic {
-    use crate::models::{BenchmarkObserver, ProgressCallback, SolverResult};
+    use crate::models::{BenchmarkObserver, ProgressHook, SolverResult};
     use crate::solver_support::SolverError;
 
     use super::super::runtime_state::RuntimeState;
@@ -24,7 +24,7 @@ mod memetic {
     pub(super) fn run(
         _state: &mut RuntimeState,
         _run_context: SearchRunContext,
-        _progress_callback: Option<&ProgressCallback>,
+        _progress_callback: Option<ProgressHook<'_>>,
         _benchmark_observer: Option<&BenchmarkObserver>,
     ) -> Result<SolverResult, SolverError> {
         Err(SolverError::ValidationError(
@@ -37,7 +37,7 @@ mod memetic {
 mod path_relinking;
 #[cfg(not(feature = "solver3-experimental-recombination"))]
 mod path_relinking {
-    use crate::models::{BenchmarkObserver, ProgressCallback, SolverResult};
+    use crate::models::{BenchmarkObserver, ProgressHook, SolverResult};
     use crate::solver_support::SolverError;
 
     use super::super::runtime_state::RuntimeState;
@@ -48,7 +48,7 @@ mod path_relinking {
     pub(super) fn run(
         _state: &mut RuntimeState,
         _run_context: SearchRunContext,
-        _progress_callback: Option<&ProgressCallback>,
+        _progress_callback: Option<ProgressHook<'_>>,
         _benchmark_observer: Option<&BenchmarkObserver>,
     ) -> Result<SolverResult, SolverError> {
         Err(SolverError::ValidationError(
@@ -60,7 +60,7 @@ mod path_relinking {
     pub(super) fn run_multi_root_balanced_session_inheritance(
         _state: &mut RuntimeState,
         _run_context: SearchRunContext,
-        _progress_callback: Option<&ProgressCallback>,
+        _progress_callback: Option<ProgressHook<'_>>,
         _benchmark_observer: Option<&BenchmarkObserver>,
     ) -> Result<SolverResult, SolverError> {
         Err(SolverError::ValidationError(
@@ -73,7 +73,7 @@ mod path_relinking {
 mod recombination;
 #[cfg(not(feature = "solver3-experimental-recombination"))]
 mod recombination {
-    use crate::models::{BenchmarkObserver, ProgressCallback, SolverResult};
+    use crate::models::{BenchmarkObserver, ProgressHook, SolverResult};
     use crate::solver_support::SolverError;
 
     use super::super::runtime_state::RuntimeState;
@@ -82,7 +82,7 @@ mod recombination {
     pub(super) fn run(
         _state: &mut RuntimeState,
         _run_context: SearchRunContext,
-        _progress_callback: Option<&ProgressCallback>,
+        _progress_callback: Option<ProgressHook<'_>>,
         _benchmark_observer: Option<&BenchmarkObserver>,
     ) -> Result<SolverResult, SolverError> {
         Err(SolverError::ValidationError(
@@ -133,4 +133,5 @@ mod sgp_conflicts {
 #[cfg(test)]
 mod tests;
 
+pub(crate) use context::DEFAULT_MAX_ITERATIONS;
 pub use engine::SearchEngine;
@@ -5,10 +5,9 @@ use rand::{RngExt, SeedableRng};
 use rand_chacha::ChaCha12Rng;
 
 use crate::models::{
-    BenchmarkEvent, BenchmarkObserver, ProgressCallback,
-    SessionAlignedPathRelinkingBenchmarkTelemetry, SessionAlignedPathRelinkingEventTelemetry,
-    SessionAlignedPathRelinkingStepTelemetry, Solver3PathRelinkingOperatorVariant, SolverResult,
-    StopReason,
+    BenchmarkEvent, BenchmarkObserver, ProgressHook, SessionAlignedPathRelinkingBenchmarkTelemetry,
+    SessionAlignedPathRelinkingEventTelemetry, SessionAlignedPathRelinkingStepTelemetry,
+    Solver3PathRelinkingOperatorVariant, SolverResult, StopReason,
 };
 use crate::solver3::search::archive;
 use crate::solver_support::SolverError;
@@ -69,7 +68,7 @@ struct PathStepEvaluation {
 pub(crate) fn run(
     state: &mut RuntimeState,
     run_context: SearchRunContext,
-    progress_callback: Option<&ProgressCallback>,
+    progress_callback: Option<ProgressHook<'_>>,
     benchmark_observer: Option<&BenchmarkObserver>,
 ) -> Result<SolverResult, SolverError> {
     let total_started_at = get_current_time();
@@ -692,7 +691,7 @@ pub(crate) fn run(
                 final_elapsed,
                 Some(stop_reason),
             );
-            let _ = (callback)(&final_progress);
+            let _ = callback.report(&final_progress);
         }
     }
 
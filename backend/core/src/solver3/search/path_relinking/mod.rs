@@ -142,20 +142,27 @@ mod tests {
                         id: "g0".into(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                     Group {
                         id: "g1".into(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                 ],
                 num_sessions: sessions.len() as u32,
             },
             initial_schedule: Some(schedule(&["g0", "g1"], sessions)),
             construction_seed_schedule: None,
+            contact_history: None,
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".into(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![Constraint::RepeatEncounter(RepeatEncounterParams {
                 max_allowed_encounters: 1,
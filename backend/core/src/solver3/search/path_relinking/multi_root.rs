@@ -3,7 +3,7 @@ use rand_chacha::ChaCha12Rng;
 
 use crate::models::{
     BenchmarkEvent, BenchmarkObserver, MultiRootBalancedSessionInheritanceBenchmarkTelemetry,
-    MultiRootBalancedSessionInheritanceEventTelemetry, ProgressCallback, SolverResult, StopReason,
+    MultiRootBalancedSessionInheritanceEventTelemetry, ProgressHook, SolverResult, StopReason,
 };
 use crate::solver_support::SolverError;
 
@@ -59,7 +59,7 @@ struct CanonicalCrossRootParentPair {
 pub(crate) fn run_multi_root_balanced_session_inheritance(
     state: &mut RuntimeState,
     run_context: SearchRunContext,
-    _progress_callback: Option<&ProgressCallback>,
+    _progress_callback: Option<ProgressHook<'_>>,
     benchmark_observer: Option<&BenchmarkObserver>,
 ) -> Result<SolverResult, SolverError> {
     let total_started_at = get_current_time();
@@ -1,7 +1,7 @@
 use crate::models::{
     BestScoreTimelinePoint, MoveFamilyBenchmarkTelemetry, MoveFamilyBenchmarkTelemetrySummary,
-    ProgressCallback, RepeatGuidedSwapBenchmarkTelemetry, SgpWeekPairTabuBenchmarkTelemetry,
-    StopReason,
+    ProgressControl, ProgressHook, RepeatGuidedSwapBenchmarkTelemetry,
+    SgpWeekPairTabuBenchmarkTelemetry, StopReason,
 };
 
 use super::super::context::SearchProgressState;
@@ -126,7 +126,7 @@ fn merge_move_family_metrics(
 pub(super) fn maybe_emit_progress(
     aggregate: &SearchProgressState,
     run_context: &SearchRunContext,
-    progress_callback: Option<&ProgressCallback>,
+    progress_callback: Option<ProgressHook<'_>>,
     total_started_at: TimePoint,
     last_progress_callback_at: &mut TimePoint,
 ) -> bool {
@@ -144,7 +144,7 @@ pub(super) fn maybe_emit_progress(
 
     let elapsed_seconds = get_elapsed_seconds(total_started_at);
     let progress = aggregate.to_progress_update(run_context, iteration, 0.0, elapsed_seconds, None);
-    if !(callback)(&progress) {
+    if callback.report(&progress) == ProgressControl::Stop {
         let final_progress = aggregate.to_progress_update(
             run_context,
             iteration,
@@ -152,7 +152,7 @@ pub(super) fn maybe_emit_progress(
             elapsed_seconds,
             Some(StopReason::ProgressCallbackRequestedStop),
         );
-        let _ = (callback)(&final_progress);
+        let _ = callback.report(&final_progress);
         return true;
     }
 
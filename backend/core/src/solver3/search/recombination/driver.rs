@@ -2,8 +2,8 @@ use rand::{RngExt, SeedableRng};
 use rand_chacha::ChaCha12Rng;
 
 use crate::models::{
-    BenchmarkEvent, BenchmarkObserver, DonorSessionTransplantBenchmarkTelemetry, ProgressCallback,
-    SolverResult, StopReason,
+    BenchmarkEvent, BenchmarkObserver, DonorSessionTransplantBenchmarkTelemetry, ProgressControl,
+    ProgressHook, SolverResult, StopReason,
 };
 use crate::solver_support::SolverError;
 
@@ -35,7 +35,7 @@ use crate::solver3::search::archive;
 pub(crate) fn run(
     state: &mut RuntimeState,
     run_context: SearchRunContext,
-    progress_callback: Option<&ProgressCallback>,
+    progress_callback: Option<ProgressHook<'_>>,
     benchmark_observer: Option<&BenchmarkObserver>,
 ) -> Result<SolverResult, SolverError> {
     let total_started_at = get_current_time();
@@ -456,7 +456,7 @@ pub(crate) fn run(
                 final_elapsed,
                 Some(stop_reason),
             );
-            let _ = (callback)(&final_progress);
+            let _ = callback.report(&final_progress);
         }
     }
 
@@ -505,7 +505,7 @@ pub(super) fn transplant_donor_session(
 fn maybe_emit_progress(
     aggregate: &SearchProgressState,
     run_context: &SearchRunContext,
-    progress_callback: Option<&ProgressCallback>,
+    progress_callback: Option<ProgressHook<'_>>,
     total_started_at: TimePoint,
     last_progress_callback_at: &mut TimePoint,
 ) -> bool {
@@ -523,7 +523,7 @@ fn maybe_emit_progress(
 
     let elapsed_seconds = get_elapsed_seconds(total_started_at);
     let progress = aggregate.to_progress_update(run_context, iteration, 0.0, elapsed_seconds, None);
-    if !(callback)(&progress) {
+    if callback.report(&progress) == ProgressControl::Stop {
         let final_progress = aggregate.to_progress_update(
             run_context,
             iteration,
@@ -531,7 +531,7 @@ fn maybe_emit_progress(
             elapsed_seconds,
             Some(StopReason::ProgressCallbackRequestedStop),
         );
-        let _ = (callback)(&final_progress);
+        let _ = callback.report(&final_progress);
         return true;
     }
 
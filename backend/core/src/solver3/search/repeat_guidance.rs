@@ -222,6 +222,9 @@ mod tests {
                 time_limit_seconds: None,
                 no_improvement_iterations: Some(40),
                 stop_on_optimal_score: true,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::Solver3(Solver3Params::default()),
             logging: Default::default(),
@@ -245,11 +248,15 @@ mod tests {
                 id: "g0".into(),
                 size: 2,
                 session_sizes: None,
+                attributes: HashMap::new(),
+                available_sessions: None,
             },
             Group {
                 id: "g1".into(),
                 size: 2,
                 session_sizes: None,
+                attributes: HashMap::new(),
+                available_sessions: None,
             },
         ];
 
@@ -278,9 +285,12 @@ mod tests {
             },
             initial_schedule: Some(initial_schedule),
             construction_seed_schedule: None,
+            contact_history: None,
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".into(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![Constraint::RepeatEncounter(RepeatEncounterParams {
                 max_allowed_encounters: 1,
@@ -308,20 +318,27 @@ mod tests {
                         id: "g0".into(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                     Group {
                         id: "g1".into(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                 ],
                 num_sessions: 1,
             },
             initial_schedule: None,
             construction_seed_schedule: None,
+            contact_history: None,
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".into(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![],
             solver: solver3_config(),
@@ -396,16 +413,22 @@ mod tests {
                         id: "g0".into(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                     Group {
                         id: "g1".into(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                     Group {
                         id: "g2".into(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                 ],
                 num_sessions: 2,
@@ -429,9 +452,12 @@ mod tests {
                 ),
             ])),
             construction_seed_schedule: None,
+            contact_history: None,
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".into(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![Constraint::RepeatEncounter(RepeatEncounterParams {
                 max_allowed_encounters: 1,
@@ -468,16 +494,22 @@ mod tests {
                         id: "g0".into(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                     Group {
                         id: "g1".into(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                     Group {
                         id: "g2".into(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                 ],
                 num_sessions: 2,
@@ -501,9 +533,12 @@ mod tests {
                 ),
             ])),
             construction_seed_schedule: None,
+            contact_history: None,
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".into(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![
                 Constraint::RepeatEncounter(RepeatEncounterParams {
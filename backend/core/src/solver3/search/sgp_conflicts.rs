@@ -175,6 +175,9 @@ mod tests {
                 time_limit_seconds: None,
                 no_improvement_iterations: None,
                 stop_on_optimal_score: false,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::Solver3(Solver3Params::default()),
             logging: Default::default(),
@@ -200,11 +203,15 @@ mod tests {
                         id: "g0".into(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                     Group {
                         id: "g1".into(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                 ],
                 num_sessions: 2,
@@ -226,9 +233,12 @@ mod tests {
                 ),
             ])),
             construction_seed_schedule: None,
+            contact_history: None,
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".into(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![Constraint::RepeatEncounter(RepeatEncounterParams {
                 max_allowed_encounters: 1,
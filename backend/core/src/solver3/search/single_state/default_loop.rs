@@ -1,7 +1,9 @@
 use rand::SeedableRng;
 use rand_chacha::ChaCha12Rng;
 
-use crate::models::{BenchmarkEvent, BenchmarkRunStarted, Solver3LocalImproverMode, StopReason};
+use crate::models::{
+    BenchmarkEvent, BenchmarkRunStarted, ProgressControl, Solver3LocalImproverMode, StopReason,
+};
 use crate::solver_support::SolverError;
 
 use super::super::super::runtime_state::RuntimeState;
@@ -272,7 +274,7 @@ pub(super) fn run_local_improver_default(
                         None,
                     );
 
-                    if !(callback)(&progress) {
+                    if callback.report(&progress) == ProgressControl::Stop {
                         stop_reason = StopReason::ProgressCallbackRequestedStop;
                         let final_progress = search.to_progress_update(
                             run_context,
@@ -281,7 +283,7 @@ pub(super) fn run_local_improver_default(
                             callback_elapsed_seconds,
                             Some(stop_reason),
                         );
-                        let _ = (callback)(&final_progress);
+                        let _ = callback.report(&final_progress);
                         final_progress_emitted = true;
                         break;
                     }
@@ -325,7 +327,7 @@ pub(super) fn run_local_improver_default(
                 final_elapsed,
                 Some(stop_reason),
             );
-            let _ = (callback)(&final_progress);
+            let _ = callback.report(&final_progress);
         }
     }
 
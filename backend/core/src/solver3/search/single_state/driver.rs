@@ -1,5 +1,5 @@
 use crate::models::{
-    BenchmarkEvent, BenchmarkObserver, ProgressCallback, Solver3LocalImproverMode, SolverResult,
+    BenchmarkEvent, BenchmarkObserver, ProgressHook, Solver3LocalImproverMode, SolverResult,
     StopReason,
 };
 use crate::solver_support::SolverError;
@@ -30,14 +30,14 @@ pub(crate) struct LocalImproverRunResult {
 
 #[derive(Clone, Copy)]
 pub(super) struct LocalImproverHooks<'a> {
-    pub(super) progress_callback: Option<&'a ProgressCallback>,
+    pub(super) progress_callback: Option<ProgressHook<'a>>,
     pub(super) benchmark_observer: Option<&'a BenchmarkObserver>,
 }
 
 pub(crate) fn run(
     state: &mut RuntimeState,
     run_context: SearchRunContext,
-    progress_callback: Option<&ProgressCallback>,
+    progress_callback: Option<ProgressHook<'_>>,
     benchmark_observer: Option<&BenchmarkObserver>,
 ) -> Result<SolverResult, SolverError> {
     let outcome = run_local_improver(
@@ -1,7 +1,9 @@
 use rand::SeedableRng;
 use rand_chacha::ChaCha12Rng;
 
-use crate::models::{BenchmarkEvent, BenchmarkRunStarted, Solver3LocalImproverMode, StopReason};
+use crate::models::{
+    BenchmarkEvent, BenchmarkRunStarted, ProgressControl, Solver3LocalImproverMode, StopReason,
+};
 use crate::solver_support::SolverError;
 
 use super::super::super::runtime_state::RuntimeState;
@@ -400,7 +402,7 @@ pub(super) fn run_local_improver_general(
                         None,
                     );
 
-                    if !(callback)(&progress) {
+                    if callback.report(&progress) == ProgressControl::Stop {
                         stop_reason = StopReason::ProgressCallbackRequestedStop;
                         let final_progress = search.to_progress_update(
                             run_context,
@@ -409,7 +411,7 @@ pub(super) fn run_local_improver_general(
                             callback_elapsed_seconds,
                             Some(stop_reason),
                         );
-                        let _ = (callback)(&final_progress);
+                        let _ = callback.report(&final_progress);
                         final_progress_emitted = true;
                         break;
                     }
@@ -453,7 +455,7 @@ pub(super) fn run_local_improver_general(
                 final_elapsed,
                 Some(stop_reason),
             );
-            let _ = (callback)(&final_progress);
+            let _ = callback.report(&final_progress);
         }
     }
 
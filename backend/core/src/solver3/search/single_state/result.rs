@@ -32,7 +32,22 @@ pub(crate) fn build_solver_result(
         effective_seed: Some(effective_seed),
         move_policy: Some(move_policy),
         stop_reason: Some(stop_reason),
+        iterations_run: Some(benchmark_telemetry.iterations_completed),
+        time_elapsed_ms: Some(benchmark_telemetry.total_milliseconds()),
+        final_temperature: None,
+        accepted_moves: Some(benchmark_telemetry.accepted_moves()),
         benchmark_telemetry: Some(benchmark_telemetry),
+        constraint_violations: None,
+        session_breakdown: None,
+        contact_matrix: None,
+        display_names: None,
+        person_attributes: None,
+        score_history: None,
+        move_statistics: None,
+        constraint_penalties: None,
+        effective_configuration: None,
+        unique_contacts_upper_bound: None,
+        optimality_gap: None,
     })
 }
 
@@ -36,6 +36,9 @@ fn solver3_config() -> SolverConfiguration {
             time_limit_seconds: None,
             no_improvement_iterations: Some(40),
             stop_on_optimal_score: true,
+            stop_on_feasible: false,
+            feasible_score_threshold: None,
+            convergence: None,
         },
         solver_params: SolverParams::Solver3(Solver3Params::default()),
         logging: Default::default(),
@@ -75,11 +78,15 @@ fn search_input() -> ApiInput {
             id: "g0".into(),
             size: 2,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         },
         Group {
             id: "g1".into(),
             size: 2,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         },
     ];
 
@@ -91,9 +98,12 @@ fn search_input() -> ApiInput {
         },
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![],
         solver: solver3_config(),
@@ -115,11 +125,15 @@ fn repeat_guidance_input() -> ApiInput {
                     id: "g0".into(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g1".into(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 2,
@@ -141,9 +155,12 @@ fn repeat_guidance_input() -> ApiInput {
             ),
         ])),
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![Constraint::RepeatEncounter(RepeatEncounterParams {
             max_allowed_encounters: 1,
@@ -67,6 +67,9 @@ fn solver3_config() -> SolverConfiguration {
             time_limit_seconds: None,
             no_improvement_iterations: None,
             stop_on_optimal_score: true,
+            stop_on_feasible: false,
+            feasible_score_threshold: None,
+            convergence: None,
         },
         solver_params: SolverParams::Solver3(Solver3Params::default()),
         logging: Default::default(),
@@ -93,20 +96,27 @@ fn minimal_input() -> ApiInput {
                     id: "g0".into(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g1".into(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 2,
         },
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![],
         solver: solver3_config(),
@@ -129,15 +139,20 @@ fn pure_sgp_solver3_input(num_groups: usize, group_size: usize, num_sessions: us
                     id: format!("g{idx}"),
                     size: group_size as u32,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 })
                 .collect(),
             num_sessions: num_sessions as u32,
         },
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![Constraint::RepeatEncounter(RepeatEncounterParams {
             max_allowed_encounters: 1,
@@ -186,11 +201,15 @@ fn representative_input() -> ApiInput {
             id: "g0".into(),
             size: 3,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         },
         Group {
             id: "g1".into(),
             size: 3,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         },
     ];
 
@@ -225,9 +244,12 @@ fn representative_input() -> ApiInput {
         },
         initial_schedule: Some(initial_schedule),
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![
             Constraint::RepeatEncounter(crate::models::RepeatEncounterParams {
@@ -332,25 +354,34 @@ fn freedom_aware_input() -> ApiInput {
                     id: "g0".into(),
                     size: 2,
                     session_sizes: Some(vec![2, 2]),
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g1".into(),
                     size: 1,
                     session_sizes: Some(vec![1, 1]),
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g2".into(),
                     size: 2,
                     session_sizes: Some(vec![2, 2]),
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 2,
         },
         initial_schedule: None,
         construction_seed_schedule: Some(construction_seed_schedule),
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![
             Constraint::MustStayTogether {
@@ -426,11 +457,14 @@ fn num_pairs_is_zero_for_single_person() {
                 id: "g0".into(),
                 size: 1,
                 session_sizes: None,
+                attributes: HashMap::new(),
+                available_sessions: None,
             }],
             num_sessions: 1,
         },
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![],
         constraints: vec![],
         solver: solver3_config(),
@@ -1806,20 +1840,27 @@ fn zero_unique_contacts_when_no_pairs_share_groups() {
                     id: "g0".into(),
                     size: 1,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g1".into(),
                     size: 1,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 1,
         },
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![],
         solver: solver3_config(),
@@ -1853,17 +1894,22 @@ fn immovable_violation_is_diagnostic_not_scored() {
                     id: "g0".into(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g1".into(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 1,
         },
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![],
         constraints: vec![Constraint::ImmovablePerson(ImmovablePersonParams {
             person_id: "p0".into(),
@@ -1917,11 +1963,14 @@ fn soft_apart_pair_penalty_is_scored() {
                 id: "g0".into(),
                 size: 2,
                 session_sizes: None,
+                attributes: HashMap::new(),
+                available_sessions: None,
             }],
             num_sessions: 1,
         },
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![],
         constraints: vec![Constraint::ShouldNotBeTogether {
             people: vec!["a".into(), "b".into()],
@@ -1995,16 +2044,22 @@ fn swap_kernel_input() -> ApiInput {
             id: "g0".into(),
             size: 2,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         },
         Group {
             id: "g1".into(),
             size: 2,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         },
         Group {
             id: "g2".into(),
             size: 2,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         },
     ];
 
@@ -2034,9 +2089,12 @@ fn swap_kernel_input() -> ApiInput {
         },
         initial_schedule: Some(initial_schedule),
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![
             Constraint::RepeatEncounter(RepeatEncounterParams {
@@ -2213,11 +2271,15 @@ fn swap_feasibility_rejects_must_stay_apart_conflict() {
                     id: "g0".into(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g1".into(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 1,
@@ -2230,6 +2292,7 @@ fn swap_feasibility_rejects_must_stay_apart_conflict() {
             ]),
         )])),
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![],
         constraints: vec![Constraint::MustStayApart {
             people: vec!["p0".into(), "p1".into()],
@@ -2273,16 +2336,22 @@ fn transfer_kernel_input() -> ApiInput {
                     id: "g0".into(),
                     size: 3,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g1".into(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g2".into(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 2,
@@ -2306,9 +2375,12 @@ fn transfer_kernel_input() -> ApiInput {
             ),
         ])),
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![
             Constraint::RepeatEncounter(RepeatEncounterParams {
@@ -2370,16 +2442,22 @@ fn transfer_restricted_input() -> ApiInput {
                     id: "g0".into(),
                     size: 3,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g1".into(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g2".into(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 2,
@@ -2403,9 +2481,12 @@ fn transfer_restricted_input() -> ApiInput {
             ),
         ])),
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![
             Constraint::MustStayTogether {
@@ -2712,11 +2793,15 @@ fn transfer_feasibility_regressions_report_specific_reasons() {
                     id: "g0".into(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g1".into(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 1,
@@ -2729,6 +2814,7 @@ fn transfer_feasibility_regressions_report_specific_reasons() {
             ]),
         )])),
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![],
         constraints: vec![Constraint::MustStayApart {
             people: vec!["p0".into(), "p1".into()],
@@ -2779,16 +2865,22 @@ fn clique_swap_kernel_input() -> ApiInput {
                     id: "g0".into(),
                     size: 3,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g1".into(),
                     size: 3,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g2".into(),
                     size: 1,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 2,
@@ -2812,9 +2904,12 @@ fn clique_swap_kernel_input() -> ApiInput {
             ),
         ])),
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![
             Constraint::RepeatEncounter(RepeatEncounterParams {
@@ -2877,16 +2972,22 @@ fn clique_swap_restricted_input() -> ApiInput {
                     id: "g0".into(),
                     size: 3,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g1".into(),
                     size: 3,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g2".into(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 2,
@@ -2910,9 +3011,12 @@ fn clique_swap_restricted_input() -> ApiInput {
             ),
         ])),
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![
             Constraint::MustStayTogether {
@@ -3192,16 +3296,22 @@ fn clique_heavy_feasibility_regressions_report_specific_reasons() {
                     id: "g0".into(),
                     size: 3,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g1".into(),
                     size: 3,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g2".into(),
                     size: 1,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 1,
@@ -3215,6 +3325,7 @@ fn clique_heavy_feasibility_regressions_report_specific_reasons() {
             ]),
         )])),
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![],
         constraints: vec![
             Constraint::MustStayTogether {
@@ -8,6 +8,8 @@ pub(super) fn stop_reason_name(reason: StopReason) -> &'static str {
         StopReason::NoImprovementTimeLimitReached => "no_improvement_time_limit_reached",
         StopReason::ProgressCallbackRequestedStop => "progress_callback_requested_stop",
         StopReason::OptimalScoreReached => "optimal_score_reached",
+        StopReason::Converged => "converged",
+        StopReason::FeasibleReached => "feasible_reached",
     }
 }
 
@@ -96,7 +98,28 @@ pub(super) fn build_solver_result(
         effective_seed: Some(effective_seed),
         move_policy: Some(crate::models::MovePolicy::default()),
         stop_reason: Some(stop_reason),
+        iterations_run: benchmark_telemetry
+            .as_ref()
+            .map(|telemetry| telemetry.iterations_completed),
+        time_elapsed_ms: benchmark_telemetry
+            .as_ref()
+            .map(SolverBenchmarkTelemetry::total_milliseconds),
+        final_temperature: None,
+        accepted_moves: benchmark_telemetry
+            .as_ref()
+            .map(SolverBenchmarkTelemetry::accepted_moves),
         benchmark_telemetry,
+        constraint_violations: None,
+        session_breakdown: None,
+        contact_matrix: None,
+        display_names: None,
+        person_attributes: None,
+        score_history: None,
+        move_statistics: None,
+        constraint_penalties: None,
+        effective_configuration: None,
+        unique_contacts_upper_bound: None,
+        optimality_gap: None,
     })
 }
 
@@ -193,6 +193,8 @@ fn pure_problem(num_groups: u32, group_size: u32, weeks: u32) -> ProblemDefiniti
                 id: format!("g{idx}"),
                 size: group_size,
                 session_sizes: None,
+                attributes: HashMap::new(),
+                available_sessions: None,
             })
             .collect(),
         num_sessions: weeks,
@@ -207,6 +209,9 @@ fn solver4_config() -> SolverConfiguration {
             time_limit_seconds: Some(5),
             no_improvement_iterations: Some(1_000),
             stop_on_optimal_score: true,
+            stop_on_feasible: false,
+            feasible_score_threshold: None,
+            convergence: None,
         },
         solver_params: SolverParams::Solver4(Solver4Params::default()),
         logging: LoggingOptions::default(),
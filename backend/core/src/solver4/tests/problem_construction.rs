@@ -8,6 +8,7 @@ fn pure_problem_gate_rejects_partial_attendance() {
         problem,
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![],
         constraints: vec![repeat_constraint()],
         solver: solver4_config(),
@@ -22,6 +23,7 @@ fn pure_problem_gate_requires_meet_at_most_once_repeat_encoding() {
         problem: pure_problem(2, 2, 2),
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![],
         constraints: vec![Constraint::RepeatEncounter(RepeatEncounterParams {
             max_allowed_encounters: 0,
@@ -40,6 +42,7 @@ fn pure_problem_gate_requires_repeat_constraint() {
         problem: pure_problem(2, 2, 2),
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![],
         constraints: vec![],
         solver: solver4_config(),
@@ -56,9 +59,12 @@ fn solver4_solves_small_pure_instance() {
         problem: pure_problem(2, 2, 2),
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![repeat_constraint()],
         solver: solver4_config(),
@@ -81,9 +87,12 @@ fn solver4_complete_backtracking_solves_small_instance() {
         problem: pure_problem(2, 2, 2),
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![repeat_constraint()],
         solver: config,
@@ -99,9 +108,12 @@ fn solver4_final_result_uses_canonical_repo_scoring() {
         problem: pure_problem(1, 2, 2),
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![repeat_constraint()],
         solver: solver4_config(),
@@ -132,9 +144,12 @@ fn canonical_scoring_gives_zero_for_perfect_meet_at_most_once_schedule() {
         problem: pure_problem(1, 2, 1),
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![repeat_constraint()],
         solver: solver4_config(),
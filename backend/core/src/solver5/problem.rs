@@ -33,6 +33,11 @@ impl PureSgpProblem {
                     .into(),
             ));
         }
+        if input.contact_history.is_some() {
+            return Err(SolverError::ValidationError(
+                "solver5 does not accept contact_history; it constructs schedules directly".into(),
+            ));
+        }
 
         let num_weeks = usize::try_from(input.problem.num_sessions).map_err(|_| {
             SolverError::ValidationError("solver5 num_sessions does not fit usize".into())
@@ -59,7 +64,7 @@ impl PureSgpProblem {
                 "solver5 requires positive uniform group size".into(),
             ));
         }
-        if first_group.session_sizes.is_some() {
+        if first_group.session_sizes.is_some() || first_group.available_sessions.is_some() {
             return Err(SolverError::ValidationError(
                 "solver5 rejects session-specific capacities; pure SGP requires one fixed group size"
                     .into(),
@@ -70,7 +75,7 @@ impl PureSgpProblem {
         })?;
 
         for group in &input.problem.groups {
-            if group.session_sizes.is_some() {
+            if group.session_sizes.is_some() || group.available_sessions.is_some() {
                 return Err(SolverError::ValidationError(
                     "solver5 rejects session-specific capacities; pure SGP requires one fixed group size"
                         .into(),
@@ -135,6 +140,18 @@ fn validate_pure_sgp_objectives(objectives: &[Objective]) -> Result<(), SolverEr
                 objective.r#type
             )));
         }
+        if !objective.person_weights.is_empty() {
+            return Err(SolverError::ValidationError(
+                "solver5 rejects objective person_weights; pure SGP weighs every contact equally"
+                    .into(),
+            ));
+        }
+        if !objective.session_weights.is_empty() {
+            return Err(SolverError::ValidationError(
+                "solver5 rejects objective session_weights; pure SGP weighs every session equally"
+                    .into(),
+            ));
+        }
     }
     Ok(())
 }
@@ -59,6 +59,7 @@ use catalog::{
 use problem::PureSgpProblem;
 use result::build_solver_result;
 use scaffolding::ReservedExecutionPlan;
+pub(crate) use search::DEFAULT_MAX_ITERATIONS;
 use search::{run_configured_local_search, state::LocalSearchState, RepeatAwareLocalSearchOutcome};
 use seed::mixed::{build_preferred_mixed_seed, MixedSeedSelection};
 
@@ -77,6 +77,9 @@ impl Default for Solver6BenchmarkConfig {
                 time_limit_seconds: Some(1),
                 no_improvement_iterations: Some(150),
                 stop_on_optimal_score: true,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             active_penalty_model: Solver6PairRepeatPenaltyModel::LinearRepeatExcess,
             matrices: default_matrix_views(),
@@ -650,6 +653,8 @@ fn objective_status_for_metrics(
         "no_improvement_limit_reached" => StopReason::NoImprovementLimitReached,
         "no_improvement_time_limit_reached" => StopReason::NoImprovementTimeLimitReached,
         "optimal_score_reached" => StopReason::OptimalScoreReached,
+        "converged" => StopReason::Converged,
+        "feasible_reached" => StopReason::FeasibleReached,
         _ => StopReason::NoImprovementLimitReached,
     };
     match model {
@@ -997,15 +1002,20 @@ fn pure_input_for_benchmark_with_penalty_model(
                     id: format!("G{}", idx + 1),
                     size: group_size as u32,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 })
                 .collect(),
             num_sessions: weeks as u32,
         },
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![Constraint::RepeatEncounter(RepeatEncounterParams {
             max_allowed_encounters: 1,
@@ -1299,6 +1309,8 @@ fn stop_reason_label(reason: StopReason) -> &'static str {
         StopReason::NoImprovementTimeLimitReached => "no_improvement_time_limit_reached",
         StopReason::ProgressCallbackRequestedStop => "progress_callback_requested_stop",
         StopReason::OptimalScoreReached => "optimal_score_reached",
+        StopReason::Converged => "converged",
+        StopReason::FeasibleReached => "feasible_reached",
     }
 }
 
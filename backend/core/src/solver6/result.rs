@@ -28,6 +28,21 @@ pub(super) fn build_solver_result(
         move_policy: Some(MovePolicy::default()),
         stop_reason: Some(stop_reason),
         benchmark_telemetry: None,
+        iterations_run: None,
+        time_elapsed_ms: None,
+        final_temperature: None,
+        accepted_moves: None,
+        constraint_violations: None,
+        session_breakdown: None,
+        contact_matrix: None,
+        display_names: None,
+        person_attributes: None,
+        score_history: None,
+        move_statistics: None,
+        constraint_penalties: None,
+        effective_configuration: None,
+        unique_contacts_upper_bound: None,
+        optimality_gap: None,
     })
 }
 
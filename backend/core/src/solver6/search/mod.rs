@@ -18,6 +18,9 @@ use crate::solver_support::SolverError;
 #[cfg(target_arch = "wasm32")]
 use js_sys;
 
+/// `max_iterations` of the local search when the stop conditions leave it unset.
+pub(crate) const DEFAULT_MAX_ITERATIONS: u64 = 5_000;
+
 #[cfg(not(target_arch = "wasm32"))]
 use std::time::Instant as SearchInstant;
 
@@ -79,7 +82,9 @@ impl RepeatAwareLocalSearchConfig {
         problem: &PureSgpProblem,
         effective_seed: u64,
     ) -> Self {
-        let max_iterations = stop_conditions.max_iterations.unwrap_or(5_000);
+        let max_iterations = stop_conditions
+            .max_iterations
+            .unwrap_or(DEFAULT_MAX_ITERATIONS);
         let no_improvement_limit = stop_conditions
             .no_improvement_iterations
             .unwrap_or(max_iterations.min(500));
@@ -110,7 +115,9 @@ pub(crate) struct DeterministicHillClimbConfig {
 impl DeterministicHillClimbConfig {
     pub(crate) fn for_solver_configuration(stop_conditions: &StopConditions) -> Self {
         Self {
-            max_iterations: stop_conditions.max_iterations.unwrap_or(5_000),
+            max_iterations: stop_conditions
+                .max_iterations
+                .unwrap_or(DEFAULT_MAX_ITERATIONS),
             time_limit_seconds: stop_conditions.time_limit_seconds,
         }
     }
@@ -549,6 +556,9 @@ mod tests {
                 time_limit_seconds: Some(30),
                 no_improvement_iterations: Some(4),
                 stop_on_optimal_score: true,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::Solver6(Solver6Params::default()),
             logging: Default::default(),
@@ -574,15 +584,20 @@ mod tests {
                         id: format!("g{idx}"),
                         size: group_size as u32,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     })
                     .collect(),
                 num_sessions: weeks as u32,
             },
             initial_schedule: None,
             construction_seed_schedule: None,
+            contact_history: None,
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".into(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![Constraint::RepeatEncounter(RepeatEncounterParams {
                 max_allowed_encounters: 1,
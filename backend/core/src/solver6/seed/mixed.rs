@@ -492,6 +492,9 @@ mod tests {
                 time_limit_seconds: Some(10),
                 no_improvement_iterations: Some(40),
                 stop_on_optimal_score: true,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::Solver6(Solver6Params {
                 exact_construction_handoff_enabled: false,
@@ -525,15 +528,20 @@ mod tests {
                         id: format!("g{idx}"),
                         size: group_size as u32,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     })
                     .collect(),
                 num_sessions: weeks as u32,
             },
             initial_schedule: None,
             construction_seed_schedule: None,
+            contact_history: None,
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".into(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![Constraint::RepeatEncounter(RepeatEncounterParams {
                 max_allowed_encounters: 1,
@@ -20,6 +20,9 @@ fn solver6_config() -> SolverConfiguration {
             time_limit_seconds: Some(30),
             no_improvement_iterations: Some(100_000),
             stop_on_optimal_score: true,
+            stop_on_feasible: false,
+            feasible_score_threshold: None,
+            convergence: None,
         },
         solver_params: SolverParams::Solver6(Solver6Params::default()),
         logging: Default::default(),
@@ -45,15 +48,20 @@ fn pure_input(groups: usize, group_size: usize, weeks: usize) -> ApiInput {
                     id: format!("g{idx}"),
                     size: group_size as u32,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 })
                 .collect(),
             num_sessions: weeks as u32,
         },
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![Constraint::RepeatEncounter(RepeatEncounterParams {
             max_allowed_encounters: 1,
@@ -0,0 +1,160 @@
+//! Handle-based control of a solve running on a background thread.
+//!
+//! A [`SolverHandle`] owns the solver thread and steers it through its progress callback,
+//! which the search loop invokes roughly every 100 ms. Pausing blocks the search inside that
+//! callback, and stopping makes it return `false`, so requests take effect at the next
+//! progress update rather than immediately.
+
+use crate::models::{ApiInput, ApiSchedule, ProgressCallback, ProgressUpdate, SolverResult};
+use crate::run_solver_with_progress;
+use crate::solver_support::SolverError;
+use std::sync::{Arc, Condvar, Mutex, PoisonError};
+use std::thread::JoinHandle;
+
+#[derive(Default)]
+struct Control {
+    paused: bool,
+    stop_requested: bool,
+}
+
+#[derive(Default)]
+struct Shared {
+    control: Mutex<Control>,
+    control_changed: Condvar,
+    latest_progress: Mutex<Option<ProgressUpdate>>,
+    best_schedule: Mutex<Option<ApiSchedule>>,
+}
+
+impl Shared {
+    fn control(&self) -> std::sync::MutexGuard<'_, Control> {
+        self.control.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Records `update` and blocks while the run is paused. Returns whether to continue.
+    fn on_progress(&self, update: &ProgressUpdate) -> bool {
+        if let Some(schedule) = &update.best_schedule {
+            *self
+                .best_schedule
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner) = Some(schedule.clone());
+        }
+        *self
+            .latest_progress
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(update.clone());
+
+        let mut control = self.control();
+        while control.paused && !control.stop_requested {
+            control = self
+                .control_changed
+                .wait(control)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        !control.stop_requested
+    }
+}
+
+/// A solve running on a background thread that can be paused, inspected, and stopped.
+///
+/// With solver1, time spent paused is not charged to the run's `time_limit_seconds`; other
+/// solver families count it.
+///
+/// # Example
+///
+/// ```no_run
+/// use gm_core::solver_handle::SolverHandle;
+/// # use gm_core::models::ApiInput;
+/// # fn example(input: ApiInput) {
+/// let handle = SolverHandle::spawn(input);
+/// handle.pause();
+/// if let Some(schedule) = handle.best_schedule() {
+///     println!("best so far covers {} sessions", schedule.len());
+/// }
+/// handle.resume();
+/// let result = handle.stop().expect("solve should succeed");
+/// println!("final score: {}", result.final_score);
+/// # }
+/// ```
+pub struct SolverHandle {
+    shared: Arc<Shared>,
+    thread: JoinHandle<Result<SolverResult, SolverError>>,
+}
+
+impl SolverHandle {
+    /// Starts solving `input` on a new thread.
+    ///
+    /// `input.solver.telemetry` is used as given, so [`best_schedule`](Self::best_schedule)
+    /// only reports something when `emit_best_schedule` is set.
+    pub fn spawn(input: ApiInput) -> Self {
+        let shared = Arc::new(Shared::default());
+        let callback_shared = Arc::clone(&shared);
+        let thread = std::thread::spawn(move || {
+            let progress_callback: ProgressCallback =
+                Box::new(move |update| callback_shared.on_progress(update));
+            run_solver_with_progress(&input, Some(&progress_callback))
+        });
+
+        Self { shared, thread }
+    }
+
+    /// Pauses the search at its next progress update.
+    pub fn pause(&self) {
+        self.shared.control().paused = true;
+    }
+
+    /// Continues a paused search.
+    pub fn resume(&self) {
+        self.shared.control().paused = false;
+        self.shared.control_changed.notify_all();
+    }
+
+    /// Whether a pause has been requested and not lifted.
+    pub fn is_paused(&self) -> bool {
+        self.shared.control().paused
+    }
+
+    /// Whether the solver thread has finished.
+    pub fn is_finished(&self) -> bool {
+        self.thread.is_finished()
+    }
+
+    /// The most recent progress update, if any was sent yet.
+    pub fn latest_progress(&self) -> Option<ProgressUpdate> {
+        self.shared
+            .latest_progress
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// The most recent full best-schedule snapshot, for solver families that report snapshots
+    /// (solver1).
+    ///
+    /// Snapshots follow `telemetry.best_schedule_every_n_callbacks`; updates sent as a
+    /// `best_schedule_diff` are not applied, so leave `best_schedule_as_diff` off to keep this
+    /// current.
+    pub fn best_schedule(&self) -> Option<ApiSchedule> {
+        self.shared
+            .best_schedule
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Stops the search at its next progress update and returns the best result found.
+    pub fn stop(self) -> Result<SolverResult, SolverError> {
+        self.shared.control().stop_requested = true;
+        self.shared.control_changed.notify_all();
+        self.wait()
+    }
+
+    /// Waits for the search to finish on its own and returns its result.
+    ///
+    /// A paused search does not finish until it is resumed or stopped.
+    pub fn wait(self) -> Result<SolverResult, SolverError> {
+        match self.thread.join() {
+            Ok(result) => result,
+            Err(panic) => std::panic::resume_unwind(panic),
+        }
+    }
+}
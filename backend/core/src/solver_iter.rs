@@ -0,0 +1,136 @@
+//! Anytime iteration over the improvements of a solve running on a background thread.
+//!
+//! The solver thread turns every progress update that carries a better best schedule into an
+//! [`Improvement`] and sends it over a channel. Like [`SolverHandle`](crate::solver_handle::SolverHandle),
+//! it relies on the progress callback, so improvements arrive at most every 100 ms or so and
+//! a stop request takes effect at the next update.
+
+use crate::models::{ApiInput, Improvement, ProgressCallback, ProgressUpdate, SolverResult};
+use crate::run_solver_with_progress;
+use crate::solver_support::SolverError;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::JoinHandle;
+
+/// Iterator over the new best schedules of a background solve.
+///
+/// Iteration ends when the run finishes. Dropping the iterator stops the run at its next
+/// progress update; call [`finish`](Self::finish) instead to also get the final result.
+///
+/// # Example
+///
+/// ```no_run
+/// # use gm_core::models::ApiInput;
+/// # fn example(input: &ApiInput) {
+/// let mut improvements = gm_core::run_solver_iter(input);
+/// for improvement in improvements.by_ref().take(5) {
+///     println!("{:.2} at iteration {}", improvement.best_score, improvement.iteration);
+/// }
+/// // Good enough: stop the search and keep the best result.
+/// let result = improvements.finish().expect("solve should succeed");
+/// # }
+/// ```
+pub struct SolverIter {
+    receiver: Receiver<Improvement>,
+    stop_requested: Arc<AtomicBool>,
+    thread: Option<JoinHandle<Result<SolverResult, SolverError>>>,
+}
+
+impl SolverIter {
+    /// Starts solving `input` on a new thread.
+    ///
+    /// Full best-schedule snapshots are enabled on every progress update, so that each
+    /// improvement can carry its schedule.
+    pub fn spawn(input: &ApiInput) -> Self {
+        let mut input = input.clone();
+        input.solver.telemetry.emit_best_schedule = true;
+        input.solver.telemetry.best_schedule_every_n_callbacks = 1;
+        input.solver.telemetry.best_schedule_as_diff = false;
+
+        let (sender, receiver) = mpsc::channel();
+        let stop_requested = Arc::new(AtomicBool::new(false));
+        let callback_stop = Arc::clone(&stop_requested);
+        let thread = std::thread::spawn(move || {
+            let tracker = ImprovementTracker {
+                sender,
+                best_score: Mutex::new(None),
+            };
+            let progress_callback: ProgressCallback = Box::new(move |update| {
+                tracker.on_progress(update) && !callback_stop.load(Ordering::Relaxed)
+            });
+            run_solver_with_progress(&input, Some(&progress_callback))
+        });
+
+        Self {
+            receiver,
+            stop_requested,
+            thread: Some(thread),
+        }
+    }
+
+    /// Stops the search at its next progress update and returns the best result found.
+    ///
+    /// Improvements not consumed yet are discarded. If the run already finished, this only
+    /// returns its result.
+    pub fn finish(mut self) -> Result<SolverResult, SolverError> {
+        self.stop_requested.store(true, Ordering::Relaxed);
+        let thread = self
+            .thread
+            .take()
+            .expect("thread is only taken here or on drop");
+        match thread.join() {
+            Ok(result) => result,
+            Err(panic) => std::panic::resume_unwind(panic),
+        }
+    }
+}
+
+impl Iterator for SolverIter {
+    type Item = Improvement;
+
+    /// Blocks until the next improvement, or returns `None` once the run has finished.
+    fn next(&mut self) -> Option<Improvement> {
+        self.receiver.recv().ok()
+    }
+}
+
+impl Drop for SolverIter {
+    fn drop(&mut self) {
+        // The thread is left to wind down on its own; `finish` is the way to wait for it.
+        self.stop_requested.store(true, Ordering::Relaxed);
+    }
+}
+
+struct ImprovementTracker {
+    sender: Sender<Improvement>,
+    best_score: Mutex<Option<f64>>,
+}
+
+impl ImprovementTracker {
+    /// Sends `update` as an improvement if it reports a better best schedule. Returns
+    /// whether anyone is still listening.
+    fn on_progress(&self, update: &ProgressUpdate) -> bool {
+        let Some(schedule) = &update.best_schedule else {
+            return true;
+        };
+        let mut best_score = self
+            .best_score
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if best_score.is_some_and(|best| update.best_score >= best) {
+            return true;
+        }
+        *best_score = Some(update.best_score);
+
+        self.sender
+            .send(Improvement {
+                iteration: update.iteration,
+                elapsed_seconds: update.elapsed_seconds,
+                best_score: update.best_score,
+                unique_contacts: update.best_contacts,
+                schedule: schedule.clone(),
+            })
+            .is_ok()
+    }
+}
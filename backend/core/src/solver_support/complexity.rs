@@ -56,6 +56,10 @@ pub struct ConstraintComplexityBreakdown {
     pub should_stay_together_events: f64,
     pub should_not_be_together_events: f64,
     pub pair_meeting_count_events: f64,
+    pub baseline_move_limit_events: f64,
+    pub unique_group_composition_events: f64,
+    pub group_attribute_requirement_events: f64,
+    pub track_grouping_events: f64,
     pub total_events: f64,
     pub density: f64,
 }
@@ -214,6 +218,13 @@ impl<'a> ComplexityContext<'a> {
                     )));
                 }
             }
+            if let Some(available_sessions) = &group.available_sessions {
+                normalized_sessions(
+                    Some(available_sessions),
+                    session_count,
+                    &format!("group {} available_sessions", group.id),
+                )?;
+            }
         }
 
         let mut active_by_person_session = vec![vec![false; session_count]; people.len()];
@@ -424,6 +435,39 @@ impl<'a> ComplexityContext<'a> {
                     breakdown.pair_meeting_count_events +=
                         sessions.len() as f64 * 3.0 * soft_weight_factor(params.penalty_weight);
                 }
+                Constraint::BaselineMoveLimit(params) => {
+                    let sessions = normalized_sessions(
+                        params.sessions.as_ref(),
+                        self.session_count,
+                        "BaselineMoveLimit",
+                    )?;
+                    breakdown.baseline_move_limit_events +=
+                        sessions.len() as f64 * self.people.len() as f64 * 0.5;
+                }
+                Constraint::UniqueGroupComposition(params) => {
+                    let sessions = normalized_sessions(
+                        params.sessions.as_ref(),
+                        self.session_count,
+                        "UniqueGroupComposition",
+                    )?;
+                    breakdown.unique_group_composition_events += sessions.len() as f64
+                        * self.group_ids.len() as f64
+                        * soft_weight_factor(params.penalty_weight);
+                }
+                Constraint::GroupAttributeRequirement(params) => {
+                    self.ensure_people_exist(&params.people, "GroupAttributeRequirement")?;
+                    let sessions = normalized_sessions(
+                        params.sessions.as_ref(),
+                        self.session_count,
+                        "GroupAttributeRequirement",
+                    )?;
+                    breakdown.group_attribute_requirement_events +=
+                        sessions.len() as f64 * params.people.len() as f64 * 2.0;
+                }
+                Constraint::TrackGrouping(_) => {
+                    breakdown.track_grouping_events +=
+                        self.session_count as f64 * self.people.len() as f64;
+                }
             }
         }
 
@@ -435,7 +479,11 @@ impl<'a> ComplexityContext<'a> {
             + breakdown.must_stay_apart_events
             + breakdown.should_stay_together_events
             + breakdown.should_not_be_together_events
-            + breakdown.pair_meeting_count_events;
+            + breakdown.pair_meeting_count_events
+            + breakdown.baseline_move_limit_events
+            + breakdown.unique_group_composition_events
+            + breakdown.group_attribute_requirement_events
+            + breakdown.track_grouping_events;
 
         Ok(breakdown)
     }
@@ -582,12 +630,15 @@ fn estimated_session_pair_contact_slots(
 }
 
 fn group_capacity(group: &Group, session_idx: usize) -> Result<usize, SolverError> {
-    let capacity = if let Some(session_sizes) = &group.session_sizes {
-        session_sizes[session_idx]
-    } else {
-        group.size
-    };
-    Ok(capacity as usize)
+    group
+        .capacity_for_session(session_idx)
+        .map(|capacity| capacity as usize)
+        .ok_or_else(|| {
+            validation_error(format!(
+                "group {} has no session_sizes entry for session {session_idx}",
+                group.id
+            ))
+        })
 }
 
 fn normalized_sessions(
@@ -632,6 +683,10 @@ fn count_session_specific_constraints(constraints: &[Constraint]) -> usize {
             Constraint::ShouldNotBeTogether { sessions, .. } => sessions.is_some(),
             Constraint::ImmovablePeople(params) => params.sessions.is_some(),
             Constraint::PairMeetingCount(_) => true,
+            Constraint::BaselineMoveLimit(params) => params.sessions.is_some(),
+            Constraint::UniqueGroupComposition(params) => params.sessions.is_some(),
+            Constraint::GroupAttributeRequirement(params) => params.sessions.is_some(),
+            Constraint::TrackGrouping(_) => false,
         })
         .count()
 }
@@ -761,15 +816,20 @@ mod tests {
                         id: format!("g{idx}"),
                         size: group_size as u32,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     })
                     .collect(),
                 num_sessions: weeks as u32,
             },
             initial_schedule: None,
             construction_seed_schedule: None,
+            contact_history: None,
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".to_string(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: Vec::new(),
             solver: test_solver_configuration(),
@@ -844,6 +904,9 @@ mod tests {
                 time_limit_seconds: None,
                 no_improvement_iterations: None,
                 stop_on_optimal_score: false,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
                 initial_temperature: 1.0,
@@ -851,6 +914,7 @@ mod tests {
                 cooling_schedule: "geometric".to_string(),
                 reheat_cycles: None,
                 reheat_after_no_improvement: None,
+                penalty_ramp: None,
             }),
             logging: LoggingOptions::default(),
             telemetry: TelemetryOptions::default(),
@@ -36,6 +36,9 @@ pub(crate) struct BaselineConstructionContext<'a> {
     pub cliques: &'a [Vec<usize>],
     pub clique_sessions: &'a [Option<Vec<usize>>],
     pub hard_apart_partners_by_person_session: &'a [Vec<usize>],
+    /// Dense `[session * people + person] -> sorted allowed groups`; empty entries and an
+    /// empty table mean unrestricted.
+    pub allowed_groups_by_person_session: &'a [Vec<usize>],
     pub schedule: &'a mut Vec<Vec<Vec<usize>>>,
 }
 
@@ -60,6 +63,17 @@ impl BaselineConstructionContext<'_> {
         &self.hard_apart_partners_by_person_session[self.person_session_slot(day, person_idx)]
     }
 
+    #[inline]
+    fn group_allowed_for_person(&self, day: usize, person_idx: usize, group_idx: usize) -> bool {
+        group_allowed_for_person(
+            self.allowed_groups_by_person_session,
+            self.people_count(),
+            day,
+            person_idx,
+            group_idx,
+        )
+    }
+
     fn group_has_hard_apart_conflict(
         &self,
         day: usize,
@@ -97,6 +111,20 @@ fn hard_apart_partners<'a>(
     &hard_apart_partners_by_person_session[day * people_count + person_idx]
 }
 
+fn group_allowed_for_person(
+    allowed_groups_by_person_session: &[Vec<usize>],
+    people_count: usize,
+    day: usize,
+    person_idx: usize,
+    group_idx: usize,
+) -> bool {
+    if allowed_groups_by_person_session.is_empty() {
+        return true;
+    }
+    let allowed = &allowed_groups_by_person_session[day * people_count + person_idx];
+    allowed.is_empty() || allowed.binary_search(&group_idx).is_ok()
+}
+
 fn group_has_hard_apart_conflict(
     hard_apart_partners_by_person_session: &[Vec<usize>],
     people_count: usize,
@@ -194,6 +222,22 @@ fn validate_seeded_hard_constraints(
         }
     }
 
+    for (day, groups) in assigned_group_by_person.iter().enumerate() {
+        for (person_idx, group_idx) in groups.iter().enumerate() {
+            let Some(group_idx) = *group_idx else {
+                continue;
+            };
+            if !context.group_allowed_for_person(day, person_idx, group_idx) {
+                return Err(SolverError::ValidationError(format!(
+                    "construction seed places '{}' in group '{}' which does not satisfy their GroupAttributeRequirement in session {}",
+                    context.person_idx_to_id[person_idx],
+                    context.group_idx_to_id[group_idx],
+                    day
+                )));
+            }
+        }
+    }
+
     for (clique_idx, clique) in context.cliques.iter().enumerate() {
         for day in 0..num_sessions {
             if let Some(sessions) = &context.clique_sessions[clique_idx] {
@@ -264,6 +308,7 @@ pub(crate) fn apply_baseline_construction_heuristic(
     let people_count = context.people_count();
     let group_count = context.group_count();
     let hard_apart_partners_by_person_session = context.hard_apart_partners_by_person_session;
+    let allowed_groups_by_person_session = context.allowed_groups_by_person_session;
 
     // Preserve the legacy solver1 construction heuristic exactly.
     let mut rng = ChaCha12Rng::seed_from_u64(derive_phase_seed(
@@ -363,6 +408,15 @@ pub(crate) fn apply_baseline_construction_heuristic(
                 let available_space = group_size.saturating_sub(group_cursors[group_idx]);
 
                 if available_space >= missing_members
+                    && active_members.iter().all(|&member| {
+                        group_allowed_for_person(
+                            allowed_groups_by_person_session,
+                            people_count,
+                            day,
+                            member,
+                            group_idx,
+                        )
+                    })
                     && !block_has_hard_apart_conflict(
                         hard_apart_partners_by_person_session,
                         people_count,
@@ -409,24 +463,33 @@ pub(crate) fn apply_baseline_construction_heuristic(
             .filter(|&&person_idx| !assigned_in_day[person_idx])
             .cloned()
             .collect();
+        let is_restricted = |person_idx: usize| {
+            !allowed_groups_by_person_session.is_empty()
+                && !allowed_groups_by_person_session[day * people_count + person_idx].is_empty()
+        };
+        // People with group requirements go first so their allowed groups still have room.
         unassigned_people.sort_by(|&left, &right| {
-            hard_apart_partners(
-                hard_apart_partners_by_person_session,
-                people_count,
-                day,
-                right,
-            )
-            .len()
-            .cmp(
-                &hard_apart_partners(
-                    hard_apart_partners_by_person_session,
-                    people_count,
-                    day,
-                    left,
-                )
-                .len(),
-            )
-            .then_with(|| left.cmp(&right))
+            is_restricted(right)
+                .cmp(&is_restricted(left))
+                .then_with(|| {
+                    hard_apart_partners(
+                        hard_apart_partners_by_person_session,
+                        people_count,
+                        day,
+                        right,
+                    )
+                    .len()
+                    .cmp(
+                        &hard_apart_partners(
+                            hard_apart_partners_by_person_session,
+                            people_count,
+                            day,
+                            left,
+                        )
+                        .len(),
+                    )
+                })
+                .then_with(|| left.cmp(&right))
         });
 
         for person_idx in unassigned_people {
@@ -436,6 +499,13 @@ pub(crate) fn apply_baseline_construction_heuristic(
             for group_idx in potential_groups {
                 let group_size = context.effective_group_capacities[day * group_count + group_idx];
                 if group_cursors[group_idx] < group_size
+                    && group_allowed_for_person(
+                        allowed_groups_by_person_session,
+                        people_count,
+                        day,
+                        person_idx,
+                        group_idx,
+                    )
                     && !group_has_hard_apart_conflict(
                         hard_apart_partners_by_person_session,
                         people_count,
@@ -1321,6 +1391,7 @@ mod tests {
             cliques,
             clique_sessions,
             hard_apart_partners_by_person_session,
+            allowed_groups_by_person_session: &[],
             schedule,
         }
     }
@@ -89,6 +89,8 @@ fn build_solver6_oracle_input(
             id: oracle_group_id(idx),
             size: group_size,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         })
         .collect::<Vec<_>>();
 
@@ -100,9 +102,12 @@ fn build_solver6_oracle_input(
         },
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![Constraint::RepeatEncounter(RepeatEncounterParams {
             max_allowed_encounters: 1,
@@ -116,6 +121,9 @@ fn build_solver6_oracle_input(
                 time_limit_seconds: Some(1),
                 no_improvement_iterations: Some(100),
                 stop_on_optimal_score: true,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::Solver6(Solver6Params {
                 exact_construction_handoff_enabled: true,
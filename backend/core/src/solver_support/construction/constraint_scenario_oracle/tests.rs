@@ -24,6 +24,9 @@ fn solver3_config() -> SolverConfiguration {
             time_limit_seconds: None,
             no_improvement_iterations: None,
             stop_on_optimal_score: true,
+            stop_on_feasible: false,
+            feasible_score_threshold: None,
+            convergence: None,
         },
         solver_params: SolverParams::Solver3(Solver3Params::default()),
         logging: Default::default(),
@@ -49,15 +52,20 @@ fn hard_apart_merge_input() -> ApiInput {
                     id: format!("g{idx}"),
                     size: 3,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 })
                 .collect(),
             num_sessions: 1,
         },
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".into(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![
             Constraint::RepeatEncounter(RepeatEncounterParams {
@@ -0,0 +1,114 @@
+//! Upper bound on the unique contacts a schedule can reach, for reporting the optimality gap.
+
+use crate::models::ApiInput;
+use std::collections::BTreeMap;
+
+/// Returns a number of unique contacts no schedule of `input` can exceed.
+///
+/// Only group capacities and session attendance are considered; constraints and contact
+/// history are ignored, which keeps the bound valid but possibly out of reach. The bound is the
+/// smallest of three counts:
+/// - pairs of people who attend at least one session together;
+/// - per session, the most pairs its participants form when filling the largest groups first;
+/// - per person, the most new partners the largest group of each attended session allows.
+///
+/// A group whose `session_sizes` is too short for a session counts as closed there; solves
+/// reject such input before a bound is reported.
+pub fn unique_contacts_upper_bound(input: &ApiInput) -> u32 {
+    let num_sessions = input.problem.num_sessions as usize;
+
+    // People with the same attendance are interchangeable here, so count them per pattern
+    // instead of comparing every pair of people.
+    let mut patterns: BTreeMap<Vec<bool>, u64> = BTreeMap::new();
+    for person in &input.problem.people {
+        let mut attends = vec![person.sessions.is_none(); num_sessions];
+        for &session in person.sessions.iter().flatten() {
+            if let Some(slot) = attends.get_mut(session as usize) {
+                *slot = true;
+            }
+        }
+        *patterns.entry(attends).or_insert(0) += 1;
+    }
+    let patterns: Vec<(Vec<bool>, u64)> = patterns.into_iter().collect();
+    let co_attend = |a: &[bool], b: &[bool]| a.iter().zip(b).any(|(&in_a, &in_b)| in_a && in_b);
+
+    let participants: Vec<u64> = (0..num_sessions)
+        .map(|session| {
+            patterns
+                .iter()
+                .filter(|(attends, _)| attends[session])
+                .map(|(_, count)| count)
+                .sum()
+        })
+        .collect();
+    let capacities: Vec<Vec<u64>> = (0..num_sessions)
+        .map(|session| {
+            let mut capacities: Vec<u64> = input
+                .problem
+                .groups
+                .iter()
+                .map(|group| group.capacity_for_session(session).unwrap_or(0) as u64)
+                .collect();
+            capacities.sort_unstable_by(|a, b| b.cmp(a));
+            capacities
+        })
+        .collect();
+
+    let mut co_attending_pairs = 0;
+    for (idx, (attends, count)) in patterns.iter().enumerate() {
+        if co_attend(attends, attends) {
+            co_attending_pairs += pairs(*count);
+        }
+        for (other, other_count) in &patterns[idx + 1..] {
+            if co_attend(attends, other) {
+                co_attending_pairs += count * other_count;
+            }
+        }
+    }
+
+    let session_pairs: u64 = (0..num_sessions)
+        .map(|session| {
+            let mut remaining = participants[session];
+            let mut session_pairs = 0;
+            for &capacity in &capacities[session] {
+                let seated = capacity.min(remaining);
+                session_pairs += pairs(seated);
+                remaining -= seated;
+            }
+            session_pairs
+        })
+        .sum();
+
+    let mut partner_slots = 0;
+    for (attends, count) in &patterns {
+        let co_attendees: u64 = patterns
+            .iter()
+            .filter(|(other, _)| co_attend(attends, other))
+            .map(|(_, other_count)| other_count)
+            .sum();
+        let reachable: u64 = (0..num_sessions)
+            .filter(|&session| attends[session])
+            .map(|session| {
+                let largest = capacities[session].first().copied().unwrap_or(0);
+                largest.min(participants[session]).saturating_sub(1)
+            })
+            .sum();
+        partner_slots += count * co_attendees.saturating_sub(1).min(reachable);
+    }
+
+    let bound = co_attending_pairs.min(session_pairs).min(partner_slots / 2);
+    bound.min(u32::MAX as u64) as u32
+}
+
+/// Share of `bound` that `unique_contacts` falls short of, in `[0, 1]`.
+pub fn optimality_gap(unique_contacts: i32, bound: u32) -> f64 {
+    if bound == 0 {
+        return 0.0;
+    }
+    let missing = (bound as i64 - unique_contacts as i64).max(0);
+    missing as f64 / bound as f64
+}
+
+fn pairs(people: u64) -> u64 {
+    people * people.saturating_sub(1) / 2
+}
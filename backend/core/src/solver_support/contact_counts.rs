@@ -0,0 +1,34 @@
+//! Pairwise meeting counts of a finished schedule.
+
+use crate::models::{ApiSchedule, PairContactCount};
+use std::collections::BTreeMap;
+
+/// Counts, for every pair of people who shared a group in `schedule`, how many sessions
+/// they shared. Pairs are ordered by person ID, and each pair lists the smaller ID first.
+pub fn schedule_contact_counts(schedule: &ApiSchedule) -> Vec<PairContactCount> {
+    // BTreeMap keeps the pairs in a deterministic order.
+    let mut meetings: BTreeMap<(&str, &str), u32> = BTreeMap::new();
+    for groups in schedule.values() {
+        for members in groups.values() {
+            for (idx, left) in members.iter().enumerate() {
+                for right in &members[idx + 1..] {
+                    let pair = if left <= right {
+                        (left.as_str(), right.as_str())
+                    } else {
+                        (right.as_str(), left.as_str())
+                    };
+                    *meetings.entry(pair).or_insert(0) += 1;
+                }
+            }
+        }
+    }
+
+    meetings
+        .into_iter()
+        .map(|((person_a, person_b), count)| PairContactCount {
+            person_a: person_a.to_string(),
+            person_b: person_b.to_string(),
+            count,
+        })
+        .collect()
+}
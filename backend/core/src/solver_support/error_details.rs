@@ -0,0 +1,185 @@
+//! Machine-readable payloads for [`SolverError`], naming the input rows an error is about.
+
+use super::SolverError;
+use crate::models::{ApiInput, Constraint};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The people, groups, constraints, and sessions a [`SolverError`] involves.
+///
+/// Front ends use this to highlight the broken rows of an input instead of showing only the
+/// English message.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SolverErrorDetails {
+    /// Snake-case name of the error variant, e.g. `"unknown_person_id"`.
+    pub kind: String,
+    #[serde(default)]
+    pub person_ids: Vec<String>,
+    #[serde(default)]
+    pub group_ids: Vec<String>,
+    /// Positions in `constraints` of the constraints the error refers to.
+    #[serde(default)]
+    pub constraint_indices: Vec<usize>,
+    /// 0-based session indices.
+    #[serde(default)]
+    pub sessions: Vec<usize>,
+}
+
+impl SolverError {
+    /// Snake-case name of the variant, stable across message wording changes.
+    ///
+    /// [`InEvent`](SolverError::InEvent) reports the kind of the event's own error.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            SolverError::InEvent { source, .. } => source.kind(),
+            SolverError::ValidationError(_) => "validation_error",
+            SolverError::InsufficientCapacity { .. } => "insufficient_capacity",
+            SolverError::UnknownPersonId { .. } => "unknown_person_id",
+            SolverError::UnknownGroupId { .. } => "unknown_group_id",
+            SolverError::InvalidSession { .. } => "invalid_session",
+            SolverError::ConflictingConstraints { .. } => "conflicting_constraints",
+            SolverError::CliqueTooLarge { .. } => "clique_too_large",
+            SolverError::MemoryLimitExceeded { .. } => "memory_limit_exceeded",
+        }
+    }
+
+    /// Collects the entities of `input` this error refers to.
+    ///
+    /// Constraint indices are found by matching the constraint type and the ids in the error
+    /// against `input.constraints`, so `input` should be the one that produced the error; for
+    /// [`InEvent`](SolverError::InEvent) that is the failing event.
+    pub fn details(&self, input: &ApiInput) -> SolverErrorDetails {
+        if let SolverError::InEvent { source, .. } = self {
+            return source.details(input);
+        }
+        let mut details = SolverErrorDetails {
+            kind: self.kind().to_string(),
+            ..SolverErrorDetails::default()
+        };
+
+        match self {
+            SolverError::ValidationError(_)
+            | SolverError::MemoryLimitExceeded { .. }
+            | SolverError::InEvent { .. } => {}
+            SolverError::InsufficientCapacity { session, .. } => {
+                details.sessions.push(*session);
+            }
+            SolverError::UnknownPersonId {
+                person_id,
+                referenced_by,
+            } => {
+                details.person_ids.push(person_id.clone());
+                details.constraint_indices = constraint_indices(input, |constraint| {
+                    constraint.type_name() == referenced_by.as_str()
+                        && constraint_people(constraint).contains(person_id)
+                });
+            }
+            SolverError::UnknownGroupId {
+                group_id,
+                referenced_by,
+            } => {
+                details.group_ids.push(group_id.clone());
+                details.constraint_indices = constraint_indices(input, |constraint| {
+                    constraint.type_name() == referenced_by.as_str()
+                        && constraint_group(constraint) == Some(group_id.as_str())
+                });
+            }
+            SolverError::InvalidSession {
+                session,
+                referenced_by,
+                ..
+            } => {
+                details.sessions.push(*session);
+                details.constraint_indices = constraint_indices(input, |constraint| {
+                    constraint.type_name() == referenced_by.as_str()
+                        && constraint_sessions(constraint)
+                            .is_some_and(|sessions| sessions.contains(&(*session as u32)))
+                });
+            }
+            SolverError::ConflictingConstraints {
+                constraint,
+                conflicts_with,
+                people,
+                session,
+            } => {
+                details.person_ids = people.clone();
+                details.sessions.extend(*session);
+                details.constraint_indices = constraint_indices(input, |candidate| {
+                    let type_name = candidate.type_name();
+                    (type_name == constraint.as_str() || type_name == conflicts_with.as_str())
+                        && people
+                            .iter()
+                            .all(|person_id| constraint_people(candidate).contains(person_id))
+                });
+            }
+            SolverError::CliqueTooLarge {
+                people, session, ..
+            } => {
+                details.person_ids = people.clone();
+                details.sessions.push(*session);
+                details.constraint_indices = constraint_indices(input, |constraint| {
+                    matches!(constraint, Constraint::MustStayTogether { .. })
+                        && constraint_people(constraint)
+                            .iter()
+                            .any(|person_id| people.contains(person_id))
+                });
+            }
+        }
+
+        details
+    }
+}
+
+fn constraint_indices(input: &ApiInput, matches: impl Fn(&Constraint) -> bool) -> Vec<usize> {
+    input
+        .constraints
+        .iter()
+        .enumerate()
+        .filter(|(_, constraint)| matches(constraint))
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+pub(super) fn constraint_people(constraint: &Constraint) -> &[String] {
+    match constraint {
+        Constraint::ImmovablePerson(params) => std::slice::from_ref(&params.person_id),
+        Constraint::MustStayTogether { people, .. }
+        | Constraint::MustStayApart { people, .. }
+        | Constraint::ShouldStayTogether { people, .. }
+        | Constraint::ShouldNotBeTogether { people, .. } => people,
+        Constraint::ImmovablePeople(params) => &params.people,
+        Constraint::PairMeetingCount(params) => &params.people,
+        Constraint::GroupAttributeRequirement(params) => &params.people,
+        Constraint::RepeatEncounter(_)
+        | Constraint::AttributeBalance(_)
+        | Constraint::BaselineMoveLimit(_)
+        | Constraint::UniqueGroupComposition(_)
+        | Constraint::TrackGrouping(_) => &[],
+    }
+}
+
+fn constraint_group(constraint: &Constraint) -> Option<&str> {
+    match constraint {
+        Constraint::AttributeBalance(params) => Some(&params.group_id),
+        Constraint::ImmovablePerson(params) => Some(&params.group_id),
+        Constraint::ImmovablePeople(params) => Some(&params.group_id),
+        _ => None,
+    }
+}
+
+pub(super) fn constraint_sessions(constraint: &Constraint) -> Option<&[u32]> {
+    match constraint {
+        Constraint::AttributeBalance(params) => params.sessions.as_deref(),
+        Constraint::ImmovablePerson(params) => params.sessions.as_deref(),
+        Constraint::MustStayTogether { sessions, .. }
+        | Constraint::MustStayApart { sessions, .. }
+        | Constraint::ShouldStayTogether { sessions, .. }
+        | Constraint::ShouldNotBeTogether { sessions, .. } => sessions.as_deref(),
+        Constraint::ImmovablePeople(params) => params.sessions.as_deref(),
+        Constraint::PairMeetingCount(params) => Some(&params.sessions),
+        Constraint::BaselineMoveLimit(params) => params.sessions.as_deref(),
+        Constraint::UniqueGroupComposition(params) => params.sessions.as_deref(),
+        Constraint::GroupAttributeRequirement(params) => params.sessions.as_deref(),
+        Constraint::RepeatEncounter(_) | Constraint::TrackGrouping(_) => None,
+    }
+}
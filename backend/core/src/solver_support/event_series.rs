@@ -0,0 +1,130 @@
+//! Contact history bookkeeping for multi-event planning.
+//!
+//! `run_event_series` solves a sequence of events one after another. Between events the
+//! contacts of the finished schedule are folded into a running [`ContactHistory`], so each
+//! later event sees who already met (and how long ago) through its `contact_history`.
+//!
+//! The running history stays compact: entries for the same pair and age are merged, and
+//! pairs whose decayed weight has faded to nothing are dropped.
+
+use super::contact_counts::schedule_contact_counts;
+use crate::models::{ApiInput, ApiSchedule, ContactHistory, HistoricalContact};
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+
+/// Decayed pair weight below which a pair no longer affects a solve. solver1 snaps history
+/// weights to `1e-9` before using them, so anything under half of that reads as zero.
+const NEGLIGIBLE_HISTORY_WEIGHT: f64 = 0.5e-9;
+
+/// Builds the `contact_history` to solve `event` with, given the running series history.
+///
+/// Contacts involving people who do not attend `event` are left out here but stay in the
+/// running history for later events. The event's own `contact_history` is merged in, and
+/// its `decay_factor`, when given, takes precedence.
+pub fn contact_history_for_event(
+    series_history: &ContactHistory,
+    event: &ApiInput,
+) -> Option<ContactHistory> {
+    let own_history = event.contact_history.as_ref();
+    let attendees: HashSet<&str> = event
+        .problem
+        .people
+        .iter()
+        .map(|person| person.id.as_str())
+        .collect();
+
+    let mut contacts: Vec<HistoricalContact> = series_history
+        .contacts
+        .iter()
+        .filter(|contact| {
+            contact
+                .people
+                .iter()
+                .all(|person_id| attendees.contains(person_id.as_str()))
+        })
+        .cloned()
+        .collect();
+    if let Some(own_history) = own_history {
+        contacts.extend(own_history.contacts.iter().cloned());
+    }
+
+    if contacts.is_empty() && own_history.is_none() {
+        return None;
+    }
+    Some(ContactHistory {
+        contacts,
+        decay_factor: own_history.map_or(series_history.decay_factor, |own| own.decay_factor),
+    })
+}
+
+/// Returns the series history after an event produced `schedule`.
+///
+/// Existing entries, including the event's own `contact_history`, become one event older.
+/// Each pair that met in `schedule` is added with `events_ago: 1` and the number of sessions
+/// the pair shared. Entries for the same pair and age are then merged, and pairs whose summed
+/// weight under `decay_factor` is negligible are dropped, since aging only shrinks it further.
+pub fn advance_contact_history(
+    series_history: &ContactHistory,
+    event: &ApiInput,
+    schedule: &ApiSchedule,
+) -> ContactHistory {
+    let own_history = event.contact_history.as_ref();
+    let mut contacts: Vec<HistoricalContact> = series_history
+        .contacts
+        .iter()
+        .chain(own_history.into_iter().flat_map(|own| own.contacts.iter()))
+        .map(|contact| HistoricalContact {
+            people: contact.people.clone(),
+            count: contact.count,
+            events_ago: contact.events_ago.saturating_add(1),
+        })
+        .collect();
+
+    contacts.extend(
+        schedule_contact_counts(schedule)
+            .into_iter()
+            .map(|pair| HistoricalContact {
+                people: vec![pair.person_a, pair.person_b],
+                count: pair.count,
+                events_ago: 1,
+            }),
+    );
+
+    let decay_factor = own_history.map_or(series_history.decay_factor, |own| own.decay_factor);
+    ContactHistory {
+        contacts: compact_contacts(contacts, decay_factor),
+        decay_factor,
+    }
+}
+
+/// Merges entries with the same pair and age, keeping first-seen order, and drops pairs whose
+/// summed decayed weight is negligible.
+fn compact_contacts(contacts: Vec<HistoricalContact>, decay_factor: f64) -> Vec<HistoricalContact> {
+    let pair_key = |contact: &HistoricalContact| {
+        let mut pair = contact.people.clone();
+        pair.sort();
+        pair
+    };
+
+    let mut merged: Vec<HistoricalContact> = Vec::with_capacity(contacts.len());
+    let mut index: HashMap<(Vec<String>, u32), usize> = HashMap::new();
+    let mut pair_weights: HashMap<Vec<String>, f64> = HashMap::new();
+    for contact in contacts {
+        let pair = pair_key(&contact);
+        let age_weight = decay_factor.powi((contact.events_ago - 1).min(i32::MAX as u32) as i32);
+        *pair_weights.entry(pair.clone()).or_insert(0.0) += contact.count as f64 * age_weight;
+        match index.entry((pair, contact.events_ago)) {
+            Entry::Occupied(slot) => {
+                let existing = &mut merged[*slot.get()];
+                existing.count = existing.count.saturating_add(contact.count);
+            }
+            Entry::Vacant(slot) => {
+                slot.insert(merged.len());
+                merged.push(contact);
+            }
+        }
+    }
+
+    merged.retain(|contact| pair_weights[&pair_key(contact)] >= NEGLIGIBLE_HISTORY_WEIGHT);
+    merged
+}
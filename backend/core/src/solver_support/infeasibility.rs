@@ -0,0 +1,63 @@
+//! Likely conflict sets for inputs whose constraints solver1 cannot satisfy together.
+
+use super::SolverError;
+use crate::models::{ApiInput, InfeasibilityExplanation};
+use crate::solver1::State;
+
+/// Shrinks the constraints of an `input` that fails construction to a set that still fails.
+///
+/// Uses a deletion filter: each constraint is dropped in turn and stays dropped if the rest
+/// still fail to build a starting state. The oracle is solver1's randomized greedy
+/// construction rather than an exact feasibility check, so the result is a set of likely
+/// conflicting constraints: each one is needed for this construction to fail, but the set
+/// itself may be satisfiable.
+pub fn explain_infeasibility(input: &ApiInput) -> Option<InfeasibilityExplanation> {
+    let mut probe = input.clone();
+    // Construction is randomized; a fixed seed keeps the verdicts consistent between probes.
+    probe.solver.seed = Some(input.solver.seed.unwrap_or(0));
+
+    let error = State::new(&probe).err()?;
+
+    let mut kept: Vec<usize> = (0..input.constraints.len()).collect();
+    let mut last_error = error;
+    let mut candidate = 0;
+    while candidate < kept.len() {
+        let mut trial = kept.clone();
+        trial.remove(candidate);
+        match failure_with(&probe, &trial) {
+            Some(error) => {
+                kept = trial;
+                last_error = error;
+            }
+            None => candidate += 1,
+        }
+    }
+
+    let mut reduced = probe.clone();
+    reduced.constraints = kept
+        .iter()
+        .map(|&idx| input.constraints[idx].clone())
+        .collect();
+    let mut details = last_error.details(&reduced);
+    details.constraint_indices = details
+        .constraint_indices
+        .iter()
+        .map(|&idx| kept[idx])
+        .collect();
+
+    Some(InfeasibilityExplanation {
+        message: last_error.message(),
+        details,
+        constraints: reduced.constraints,
+        constraint_indices: kept,
+    })
+}
+
+fn failure_with(probe: &ApiInput, constraint_indices: &[usize]) -> Option<SolverError> {
+    let mut trial = probe.clone();
+    trial.constraints = constraint_indices
+        .iter()
+        .map(|&idx| probe.constraints[idx].clone())
+        .collect();
+    State::new(&trial).err()
+}
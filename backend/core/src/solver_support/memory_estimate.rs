@@ -0,0 +1,111 @@
+//! Working-memory estimate of a solve, checked before anything large is allocated.
+//!
+//! The per-pair tables grow with the square of the number of people, so a big enough problem
+//! asks for gigabytes at once. In the browser that aborts the tab instead of returning an
+//! error, and a 32-bit WASM heap cannot grow past 4 GiB anyway, so WebAssembly builds refuse
+//! solves whose estimate is above [`WASM_MEMORY_LIMIT_BYTES`] up front. Native builds have no
+//! such ceiling and leave the limit to the machine.
+
+use super::SolverError;
+use crate::models::{ApiInput, MemoryEstimate};
+use crate::solver1::{ContactMatrix, GroupMembers};
+
+/// Largest estimated working memory a solve is started with in WebAssembly builds (2 GiB).
+pub const WASM_MEMORY_LIMIT_BYTES: u64 = 2 * 1024 * 1024 * 1024;
+
+/// The limit solves are checked against on this target: [`WASM_MEMORY_LIMIT_BYTES`] on
+/// `wasm32`, none elsewhere.
+pub const MEMORY_LIMIT_BYTES: Option<u64> = if cfg!(target_arch = "wasm32") {
+    Some(WASM_MEMORY_LIMIT_BYTES)
+} else {
+    None
+};
+
+/// Estimate above which [`validate`](crate::validate) warns that a solve will be heavy (512 MiB).
+pub const MEMORY_WARNING_BYTES: u64 = 512 * 1024 * 1024;
+
+/// Solver states alive at the same time during a search: the caller's and the search copy.
+const STATE_COPIES: u64 = 2;
+
+/// Estimates the working memory of solving `input` from its people, groups, and sessions.
+pub fn estimate_memory(input: &ApiInput) -> MemoryEstimate {
+    let problem = &input.problem;
+    let people = problem.people.len() as u64;
+    let sessions = u64::from(problem.num_sessions);
+    let groups = problem.groups.len() as u64;
+
+    let max_historical_count = input.contact_history.as_ref().map_or(0, |history| {
+        history
+            .contacts
+            .iter()
+            .map(|contact| contact.count)
+            .max()
+            .unwrap_or(0)
+    });
+    let mut pair_table_bytes = ContactMatrix::estimated_bytes(
+        problem.people.len(),
+        problem.num_sessions.saturating_add(max_historical_count),
+    );
+    let session_weighted = input.objectives.iter().any(|objective| {
+        objective.r#type == "maximize_unique_contacts" && !objective.session_weights.is_empty()
+    });
+    if session_weighted {
+        pair_table_bytes += people * people * std::mem::size_of::<f64>() as u64;
+    }
+
+    let group_bytes = std::mem::size_of::<GroupMembers>() as u64;
+    let row_bytes = std::mem::size_of::<Vec<usize>>() as u64;
+    let schedule_bytes = sessions * (groups * group_bytes + row_bytes)
+        + sessions * (people * std::mem::size_of::<(usize, usize)>() as u64 + row_bytes)
+        + people * (sessions + row_bytes);
+
+    MemoryEstimate {
+        pair_table_bytes,
+        schedule_bytes,
+        total_bytes: (pair_table_bytes + schedule_bytes) * STATE_COPIES,
+        limit_bytes: MEMORY_LIMIT_BYTES,
+    }
+}
+
+/// Fails with [`SolverError::MemoryLimitExceeded`] when solving `input` would need more than
+/// [`MEMORY_LIMIT_BYTES`], if this target has a limit.
+pub fn check_memory_limit(input: &ApiInput) -> Result<(), SolverError> {
+    check_estimate(&estimate_memory(input))
+}
+
+fn check_estimate(estimate: &MemoryEstimate) -> Result<(), SolverError> {
+    match estimate.limit_bytes {
+        Some(limit_bytes) if estimate.total_bytes > limit_bytes => {
+            Err(SolverError::MemoryLimitExceeded {
+                estimated_bytes: estimate.total_bytes,
+                limit_bytes,
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimates_above_the_limit_are_refused() {
+        let estimate = |total_bytes, limit_bytes| MemoryEstimate {
+            pair_table_bytes: 0,
+            schedule_bytes: 0,
+            total_bytes,
+            limit_bytes,
+        };
+
+        assert!(check_estimate(&estimate(10, Some(10))).is_ok());
+        assert!(check_estimate(&estimate(u64::MAX, None)).is_ok());
+        assert!(matches!(
+            check_estimate(&estimate(11, Some(10))),
+            Err(SolverError::MemoryLimitExceeded {
+                estimated_bytes: 11,
+                limit_bytes: 10,
+            })
+        ));
+    }
+}
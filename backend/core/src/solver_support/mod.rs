@@ -1,15 +1,117 @@
 pub mod complexity;
 pub(crate) mod constraint_presolve;
 pub(crate) mod construction;
+pub mod contact_bound;
+pub mod contact_counts;
+pub mod error_details;
+pub mod event_series;
+pub mod infeasibility;
+pub mod memory_estimate;
+pub mod move_evaluation;
+pub mod people_changes;
+pub mod problem_stats;
+pub mod schedule_diff;
 pub mod validation;
+pub mod validation_report;
+pub mod warm_start_repair;
 
 use serde::Serialize;
 use thiserror::Error;
 
 /// Errors that can occur across solver-family selection, validation, and execution.
-#[derive(Error, Debug, Serialize)]
+///
+/// The typed variants cover input problems that callers commonly want to handle, such as
+/// pointing an editor at the offending id. Everything else is reported as a
+/// [`ValidationError`](Self::ValidationError) with a descriptive message.
+#[derive(Error, Debug, Clone, PartialEq, Serialize)]
 pub enum SolverError {
     /// A constraint validation error with descriptive message.
     #[error("Constraint violation: {0}")]
     ValidationError(String),
+    /// More people attend `session` than its open groups can hold.
+    #[error(
+        "Constraint violation: Not enough group capacity in session {session}. People: {people}, Capacity: {capacity}"
+    )]
+    InsufficientCapacity {
+        session: usize,
+        people: usize,
+        capacity: usize,
+    },
+    /// A constraint, schedule, or weight refers to a person id that is not in the problem.
+    #[error("Constraint violation: {referenced_by} references unknown person '{person_id}'")]
+    UnknownPersonId {
+        person_id: String,
+        /// What holds the reference, e.g. `"MustStayApart"` or `"contact_history"`.
+        referenced_by: String,
+    },
+    /// A constraint or schedule refers to a group id that is not in the problem.
+    #[error("Constraint violation: {referenced_by} references unknown group '{group_id}'")]
+    UnknownGroupId {
+        group_id: String,
+        /// What holds the reference, e.g. `"ImmovablePerson"`.
+        referenced_by: String,
+    },
+    /// A 0-based session index is not below `num_sessions`.
+    #[error(
+        "Constraint violation: {referenced_by} references invalid session {session} (max: {})",
+        num_sessions.saturating_sub(1)
+    )]
+    InvalidSession {
+        session: usize,
+        num_sessions: usize,
+        referenced_by: String,
+    },
+    /// Two hard or soft constraints ask for incompatible things for the same people.
+    #[error(
+        "Constraint violation: {constraint} conflicts with {conflicts_with}{}: people {people:?}",
+        session.map(|session| format!(" in session {session}")).unwrap_or_default()
+    )]
+    ConflictingConstraints {
+        constraint: String,
+        conflicts_with: String,
+        people: Vec<String>,
+        /// The session the conflict shows up in, if it is limited to one.
+        session: Option<usize>,
+    },
+    /// A `MustStayTogether` clique has more attending members than the largest group of a session.
+    #[error(
+        "Constraint violation: MustStayTogether clique {people:?} of size {size} cannot fit in any group for session {session} (largest group: {max_group_size})"
+    )]
+    CliqueTooLarge {
+        people: Vec<String>,
+        size: usize,
+        max_group_size: usize,
+        session: usize,
+    },
+    /// The problem would need more working memory than a solve is allowed to allocate.
+    #[error(
+        "Problem too large: a solve needs an estimated {} MiB of memory, above the {} MiB limit; reduce the number of people",
+        estimated_bytes / (1024 * 1024),
+        limit_bytes / (1024 * 1024)
+    )]
+    MemoryLimitExceeded {
+        estimated_bytes: u64,
+        limit_bytes: u64,
+    },
+    /// Event `event` (0-based) of [`run_event_series`](crate::run_event_series) failed with
+    /// `source`.
+    #[error("event {event}: {source}")]
+    InEvent {
+        event: usize,
+        source: Box<SolverError>,
+    },
+}
+
+impl SolverError {
+    /// The error description without the leading `"Constraint violation: "`.
+    pub fn message(&self) -> String {
+        if let SolverError::InEvent { event, source } = self {
+            return format!("event {event}: {}", source.message());
+        }
+        let message = self.to_string();
+        match message.strip_prefix("Constraint violation: ") {
+            Some(stripped) => stripped.to_string(),
+            None => message,
+        }
+    }
 }
@@ -0,0 +1,93 @@
+//! What-if scoring of manual edits, for previewing a drag-and-drop swap before applying it.
+
+use super::validation::validate_schedule_as_incumbent;
+use super::SolverError;
+use crate::engines::default_solver_configuration_for;
+use crate::models::{ApiInput, ApiSchedule, ScoreDelta, SolverKind};
+use crate::solver1::{group_members_schedule, State};
+
+/// Scores swapping `person_a` and `person_b` in `session` of `schedule`.
+///
+/// Both schedules are scored from scratch by the solver1 cost model, so the delta matches what
+/// the solver would see. `schedule` must be complete and satisfy the hard constraints; moves
+/// are measured against `input.initial_schedule` when `BaselineMoveLimit` is used, and against
+/// `schedule` itself when `input` has none.
+pub fn evaluate_move(
+    input: &ApiInput,
+    schedule: &ApiSchedule,
+    session: usize,
+    person_a: &str,
+    person_b: &str,
+) -> Result<ScoreDelta, SolverError> {
+    let mut probe = input.clone();
+    probe.initial_schedule = Some(
+        input
+            .initial_schedule
+            .clone()
+            .unwrap_or_else(|| schedule.clone()),
+    );
+    probe.construction_seed_schedule = None;
+    let mut solver = default_solver_configuration_for(SolverKind::Solver1);
+    solver.seed = input.solver.seed;
+    probe.solver = solver;
+
+    let mut before = State::new(&probe)?;
+    before.schedule =
+        group_members_schedule(validate_schedule_as_incumbent(&probe, schedule)?.schedule);
+    before._recalculate_locations_from_schedule();
+    before._recalculate_scores();
+
+    let num_sessions = before.num_sessions as usize;
+    if session >= num_sessions {
+        return Err(SolverError::InvalidSession {
+            session,
+            num_sessions,
+            referenced_by: "evaluate_move".to_string(),
+        });
+    }
+    let resolve = |person_id: &str| {
+        let person_idx = before
+            .person_id_to_idx
+            .get(person_id)
+            .copied()
+            .ok_or_else(|| SolverError::UnknownPersonId {
+                person_id: person_id.to_string(),
+                referenced_by: "evaluate_move".to_string(),
+            })?;
+        if !before.person_participation[person_idx][session] {
+            return Err(SolverError::ValidationError(format!(
+                "person '{}' does not attend session {}",
+                person_id, session
+            )));
+        }
+        Ok(person_idx)
+    };
+    let a_idx = resolve(person_a)?;
+    let b_idx = resolve(person_b)?;
+
+    let (a_group, a_pos) = before.locations[session][a_idx];
+    let (b_group, b_pos) = before.locations[session][b_idx];
+    let feasible = before
+        .calculate_swap_cost_delta(session, a_idx, b_idx)
+        .is_finite();
+
+    let mut after = before.clone();
+    after.schedule[session][a_group][a_pos] = b_idx;
+    after.schedule[session][b_group][b_pos] = a_idx;
+    after._recalculate_locations_from_schedule();
+    after._recalculate_scores();
+
+    Ok(ScoreDelta {
+        feasible,
+        cost_delta: after.current_cost - before.current_cost,
+        unique_contacts_delta: after.unique_contacts - before.unique_contacts,
+        repetition_penalty_delta: after.repetition_penalty - before.repetition_penalty,
+        attribute_balance_penalty_delta: after.attribute_balance_penalty
+            - before.attribute_balance_penalty,
+        constraint_penalty_delta: after.constraint_penalty - before.constraint_penalty,
+        weighted_repetition_penalty_delta: after.weighted_repetition_penalty()
+            - before.weighted_repetition_penalty(),
+        weighted_constraint_penalty_delta: after.weighted_constraint_penalty
+            - before.weighted_constraint_penalty,
+    })
+}
@@ -0,0 +1,170 @@
+//! Re-solving after late registrations and cancellations without reshuffling everyone else.
+
+use crate::models::{
+    ApiInput, ApiSchedule, Constraint, ImmovablePersonParams, PeopleChangeOptions, Person,
+};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Builds the input that re-solves `previous_schedule` for the revised `people` list.
+///
+/// The previous schedule, minus removed people and sessions people no longer attend, becomes
+/// the `construction_seed_schedule`, so only added people and newly attended sessions need
+/// seats. With `keep_unaffected_fixed`, every seeded assignment is pinned with an
+/// `ImmovablePerson` constraint and the search is limited to the sessions that changed.
+///
+/// Removed people are also dropped from constraints and `contact_history`; constraints left
+/// without enough people are removed. `initial_schedule` and `BaselineMoveLimit` constraints
+/// are removed because the previous baseline no longer matches the people list.
+pub fn people_change_input(
+    input: &ApiInput,
+    previous_schedule: &ApiSchedule,
+    people: &[Person],
+    options: &PeopleChangeOptions,
+) -> ApiInput {
+    let num_sessions = input.problem.num_sessions;
+    let people_by_id: HashMap<&str, &Person> = people
+        .iter()
+        .map(|person| (person.id.as_str(), person))
+        .collect();
+    let attends = |person_id: &str, session: u32| {
+        people_by_id.get(person_id).is_some_and(|person| {
+            person
+                .sessions
+                .as_ref()
+                .is_none_or(|sessions| sessions.contains(&session))
+        })
+    };
+
+    let mut seed = ApiSchedule::new();
+    let mut changed_sessions = BTreeSet::new();
+    // (person, group) -> sessions the person keeps that group in
+    let mut kept: BTreeMap<(&str, &str), Vec<u32>> = BTreeMap::new();
+    for session in 0..num_sessions {
+        let session_key = format!("session_{session}");
+        let mut seated = BTreeSet::new();
+        let mut session_groups = HashMap::new();
+        for (group_id, members) in previous_schedule.get(&session_key).into_iter().flatten() {
+            let (staying, leaving): (Vec<&String>, Vec<&String>) = members
+                .iter()
+                .partition(|person_id| attends(person_id, session));
+            if !leaving.is_empty() {
+                changed_sessions.insert(session);
+            }
+            for &person_id in &staying {
+                seated.insert(person_id.as_str());
+                kept.entry((person_id.as_str(), group_id.as_str()))
+                    .or_default()
+                    .push(session);
+            }
+            session_groups.insert(
+                group_id.clone(),
+                staying.into_iter().cloned().collect::<Vec<_>>(),
+            );
+        }
+        if people
+            .iter()
+            .any(|person| attends(&person.id, session) && !seated.contains(person.id.as_str()))
+        {
+            changed_sessions.insert(session);
+        }
+        seed.insert(session_key, session_groups);
+    }
+
+    let mut revised = input.clone();
+    revised.problem.people = people.to_vec();
+    revised.initial_schedule = None;
+    revised.construction_seed_schedule = Some(seed);
+    revised.constraints = input
+        .constraints
+        .iter()
+        .filter_map(|constraint| retain_people(constraint, &people_by_id))
+        .collect();
+    if let Some(history) = &mut revised.contact_history {
+        history.contacts.retain(|contact| {
+            contact
+                .people
+                .iter()
+                .all(|person_id| people_by_id.contains_key(person_id.as_str()))
+        });
+    }
+
+    if options.keep_unaffected_fixed {
+        revised
+            .constraints
+            .extend(kept.into_iter().map(|((person_id, group_id), sessions)| {
+                Constraint::ImmovablePerson(ImmovablePersonParams {
+                    person_id: person_id.to_string(),
+                    group_id: group_id.to_string(),
+                    sessions: Some(sessions),
+                })
+            }));
+        let allowed: Vec<u32> = changed_sessions
+            .into_iter()
+            .filter(|session| {
+                input
+                    .solver
+                    .allowed_sessions
+                    .as_ref()
+                    .is_none_or(|allowed| allowed.contains(session))
+            })
+            .collect();
+        if !allowed.is_empty() {
+            revised.solver.allowed_sessions = Some(allowed);
+        }
+    }
+
+    revised
+}
+
+/// Returns `constraint` without the people missing from `people_by_id`, or `None` when it no
+/// longer applies to anyone.
+fn retain_people(
+    constraint: &Constraint,
+    people_by_id: &HashMap<&str, &Person>,
+) -> Option<Constraint> {
+    let present = |person_id: &String| people_by_id.contains_key(person_id.as_str());
+    let retain = |people: &[String]| -> Vec<String> {
+        people.iter().filter(|id| present(id)).cloned().collect()
+    };
+
+    let mut constraint = constraint.clone();
+    match &mut constraint {
+        Constraint::MustStayTogether { people, .. }
+        | Constraint::MustStayApart { people, .. }
+        | Constraint::ShouldStayTogether { people, .. }
+        | Constraint::ShouldNotBeTogether { people, .. } => {
+            *people = retain(people);
+            if people.len() < 2 {
+                return None;
+            }
+        }
+        Constraint::ImmovablePerson(params) => {
+            if !present(&params.person_id) {
+                return None;
+            }
+        }
+        Constraint::ImmovablePeople(params) => {
+            params.people = retain(&params.people);
+            if params.people.is_empty() {
+                return None;
+            }
+        }
+        Constraint::GroupAttributeRequirement(params) => {
+            params.people = retain(&params.people);
+            if params.people.is_empty() {
+                return None;
+            }
+        }
+        Constraint::PairMeetingCount(params) => {
+            if !params.people.iter().all(present) {
+                return None;
+            }
+        }
+        Constraint::BaselineMoveLimit(_) => return None,
+        Constraint::RepeatEncounter(_)
+        | Constraint::AttributeBalance(_)
+        | Constraint::UniqueGroupComposition(_)
+        | Constraint::TrackGrouping(_) => {}
+    }
+    Some(constraint)
+}
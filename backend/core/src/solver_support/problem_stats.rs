@@ -0,0 +1,96 @@
+//! Size and tightness figures of a problem, computed without solving it.
+
+use super::contact_bound::unique_contacts_upper_bound;
+use crate::models::{ApiInput, Constraint, ProblemStats, SessionCapacity};
+use std::collections::{BTreeMap, HashMap};
+
+/// Counts people, seats, cliques, and constraints of `input`.
+///
+/// Seats a too-short `session_sizes` leaves undefined are not counted.
+pub fn problem_stats(input: &ApiInput) -> ProblemStats {
+    let problem = &input.problem;
+    let people = problem.people.len() as u64;
+
+    let capacity_slack = (0..problem.num_sessions)
+        .map(|session| {
+            let attendees = problem
+                .people
+                .iter()
+                .filter(|person| {
+                    person
+                        .sessions
+                        .as_ref()
+                        .is_none_or(|sessions| sessions.contains(&session))
+                })
+                .count() as u32;
+            let capacity = problem
+                .groups
+                .iter()
+                .filter_map(|group| group.capacity_for_session(session as usize))
+                .sum::<u32>();
+            SessionCapacity {
+                session,
+                attendees,
+                capacity,
+                slack: i64::from(capacity) - i64::from(attendees),
+            }
+        })
+        .collect();
+
+    let mut constraint_counts = BTreeMap::new();
+    for constraint in &input.constraints {
+        *constraint_counts
+            .entry(constraint.type_name().to_string())
+            .or_insert(0) += 1;
+    }
+
+    ProblemStats {
+        people: people as u32,
+        groups: problem.groups.len() as u32,
+        num_sessions: problem.num_sessions,
+        total_pairs: people * people.saturating_sub(1) / 2,
+        max_possible_contacts: unique_contacts_upper_bound(input),
+        capacity_slack,
+        clique_sizes: clique_sizes(&input.constraints),
+        constraint_counts,
+    }
+}
+
+/// Sizes of the connected components formed by `MustStayTogether` people lists.
+fn clique_sizes(constraints: &[Constraint]) -> Vec<u32> {
+    fn root(parent: &mut HashMap<String, String>, person: &str) -> String {
+        let next = parent
+            .entry(person.to_string())
+            .or_insert_with(|| person.to_string())
+            .clone();
+        if next == person {
+            return next;
+        }
+        let root_id = root(parent, &next);
+        parent.insert(person.to_string(), root_id.clone());
+        root_id
+    }
+
+    let mut parent = HashMap::new();
+    for constraint in constraints {
+        if let Constraint::MustStayTogether { people, .. } = constraint {
+            let Some((first, rest)) = people.split_first() else {
+                continue;
+            };
+            let first_root = root(&mut parent, first);
+            for person in rest {
+                let person_root = root(&mut parent, person);
+                parent.insert(person_root, first_root.clone());
+            }
+        }
+    }
+
+    let mut sizes: HashMap<String, u32> = HashMap::new();
+    let people: Vec<String> = parent.keys().cloned().collect();
+    for person in people {
+        *sizes.entry(root(&mut parent, &person)).or_insert(0) += 1;
+    }
+    let mut sizes: Vec<u32> = sizes.into_values().filter(|&size| size > 1).collect();
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    sizes
+}
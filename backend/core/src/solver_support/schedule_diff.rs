@@ -0,0 +1,92 @@
+//! Comparison of two schedules for the same problem.
+
+use crate::models::{ApiSchedule, PersonMove, ScheduleDiff, SessionDiff};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Compares `before` and `after` session by session.
+///
+/// A person counts as moved when their group differs between the two schedules, including
+/// being assigned in only one of them.
+pub fn diff_schedules(before: &ApiSchedule, after: &ApiSchedule) -> ScheduleDiff {
+    let mut session_keys: Vec<&String> = before
+        .keys()
+        .chain(after.keys())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    session_keys.sort_by_key(|key| (session_number(key), key.as_str()));
+
+    let mut total_moves = 0u32;
+    let mut total_assigned = 0u32;
+    let sessions = session_keys
+        .into_iter()
+        .map(|session_key| {
+            let old_groups = group_by_person(before.get(session_key));
+            let new_groups = group_by_person(after.get(session_key));
+            let people: BTreeSet<&str> = old_groups
+                .keys()
+                .chain(new_groups.keys())
+                .copied()
+                .collect();
+
+            let moves: Vec<PersonMove> = people
+                .iter()
+                .filter_map(|&person_id| {
+                    let from_group = old_groups.get(person_id).copied();
+                    let to_group = new_groups.get(person_id).copied();
+                    (from_group != to_group).then(|| PersonMove {
+                        person_id: person_id.to_string(),
+                        from_group: from_group.map(str::to_string),
+                        to_group: to_group.map(str::to_string),
+                    })
+                })
+                .collect();
+
+            let people_moved = moves.len() as u32;
+            let assigned = people.len() as u32;
+            total_moves += people_moved;
+            total_assigned += assigned;
+            SessionDiff {
+                session: session_key.clone(),
+                moves,
+                people_moved,
+                churn_rate: churn_rate(people_moved, assigned),
+            }
+        })
+        .collect();
+
+    ScheduleDiff {
+        sessions,
+        total_moves,
+        churn_rate: churn_rate(total_moves, total_assigned),
+    }
+}
+
+/// Numeric suffix of a `session_N` key, so that `session_10` sorts after `session_9`.
+fn session_number(session_key: &str) -> usize {
+    session_key
+        .split('_')
+        .next_back()
+        .and_then(|suffix| suffix.parse().ok())
+        .unwrap_or(0)
+}
+
+fn group_by_person(groups: Option<&HashMap<String, Vec<String>>>) -> BTreeMap<&str, &str> {
+    groups
+        .into_iter()
+        .flatten()
+        .flat_map(|(group_id, members)| {
+            members
+                .iter()
+                .map(move |person_id| (person_id.as_str(), group_id.as_str()))
+        })
+        .collect()
+}
+
+fn churn_rate(moved: u32, assigned: u32) -> f64 {
+    if assigned == 0 {
+        0.0
+    } else {
+        moved as f64 / assigned as f64
+    }
+}
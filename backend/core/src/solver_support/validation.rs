@@ -105,15 +105,22 @@ fn validate_schedule(
             continue;
         };
 
-        if require_complete && group_map.len() != num_groups {
+        // Groups closed in this session may be omitted; every open group must be listed.
+        let open_groups = (0..num_groups)
+            .filter(|&group_idx| {
+                effective_group_capacities[session_idx * num_groups + group_idx] > 0
+            })
+            .count();
+        if require_complete && group_map.len() < open_groups {
             return Err(SolverError::ValidationError(format!(
                 "warm start session '{}' must define all {} groups explicitly",
-                session_key, num_groups
+                session_key, open_groups
             )));
         }
 
-        for group in &input.problem.groups {
-            if require_complete && !group_map.contains_key(&group.id) {
+        for (group_idx, group) in input.problem.groups.iter().enumerate() {
+            let is_open = effective_group_capacities[session_idx * num_groups + group_idx] > 0;
+            if require_complete && is_open && !group_map.contains_key(&group.id) {
                 return Err(SolverError::ValidationError(format!(
                     "warm start session '{}' is missing required group '{}'",
                     session_key, group.id
@@ -123,10 +130,10 @@ fn validate_schedule(
 
         for (group_id, people_ids) in group_map {
             let Some(&group_idx) = group_id_to_idx.get(group_id) else {
-                return Err(SolverError::ValidationError(format!(
-                    "schedule references unknown group '{}' in {}",
-                    group_id, session_key
-                )));
+                return Err(SolverError::UnknownGroupId {
+                    group_id: group_id.clone(),
+                    referenced_by: format!("schedule for {session_key}"),
+                });
             };
             let capacity = effective_group_capacities[session_idx * num_groups + group_idx];
             if people_ids.len() > capacity {
@@ -138,10 +145,10 @@ fn validate_schedule(
 
             for person_id in people_ids {
                 let Some(&person_idx) = person_id_to_idx.get(person_id) else {
-                    return Err(SolverError::ValidationError(format!(
-                        "schedule references unknown person '{}' in {}",
-                        person_id, session_key
-                    )));
+                    return Err(SolverError::UnknownPersonId {
+                        person_id: person_id.clone(),
+                        referenced_by: format!("schedule for {session_key}"),
+                    });
                 };
                 if !person_participation[person_idx][session_idx] {
                     return Err(SolverError::ValidationError(format!(
@@ -189,7 +196,7 @@ fn validate_schedule(
     Ok(IndexedScheduleValidation { schedule: compiled })
 }
 
-fn build_person_participation(input: &ApiInput) -> Result<Vec<Vec<bool>>, SolverError> {
+pub(crate) fn build_person_participation(input: &ApiInput) -> Result<Vec<Vec<bool>>, SolverError> {
     let people_count = input.problem.people.len();
     let num_sessions = input.problem.num_sessions as usize;
     let mut person_participation = vec![vec![false; num_sessions]; people_count];
@@ -199,12 +206,11 @@ fn build_person_participation(input: &ApiInput) -> Result<Vec<Vec<bool>>, Solver
             for &session in sessions {
                 let session_idx = session as usize;
                 if session_idx >= num_sessions {
-                    return Err(SolverError::ValidationError(format!(
-                        "person '{}' has invalid session index: {} (max: {})",
-                        person.id,
-                        session,
-                        num_sessions.saturating_sub(1)
-                    )));
+                    return Err(SolverError::InvalidSession {
+                        session: session_idx,
+                        num_sessions,
+                        referenced_by: format!("person '{}'", person.id),
+                    });
                 }
                 person_participation[person_idx][session_idx] = true;
             }
@@ -218,11 +224,14 @@ fn build_person_participation(input: &ApiInput) -> Result<Vec<Vec<bool>>, Solver
     Ok(person_participation)
 }
 
-fn build_effective_group_capacities(input: &ApiInput) -> Result<Vec<usize>, SolverError> {
+pub(crate) fn build_effective_group_capacities(
+    input: &ApiInput,
+) -> Result<Vec<usize>, SolverError> {
     let num_sessions = input.problem.num_sessions as usize;
     let num_groups = input.problem.groups.len();
     let mut capacities = vec![0usize; num_sessions * num_groups];
 
+    validate_available_sessions(input)?;
     for (group_idx, group) in input.problem.groups.iter().enumerate() {
         if let Some(session_sizes) = &group.session_sizes {
             if session_sizes.len() != num_sessions {
@@ -235,17 +244,31 @@ fn build_effective_group_capacities(input: &ApiInput) -> Result<Vec<usize>, Solv
             }
         }
         for session_idx in 0..num_sessions {
-            capacities[session_idx * num_groups + group_idx] = group
-                .session_sizes
-                .as_ref()
-                .map(|sizes| sizes[session_idx] as usize)
-                .unwrap_or(group.size as usize);
+            capacities[session_idx * num_groups + group_idx] =
+                group.capacity_for_session(session_idx).unwrap_or(0) as usize;
         }
     }
 
     Ok(capacities)
 }
 
+/// Rejects `available_sessions` entries outside the problem's sessions.
+pub(crate) fn validate_available_sessions(input: &ApiInput) -> Result<(), SolverError> {
+    let num_sessions = input.problem.num_sessions as usize;
+    for group in &input.problem.groups {
+        for &session in group.available_sessions.iter().flatten() {
+            if session as usize >= num_sessions {
+                return Err(SolverError::InvalidSession {
+                    session: session as usize,
+                    num_sessions,
+                    referenced_by: format!("available_sessions of group '{}'", group.id),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone)]
 struct CompiledClique {
     members: Vec<usize>,
@@ -279,16 +302,16 @@ fn compile_cliques(
                 }
                 for window in people.windows(2) {
                     let a = *person_id_to_idx.get(&window[0]).ok_or_else(|| {
-                        SolverError::ValidationError(format!(
-                            "MustStayTogether references unknown person '{}'",
-                            window[0]
-                        ))
+                        SolverError::UnknownPersonId {
+                            person_id: window[0].to_string(),
+                            referenced_by: "MustStayTogether".to_string(),
+                        }
                     })?;
                     let b = *person_id_to_idx.get(&window[1]).ok_or_else(|| {
-                        SolverError::ValidationError(format!(
-                            "MustStayTogether references unknown person '{}'",
-                            window[1]
-                        ))
+                        SolverError::UnknownPersonId {
+                            person_id: window[1].to_string(),
+                            referenced_by: "MustStayTogether".to_string(),
+                        }
                     })?;
                     dsu.union(a, b);
                 }
@@ -343,16 +366,16 @@ fn compile_hard_apart_pairs(
             for left_idx in 0..people.len() {
                 for right_idx in (left_idx + 1)..people.len() {
                     let &left = person_id_to_idx.get(&people[left_idx]).ok_or_else(|| {
-                        SolverError::ValidationError(format!(
-                            "MustStayApart references unknown person '{}'",
-                            people[left_idx]
-                        ))
+                        SolverError::UnknownPersonId {
+                            person_id: people[left_idx].to_string(),
+                            referenced_by: "MustStayApart".to_string(),
+                        }
                     })?;
                     let &right = person_id_to_idx.get(&people[right_idx]).ok_or_else(|| {
-                        SolverError::ValidationError(format!(
-                            "MustStayApart references unknown person '{}'",
-                            people[right_idx]
-                        ))
+                        SolverError::UnknownPersonId {
+                            person_id: people[right_idx].to_string(),
+                            referenced_by: "MustStayApart".to_string(),
+                        }
                     })?;
                     let people = canonical_pair(left, right);
                     if seen.insert((people, sessions.clone())) {
@@ -370,13 +393,13 @@ fn compile_hard_apart_pairs(
 }
 
 #[derive(Debug, Clone)]
-struct CompiledImmovableAssignment {
-    person_idx: usize,
-    session_idx: usize,
-    group_idx: usize,
+pub(crate) struct CompiledImmovableAssignment {
+    pub(crate) person_idx: usize,
+    pub(crate) session_idx: usize,
+    pub(crate) group_idx: usize,
 }
 
-fn compile_immovable_assignments(
+pub(crate) fn compile_immovable_assignments(
     input: &ApiInput,
     person_id_to_idx: &HashMap<String, usize>,
     group_id_to_idx: &HashMap<String, usize>,
@@ -388,16 +411,16 @@ fn compile_immovable_assignments(
         match constraint {
             Constraint::ImmovablePerson(params) => {
                 let &person_idx = person_id_to_idx.get(&params.person_id).ok_or_else(|| {
-                    SolverError::ValidationError(format!(
-                        "ImmovablePerson references unknown person '{}'",
-                        params.person_id
-                    ))
+                    SolverError::UnknownPersonId {
+                        person_id: params.person_id.to_string(),
+                        referenced_by: "ImmovablePerson".to_string(),
+                    }
                 })?;
                 let &group_idx = group_id_to_idx.get(&params.group_id).ok_or_else(|| {
-                    SolverError::ValidationError(format!(
-                        "ImmovablePerson references unknown group '{}'",
-                        params.group_id
-                    ))
+                    SolverError::UnknownGroupId {
+                        group_id: params.group_id.to_string(),
+                        referenced_by: "ImmovablePerson".to_string(),
+                    }
                 })?;
                 for session in params
                     .sessions
@@ -406,11 +429,11 @@ fn compile_immovable_assignments(
                 {
                     let session_idx = session as usize;
                     if session_idx >= num_sessions {
-                        return Err(SolverError::ValidationError(format!(
-                            "ImmovablePerson references invalid session {} (max: {})",
-                            session_idx,
-                            num_sessions.saturating_sub(1)
-                        )));
+                        return Err(SolverError::InvalidSession {
+                            session: session_idx,
+                            num_sessions,
+                            referenced_by: "ImmovablePerson".to_string(),
+                        });
                     }
                     assignments.push(CompiledImmovableAssignment {
                         person_idx,
@@ -421,10 +444,10 @@ fn compile_immovable_assignments(
             }
             Constraint::ImmovablePeople(params) => {
                 let &group_idx = group_id_to_idx.get(&params.group_id).ok_or_else(|| {
-                    SolverError::ValidationError(format!(
-                        "ImmovablePeople references unknown group '{}'",
-                        params.group_id
-                    ))
+                    SolverError::UnknownGroupId {
+                        group_id: params.group_id.to_string(),
+                        referenced_by: "ImmovablePeople".to_string(),
+                    }
                 })?;
                 for session in params
                     .sessions
@@ -433,18 +456,18 @@ fn compile_immovable_assignments(
                 {
                     let session_idx = session as usize;
                     if session_idx >= num_sessions {
-                        return Err(SolverError::ValidationError(format!(
-                            "ImmovablePeople references invalid session {} (max: {})",
-                            session_idx,
-                            num_sessions.saturating_sub(1)
-                        )));
+                        return Err(SolverError::InvalidSession {
+                            session: session_idx,
+                            num_sessions,
+                            referenced_by: "ImmovablePeople".to_string(),
+                        });
                     }
                     for person_id in &params.people {
                         let &person_idx = person_id_to_idx.get(person_id).ok_or_else(|| {
-                            SolverError::ValidationError(format!(
-                                "ImmovablePeople references unknown person '{}'",
-                                person_id
-                            ))
+                            SolverError::UnknownPersonId {
+                                person_id: person_id.to_string(),
+                                referenced_by: "ImmovablePeople".to_string(),
+                            }
                         })?;
                         assignments.push(CompiledImmovableAssignment {
                             person_idx,
@@ -620,11 +643,11 @@ fn normalize_sessions(
     normalized.dedup();
     for &session_idx in &normalized {
         if session_idx >= num_sessions {
-            return Err(SolverError::ValidationError(format!(
-                "{label} references invalid session {} (max: {})",
-                session_idx,
-                num_sessions.saturating_sub(1)
-            )));
+            return Err(SolverError::InvalidSession {
+                session: session_idx,
+                num_sessions,
+                referenced_by: label.to_string(),
+            });
         }
     }
     Ok(Some(normalized))
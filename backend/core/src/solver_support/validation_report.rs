@@ -0,0 +1,332 @@
+//! Input checks that report errors and warnings without constructing a schedule.
+
+use super::error_details::{constraint_people, constraint_sessions, SolverErrorDetails};
+use super::memory_estimate::{estimate_memory, MEMORY_WARNING_BYTES};
+use super::validation::{validate_schedule_as_construction_seed, validate_schedule_as_incumbent};
+use super::SolverError;
+use crate::models::{ApiInput, Constraint, ValidationFinding, ValidationReport};
+use crate::solver1::State;
+use std::collections::{BTreeMap, HashMap};
+
+/// Validates `input` the same way a solve would, then looks for suspicious constraints.
+pub fn validate(input: &ApiInput) -> ValidationReport {
+    let mut report = ValidationReport::default();
+
+    if let Err(message) = input.solver.validate_solver_selection() {
+        report
+            .errors
+            .push(error_finding(&SolverError::ValidationError(message), input));
+    }
+
+    let state = match State::preprocess(input).and_then(|state| {
+        validate_schedules(input)?;
+        Ok(state)
+    }) {
+        Ok(state) => Some(state),
+        Err(error) => {
+            report.errors.push(error_finding(&error, input));
+            None
+        }
+    };
+
+    report.warnings.extend(memory_estimate_warning(input));
+    report.warnings.extend(unattended_session_warnings(input));
+    if let Some(state) = &state {
+        report
+            .warnings
+            .extend(redundant_together_warnings(input, state));
+        report
+            .warnings
+            .extend(unreachable_attribute_balance_warnings(input, state));
+    }
+
+    report
+}
+
+fn validate_schedules(input: &ApiInput) -> Result<(), SolverError> {
+    if let Some(schedule) = &input.initial_schedule {
+        validate_schedule_as_incumbent(input, schedule)?;
+    }
+    if let Some(schedule) = &input.construction_seed_schedule {
+        validate_schedule_as_construction_seed(input, schedule)?;
+    }
+    Ok(())
+}
+
+fn error_finding(error: &SolverError, input: &ApiInput) -> ValidationFinding {
+    ValidationFinding {
+        message: error.message(),
+        details: error.details(input),
+    }
+}
+
+/// Warns when a solve needs a lot of memory but stays under the hard limit.
+fn memory_estimate_warning(input: &ApiInput) -> Option<ValidationFinding> {
+    let estimate = estimate_memory(input);
+    let over_limit = estimate
+        .limit_bytes
+        .is_some_and(|limit_bytes| estimate.total_bytes > limit_bytes);
+    if estimate.total_bytes <= MEMORY_WARNING_BYTES || over_limit {
+        return None;
+    }
+    Some(ValidationFinding {
+        message: format!(
+            "a solve needs an estimated {} MiB of memory, which may be too much for a browser tab",
+            estimate.total_bytes / (1024 * 1024)
+        ),
+        details: SolverErrorDetails {
+            kind: "memory_estimate".to_string(),
+            ..SolverErrorDetails::default()
+        },
+    })
+}
+
+/// Warns about constraints that name sessions some of their people do not attend.
+fn unattended_session_warnings(input: &ApiInput) -> Vec<ValidationFinding> {
+    let attendance: HashMap<&str, Option<&[u32]>> = input
+        .problem
+        .people
+        .iter()
+        .map(|person| (person.id.as_str(), person.sessions.as_deref()))
+        .collect();
+
+    let mut warnings = Vec::new();
+    for (constraint_idx, constraint) in input.constraints.iter().enumerate() {
+        let Some(sessions) = constraint_sessions(constraint).filter(|s| !s.is_empty()) else {
+            continue;
+        };
+        for person_id in constraint_people(constraint) {
+            let Some(Some(attended)) = attendance.get(person_id.as_str()) else {
+                continue;
+            };
+            let mut missed: Vec<usize> = sessions
+                .iter()
+                .filter(|session| !attended.contains(session))
+                .map(|&session| session as usize)
+                .collect();
+            if missed.is_empty() {
+                continue;
+            }
+            missed.sort_unstable();
+            missed.dedup();
+            warnings.push(ValidationFinding {
+                message: format!(
+                    "{} (constraint {}) applies in sessions {:?} that '{}' does not attend",
+                    constraint.type_name(),
+                    constraint_idx,
+                    missed,
+                    person_id
+                ),
+                details: SolverErrorDetails {
+                    kind: "unattended_session".to_string(),
+                    person_ids: vec![person_id.clone()],
+                    constraint_indices: vec![constraint_idx],
+                    sessions: missed,
+                    ..SolverErrorDetails::default()
+                },
+            });
+        }
+    }
+    warnings
+}
+
+/// Warns about `ShouldStayTogether` constraints whose people already share a clique.
+fn redundant_together_warnings(input: &ApiInput, state: &State) -> Vec<ValidationFinding> {
+    let mut warnings = Vec::new();
+    for (constraint_idx, constraint) in input.constraints.iter().enumerate() {
+        let Constraint::ShouldStayTogether {
+            people, sessions, ..
+        } = constraint
+        else {
+            continue;
+        };
+        let Some(person_indices) = people
+            .iter()
+            .map(|person_id| state.person_id_to_idx.get(person_id).copied())
+            .collect::<Option<Vec<usize>>>()
+        else {
+            continue;
+        };
+        let active_sessions: Vec<usize> = match sessions {
+            Some(sessions) => sessions.iter().map(|&session| session as usize).collect(),
+            None => (0..state.num_sessions as usize).collect(),
+        };
+
+        // Per session: `Some(true)` if the attending people share a clique, `Some(false)` if
+        // fewer than two attend, `None` if they are not all in one clique.
+        let shared_clique_by_session: Vec<Option<bool>> = active_sessions
+            .iter()
+            .map(|&session_idx| {
+                let cliques: Vec<Option<usize>> = person_indices
+                    .iter()
+                    .filter(|&&person_idx| state.person_participation[person_idx][session_idx])
+                    .map(|&person_idx| state.person_to_clique_id[session_idx][person_idx])
+                    .collect();
+                match cliques.as_slice() {
+                    [] | [_] => Some(false),
+                    [Some(first), rest @ ..] => rest
+                        .iter()
+                        .all(|clique| clique == &Some(*first))
+                        .then_some(true),
+                    [None, ..] => None,
+                }
+            })
+            .collect();
+        let redundant = shared_clique_by_session.iter().all(Option::is_some)
+            && shared_clique_by_session.contains(&Some(true));
+        if !redundant {
+            continue;
+        }
+
+        let mut constraint_indices = vec![constraint_idx];
+        constraint_indices.extend(
+            input
+                .constraints
+                .iter()
+                .enumerate()
+                .filter(|(_, other)| {
+                    matches!(other, Constraint::MustStayTogether { .. })
+                        && constraint_people(other)
+                            .iter()
+                            .any(|person_id| people.contains(person_id))
+                })
+                .map(|(idx, _)| idx),
+        );
+        warnings.push(ValidationFinding {
+            message: format!(
+                "ShouldStayTogether (constraint {}) is redundant: people {:?} already must stay together",
+                constraint_idx, people
+            ),
+            details: SolverErrorDetails {
+                kind: "redundant_constraint".to_string(),
+                person_ids: people.clone(),
+                constraint_indices,
+                ..SolverErrorDetails::default()
+            },
+        });
+    }
+    warnings
+}
+
+/// Warns about `AttributeBalance` targets no schedule can meet: desired counts that add up to
+/// more than the group seats, or a desired count above the number of attendees with the value.
+///
+/// Sessions with the same shortfall share one finding; groups closed in a session are skipped.
+fn unreachable_attribute_balance_warnings(
+    input: &ApiInput,
+    state: &State,
+) -> Vec<ValidationFinding> {
+    let group_count = state.group_idx_to_id.len();
+    let mut warnings = Vec::new();
+    for (constraint_idx, constraint) in input.constraints.iter().enumerate() {
+        let Constraint::AttributeBalance(params) = constraint else {
+            continue;
+        };
+        let target_groups: Vec<usize> = if params.group_id == "ALL" {
+            (0..group_count).collect()
+        } else {
+            match state.group_id_to_idx.get(&params.group_id) {
+                Some(&group_idx) => vec![group_idx],
+                None => continue,
+            }
+        };
+        let Some(&attr_idx) = state.attr_key_to_idx.get(&params.attribute_key) else {
+            continue;
+        };
+        let active_sessions: Vec<usize> = match &params.sessions {
+            Some(sessions) => sessions.iter().map(|&session| session as usize).collect(),
+            None => (0..state.num_sessions as usize).collect(),
+        };
+        let mut desired_values: Vec<(&str, u32)> = params
+            .desired_values
+            .iter()
+            .map(|(value, &count)| (value.as_str(), count))
+            .collect();
+        desired_values.sort_unstable();
+        let desired_total: u32 = desired_values.iter().map(|&(_, count)| count).sum();
+
+        // (group, desired total, capacity) -> sessions
+        let mut over_capacity: BTreeMap<(usize, u32, usize), Vec<usize>> = BTreeMap::new();
+        // (value, desired count per group, open groups, attendees with the value) -> sessions
+        let mut over_supply: BTreeMap<(&str, u32, usize, usize), Vec<usize>> = BTreeMap::new();
+        for &session_idx in &active_sessions {
+            let open_groups: Vec<usize> = target_groups
+                .iter()
+                .copied()
+                .filter(|&group_idx| {
+                    state.effective_group_capacities[session_idx * group_count + group_idx] > 0
+                })
+                .collect();
+            if open_groups.is_empty() {
+                continue;
+            }
+            for &group_idx in &open_groups {
+                let capacity =
+                    state.effective_group_capacities[session_idx * group_count + group_idx];
+                if desired_total as usize > capacity {
+                    over_capacity
+                        .entry((group_idx, desired_total, capacity))
+                        .or_default()
+                        .push(session_idx);
+                }
+            }
+            for &(value, desired) in &desired_values {
+                let value_idx = state.attr_val_to_idx[attr_idx].get(value).copied();
+                let available = (0..state.person_participation.len())
+                    .filter(|&person_idx| {
+                        state.person_participation[person_idx][session_idx]
+                            && Some(state.person_attributes[person_idx][attr_idx]) == value_idx
+                    })
+                    .count();
+                // "ALL" asks for `desired` in every open group at once.
+                if desired as usize * open_groups.len() > available {
+                    over_supply
+                        .entry((value, desired, open_groups.len(), available))
+                        .or_default()
+                        .push(session_idx);
+                }
+            }
+        }
+
+        for ((group_idx, desired_total, capacity), sessions) in over_capacity {
+            let group_id = &state.group_idx_to_id[group_idx];
+            warnings.push(ValidationFinding {
+                message: format!(
+                    "AttributeBalance (constraint {}) wants {} people in '{}', which seats only {} in sessions {:?}",
+                    constraint_idx, desired_total, group_id, capacity, sessions
+                ),
+                details: SolverErrorDetails {
+                    kind: "unreachable_attribute_balance".to_string(),
+                    group_ids: vec![group_id.clone()],
+                    constraint_indices: vec![constraint_idx],
+                    sessions,
+                    ..SolverErrorDetails::default()
+                },
+            });
+        }
+        for ((value, desired, open_groups, available), sessions) in over_supply {
+            let target_label = if params.group_id == "ALL" {
+                format!("each of {open_groups} groups")
+            } else {
+                format!("'{}'", params.group_id)
+            };
+            warnings.push(ValidationFinding {
+                message: format!(
+                    "AttributeBalance (constraint {}) wants {} people with {}={} in {}, but only {} attend sessions {:?}",
+                    constraint_idx, desired, params.attribute_key, value, target_label, available, sessions
+                ),
+                details: SolverErrorDetails {
+                    kind: "unreachable_attribute_balance".to_string(),
+                    group_ids: target_groups
+                        .iter()
+                        .map(|&group_idx| state.group_idx_to_id[group_idx].clone())
+                        .collect(),
+                    constraint_indices: vec![constraint_idx],
+                    sessions,
+                    ..SolverErrorDetails::default()
+                },
+            });
+        }
+    }
+    warnings
+}
@@ -0,0 +1,210 @@
+//! Repairing a hand-edited draft before it is used as a warm start.
+//!
+//! A warm start must place every attendee exactly once, within capacity, and with immovable
+//! people in their required groups; otherwise validation rejects it. Drafts edited by hand
+//! often break one of these rules, for example after a late registration. Instead of rejecting
+//! them, the repair moves the affected people in a fixed order and reports every move, so
+//! callers can show how the draft was altered.
+
+use crate::models::{ApiInput, ApiSchedule, ScheduleRepair, ScheduleRepairKind, WarmStartRepair};
+use crate::solver_support::validation::{
+    build_effective_group_capacities, build_person_participation, compile_immovable_assignments,
+};
+use crate::solver_support::SolverError;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+/// Repairs `schedule` so it can be used as the `initial_schedule` of `input`.
+///
+/// Each session is repaired in three passes:
+/// 1. Immovable people are moved to the group their constraint requires.
+/// 2. Groups over capacity give up their last-listed people, skipping people pinned there.
+/// 3. Attendees the draft leaves out are seated.
+///
+/// People displaced by the second pass, and those seated by the third, go to the open group
+/// with the most free seats, the earlier group of `problem.groups` on ties, so the same draft
+/// always gets the same repairs.
+///
+/// Problems outside these three are left in place and still rejected when the schedule is
+/// used: unknown ids, people listed twice or in sessions they do not attend, and the other
+/// hard constraints. Fails with [`SolverError::InsufficientCapacity`] when a session has no
+/// free seat for a displaced or missing person.
+pub fn repair_warm_start(
+    input: &ApiInput,
+    schedule: &ApiSchedule,
+) -> Result<WarmStartRepair, SolverError> {
+    let groups = &input.problem.groups;
+    let num_groups = groups.len();
+    let person_id_to_idx: HashMap<String, usize> = input
+        .problem
+        .people
+        .iter()
+        .enumerate()
+        .map(|(idx, person)| (person.id.clone(), idx))
+        .collect();
+    let group_id_to_idx: HashMap<String, usize> = groups
+        .iter()
+        .enumerate()
+        .map(|(idx, group)| (group.id.clone(), idx))
+        .collect();
+
+    let participation = build_person_participation(input)?;
+    let capacities = build_effective_group_capacities(input)?;
+    // (session, person) -> required group
+    let required_groups: HashMap<(usize, usize), usize> =
+        compile_immovable_assignments(input, &person_id_to_idx, &group_id_to_idx)?
+            .into_iter()
+            .map(|assignment| {
+                (
+                    (assignment.session_idx, assignment.person_idx),
+                    assignment.group_idx,
+                )
+            })
+            .collect();
+
+    let mut repaired = ApiSchedule::new();
+    let mut repairs = Vec::new();
+    for session_idx in 0..input.problem.num_sessions as usize {
+        let session_key = format!("session_{session_idx}");
+        let draft = schedule.get(&session_key);
+        let capacity = |group_idx: usize| capacities[session_idx * num_groups + group_idx];
+        let required_group = |person_id: &str| {
+            person_id_to_idx
+                .get(person_id)
+                .and_then(|&person_idx| required_groups.get(&(session_idx, person_idx)))
+                .copied()
+        };
+        let mut members: Vec<Vec<String>> = groups
+            .iter()
+            .map(|group| {
+                draft
+                    .and_then(|groups| groups.get(&group.id))
+                    .cloned()
+                    .unwrap_or_default()
+            })
+            .collect();
+        // Groups the problem does not know are kept for validation to reject.
+        let unknown_groups: HashMap<String, Vec<String>> = draft
+            .into_iter()
+            .flatten()
+            .filter(|(group_id, _)| !group_id_to_idx.contains_key(*group_id))
+            .map(|(group_id, people)| (group_id.clone(), people.clone()))
+            .collect();
+        let mut record = |kind, person_id: &str, from_group: Option<usize>, to_group: usize| {
+            repairs.push(ScheduleRepair {
+                kind,
+                session: session_idx as u32,
+                person_id: person_id.to_string(),
+                from_group: from_group.map(|group_idx| groups[group_idx].id.clone()),
+                to_group: groups[to_group].id.clone(),
+            });
+        };
+
+        for (person_idx, person) in input.problem.people.iter().enumerate() {
+            if !participation[person_idx][session_idx] {
+                continue;
+            }
+            let Some(&group_idx) = required_groups.get(&(session_idx, person_idx)) else {
+                continue;
+            };
+            let current = group_of(&members, &person.id);
+            if current == Some(group_idx) {
+                continue;
+            }
+            if let Some(current) = current {
+                members[current].retain(|member| *member != person.id);
+            }
+            members[group_idx].push(person.id.clone());
+            record(
+                ScheduleRepairKind::DisplacedImmovable,
+                &person.id,
+                current,
+                group_idx,
+            );
+        }
+
+        let mut displaced = Vec::new();
+        for (group_idx, group_members) in members.iter_mut().enumerate() {
+            let mut evicted = Vec::new();
+            while group_members.len() > capacity(group_idx) {
+                let Some(position) = group_members
+                    .iter()
+                    .rposition(|member| required_group(member) != Some(group_idx))
+                else {
+                    break;
+                };
+                evicted.push(group_members.remove(position));
+            }
+            displaced.extend(evicted.into_iter().rev().map(|person| (person, group_idx)));
+        }
+        let missing = input
+            .problem
+            .people
+            .iter()
+            .enumerate()
+            .filter(|&(person_idx, person)| {
+                participation[person_idx][session_idx]
+                    && group_of(&members, &person.id).is_none()
+                    && !displaced.iter().any(|(id, _)| *id == person.id)
+                    && !unknown_groups
+                        .values()
+                        .any(|people| people.contains(&person.id))
+            })
+            .map(|(_, person)| person.id.clone())
+            .collect::<Vec<_>>();
+
+        let placements = displaced
+            .into_iter()
+            .map(|(person, from_group)| {
+                (
+                    ScheduleRepairKind::CapacityOverflow,
+                    person,
+                    Some(from_group),
+                )
+            })
+            .chain(
+                missing
+                    .into_iter()
+                    .map(|person| (ScheduleRepairKind::MissingPerson, person, None)),
+            );
+        for (kind, person_id, from_group) in placements {
+            let to_group = (0..num_groups)
+                .filter(|&group_idx| members[group_idx].len() < capacity(group_idx))
+                .max_by_key(|&group_idx| {
+                    (
+                        capacity(group_idx) - members[group_idx].len(),
+                        Reverse(group_idx),
+                    )
+                })
+                .ok_or_else(|| SolverError::InsufficientCapacity {
+                    session: session_idx,
+                    people: (0..input.problem.people.len())
+                        .filter(|&person_idx| participation[person_idx][session_idx])
+                        .count(),
+                    capacity: (0..num_groups).map(capacity).sum(),
+                })?;
+            members[to_group].push(person_id.clone());
+            record(kind, &person_id, from_group, to_group);
+        }
+
+        let mut session_groups = unknown_groups;
+        for (group_idx, group_members) in members.into_iter().enumerate() {
+            if capacity(group_idx) > 0 || !group_members.is_empty() {
+                session_groups.insert(groups[group_idx].id.clone(), group_members);
+            }
+        }
+        repaired.insert(session_key, session_groups);
+    }
+
+    Ok(WarmStartRepair {
+        schedule: repaired,
+        repairs,
+    })
+}
+
+/// Index of the first group listing `person_id`.
+fn group_of(members: &[Vec<String>], person_id: &str) -> Option<usize> {
+    members
+        .iter()
+        .position(|group_members| group_members.iter().any(|member| member == person_id))
+}
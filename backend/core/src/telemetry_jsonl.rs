@@ -0,0 +1,108 @@
+//! Streaming progress telemetry to a JSON Lines file or writer while a solve runs.
+//!
+//! Every progress update becomes one line holding the full [`ProgressUpdate`], written and
+//! flushed as it arrives, so a long run can be analyzed offline (or after a crash) without
+//! keeping its history in memory. Enable `telemetry.record_move_statistics` or
+//! `telemetry.record_constraint_penalties` to include move statistics windows or per-constraint
+//! penalty samples in the records.
+
+use crate::models::{ProgressCallback, ProgressUpdate};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Appends progress updates to a writer as JSON lines.
+///
+/// Write errors do not interrupt the solve: the first one is kept, later records are dropped,
+/// and [`finish`](Self::finish) reports it.
+///
+/// # Example
+///
+/// ```no_run
+/// # use gm_core::models::ApiInput;
+/// # fn example(input: &ApiInput) -> std::io::Result<()> {
+/// use gm_core::telemetry_jsonl::JsonlTelemetryWriter;
+/// use std::sync::Arc;
+///
+/// let telemetry = Arc::new(JsonlTelemetryWriter::create("run-telemetry.jsonl")?);
+/// let callback = telemetry.progress_callback();
+/// let result = gm_core::run_solver_with_progress(input, Some(&callback));
+/// let records = telemetry.finish()?;
+/// println!("{records} telemetry records written");
+/// # Ok(())
+/// # }
+/// ```
+pub struct JsonlTelemetryWriter<W: Write> {
+    state: Mutex<WriterState<W>>,
+}
+
+struct WriterState<W> {
+    writer: W,
+    records: u64,
+    error: Option<io::Error>,
+}
+
+impl JsonlTelemetryWriter<BufWriter<File>> {
+    /// Creates (or truncates) the file at `path` and writes records to it.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: Write> JsonlTelemetryWriter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            state: Mutex::new(WriterState {
+                writer,
+                records: 0,
+                error: None,
+            }),
+        }
+    }
+
+    /// Writes `update` as one line and flushes it.
+    pub fn record(&self, update: &ProgressUpdate) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if state.error.is_some() {
+            return;
+        }
+        let written = serde_json::to_writer(&mut state.writer, update)
+            .map_err(io::Error::from)
+            .and_then(|()| state.writer.write_all(b"\n"))
+            .and_then(|()| state.writer.flush());
+        match written {
+            Ok(()) => state.records += 1,
+            Err(error) => state.error = Some(error),
+        }
+    }
+
+    /// Flushes the writer and returns how many records were written, or the first write error.
+    pub fn finish(&self) -> io::Result<u64> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(error) = state.error.take() {
+            return Err(error);
+        }
+        state.writer.flush()?;
+        Ok(state.records)
+    }
+
+    /// Consumes the telemetry writer and returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.state
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner)
+            .writer
+    }
+}
+
+impl<W: Write + Send + 'static> JsonlTelemetryWriter<W> {
+    /// A progress callback that records every update and never stops the run.
+    pub fn progress_callback(self: &Arc<Self>) -> ProgressCallback {
+        let telemetry = Arc::clone(self);
+        Box::new(move |update| {
+            telemetry.record(update);
+            true
+        })
+    }
+}
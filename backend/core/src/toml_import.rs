@@ -0,0 +1,114 @@
+//! Reading inputs and results written in TOML.
+//!
+//! A TOML document is parsed and then converted to the JSON data model before it is
+//! deserialized, so field names, aliases, and defaults are exactly those of the JSON format.
+//! Datetimes, which JSON lacks, become strings.
+//!
+//! ```text
+//! [problem]
+//! num_sessions = 3 # one per day
+//!
+//! [[problem.people]]
+//! id = "alice"
+//! attributes = { team = "red" }
+//! ```
+
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value as JsonValue};
+use toml_edit::{Item, Table, Value};
+
+/// Error reading a TOML document.
+#[derive(Debug)]
+pub struct TomlImportError {
+    /// 1-based line and column of a syntax error; `None` when the TOML is valid but does not
+    /// have the expected fields
+    pub location: Option<(usize, usize)>,
+    /// What is wrong
+    pub message: String,
+}
+
+impl std::fmt::Display for TomlImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some((line, column)) = self.location {
+            write!(f, "line {line}, column {column}: ")?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for TomlImportError {}
+
+/// Deserializes a `T` from the TOML document `toml`.
+///
+/// # Example
+///
+/// ```no_run
+/// use gm_core::models::ApiInput;
+/// use gm_core::toml_import::from_toml_str;
+///
+/// let input: ApiInput = from_toml_str(
+///     r#"
+/// [problem]
+/// num_sessions = 2
+/// people = [{ id = "alice", attributes = {} }, { id = "bob", attributes = {} }]
+/// groups = [{ id = "g0", size = 2 }]
+/// "#,
+/// )
+/// .expect("valid TOML");
+/// assert_eq!(input.problem.people.len(), 2);
+/// ```
+pub fn from_toml_str<T: DeserializeOwned>(toml: &str) -> Result<T, TomlImportError> {
+    let document = toml_edit::Document::parse(toml).map_err(|error| TomlImportError {
+        location: error.span().map(|span| line_and_column(toml, span.start)),
+        message: error.message().to_string(),
+    })?;
+    serde_json::from_value(table_to_json(document.as_table())).map_err(|error| TomlImportError {
+        location: None,
+        message: error.to_string(),
+    })
+}
+
+fn line_and_column(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..offset.min(text.len())];
+    let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+fn table_to_json(table: &Table) -> JsonValue {
+    JsonValue::Object(
+        table
+            .iter()
+            .map(|(key, item)| (key.to_string(), item_to_json(item)))
+            .collect(),
+    )
+}
+
+fn item_to_json(item: &Item) -> JsonValue {
+    match item {
+        Item::None => JsonValue::Null,
+        Item::Value(value) => value_to_json(value),
+        Item::Table(table) => table_to_json(table),
+        Item::ArrayOfTables(tables) => tables.iter().map(table_to_json).collect(),
+    }
+}
+
+fn value_to_json(value: &Value) -> JsonValue {
+    match value {
+        Value::String(string) => JsonValue::String(string.value().clone()),
+        Value::Integer(integer) => JsonValue::from(*integer.value()),
+        // JSON has no NaN or infinity; they read as null like in `serde_json::to_value`.
+        Value::Float(float) => JsonValue::from(*float.value()),
+        Value::Boolean(boolean) => JsonValue::Bool(*boolean.value()),
+        Value::Datetime(datetime) => JsonValue::String(datetime.value().to_string()),
+        Value::Array(array) => array.iter().map(value_to_json).collect(),
+        Value::InlineTable(table) => JsonValue::Object(
+            table
+                .iter()
+                .map(|(key, value)| (key.to_string(), value_to_json(value)))
+                .collect::<Map<_, _>>(),
+        ),
+    }
+}
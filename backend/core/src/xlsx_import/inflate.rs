@@ -0,0 +1,250 @@
+//! DEFLATE decompression (RFC 1951), the only compression method `.xlsx` archives use.
+
+const MAX_BITS: usize = 15;
+const TOO_LONG: &str = "DEFLATE stream is longer than its declared size";
+
+/// Base lengths and extra bits of length codes 257..=285.
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+/// Base distances and extra bits of distance codes 0..=29.
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+/// Order in which code length code lengths are stored in a dynamic block header.
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+
+/// Decompresses a raw DEFLATE stream, failing once the output would exceed `expected_len`
+/// bytes so a corrupt or hostile archive cannot exhaust memory.
+pub(super) fn inflate(data: &[u8], expected_len: usize) -> Result<Vec<u8>, String> {
+    let mut input = BitReader { data, position: 0 };
+    let mut output = Vec::with_capacity(expected_len.min(data.len().saturating_mul(8)));
+    loop {
+        let last_block = input.bits(1)? == 1;
+        match input.bits(2)? {
+            0 => stored_block(&mut input, &mut output, expected_len)?,
+            1 => {
+                let (literals, distances) = fixed_codes();
+                compressed_block(&mut input, &mut output, expected_len, &literals, &distances)?;
+            }
+            2 => {
+                let (literals, distances) = dynamic_codes(&mut input)?;
+                compressed_block(&mut input, &mut output, expected_len, &literals, &distances)?;
+            }
+            _ => return Err("invalid DEFLATE block type".to_string()),
+        }
+        if last_block {
+            return Ok(output);
+        }
+    }
+}
+
+struct BitReader<'a> {
+    data: &'a [u8],
+    /// Position in bits.
+    position: usize,
+}
+
+impl BitReader<'_> {
+    /// Reads `count` bits, least significant first.
+    fn bits(&mut self, count: u32) -> Result<u32, String> {
+        let mut value = 0;
+        for shift in 0..count {
+            let byte = self
+                .data
+                .get(self.position / 8)
+                .ok_or_else(|| "DEFLATE stream ends early".to_string())?;
+            value |= u32::from((byte >> (self.position % 8)) & 1) << shift;
+            self.position += 1;
+        }
+        Ok(value)
+    }
+
+    fn align_to_byte(&mut self) {
+        self.position = self.position.div_ceil(8) * 8;
+    }
+}
+
+/// A canonical Huffman code as symbol counts per code length and symbols in code order.
+struct Huffman {
+    counts: [u16; MAX_BITS + 1],
+    symbols: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Result<Self, String> {
+        let mut counts = [0u16; MAX_BITS + 1];
+        for &length in lengths {
+            counts[usize::from(length)] += 1;
+        }
+        counts[0] = 0;
+
+        let mut offsets = [0u16; MAX_BITS + 2];
+        let mut left = 1i32;
+        for bits in 1..=MAX_BITS {
+            left = (left << 1) - i32::from(counts[bits]);
+            if left < 0 {
+                return Err("oversubscribed DEFLATE code".to_string());
+            }
+            offsets[bits + 1] = offsets[bits] + counts[bits];
+        }
+
+        let mut symbols = vec![0u16; usize::from(offsets[MAX_BITS + 1])];
+        for (symbol, &length) in lengths.iter().enumerate() {
+            if length != 0 {
+                let slot = &mut offsets[usize::from(length)];
+                symbols[usize::from(*slot)] = symbol as u16;
+                *slot += 1;
+            }
+        }
+        Ok(Self { counts, symbols })
+    }
+
+    fn decode(&self, input: &mut BitReader<'_>) -> Result<u16, String> {
+        let mut code = 0i32;
+        let mut first = 0i32;
+        let mut index = 0i32;
+        for bits in 1..=MAX_BITS {
+            code |= input.bits(1)? as i32;
+            let count = i32::from(self.counts[bits]);
+            if code - first < count {
+                return Ok(self.symbols[(index + code - first) as usize]);
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        Err("invalid DEFLATE code".to_string())
+    }
+}
+
+fn stored_block(
+    input: &mut BitReader<'_>,
+    output: &mut Vec<u8>,
+    limit: usize,
+) -> Result<(), String> {
+    input.align_to_byte();
+    let start = input.position / 8;
+    let header = input
+        .data
+        .get(start..start + 4)
+        .ok_or_else(|| "DEFLATE stream ends early".to_string())?;
+    let len = usize::from(u16::from_le_bytes([header[0], header[1]]));
+    let complement = u16::from_le_bytes([header[2], header[3]]);
+    if len as u16 != !complement {
+        return Err("corrupt stored DEFLATE block".to_string());
+    }
+    let bytes = input
+        .data
+        .get(start + 4..start + 4 + len)
+        .ok_or_else(|| "DEFLATE stream ends early".to_string())?;
+    if output.len() + len > limit {
+        return Err(TOO_LONG.to_string());
+    }
+    output.extend_from_slice(bytes);
+    input.position = (start + 4 + len) * 8;
+    Ok(())
+}
+
+fn fixed_codes() -> (Huffman, Huffman) {
+    let mut lengths = [0u8; 288];
+    lengths[..144].fill(8);
+    lengths[144..256].fill(9);
+    lengths[256..280].fill(7);
+    lengths[280..].fill(8);
+    (
+        Huffman::new(&lengths).expect("fixed literal code is complete"),
+        Huffman::new(&[5; 30]).expect("fixed distance code is complete"),
+    )
+}
+
+fn dynamic_codes(input: &mut BitReader<'_>) -> Result<(Huffman, Huffman), String> {
+    let literal_count = input.bits(5)? as usize + 257;
+    let distance_count = input.bits(5)? as usize + 1;
+    let code_length_count = input.bits(4)? as usize + 4;
+
+    let mut code_length_lengths = [0u8; 19];
+    for &slot in &CODE_LENGTH_ORDER[..code_length_count] {
+        code_length_lengths[slot] = input.bits(3)? as u8;
+    }
+    let code_lengths = Huffman::new(&code_length_lengths)?;
+
+    let mut lengths = Vec::with_capacity(literal_count + distance_count);
+    while lengths.len() < literal_count + distance_count {
+        let symbol = code_lengths.decode(input)?;
+        let (value, repeat) = match symbol {
+            0..=15 => (symbol as u8, 1),
+            16 => {
+                let previous = *lengths
+                    .last()
+                    .ok_or_else(|| "DEFLATE length repeat without a previous length".to_string())?;
+                (previous, 3 + input.bits(2)?)
+            }
+            17 => (0, 3 + input.bits(3)?),
+            _ => (0, 11 + input.bits(7)?),
+        };
+        lengths.extend(std::iter::repeat_n(value, repeat as usize));
+    }
+    if lengths.len() != literal_count + distance_count {
+        return Err("DEFLATE code lengths overrun their table".to_string());
+    }
+    if lengths[256] == 0 {
+        return Err("DEFLATE code has no end-of-block symbol".to_string());
+    }
+
+    Ok((
+        Huffman::new(&lengths[..literal_count])?,
+        Huffman::new(&lengths[literal_count..])?,
+    ))
+}
+
+fn compressed_block(
+    input: &mut BitReader<'_>,
+    output: &mut Vec<u8>,
+    limit: usize,
+    literals: &Huffman,
+    distances: &Huffman,
+) -> Result<(), String> {
+    loop {
+        let symbol = usize::from(literals.decode(input)?);
+        match symbol {
+            0..=255 if output.len() < limit => output.push(symbol as u8),
+            0..=255 => return Err(TOO_LONG.to_string()),
+            256 => return Ok(()),
+            257..=285 => {
+                let code = symbol - 257;
+                let len = usize::from(LENGTH_BASE[code])
+                    + input.bits(u32::from(LENGTH_EXTRA[code]))? as usize;
+                let code = usize::from(distances.decode(input)?);
+                if code >= DISTANCE_BASE.len() {
+                    return Err("invalid DEFLATE distance code".to_string());
+                }
+                let distance = usize::from(DISTANCE_BASE[code])
+                    + input.bits(u32::from(DISTANCE_EXTRA[code]))? as usize;
+                if distance > output.len() {
+                    return Err("DEFLATE distance reaches before the start".to_string());
+                }
+                if output.len() + len > limit {
+                    return Err(TOO_LONG.to_string());
+                }
+                // Copies may overlap their own output, so go byte by byte.
+                let start = output.len() - distance;
+                for offset in 0..len {
+                    output.push(output[start + offset]);
+                }
+            }
+            _ => return Err("invalid DEFLATE literal/length code".to_string()),
+        }
+    }
+}
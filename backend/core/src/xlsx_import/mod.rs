@@ -0,0 +1,548 @@
+//! Importing people and pair constraints from an Excel (`.xlsx`) workbook.
+//!
+//! The people sheet is the sheet named `People`, or else the first sheet. It is laid out like
+//! a [people CSV](crate::csv_import): a header row with an `id` column, an optional `sessions`
+//! column, and one attribute per remaining column.
+//!
+//! An optional sheet named `Constraints` lists pair constraints, one per row:
+//!
+//! ```text
+//! type                 people           penalty_weight  sessions
+//! MustStayTogether     alice;bob
+//! ShouldNotBeTogether  carol;dave;erin  500             0;1
+//! ```
+//!
+//! `type` is `MustStayTogether`, `MustStayApart`, `ShouldStayTogether` or
+//! `ShouldNotBeTogether`, and `people` lists two or more ids separated by `;` or `,`.
+//! `penalty_weight` only applies to the two soft types and defaults to 1000; `sessions` reads
+//! like the people sheet's, with an empty cell meaning every session.
+//!
+//! Sheet names are matched ignoring case, column names exactly. Blank rows are skipped, and
+//! whitespace around cell values is trimmed. Formula cells contribute their cached value.
+
+mod inflate;
+mod xml;
+mod zip;
+
+use crate::csv_import::{parse_sessions, people_from_rows, CsvImportError};
+use crate::models::{default_constraint_weight, Constraint, Person};
+use std::collections::{HashMap, HashSet};
+use xml::{attribute, Event, Reader};
+use zip::Archive;
+
+const PEOPLE_SHEET: &str = "people";
+const CONSTRAINTS_SHEET: &str = "constraints";
+const TYPE_COLUMN: &str = "type";
+const PEOPLE_COLUMN: &str = "people";
+const PENALTY_WEIGHT_COLUMN: &str = "penalty_weight";
+const SESSIONS_COLUMN: &str = "sessions";
+/// Excel's column limit (XFD).
+const MAX_COLUMNS: usize = 16_384;
+
+/// People and pair constraints read from a workbook.
+#[derive(Debug, Clone)]
+pub struct WorkbookImport {
+    /// One person per row of the people sheet
+    pub people: Vec<Person>,
+    /// One constraint per row of the `Constraints` sheet; empty when there is no such sheet
+    pub constraints: Vec<Constraint>,
+}
+
+/// Error importing a workbook, pointing at the offending sheet, row and column when it
+/// concerns one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XlsxImportError {
+    /// Name of the sheet the error is on
+    pub sheet: Option<String>,
+    /// 1-based spreadsheet row of the offending cell or row
+    pub row: Option<usize>,
+    /// Header name of the offending column, or its letter for cells outside the header
+    pub column: Option<String>,
+    /// What is wrong
+    pub message: String,
+}
+
+impl std::fmt::Display for XlsxImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut location = Vec::new();
+        if let Some(sheet) = &self.sheet {
+            location.push(format!("sheet '{sheet}'"));
+        }
+        if let Some(row) = self.row {
+            location.push(format!("row {row}"));
+        }
+        if let Some(column) = &self.column {
+            location.push(format!("column '{column}'"));
+        }
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(", "))?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for XlsxImportError {}
+
+impl XlsxImportError {
+    fn workbook(message: impl Into<String>) -> Self {
+        Self {
+            sheet: None,
+            row: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    fn on_sheet(
+        sheet: &str,
+        row: Option<usize>,
+        column: Option<&str>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            sheet: Some(sheet.to_string()),
+            row,
+            column: column.map(str::to_string),
+            message: message.into(),
+        }
+    }
+
+    fn from_rows(sheet: &str, error: CsvImportError) -> Self {
+        Self {
+            sheet: Some(sheet.to_string()),
+            row: Some(error.line),
+            column: error.column,
+            message: error.message,
+        }
+    }
+}
+
+/// Reads people and pair constraints from the `.xlsx` file contents `workbook`.
+///
+/// Ids must be non-empty and unique. Whether constraints name existing people is left to
+/// input validation, as for any other input.
+///
+/// # Example
+///
+/// ```no_run
+/// use gm_core::xlsx_import::import_workbook;
+///
+/// let bytes = std::fs::read("attendees.xlsx").expect("readable file");
+/// let import = import_workbook(&bytes).expect("valid workbook");
+/// println!("{} people, {} constraints", import.people.len(), import.constraints.len());
+/// ```
+pub fn import_workbook(workbook: &[u8]) -> Result<WorkbookImport, XlsxImportError> {
+    let archive = Archive::open(workbook).map_err(XlsxImportError::workbook)?;
+    let sheets = sheets(&archive).map_err(XlsxImportError::workbook)?;
+    let shared_strings = shared_strings(&archive).map_err(XlsxImportError::workbook)?;
+    let sheet_named = |name: &str| {
+        sheets
+            .iter()
+            .find(|(sheet, _)| sheet.eq_ignore_ascii_case(name))
+    };
+
+    let (people_sheet, people_path) = sheet_named(PEOPLE_SHEET)
+        .or(sheets.first())
+        .ok_or_else(|| XlsxImportError::workbook("workbook has no sheets"))?;
+    let people = people_from_rows(
+        sheet_rows(&archive, people_sheet, people_path, &shared_strings)?,
+        "row",
+    )
+    .map_err(|error| XlsxImportError::from_rows(people_sheet, error))?;
+
+    let constraints = match sheet_named(CONSTRAINTS_SHEET) {
+        Some((sheet, path)) => {
+            constraints_from_rows(sheet, sheet_rows(&archive, sheet, path, &shared_strings)?)?
+        }
+        None => Vec::new(),
+    };
+
+    Ok(WorkbookImport {
+        people,
+        constraints,
+    })
+}
+
+fn constraints_from_rows(
+    sheet: &str,
+    rows: Vec<(usize, Vec<String>)>,
+) -> Result<Vec<Constraint>, XlsxImportError> {
+    let mut rows = rows.into_iter();
+    let Some((header_row, header)) = rows.next() else {
+        return Ok(Vec::new());
+    };
+
+    let mut seen_columns = HashSet::new();
+    for name in &header {
+        let error =
+            |message: &str| XlsxImportError::on_sheet(sheet, Some(header_row), Some(name), message);
+        if ![
+            TYPE_COLUMN,
+            PEOPLE_COLUMN,
+            PENALTY_WEIGHT_COLUMN,
+            SESSIONS_COLUMN,
+        ]
+        .contains(&name.as_str())
+        {
+            return Err(error(
+                "unknown column; expected type, people, penalty_weight or sessions",
+            ));
+        }
+        if !seen_columns.insert(name.as_str()) {
+            return Err(error("duplicate column name"));
+        }
+    }
+    let index_of = |column: &str| header.iter().position(|name| name == column);
+    let missing = |column: &str| {
+        XlsxImportError::on_sheet(
+            sheet,
+            Some(header_row),
+            None,
+            format!("missing '{column}' column"),
+        )
+    };
+    let type_index = index_of(TYPE_COLUMN).ok_or_else(|| missing(TYPE_COLUMN))?;
+    let people_index = index_of(PEOPLE_COLUMN).ok_or_else(|| missing(PEOPLE_COLUMN))?;
+    let penalty_weight_index = index_of(PENALTY_WEIGHT_COLUMN);
+    let sessions_index = index_of(SESSIONS_COLUMN);
+
+    let mut constraints = Vec::new();
+    for (row, cells) in rows {
+        let error = |column: &str, message: String| {
+            XlsxImportError::on_sheet(sheet, Some(row), Some(column), message)
+        };
+
+        let people: Vec<String> = cells[people_index]
+            .split([';', ','])
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .map(str::to_string)
+            .collect();
+        if people.len() < 2 {
+            return Err(error(
+                PEOPLE_COLUMN,
+                "a pair constraint needs at least two people".to_string(),
+            ));
+        }
+        let sessions = match sessions_index {
+            Some(index) => parse_sessions(&cells[index], row)
+                .map_err(|error| XlsxImportError::from_rows(sheet, error))?,
+            None => None,
+        };
+        let penalty_weight = penalty_weight_index
+            .map(|index| cells[index].as_str())
+            .filter(|cell| !cell.is_empty());
+        let parsed_penalty_weight = || match penalty_weight {
+            Some(cell) => cell
+                .parse::<f64>()
+                .map_err(|_| error(PENALTY_WEIGHT_COLUMN, format!("'{cell}' is not a number"))),
+            None => Ok(default_constraint_weight()),
+        };
+        let hard = |constraint: Constraint| match penalty_weight {
+            Some(_) => Err(error(
+                PENALTY_WEIGHT_COLUMN,
+                format!(
+                    "{} is a hard constraint and takes no penalty_weight",
+                    constraint.type_name()
+                ),
+            )),
+            None => Ok(constraint),
+        };
+
+        let constraint_type = &cells[type_index];
+        let constraint = if constraint_type.eq_ignore_ascii_case("MustStayTogether") {
+            hard(Constraint::MustStayTogether { people, sessions })?
+        } else if constraint_type.eq_ignore_ascii_case("MustStayApart") {
+            hard(Constraint::MustStayApart { people, sessions })?
+        } else if constraint_type.eq_ignore_ascii_case("ShouldStayTogether") {
+            Constraint::ShouldStayTogether {
+                people,
+                penalty_weight: parsed_penalty_weight()?,
+                sessions,
+            }
+        } else if constraint_type.eq_ignore_ascii_case("ShouldNotBeTogether") {
+            Constraint::ShouldNotBeTogether {
+                people,
+                penalty_weight: parsed_penalty_weight()?,
+                sessions,
+            }
+        } else {
+            return Err(error(
+                TYPE_COLUMN,
+                format!(
+                    "unknown constraint type '{constraint_type}'; expected MustStayTogether, \
+                     MustStayApart, ShouldStayTogether or ShouldNotBeTogether"
+                ),
+            ));
+        };
+        constraints.push(constraint);
+    }
+    Ok(constraints)
+}
+
+/// The workbook's sheets in tab order, as names and archive paths.
+fn sheets(archive: &Archive<'_>) -> Result<Vec<(String, String)>, String> {
+    let workbook = archive
+        .read("xl/workbook.xml")?
+        .ok_or("not an .xlsx workbook (no xl/workbook.xml)")?;
+    let relationships = archive
+        .read("xl/_rels/workbook.xml.rels")?
+        .ok_or("not an .xlsx workbook (no xl/_rels/workbook.xml.rels)")?;
+
+    let mut targets = HashMap::new();
+    let mut reader = Reader::new(&relationships)?;
+    while let Some(event) = reader.next_event()? {
+        if let Event::Start {
+            name: "Relationship",
+            attributes,
+            ..
+        } = event
+        {
+            if let (Some(id), Some(target)) = (
+                attribute(&attributes, "Id"),
+                attribute(&attributes, "Target"),
+            ) {
+                let path = match target.strip_prefix('/') {
+                    Some(absolute) => absolute.to_string(),
+                    None => format!("xl/{target}"),
+                };
+                targets.insert(id.to_string(), path);
+            }
+        }
+    }
+
+    let mut sheets = Vec::new();
+    let mut reader = Reader::new(&workbook)?;
+    while let Some(event) = reader.next_event()? {
+        if let Event::Start {
+            name: "sheet",
+            attributes,
+            ..
+        } = event
+        {
+            let name = attribute(&attributes, "name").ok_or("sheet without a name")?;
+            let path = attribute(&attributes, "id")
+                .and_then(|id| targets.get(id))
+                .ok_or_else(|| format!("sheet '{name}' has no worksheet part"))?;
+            sheets.push((name.to_string(), path.clone()));
+        }
+    }
+    Ok(sheets)
+}
+
+fn shared_strings(archive: &Archive<'_>) -> Result<Vec<String>, String> {
+    let Some(xml) = archive.read("xl/sharedStrings.xml")? else {
+        return Ok(Vec::new());
+    };
+    let mut strings = Vec::new();
+    let mut reader = Reader::new(&xml)?;
+    while let Some(event) = reader.next_event()? {
+        if let Event::Start {
+            name: "si",
+            self_closing,
+            ..
+        } = event
+        {
+            strings.push(if self_closing {
+                String::new()
+            } else {
+                rich_text(&mut reader)?
+            });
+        }
+    }
+    Ok(strings)
+}
+
+/// Collects the text runs of the string item that was just started, leaving out phonetic
+/// guides.
+fn rich_text(reader: &mut Reader<'_>) -> Result<String, String> {
+    let mut text = String::new();
+    let mut depth = 0usize;
+    while let Some(event) = reader.next_event()? {
+        match event {
+            Event::Start {
+                name: "t",
+                self_closing: false,
+                ..
+            } => text.push_str(&reader.element_text()?),
+            Event::Start {
+                name: "rPh",
+                self_closing: false,
+                ..
+            } => reader.skip_element()?,
+            Event::Start {
+                self_closing: false,
+                ..
+            } => depth += 1,
+            Event::Start { .. } | Event::Text(_) => {}
+            Event::End { .. } if depth == 0 => return Ok(text),
+            Event::End { .. } => depth -= 1,
+        }
+    }
+    Err("unterminated string item".to_string())
+}
+
+/// Reads the non-blank rows of a sheet with their 1-based row numbers, each trimmed to or
+/// padded out to the width of the first (header) row.
+fn sheet_rows(
+    archive: &Archive<'_>,
+    sheet: &str,
+    path: &str,
+    shared_strings: &[String],
+) -> Result<Vec<(usize, Vec<String>)>, XlsxImportError> {
+    let sheet_error = |message: String| XlsxImportError::on_sheet(sheet, None, None, message);
+    let xml = archive
+        .read(path)
+        .map_err(sheet_error)?
+        .ok_or_else(|| sheet_error(format!("worksheet part '{path}' is missing")))?;
+    let rows = read_cells(&xml, shared_strings).map_err(sheet_error)?;
+
+    let mut rows = rows
+        .into_iter()
+        .filter(|(_, cells)| cells.iter().any(|cell| !cell.is_empty()));
+    let Some((header_row, mut header)) = rows.next() else {
+        return Ok(Vec::new());
+    };
+    while header.last().is_some_and(String::is_empty) {
+        header.pop();
+    }
+    let width = header.len();
+
+    let mut normalized = vec![(header_row, header)];
+    for (row, mut cells) in rows {
+        if let Some(index) = cells.iter().skip(width).position(|cell| !cell.is_empty()) {
+            return Err(XlsxImportError::on_sheet(
+                sheet,
+                Some(row),
+                Some(&column_letters(width + index)),
+                "value outside the header's columns",
+            ));
+        }
+        cells.resize(width, String::new());
+        normalized.push((row, cells));
+    }
+    Ok(normalized)
+}
+
+/// Reads every row of a worksheet as trimmed cell values indexed by column.
+fn read_cells(xml: &[u8], shared_strings: &[String]) -> Result<Vec<(usize, Vec<String>)>, String> {
+    let mut rows = Vec::new();
+    let mut reader = Reader::new(xml)?;
+    let mut row_number = 0;
+    let mut cells: Vec<String> = Vec::new();
+    while let Some(event) = reader.next_event()? {
+        match event {
+            Event::Start {
+                name: "row",
+                attributes,
+                self_closing,
+            } => {
+                row_number = match attribute(&attributes, "r") {
+                    Some(number) => number
+                        .parse()
+                        .map_err(|_| format!("invalid row number '{number}'"))?,
+                    None => row_number + 1,
+                };
+                if self_closing {
+                    rows.push((row_number, Vec::new()));
+                }
+            }
+            Event::End { name: "row" } => rows.push((row_number, std::mem::take(&mut cells))),
+            Event::Start {
+                name: "c",
+                attributes,
+                self_closing,
+            } => {
+                let column = match attribute(&attributes, "r") {
+                    Some(reference) => column_index(reference)
+                        .filter(|&column| column < MAX_COLUMNS)
+                        .ok_or_else(|| format!("invalid cell reference '{reference}'"))?,
+                    None => cells.len(),
+                };
+                let value = if self_closing {
+                    String::new()
+                } else {
+                    cell_value(&mut reader, attribute(&attributes, "t"), shared_strings)?
+                };
+                if cells.len() <= column {
+                    cells.resize(column + 1, String::new());
+                }
+                cells[column] = value.trim().to_string();
+            }
+            _ => {}
+        }
+    }
+    Ok(rows)
+}
+
+/// Reads the value of the cell that was just started, given its `t` (type) attribute.
+fn cell_value(
+    reader: &mut Reader<'_>,
+    cell_type: Option<&str>,
+    shared_strings: &[String],
+) -> Result<String, String> {
+    let mut value = String::new();
+    while let Some(event) = reader.next_event()? {
+        match event {
+            Event::Start {
+                name: "v",
+                self_closing: false,
+                ..
+            } => value = reader.element_text()?,
+            Event::Start {
+                name: "is",
+                self_closing: false,
+                ..
+            } => value = rich_text(reader)?,
+            Event::Start {
+                self_closing: false,
+                ..
+            } => reader.skip_element()?,
+            Event::End { .. } => break,
+            _ => {}
+        }
+    }
+
+    match cell_type {
+        Some("s") => value
+            .trim()
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| shared_strings.get(index))
+            .cloned()
+            .ok_or_else(|| format!("invalid shared string index '{value}'")),
+        Some("b") => Ok(if value.trim() == "1" { "TRUE" } else { "FALSE" }.to_string()),
+        _ => Ok(value),
+    }
+}
+
+/// Zero-based column index of a cell reference such as `AB12`.
+fn column_index(reference: &str) -> Option<usize> {
+    let letters = reference
+        .bytes()
+        .take_while(u8::is_ascii_alphabetic)
+        .collect::<Vec<_>>();
+    if letters.is_empty() {
+        return None;
+    }
+    letters
+        .iter()
+        .try_fold(0usize, |index, letter| {
+            index
+                .checked_mul(26)?
+                .checked_add(usize::from(letter.to_ascii_uppercase() - b'A') + 1)
+        })
+        .map(|index| index - 1)
+}
+
+fn column_letters(index: usize) -> String {
+    let mut letters = Vec::new();
+    let mut remaining = index + 1;
+    while remaining > 0 {
+        remaining -= 1;
+        letters.push(b'A' + (remaining % 26) as u8);
+        remaining /= 26;
+    }
+    letters.reverse();
+    String::from_utf8(letters).expect("column letters are ASCII")
+}
@@ -0,0 +1,187 @@
+//! A minimal XML reader covering what workbook parts contain: elements, attributes, text,
+//! character references, and CDATA. Namespace prefixes are dropped from names.
+
+pub(super) enum Event<'a> {
+    /// An opening tag; `self_closing` tags get no matching [`Event::End`].
+    Start {
+        name: &'a str,
+        attributes: Vec<(&'a str, String)>,
+        self_closing: bool,
+    },
+    End {
+        name: &'a str,
+    },
+    Text(String),
+}
+
+pub(super) struct Reader<'a> {
+    xml: &'a str,
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub(super) fn new(xml: &'a [u8]) -> Result<Self, String> {
+        let xml = std::str::from_utf8(xml).map_err(|_| "workbook XML is not UTF-8".to_string())?;
+        Ok(Self {
+            xml: xml.strip_prefix('\u{feff}').unwrap_or(xml),
+            position: 0,
+        })
+    }
+
+    pub(super) fn next_event(&mut self) -> Result<Option<Event<'a>>, String> {
+        loop {
+            let rest = &self.xml[self.position..];
+            if rest.is_empty() {
+                return Ok(None);
+            }
+            if !rest.starts_with('<') {
+                let len = rest.find('<').unwrap_or(rest.len());
+                self.position += len;
+                return Ok(Some(Event::Text(unescape(&rest[..len])?)));
+            }
+            if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+                let len = cdata.find("]]>").ok_or("unterminated CDATA section")?;
+                self.position += "<![CDATA[".len() + len + "]]>".len();
+                return Ok(Some(Event::Text(cdata[..len].to_string())));
+            }
+            if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+                continue;
+            }
+            if rest.starts_with("<?") || rest.starts_with("<!") {
+                self.skip_past(">")?;
+                continue;
+            }
+
+            let len = tag_len(rest).ok_or("unterminated XML tag")?;
+            self.position += len;
+            let tag = &rest[1..len - 1];
+            if let Some(name) = tag.strip_prefix('/') {
+                return Ok(Some(Event::End {
+                    name: local_name(name.trim()),
+                }));
+            }
+            let (tag, self_closing) = match tag.strip_suffix('/') {
+                Some(tag) => (tag, true),
+                None => (tag, false),
+            };
+            let name_len = tag
+                .find(|c: char| c.is_ascii_whitespace())
+                .unwrap_or(tag.len());
+            return Ok(Some(Event::Start {
+                name: local_name(&tag[..name_len]),
+                attributes: attributes(&tag[name_len..])?,
+                self_closing,
+            }));
+        }
+    }
+
+    /// Collects the text up to the end of the element that was just started, including text
+    /// in nested elements.
+    pub(super) fn element_text(&mut self) -> Result<String, String> {
+        let mut text = String::new();
+        let mut depth = 0usize;
+        while let Some(event) = self.next_event()? {
+            match event {
+                Event::Text(chunk) => text.push_str(&chunk),
+                Event::Start {
+                    self_closing: false,
+                    ..
+                } => depth += 1,
+                Event::Start { .. } => {}
+                Event::End { .. } if depth == 0 => return Ok(text),
+                Event::End { .. } => depth -= 1,
+            }
+        }
+        Err("unterminated XML element".to_string())
+    }
+
+    /// Skips to the end of the element that was just started.
+    pub(super) fn skip_element(&mut self) -> Result<(), String> {
+        self.element_text().map(drop)
+    }
+
+    fn skip_past(&mut self, terminator: &str) -> Result<(), String> {
+        let len = self.xml[self.position..]
+            .find(terminator)
+            .ok_or("unterminated XML markup")?;
+        self.position += len + terminator.len();
+        Ok(())
+    }
+}
+
+/// The value of attribute `name` among `attributes`.
+pub(super) fn attribute<'v>(attributes: &'v [(&str, String)], name: &str) -> Option<&'v str> {
+    attributes
+        .iter()
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value.as_str())
+}
+
+/// Length of the tag at the start of `text`, skipping `>` inside quoted attribute values.
+fn tag_len(text: &str) -> Option<usize> {
+    let mut quote = None;
+    for (offset, c) in text.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(open), _) if open == c => quote = None,
+            (None, '>') => return Some(offset + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn local_name(name: &str) -> &str {
+    name.rsplit(':').next().unwrap_or(name)
+}
+
+fn attributes(mut text: &str) -> Result<Vec<(&str, String)>, String> {
+    let mut attributes = Vec::new();
+    loop {
+        text = text.trim_start();
+        if text.is_empty() {
+            return Ok(attributes);
+        }
+        let (name, rest) = text.split_once('=').ok_or("malformed XML attribute")?;
+        let rest = rest.trim_start();
+        let quote = rest
+            .chars()
+            .next()
+            .filter(|c| matches!(c, '"' | '\''))
+            .ok_or("unquoted XML attribute value")?;
+        let len = rest[1..]
+            .find(quote)
+            .ok_or("unterminated XML attribute value")?;
+        attributes.push((local_name(name.trim()), unescape(&rest[1..1 + len])?));
+        text = &rest[len + 2..];
+    }
+}
+
+fn unescape(text: &str) -> Result<String, String> {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        let len = rest[start..].find(';').ok_or("unterminated XML entity")?;
+        let entity = &rest[start + 1..start + len];
+        let c = match entity {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32)
+                .ok_or_else(|| format!("unknown XML entity '&{entity};'"))?,
+        };
+        unescaped.push(c);
+        rest = &rest[start + len + 1..];
+    }
+    unescaped.push_str(rest);
+    Ok(unescaped)
+}
@@ -0,0 +1,119 @@
+//! Reading entries out of the ZIP archive an `.xlsx` workbook is stored in.
+
+use super::inflate::inflate;
+
+const END_OF_CENTRAL_DIRECTORY: u32 = 0x0605_4b50;
+const CENTRAL_DIRECTORY_ENTRY: u32 = 0x0201_4b50;
+const LOCAL_FILE_HEADER: u32 = 0x0403_4b50;
+
+const STORED: u16 = 0;
+const DEFLATED: u16 = 8;
+
+/// Largest uncompressed entry read, far above any real workbook part, so a hostile archive
+/// cannot declare its way into a multi-gigabyte allocation.
+const MAX_ENTRY_BYTES: usize = 64 * 1024 * 1024;
+
+/// A ZIP archive's file entries, located through its central directory.
+pub(super) struct Archive<'a> {
+    data: &'a [u8],
+    entries: Vec<Entry>,
+}
+
+struct Entry {
+    name: String,
+    method: u16,
+    compressed_size: usize,
+    uncompressed_size: usize,
+    local_header_offset: usize,
+}
+
+impl<'a> Archive<'a> {
+    pub(super) fn open(data: &'a [u8]) -> Result<Self, String> {
+        const NOT_A_WORKBOOK: &str = "not an .xlsx workbook (no ZIP directory found)";
+        // The end record is 22 bytes plus a comment of at most 64 KiB.
+        let search_start = data.len().saturating_sub(22 + usize::from(u16::MAX));
+        let end = (search_start..data.len().saturating_sub(21))
+            .rev()
+            .find(|&offset| read_u32(data, offset) == Some(END_OF_CENTRAL_DIRECTORY))
+            .ok_or(NOT_A_WORKBOOK)?;
+
+        let entry_count = usize::from(read_u16(data, end + 10).ok_or(NOT_A_WORKBOOK)?);
+        let mut offset = read_u32(data, end + 16).ok_or(NOT_A_WORKBOOK)? as usize;
+        if entry_count == usize::from(u16::MAX) || offset == u32::MAX as usize {
+            return Err("ZIP64 workbooks are not supported".to_string());
+        }
+
+        let mut entries = Vec::with_capacity(entry_count);
+        for _ in 0..entry_count {
+            let corrupt = || "corrupt ZIP directory".to_string();
+            if read_u32(data, offset) != Some(CENTRAL_DIRECTORY_ENTRY) {
+                return Err(corrupt());
+            }
+            let field = |at: usize| read_u16(data, offset + at).ok_or_else(corrupt);
+            let wide_field = |at: usize| read_u32(data, offset + at).ok_or_else(corrupt);
+            let name_len = usize::from(field(28)?);
+            let name = data
+                .get(offset + 46..offset + 46 + name_len)
+                .ok_or_else(corrupt)?;
+            entries.push(Entry {
+                name: String::from_utf8_lossy(name).into_owned(),
+                method: field(10)?,
+                compressed_size: wide_field(20)? as usize,
+                uncompressed_size: wide_field(24)? as usize,
+                local_header_offset: wide_field(42)? as usize,
+            });
+            offset += 46 + name_len + usize::from(field(30)?) + usize::from(field(32)?);
+        }
+        Ok(Self { data, entries })
+    }
+
+    /// The contents of the entry called `name`, or `None` when there is no such entry.
+    pub(super) fn read(&self, name: &str) -> Result<Option<Vec<u8>>, String> {
+        let Some(entry) = self.entries.iter().find(|entry| entry.name == name) else {
+            return Ok(None);
+        };
+        let corrupt = || format!("corrupt ZIP entry '{name}'");
+        if entry.uncompressed_size > MAX_ENTRY_BYTES {
+            return Err(format!(
+                "ZIP entry '{name}' is larger than {} MiB",
+                MAX_ENTRY_BYTES / (1024 * 1024)
+            ));
+        }
+        let header = entry.local_header_offset;
+        if read_u32(self.data, header) != Some(LOCAL_FILE_HEADER) {
+            return Err(corrupt());
+        }
+        let name_len = usize::from(read_u16(self.data, header + 26).ok_or_else(corrupt)?);
+        let extra_len = usize::from(read_u16(self.data, header + 28).ok_or_else(corrupt)?);
+        let start = header + 30 + name_len + extra_len;
+        let compressed = self
+            .data
+            .get(start..start.saturating_add(entry.compressed_size))
+            .ok_or_else(corrupt)?;
+
+        let contents = match entry.method {
+            STORED => compressed.to_vec(),
+            DEFLATED => inflate(compressed, entry.uncompressed_size)
+                .map_err(|message| format!("ZIP entry '{name}': {message}"))?,
+            method => {
+                return Err(format!(
+                    "ZIP entry '{name}' uses unsupported compression method {method}"
+                ))
+            }
+        };
+        if contents.len() != entry.uncompressed_size {
+            return Err(corrupt());
+        }
+        Ok(Some(contents))
+    }
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes = data.get(offset..offset.checked_add(2)?)?;
+    Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset.checked_add(4)?)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
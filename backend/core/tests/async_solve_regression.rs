@@ -0,0 +1,45 @@
+#![cfg(feature = "tokio")]
+
+use gm_core::models::{ApiInput, SolverConfiguration, SolverKind};
+use gm_core::run_solver_async;
+
+fn base_input() -> ApiInput {
+    ApiInput::builder()
+        .people((0..8).map(|idx| format!("p{idx}")))
+        .group("g0", 4)
+        .group("g1", 4)
+        .sessions(3)
+        .solver(
+            SolverConfiguration::builder(SolverKind::Solver1)
+                .seed(71)
+                .max_iterations(2_000)
+                .stop_on_optimal_score(false)
+                .build(),
+        )
+        .build()
+}
+
+#[tokio::test]
+async fn async_solve_streams_progress_and_resolves_to_the_result() {
+    let (mut progress, result) = run_solver_async(base_input());
+
+    let result = result.await.expect("solve should succeed");
+    let mut updates = Vec::new();
+    while let Some(update) = progress.recv().await {
+        updates.push(update);
+    }
+
+    assert_eq!(result.iterations_run, Some(2_000));
+    let last = updates.last().expect("at least the final update arrives");
+    assert_eq!(last.stop_reason, result.stop_reason);
+    assert_eq!(last.best_score, result.final_score);
+}
+
+#[tokio::test]
+async fn async_solve_surfaces_validation_errors() {
+    let mut input = base_input();
+    input.objectives[0].session_weights = vec![1.0];
+
+    let (_, result) = run_solver_async(input);
+    assert!(result.await.is_err());
+}
@@ -0,0 +1,182 @@
+use gm_core::models::{
+    ApiInput, ApiSchedule, BaselineMoveLimitParams, BaselineMoveLimitScope, Constraint, Group,
+    Objective, Person, ProblemDefinition, RepeatEncounterParams, SolverKind,
+};
+use gm_core::{default_solver_configuration_for, run_solver};
+use std::collections::HashMap;
+
+fn person(id: &str) -> Person {
+    Person {
+        id: id.to_string(),
+        attributes: HashMap::new(),
+        sessions: None,
+    }
+}
+
+/// Every session repeats the same split, so the unconstrained solver wants to move many people.
+fn baseline_schedule() -> ApiSchedule {
+    (0..3)
+        .map(|session| {
+            (
+                format!("session_{session}"),
+                HashMap::from([
+                    (
+                        "g0".to_string(),
+                        (0..4).map(|idx| format!("p{idx}")).collect::<Vec<_>>(),
+                    ),
+                    (
+                        "g1".to_string(),
+                        (4..8).map(|idx| format!("p{idx}")).collect::<Vec<_>>(),
+                    ),
+                ]),
+            )
+        })
+        .collect()
+}
+
+fn base_input(solver_kind: SolverKind, limit: BaselineMoveLimitParams) -> ApiInput {
+    let mut solver = default_solver_configuration_for(solver_kind);
+    solver.seed = Some(11);
+    solver.stop_conditions.max_iterations = Some(5_000);
+    solver.stop_conditions.time_limit_seconds = None;
+    solver.stop_conditions.no_improvement_iterations = None;
+
+    ApiInput {
+        initial_schedule: Some(baseline_schedule()),
+        construction_seed_schedule: None,
+        contact_history: None,
+        problem: ProblemDefinition {
+            people: (0..8).map(|idx| person(&format!("p{idx}"))).collect(),
+            groups: vec![
+                Group {
+                    id: "g0".to_string(),
+                    size: 4,
+                    session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
+                },
+                Group {
+                    id: "g1".to_string(),
+                    size: 4,
+                    session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
+                },
+            ],
+            num_sessions: 3,
+        },
+        objectives: vec![Objective {
+            r#type: "maximize_unique_contacts".to_string(),
+            weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
+        }],
+        constraints: vec![
+            Constraint::RepeatEncounter(RepeatEncounterParams {
+                max_allowed_encounters: 1,
+                penalty_function: "squared".to_string(),
+                penalty_weight: 100.0,
+            }),
+            Constraint::BaselineMoveLimit(limit),
+        ],
+        solver,
+    }
+}
+
+fn moved_people_per_session(schedule: &ApiSchedule) -> Vec<usize> {
+    let baseline = baseline_schedule();
+    (0..3)
+        .map(|session| {
+            let key = format!("session_{session}");
+            let group_of = |groups: &HashMap<String, Vec<String>>, person: &str| {
+                groups
+                    .iter()
+                    .find(|(_, members)| members.iter().any(|member| member == person))
+                    .map(|(group_id, _)| group_id.clone())
+            };
+            (0..8)
+                .filter(|idx| {
+                    let person = format!("p{idx}");
+                    group_of(&baseline[&key], &person) != group_of(&schedule[&key], &person)
+                })
+                .count()
+        })
+        .collect()
+}
+
+#[test]
+fn solver1_total_baseline_move_limit_caps_moves_across_sessions() {
+    let input = base_input(
+        SolverKind::Solver1,
+        BaselineMoveLimitParams {
+            max_moves: 2,
+            scope: BaselineMoveLimitScope::Total,
+            sessions: None,
+        },
+    );
+
+    let result = run_solver(&input).expect("solver1 should honor the baseline move limit");
+    let moved = moved_people_per_session(&result.schedule);
+
+    assert!(
+        moved.iter().sum::<usize>() <= 2,
+        "expected at most 2 moved people in total, got {moved:?}"
+    );
+}
+
+#[test]
+fn solver1_per_session_baseline_move_limit_caps_each_session() {
+    let input = base_input(
+        SolverKind::Solver1,
+        BaselineMoveLimitParams {
+            max_moves: 2,
+            scope: BaselineMoveLimitScope::PerSession,
+            sessions: Some(vec![1, 2]),
+        },
+    );
+
+    let result = run_solver(&input).expect("solver1 should honor the baseline move limit");
+    let moved = moved_people_per_session(&result.schedule);
+
+    assert!(
+        moved[1] <= 2 && moved[2] <= 2,
+        "expected at most 2 moved people in sessions 1 and 2, got {moved:?}"
+    );
+    assert!(
+        moved.iter().sum::<usize>() > 2,
+        "the uncovered session should still be free to move people, got {moved:?}"
+    );
+}
+
+#[test]
+fn baseline_move_limit_requires_initial_schedule() {
+    let mut input = base_input(
+        SolverKind::Solver1,
+        BaselineMoveLimitParams {
+            max_moves: 2,
+            scope: BaselineMoveLimitScope::Total,
+            sessions: None,
+        },
+    );
+    input.initial_schedule = None;
+
+    let error = run_solver(&input).expect_err("missing baseline should be rejected");
+    assert!(error.to_string().contains("requires an initial_schedule"));
+}
+
+#[test]
+fn solver3_rejects_baseline_move_limit_explicitly() {
+    let input = base_input(
+        SolverKind::Solver3,
+        BaselineMoveLimitParams {
+            max_moves: 2,
+            scope: BaselineMoveLimitScope::Total,
+            sessions: None,
+        },
+    );
+
+    let error = run_solver(&input).expect_err("solver3 does not model baseline move limits");
+    assert!(error
+        .to_string()
+        .contains("solver3 does not support BaselineMoveLimit"));
+}
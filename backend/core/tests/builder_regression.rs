@@ -0,0 +1,75 @@
+use gm_core::models::{
+    ApiInput, Constraint, ImmovablePeopleParams, SolverConfiguration, SolverKind,
+};
+use gm_core::{default_solver_configuration, run_solver};
+
+#[test]
+fn builder_fills_in_defaults() {
+    let input = ApiInput::builder()
+        .people(["alice", "bob"])
+        .person_with_attributes("carol", [("team", "red")])
+        .group("t1", 2)
+        .group("t2", 2)
+        .sessions(3)
+        .build();
+
+    let ids: Vec<&str> = input
+        .problem
+        .people
+        .iter()
+        .map(|person| person.id.as_str())
+        .collect();
+    assert_eq!(ids, vec!["alice", "bob", "carol"]);
+    assert_eq!(input.problem.people[2].attributes["team"], "red");
+    assert_eq!(input.problem.groups[1].id, "t2");
+    assert_eq!(input.problem.groups[1].size, 2);
+    assert_eq!(input.problem.num_sessions, 3);
+    assert_eq!(input.objectives.len(), 1);
+    assert_eq!(input.objectives[0].r#type, "maximize_unique_contacts");
+    assert_eq!(input.objectives[0].weight, 1.0);
+    assert!(input.constraints.is_empty());
+    assert_eq!(
+        input.solver.solver_type,
+        default_solver_configuration().solver_type
+    );
+}
+
+#[test]
+fn explicit_objectives_replace_the_default() {
+    let input = ApiInput::builder()
+        .objective("maximize_unique_contacts", 2.5)
+        .build();
+
+    assert_eq!(input.objectives.len(), 1);
+    assert_eq!(input.objectives[0].weight, 2.5);
+}
+
+#[test]
+fn built_input_solves() {
+    let input = ApiInput::builder()
+        .people((0..8).map(|idx| format!("p{idx}")))
+        .group("g0", 4)
+        .group("g1", 4)
+        .sessions(3)
+        .constraint(Constraint::ImmovablePeople(ImmovablePeopleParams {
+            people: vec!["p0".to_string()],
+            group_id: "g1".to_string(),
+            sessions: None,
+        }))
+        .solver(
+            SolverConfiguration::builder(SolverKind::Solver1)
+                .seed(5)
+                .max_iterations(500)
+                .stop_on_optimal_score(false)
+                .build(),
+        )
+        .build();
+
+    let result = run_solver(&input).expect("solve should succeed");
+    assert_eq!(result.effective_seed, Some(5));
+    assert_eq!(result.iterations_run, Some(500));
+    assert!(result
+        .schedule
+        .values()
+        .all(|groups| groups["g1"].contains(&"p0".to_string())));
+}
@@ -0,0 +1,83 @@
+use gm_core::models::{ApiInput, Constraint};
+use serde_json::json;
+
+fn parse(value: serde_json::Value) -> ApiInput {
+    serde_json::from_value(value).expect("input should parse")
+}
+
+fn input_json(camel_case: bool) -> serde_json::Value {
+    let key = |snake: &'static str, camel: &'static str| if camel_case { camel } else { snake };
+    let mut problem = serde_json::Map::new();
+    problem.insert(
+        "people".to_string(),
+        json!([
+            {"id": "p0", "attributes": {}},
+            {"id": "p1", "attributes": {}},
+            {"id": "p2", "attributes": {}},
+            {"id": "p3", "attributes": {}, "sessions": [0]}
+        ]),
+    );
+    problem.insert(
+        "groups".to_string(),
+        json!([
+            {"id": "g0", "size": 2},
+            {"id": "g1", "size": 2, key("session_sizes", "sessionSizes"): [2, 1]}
+        ]),
+    );
+    problem.insert(key("num_sessions", "numSessions").to_string(), json!(2));
+
+    json!({
+        "problem": problem,
+        "objectives": [{"type": "maximize_unique_contacts", "weight": 1.0}],
+        "constraints": [
+            {
+                "type": "ShouldNotBeTogether",
+                "people": ["p0", "p1"],
+                key("penalty_weight", "penaltyWeight"): 7.5
+            },
+            {
+                "type": "PairMeetingCount",
+                "people": ["p0", "p2"],
+                "sessions": [0, 1],
+                key("target_meetings", "targetMeetings"): 1,
+                key("penalty_weight", "penaltyWeight"): 3.0
+            }
+        ],
+        "solver": {
+            key("solver_type", "solverType"): "solver1",
+            key("stop_conditions", "stopConditions"): {
+                key("max_iterations", "maxIterations"): 500,
+                key("no_improvement_iterations", "noImprovementIterations"): 100
+            },
+            key("allowed_sessions", "allowedSessions"): [1],
+            "seed": 4
+        }
+    })
+}
+
+#[test]
+fn camel_case_fields_parse_like_snake_case_ones() {
+    let snake = parse(input_json(false));
+    let camel = parse(input_json(true));
+
+    assert_eq!(
+        serde_json::to_value(&camel).unwrap(),
+        serde_json::to_value(&snake).unwrap()
+    );
+    assert_eq!(camel.problem.num_sessions, 2);
+    assert_eq!(camel.solver.stop_conditions.max_iterations, Some(500));
+    assert_eq!(camel.solver.allowed_sessions, Some(vec![1]));
+    assert!(matches!(
+        camel.constraints[0],
+        Constraint::ShouldNotBeTogether { penalty_weight, .. } if penalty_weight == 7.5
+    ));
+}
+
+#[test]
+fn serialized_input_keeps_snake_case_names() {
+    let value = serde_json::to_value(parse(input_json(true))).unwrap();
+
+    assert!(value["problem"].get("num_sessions").is_some());
+    assert!(value["problem"].get("numSessions").is_none());
+    assert!(value["constraints"][0].get("penalty_weight").is_some());
+}
@@ -0,0 +1,191 @@
+use gm_core::models::{
+    ApiInput, Constraint, ConvergenceStop, Group, MoveFamilyWeights, MovePolicy, MoveSelectionMode,
+    Objective, PenaltyRampParams, Person, ProblemDefinition, ProgressCallback, ProgressControl,
+    ProgressControlCallback, RepeatEncounterParams, SolverCheckpoint, SolverKind, SolverParams,
+    SolverResult,
+};
+use gm_core::{
+    default_solver_configuration_for, resume_from_checkpoint, resume_from_checkpoint_with_control,
+    run_solver_with_progress,
+};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+fn base_input(solver_kind: SolverKind) -> ApiInput {
+    let mut solver = default_solver_configuration_for(solver_kind);
+    solver.seed = Some(61);
+    solver.stop_conditions.max_iterations = Some(1_000);
+    solver.stop_conditions.time_limit_seconds = None;
+    solver.stop_conditions.no_improvement_iterations = None;
+    solver.stop_conditions.stop_on_optimal_score = false;
+    solver.telemetry.checkpoint_every_n_iterations = 200;
+
+    ApiInput {
+        initial_schedule: None,
+        construction_seed_schedule: None,
+        contact_history: None,
+        problem: ProblemDefinition {
+            people: (0..12)
+                .map(|idx| Person {
+                    id: format!("p{idx}"),
+                    attributes: HashMap::new(),
+                    sessions: None,
+                })
+                .collect(),
+            groups: (0..3)
+                .map(|idx| Group {
+                    id: format!("g{idx}"),
+                    size: 4,
+                    session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
+                })
+                .collect(),
+            num_sessions: 3,
+        },
+        objectives: vec![Objective {
+            r#type: "maximize_unique_contacts".to_string(),
+            weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
+        }],
+        constraints: vec![Constraint::RepeatEncounter(RepeatEncounterParams {
+            max_allowed_encounters: 1,
+            penalty_function: "squared".to_string(),
+            penalty_weight: 10.0,
+        })],
+        solver,
+    }
+}
+
+fn run_collecting_checkpoints(input: &ApiInput) -> (SolverResult, Vec<SolverCheckpoint>) {
+    let checkpoints = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&checkpoints);
+    let progress: ProgressCallback = Box::new(move |update| {
+        if let Some(checkpoint) = &update.checkpoint {
+            sink.lock().unwrap().push(checkpoint.clone());
+        }
+        true
+    });
+
+    let result = run_solver_with_progress(input, Some(&progress)).expect("solve should succeed");
+    let checkpoints = checkpoints.lock().unwrap().clone();
+    (result, checkpoints)
+}
+
+#[test]
+fn checkpoints_are_emitted_at_the_configured_interval() {
+    let (_, checkpoints) = run_collecting_checkpoints(&base_input(SolverKind::Solver1));
+
+    let iterations: Vec<u64> = checkpoints
+        .iter()
+        .map(|checkpoint| checkpoint.iterations_completed)
+        .collect();
+    assert_eq!(iterations, vec![200, 400, 600, 800]);
+    assert!(checkpoints
+        .iter()
+        .all(|checkpoint| checkpoint.effective_seed == 61));
+}
+
+#[test]
+fn resumed_run_matches_the_uninterrupted_run() {
+    let input = base_input(SolverKind::Solver1);
+    let (uninterrupted, checkpoints) = run_collecting_checkpoints(&input);
+
+    // Round-trip through JSON, as a checkpoint written to disk would.
+    let json = serde_json::to_string(&checkpoints[1]).expect("checkpoint serializes");
+    let checkpoint: SolverCheckpoint = serde_json::from_str(&json).expect("checkpoint parses");
+    assert_eq!(checkpoint.iterations_completed, 400);
+
+    let resumed = resume_from_checkpoint(&input, &checkpoint).expect("resume should succeed");
+    assert_eq!(resumed.schedule, uninterrupted.schedule);
+    assert_eq!(resumed.final_score, uninterrupted.final_score);
+    assert_eq!(resumed.iterations_run, Some(1_000));
+}
+
+#[test]
+fn resumed_run_keeps_move_policy_ramp_and_convergence_window() {
+    let mut input = base_input(SolverKind::Solver1);
+    input.solver.move_policy = Some(MovePolicy {
+        mode: MoveSelectionMode::Weighted,
+        weights: Some(MoveFamilyWeights {
+            swap: 1.0,
+            transfer: 3.0,
+            clique_swap: 0.0,
+        }),
+        ..MovePolicy::default()
+    });
+    if let SolverParams::SimulatedAnnealing(params) = &mut input.solver.solver_params {
+        // Hot enough that uphill moves are accepted, so the window's rate depends on its start.
+        params.initial_temperature = 30.0;
+        params.final_temperature = 5.0;
+        params.penalty_ramp = Some(PenaltyRampParams {
+            initial_scale: 0.1,
+            ramp_fraction: 0.8,
+        });
+    }
+    // Windows close at 300, 600, and 900, so the checkpoint at 400 splits one.
+    input.solver.stop_conditions.convergence = Some(ConvergenceStop {
+        window_iterations: 300,
+        max_acceptance_rate: 0.22,
+        max_temperature: None,
+    });
+    let (uninterrupted, checkpoints) = run_collecting_checkpoints(&input);
+    let checkpoint = checkpoints
+        .iter()
+        .find(|checkpoint| checkpoint.iterations_completed == 400)
+        .expect("checkpoint at 400");
+    assert!(checkpoint.convergence_window_uphill_proposed > 0);
+    assert!(checkpoint.temperature.is_some());
+
+    // The move policy comes from the checkpoint, not from the resuming input.
+    let mut resume_input = input.clone();
+    resume_input.solver.move_policy = None;
+    let resumed = resume_from_checkpoint(&resume_input, checkpoint).expect("resume should succeed");
+    assert_eq!(resumed.schedule, uninterrupted.schedule);
+    assert_eq!(resumed.final_score, uninterrupted.final_score);
+    assert_eq!(resumed.stop_reason, uninterrupted.stop_reason);
+    assert_eq!(resumed.iterations_run, uninterrupted.iterations_run);
+    assert_eq!(resumed.move_policy, uninterrupted.move_policy);
+}
+
+#[test]
+fn resumed_run_saves_checkpoints_on_request() {
+    let input = base_input(SolverKind::Solver1);
+    let (uninterrupted, checkpoints) = run_collecting_checkpoints(&input);
+
+    let requested: Arc<Mutex<Vec<SolverCheckpoint>>> = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&requested);
+    let controller: ProgressControlCallback = Box::new(move |update| {
+        if let Some(checkpoint) = &update.checkpoint {
+            sink.lock().unwrap().push(checkpoint.clone());
+        }
+        if sink.lock().unwrap().is_empty() {
+            ProgressControl::SaveCheckpoint
+        } else {
+            ProgressControl::Continue
+        }
+    });
+    // Only the controller asks for checkpoints in the resumed run.
+    let mut resume_input = input.clone();
+    resume_input.solver.telemetry.checkpoint_every_n_iterations = 0;
+    let resumed =
+        resume_from_checkpoint_with_control(&resume_input, &checkpoints[1], Some(&controller))
+            .expect("resume should succeed");
+
+    assert_eq!(resumed.schedule, uninterrupted.schedule);
+    let requested = requested.lock().unwrap();
+    assert_eq!(requested.len(), 1);
+    assert!(requested[0].iterations_completed > 400);
+}
+
+#[test]
+fn resume_requires_solver1() {
+    let (_, checkpoints) = run_collecting_checkpoints(&base_input(SolverKind::Solver1));
+
+    let error = resume_from_checkpoint(&base_input(SolverKind::Solver3), &checkpoints[0])
+        .expect_err("solver3 cannot resume solver1 checkpoints");
+    assert!(error
+        .to_string()
+        .contains("resuming from a checkpoint is only supported by solver1"));
+}
@@ -40,12 +40,15 @@ pub fn create_simple_input(
             id: format!("g{}", i),
             size: group_size,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         })
         .collect();
 
     ApiInput {
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people,
             groups,
@@ -84,12 +87,15 @@ pub fn create_input_with_attributes(
             id: id.to_string(),
             size,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         })
         .collect();
 
     ApiInput {
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people,
             groups,
@@ -114,6 +120,9 @@ pub fn default_solver_config(max_iterations: u64) -> SolverConfiguration {
             time_limit_seconds: None,
             no_improvement_iterations: None,
             stop_on_optimal_score: true,
+            stop_on_feasible: false,
+            feasible_score_threshold: None,
+            convergence: None,
         },
         solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
             initial_temperature: 10.0,
@@ -121,6 +130,7 @@ pub fn default_solver_config(max_iterations: u64) -> SolverConfiguration {
             cooling_schedule: "geometric".to_string(),
             reheat_after_no_improvement: Some(0),
             reheat_cycles: Some(0),
+            penalty_ramp: None,
         }),
         logging: Default::default(),
         telemetry: Default::default(),
@@ -0,0 +1,208 @@
+use gm_core::builder::ProblemBuilder;
+use gm_core::models::{
+    ApiInput, Constraint, PairMeetingCountParams, PairMeetingMode, SolverConfiguration, SolverKind,
+};
+use gm_core::solver::SolverError;
+use gm_core::solver1::State;
+
+fn problem() -> ProblemBuilder {
+    ApiInput::builder()
+        .people((0..8).map(|idx| format!("p{idx}")))
+        .group("g0", 4)
+        .group("g1", 4)
+        .sessions(3)
+        .objective("maximize_unique_contacts", 1.0)
+        .solver(
+            SolverConfiguration::builder(SolverKind::Solver1)
+                .seed(5)
+                .build(),
+        )
+        .constraint(Constraint::ShouldStayTogether {
+            people: vec!["p0".to_string(), "p1".to_string()],
+            penalty_weight: 20.0,
+            sessions: None,
+        })
+}
+
+fn people(ids: &[&str]) -> Vec<String> {
+    ids.iter().map(|id| id.to_string()).collect()
+}
+
+/// The state `State::new` builds for `input` plus `extra`, on the schedule of `state`.
+fn rebuilt(input: &ApiInput, state: &State, extra: &Constraint) -> State {
+    let mut input = input.clone();
+    input.constraints.push(extra.clone());
+    input.initial_schedule = Some(state.api_schedule());
+    State::new(&input).expect("rebuilt state")
+}
+
+fn assert_same_score(edited: &State, expected: &State) {
+    assert!(
+        (edited.current_cost - expected.current_cost).abs() < 1e-9,
+        "cost {} != {}",
+        edited.current_cost,
+        expected.current_cost
+    );
+    assert_eq!(edited.constraint_penalty, expected.constraint_penalty);
+    assert!(
+        (edited.weighted_constraint_penalty - expected.weighted_constraint_penalty).abs() < 1e-9
+    );
+}
+
+#[test]
+fn added_pair_constraints_score_like_a_rebuilt_state() {
+    let input = problem().build();
+    let extras = [
+        Constraint::ShouldNotBeTogether {
+            people: people(&["p2", "p3", "p4"]),
+            penalty_weight: 7.5,
+            sessions: Some(vec![0, 2]),
+        },
+        Constraint::ShouldStayTogether {
+            people: people(&["p5", "p6"]),
+            penalty_weight: 3.0,
+            sessions: None,
+        },
+        Constraint::PairMeetingCount(PairMeetingCountParams {
+            people: people(&["p2", "p7"]),
+            sessions: vec![],
+            target_meetings: 3,
+            mode: PairMeetingMode::AtLeast,
+            penalty_weight: 4.0,
+        }),
+    ];
+
+    for extra in &extras {
+        let mut state = State::new(&input).expect("state");
+        state.add_constraint(extra).expect("add");
+        let expected = rebuilt(&input, &state, extra);
+        assert_same_score(&state, &expected);
+        assert_eq!(
+            state.constraint_penalties(),
+            expected.constraint_penalties()
+        );
+    }
+}
+
+#[test]
+fn swap_deltas_see_added_and_removed_pair_constraints() {
+    let input = problem().build();
+    let extra = Constraint::PairMeetingCount(PairMeetingCountParams {
+        people: people(&["p0", "p3"]),
+        sessions: vec![],
+        target_meetings: 2,
+        mode: PairMeetingMode::Exact,
+        penalty_weight: 6.0,
+    });
+    let mut state = State::new(&input).expect("state");
+    state.add_constraint(&extra).expect("add");
+    let expected = rebuilt(&input, &state, &extra);
+
+    for day in 0..3 {
+        for p1 in 0..8 {
+            for p2 in p1 + 1..8 {
+                if state.locations[day][p1].0 == state.locations[day][p2].0 {
+                    continue;
+                }
+                let edited = state.calculate_swap_cost_delta(day, p1, p2);
+                let rebuilt = expected.calculate_swap_cost_delta(day, p1, p2);
+                assert!(
+                    (edited - rebuilt).abs() < 1e-9,
+                    "day {day} swap {p1}<->{p2}: {edited} != {rebuilt}"
+                );
+            }
+        }
+    }
+
+    state.remove_constraint(&extra).expect("remove");
+    let original = State::new(&{
+        let mut input = input.clone();
+        input.initial_schedule = Some(state.api_schedule());
+        input
+    })
+    .expect("original");
+    for p2 in 1..8 {
+        if state.locations[0][0].0 != state.locations[0][p2].0 {
+            assert!(
+                (state.calculate_swap_cost_delta(0, 0, p2)
+                    - original.calculate_swap_cost_delta(0, 0, p2))
+                .abs()
+                    < 1e-9
+            );
+        }
+    }
+}
+
+#[test]
+fn removing_an_added_constraint_restores_the_score() {
+    let input = problem().build();
+    let mut state = State::new(&input).expect("state");
+    let original_cost = state.current_cost;
+    let extra = Constraint::ShouldNotBeTogether {
+        people: people(&["p2", "p3"]),
+        penalty_weight: 50.0,
+        sessions: None,
+    };
+
+    state.add_constraint(&extra).expect("add");
+    state.remove_constraint(&extra).expect("remove");
+
+    assert!((state.current_cost - original_cost).abs() < 1e-9);
+    assert!(state.soft_apart_pairs.is_empty());
+}
+
+#[test]
+fn constraints_from_the_input_can_be_removed() {
+    let input = problem().build();
+    let mut state = State::new(&input).expect("state");
+
+    state
+        .remove_constraint(&input.constraints[0])
+        .expect("remove");
+
+    assert!(state.should_together_pairs.is_empty());
+    assert!(state.should_together_violations.is_empty());
+    assert_eq!(
+        state
+            .remove_constraint(&input.constraints[0])
+            .map_err(|e| e.message()),
+        Err(
+            "ShouldStayTogether constraint to remove does not match any constraint of this state"
+                .to_string()
+        )
+    );
+}
+
+#[test]
+fn conflicting_addition_leaves_the_state_unchanged() {
+    let input = problem().build();
+    let mut state = State::new(&input).expect("state");
+    let cost = state.current_cost;
+
+    let error = state
+        .add_constraint(&Constraint::ShouldNotBeTogether {
+            people: people(&["p1", "p0"]),
+            penalty_weight: 5.0,
+            sessions: None,
+        })
+        .unwrap_err();
+
+    assert!(matches!(error, SolverError::ConflictingConstraints { .. }));
+    assert!(state.soft_apart_pairs.is_empty());
+    assert_eq!(state.current_cost, cost);
+}
+
+#[test]
+fn structural_constraints_need_a_new_state() {
+    let input = problem().build();
+    let mut state = State::new(&input).expect("state");
+
+    let error = state
+        .add_constraint(&Constraint::MustStayTogether {
+            people: people(&["p2", "p3"]),
+            sessions: None,
+        })
+        .unwrap_err();
+
+    assert!(error.to_string().contains("rebuild it with State::new"));
+}
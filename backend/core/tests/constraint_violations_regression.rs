@@ -0,0 +1,128 @@
+use gm_core::models::{
+    ApiInput, Constraint, ConstraintViolation, Group, Objective, Person, ProblemDefinition,
+    SolverKind,
+};
+use gm_core::{default_solver_configuration_for, run_solver};
+use std::collections::HashMap;
+
+fn base_input(solver_kind: SolverKind) -> ApiInput {
+    let mut solver = default_solver_configuration_for(solver_kind);
+    solver.seed = Some(17);
+    solver.stop_conditions.max_iterations = Some(5_000);
+    solver.stop_conditions.time_limit_seconds = None;
+    solver.stop_conditions.no_improvement_iterations = None;
+
+    ApiInput {
+        initial_schedule: None,
+        construction_seed_schedule: None,
+        contact_history: None,
+        problem: ProblemDefinition {
+            people: (0..4)
+                .map(|idx| Person {
+                    id: format!("p{idx}"),
+                    attributes: HashMap::new(),
+                    sessions: None,
+                })
+                .collect(),
+            groups: (0..2)
+                .map(|idx| Group {
+                    id: format!("g{idx}"),
+                    size: 2,
+                    session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
+                })
+                .collect(),
+            num_sessions: 1,
+        },
+        objectives: vec![Objective {
+            r#type: "maximize_unique_contacts".to_string(),
+            weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
+        }],
+        // Three people across two groups of two: one pair always ends up together.
+        constraints: vec![Constraint::ShouldNotBeTogether {
+            people: vec!["p0".to_string(), "p1".to_string(), "p2".to_string()],
+            penalty_weight: 50.0,
+            sessions: None,
+        }],
+        solver,
+    }
+}
+
+fn assert_single_apart_violation(violations: &[ConstraintViolation]) {
+    assert_eq!(violations.len(), 1, "{violations:?}");
+    let violation = &violations[0];
+    assert_eq!(violation.constraint_type, "ShouldNotBeTogether");
+    assert_eq!(violation.session, Some(0));
+    assert_eq!(violation.people.len(), 2);
+    assert!(violation
+        .people
+        .iter()
+        .all(|person| ["p0", "p1", "p2"].contains(&person.as_str())));
+    assert_eq!(violation.groups.len(), 1);
+    assert_eq!(violation.weighted_penalty, 50.0);
+}
+
+#[test]
+fn solver1_itemizes_the_unavoidable_violation() {
+    let result = run_solver(&base_input(SolverKind::Solver1)).expect("solver1 should solve");
+
+    let violations = result
+        .constraint_violations
+        .expect("solver1 reports constraint violations");
+    assert_single_apart_violation(&violations);
+    assert_eq!(result.weighted_constraint_penalty, 50.0);
+}
+
+#[test]
+fn solver3_results_are_rescored_into_violations() {
+    let result = run_solver(&base_input(SolverKind::Solver3)).expect("solver3 should solve");
+
+    let violations = result
+        .constraint_violations
+        .expect("violations are filled in by rescoring the final schedule");
+    assert_single_apart_violation(&violations);
+}
+
+#[test]
+fn feasible_schedule_reports_an_empty_list() {
+    let mut input = base_input(SolverKind::Solver1);
+    input.constraints.clear();
+
+    let result = run_solver(&input).expect("solver1 should solve");
+    assert_eq!(result.constraint_violations, Some(Vec::new()));
+}
+
+#[test]
+fn uneven_group_sizes_are_itemized_per_session() {
+    let mut input = base_input(SolverKind::Solver1);
+    input.constraints.clear();
+    input.problem.people.push(Person {
+        id: "p4".to_string(),
+        attributes: HashMap::new(),
+        sessions: None,
+    });
+    for group in &mut input.problem.groups {
+        group.size = 3;
+    }
+    input.objectives.push(Objective {
+        r#type: "balance_group_sizes".to_string(),
+        weight: 2.0,
+        person_weights: HashMap::new(),
+        session_weights: Vec::new(),
+    });
+
+    let result = run_solver(&input).expect("solver1 should solve");
+    let violations = result
+        .constraint_violations
+        .expect("solver1 reports violations");
+
+    // Five people in two groups: sizes 3 and 2 leave a spread of 13 - 25 / 2 = 0.5.
+    assert_eq!(violations.len(), 1, "{violations:?}");
+    assert_eq!(violations[0].constraint_type, "balance_group_sizes");
+    assert_eq!(violations[0].session, Some(0));
+    assert_eq!(violations[0].groups, vec!["g0", "g1"]);
+    assert_eq!(violations[0].weighted_penalty, 1.0);
+}
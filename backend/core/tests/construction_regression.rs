@@ -5,6 +5,7 @@ use gm_core::models::{
     ApiInput, Constraint, Group, ImmovablePersonParams, Objective, Person, ProblemDefinition,
 };
 use gm_core::solver1::State;
+use gm_core::solver_support::SolverError;
 use std::collections::HashMap;
 
 fn person(id: &str) -> Person {
@@ -30,6 +31,7 @@ fn construction_input() -> ApiInput {
     ApiInput {
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people: vec![
                 person("p0"),
@@ -44,16 +46,22 @@ fn construction_input() -> ApiInput {
                     id: "g0".to_string(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g1".to_string(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g2".to_string(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 2,
@@ -61,6 +69,8 @@ fn construction_input() -> ApiInput {
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![
             Constraint::MustStayTogether {
@@ -127,6 +137,7 @@ fn construction_assigns_clique_ids_in_sorted_member_order() {
     let input = ApiInput {
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people: vec![
                 person("p0"),
@@ -141,16 +152,22 @@ fn construction_assigns_clique_ids_in_sorted_member_order() {
                     id: "g0".to_string(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g1".to_string(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g2".to_string(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 1,
@@ -158,6 +175,8 @@ fn construction_assigns_clique_ids_in_sorted_member_order() {
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![
             Constraint::MustStayTogether {
@@ -203,6 +222,7 @@ fn construction_propagates_immovable_assignment_to_clique_members() {
     let input = ApiInput {
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people: vec![person("p0"), person("p1"), person("p2"), person("p3")],
             groups: vec![
@@ -210,11 +230,15 @@ fn construction_propagates_immovable_assignment_to_clique_members() {
                     id: "g0".to_string(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g1".to_string(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 2,
@@ -222,6 +246,8 @@ fn construction_propagates_immovable_assignment_to_clique_members() {
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![
             Constraint::MustStayTogether {
@@ -259,6 +285,7 @@ fn construction_handles_sparse_participation_for_partially_active_clique() {
     let input = ApiInput {
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people: vec![
                 person_with_sessions("p0", vec![0, 1]),
@@ -271,11 +298,15 @@ fn construction_handles_sparse_participation_for_partially_active_clique() {
                     id: "g0".to_string(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g1".to_string(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 2,
@@ -283,6 +314,8 @@ fn construction_handles_sparse_participation_for_partially_active_clique() {
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![Constraint::MustStayTogether {
             people: vec!["p0".to_string(), "p1".to_string()],
@@ -309,6 +342,7 @@ fn construction_hard_fails_when_immovable_constraints_overfill_group() {
     let input = ApiInput {
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people: vec![person("p0"), person("p1")],
             groups: vec![
@@ -316,11 +350,15 @@ fn construction_hard_fails_when_immovable_constraints_overfill_group() {
                     id: "g0".to_string(),
                     size: 1,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g1".to_string(),
                     size: 1,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 1,
@@ -328,6 +366,8 @@ fn construction_hard_fails_when_immovable_constraints_overfill_group() {
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![
             Constraint::ImmovablePerson(ImmovablePersonParams {
@@ -361,6 +401,7 @@ fn construction_hard_fails_when_active_clique_cannot_fit_any_group() {
     let input = ApiInput {
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people: vec![person("p0"), person("p1"), person("p2")],
             groups: vec![
@@ -368,11 +409,15 @@ fn construction_hard_fails_when_active_clique_cannot_fit_any_group() {
                     id: "g0".to_string(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g1".to_string(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 1,
@@ -380,6 +425,8 @@ fn construction_hard_fails_when_active_clique_cannot_fit_any_group() {
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![Constraint::MustStayTogether {
             people: vec!["p0".to_string(), "p1".to_string(), "p2".to_string()],
@@ -388,11 +435,14 @@ fn construction_hard_fails_when_active_clique_cannot_fit_any_group() {
         solver,
     };
 
-    let error = State::new(&input)
-        .expect_err("state construction should fail")
-        .to_string();
-    assert!(
-        error.contains("Could not place clique"),
-        "unexpected validation error: {error}"
+    let error = State::new(&input).expect_err("state construction should fail");
+    assert_eq!(
+        error,
+        SolverError::CliqueTooLarge {
+            people: vec!["p0".to_string(), "p1".to_string(), "p2".to_string()],
+            size: 3,
+            max_group_size: 2,
+            session: 0,
+        }
     );
 }
@@ -0,0 +1,72 @@
+use gm_core::models::{
+    ApiInput, Group, Objective, PairContactCount, Person, ProblemDefinition, SolverKind,
+};
+use gm_core::{default_solver_configuration_for, run_solver};
+use std::collections::HashMap;
+
+fn base_input(solver_kind: SolverKind) -> ApiInput {
+    let mut solver = default_solver_configuration_for(solver_kind);
+    solver.seed = Some(31);
+    solver.stop_conditions.max_iterations = Some(5_000);
+    solver.stop_conditions.time_limit_seconds = None;
+    solver.stop_conditions.no_improvement_iterations = None;
+
+    ApiInput {
+        initial_schedule: None,
+        construction_seed_schedule: None,
+        contact_history: None,
+        problem: ProblemDefinition {
+            people: (0..4)
+                .map(|idx| Person {
+                    id: format!("p{idx}"),
+                    attributes: HashMap::new(),
+                    sessions: None,
+                })
+                .collect(),
+            groups: (0..2)
+                .map(|idx| Group {
+                    id: format!("g{idx}"),
+                    size: 2,
+                    session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
+                })
+                .collect(),
+            num_sessions: 4,
+        },
+        objectives: vec![Objective {
+            r#type: "maximize_unique_contacts".to_string(),
+            weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
+        }],
+        constraints: Vec::new(),
+        solver,
+    }
+}
+
+#[test]
+fn contact_matrix_is_omitted_by_default() {
+    let result = run_solver(&base_input(SolverKind::Solver1)).expect("solver1 should solve");
+    assert_eq!(result.contact_matrix, None);
+}
+
+#[test]
+fn contact_matrix_lists_every_pair_that_met() {
+    for solver_kind in [SolverKind::Solver1, SolverKind::Solver3] {
+        let mut input = base_input(solver_kind);
+        input.solver.telemetry.include_contact_matrix = true;
+        let result = run_solver(&input).expect("solve should succeed");
+
+        let matrix: Vec<PairContactCount> =
+            result.contact_matrix.expect("contact matrix was requested");
+        assert_eq!(matrix.len(), result.unique_contacts as usize);
+        // Four sessions of two pairs each: eight meetings in total.
+        assert_eq!(matrix.iter().map(|pair| pair.count).sum::<u32>(), 8);
+        assert!(matrix
+            .windows(2)
+            .all(|pairs| (&pairs[0].person_a, &pairs[0].person_b)
+                < (&pairs[1].person_a, &pairs[1].person_b)));
+        assert!(matrix.iter().all(|pair| pair.person_a < pair.person_b));
+    }
+}
@@ -8,10 +8,12 @@ use gm_core::models::{
 };
 use gm_core::solver1::search::simulated_annealing::SimulatedAnnealing;
 use gm_core::solver1::State;
+use gm_core::solver_support::SolverError;
 use gm_core::{
     run_solver, run_solver_with_benchmark_observer, run_solver_with_callbacks,
     run_solver_with_progress,
 };
+use smallvec::smallvec;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
@@ -19,6 +21,7 @@ fn basic_input() -> ApiInput {
     ApiInput {
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people: vec![
                 Person {
@@ -47,11 +50,15 @@ fn basic_input() -> ApiInput {
                     id: "g0".to_string(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g1".to_string(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 2,
@@ -59,6 +66,8 @@ fn basic_input() -> ApiInput {
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![Constraint::RepeatEncounter(RepeatEncounterParams {
             max_allowed_encounters: 1,
@@ -72,6 +81,9 @@ fn basic_input() -> ApiInput {
                 time_limit_seconds: None,
                 no_improvement_iterations: Some(100),
                 stop_on_optimal_score: true,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
                 initial_temperature: 5.0,
@@ -79,6 +91,7 @@ fn basic_input() -> ApiInput {
                 cooling_schedule: "geometric".to_string(),
                 reheat_after_no_improvement: Some(0),
                 reheat_cycles: Some(0),
+                penalty_ramp: None,
             }),
             logging: LoggingOptions::default(),
             telemetry: Default::default(),
@@ -125,6 +138,8 @@ fn transfer_input() -> ApiInput {
         id: "g2".to_string(),
         size: 2,
         session_sizes: None,
+        attributes: HashMap::new(),
+        available_sessions: None,
     });
     input
 }
@@ -154,6 +169,8 @@ fn insufficient_group_capacity_is_rejected_with_clear_error() {
         id: "tiny".to_string(),
         size: 2,
         session_sizes: None,
+        attributes: HashMap::new(),
+        available_sessions: None,
     }];
 
     let error = State::new(&input).unwrap_err().to_string();
@@ -194,11 +211,19 @@ fn invalid_person_session_equal_to_num_sessions_is_rejected() {
     let mut input = basic_input();
     input.problem.people[0].sessions = Some(vec![2]);
 
-    let error = State::new(&input).unwrap_err().to_string();
+    let error = State::new(&input).unwrap_err();
     assert!(
-        error.contains("invalid session index: 2 (max: 1)"),
+        matches!(
+            error,
+            SolverError::InvalidSession {
+                session: 2,
+                num_sessions: 2,
+                ..
+            }
+        ),
         "{error}"
     );
+    assert!(error.to_string().contains("invalid session 2 (max: 1)"));
 }
 
 #[test]
@@ -587,6 +612,7 @@ fn display_helpers_prefer_name_and_fallback_cleanly() {
     let input = ApiInput {
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people: vec![
                 Person {
@@ -604,6 +630,8 @@ fn display_helpers_prefer_name_and_fallback_cleanly() {
                 id: "g0".to_string(),
                 size: 2,
                 session_sizes: None,
+                attributes: HashMap::new(),
+                available_sessions: None,
             }],
             num_sessions: 1,
         },
@@ -735,7 +763,10 @@ fn validate_hard_constraints_reports_must_stay_apart_violation() {
     });
 
     let mut state = State::new(&input).unwrap();
-    state.schedule = vec![vec![vec![0, 1], vec![2, 3]], vec![vec![0, 2], vec![1, 3]]];
+    state.schedule = vec![
+        vec![smallvec![0, 1], smallvec![2, 3]],
+        vec![smallvec![0, 2], smallvec![1, 3]],
+    ];
     state._recalculate_locations_from_schedule();
 
     let error = state
@@ -791,6 +822,9 @@ fn simulated_annealing_auto_reheat_defaults_without_no_improvement_limit() {
             time_limit_seconds: None,
             no_improvement_iterations: None,
             stop_on_optimal_score: true,
+            stop_on_feasible: false,
+            feasible_score_threshold: None,
+            convergence: None,
         },
         solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
             initial_temperature: 10.0,
@@ -798,6 +832,7 @@ fn simulated_annealing_auto_reheat_defaults_without_no_improvement_limit() {
             cooling_schedule: "geometric".to_string(),
             reheat_after_no_improvement: None,
             reheat_cycles: Some(0),
+            penalty_ramp: None,
         }),
         logging: LoggingOptions::default(),
         telemetry: Default::default(),
@@ -819,6 +854,9 @@ fn simulated_annealing_auto_reheat_is_bounded_by_no_improvement_limit() {
             time_limit_seconds: None,
             no_improvement_iterations: Some(120),
             stop_on_optimal_score: true,
+            stop_on_feasible: false,
+            feasible_score_threshold: None,
+            convergence: None,
         },
         solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
             initial_temperature: 10.0,
@@ -826,6 +864,7 @@ fn simulated_annealing_auto_reheat_is_bounded_by_no_improvement_limit() {
             cooling_schedule: "geometric".to_string(),
             reheat_after_no_improvement: None,
             reheat_cycles: Some(0),
+            penalty_ramp: None,
         }),
         logging: LoggingOptions::default(),
         telemetry: Default::default(),
@@ -847,6 +886,9 @@ fn simulated_annealing_keeps_explicit_zero_reheat_disabled() {
             time_limit_seconds: None,
             no_improvement_iterations: Some(250),
             stop_on_optimal_score: true,
+            stop_on_feasible: false,
+            feasible_score_threshold: None,
+            convergence: None,
         },
         solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
             initial_temperature: 10.0,
@@ -854,6 +896,7 @@ fn simulated_annealing_keeps_explicit_zero_reheat_disabled() {
             cooling_schedule: "geometric".to_string(),
             reheat_after_no_improvement: Some(0),
             reheat_cycles: Some(0),
+            penalty_ramp: None,
         }),
         logging: LoggingOptions::default(),
         telemetry: Default::default(),
@@ -0,0 +1,80 @@
+use gm_core::csv_import::{people_from_csv, CsvImportError};
+
+fn error(csv: &str) -> CsvImportError {
+    people_from_csv(csv).expect_err("import should fail")
+}
+
+#[test]
+fn columns_become_ids_attributes_and_sessions() {
+    let people = people_from_csv(
+        "\u{feff}id, team ,role,sessions\r\n\
+         alice,red,lead,\r\n\
+         \r\n\
+         bob , blue,,0;2\r\n\
+         \"Smith, Carol\",red,\"says \"\"hi\"\"\",1 3\r\n",
+    )
+    .expect("valid CSV");
+
+    let ids: Vec<_> = people.iter().map(|person| person.id.as_str()).collect();
+    assert_eq!(ids, ["alice", "bob", "Smith, Carol"]);
+    assert_eq!(people[0].attributes.len(), 2);
+    assert_eq!(people[0].attributes["team"], "red");
+    assert_eq!(people[0].sessions, None);
+    assert!(!people[1].attributes.contains_key("role"));
+    assert_eq!(people[1].sessions, Some(vec![0, 2]));
+    assert_eq!(people[2].attributes["role"], "says \"hi\"");
+    assert_eq!(people[2].sessions, Some(vec![1, 3]));
+}
+
+#[test]
+fn quoted_fields_may_span_lines() {
+    let people = people_from_csv("id,note\nalice,\"first\nsecond\"\nbob,x\n").expect("valid CSV");
+
+    assert_eq!(people[0].attributes["note"], "first\nsecond");
+    assert_eq!(people[1].id, "bob");
+}
+
+#[test]
+fn errors_name_the_line_and_column() {
+    let duplicate = error("id,team\nalice,red\nbob,blue\nalice,green\n");
+    assert_eq!(duplicate.line, 4);
+    assert_eq!(duplicate.column.as_deref(), Some("id"));
+    assert_eq!(
+        duplicate.to_string(),
+        "line 4, column 'id': duplicate person id 'alice', first used on line 2"
+    );
+
+    let session = error("id,sessions\nalice,0;x\n");
+    assert_eq!(
+        session.to_string(),
+        "line 2, column 'sessions': 'x' is not a session index"
+    );
+
+    // Line numbers count the lines inside multi-line quoted fields.
+    let ragged = error("id,note\nalice,\"a\nb\"\nbob\n");
+    assert_eq!(
+        ragged.to_string(),
+        "line 4: expected 2 fields as in the header, found 1"
+    );
+}
+
+#[test]
+fn malformed_documents_are_rejected() {
+    assert_eq!(error("").to_string(), "line 1: missing header row");
+    assert_eq!(
+        error("name,team\nalice,red\n").to_string(),
+        "line 1: missing 'id' column"
+    );
+    assert_eq!(
+        error("id,team,team\n").to_string(),
+        "line 1, column 'team': duplicate column name"
+    );
+    assert_eq!(
+        error("id\n\"alice\n").to_string(),
+        "line 2: unterminated quoted field"
+    );
+    assert_eq!(
+        error("id,team\n,red\n").to_string(),
+        "line 2, column 'id': empty person id"
+    );
+}
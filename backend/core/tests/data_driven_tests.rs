@@ -1,7 +1,7 @@
 use gm_core::models::{ApiInput, SolverKind, SolverResult};
 use gm_core::{default_solver_configuration_for, run_solver_with_progress};
 use serde::Deserialize;
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashMap};
 use std::fs;
 use std::io::{self, Write};
 use std::path::Path;
@@ -216,7 +216,7 @@ fn run_fixture_case(path: &Path) {
             Err(e) => {
                 if test_case.expected.expect_solver_error {
                     if let Some(substr) = &test_case.expected.expected_error_contains {
-                        let msg = format!("{:?}", e);
+                        let msg = e.to_string();
                         assert!(
                             msg.contains(substr),
                             "Expected error to contain '{}' for solver {}, but got {:?}",
@@ -856,6 +856,7 @@ fn sample_fixture_input() -> ApiInput {
     ApiInput {
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         problem: gm_core::models::ProblemDefinition {
             people: vec![
                 gm_core::models::Person {
@@ -873,6 +874,8 @@ fn sample_fixture_input() -> ApiInput {
                 id: "g0".to_string(),
                 size: 2,
                 session_sizes: None,
+                attributes: HashMap::new(),
+                available_sessions: None,
             }],
             num_sessions: 1,
         },
@@ -0,0 +1,150 @@
+use gm_core::models::{
+    ApiInput, ApiSchedule, Constraint, ContactHistory, Group, HistoricalContact, Objective, Person,
+    ProblemDefinition, RepeatEncounterParams, SolverKind,
+};
+use gm_core::solver_support::event_series::advance_contact_history;
+use gm_core::solver_support::SolverError;
+use gm_core::{default_solver_configuration_for, run_event_series};
+use std::collections::HashMap;
+
+fn event(solver_kind: SolverKind, seed: u64) -> ApiInput {
+    let mut solver = default_solver_configuration_for(solver_kind);
+    solver.seed = Some(seed);
+    solver.stop_conditions.max_iterations = Some(5_000);
+    solver.stop_conditions.time_limit_seconds = None;
+    solver.stop_conditions.no_improvement_iterations = None;
+
+    ApiInput {
+        initial_schedule: None,
+        construction_seed_schedule: None,
+        contact_history: None,
+        problem: ProblemDefinition {
+            people: (0..8)
+                .map(|idx| Person {
+                    id: format!("p{idx}"),
+                    attributes: HashMap::new(),
+                    sessions: None,
+                })
+                .collect(),
+            groups: (0..2)
+                .map(|idx| Group {
+                    id: format!("g{idx}"),
+                    size: 4,
+                    session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
+                })
+                .collect(),
+            num_sessions: 1,
+        },
+        objectives: vec![Objective {
+            r#type: "maximize_unique_contacts".to_string(),
+            weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
+        }],
+        constraints: vec![Constraint::RepeatEncounter(RepeatEncounterParams {
+            max_allowed_encounters: 1,
+            penalty_function: "squared".to_string(),
+            penalty_weight: 100.0,
+        })],
+        solver,
+    }
+}
+
+#[test]
+fn later_events_avoid_pairs_from_earlier_events() {
+    let results = run_event_series(&[event(SolverKind::Solver1, 1), event(SolverKind::Solver1, 2)])
+        .expect("series should solve");
+    assert_eq!(results.len(), 2);
+
+    let first = &results[0].schedule["session_0"];
+    let second = &results[1].schedule["session_0"];
+    for members in second.values() {
+        for earlier in first.values() {
+            let shared = members.iter().filter(|id| earlier.contains(id)).count();
+            assert!(
+                shared <= 2,
+                "group {members:?} reunites too many people from {earlier:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn event_series_errors_name_the_failing_event() {
+    let error = run_event_series(&[event(SolverKind::Solver1, 1), event(SolverKind::Solver3, 2)])
+        .expect_err("solver3 cannot take the carried history");
+    let message = error.to_string();
+    assert!(message.contains("event 1:"), "{message}");
+    assert!(message.contains("contact_history"), "{message}");
+    let SolverError::InEvent { event, source } = &error else {
+        panic!("expected InEvent, got {error:?}");
+    };
+    assert_eq!(*event, 1);
+    assert_eq!(error.kind(), source.kind());
+}
+
+#[test]
+fn advancing_history_ages_entries_and_records_new_pairs() {
+    let mut input = event(SolverKind::Solver1, 1);
+    input.contact_history = Some(ContactHistory {
+        contacts: vec![HistoricalContact {
+            people: vec!["p0".to_string(), "p7".to_string()],
+            count: 2,
+            events_ago: 1,
+        }],
+        decay_factor: 0.5,
+    });
+    let schedule: ApiSchedule = HashMap::from([(
+        "session_0".to_string(),
+        HashMap::from([
+            ("g0".to_string(), vec!["p1".to_string(), "p0".to_string()]),
+            ("g1".to_string(), vec!["p2".to_string()]),
+        ]),
+    )]);
+
+    let history = advance_contact_history(&ContactHistory::default(), &input, &schedule);
+
+    assert_eq!(history.decay_factor, 0.5);
+    assert_eq!(history.contacts.len(), 2);
+    assert_eq!(history.contacts[0].people, vec!["p0", "p7"]);
+    assert_eq!(history.contacts[0].events_ago, 2);
+    assert_eq!(history.contacts[1].people, vec!["p0", "p1"]);
+    assert_eq!(history.contacts[1].count, 1);
+    assert_eq!(history.contacts[1].events_ago, 1);
+}
+
+#[test]
+fn advancing_history_merges_pairs_and_drops_faded_ones() {
+    let mut input = event(SolverKind::Solver1, 1);
+    input.problem.people.truncate(2);
+    input.contact_history = Some(ContactHistory {
+        contacts: vec![
+            HistoricalContact {
+                people: vec!["p0".to_string(), "p1".to_string()],
+                count: 1,
+                events_ago: 1,
+            },
+            HistoricalContact {
+                people: vec!["p1".to_string(), "p0".to_string()],
+                count: 2,
+                events_ago: 1,
+            },
+            HistoricalContact {
+                people: vec!["p2".to_string(), "p3".to_string()],
+                count: 1,
+                events_ago: 40,
+            },
+        ],
+        decay_factor: 0.5,
+    });
+    let empty: ApiSchedule = HashMap::new();
+
+    let history = advance_contact_history(&ContactHistory::default(), &input, &empty);
+
+    assert_eq!(history.contacts.len(), 1, "{:?}", history.contacts);
+    assert_eq!(history.contacts[0].people, vec!["p0", "p1"]);
+    assert_eq!(history.contacts[0].count, 3);
+    assert_eq!(history.contacts[0].events_ago, 2);
+}
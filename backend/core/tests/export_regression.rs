@@ -0,0 +1,517 @@
+use gm_core::models::{
+    ApiInput, Constraint, ConstraintViolation, DisplayOptions, ItineraryStop, Person,
+    RepeatEncounterParams, SessionTime, SolverResult,
+};
+use gm_core::run_solver;
+use std::collections::HashMap;
+
+fn person(id: &str, attributes: &[(&str, &str)]) -> Person {
+    Person {
+        id: id.to_string(),
+        attributes: attributes
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect(),
+        sessions: None,
+    }
+}
+
+fn people() -> Vec<Person> {
+    vec![
+        person("alice", &[("team", "red"), ("role", "lead")]),
+        person("bob", &[("team", "blue")]),
+        person("carol", &[("team", "red, east"), ("role", "says \"hi\"")]),
+        person("dave", &[("team", "blue")]),
+    ]
+}
+
+fn result() -> SolverResult {
+    let session = |groups: &[(&str, &[&str])]| -> HashMap<String, Vec<String>> {
+        groups
+            .iter()
+            .map(|(group, members)| {
+                (
+                    group.to_string(),
+                    members.iter().map(|id| id.to_string()).collect(),
+                )
+            })
+            .collect()
+    };
+    let input = ApiInput::builder()
+        .people(["alice", "bob"])
+        .group("g1", 2)
+        .sessions(1)
+        .build();
+    let mut result = run_solver(&input).expect("solve should succeed");
+    // session_10 sorts after session_2 by index, not by name.
+    result.schedule = HashMap::from([
+        (
+            "session_10".to_string(),
+            session(&[("g2", &["dave"]), ("g1", &["carol", "alice"])]),
+        ),
+        (
+            "session_2".to_string(),
+            session(&[("g1", &["alice", "bob"]), ("g2", &["carol", "ghost"])]),
+        ),
+    ]);
+    result
+}
+
+#[test]
+fn csv_has_one_row_per_assignment_with_selected_attributes() {
+    let csv = result().to_csv(&people(), &["team", "role"]);
+
+    assert_eq!(
+        csv,
+        "session,group,person,team,role\n\
+         2,g1,alice,red,lead\n\
+         2,g1,bob,blue,\n\
+         2,g2,carol,\"red, east\",\"says \"\"hi\"\"\"\n\
+         2,g2,ghost,,\n\
+         10,g1,carol,\"red, east\",\"says \"\"hi\"\"\"\n\
+         10,g1,alice,red,lead\n\
+         10,g2,dave,blue,\n"
+    );
+}
+
+#[test]
+fn csv_without_attributes_has_the_three_base_columns() {
+    let csv = result().to_csv(&people(), &[]);
+
+    assert_eq!(csv.lines().next(), Some("session,group,person"));
+    assert_eq!(csv.lines().count(), 8);
+}
+
+#[test]
+fn markdown_has_a_table_per_session_with_groups_as_columns() {
+    let mut result = result();
+    result
+        .schedule
+        .get_mut("session_2")
+        .unwrap()
+        .insert("g|3".to_string(), vec![]);
+
+    assert_eq!(
+        result.to_markdown(),
+        "## Session 2\n\
+         \n\
+         | g1 | g2 | g\\|3 |\n\
+         | --- | --- | --- |\n\
+         | alice | carol |  |\n\
+         | bob | ghost |  |\n\
+         \n\
+         ## Session 10\n\
+         \n\
+         | g1 | g2 |\n\
+         | --- | --- |\n\
+         | carol | dave |\n\
+         | alice |  |\n"
+    );
+}
+
+fn stop(session: u32, group_id: Option<&str>) -> ItineraryStop {
+    ItineraryStop {
+        session,
+        group_id: group_id.map(str::to_string),
+    }
+}
+
+#[test]
+fn itineraries_list_every_session_for_every_person() {
+    let mut people = people();
+    people.push(person("erin", &[]));
+    let itineraries = result().itineraries(&people);
+
+    assert_eq!(itineraries.len(), 5);
+    assert_eq!(itineraries[0].person_id, "alice");
+    assert_eq!(
+        itineraries[0].stops,
+        [stop(2, Some("g1")), stop(10, Some("g1"))]
+    );
+    assert_eq!(itineraries[1].stops, [stop(2, Some("g1")), stop(10, None)]);
+    assert_eq!(itineraries[4].stops, [stop(2, None), stop(10, None)]);
+    assert_eq!(
+        itineraries[1].to_text(),
+        "bob\n  Session 2: g1\n  Session 10: not scheduled\n"
+    );
+}
+
+#[test]
+fn ical_has_an_event_per_scheduled_session() {
+    let itinerary = &result().itineraries(&people())[1];
+    let mut times = vec![
+        SessionTime {
+            start: "20261014T090000Z".to_string(),
+            end: "20261014T100000Z".to_string(),
+        };
+        11
+    ];
+    times[2].start = "20261014T130000Z".to_string();
+    times[2].end = "20261014T143000Z".to_string();
+
+    let ical = itinerary.to_ical(&times).expect("valid times");
+
+    assert!(ical.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+    assert!(ical.ends_with("END:VCALENDAR\r\n"));
+    assert_eq!(ical.matches("BEGIN:VEVENT").count(), 1);
+    assert!(ical.contains(
+        "UID:bob-session-2@groupmixer\r\n\
+         DTSTAMP:20261014T130000Z\r\n\
+         DTSTART:20261014T130000Z\r\n\
+         DTEND:20261014T143000Z\r\n\
+         SUMMARY:g1 (session 2)\r\n\
+         LOCATION:g1\r\n"
+    ));
+    assert!(ical.lines().all(|line| line.len() <= 75));
+}
+
+#[test]
+fn ical_rejects_missing_or_malformed_times() {
+    let itinerary = &result().itineraries(&people())[0];
+    let time = |start: &str| SessionTime {
+        start: start.to_string(),
+        end: "20261014T100000Z".to_string(),
+    };
+
+    let missing = itinerary.to_ical(&[time("20261014T090000Z")]).unwrap_err();
+    assert_eq!(missing.message(), "no session time given for session 2");
+
+    let local = itinerary
+        .to_ical(&vec![time("2026-10-14 09:00"); 11])
+        .unwrap_err();
+    assert!(local.message().contains("is not a UTC date-time"));
+}
+
+#[test]
+fn long_ical_lines_are_folded() {
+    let mut result = result();
+    let long_group = "a-very-long-group-name-".repeat(5);
+    result
+        .schedule
+        .get_mut("session_2")
+        .unwrap()
+        .insert(long_group.clone(), vec!["erin".to_string()]);
+    let itinerary = &result.itineraries(&[person("erin", &[])])[0];
+    let times = vec![
+        SessionTime {
+            start: "20261014T090000Z".to_string(),
+            end: "20261014T100000Z".to_string(),
+        };
+        11
+    ];
+
+    let ical = itinerary.to_ical(&times).expect("valid times");
+
+    assert!(ical.split("\r\n").all(|line| line.len() <= 75));
+    assert!(ical
+        .replace("\r\n ", "")
+        .contains(&format!("LOCATION:{long_group}\r\n")));
+}
+
+#[test]
+fn dot_graph_has_people_as_nodes_and_meetings_as_edges() {
+    let dot = result().to_dot(&people(), Some("team"));
+
+    assert!(dot.starts_with("graph contacts {\n"));
+    assert!(dot.ends_with("}\n"));
+    // Colors follow the sorted attribute values: blue, red, "red, east".
+    assert!(dot.contains("  \"alice\" [fillcolor=\"#ffffb3\", tooltip=\"red\"];\n"));
+    assert!(dot.contains("  \"bob\" [fillcolor=\"#8dd3c7\", tooltip=\"blue\"];\n"));
+    assert!(dot.contains("\"carol\" [fillcolor=\"#bebada\", tooltip=\"red, east\"];\n"));
+    assert!(dot.contains("  \"alice\" -- \"carol\" [label=\"1\", weight=1, penwidth=1];\n"));
+    assert!(dot.contains("  \"alice\" -- \"bob\" [label=\"1\", weight=1, penwidth=1];\n"));
+    assert_eq!(dot.matches(" -- ").count(), 3);
+}
+
+#[test]
+fn dot_graph_counts_repeat_meetings_and_escapes_ids() {
+    let mut result = result();
+    result.schedule.insert(
+        "session_11".to_string(),
+        HashMap::from([(
+            "g1".to_string(),
+            vec!["alice".to_string(), "carol".to_string()],
+        )]),
+    );
+    let quoted = person("say \"hi\"", &[]);
+
+    let dot = result.to_dot(&[quoted], None);
+
+    assert!(dot.contains("  \"say \\\"hi\\\"\";\n"));
+    assert!(dot.contains("  \"alice\" -- \"carol\" [label=\"2\", weight=2, penwidth=2];\n"));
+}
+
+#[test]
+fn contact_matrix_follows_people_order_and_ignores_unknown_ids() {
+    let result = result();
+
+    assert_eq!(
+        result.contact_matrix(&people()),
+        vec![
+            vec![0, 1, 1, 0],
+            vec![1, 0, 0, 0],
+            vec![1, 0, 0, 0],
+            vec![0, 0, 0, 0],
+        ]
+    );
+    assert_eq!(
+        result.to_contact_matrix_text(&people()),
+        "       alice  bob  carol  dave\n\
+         alice  -      1    1      0\n\
+         bob    1      -    0      0\n\
+         carol  1      0    -      0\n\
+         dave   0      0    0      -\n"
+    );
+    assert_eq!(
+        result.to_contact_matrix_csv(&[person("a,b", &[]), person("alice", &[])]),
+        "person,\"a,b\",alice\n\
+         \"a,b\",,0\n\
+         alice,0,\n"
+    );
+}
+
+#[test]
+fn display_aligns_columns_across_sessions() {
+    assert_eq!(
+        result().display(),
+        "=== Session 2 ===\n\
+         Group  Person\n\
+         -----  ------\n\
+         g1     alice\n\
+         \x20      bob\n\
+         g2     carol\n\
+         \x20      ghost\n\
+         \n\
+         === Session 10 ===\n\
+         Group  Person\n\
+         -----  ------\n\
+         g1     carol\n\
+         \x20      alice\n\
+         g2     dave\n"
+    );
+}
+
+#[test]
+fn display_with_adds_attribute_columns_labels_and_fixed_widths() {
+    let options = DisplayOptions {
+        column_width: Some(6),
+        attributes: vec!["team".to_string()],
+        session_labels: vec![String::new(), String::new(), "Monday".to_string()],
+    };
+
+    let display = result().display_with(&people(), &options);
+
+    assert!(display.starts_with(
+        "=== Monday ===\n\
+         Group   Person  team\n\
+         ------  ------  ------\n\
+         g1      alice   red\n\
+         \x20       bob     blue\n\
+         g2      carol   red, …\n\
+         \x20       ghost\n"
+    ));
+    assert!(display.contains("=== Session 10 ===\n"));
+}
+
+#[test]
+fn display_names_are_only_collected_on_request() {
+    let mut input = ApiInput::builder()
+        .people(["alice", "bob"])
+        .group("g1", 2)
+        .sessions(1)
+        .build();
+    input.problem.people[0]
+        .attributes
+        .insert("name".to_string(), "Alice Smith".to_string());
+    assert_eq!(run_solver(&input).expect("solve").display_names, None);
+
+    input.solver.telemetry.include_display_names = true;
+    let result = run_solver(&input).expect("solve");
+
+    assert_eq!(
+        result.display_names,
+        Some(HashMap::from([(
+            "alice".to_string(),
+            "Alice Smith".to_string()
+        )]))
+    );
+    assert!(result.display().contains(" Alice Smith (alice)\n"));
+}
+
+#[test]
+fn display_with_shows_names_from_people() {
+    let mut people = people();
+    people[1]
+        .attributes
+        .insert("name".to_string(), "Bob".to_string());
+
+    let display = result().display_with(&people, &DisplayOptions::default());
+
+    assert!(display.contains("\n       Bob (bob)\n"));
+    assert!(display.contains("\ng1     alice\n"));
+}
+
+#[test]
+fn person_attributes_echo_the_requested_keys() {
+    let mut input = ApiInput::builder()
+        .people(["alice", "bob"])
+        .group("g1", 2)
+        .sessions(1)
+        .build();
+    input.problem.people = vec![
+        person("alice", &[("department", "ops"), ("diet", "vegan")]),
+        person("bob", &[("diet", "none")]),
+    ];
+    assert_eq!(run_solver(&input).expect("solve").person_attributes, None);
+
+    input.solver.telemetry.include_person_attributes =
+        vec!["department".to_string(), "missing".to_string()];
+    let result = run_solver(&input).expect("solve");
+
+    assert_eq!(
+        result.person_attributes,
+        Some(HashMap::from([
+            (
+                "alice".to_string(),
+                HashMap::from([("department".to_string(), "ops".to_string())])
+            ),
+            ("bob".to_string(), HashMap::new()),
+        ]))
+    );
+}
+
+#[test]
+fn schedule_serializes_in_session_and_group_order() {
+    let json = serde_json::to_string(&result()).expect("serialize");
+    let schedule = &json[json.find("\"schedule\"").expect("schedule field")..];
+
+    assert!(schedule.starts_with(
+        "\"schedule\":{\"session_2\":{\"g1\":[\"alice\",\"bob\"],\"g2\":[\"carol\",\"ghost\"]},\
+         \"session_10\":{\"g1\":[\"carol\",\"alice\"],\"g2\":[\"dave\"]}}"
+    ));
+}
+
+#[test]
+fn violations_are_explained_in_plain_language_grouped_by_type() {
+    let mut input = ApiInput::builder()
+        .people(["alice", "bob"])
+        .group("g1", 2)
+        .sessions(1)
+        .build();
+    input.problem.people = vec![person("alice", &[("name", "Alice")]), person("bob", &[])];
+    input.constraints = vec![Constraint::RepeatEncounter(RepeatEncounterParams {
+        max_allowed_encounters: 1,
+        penalty_function: "linear".to_string(),
+        penalty_weight: 2.0,
+    })];
+    let mut result = run_solver(&input).expect("solve should succeed");
+    let session = HashMap::from([(
+        "g1".to_string(),
+        vec!["alice".to_string(), "bob".to_string()],
+    )]);
+    result.schedule = (0..3)
+        .map(|index| (format!("session_{index}"), session.clone()))
+        .collect();
+    result.constraint_violations = Some(vec![
+        ConstraintViolation {
+            constraint_type: "RepeatEncounter".to_string(),
+            session: None,
+            people: vec!["alice".to_string(), "bob".to_string()],
+            groups: Vec::new(),
+            weighted_penalty: 4.0,
+        },
+        ConstraintViolation {
+            constraint_type: "MustStayApart".to_string(),
+            session: Some(1),
+            people: vec!["alice".to_string(), "bob".to_string()],
+            groups: vec!["g1".to_string()],
+            weighted_penalty: 0.0,
+        },
+    ]);
+
+    assert_eq!(
+        result
+            .explain_violations(Some(&input))
+            .expect("violations recorded"),
+        "MustStayApart (1):\n  Session 1: Alice and bob share g1 but must be apart\n\
+         RepeatEncounter (1):\n  Alice and bob met 3 times; limit is 1 (penalty 4)\n"
+    );
+    assert!(result
+        .explain_violations(None)
+        .expect("violations recorded")
+        .contains("alice and bob met 3 times, more often than allowed"));
+
+    result.constraint_violations = Some(Vec::new());
+    assert_eq!(
+        result.explain_violations(None).as_deref(),
+        Some("No violations.\n")
+    );
+    result.constraint_violations = None;
+    assert_eq!(result.explain_violations(None), None);
+}
+
+#[test]
+fn explained_limit_violations_state_the_configured_limit() {
+    #[derive(serde::Deserialize)]
+    struct Fixture {
+        problem: ApiInput,
+        result: SolverResult,
+    }
+    let fixture: Fixture =
+        serde_json::from_str(include_str!("fixtures/explain_limits.json")).expect("fixture");
+
+    assert_eq!(
+        fixture
+            .result
+            .explain_violations(Some(&fixture.problem))
+            .expect("violations recorded"),
+        "BaselineMoveLimit (2):\n  4 moves from the baseline (b and c); limit is 3\n  \
+         Session 1: 2 moves from the baseline (b and c); limit is 1\n\
+         RepeatEncounter (2):\n  a and c met 2 times; limit is 1 (penalty 1)\n  \
+         b and d met 2 times; limit is 1 (penalty 1)\n"
+    );
+}
+
+#[test]
+fn people_text_lists_groups_contacts_and_violations_per_person() {
+    let mut input = ApiInput::builder()
+        .people(["alice", "bob", "carol"])
+        .group("g1", 2)
+        .group("g2", 2)
+        .sessions(2)
+        .build();
+    input.problem.people[0] = person("alice", &[("name", "Alice")]);
+    let mut result = run_solver(&input).expect("solve should succeed");
+    let owned = |ids: &[&str]| ids.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+    result.schedule = HashMap::from([
+        (
+            "session_0".to_string(),
+            HashMap::from([
+                ("g1".to_string(), owned(&["alice", "bob"])),
+                ("g2".to_string(), owned(&["carol"])),
+            ]),
+        ),
+        (
+            "session_1".to_string(),
+            HashMap::from([("g1".to_string(), owned(&["alice", "bob"]))]),
+        ),
+    ]);
+    result.constraint_violations = Some(vec![ConstraintViolation {
+        constraint_type: "MustStayApart".to_string(),
+        session: Some(1),
+        people: owned(&["alice", "bob"]),
+        groups: owned(&["g1"]),
+        weighted_penalty: 0.0,
+    }]);
+
+    assert_eq!(
+        result.to_people_text(&input.problem.people, Some(&input)),
+        "Alice (alice)\n  Groups: 0: g1, 1: g1\n  Contacts: 1 unique, 1 repeated\n  Violations:\n    \
+         Session 1: Alice and bob share g1 but must be apart\n\
+         \n\
+         bob\n  Groups: 0: g1, 1: g1\n  Contacts: 1 unique, 1 repeated\n  Violations:\n    \
+         Session 1: Alice and bob share g1 but must be apart\n\
+         \n\
+         carol\n  Groups: 0: g2, 1: -\n  Contacts: 0 unique, 0 repeated\n  Violations: none\n"
+    );
+}
@@ -0,0 +1,146 @@
+use gm_core::models::{
+    ApiInput, ApiSchedule, Constraint, Group, GroupAttributeRequirementParams, Objective, Person,
+    ProblemDefinition, RepeatEncounterParams, SolverKind, SolverResult,
+};
+use gm_core::{default_solver_configuration_for, run_solver};
+use std::collections::HashMap;
+
+fn group(id: &str, accessible: bool) -> Group {
+    Group {
+        id: id.to_string(),
+        size: 4,
+        session_sizes: None,
+        attributes: if accessible {
+            HashMap::from([("accessible".to_string(), "true".to_string())])
+        } else {
+            HashMap::new()
+        },
+        available_sessions: None,
+    }
+}
+
+fn base_input(solver_kind: SolverKind) -> ApiInput {
+    let mut solver = default_solver_configuration_for(solver_kind);
+    solver.seed = Some(5);
+    solver.stop_conditions.max_iterations = Some(5_000);
+    solver.stop_conditions.time_limit_seconds = None;
+    solver.stop_conditions.no_improvement_iterations = None;
+
+    ApiInput {
+        initial_schedule: None,
+        construction_seed_schedule: None,
+        contact_history: None,
+        problem: ProblemDefinition {
+            people: (0..12)
+                .map(|idx| Person {
+                    id: format!("p{idx}"),
+                    attributes: HashMap::new(),
+                    sessions: None,
+                })
+                .collect(),
+            groups: vec![group("g0", true), group("g1", false), group("g2", true)],
+            num_sessions: 3,
+        },
+        objectives: vec![Objective {
+            r#type: "maximize_unique_contacts".to_string(),
+            weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
+        }],
+        constraints: vec![
+            Constraint::RepeatEncounter(RepeatEncounterParams {
+                max_allowed_encounters: 1,
+                penalty_function: "squared".to_string(),
+                penalty_weight: 100.0,
+            }),
+            Constraint::GroupAttributeRequirement(GroupAttributeRequirementParams {
+                people: vec!["p0".to_string(), "p1".to_string(), "p2".to_string()],
+                required_attributes: HashMap::from([(
+                    "accessible".to_string(),
+                    "true".to_string(),
+                )]),
+                sessions: None,
+            }),
+        ],
+        solver,
+    }
+}
+
+fn group_of<'a>(result: &'a SolverResult, session: &str, person: &str) -> &'a str {
+    result.schedule[session]
+        .iter()
+        .find(|(_, members)| members.iter().any(|member| member == person))
+        .map(|(group_id, _)| group_id.as_str())
+        .expect("every person is scheduled")
+}
+
+#[test]
+fn solver1_keeps_people_in_groups_matching_their_requirements() {
+    let result = run_solver(&base_input(SolverKind::Solver1)).expect("solver1 should solve");
+
+    for session in 0..3 {
+        for person in ["p0", "p1", "p2"] {
+            let group_id = group_of(&result, &format!("session_{session}"), person);
+            assert_ne!(
+                group_id, "g1",
+                "{person} must stay in an accessible group in session {session}"
+            );
+        }
+    }
+}
+
+#[test]
+fn group_attribute_requirement_without_matching_group_is_rejected() {
+    let mut input = base_input(SolverKind::Solver1);
+    input.constraints[1] = Constraint::GroupAttributeRequirement(GroupAttributeRequirementParams {
+        people: vec!["p0".to_string()],
+        required_attributes: HashMap::from([("floor".to_string(), "1".to_string())]),
+        sessions: None,
+    });
+
+    let error = run_solver(&input).expect_err("unsatisfiable requirement should be rejected");
+    assert!(error
+        .to_string()
+        .contains("GroupAttributeRequirement for person 'p0' matches no group"));
+}
+
+#[test]
+fn warm_start_violating_group_attribute_requirement_is_rejected() {
+    let mut input = base_input(SolverKind::Solver1);
+    let schedule: ApiSchedule = (0..3)
+        .map(|session| {
+            (
+                format!("session_{session}"),
+                HashMap::from([
+                    (
+                        "g0".to_string(),
+                        (4..8).map(|idx| format!("p{idx}")).collect(),
+                    ),
+                    (
+                        "g1".to_string(),
+                        (0..4).map(|idx| format!("p{idx}")).collect(),
+                    ),
+                    (
+                        "g2".to_string(),
+                        (8..12).map(|idx| format!("p{idx}")).collect(),
+                    ),
+                ]),
+            )
+        })
+        .collect();
+    input.initial_schedule = Some(schedule);
+
+    let error = run_solver(&input).expect_err("warm start breaks the requirement");
+    assert!(error
+        .to_string()
+        .contains("GroupAttributeRequirement violation"));
+}
+
+#[test]
+fn solver3_rejects_group_attribute_requirement_explicitly() {
+    let error = run_solver(&base_input(SolverKind::Solver3))
+        .expect_err("solver3 does not model group attribute requirements");
+    assert!(error
+        .to_string()
+        .contains("solver3 does not support GroupAttributeRequirement"));
+}
@@ -0,0 +1,136 @@
+use gm_core::models::{
+    ApiInput, ApiSchedule, Constraint, Group, Objective, Person, ProblemDefinition,
+    RepeatEncounterParams, SolverKind,
+};
+use gm_core::solver_support::SolverError;
+use gm_core::{default_solver_configuration_for, run_solver};
+use std::collections::HashMap;
+
+fn base_input(solver_kind: SolverKind) -> ApiInput {
+    let mut solver = default_solver_configuration_for(solver_kind);
+    solver.seed = Some(11);
+    solver.stop_conditions.max_iterations = Some(5_000);
+    solver.stop_conditions.time_limit_seconds = None;
+    solver.stop_conditions.no_improvement_iterations = None;
+
+    ApiInput {
+        initial_schedule: None,
+        construction_seed_schedule: None,
+        contact_history: None,
+        problem: ProblemDefinition {
+            people: (0..8)
+                .map(|idx| Person {
+                    id: format!("p{idx}"),
+                    attributes: HashMap::new(),
+                    sessions: None,
+                })
+                .collect(),
+            groups: (0..3)
+                .map(|idx| Group {
+                    id: format!("g{idx}"),
+                    size: 4,
+                    session_sizes: None,
+                    attributes: HashMap::new(),
+                    // g2 only exists in the first and last session.
+                    available_sessions: (idx == 2).then(|| vec![0, 2]),
+                })
+                .collect(),
+            num_sessions: 3,
+        },
+        objectives: vec![Objective {
+            r#type: "maximize_unique_contacts".to_string(),
+            weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
+        }],
+        constraints: vec![Constraint::RepeatEncounter(RepeatEncounterParams {
+            max_allowed_encounters: 1,
+            penalty_function: "squared".to_string(),
+            penalty_weight: 100.0,
+        })],
+        solver,
+    }
+}
+
+fn assert_closed_group_stays_empty(solver_kind: SolverKind) {
+    let result = run_solver(&base_input(solver_kind)).expect("solver should solve");
+
+    let closed_session = &result.schedule["session_1"];
+    assert!(
+        closed_session
+            .get("g2")
+            .is_none_or(|members| members.is_empty()),
+        "{solver_kind}: nobody may be placed in g2 while it is closed"
+    );
+    let placed: usize = closed_session.values().map(Vec::len).sum();
+    assert_eq!(placed, 8, "{solver_kind}: everyone is still scheduled");
+}
+
+#[test]
+fn solver1_never_assigns_people_to_a_closed_group() {
+    assert_closed_group_stays_empty(SolverKind::Solver1);
+}
+
+#[test]
+fn solver3_never_assigns_people_to_a_closed_group() {
+    assert_closed_group_stays_empty(SolverKind::Solver3);
+}
+
+#[test]
+fn warm_start_may_omit_a_closed_group() {
+    let mut input = base_input(SolverKind::Solver1);
+    let people = |range: std::ops::Range<usize>| -> Vec<String> {
+        range.map(|idx| format!("p{idx}")).collect()
+    };
+    let open_session = HashMap::from([
+        ("g0".to_string(), people(0..3)),
+        ("g1".to_string(), people(3..6)),
+        ("g2".to_string(), people(6..8)),
+    ]);
+    let schedule: ApiSchedule = HashMap::from([
+        ("session_0".to_string(), open_session.clone()),
+        (
+            "session_1".to_string(),
+            HashMap::from([
+                ("g0".to_string(), people(0..4)),
+                ("g1".to_string(), people(4..8)),
+            ]),
+        ),
+        ("session_2".to_string(), open_session),
+    ]);
+    input.initial_schedule = Some(schedule);
+
+    run_solver(&input).expect("closed groups need not appear in a warm start");
+}
+
+#[test]
+fn available_sessions_out_of_range_is_rejected() {
+    for solver_kind in [SolverKind::Solver1, SolverKind::Solver3] {
+        let mut input = base_input(solver_kind);
+        input.problem.groups[2].available_sessions = Some(vec![0, 3]);
+
+        let error = run_solver(&input).expect_err("session 3 does not exist");
+        assert!(
+            matches!(
+                error,
+                SolverError::InvalidSession {
+                    session: 3,
+                    ref referenced_by,
+                    ..
+                } if referenced_by.contains("available_sessions")
+            ),
+            "{solver_kind:?}: {error}"
+        );
+    }
+}
+
+#[test]
+fn short_session_sizes_leave_capacity_undefined() {
+    let mut input = base_input(SolverKind::Solver1);
+    input.problem.groups[0].session_sizes = Some(vec![4, 4]);
+
+    assert_eq!(input.problem.groups[0].capacity_for_session(1), Some(4));
+    assert_eq!(input.problem.groups[0].capacity_for_session(2), None);
+    assert_eq!(input.problem.groups[2].capacity_for_session(1), Some(0));
+    assert!(run_solver(&input).is_err());
+}
@@ -0,0 +1,86 @@
+use gm_core::models::{
+    ApiInput, Group, Objective, Person, ProblemDefinition, SolverKind, SolverResult,
+};
+use gm_core::{default_solver_configuration_for, run_solver};
+use std::collections::HashMap;
+
+fn base_input(solver_kind: SolverKind) -> ApiInput {
+    let mut solver = default_solver_configuration_for(solver_kind);
+    solver.seed = Some(7);
+    solver.stop_conditions.max_iterations = Some(20_000);
+    solver.stop_conditions.time_limit_seconds = None;
+    solver.stop_conditions.no_improvement_iterations = None;
+
+    ApiInput {
+        initial_schedule: None,
+        construction_seed_schedule: None,
+        contact_history: None,
+        problem: ProblemDefinition {
+            people: (0..10)
+                .map(|idx| Person {
+                    id: format!("p{idx}"),
+                    attributes: HashMap::new(),
+                    sessions: None,
+                })
+                .collect(),
+            groups: (0..3)
+                .map(|idx| Group {
+                    id: format!("g{idx}"),
+                    size: 6,
+                    session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
+                })
+                .collect(),
+            num_sessions: 3,
+        },
+        objectives: vec![
+            Objective {
+                r#type: "maximize_unique_contacts".to_string(),
+                weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
+            },
+            Objective {
+                r#type: "balance_group_sizes".to_string(),
+                weight: 10.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
+            },
+        ],
+        constraints: vec![],
+        solver,
+    }
+}
+
+fn size_spread_per_session(result: &SolverResult) -> Vec<usize> {
+    let mut sessions: Vec<_> = result.schedule.iter().collect();
+    sessions.sort_by(|left, right| left.0.cmp(right.0));
+    sessions
+        .into_iter()
+        .map(|(_, groups)| {
+            let sizes: Vec<usize> = groups.values().map(Vec::len).collect();
+            sizes.iter().max().unwrap() - sizes.iter().min().unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn solver1_balance_group_sizes_evens_out_groups() {
+    let result = run_solver(&base_input(SolverKind::Solver1)).expect("solver1 should solve");
+
+    let spreads = size_spread_per_session(&result);
+    assert!(
+        spreads.iter().all(|&spread| spread <= 1),
+        "expected group sizes within one of each other, got spreads {spreads:?}"
+    );
+}
+
+#[test]
+fn solver3_rejects_balance_group_sizes_explicitly() {
+    let error = run_solver(&base_input(SolverKind::Solver3))
+        .expect_err("solver3 does not model group-fill balance");
+    assert!(error
+        .to_string()
+        .contains("solver3 does not support the balance_group_sizes objective"));
+}
@@ -0,0 +1,80 @@
+use gm_core::builder::ProblemBuilder;
+use gm_core::explain_infeasibility;
+use gm_core::models::{
+    ApiInput, Constraint, ImmovablePersonParams, SolverConfiguration, SolverKind,
+};
+
+fn problem() -> ProblemBuilder {
+    ApiInput::builder()
+        .people((0..6).map(|idx| format!("p{idx}")))
+        .group("small", 2)
+        .group("large", 4)
+        .sessions(2)
+        .solver(SolverConfiguration::builder(SolverKind::Solver1).build())
+}
+
+fn immovable(person_id: &str, group_id: &str) -> Constraint {
+    Constraint::ImmovablePerson(ImmovablePersonParams {
+        person_id: person_id.to_string(),
+        group_id: group_id.to_string(),
+        sessions: None,
+    })
+}
+
+#[test]
+fn feasible_input_needs_no_explanation() {
+    let input = problem()
+        .constraint(Constraint::MustStayTogether {
+            people: vec!["p0".to_string(), "p1".to_string(), "p2".to_string()],
+            sessions: None,
+        })
+        .build();
+
+    assert!(explain_infeasibility(&input).is_none());
+}
+
+#[test]
+fn clique_pinned_to_a_small_group_is_reduced_to_the_two_culprits() {
+    let input = problem()
+        .constraint(Constraint::ShouldNotBeTogether {
+            people: vec!["p4".to_string(), "p5".to_string()],
+            penalty_weight: 10.0,
+            sessions: None,
+        })
+        .constraint(Constraint::MustStayTogether {
+            people: vec!["p0".to_string(), "p1".to_string(), "p2".to_string()],
+            sessions: None,
+        })
+        .constraint(immovable("p3", "large"))
+        .constraint(immovable("p0", "small"))
+        .build();
+
+    let explanation = explain_infeasibility(&input).expect("input is infeasible");
+
+    assert_eq!(explanation.constraint_indices, vec![1, 3]);
+    assert_eq!(explanation.constraints.len(), 2);
+    assert!(matches!(
+        explanation.constraints[0],
+        Constraint::MustStayTogether { .. }
+    ));
+    assert!(explanation
+        .details
+        .constraint_indices
+        .iter()
+        .all(|idx| explanation.constraint_indices.contains(idx)));
+}
+
+#[test]
+fn capacity_shortfall_involves_no_constraints() {
+    let input = problem()
+        .person("p6")
+        .person("p7")
+        .person("p8")
+        .constraint(immovable("p3", "large"))
+        .build();
+
+    let explanation = explain_infeasibility(&input).expect("input is infeasible");
+
+    assert!(explanation.constraint_indices.is_empty());
+    assert_eq!(explanation.details.kind, "insufficient_capacity");
+}
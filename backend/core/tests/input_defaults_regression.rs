@@ -0,0 +1,97 @@
+use gm_core::models::{ApiInput, SolverKind, SolverParams};
+use gm_core::{default_solver_configuration_for, run_solver};
+use serde_json::json;
+
+fn problem_json() -> serde_json::Value {
+    json!({
+        "people": [
+            {"id": "p0", "attributes": {}},
+            {"id": "p1", "attributes": {}},
+            {"id": "p2", "attributes": {}},
+            {"id": "p3", "attributes": {}}
+        ],
+        "groups": [{"id": "g0", "size": 2}, {"id": "g1", "size": 2}],
+        "num_sessions": 2
+    })
+}
+
+fn parse(value: serde_json::Value) -> ApiInput {
+    serde_json::from_value(value).expect("input should parse")
+}
+
+#[test]
+fn input_with_only_a_problem_uses_the_automatic_solver() {
+    let input = parse(json!({ "problem": problem_json() }));
+
+    assert_eq!(
+        input.solver.validate_solver_selection(),
+        Ok(SolverKind::Auto)
+    );
+    assert!(matches!(input.solver.solver_params, SolverParams::Auto(_)));
+    let result = run_solver(&input).expect("solve should succeed");
+    assert_eq!(result.schedule.len(), 2);
+}
+
+#[test]
+fn solver_type_alone_gets_that_family_defaults() {
+    let input = parse(json!({
+        "problem": problem_json(),
+        "solver": {"solver_type": "solver1", "seed": 3}
+    }));
+    let defaults = default_solver_configuration_for(SolverKind::Solver1);
+
+    assert_eq!(
+        input.solver.validate_solver_selection(),
+        Ok(SolverKind::Solver1)
+    );
+    assert_eq!(
+        input.solver.stop_conditions.max_iterations,
+        defaults.stop_conditions.max_iterations
+    );
+    assert_eq!(
+        input.solver.stop_conditions.time_limit_seconds,
+        defaults.stop_conditions.time_limit_seconds
+    );
+    assert_eq!(input.solver.seed, Some(3));
+}
+
+#[test]
+fn solver_params_alone_select_their_family() {
+    let input = parse(json!({
+        "problem": problem_json(),
+        "solver": {
+            "solver_params": {
+                "solver_type": "SimulatedAnnealing",
+                "initial_temperature": 5.0,
+                "final_temperature": 0.5,
+                "cooling_schedule": "geometric"
+            },
+            "stop_conditions": {"max_iterations": 200}
+        }
+    }));
+
+    assert_eq!(input.solver.solver_type, "solver1");
+    let params = input
+        .solver
+        .simulated_annealing_params()
+        .expect("params match the solver type");
+    assert_eq!(params.initial_temperature, 5.0);
+    assert_eq!(input.solver.stop_conditions.max_iterations, Some(200));
+    assert_eq!(input.solver.stop_conditions.time_limit_seconds, None);
+    assert!(input.solver.stop_conditions.stop_on_optimal_score);
+}
+
+#[test]
+fn unknown_solver_type_is_reported_by_validation() {
+    let input = parse(json!({
+        "problem": problem_json(),
+        "solver": {"solver_type": "solver42"}
+    }));
+
+    assert_eq!(input.solver.solver_type, "solver42");
+    assert!(input
+        .solver
+        .validate_solver_selection()
+        .unwrap_err()
+        .contains("Unknown solver type 'solver42'"));
+}
@@ -0,0 +1,54 @@
+use gm_core::models::ApiInput;
+use gm_core::solver_support::memory_estimate::WASM_MEMORY_LIMIT_BYTES;
+use gm_core::{estimate_memory, validate};
+
+fn problem(people: usize) -> ApiInput {
+    let groups = people.div_ceil(10);
+    let mut builder = ApiInput::builder()
+        .people((0..people).map(|idx| format!("p{idx}")))
+        .sessions(3);
+    for group in 0..groups {
+        builder = builder.group(format!("g{group}"), 10);
+    }
+    builder.build()
+}
+
+#[test]
+fn estimate_grows_with_the_square_of_the_population() {
+    let small = estimate_memory(&problem(100));
+    let large = estimate_memory(&problem(400));
+
+    assert!(small.pair_table_bytes >= 100 * 100 * 4);
+    assert!(large.pair_table_bytes >= 16 * small.pair_table_bytes - 16);
+    assert!(large.total_bytes > small.total_bytes);
+    assert!(small.total_bytes < WASM_MEMORY_LIMIT_BYTES);
+}
+
+#[test]
+fn only_webassembly_builds_limit_memory() {
+    let estimate = estimate_memory(&problem(60_000));
+
+    assert!(estimate.total_bytes > WASM_MEMORY_LIMIT_BYTES);
+    assert_eq!(
+        estimate.limit_bytes,
+        cfg!(target_arch = "wasm32").then_some(WASM_MEMORY_LIMIT_BYTES)
+    );
+}
+
+#[test]
+fn validate_warns_about_heavy_but_solvable_problems() {
+    let input = problem(25_000);
+    let estimate = estimate_memory(&input);
+    assert!(estimate.total_bytes > 512 * 1024 * 1024);
+    assert!(estimate.total_bytes <= WASM_MEMORY_LIMIT_BYTES);
+
+    let report = validate(&input);
+    assert!(report
+        .warnings
+        .iter()
+        .any(|finding| finding.details.kind == "memory_estimate"));
+    assert!(validate(&problem(50))
+        .warnings
+        .iter()
+        .all(|finding| finding.details.kind != "memory_estimate"));
+}
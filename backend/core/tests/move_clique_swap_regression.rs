@@ -44,6 +44,8 @@ fn groups_with_sizes(sizes: &[u32]) -> Vec<Group> {
             id: format!("g{idx}"),
             size,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         })
         .collect()
 }
@@ -66,6 +68,7 @@ fn clique_state_with_group_sizes(
     let input = ApiInput {
         initial_schedule: Some(make_initial_schedule(&group_id_refs, sessions)),
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people,
             groups: groups_with_sizes(group_sizes),
@@ -74,6 +77,8 @@ fn clique_state_with_group_sizes(
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints,
         solver,
@@ -0,0 +1,89 @@
+mod common;
+
+use common::make_initial_schedule;
+use gm_core::builder::ProblemBuilder;
+use gm_core::evaluate_move;
+use gm_core::models::{ApiInput, ApiSchedule, Constraint, SolverConfiguration, SolverKind};
+use gm_core::solver::SolverError;
+
+fn problem() -> ProblemBuilder {
+    ApiInput::builder()
+        .people((0..4).map(|idx| format!("p{idx}")))
+        .group("g0", 2)
+        .group("g1", 2)
+        .sessions(2)
+        .objective("maximize_unique_contacts", 1.0)
+        .solver(SolverConfiguration::builder(SolverKind::Solver1).build())
+}
+
+fn repeated_schedule() -> ApiSchedule {
+    make_initial_schedule(
+        &["g0", "g1"],
+        vec![
+            vec![vec!["p0", "p1"], vec!["p2", "p3"]],
+            vec![vec!["p0", "p1"], vec!["p2", "p3"]],
+        ],
+    )
+}
+
+#[test]
+fn swap_that_meets_new_people_lowers_the_cost() {
+    let input = problem().build();
+
+    let delta = evaluate_move(&input, &repeated_schedule(), 1, "p1", "p2").expect("valid move");
+
+    assert!(delta.feasible);
+    assert_eq!(delta.unique_contacts_delta, 2);
+    assert!(delta.cost_delta < 0.0, "{delta:?}");
+    assert_eq!(delta.constraint_penalty_delta, 0);
+}
+
+#[test]
+fn swap_within_a_group_changes_nothing() {
+    let input = problem().build();
+
+    let delta = evaluate_move(&input, &repeated_schedule(), 0, "p0", "p1").expect("valid move");
+
+    assert!(delta.feasible);
+    assert_eq!(delta.cost_delta, 0.0);
+    assert_eq!(delta.unique_contacts_delta, 0);
+}
+
+#[test]
+fn swap_breaking_a_hard_constraint_is_infeasible_but_scored() {
+    let input = problem()
+        .constraint(Constraint::MustStayApart {
+            people: vec!["p0".to_string(), "p2".to_string()],
+            sessions: None,
+        })
+        .build();
+
+    let delta = evaluate_move(&input, &repeated_schedule(), 1, "p1", "p2").expect("valid move");
+
+    assert!(!delta.feasible);
+    assert!(delta.constraint_penalty_delta > 0, "{delta:?}");
+}
+
+#[test]
+fn unknown_person_is_a_typed_error() {
+    let input = problem().build();
+
+    let error = evaluate_move(&input, &repeated_schedule(), 0, "p0", "ghost").unwrap_err();
+
+    assert!(matches!(
+        error,
+        SolverError::UnknownPersonId { ref person_id, .. } if person_id == "ghost"
+    ));
+}
+
+#[test]
+fn session_out_of_range_is_rejected() {
+    let input = problem().build();
+
+    let error = evaluate_move(&input, &repeated_schedule(), 2, "p0", "p2").unwrap_err();
+
+    assert!(matches!(
+        error,
+        SolverError::InvalidSession { session: 2, .. }
+    ));
+}
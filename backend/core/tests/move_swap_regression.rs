@@ -38,11 +38,15 @@ fn groups() -> Vec<Group> {
             id: "g0".to_string(),
             size: 2,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         },
         Group {
             id: "g1".to_string(),
             size: 2,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         },
     ]
 }
@@ -54,6 +58,7 @@ fn single_session_swap_state(constraints: Vec<Constraint>) -> State {
             vec![vec![vec!["p0", "p1"], vec!["p2", "p3"]]],
         )),
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people: vec![person("p0"), person("p1"), person("p2"), person("p3")],
             groups: groups(),
@@ -62,6 +67,8 @@ fn single_session_swap_state(constraints: Vec<Constraint>) -> State {
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints,
         solver: {
@@ -103,6 +110,7 @@ fn non_participant_swap_returns_infinity_and_apply_is_noop() {
             ],
         )),
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people: vec![
                 person("p0"),
@@ -116,6 +124,8 @@ fn non_participant_swap_returns_infinity_and_apply_is_noop() {
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![],
         solver,
@@ -214,6 +224,7 @@ fn attribute_balance_delta_matches_apply_and_recalculation() {
             vec![vec![vec!["p0", "p1"], vec!["p2", "p3"]]],
         )),
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people: vec![
                 person_with_attribute("p0", "team", "red"),
@@ -227,6 +238,8 @@ fn attribute_balance_delta_matches_apply_and_recalculation() {
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![Constraint::AttributeBalance(AttributeBalanceParams {
             group_id: "g0".to_string(),
@@ -265,6 +278,7 @@ fn pair_meeting_delta_matches_apply_and_recalculation() {
             ],
         )),
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people: vec![person("p0"), person("p1"), person("p2"), person("p3")],
             groups: groups(),
@@ -273,6 +287,8 @@ fn pair_meeting_delta_matches_apply_and_recalculation() {
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![Constraint::PairMeetingCount(PairMeetingCountParams {
             people: vec!["p0".to_string(), "p1".to_string()],
@@ -295,3 +311,93 @@ fn pair_meeting_delta_matches_apply_and_recalculation() {
 
     assert_delta_matches_after(&before, &state, delta);
 }
+
+#[test]
+fn swaps_on_a_compact_contact_matrix_match_recalculation() {
+    use gm_core::models::{SolverConfiguration, SolverKind};
+    use gm_core::solver1::COMPACT_MIN_PEOPLE;
+
+    let mut builder = ApiInput::builder()
+        .people((0..COMPACT_MIN_PEOPLE).map(|idx| format!("p{idx}")))
+        .sessions(3)
+        .objective("maximize_unique_contacts", 1.0)
+        .solver(
+            SolverConfiguration::builder(SolverKind::Solver1)
+                .seed(11)
+                .build(),
+        );
+    for group in 0..COMPACT_MIN_PEOPLE / 10 {
+        builder = builder.group(format!("g{group}"), 10);
+    }
+    let mut state = State::new(&builder.build()).expect("state");
+    assert!(state.contact_matrix.is_compact());
+
+    for day in 0..3 {
+        for group in 0..5 {
+            let p1 = state.schedule[day][group][0];
+            let p2 = state.schedule[day][group + 1][1];
+            let before = state.clone();
+            let delta = state.calculate_swap_cost_delta(day, p1, p2);
+            state.apply_swap(day, p1, p2);
+            assert_delta_matches_after(&before, &state, delta);
+        }
+    }
+}
+
+fn widely_excluded_person_input() -> ApiInput {
+    use gm_core::models::{SolverConfiguration, SolverKind};
+
+    // p0 is excluded from more people than a group holds, the others from a few each.
+    let mut builder = ApiInput::builder()
+        .people((0..30).map(|idx| format!("p{idx}")))
+        .sessions(2)
+        .objective("maximize_unique_contacts", 1.0)
+        .solver(
+            SolverConfiguration::builder(SolverKind::Solver1)
+                .seed(5)
+                .build(),
+        );
+    for group in 0..6 {
+        builder = builder.group(format!("g{group}"), 6);
+    }
+    for other in 1..30 {
+        builder = builder.constraint(Constraint::ShouldNotBeTogether {
+            people: vec!["p0".to_string(), format!("p{other}")],
+            penalty_weight: f64::from(other),
+            sessions: (other % 3 == 0).then(|| vec![1]),
+        });
+    }
+    for (left, right) in [(1, 2), (2, 3), (4, 9), (1, 2)] {
+        builder = builder.constraint(Constraint::ShouldNotBeTogether {
+            people: vec![format!("p{left}"), format!("p{right}")],
+            penalty_weight: 7.0,
+            sessions: None,
+        });
+    }
+    builder.build()
+}
+
+#[test]
+fn swaps_of_a_widely_excluded_person_match_recalculation() {
+    let mut state = State::new(&widely_excluded_person_input()).expect("state");
+    let p0 = state.person_id_to_idx["p0"];
+
+    for day in 0..2 {
+        for group in 0..6 {
+            let p0_group = state.locations[day][p0].0;
+            if group == p0_group {
+                continue;
+            }
+            for (p1, member) in [(p0, 0), (state.schedule[day][p0_group][0], 1)] {
+                let p2 = state.schedule[day][group][member];
+                if p1 == p2 || state.locations[day][p1].0 == state.locations[day][p2].0 {
+                    continue;
+                }
+                let before = state.clone();
+                let delta = state.calculate_swap_cost_delta(day, p1, p2);
+                state.apply_swap(day, p1, p2);
+                assert_delta_matches_after(&before, &state, delta);
+            }
+        }
+    }
+}
@@ -45,6 +45,7 @@ fn transfer_state(
     let input = ApiInput {
         initial_schedule: Some(make_initial_schedule(&group_ids, sessions)),
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people,
             groups,
@@ -53,6 +54,8 @@ fn transfer_state(
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints,
         solver,
@@ -67,16 +70,22 @@ fn groups_3x2() -> Vec<Group> {
             id: "g0".to_string(),
             size: 2,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         },
         Group {
             id: "g1".to_string(),
             size: 2,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         },
         Group {
             id: "g2".to_string(),
             size: 2,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         },
     ]
 }
@@ -244,16 +253,22 @@ fn pair_meeting_transfer_delta_matches_apply_and_recalculation() {
             id: "g0".to_string(),
             size: 3,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         },
         Group {
             id: "g1".to_string(),
             size: 2,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         },
         Group {
             id: "g2".to_string(),
             size: 2,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         },
     ];
     let group_ids = groups
@@ -270,6 +285,7 @@ fn pair_meeting_transfer_delta_matches_apply_and_recalculation() {
             ],
         )),
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people: vec![
                 person("p0"),
@@ -284,6 +300,8 @@ fn pair_meeting_transfer_delta_matches_apply_and_recalculation() {
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![Constraint::PairMeetingCount(PairMeetingCountParams {
             people: vec!["p0".to_string(), "p1".to_string()],
@@ -350,16 +368,22 @@ fn sequential_transfer_moves_do_not_drift_caches() {
             id: "g0".to_string(),
             size: 3,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         },
         Group {
             id: "g1".to_string(),
             size: 2,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         },
         Group {
             id: "g2".to_string(),
             size: 2,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         },
     ];
     let group_ids = groups
@@ -376,6 +400,7 @@ fn sequential_transfer_moves_do_not_drift_caches() {
             ],
         )),
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people: vec![
                 person_with_attribute("p0", "team", "red"),
@@ -390,6 +415,8 @@ fn sequential_transfer_moves_do_not_drift_caches() {
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![
             Constraint::RepeatEncounter(gm_core::models::RepeatEncounterParams {
@@ -449,3 +476,45 @@ fn sequential_transfer_moves_do_not_drift_caches() {
         assert_delta_matches_after(&before, &state, delta);
     }
 }
+
+#[test]
+fn transfers_of_a_widely_excluded_person_match_recalculation() {
+    use gm_core::models::{SolverConfiguration, SolverKind};
+
+    // p0 is excluded from more people than a group holds.
+    let mut builder = ApiInput::builder()
+        .people((0..20).map(|idx| format!("p{idx}")))
+        .sessions(2)
+        .objective("maximize_unique_contacts", 1.0)
+        .solver(
+            SolverConfiguration::builder(SolverKind::Solver1)
+                .seed(9)
+                .build(),
+        );
+    for group in 0..7 {
+        builder = builder.group(format!("g{group}"), 4);
+    }
+    for other in 1..20 {
+        builder = builder.constraint(Constraint::ShouldNotBeTogether {
+            people: vec!["p0".to_string(), format!("p{other}")],
+            penalty_weight: f64::from(other),
+            sessions: (other % 4 == 0).then(|| vec![0]),
+        });
+    }
+    let mut state = State::new(&builder.build()).expect("state");
+    let p0 = state.person_id_to_idx["p0"];
+
+    for day in 0..2 {
+        for to_group in 0..7 {
+            let from_group = state.locations[day][p0].0;
+            if to_group == from_group || state.schedule[day][to_group].len() >= 4 {
+                continue;
+            }
+            let before = state.clone();
+            let delta = state.calculate_transfer_cost_delta(day, p0, from_group, to_group);
+            assert!(delta.is_finite());
+            state.apply_transfer(day, p0, from_group, to_group);
+            assert_delta_matches_after(&before, &state, delta);
+        }
+    }
+}
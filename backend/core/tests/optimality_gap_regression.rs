@@ -0,0 +1,81 @@
+use gm_core::builder::ProblemBuilder;
+use gm_core::models::{ApiInput, Person, SolverConfiguration, SolverKind};
+use gm_core::run_solver;
+use gm_core::solver_support::contact_bound::{optimality_gap, unique_contacts_upper_bound};
+use std::collections::HashMap;
+
+fn problem(people: usize, groups: &[u32], sessions: u32) -> ProblemBuilder {
+    let builder = ApiInput::builder()
+        .people((0..people).map(|idx| format!("p{idx}")))
+        .sessions(sessions)
+        .objective("maximize_unique_contacts", 1.0)
+        .solver(
+            SolverConfiguration::builder(SolverKind::Solver1)
+                .seed(7)
+                .max_iterations(20_000)
+                .build(),
+        );
+    groups
+        .iter()
+        .enumerate()
+        .fold(builder, |builder, (idx, &size)| {
+            builder.group(format!("g{idx}"), size)
+        })
+}
+
+#[test]
+fn bound_is_limited_by_the_number_of_pairs() {
+    // 6 people in two groups of 3 over 3 sessions could form 18 pairs, but only 15 exist.
+    assert_eq!(
+        unique_contacts_upper_bound(&problem(6, &[3, 3], 3).build()),
+        15
+    );
+}
+
+#[test]
+fn bound_is_limited_by_group_sizes() {
+    // Each session seats 3 groups of 4, i.e. 18 pairs, and each person meets at most 3 new
+    // people per session.
+    assert_eq!(
+        unique_contacts_upper_bound(&problem(12, &[4, 4, 4], 2).build()),
+        36
+    );
+}
+
+#[test]
+fn people_who_never_attend_together_cannot_meet() {
+    let part_time = |id: &str, sessions: Vec<u32>| Person {
+        id: id.to_string(),
+        attributes: HashMap::new(),
+        sessions: Some(sessions),
+    };
+    let input = problem(0, &[4], 2)
+        .add_person(part_time("a", vec![0]))
+        .add_person(part_time("b", vec![0]))
+        .add_person(part_time("c", vec![1]))
+        .add_person(part_time("d", vec![1]))
+        .build();
+
+    assert_eq!(unique_contacts_upper_bound(&input), 2);
+}
+
+#[test]
+fn solver_result_reports_the_bound_and_gap() {
+    let input = problem(4, &[2, 2], 3).build();
+
+    let result = run_solver(&input).expect("solve succeeds");
+
+    assert_eq!(result.unique_contacts_upper_bound, Some(6));
+    assert_eq!(
+        result.optimality_gap,
+        Some(optimality_gap(result.unique_contacts, 6))
+    );
+    assert!(result.unique_contacts <= 6);
+}
+
+#[test]
+fn gap_is_the_missing_share_of_the_bound() {
+    assert_eq!(optimality_gap(15, 20), 0.25);
+    assert_eq!(optimality_gap(20, 20), 0.0);
+    assert_eq!(optimality_gap(0, 0), 0.0);
+}
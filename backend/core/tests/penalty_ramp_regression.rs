@@ -0,0 +1,127 @@
+use gm_core::models::{
+    ApiInput, Constraint, Group, Objective, PenaltyRampParams, Person, ProblemDefinition,
+    RepeatEncounterParams, SimulatedAnnealingParams, SolverKind, SolverParams,
+};
+use gm_core::{default_solver_configuration_for, run_solver};
+use std::collections::HashMap;
+
+fn ramped_input(ramp: PenaltyRampParams) -> ApiInput {
+    let mut solver = default_solver_configuration_for(SolverKind::Solver1);
+    solver.seed = Some(41);
+    solver.stop_conditions.max_iterations = Some(5_000);
+    solver.stop_conditions.time_limit_seconds = None;
+    solver.stop_conditions.no_improvement_iterations = None;
+    solver.stop_conditions.stop_on_optimal_score = false;
+    if let SolverParams::SimulatedAnnealing(params) = &mut solver.solver_params {
+        params.penalty_ramp = Some(ramp);
+    }
+
+    ApiInput {
+        initial_schedule: None,
+        construction_seed_schedule: None,
+        contact_history: None,
+        problem: ProblemDefinition {
+            people: (0..12)
+                .map(|idx| Person {
+                    id: format!("p{idx}"),
+                    attributes: HashMap::new(),
+                    sessions: None,
+                })
+                .collect(),
+            groups: (0..3)
+                .map(|idx| Group {
+                    id: format!("g{idx}"),
+                    size: 4,
+                    session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
+                })
+                .collect(),
+            num_sessions: 3,
+        },
+        objectives: vec![Objective {
+            r#type: "maximize_unique_contacts".to_string(),
+            weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
+        }],
+        constraints: vec![
+            Constraint::RepeatEncounter(RepeatEncounterParams {
+                max_allowed_encounters: 1,
+                penalty_function: "squared".to_string(),
+                penalty_weight: 100.0,
+            }),
+            Constraint::ShouldNotBeTogether {
+                people: vec!["p0".to_string(), "p1".to_string(), "p2".to_string()],
+                penalty_weight: 1_000.0,
+                sessions: None,
+            },
+        ],
+        solver,
+    }
+}
+
+#[test]
+fn ramped_run_tightens_back_into_feasibility() {
+    let result = run_solver(&ramped_input(PenaltyRampParams {
+        initial_scale: 0.0,
+        ramp_fraction: 0.5,
+    }))
+    .expect("ramped run should solve");
+
+    assert_eq!(result.constraint_penalty, 0);
+    assert!(result.unique_contacts > 0);
+}
+
+#[test]
+fn penalty_scale_grows_linearly_then_stays_at_full_strength() {
+    let ramp = PenaltyRampParams {
+        initial_scale: 0.2,
+        ramp_fraction: 0.5,
+    };
+
+    assert_eq!(ramp.scale_at(0.0), 0.2);
+    assert!((ramp.scale_at(0.25) - 0.6).abs() < 1e-12);
+    assert_eq!(ramp.scale_at(0.5), 1.0);
+    assert_eq!(ramp.scale_at(0.9), 1.0);
+}
+
+#[test]
+fn out_of_range_ramp_parameters_are_rejected() {
+    let error = run_solver(&ramped_input(PenaltyRampParams {
+        initial_scale: 1.5,
+        ramp_fraction: 0.5,
+    }))
+    .expect_err("initial_scale above 1 is invalid");
+    assert!(error
+        .to_string()
+        .contains("penalty_ramp.initial_scale must be in [0, 1]"));
+
+    let error = run_solver(&ramped_input(PenaltyRampParams {
+        initial_scale: 0.1,
+        ramp_fraction: 0.0,
+    }))
+    .expect_err("ramp_fraction of zero is invalid");
+    assert!(error
+        .to_string()
+        .contains("penalty_ramp.ramp_fraction must be in (0, 1]"));
+}
+
+#[test]
+fn empty_ramp_object_uses_documented_defaults() {
+    let params: SimulatedAnnealingParams = serde_json::from_value(serde_json::json!({
+        "initial_temperature": 1.0,
+        "final_temperature": 0.1,
+        "cooling_schedule": "geometric",
+        "penalty_ramp": {}
+    }))
+    .expect("params should deserialize");
+
+    assert_eq!(
+        params.penalty_ramp,
+        Some(PenaltyRampParams {
+            initial_scale: 0.1,
+            ramp_fraction: 0.5,
+        })
+    );
+}
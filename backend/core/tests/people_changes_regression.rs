@@ -0,0 +1,162 @@
+use gm_core::builder::ProblemBuilder;
+use gm_core::models::{
+    ApiInput, Constraint, PeopleChangeOptions, Person, SolverConfiguration, SolverKind,
+};
+use gm_core::solver_support::people_changes::people_change_input;
+use gm_core::{resolve_with_people_changes, run_solver};
+use std::collections::HashMap;
+
+fn problem() -> ProblemBuilder {
+    ApiInput::builder()
+        .people((0..8).map(|idx| format!("p{idx}")))
+        .group("g0", 5)
+        .group("g1", 5)
+        .sessions(3)
+        .solver(
+            SolverConfiguration::builder(SolverKind::Solver1)
+                .seed(11)
+                .max_iterations(2_000)
+                .build(),
+        )
+}
+
+fn person(id: &str, sessions: Option<Vec<u32>>) -> Person {
+    Person {
+        id: id.to_string(),
+        attributes: HashMap::new(),
+        sessions,
+    }
+}
+
+fn group_of(schedule: &gm_core::models::ApiSchedule, session: u32, person_id: &str) -> String {
+    schedule[&format!("session_{session}")]
+        .iter()
+        .find(|(_, members)| members.iter().any(|member| member == person_id))
+        .map(|(group_id, _)| group_id.clone())
+        .unwrap_or_else(|| panic!("{person_id} is not seated in session {session}"))
+}
+
+#[test]
+fn late_registration_and_cancellation_keep_everyone_else_in_place() {
+    let input = problem().build();
+    let previous = run_solver(&input).expect("initial solve");
+
+    let mut people: Vec<Person> = input.problem.people.clone();
+    people.retain(|person| person.id != "p0");
+    people.push(person("late", None));
+
+    let revised =
+        resolve_with_people_changes(&input, &previous, &people, &PeopleChangeOptions::default())
+            .expect("re-solve");
+
+    for session in 0..3 {
+        for idx in 1..8 {
+            let person_id = format!("p{idx}");
+            assert_eq!(
+                group_of(&revised.schedule, session, &person_id),
+                group_of(&previous.schedule, session, &person_id),
+                "{person_id} moved in session {session}"
+            );
+        }
+        group_of(&revised.schedule, session, "late");
+        assert!(revised.schedule[&format!("session_{session}")]
+            .values()
+            .all(|members| !members.contains(&"p0".to_string())));
+    }
+}
+
+#[test]
+fn constraints_lose_cancelled_people() {
+    let input = problem()
+        .constraint(Constraint::MustStayTogether {
+            people: vec!["p0".to_string(), "p1".to_string(), "p2".to_string()],
+            sessions: None,
+        })
+        .constraint(Constraint::ShouldNotBeTogether {
+            people: vec!["p0".to_string(), "p3".to_string()],
+            penalty_weight: 10.0,
+            sessions: None,
+        })
+        .build();
+    let previous = run_solver(&input).expect("initial solve");
+    let people: Vec<Person> = input.problem.people[1..].to_vec();
+
+    let revised = people_change_input(
+        &input,
+        &previous.schedule,
+        &people,
+        &PeopleChangeOptions::default(),
+    );
+
+    let remaining: Vec<&Constraint> = revised
+        .constraints
+        .iter()
+        .filter(|constraint| !matches!(constraint, Constraint::ImmovablePerson(_)))
+        .collect();
+    assert_eq!(remaining.len(), 1);
+    assert!(matches!(
+        remaining[0],
+        Constraint::MustStayTogether { people, .. } if people == &["p1".to_string(), "p2".to_string()]
+    ));
+    assert!(resolve_with_people_changes(
+        &input,
+        &previous,
+        &people,
+        &PeopleChangeOptions::default()
+    )
+    .is_ok());
+}
+
+#[test]
+fn only_sessions_with_changes_are_searched() {
+    let input = problem()
+        .add_person(person("part_time", Some(vec![0])))
+        .build();
+    let previous = run_solver(&input).expect("initial solve");
+
+    let mut people = input.problem.people.clone();
+    people.last_mut().expect("part_time").sessions = Some(vec![0, 2]);
+
+    let revised = people_change_input(
+        &input,
+        &previous.schedule,
+        &people,
+        &PeopleChangeOptions::default(),
+    );
+
+    assert_eq!(revised.solver.allowed_sessions, Some(vec![2]));
+    let pinned_sessions: Vec<u32> = revised
+        .constraints
+        .iter()
+        .filter_map(|constraint| match constraint {
+            Constraint::ImmovablePerson(params) if params.person_id == "part_time" => {
+                params.sessions.clone()
+            }
+            _ => None,
+        })
+        .flatten()
+        .collect();
+    assert_eq!(pinned_sessions, vec![0]);
+}
+
+#[test]
+fn unpinned_re_solve_only_seeds_the_previous_schedule() {
+    let input = problem().build();
+    let previous = run_solver(&input).expect("initial solve");
+    let mut people = input.problem.people.clone();
+    people.push(person("late", None));
+
+    let revised = people_change_input(
+        &input,
+        &previous.schedule,
+        &people,
+        &PeopleChangeOptions {
+            keep_unaffected_fixed: false,
+        },
+    );
+
+    assert!(revised.constraints.is_empty());
+    assert_eq!(revised.solver.allowed_sessions, None);
+    assert!(revised.construction_seed_schedule.is_some());
+    assert_eq!(revised.problem.people.len(), 9);
+}
@@ -0,0 +1,56 @@
+use gm_core::models::{ApiInput, Constraint, SessionCapacity};
+use gm_core::problem_stats;
+
+fn together(people: &[&str]) -> Constraint {
+    Constraint::MustStayTogether {
+        people: people.iter().map(|id| id.to_string()).collect(),
+        sessions: None,
+    }
+}
+
+#[test]
+fn stats_count_pairs_capacity_cliques_and_constraints() {
+    let mut input = ApiInput::builder()
+        .people(["a", "b", "c", "d", "e", "f", "g"])
+        .group("g1", 3)
+        .group("g2", 3)
+        .sessions(2)
+        .constraint(together(&["a", "b"]))
+        .constraint(together(&["b", "c"]))
+        .constraint(together(&["d", "e"]))
+        .constraint(Constraint::MustStayApart {
+            people: vec!["a".to_string(), "g".to_string()],
+            sessions: None,
+        })
+        .build();
+    input.problem.people[6].sessions = Some(vec![1]);
+
+    let stats = problem_stats(&input);
+
+    assert_eq!(stats.people, 7);
+    assert_eq!(stats.groups, 2);
+    assert_eq!(stats.num_sessions, 2);
+    assert_eq!(stats.total_pairs, 21);
+    assert!(stats.max_possible_contacts <= 12);
+    assert_eq!(
+        stats.capacity_slack,
+        vec![
+            SessionCapacity {
+                session: 0,
+                attendees: 6,
+                capacity: 6,
+                slack: 0,
+            },
+            SessionCapacity {
+                session: 1,
+                attendees: 7,
+                capacity: 6,
+                slack: -1,
+            },
+        ]
+    );
+    assert_eq!(stats.clique_sizes, vec![3, 2]);
+    assert_eq!(stats.constraint_counts["MustStayTogether"], 3);
+    assert_eq!(stats.constraint_counts["MustStayApart"], 1);
+    assert_eq!(stats.constraint_counts.len(), 2);
+}
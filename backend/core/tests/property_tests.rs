@@ -92,12 +92,15 @@ fn create_test_input(
             id: format!("g{}", i),
             size: group_size,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         })
         .collect();
 
     ApiInput {
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people,
             groups,
@@ -112,6 +115,9 @@ fn create_test_input(
                 time_limit_seconds: None,
                 no_improvement_iterations: None,
                 stop_on_optimal_score: true,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
                 initial_temperature: 1.0,
@@ -119,6 +125,7 @@ fn create_test_input(
                 cooling_schedule: "linear".to_string(),
                 reheat_after_no_improvement: Some(0),
                 reheat_cycles: Some(0),
+                penalty_ramp: None,
             }),
             logging: Default::default(),
             telemetry: Default::default(),
@@ -196,12 +203,15 @@ fn create_test_input_with_sessions(
             id: format!("g{}", i),
             size: group_size,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         })
         .collect();
 
     ApiInput {
         initial_schedule: None,
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people,
             groups,
@@ -216,6 +226,9 @@ fn create_test_input_with_sessions(
                 time_limit_seconds: None,
                 no_improvement_iterations: None,
                 stop_on_optimal_score: true,
+                stop_on_feasible: false,
+                feasible_score_threshold: None,
+                convergence: None,
             },
             solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
                 initial_temperature: 1.0,
@@ -223,6 +236,7 @@ fn create_test_input_with_sessions(
                 cooling_schedule: "linear".to_string(),
                 reheat_after_no_improvement: Some(0),
                 reheat_cycles: Some(0),
+                penalty_ramp: None,
             }),
             logging: Default::default(),
             telemetry: Default::default(),
@@ -1,10 +1,15 @@
-use gm_core::calculate_recommended_settings;
-use gm_core::models::{Group, Person, ProblemDefinition, SolverParams};
+use gm_core::models::{
+    ApiInput, Constraint, Group, Objective, Person, ProblemDefinition, RepeatEncounterParams,
+    SolverKind, SolverParams, TuningBudget,
+};
+use gm_core::{
+    auto_tune, calculate_recommendation_report_for, calculate_recommended_settings,
+    default_solver_configuration_for,
+};
 use std::collections::HashMap;
 
-#[test]
-fn test_calculate_recommended_settings_initial_temp_above_one() {
-    // Build a small but non-trivial problem that can generate cost differences.
+/// A small but non-trivial problem that can generate cost differences.
+fn eight_people_problem() -> ProblemDefinition {
     let num_people = 8u32;
     let mut people = Vec::new();
     for i in 0..num_people {
@@ -21,19 +26,28 @@ fn test_calculate_recommended_settings_initial_temp_above_one() {
             id: "G1".into(),
             size: 4,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         },
         Group {
             id: "G2".into(),
             size: 4,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         },
     ];
 
-    let problem = ProblemDefinition {
+    ProblemDefinition {
         people,
         groups,
         num_sessions: 3,
-    };
+    }
+}
+
+#[test]
+fn test_calculate_recommended_settings_initial_temp_above_one() {
+    let problem = eight_people_problem();
 
     let cfg =
         calculate_recommended_settings(&problem, &[], &[], 1 /* desired runtime seconds */)
@@ -51,3 +65,166 @@ fn test_calculate_recommended_settings_initial_temp_above_one() {
         }
     }
 }
+
+#[test]
+fn solver1_report_derives_temperatures_from_trials() {
+    let objectives = [Objective {
+        r#type: "maximize_unique_contacts".to_string(),
+        weight: 1.0,
+        person_weights: HashMap::new(),
+        session_weights: Vec::new(),
+    }];
+    let constraints = [Constraint::RepeatEncounter(RepeatEncounterParams {
+        max_allowed_encounters: 1,
+        penalty_function: "squared".to_string(),
+        penalty_weight: 10.0,
+    })];
+
+    let report = calculate_recommendation_report_for(
+        SolverKind::Solver1,
+        &eight_people_problem(),
+        &objectives,
+        &constraints,
+        2,
+    )
+    .expect("recommendation should succeed");
+
+    let deltas = report
+        .delta_distribution
+        .expect("solver1 samples move deltas");
+    assert!(deltas.samples > 0);
+    assert_eq!(
+        deltas.improving + deltas.neutral + deltas.worsening,
+        deltas.samples
+    );
+    assert!(deltas.worsening > 0);
+    assert!(deltas.worsening_p10 <= deltas.worsening_median);
+    assert!(deltas.worsening_median <= deltas.worsening_p90);
+
+    assert_eq!(report.trials.len(), 3);
+    assert!(report.trials.iter().all(|trial| trial.iterations > 0));
+    assert!(!report.reasoning.is_empty());
+
+    let SolverParams::SimulatedAnnealing(params) = &report.configuration.solver_params else {
+        panic!("solver1 recommends simulated annealing parameters");
+    };
+    assert!(report
+        .trials
+        .iter()
+        .any(|trial| trial.temperature == params.initial_temperature));
+    assert!(params.final_temperature < params.initial_temperature);
+    assert_eq!(
+        report.configuration.stop_conditions.time_limit_seconds,
+        Some(2)
+    );
+
+    let again = calculate_recommendation_report_for(
+        SolverKind::Solver1,
+        &eight_people_problem(),
+        &objectives,
+        &constraints,
+        2,
+    )
+    .expect("recommendation should succeed");
+    assert_eq!(
+        serde_json::to_value(&again.configuration).unwrap(),
+        serde_json::to_value(&report.configuration).unwrap(),
+        "recommendations are deterministic"
+    );
+}
+
+#[test]
+fn families_without_trials_report_their_configuration_alone() {
+    let report = calculate_recommendation_report_for(
+        SolverKind::Solver3,
+        &eight_people_problem(),
+        &[],
+        &[],
+        1,
+    )
+    .expect("recommendation should succeed");
+
+    assert!(report.delta_distribution.is_none());
+    assert!(report.trials.is_empty());
+    assert!(report.iterations_per_second.is_none());
+    assert_eq!(report.reasoning.len(), 1);
+    assert!(matches!(
+        report.configuration.solver_params,
+        SolverParams::Solver3(_)
+    ));
+}
+
+fn tuning_input(kind: SolverKind) -> ApiInput {
+    let mut solver = default_solver_configuration_for(kind);
+    solver.seed = Some(17);
+    solver.stop_conditions.time_limit_seconds = Some(3);
+    ApiInput {
+        initial_schedule: None,
+        construction_seed_schedule: None,
+        contact_history: None,
+        problem: eight_people_problem(),
+        objectives: vec![Objective {
+            r#type: "maximize_unique_contacts".to_string(),
+            weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
+        }],
+        constraints: vec![Constraint::RepeatEncounter(RepeatEncounterParams {
+            max_allowed_encounters: 1,
+            penalty_function: "squared".to_string(),
+            penalty_weight: 10.0,
+        })],
+        solver,
+    }
+}
+
+#[test]
+fn auto_tune_races_annealing_parameters_deterministically() {
+    let input = tuning_input(SolverKind::Solver1);
+    let budget = TuningBudget {
+        candidates: 4,
+        initial_iterations: 200,
+    };
+
+    let tuned = auto_tune(&input, budget).expect("tuning should succeed");
+
+    assert_eq!(
+        tuned.stop_conditions.time_limit_seconds,
+        input.solver.stop_conditions.time_limit_seconds
+    );
+    assert_eq!(tuned.seed, Some(17));
+    let SolverParams::SimulatedAnnealing(params) = &tuned.solver_params else {
+        panic!("tuning returns simulated annealing parameters");
+    };
+    assert!(params.final_temperature < params.initial_temperature);
+
+    let again = auto_tune(&input, budget).expect("tuning should succeed");
+    assert_eq!(
+        serde_json::to_value(&again).unwrap(),
+        serde_json::to_value(&tuned).unwrap()
+    );
+
+    let mut tuned_input = input.clone();
+    tuned_input.solver = tuned;
+    gm_core::run_solver(&tuned_input).expect("the tuned configuration should solve");
+}
+
+#[test]
+fn auto_tune_switches_other_families_to_simulated_annealing() {
+    let input = tuning_input(SolverKind::Solver3);
+
+    let tuned = auto_tune(
+        &input,
+        TuningBudget {
+            candidates: 2,
+            initial_iterations: 100,
+        },
+    )
+    .expect("tuning should succeed");
+
+    assert_eq!(tuned.validate_solver_selection(), Ok(SolverKind::Solver1));
+    assert_eq!(
+        tuned.stop_conditions.time_limit_seconds,
+        input.solver.stop_conditions.time_limit_seconds
+    );
+}
@@ -0,0 +1,101 @@
+use gm_core::models::{ApiInput, PersonMetrics, ReportFormat, ScoreHistoryPoint, SolverResult};
+use gm_core::run_solver;
+use std::collections::HashMap;
+
+fn result() -> SolverResult {
+    let input = ApiInput::builder()
+        .people(["alice", "bob", "carol", "dave"])
+        .group("g1", 2)
+        .group("g2", 2)
+        .sessions(2)
+        .build();
+    let mut result = run_solver(&input).expect("solve should succeed");
+    let session = |g1: [&str; 2], g2: [&str; 2]| {
+        HashMap::from([
+            ("g1".to_string(), g1.map(str::to_string).to_vec()),
+            ("g2".to_string(), g2.map(str::to_string).to_vec()),
+        ])
+    };
+    result.schedule = HashMap::from([
+        (
+            "session_0".to_string(),
+            session(["alice", "bob"], ["carol", "dave"]),
+        ),
+        (
+            "session_1".to_string(),
+            session(["alice", "bob"], ["carol", "<dave>"]),
+        ),
+    ]);
+    result.score_history = None;
+    result.constraint_violations = Some(Vec::new());
+    result
+}
+
+fn metrics(person_id: &str, sessions: u32, unique: u32, repeat: u32) -> PersonMetrics {
+    PersonMetrics {
+        person_id: person_id.to_string(),
+        sessions,
+        unique_contacts: unique,
+        repeat_contacts: repeat,
+    }
+}
+
+#[test]
+fn person_metrics_count_sessions_contacts_and_repeats() {
+    assert_eq!(
+        result().person_metrics(),
+        vec![
+            metrics("<dave>", 1, 1, 0),
+            metrics("alice", 2, 1, 1),
+            metrics("bob", 2, 1, 1),
+            metrics("carol", 2, 2, 0),
+            metrics("dave", 1, 1, 0),
+        ]
+    );
+}
+
+#[test]
+fn markdown_report_has_every_section() {
+    let report = result().to_report(ReportFormat::Markdown);
+
+    assert!(report.starts_with("# Schedule report\n"));
+    for heading in [
+        "## Summary",
+        "## Schedule",
+        "### Session 0",
+        "### Session 1",
+        "## People",
+        "## Constraint violations",
+        "## Convergence",
+    ] {
+        assert!(report.contains(&format!("\n{heading}\n")), "{heading}");
+    }
+    assert!(report.contains("| alice | 2 | 1 | 1 |\n"));
+    assert!(report.contains("No constraints are violated."));
+    assert!(!report.contains("<svg"));
+}
+
+#[test]
+fn html_report_escapes_text_and_charts_score_history() {
+    let mut result = result();
+    result.score_history = Some(
+        [(0, 10.0), (100, 6.0), (200, 4.0)]
+            .into_iter()
+            .map(|(iteration, cost)| ScoreHistoryPoint {
+                iteration,
+                current_cost: cost,
+                best_cost: cost,
+                temperature: None,
+            })
+            .collect(),
+    );
+
+    let report = result.to_report(ReportFormat::Html);
+
+    assert!(report.starts_with("<!DOCTYPE html>"));
+    assert!(report.ends_with("</html>\n"));
+    assert!(report.contains("<td>&lt;dave&gt;</td>"));
+    assert!(!report.contains("<dave>"));
+    assert!(report.contains("<svg"));
+    assert!(report.contains("iteration 200"));
+}
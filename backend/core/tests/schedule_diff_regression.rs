@@ -0,0 +1,78 @@
+mod common;
+
+use common::make_initial_schedule;
+use gm_core::diff_schedules;
+use gm_core::models::PersonMove;
+
+#[test]
+fn identical_schedules_have_no_churn() {
+    let schedule = make_initial_schedule(
+        &["g0", "g1"],
+        vec![vec![vec!["p0", "p1"], vec!["p2", "p3"]]],
+    );
+
+    let diff = diff_schedules(&schedule, &schedule);
+    assert_eq!(diff.total_moves, 0);
+    assert_eq!(diff.churn_rate, 0.0);
+    assert_eq!(diff.sessions.len(), 1);
+    assert!(diff.sessions[0].moves.is_empty());
+}
+
+#[test]
+fn moves_are_listed_per_session_with_churn() {
+    let before = make_initial_schedule(
+        &["g0", "g1"],
+        vec![
+            vec![vec!["p0", "p1"], vec!["p2", "p3"]],
+            vec![vec!["p0", "p2"], vec!["p1", "p3"]],
+        ],
+    );
+    let after = make_initial_schedule(
+        &["g0", "g1"],
+        vec![
+            vec![vec!["p0", "p1"], vec!["p2", "p3"]],
+            vec![vec!["p0", "p3"], vec!["p1", "p2"]],
+        ],
+    );
+
+    let diff = diff_schedules(&before, &after);
+    let sessions: Vec<&str> = diff
+        .sessions
+        .iter()
+        .map(|session| session.session.as_str())
+        .collect();
+    assert_eq!(sessions, vec!["session_0", "session_1"]);
+    assert!(diff.sessions[0].moves.is_empty());
+    assert_eq!(
+        diff.sessions[1].moves,
+        vec![
+            PersonMove {
+                person_id: "p2".to_string(),
+                from_group: Some("g0".to_string()),
+                to_group: Some("g1".to_string()),
+            },
+            PersonMove {
+                person_id: "p3".to_string(),
+                from_group: Some("g1".to_string()),
+                to_group: Some("g0".to_string()),
+            },
+        ]
+    );
+    assert_eq!(diff.sessions[1].people_moved, 2);
+    assert_eq!(diff.sessions[1].churn_rate, 0.5);
+    assert_eq!(diff.total_moves, 2);
+    assert_eq!(diff.churn_rate, 0.25);
+}
+
+#[test]
+fn people_assigned_in_only_one_schedule_count_as_moved() {
+    let before = make_initial_schedule(&["g0"], vec![vec![vec!["p0", "p1"]]]);
+    let after = make_initial_schedule(&["g0"], vec![vec![vec!["p0", "p2"]]]);
+
+    let moves = &diff_schedules(&before, &after).sessions[0].moves;
+    assert_eq!(moves.len(), 2);
+    assert_eq!(moves[0].person_id, "p1");
+    assert_eq!(moves[0].to_group, None);
+    assert_eq!(moves[1].person_id, "p2");
+    assert_eq!(moves[1].from_group, None);
+}
@@ -2,21 +2,22 @@ mod common;
 
 use common::{default_solver_config, make_initial_schedule};
 use gm_core::models::{
-    ApiInput, BenchmarkEvent, Constraint, Group, MoveFamily, MovePolicy, Objective,
-    PairMeetingCountParams, PairMeetingMode, Person, ProblemDefinition, ProgressCallback,
-    RepeatEncounterParams, SimulatedAnnealingParams, Solver3CorrectnessLaneParams,
+    ApiInput, ApiSchedule, BenchmarkEvent, Constraint, ConvergenceStop, Group, MoveFamily,
+    MovePolicy, Objective, PairMeetingCountParams, PairMeetingMode, Person, ProblemDefinition,
+    ProgressCallback, ProgressControl, ProgressControlCallback, ProgressUpdate,
+    RepeatEncounterParams, ScheduleDiff, SimulatedAnnealingParams, Solver3CorrectnessLaneParams,
     Solver3DonorSessionTransplantParams, Solver3HotspotGuidanceParams, Solver3LocalImproverMode,
     Solver3LocalImproverParams, Solver3MultiRootBalancedSessionInheritanceParams, Solver3Params,
     Solver3PathRelinkingOperatorVariant, Solver3RepeatGuidedSwapParams, Solver3SearchDriverMode,
     Solver3SearchDriverParams, Solver3SessionAlignedPathRelinkingParams,
-    Solver3SgpWeekPairTabuParams, SolverKind, SolverParams, StopReason,
+    Solver3SgpWeekPairTabuParams, SolverKind, SolverParams, SolverPhase, StopReason,
 };
 use gm_core::{
     default_solver_configuration_for, run_solver, run_solver_with_benchmark_observer,
-    run_solver_with_callbacks, run_solver_with_progress,
+    run_solver_with_callbacks, run_solver_with_control, run_solver_with_progress,
 };
 use serde::Deserialize;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, Mutex};
 
 #[derive(Debug, Deserialize)]
@@ -54,11 +55,13 @@ fn driver_input() -> ApiInput {
         cooling_schedule: "geometric".to_string(),
         reheat_after_no_improvement: Some(0),
         reheat_cycles: Some(0),
+        penalty_ramp: None,
     });
 
     ApiInput {
         initial_schedule: Some(warm_start_schedule()),
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people: vec![person("p0"), person("p1"), person("p2"), person("p3")],
             groups: vec![
@@ -66,11 +69,15 @@ fn driver_input() -> ApiInput {
                     id: "g0".to_string(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g1".to_string(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 3,
@@ -78,6 +85,8 @@ fn driver_input() -> ApiInput {
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![],
         solver,
@@ -145,6 +154,7 @@ fn solver3_transfer_driver_input() -> ApiInput {
             ],
         )),
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people: vec![
                 person("p0"),
@@ -158,16 +168,22 @@ fn solver3_transfer_driver_input() -> ApiInput {
                     id: "g0".to_string(),
                     size: 3,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g1".to_string(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g2".to_string(),
                     size: 2,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 2,
@@ -175,6 +191,8 @@ fn solver3_transfer_driver_input() -> ApiInput {
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![Constraint::PairMeetingCount(PairMeetingCountParams {
             people: vec!["p0".to_string(), "p1".to_string()],
@@ -205,6 +223,7 @@ fn solver3_clique_driver_input() -> ApiInput {
             ],
         )),
         construction_seed_schedule: None,
+        contact_history: None,
         problem: ProblemDefinition {
             people: vec![
                 person("p0"),
@@ -219,16 +238,22 @@ fn solver3_clique_driver_input() -> ApiInput {
                     id: "g0".to_string(),
                     size: 3,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g1".to_string(),
                     size: 3,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
                 Group {
                     id: "g2".to_string(),
                     size: 1,
                     session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
                 },
             ],
             num_sessions: 2,
@@ -236,6 +261,8 @@ fn solver3_clique_driver_input() -> ApiInput {
         objectives: vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }],
         constraints: vec![
             Constraint::MustStayTogether {
@@ -282,6 +309,7 @@ fn cycle_reheating_is_reported_in_benchmark_telemetry() {
         cooling_schedule: "geometric".to_string(),
         reheat_after_no_improvement: Some(0),
         reheat_cycles: Some(3),
+        penalty_ramp: None,
     });
 
     let result = run_solver(&input).expect("solve should succeed");
@@ -308,6 +336,330 @@ fn cycle_reheating_is_reported_in_benchmark_telemetry() {
     assert!(telemetry.iterations_per_second >= 0.0);
 }
 
+#[test]
+fn solver1_returns_the_best_schedule_after_wandering_uphill() {
+    let mut input = driver_input();
+    input.solver.stop_conditions.max_iterations = Some(300);
+    input.solver.solver_params = SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
+        initial_temperature: 50.0,
+        final_temperature: 10.0,
+        cooling_schedule: "geometric".to_string(),
+        reheat_after_no_improvement: Some(0),
+        reheat_cycles: Some(0),
+        penalty_ramp: None,
+    });
+
+    let result = run_solver(&input).expect("solve should succeed");
+    assert!(result.accepted_moves.unwrap_or(0) > 0);
+
+    let mut rebuilt = input.clone();
+    rebuilt.initial_schedule = Some(result.schedule.clone());
+    let best = gm_core::solver1::State::new(&rebuilt).expect("best schedule should rebuild");
+    assert!((best.current_cost - result.final_score).abs() < 1e-9);
+    let initial = gm_core::solver1::State::new(&input).expect("initial schedule should build");
+    assert!(result.final_score <= initial.current_cost);
+}
+
+#[test]
+fn solver1_result_reports_run_statistics() {
+    let mut input = driver_input();
+    input.solver.stop_conditions.max_iterations = Some(25);
+
+    let result = run_solver(&input).expect("solve should succeed");
+    let telemetry = result
+        .benchmark_telemetry
+        .clone()
+        .expect("benchmark telemetry should be present");
+
+    assert_eq!(result.iterations_run, Some(25));
+    assert_eq!(result.time_elapsed_ms, Some(telemetry.total_milliseconds()));
+    assert_eq!(result.accepted_moves, Some(telemetry.accepted_moves()));
+    let final_temperature = result
+        .final_temperature
+        .expect("annealing runs report their last temperature");
+    assert!((0.1..3.0).contains(&final_temperature));
+    assert_eq!(result.score_history, None);
+}
+
+#[test]
+fn solver1_records_sampled_score_history_when_enabled() {
+    let mut input = driver_input();
+    input.solver.stop_conditions.max_iterations = Some(25);
+    input.solver.telemetry.record_score_history = true;
+    input.solver.telemetry.score_history_every_n_iterations = 10;
+
+    let result = run_solver(&input).expect("solve should succeed");
+    let history = result.score_history.expect("score history was requested");
+
+    let iterations: Vec<u64> = history.iter().map(|point| point.iteration).collect();
+    assert_eq!(iterations, vec![0, 10, 20, 25]);
+    assert!(history
+        .windows(2)
+        .all(|points| points[1].best_cost <= points[0].best_cost));
+    assert!(history
+        .iter()
+        .all(|point| point.best_cost <= point.current_cost + 1e-9 && point.temperature.is_some()));
+}
+
+#[test]
+fn solver1_records_move_statistics_windows_when_enabled() {
+    let mut input = driver_input();
+    input.solver.stop_conditions.max_iterations = Some(25);
+    input.solver.telemetry.record_move_statistics = true;
+    input.solver.telemetry.move_statistics_every_n_iterations = 10;
+
+    let result = run_solver(&input).expect("solve should succeed");
+    let windows = result
+        .move_statistics
+        .expect("move statistics were requested");
+
+    let iterations: Vec<u64> = windows.iter().map(|point| point.iteration).collect();
+    assert_eq!(iterations, vec![10, 20, 25]);
+    for point in &windows {
+        for counts in [point.swap, point.transfer, point.clique_swap] {
+            assert!(counts.accepted <= counts.proposed);
+        }
+        assert!(point.uphill_accepted <= point.uphill_proposed);
+        assert_eq!(
+            point.uphill_acceptance_rate.is_some(),
+            point.uphill_proposed > 0
+        );
+        assert!(point.temperature.is_some());
+    }
+
+    let updates = collect_progress(&input);
+    let streamed: Vec<_> = updates
+        .iter()
+        .flat_map(|update| update.move_statistics.clone().unwrap_or_default())
+        .collect();
+    assert_eq!(streamed, windows);
+    let last = updates.last().expect("final progress update");
+    let proposed: u64 = windows
+        .iter()
+        .map(|point| point.swap.proposed + point.transfer.proposed + point.clique_swap.proposed)
+        .sum();
+    assert_eq!(
+        proposed,
+        last.swaps_tried + last.transfers_tried + last.clique_swaps_tried
+    );
+}
+
+#[test]
+fn solver1_samples_constraint_penalties_by_input_index_when_enabled() {
+    let mut input = driver_input();
+    input.constraints = vec![
+        Constraint::ShouldNotBeTogether {
+            people: vec!["p0".to_string(), "p1".to_string()],
+            penalty_weight: 7.0,
+            sessions: None,
+        },
+        Constraint::MustStayTogether {
+            people: vec!["p2".to_string(), "p3".to_string()],
+            sessions: Some(vec![0]),
+        },
+        Constraint::RepeatEncounter(RepeatEncounterParams {
+            max_allowed_encounters: 1,
+            penalty_function: "linear".to_string(),
+            penalty_weight: 3.0,
+        }),
+        Constraint::ShouldStayTogether {
+            people: vec!["p0".to_string(), "p2".to_string()],
+            penalty_weight: 5.0,
+            sessions: None,
+        },
+    ];
+    input.solver.stop_conditions.max_iterations = Some(25);
+    input.solver.telemetry.record_constraint_penalties = true;
+    input
+        .solver
+        .telemetry
+        .constraint_penalties_every_n_iterations = 10;
+
+    let result = run_solver(&input).expect("solve should succeed");
+    let samples = result
+        .constraint_penalties
+        .expect("constraint penalties were requested");
+
+    let iterations: Vec<u64> = samples.iter().map(|point| point.iteration).collect();
+    assert_eq!(iterations, vec![0, 10, 20, 25]);
+    for point in &samples {
+        let indices: Vec<u32> = point
+            .penalties
+            .iter()
+            .map(|penalty| penalty.constraint_index)
+            .collect();
+        assert_eq!(indices, vec![0, 2, 3], "the hard constraint is left out");
+        let weighted = |index: usize| point.penalties[index].weighted_penalty;
+        assert_eq!(weighted(0) % 7.0, 0.0);
+        assert_eq!(weighted(1) % 3.0, 0.0);
+        assert_eq!(weighted(2) % 5.0, 0.0);
+    }
+
+    let streamed: Vec<_> = collect_progress(&input)
+        .iter()
+        .flat_map(|update| update.constraint_penalties.clone().unwrap_or_default())
+        .collect();
+    assert_eq!(streamed, samples);
+}
+
+#[test]
+fn solver1_progress_reports_phase_and_remaining_time() {
+    let mut input = driver_input();
+    input.solver.stop_conditions.max_iterations = Some(25);
+
+    let updates = collect_progress(&input);
+    let first = updates
+        .first()
+        .expect("the first iteration reports progress");
+    assert_eq!(first.phase, Some(SolverPhase::Initializing));
+    let last = updates.last().expect("the run reports final progress");
+    assert!(last.stop_reason.is_some());
+    assert_eq!(last.phase, Some(SolverPhase::Polishing));
+    assert_eq!(last.estimated_remaining_seconds, Some(0.0));
+}
+
+fn collect_progress(input: &ApiInput) -> Vec<ProgressUpdate> {
+    let updates = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&updates);
+    let callback: ProgressCallback = Box::new(move |update| {
+        sink.lock().unwrap().push(update.clone());
+        true
+    });
+    run_solver_with_progress(input, Some(&callback)).expect("solve should succeed");
+    let updates = updates.lock().unwrap().clone();
+    updates
+}
+
+fn sorted_groups(schedule: &ApiSchedule) -> BTreeMap<String, BTreeMap<String, Vec<String>>> {
+    schedule
+        .iter()
+        .map(|(session, groups)| {
+            let groups = groups
+                .iter()
+                .map(|(group, members)| {
+                    let mut members = members.clone();
+                    members.sort();
+                    (group.clone(), members)
+                })
+                .collect();
+            (session.clone(), groups)
+        })
+        .collect()
+}
+
+fn apply_diff(schedule: &mut ApiSchedule, diff: &ScheduleDiff) {
+    for session in &diff.sessions {
+        let groups = schedule.entry(session.session.clone()).or_default();
+        for person_move in &session.moves {
+            if let Some(from) = &person_move.from_group {
+                groups
+                    .get_mut(from)
+                    .unwrap()
+                    .retain(|person| person != &person_move.person_id);
+            }
+            if let Some(to) = &person_move.to_group {
+                groups
+                    .entry(to.clone())
+                    .or_default()
+                    .push(person_move.person_id.clone());
+            }
+        }
+    }
+}
+
+#[test]
+fn drift_checks_leave_the_search_unchanged() {
+    let mut input = driver_input();
+    input.solver.stop_conditions.max_iterations = Some(60);
+    let unchecked = run_solver(&input).expect("solver1 should solve");
+    input.solver.telemetry.drift_check_every_n_iterations = 7;
+    let checked = run_solver(&input).expect("solver1 should solve with drift checks");
+
+    assert_eq!(checked.schedule, unchecked.schedule);
+    assert_eq!(checked.final_score, unchecked.final_score);
+}
+
+#[test]
+fn best_schedule_diffs_reconstruct_the_full_snapshots() {
+    let mut input = driver_input();
+    input.solver.stop_conditions.max_iterations = Some(25);
+    input.solver.telemetry.emit_best_schedule = true;
+    input.solver.telemetry.best_schedule_every_n_callbacks = 1;
+    // Checkpoints force a progress callback, giving a deterministic callback cadence.
+    input.solver.telemetry.checkpoint_every_n_iterations = 5;
+    let full_updates = collect_progress(&input);
+    input.solver.telemetry.best_schedule_as_diff = true;
+    let diff_updates = collect_progress(&input);
+
+    assert_eq!(full_updates.len(), diff_updates.len());
+    assert!(diff_updates.len() > 2);
+    assert!(diff_updates[0].best_schedule.is_some());
+    assert!(diff_updates.last().unwrap().best_schedule.is_some());
+
+    let mut reconstructed = diff_updates[0].best_schedule.clone().unwrap();
+    for (full, compact) in full_updates.iter().zip(&diff_updates).skip(1) {
+        if compact.stop_reason.is_some() {
+            break;
+        }
+        assert!(compact.best_schedule.is_none());
+        apply_diff(
+            &mut reconstructed,
+            compact.best_schedule_diff.as_ref().unwrap(),
+        );
+        assert_eq!(
+            sorted_groups(&reconstructed),
+            sorted_groups(full.best_schedule.as_ref().unwrap())
+        );
+    }
+}
+
+#[test]
+fn effective_configuration_reproduces_an_unseeded_run() {
+    let mut input = driver_input();
+    input.solver.seed = None;
+    input.solver.stop_conditions.max_iterations = Some(25);
+
+    let first = run_solver(&input).expect("solve should succeed");
+    let effective = first
+        .effective_configuration
+        .clone()
+        .expect("results echo their configuration");
+    assert_eq!(effective.seed, first.effective_seed);
+    assert_eq!(effective.move_policy, first.move_policy);
+
+    let mut replay_input = input.clone();
+    replay_input.solver = effective;
+    let replay = run_solver(&replay_input).expect("replay should succeed");
+    assert_eq!(replay.schedule, first.schedule);
+    assert_eq!(replay.final_score, first.final_score);
+}
+
+#[test]
+fn effective_configuration_fills_in_defaulted_max_iterations() {
+    let mut input = driver_input();
+    input.solver.stop_conditions.max_iterations = None;
+    input.solver.stop_conditions.time_limit_seconds = None;
+    input.solver.stop_conditions.no_improvement_iterations = Some(50);
+
+    let result = run_solver(&input).expect("solve should succeed");
+    let effective = result.effective_configuration.expect("configuration");
+    assert_eq!(effective.stop_conditions.max_iterations, Some(100_000));
+}
+
+#[test]
+fn effective_configuration_of_auto_names_the_chosen_solver() {
+    let mut input = driver_input();
+    input.solver = default_solver_configuration_for(SolverKind::Auto);
+    input.solver.seed = Some(3);
+
+    let result = run_solver(&input).expect("auto should solve");
+    let effective = result.effective_configuration.expect("configuration");
+    assert_eq!(effective.solver_params.solver_kind(), SolverKind::Solver3);
+    assert_eq!(effective.solver_type, "solver3");
+    assert!(effective.stop_conditions.max_iterations.is_some());
+    assert!(effective.stop_conditions.time_limit_seconds.is_some());
+}
+
 #[test]
 fn no_improvement_reheating_is_reported_in_benchmark_telemetry() {
     let mut input = driver_input();
@@ -317,6 +669,8 @@ fn no_improvement_reheating_is_reported_in_benchmark_telemetry() {
             id: "solo".to_string(),
             size: 2,
             session_sizes: None,
+            attributes: HashMap::new(),
+            available_sessions: None,
         }],
         num_sessions: 1,
     };
@@ -331,6 +685,7 @@ fn no_improvement_reheating_is_reported_in_benchmark_telemetry() {
         cooling_schedule: "geometric".to_string(),
         reheat_after_no_improvement: Some(2),
         reheat_cycles: Some(0),
+        penalty_ramp: None,
     });
 
     let result = run_solver(&input).expect("solve should succeed");
@@ -359,6 +714,113 @@ fn time_limit_stop_reason_surfaces_through_result_and_telemetry() {
     assert!(telemetry.perturbation_count.is_none());
 }
 
+#[test]
+fn converged_search_stops_before_the_iteration_limit() {
+    let mut input = driver_input();
+    input.solver.stop_conditions.max_iterations = Some(1_000_000);
+    input.solver.stop_conditions.convergence = Some(ConvergenceStop {
+        window_iterations: 500,
+        max_acceptance_rate: 0.05,
+        max_temperature: Some(0.5),
+    });
+    input.solver.solver_params = SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
+        initial_temperature: 0.01,
+        final_temperature: 0.001,
+        cooling_schedule: "geometric".to_string(),
+        reheat_after_no_improvement: Some(0),
+        reheat_cycles: Some(0),
+        penalty_ramp: None,
+    });
+
+    let result = run_solver(&input).expect("solve should succeed");
+    let telemetry = result
+        .benchmark_telemetry
+        .clone()
+        .expect("benchmark telemetry should be present");
+
+    assert_eq!(result.stop_reason, Some(StopReason::Converged));
+    assert_eq!(telemetry.stop_reason, StopReason::Converged);
+    assert!(telemetry.iterations_completed < 1_000_000);
+    assert!(telemetry.iterations_completed.is_multiple_of(500));
+
+    input.solver.stop_conditions.convergence = Some(ConvergenceStop {
+        max_temperature: Some(0.0001),
+        ..ConvergenceStop::default()
+    });
+    input.solver.stop_conditions.max_iterations = Some(20_000);
+    let too_warm = run_solver(&input).expect("solve should succeed");
+    assert_eq!(too_warm.stop_reason, Some(StopReason::MaxIterationsReached));
+}
+
+#[test]
+fn feasible_stop_waits_for_the_score_threshold() {
+    let mut input = driver_input();
+    input.solver.stop_conditions.max_iterations = Some(2_000);
+    input.solver.stop_conditions.stop_on_feasible = true;
+
+    let result = run_solver(&input).expect("solve should succeed");
+    assert_eq!(result.stop_reason, Some(StopReason::FeasibleReached));
+    assert_eq!(
+        result
+            .benchmark_telemetry
+            .as_ref()
+            .expect("benchmark telemetry should be present")
+            .iterations_completed,
+        1
+    );
+
+    input.solver.stop_conditions.feasible_score_threshold = Some(-1.0);
+    let below_threshold = run_solver(&input).expect("solve should succeed");
+    assert_eq!(
+        below_threshold.stop_reason,
+        Some(StopReason::MaxIterationsReached)
+    );
+}
+
+#[test]
+fn only_solver1_accepts_a_feasible_stop() {
+    let kinds = [
+        SolverKind::Solver3,
+        SolverKind::Solver4,
+        SolverKind::Solver5,
+        SolverKind::Solver6,
+        SolverKind::Auto,
+    ];
+    for kind in kinds {
+        let mut input = driver_input();
+        input.solver = default_solver_configuration_for(kind);
+        input.solver.stop_conditions.stop_on_feasible = true;
+        let error = run_solver(&input).expect_err("stop_on_feasible is solver1 only");
+        assert!(error.to_string().contains(&format!(
+            "{} does not support stop_conditions.stop_on_feasible",
+            kind.canonical_id()
+        )));
+
+        input.solver.stop_conditions.stop_on_feasible = false;
+        input.solver.stop_conditions.feasible_score_threshold = Some(10.0);
+        let error = run_solver(&input).expect_err("feasible_score_threshold is solver1 only");
+        assert!(error
+            .to_string()
+            .contains("does not support stop_conditions.feasible_score_threshold"));
+    }
+}
+
+#[test]
+fn only_solver1_accepts_a_convergence_stop() {
+    for kind in [SolverKind::Solver3, SolverKind::Auto] {
+        let mut input = driver_input();
+        input.solver = default_solver_configuration_for(kind);
+        input.solver.stop_conditions.convergence = Some(ConvergenceStop::default());
+
+        let error = run_solver(&input).expect_err("convergence is solver1 only");
+
+        assert!(error.to_string().contains(&format!(
+            "{} does not support stop_conditions.convergence",
+            kind.canonical_id()
+        )));
+    }
+}
+
 #[test]
 fn progress_callback_can_request_early_stop() {
     let mut input = driver_input();
@@ -381,6 +843,65 @@ fn progress_callback_can_request_early_stop() {
     assert!(*calls.lock().unwrap() >= 1);
 }
 
+#[test]
+fn control_callback_can_request_a_checkpoint_and_a_reheat() {
+    let mut input = driver_input();
+    input.solver.stop_conditions.max_iterations = Some(25);
+
+    let updates = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&updates);
+    let controller: ProgressControlCallback = Box::new(move |update| {
+        let mut updates = sink.lock().unwrap();
+        updates.push(update.clone());
+        match updates.len() {
+            1 => ProgressControl::SaveCheckpoint,
+            2 => ProgressControl::Reheat,
+            _ => ProgressControl::Continue,
+        }
+    });
+    let result = run_solver_with_control(&input, Some(&controller)).expect("solve should succeed");
+
+    let updates = updates.lock().unwrap();
+    let checkpoint = updates[1]
+        .checkpoint
+        .as_ref()
+        .expect("the update after the request carries a checkpoint");
+    assert_eq!(checkpoint.iterations_completed, 1);
+    let telemetry = result
+        .benchmark_telemetry
+        .expect("benchmark telemetry should be present");
+    assert_eq!(telemetry.reheats_performed, 1);
+    assert_eq!(result.stop_reason, Some(StopReason::MaxIterationsReached));
+}
+
+#[test]
+fn control_callback_can_stop_solver3() {
+    let mut input = solver3_driver_input();
+    input.solver.stop_conditions.max_iterations = Some(5_000);
+
+    let controller: ProgressControlCallback = Box::new(|_| ProgressControl::Stop);
+    let result = run_solver_with_control(&input, Some(&controller)).expect("solve should stop");
+
+    assert_eq!(
+        result.stop_reason,
+        Some(StopReason::ProgressCallbackRequestedStop)
+    );
+}
+
+#[test]
+fn solver3_rejects_a_reheat_request() {
+    let mut input = solver3_driver_input();
+    input.solver.stop_conditions.max_iterations = Some(5_000);
+
+    let controller: ProgressControlCallback = Box::new(|_| ProgressControl::Reheat);
+    let error = run_solver_with_control(&input, Some(&controller))
+        .expect_err("solver3 cannot reheat on request");
+
+    assert!(error
+        .to_string()
+        .contains("solver3 does not support ProgressControl::Reheat"));
+}
+
 #[test]
 fn solver3_allowed_sessions_preserve_other_warm_start_sessions() {
     let mut input = solver3_driver_input();
@@ -0,0 +1,176 @@
+use gm_core::models::{
+    ApiInput, ApiSchedule, Constraint, ContactHistory, Group, HistoricalContact, Objective, Person,
+    ProblemDefinition, RepeatEncounterParams, SolverKind, SolverResult,
+};
+use gm_core::solver1::State;
+use gm_core::{default_solver_configuration_for, run_solver};
+use std::collections::HashMap;
+
+fn base_input(solver_kind: SolverKind) -> ApiInput {
+    let mut solver = default_solver_configuration_for(solver_kind);
+    solver.seed = Some(23);
+    solver.stop_conditions.max_iterations = Some(5_000);
+    solver.stop_conditions.time_limit_seconds = None;
+    solver.stop_conditions.no_improvement_iterations = None;
+
+    ApiInput {
+        initial_schedule: None,
+        construction_seed_schedule: None,
+        contact_history: None,
+        problem: ProblemDefinition {
+            people: (0..4)
+                .map(|idx| Person {
+                    id: format!("p{idx}"),
+                    attributes: HashMap::new(),
+                    sessions: None,
+                })
+                .collect(),
+            groups: (0..2)
+                .map(|idx| Group {
+                    id: format!("g{idx}"),
+                    size: 2,
+                    session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
+                })
+                .collect(),
+            // Four people have only three distinct pairings, so one session must repeat.
+            num_sessions: 4,
+        },
+        objectives: vec![Objective {
+            r#type: "maximize_unique_contacts".to_string(),
+            weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
+        }],
+        constraints: vec![
+            Constraint::RepeatEncounter(RepeatEncounterParams {
+                max_allowed_encounters: 1,
+                penalty_function: "squared".to_string(),
+                penalty_weight: 10.0,
+            }),
+            // Every pairing puts one of these pairs together, so session 2 always pays.
+            Constraint::ShouldNotBeTogether {
+                people: vec!["p0".to_string(), "p1".to_string(), "p2".to_string()],
+                penalty_weight: 5.0,
+                sessions: Some(vec![2]),
+            },
+        ],
+        solver,
+    }
+}
+
+fn assert_breakdown_matches_totals(result: &SolverResult) {
+    let breakdown = result
+        .session_breakdown
+        .as_ref()
+        .expect("results carry a session breakdown");
+    assert_eq!(breakdown.len(), 4);
+    for (idx, session) in breakdown.iter().enumerate() {
+        assert_eq!(session.session, idx as u32);
+    }
+
+    let gained: i32 = breakdown
+        .iter()
+        .map(|session| session.unique_contacts_gained as i32)
+        .sum();
+    assert_eq!(gained, result.unique_contacts);
+
+    let repetition: f64 = breakdown
+        .iter()
+        .map(|session| session.repetition_penalty)
+        .sum();
+    assert_eq!(repetition, result.weighted_repetition_penalty);
+
+    for session in breakdown {
+        let expected = if session.session == 2 {
+            HashMap::from([("ShouldNotBeTogether".to_string(), 5.0)])
+        } else {
+            HashMap::new()
+        };
+        assert_eq!(session.constraint_penalties, expected);
+    }
+}
+
+#[test]
+fn solver1_breakdown_points_at_the_repeated_session() {
+    let result = run_solver(&base_input(SolverKind::Solver1)).expect("solver1 should solve");
+    assert_breakdown_matches_totals(&result);
+
+    let breakdown = result.session_breakdown.as_ref().expect("breakdown");
+    let weak: Vec<_> = breakdown
+        .iter()
+        .filter(|session| session.unique_contacts_gained == 0)
+        .collect();
+    assert_eq!(weak.len(), 1, "exactly one session repeats: {breakdown:?}");
+    assert_eq!(weak[0].repetition_penalty, 20.0);
+}
+
+#[test]
+fn solver3_breakdown_is_filled_in_by_rescoring() {
+    let result = run_solver(&base_input(SolverKind::Solver3)).expect("solver3 should solve");
+    assert_breakdown_matches_totals(&result);
+}
+
+/// Two sessions that both pair p0 with p1 and p2 with p3.
+fn repeated_pairing_input() -> ApiInput {
+    let mut input = base_input(SolverKind::Solver1);
+    input.problem.num_sessions = 2;
+    input.constraints.truncate(1);
+    let pairing = HashMap::from([
+        ("g0".to_string(), vec!["p0".to_string(), "p1".to_string()]),
+        ("g1".to_string(), vec!["p2".to_string(), "p3".to_string()]),
+    ]);
+    let schedule: ApiSchedule = HashMap::from([
+        ("session_0".to_string(), pairing.clone()),
+        ("session_1".to_string(), pairing),
+    ]);
+    input.initial_schedule = Some(schedule);
+    input
+}
+
+fn repetition_by_session(state: &State) -> Vec<f64> {
+    state
+        .session_breakdown(&[])
+        .iter()
+        .map(|session| session.repetition_penalty)
+        .collect()
+}
+
+#[test]
+fn breakdown_includes_decayed_history() {
+    let mut input = repeated_pairing_input();
+    // Half an encounter of history: p0 and p1 reach 2.5 meetings instead of 2 or 3.
+    input.contact_history = Some(ContactHistory {
+        contacts: vec![HistoricalContact {
+            people: vec!["p0".to_string(), "p1".to_string()],
+            count: 1,
+            events_ago: 2,
+        }],
+        decay_factor: 0.5,
+    });
+    let state = State::new(&input).expect("state");
+
+    let repetition = repetition_by_session(&state);
+    assert_eq!(repetition, vec![2.5, 30.0]);
+    assert_eq!(
+        repetition.iter().sum::<f64>(),
+        state.weighted_repetition_penalty()
+    );
+}
+
+#[test]
+fn breakdown_scales_repeats_by_session_weight() {
+    let mut input = repeated_pairing_input();
+    input.objectives[0].session_weights = vec![1.0, 3.0];
+    let state = State::new(&input).expect("state");
+
+    // Each repeat pair pays 10, scaled by its mean meeting weight (1 + 3) / 2.
+    assert_eq!(repetition_by_session(&state), vec![0.0, 40.0]);
+    let gained: Vec<u32> = state
+        .session_breakdown(&[])
+        .iter()
+        .map(|session| session.unique_contacts_gained)
+        .collect();
+    assert_eq!(gained, vec![2, 0]);
+}
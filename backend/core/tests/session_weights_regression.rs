@@ -0,0 +1,93 @@
+use gm_core::models::{
+    ApiInput, Constraint, Group, Objective, Person, ProblemDefinition, RepeatEncounterParams,
+    SolverKind, SolverResult,
+};
+use gm_core::{default_solver_configuration_for, run_solver};
+use std::collections::{BTreeSet, HashMap};
+
+fn base_input(solver_kind: SolverKind) -> ApiInput {
+    let mut solver = default_solver_configuration_for(solver_kind);
+    solver.seed = Some(29);
+    solver.stop_conditions.max_iterations = Some(5_000);
+    solver.stop_conditions.time_limit_seconds = None;
+    solver.stop_conditions.no_improvement_iterations = None;
+
+    ApiInput {
+        initial_schedule: None,
+        construction_seed_schedule: None,
+        contact_history: None,
+        problem: ProblemDefinition {
+            people: (0..4)
+                .map(|idx| Person {
+                    id: format!("p{idx}"),
+                    attributes: HashMap::new(),
+                    sessions: None,
+                })
+                .collect(),
+            groups: (0..2)
+                .map(|idx| Group {
+                    id: format!("g{idx}"),
+                    size: 2,
+                    session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
+                })
+                .collect(),
+            // Four people have only three distinct pairings, so one session must repeat.
+            num_sessions: 4,
+        },
+        objectives: vec![Objective {
+            r#type: "maximize_unique_contacts".to_string(),
+            weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: vec![1.0, 1.0, 1.0, 0.1],
+        }],
+        constraints: vec![Constraint::RepeatEncounter(RepeatEncounterParams {
+            max_allowed_encounters: 1,
+            penalty_function: "squared".to_string(),
+            penalty_weight: 10.0,
+        })],
+        solver,
+    }
+}
+
+fn pairing(result: &SolverResult, session: usize) -> BTreeSet<BTreeSet<String>> {
+    result.schedule[&format!("session_{session}")]
+        .values()
+        .map(|members| members.iter().cloned().collect())
+        .collect()
+}
+
+#[test]
+fn solver1_places_the_unavoidable_repeat_in_the_light_session() {
+    let result = run_solver(&base_input(SolverKind::Solver1)).expect("solver1 should solve");
+
+    let wrap_up = pairing(&result, 3);
+    assert!(
+        (0..3).any(|session| pairing(&result, session) == wrap_up),
+        "the wrap-up session should repeat an earlier pairing: {:?}",
+        result.schedule
+    );
+    let earlier: BTreeSet<_> = (0..3).map(|session| pairing(&result, session)).collect();
+    assert_eq!(earlier.len(), 3, "the weighted sessions should not repeat");
+}
+
+#[test]
+fn session_weights_must_cover_every_session() {
+    let mut input = base_input(SolverKind::Solver1);
+    input.objectives[0].session_weights = vec![1.0, 0.5];
+
+    let error = run_solver(&input).expect_err("two weights for four sessions");
+    assert!(error
+        .to_string()
+        .contains("session_weights must have one entry per session (expected 4, got 2)"));
+}
+
+#[test]
+fn solver3_rejects_session_weights_explicitly() {
+    let error = run_solver(&base_input(SolverKind::Solver3))
+        .expect_err("solver3 does not model per-session weights");
+    assert!(error
+        .to_string()
+        .contains("solver3 does not support objective session_weights"));
+}
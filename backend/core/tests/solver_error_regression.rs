@@ -0,0 +1,212 @@
+use gm_core::builder::ProblemBuilder;
+use gm_core::models::{
+    ApiInput, Constraint, ImmovablePersonParams, SolverConfiguration, SolverKind,
+};
+use gm_core::run_solver;
+use gm_core::solver_support::error_details::SolverErrorDetails;
+use gm_core::solver_support::SolverError;
+
+fn problem(solver_kind: SolverKind) -> ProblemBuilder {
+    ApiInput::builder()
+        .people((0..6).map(|idx| format!("p{idx}")))
+        .group("g0", 3)
+        .group("g1", 3)
+        .sessions(2)
+        .solver(
+            SolverConfiguration::builder(solver_kind)
+                .seed(3)
+                .max_iterations(100)
+                .build(),
+        )
+}
+
+fn solve_err(input: ApiInput) -> SolverError {
+    run_solver(&input).expect_err("input should be rejected")
+}
+
+#[test]
+fn too_many_people_report_insufficient_capacity() {
+    for solver_kind in [SolverKind::Solver1, SolverKind::Solver3] {
+        let error = solve_err(problem(solver_kind).person("p6").build());
+
+        assert_eq!(
+            error,
+            SolverError::InsufficientCapacity {
+                session: 0,
+                people: 7,
+                capacity: 6,
+            },
+            "{solver_kind:?}"
+        );
+    }
+}
+
+#[test]
+fn unknown_ids_name_the_id_and_the_referencing_constraint() {
+    let error = solve_err(
+        problem(SolverKind::Solver1)
+            .constraint(Constraint::MustStayApart {
+                people: vec!["p0".to_string(), "ghost".to_string()],
+                sessions: None,
+            })
+            .build(),
+    );
+    assert_eq!(
+        error,
+        SolverError::UnknownPersonId {
+            person_id: "ghost".to_string(),
+            referenced_by: "MustStayApart".to_string(),
+        }
+    );
+
+    let error = solve_err(
+        problem(SolverKind::Solver1)
+            .constraint(Constraint::ImmovablePerson(ImmovablePersonParams {
+                person_id: "p0".to_string(),
+                group_id: "g9".to_string(),
+                sessions: None,
+            }))
+            .build(),
+    );
+    assert_eq!(
+        error,
+        SolverError::UnknownGroupId {
+            group_id: "g9".to_string(),
+            referenced_by: "ImmovablePerson".to_string(),
+        }
+    );
+}
+
+#[test]
+fn out_of_range_sessions_report_the_session_count() {
+    let error = solve_err(
+        problem(SolverKind::Solver1)
+            .constraint(Constraint::ImmovablePerson(ImmovablePersonParams {
+                person_id: "p0".to_string(),
+                group_id: "g0".to_string(),
+                sessions: Some(vec![5]),
+            }))
+            .build(),
+    );
+
+    assert_eq!(
+        error,
+        SolverError::InvalidSession {
+            session: 5,
+            num_sessions: 2,
+            referenced_by: "ImmovablePerson".to_string(),
+        }
+    );
+    assert!(error.to_string().contains("invalid session 5 (max: 1)"));
+}
+
+#[test]
+fn apart_pair_inside_a_clique_reports_conflicting_constraints() {
+    let input = problem(SolverKind::Solver3)
+        .constraint(Constraint::MustStayTogether {
+            people: vec!["p0".to_string(), "p1".to_string()],
+            sessions: None,
+        })
+        .constraint(Constraint::MustStayApart {
+            people: vec!["p0".to_string(), "p1".to_string()],
+            sessions: Some(vec![1]),
+        })
+        .build();
+
+    assert_eq!(
+        solve_err(input),
+        SolverError::ConflictingConstraints {
+            constraint: "MustStayApart".to_string(),
+            conflicts_with: "MustStayTogether".to_string(),
+            people: vec!["p0".to_string(), "p1".to_string()],
+            session: Some(1),
+        }
+    );
+}
+
+#[test]
+fn oversized_clique_reports_its_members_and_the_largest_group() {
+    for solver_kind in [SolverKind::Solver1, SolverKind::Solver3] {
+        let input = problem(solver_kind)
+            .constraint(Constraint::MustStayTogether {
+                people: (0..4).map(|idx| format!("p{idx}")).collect(),
+                sessions: None,
+            })
+            .build();
+
+        let error = solve_err(input);
+        let SolverError::CliqueTooLarge {
+            people,
+            size,
+            max_group_size,
+            session,
+        } = &error
+        else {
+            panic!("unexpected error: {error}");
+        };
+        assert_eq!(people, &["p0", "p1", "p2", "p3"]);
+        assert_eq!((*size, *max_group_size, *session), (4, 3, 0));
+        assert_eq!(
+            error.message(),
+            "MustStayTogether clique [\"p0\", \"p1\", \"p2\", \"p3\"] of size 4 cannot fit in any group for session 0 (largest group: 3)"
+        );
+    }
+}
+
+#[test]
+fn details_point_at_the_conflicting_constraints() {
+    let input = problem(SolverKind::Solver3)
+        .constraint(Constraint::ShouldStayTogether {
+            people: vec!["p4".to_string(), "p5".to_string()],
+            penalty_weight: 10.0,
+            sessions: None,
+        })
+        .constraint(Constraint::MustStayTogether {
+            people: vec!["p0".to_string(), "p1".to_string(), "p2".to_string()],
+            sessions: None,
+        })
+        .constraint(Constraint::MustStayApart {
+            people: vec!["p0".to_string(), "p1".to_string()],
+            sessions: Some(vec![1]),
+        })
+        .build();
+
+    let details = solve_err(input.clone()).details(&input);
+
+    assert_eq!(
+        details,
+        SolverErrorDetails {
+            kind: "conflicting_constraints".to_string(),
+            person_ids: vec!["p0".to_string(), "p1".to_string()],
+            group_ids: Vec::new(),
+            constraint_indices: vec![1, 2],
+            sessions: vec![1],
+        }
+    );
+}
+
+#[test]
+fn details_of_unknown_groups_name_the_group_and_constraint() {
+    let input = problem(SolverKind::Solver1)
+        .constraint(Constraint::ImmovablePerson(ImmovablePersonParams {
+            person_id: "p0".to_string(),
+            group_id: "g0".to_string(),
+            sessions: None,
+        }))
+        .constraint(Constraint::ImmovablePerson(ImmovablePersonParams {
+            person_id: "p1".to_string(),
+            group_id: "g9".to_string(),
+            sessions: None,
+        }))
+        .build();
+
+    let details = solve_err(input.clone()).details(&input);
+
+    assert_eq!(details.kind, "unknown_group_id");
+    assert_eq!(details.group_ids, vec!["g9".to_string()]);
+    assert_eq!(details.constraint_indices, vec![1]);
+    assert_eq!(
+        serde_json::to_value(&details).unwrap()["constraint_indices"],
+        serde_json::json!([1])
+    );
+}
@@ -0,0 +1,139 @@
+use gm_core::models::{ApiInput, SolverConfiguration, SolverKind, StopReason, TelemetryOptions};
+use gm_core::solver_handle::SolverHandle;
+use std::thread;
+use std::time::{Duration, Instant};
+
+fn base_input(max_iterations: u64) -> ApiInput {
+    input_with(
+        SolverConfiguration::builder(SolverKind::Solver1)
+            .seed(81)
+            .max_iterations(max_iterations)
+            .stop_on_optimal_score(false)
+            .telemetry(TelemetryOptions {
+                emit_best_schedule: true,
+                best_schedule_every_n_callbacks: 1,
+                ..TelemetryOptions::default()
+            })
+            .build(),
+    )
+}
+
+fn input_with(solver: SolverConfiguration) -> ApiInput {
+    ApiInput::builder()
+        .people((0..12).map(|idx| format!("p{idx}")))
+        .group("g0", 4)
+        .group("g1", 4)
+        .group("g2", 4)
+        .sessions(3)
+        .solver(solver)
+        .build()
+}
+
+fn wait_until(deadline: Duration, mut condition: impl FnMut() -> bool) -> bool {
+    let start = Instant::now();
+    while start.elapsed() < deadline {
+        if condition() {
+            return true;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    condition()
+}
+
+#[test]
+fn paused_handle_holds_progress_and_stops_with_a_result() {
+    let handle = SolverHandle::spawn(base_input(1_000_000_000));
+    assert!(wait_until(Duration::from_secs(10), || handle
+        .latest_progress()
+        .is_some()));
+
+    handle.pause();
+    assert!(handle.is_paused());
+    // Let the search reach its next callback, where it parks.
+    thread::sleep(Duration::from_millis(300));
+    let parked_at = handle.latest_progress().unwrap().iteration;
+    thread::sleep(Duration::from_millis(300));
+    assert_eq!(handle.latest_progress().unwrap().iteration, parked_at);
+    assert!(!handle.is_finished());
+
+    let best = handle.best_schedule().expect("snapshots are enabled");
+    assert_eq!(best.len(), 3);
+
+    handle.resume();
+    assert!(wait_until(Duration::from_secs(10), || handle
+        .latest_progress()
+        .unwrap()
+        .iteration
+        > parked_at));
+
+    let result = handle.stop().expect("solve should succeed");
+    assert_eq!(
+        result.stop_reason,
+        Some(StopReason::ProgressCallbackRequestedStop)
+    );
+    assert_eq!(result.schedule.len(), 3);
+}
+
+#[test]
+fn stop_releases_a_paused_handle() {
+    let handle = SolverHandle::spawn(base_input(1_000_000_000));
+    handle.pause();
+    thread::sleep(Duration::from_millis(200));
+
+    let result = handle.stop().expect("solve should succeed");
+    assert_eq!(
+        result.stop_reason,
+        Some(StopReason::ProgressCallbackRequestedStop)
+    );
+}
+
+#[test]
+fn wait_returns_the_result_of_a_run_that_finishes_on_its_own() {
+    let handle = SolverHandle::spawn(base_input(2_000));
+
+    let result = handle.wait().expect("solve should succeed");
+    assert_eq!(result.iterations_run, Some(2_000));
+    assert_eq!(result.stop_reason, Some(StopReason::MaxIterationsReached));
+}
+
+#[test]
+fn spawn_keeps_the_callers_telemetry() {
+    let handle = SolverHandle::spawn(input_with(
+        SolverConfiguration::builder(SolverKind::Solver1)
+            .seed(81)
+            .max_iterations(1_000_000_000)
+            .stop_on_optimal_score(false)
+            .build(),
+    ));
+    assert!(wait_until(Duration::from_secs(10), || handle
+        .latest_progress()
+        .is_some()));
+
+    assert!(handle.latest_progress().unwrap().best_schedule.is_none());
+    assert!(handle.best_schedule().is_none());
+    handle.stop().expect("solve should succeed");
+}
+
+#[test]
+fn paused_time_does_not_count_toward_the_time_limit() {
+    let started = Instant::now();
+    let handle = SolverHandle::spawn(input_with(
+        SolverConfiguration::builder(SolverKind::Solver1)
+            .seed(81)
+            .max_iterations(u64::MAX)
+            .time_limit_seconds(1)
+            .stop_on_optimal_score(false)
+            .build(),
+    ));
+    handle.pause();
+    assert!(wait_until(Duration::from_secs(10), || handle
+        .latest_progress()
+        .is_some()));
+    thread::sleep(Duration::from_millis(1_500));
+    assert!(!handle.is_finished());
+
+    handle.resume();
+    let result = handle.wait().expect("solve should succeed");
+    assert_eq!(result.stop_reason, Some(StopReason::TimeLimitReached));
+    assert!(started.elapsed() >= Duration::from_millis(2_500));
+}
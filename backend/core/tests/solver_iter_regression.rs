@@ -0,0 +1,63 @@
+use gm_core::models::{ApiInput, Constraint, SolverConfiguration, SolverKind};
+use gm_core::run_solver_iter;
+use std::time::{Duration, Instant};
+
+fn base_input(max_iterations: u64) -> ApiInput {
+    ApiInput::builder()
+        .people((0..12).map(|idx| format!("p{idx}")))
+        .group("g0", 4)
+        .group("g1", 4)
+        .group("g2", 4)
+        .sessions(3)
+        .solver(
+            SolverConfiguration::builder(SolverKind::Solver1)
+                .seed(17)
+                .max_iterations(max_iterations)
+                .stop_on_optimal_score(false)
+                .build(),
+        )
+        .build()
+}
+
+#[test]
+fn improvements_get_strictly_better_and_end_with_the_run() {
+    let improvements: Vec<_> = run_solver_iter(&base_input(200_000)).collect();
+
+    assert!(!improvements.is_empty());
+    for pair in improvements.windows(2) {
+        assert!(pair[1].best_score < pair[0].best_score);
+        assert!(pair[1].iteration >= pair[0].iteration);
+    }
+    assert!(improvements
+        .iter()
+        .all(|improvement| improvement.schedule.len() == 3));
+}
+
+#[test]
+fn finishing_early_stops_the_search_with_a_result() {
+    let started = Instant::now();
+    let mut improvements = run_solver_iter(&base_input(1_000_000_000));
+
+    let first = improvements
+        .next()
+        .expect("the first update is an improvement");
+    let result = improvements.finish().expect("solve should succeed");
+
+    assert!(started.elapsed() < Duration::from_secs(30));
+    assert!(result.final_score <= first.best_score);
+    assert_eq!(result.schedule.len(), 3);
+}
+
+#[test]
+fn invalid_input_yields_nothing_and_reports_the_error() {
+    let mut input = base_input(1_000);
+    input.constraints.push(Constraint::MustStayApart {
+        people: vec!["p0".to_string(), "ghost".to_string()],
+        sessions: None,
+    });
+
+    let mut improvements = run_solver_iter(&input);
+
+    assert!(improvements.next().is_none());
+    assert!(improvements.finish().is_err());
+}
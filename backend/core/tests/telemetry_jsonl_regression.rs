@@ -0,0 +1,80 @@
+use gm_core::models::{ApiInput, MoveStatisticsPoint, SolverConfiguration, SolverKind, StopReason};
+use gm_core::run_solver_with_progress;
+use gm_core::telemetry_jsonl::JsonlTelemetryWriter;
+use serde_json::Value;
+use std::io::{self, Write};
+use std::sync::Arc;
+
+fn input() -> ApiInput {
+    let mut input = ApiInput::builder()
+        .people((0..12).map(|idx| format!("p{idx}")))
+        .group("g0", 4)
+        .group("g1", 4)
+        .group("g2", 4)
+        .sessions(3)
+        .solver(
+            SolverConfiguration::builder(SolverKind::Solver1)
+                .seed(11)
+                .max_iterations(2_000)
+                .stop_on_optimal_score(false)
+                .build(),
+        )
+        .build();
+    input.solver.telemetry.record_move_statistics = true;
+    input.solver.telemetry.move_statistics_every_n_iterations = 500;
+    input
+}
+
+#[test]
+fn every_progress_update_becomes_one_json_line() {
+    let telemetry = Arc::new(JsonlTelemetryWriter::new(Vec::new()));
+    let callback = telemetry.progress_callback();
+    let result = run_solver_with_progress(&input(), Some(&callback)).expect("solve should succeed");
+    drop(callback);
+
+    let records = telemetry.finish().expect("writing to memory cannot fail");
+    let telemetry = Arc::into_inner(telemetry).expect("callback was dropped");
+    let text = String::from_utf8(telemetry.into_inner()).expect("records are UTF-8");
+    // Unset penalties are infinite and serialize as `null`, so read the lines as JSON values.
+    let lines: Vec<Value> = text
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line is a JSON object"))
+        .collect();
+
+    assert_eq!(lines.len() as u64, records);
+    let last = lines.last().expect("the final progress update is recorded");
+    assert_eq!(last["stop_reason"], "max_iterations_reached");
+    assert_eq!(last["best_score"], result.final_score);
+    let windows: Vec<MoveStatisticsPoint> = lines
+        .iter()
+        .filter_map(|line| line.get("move_statistics"))
+        .flat_map(|windows| {
+            serde_json::from_value::<Vec<MoveStatisticsPoint>>(windows.clone())
+                .expect("move statistics windows")
+        })
+        .collect();
+    assert_eq!(Some(windows), result.move_statistics);
+}
+
+struct FailingWriter;
+
+impl Write for FailingWriter {
+    fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+        Err(io::Error::other("disk full"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn write_errors_do_not_stop_the_solve_and_surface_on_finish() {
+    let telemetry = Arc::new(JsonlTelemetryWriter::new(FailingWriter));
+    let callback = telemetry.progress_callback();
+    let result = run_solver_with_progress(&input(), Some(&callback)).expect("solve should succeed");
+
+    assert_eq!(result.stop_reason, Some(StopReason::MaxIterationsReached));
+    let error = telemetry.finish().expect_err("the write error is reported");
+    assert_eq!(error.to_string(), "disk full");
+}
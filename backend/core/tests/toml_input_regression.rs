@@ -0,0 +1,73 @@
+#![cfg(feature = "toml")]
+
+use gm_core::models::{ApiInput, Constraint};
+
+const INPUT: &str = r#"
+# Hand-edited problem: comments are fine here.
+objectives = [{ type = "maximize_unique_contacts", weight = 1.0 }]
+constraints = [
+    { type = "ShouldNotBeTogether", people = ["alice", "carol"], penalty_weight = 5.0 },
+]
+
+[problem]
+num_sessions = 2
+groups = [{ id = "t1", size = 2 }, { id = "t2", size = 2 }]
+
+[[problem.people]]
+id = "alice"
+attributes = { team = "red" }
+
+[[problem.people]]
+id = "bob"
+attributes = { team = "blue" }
+
+[[problem.people]]
+id = "carol"
+attributes = { team = "red" }
+
+[[problem.people]]
+id = "dave"
+attributes = { team = "blue" }
+
+[solver]
+solver_type = "solver1"
+seed = 9
+stop_conditions = { max_iterations = 500 }
+"#;
+
+#[test]
+fn toml_input_matches_the_json_equivalent() {
+    let from_toml = ApiInput::from_toml_str(INPUT).expect("toml should parse");
+    let json = serde_json::to_string(&from_toml).expect("serialize");
+    let from_json: ApiInput = serde_json::from_str(&json).expect("json should parse");
+
+    assert_eq!(from_toml.problem.num_sessions, 2);
+    assert_eq!(from_toml.problem.people[1].attributes["team"], "blue");
+    assert!(matches!(
+        from_toml.constraints[0],
+        Constraint::ShouldNotBeTogether { penalty_weight, .. } if penalty_weight == 5.0
+    ));
+    assert_eq!(from_toml.solver.seed, Some(9));
+    assert_eq!(
+        serde_json::to_value(&from_json).unwrap(),
+        serde_json::to_value(&from_toml).unwrap()
+    );
+    gm_core::run_solver(&from_toml).expect("solve should succeed");
+}
+
+#[test]
+fn toml_syntax_errors_point_at_the_offending_line() {
+    let error = ApiInput::from_toml_str("[problem]\nnum_sessions = 2\npeople = ]\n").unwrap_err();
+
+    assert_eq!(error.location, Some((3, 10)));
+}
+
+#[test]
+fn toml_shape_errors_name_the_field() {
+    let error =
+        ApiInput::from_toml_str("[problem]\npeople = []\ngroups = []\nnum_sessions = \"two\"\n")
+            .unwrap_err();
+
+    assert_eq!(error.location, None);
+    assert!(error.message.contains("invalid type"));
+}
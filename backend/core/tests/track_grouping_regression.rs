@@ -0,0 +1,187 @@
+use gm_core::models::{
+    ApiInput, ApiSchedule, Constraint, Group, Objective, Person, ProblemDefinition,
+    RepeatEncounterParams, SolverKind, SolverResult, TrackGroupingParams,
+};
+use gm_core::{default_solver_configuration_for, run_solver};
+use std::collections::HashMap;
+
+fn group(id: &str, track: &str) -> Group {
+    Group {
+        id: id.to_string(),
+        size: 3,
+        session_sizes: None,
+        attributes: HashMap::from([("track".to_string(), track.to_string())]),
+        available_sessions: None,
+    }
+}
+
+fn base_input(solver_kind: SolverKind) -> ApiInput {
+    let mut solver = default_solver_configuration_for(solver_kind);
+    solver.seed = Some(23);
+    solver.stop_conditions.max_iterations = Some(5_000);
+    solver.stop_conditions.time_limit_seconds = None;
+    solver.stop_conditions.no_improvement_iterations = None;
+
+    ApiInput {
+        initial_schedule: None,
+        construction_seed_schedule: None,
+        contact_history: None,
+        problem: ProblemDefinition {
+            people: (0..12)
+                .map(|idx| Person {
+                    id: format!("p{idx}"),
+                    // p0 and p1 signed up for the AI track; everyone else is unassigned.
+                    attributes: if idx < 2 {
+                        HashMap::from([("track".to_string(), "ai".to_string())])
+                    } else {
+                        HashMap::new()
+                    },
+                    sessions: None,
+                })
+                .collect(),
+            groups: vec![
+                group("ai-1", "ai"),
+                group("ai-2", "ai"),
+                group("web-1", "web"),
+                group("web-2", "web"),
+            ],
+            num_sessions: 3,
+        },
+        objectives: vec![Objective {
+            r#type: "maximize_unique_contacts".to_string(),
+            weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
+        }],
+        constraints: vec![
+            Constraint::RepeatEncounter(RepeatEncounterParams {
+                max_allowed_encounters: 1,
+                penalty_function: "squared".to_string(),
+                penalty_weight: 100.0,
+            }),
+            Constraint::MustStayTogether {
+                people: vec!["p1".to_string(), "p5".to_string()],
+                sessions: Some(vec![0]),
+            },
+            Constraint::TrackGrouping(TrackGroupingParams {
+                track_attribute: "track".to_string(),
+            }),
+        ],
+        solver,
+    }
+}
+
+fn track_of(result: &SolverResult, session: usize, person: &str) -> &'static str {
+    let group_id = result.schedule[&format!("session_{session}")]
+        .iter()
+        .find(|(_, members)| members.iter().any(|member| member == person))
+        .map(|(group_id, _)| group_id.as_str())
+        .expect("every person is scheduled");
+    if group_id.starts_with("ai") {
+        "ai"
+    } else {
+        "web"
+    }
+}
+
+#[test]
+fn solver1_keeps_each_person_in_one_track() {
+    let result = run_solver(&base_input(SolverKind::Solver1)).expect("solver1 should solve");
+
+    for idx in 0..12 {
+        let person = format!("p{idx}");
+        let track = track_of(&result, 0, &person);
+        for session in 1..3 {
+            assert_eq!(
+                track_of(&result, session, &person),
+                track,
+                "{person} changed track in session {session}"
+            );
+        }
+    }
+    for person in ["p0", "p1", "p5"] {
+        assert_eq!(track_of(&result, 0, person), "ai");
+    }
+}
+
+#[test]
+fn track_grouping_requires_every_group_to_name_a_track() {
+    let mut input = base_input(SolverKind::Solver1);
+    input.problem.groups[3].attributes.clear();
+
+    let error = run_solver(&input).expect_err("web-2 has no track");
+    assert!(error
+        .to_string()
+        .contains("TrackGrouping requires group 'web-2' to define attribute 'track'"));
+}
+
+#[test]
+fn warm_start_changing_tracks_is_rejected() {
+    let mut input = base_input(SolverKind::Solver1);
+    input.constraints.remove(1);
+    let people =
+        |ids: [usize; 3]| -> Vec<String> { ids.iter().map(|idx| format!("p{idx}")).collect() };
+    let session = |swap: bool| {
+        let (third, ninth) = if swap { (9, 3) } else { (3, 9) };
+        HashMap::from([
+            ("ai-1".to_string(), people([0, 1, 2])),
+            ("ai-2".to_string(), people([third, 4, 5])),
+            ("web-1".to_string(), people([6, 7, 8])),
+            ("web-2".to_string(), people([ninth, 10, 11])),
+        ])
+    };
+    let schedule: ApiSchedule = HashMap::from([
+        ("session_0".to_string(), session(false)),
+        ("session_1".to_string(), session(false)),
+        ("session_2".to_string(), session(true)),
+    ]);
+    input.initial_schedule = Some(schedule);
+
+    let error = run_solver(&input).expect_err("p3 switches from ai to web");
+    assert!(
+        error.to_string().contains("TrackGrouping violation"),
+        "{error}"
+    );
+}
+
+#[test]
+fn solver3_rejects_track_grouping_explicitly() {
+    let error =
+        run_solver(&base_input(SolverKind::Solver3)).expect_err("solver3 does not model tracks");
+    assert!(error
+        .to_string()
+        .contains("solver3 does not support TrackGrouping"));
+}
+
+#[test]
+fn search_moves_people_between_tracks() {
+    let mut input = base_input(SolverKind::Solver1);
+    input.constraints.remove(1);
+    // The initial capacity-balanced choice puts p2 on web and p4 on ai; only a track swap
+    // can bring them together.
+    input.constraints.push(Constraint::ShouldStayTogether {
+        people: vec!["p2".to_string(), "p4".to_string()],
+        penalty_weight: 1_000.0,
+        sessions: None,
+    });
+
+    let result = run_solver(&input).expect("solver1 should solve");
+
+    for session in 0..3 {
+        let groups = &result.schedule[&format!("session_{session}")];
+        assert!(
+            groups.values().any(|members| {
+                members.iter().any(|member| member == "p2")
+                    && members.iter().any(|member| member == "p4")
+            }),
+            "p2 and p4 are apart in session {session}"
+        );
+    }
+    for idx in 0..12 {
+        let person = format!("p{idx}");
+        let track = track_of(&result, 0, &person);
+        assert!((1..3).all(|session| track_of(&result, session, &person) == track));
+    }
+    let repeat = run_solver(&input).expect("solver1 should solve");
+    assert_eq!(repeat.schedule, result.schedule);
+}
@@ -0,0 +1,80 @@
+use gm_core::models::{
+    ApiInput, Constraint, Group, Person, ProblemDefinition, RepeatEncounterParams, SolverKind,
+    UniqueGroupCompositionParams,
+};
+use gm_core::{default_solver_configuration_for, run_solver};
+use std::collections::{HashMap, HashSet};
+
+fn base_input(solver_kind: SolverKind) -> ApiInput {
+    let mut solver = default_solver_configuration_for(solver_kind);
+    solver.seed = Some(3);
+    solver.stop_conditions.max_iterations = Some(5_000);
+    solver.stop_conditions.time_limit_seconds = None;
+    solver.stop_conditions.no_improvement_iterations = None;
+
+    ApiInput {
+        initial_schedule: None,
+        construction_seed_schedule: None,
+        contact_history: None,
+        problem: ProblemDefinition {
+            people: (0..6)
+                .map(|idx| Person {
+                    id: format!("p{idx}"),
+                    attributes: HashMap::new(),
+                    sessions: None,
+                })
+                .collect(),
+            groups: (0..2)
+                .map(|idx| Group {
+                    id: format!("g{idx}"),
+                    size: 3,
+                    session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
+                })
+                .collect(),
+            num_sessions: 4,
+        },
+        objectives: vec![],
+        // Pairs may meet in every session, so only the composition constraint applies pressure.
+        constraints: vec![
+            Constraint::RepeatEncounter(RepeatEncounterParams {
+                max_allowed_encounters: 4,
+                penalty_function: "linear".to_string(),
+                penalty_weight: 1.0,
+            }),
+            Constraint::UniqueGroupComposition(UniqueGroupCompositionParams {
+                penalty_weight: 1000.0,
+                sessions: None,
+            }),
+        ],
+        solver,
+    }
+}
+
+#[test]
+fn solver1_unique_group_composition_avoids_repeated_groups() {
+    let result = run_solver(&base_input(SolverKind::Solver1)).expect("solver1 should solve");
+
+    let mut seen = HashSet::new();
+    for groups in result.schedule.values() {
+        for members in groups.values() {
+            let mut composition = members.clone();
+            composition.sort();
+            assert!(
+                seen.insert(composition.clone()),
+                "group {composition:?} formed in more than one session"
+            );
+        }
+    }
+    assert_eq!(result.constraint_penalty, 0);
+}
+
+#[test]
+fn solver3_rejects_unique_group_composition_explicitly() {
+    let error = run_solver(&base_input(SolverKind::Solver3))
+        .expect_err("solver3 does not model group compositions");
+    assert!(error
+        .to_string()
+        .contains("solver3 does not support UniqueGroupComposition"));
+}
@@ -0,0 +1,202 @@
+use gm_core::builder::ProblemBuilder;
+use gm_core::models::{
+    ApiInput, AttributeBalanceMode, AttributeBalanceParams, Constraint, ImmovablePersonParams,
+    Person, SolverConfiguration, SolverKind,
+};
+use gm_core::validate;
+use std::collections::HashMap;
+
+fn problem() -> ProblemBuilder {
+    ApiInput::builder()
+        .people((0..6).map(|idx| format!("p{idx}")))
+        .group("g0", 3)
+        .group("g1", 3)
+        .sessions(3)
+        .solver(SolverConfiguration::builder(SolverKind::Solver1).build())
+}
+
+#[test]
+fn clean_input_has_no_findings() {
+    let report = validate(&problem().build());
+
+    assert!(report.is_valid());
+    assert!(report.errors.is_empty());
+    assert!(report.warnings.is_empty());
+}
+
+#[test]
+fn errors_carry_the_solver_error_details() {
+    let input = problem()
+        .constraint(Constraint::MustStayApart {
+            people: vec!["p0".to_string(), "ghost".to_string()],
+            sessions: None,
+        })
+        .build();
+
+    let report = validate(&input);
+
+    assert!(!report.is_valid());
+    assert_eq!(report.errors.len(), 1);
+    assert_eq!(
+        report.errors[0].message,
+        "MustStayApart references unknown person 'ghost'"
+    );
+    assert_eq!(report.errors[0].details.kind, "unknown_person_id");
+    assert_eq!(report.errors[0].details.constraint_indices, vec![0]);
+}
+
+#[test]
+fn constraint_in_a_session_the_person_skips_is_a_warning() {
+    let input = problem()
+        .add_person(Person {
+            id: "late".to_string(),
+            attributes: HashMap::new(),
+            sessions: Some(vec![2]),
+        })
+        .group("g2", 1)
+        .constraint(Constraint::ImmovablePerson(ImmovablePersonParams {
+            person_id: "late".to_string(),
+            group_id: "g2".to_string(),
+            sessions: Some(vec![0, 2]),
+        }))
+        .build();
+
+    let report = validate(&input);
+
+    assert!(report.is_valid(), "{:?}", report.errors);
+    assert_eq!(report.warnings.len(), 1);
+    let warning = &report.warnings[0].details;
+    assert_eq!(warning.kind, "unattended_session");
+    assert_eq!(warning.person_ids, vec!["late".to_string()]);
+    assert_eq!(warning.constraint_indices, vec![0]);
+    assert_eq!(warning.sessions, vec![0]);
+}
+
+#[test]
+fn should_stay_together_inside_a_clique_is_redundant() {
+    let input = problem()
+        .constraint(Constraint::MustStayTogether {
+            people: vec!["p0".to_string(), "p1".to_string(), "p2".to_string()],
+            sessions: None,
+        })
+        .constraint(Constraint::ShouldStayTogether {
+            people: vec!["p0".to_string(), "p2".to_string()],
+            penalty_weight: 10.0,
+            sessions: Some(vec![1]),
+        })
+        .constraint(Constraint::ShouldStayTogether {
+            people: vec!["p0".to_string(), "p3".to_string()],
+            penalty_weight: 10.0,
+            sessions: None,
+        })
+        .build();
+
+    let report = validate(&input);
+
+    assert!(report.is_valid(), "{:?}", report.errors);
+    assert_eq!(report.warnings.len(), 1);
+    let warning = &report.warnings[0].details;
+    assert_eq!(warning.kind, "redundant_constraint");
+    assert_eq!(warning.constraint_indices, vec![1, 0]);
+}
+
+#[test]
+fn unknown_solver_type_is_an_error() {
+    let mut input = problem().build();
+    input.solver.solver_type = "solver42".to_string();
+
+    let report = validate(&input);
+
+    assert!(report
+        .errors
+        .iter()
+        .any(|error| error.message.contains("Unknown solver type 'solver42'")));
+}
+
+#[test]
+fn unreachable_attribute_balance_targets_are_warnings() {
+    let mut input = problem()
+        .constraint(Constraint::AttributeBalance(AttributeBalanceParams {
+            group_id: "g0".to_string(),
+            attribute_key: "role".to_string(),
+            desired_values: HashMap::from([("eng".to_string(), 3), ("design".to_string(), 1)]),
+            penalty_weight: 10.0,
+            sessions: Some(vec![0, 1]),
+            mode: AttributeBalanceMode::Exact,
+        }))
+        .build();
+    for (idx, person) in input.problem.people.iter_mut().enumerate() {
+        let role = if idx < 2 { "eng" } else { "design" };
+        person
+            .attributes
+            .insert("role".to_string(), role.to_string());
+    }
+
+    let report = validate(&input);
+
+    assert!(report.is_valid(), "{:?}", report.errors);
+    let messages: Vec<&str> = report
+        .warnings
+        .iter()
+        .map(|warning| warning.message.as_str())
+        .collect();
+    assert_eq!(
+        messages,
+        vec![
+            "AttributeBalance (constraint 0) wants 4 people in 'g0', which seats only 3 in sessions [0, 1]",
+            "AttributeBalance (constraint 0) wants 3 people with role=eng in 'g0', but only 2 attend sessions [0, 1]",
+        ]
+    );
+    let warning = &report.warnings[0].details;
+    assert_eq!(warning.kind, "unreachable_attribute_balance");
+    assert_eq!(warning.group_ids, vec!["g0".to_string()]);
+    assert_eq!(warning.constraint_indices, vec![0]);
+}
+
+#[test]
+fn attribute_balance_across_all_groups_needs_supply_for_every_group() {
+    let balance = |desired| {
+        Constraint::AttributeBalance(AttributeBalanceParams {
+            group_id: "ALL".to_string(),
+            attribute_key: "role".to_string(),
+            desired_values: HashMap::from([("eng".to_string(), desired)]),
+            penalty_weight: 10.0,
+            sessions: None,
+            mode: AttributeBalanceMode::AtLeast,
+        })
+    };
+    let with_three_engineers = |mut input: ApiInput| {
+        for (idx, person) in input.problem.people.iter_mut().enumerate() {
+            let role = if idx < 3 { "eng" } else { "design" };
+            person
+                .attributes
+                .insert("role".to_string(), role.to_string());
+        }
+        input
+    };
+
+    let report = validate(&with_three_engineers(
+        problem().constraint(balance(2)).build(),
+    ));
+
+    let messages: Vec<&str> = report
+        .warnings
+        .iter()
+        .map(|warning| warning.message.as_str())
+        .collect();
+    assert_eq!(
+        messages,
+        vec![
+            "AttributeBalance (constraint 0) wants 2 people with role=eng in each of 2 groups, but only 3 attend sessions [0, 1, 2]",
+        ]
+    );
+    assert_eq!(
+        report.warnings[0].details.group_ids,
+        vec!["g0".to_string(), "g1".to_string()]
+    );
+
+    let reachable = validate(&with_three_engineers(
+        problem().constraint(balance(1)).build(),
+    ));
+    assert!(reachable.warnings.is_empty(), "{:?}", reachable.warnings);
+}
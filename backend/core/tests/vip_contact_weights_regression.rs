@@ -0,0 +1,76 @@
+use gm_core::models::{
+    ApiInput, Constraint, Group, Objective, Person, ProblemDefinition, RepeatEncounterParams,
+    SolverKind,
+};
+use gm_core::{default_solver_configuration_for, run_solver};
+use std::collections::{HashMap, HashSet};
+
+fn base_input(solver_kind: SolverKind) -> ApiInput {
+    let mut solver = default_solver_configuration_for(solver_kind);
+    solver.seed = Some(17);
+    solver.stop_conditions.max_iterations = Some(5_000);
+    solver.stop_conditions.time_limit_seconds = None;
+    solver.stop_conditions.no_improvement_iterations = None;
+
+    ApiInput {
+        initial_schedule: None,
+        construction_seed_schedule: None,
+        contact_history: None,
+        problem: ProblemDefinition {
+            people: (0..8)
+                .map(|idx| Person {
+                    id: format!("p{idx}"),
+                    attributes: HashMap::new(),
+                    sessions: None,
+                })
+                .collect(),
+            groups: (0..2)
+                .map(|idx| Group {
+                    id: format!("g{idx}"),
+                    size: 4,
+                    session_sizes: None,
+                    attributes: HashMap::new(),
+                    available_sessions: None,
+                })
+                .collect(),
+            num_sessions: 2,
+        },
+        objectives: vec![Objective {
+            r#type: "maximize_unique_contacts".to_string(),
+            weight: 1.0,
+            person_weights: HashMap::from([("p0".to_string(), 5.0)]),
+            session_weights: Vec::new(),
+        }],
+        // Repeats are cheap, so only the VIP weight pushes p0 towards new faces.
+        constraints: vec![Constraint::RepeatEncounter(RepeatEncounterParams {
+            max_allowed_encounters: 2,
+            penalty_function: "linear".to_string(),
+            penalty_weight: 0.1,
+        })],
+        solver,
+    }
+}
+
+#[test]
+fn solver1_vip_meets_as_many_people_as_possible() {
+    let result = run_solver(&base_input(SolverKind::Solver1)).expect("solver1 should solve");
+
+    let mut met = HashSet::new();
+    for groups in result.schedule.values() {
+        for members in groups.values() {
+            if members.iter().any(|member| member == "p0") {
+                met.extend(members.iter().filter(|member| *member != "p0").cloned());
+            }
+        }
+    }
+    assert_eq!(met.len(), 6, "p0 should meet new people in each session");
+}
+
+#[test]
+fn solver3_rejects_person_weights_explicitly() {
+    let error = run_solver(&base_input(SolverKind::Solver3))
+        .expect_err("solver3 does not model per-person contact weights");
+    assert!(error
+        .to_string()
+        .contains("solver3 does not support objective person_weights"));
+}
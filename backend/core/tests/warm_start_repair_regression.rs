@@ -0,0 +1,148 @@
+use gm_core::builder::ProblemBuilder;
+use gm_core::models::{
+    ApiInput, ApiSchedule, Constraint, ImmovablePersonParams, ScheduleRepair, ScheduleRepairKind,
+    SolverConfiguration, SolverKind,
+};
+use gm_core::solver::SolverError;
+use gm_core::{repair_initial_schedule, run_solver};
+use std::collections::HashMap;
+
+fn problem() -> ProblemBuilder {
+    ApiInput::builder()
+        .people(["p0", "p1", "p2", "p3", "p4", "p5"])
+        .group("g0", 3)
+        .group("g1", 3)
+        .sessions(2)
+        .solver(
+            SolverConfiguration::builder(SolverKind::Solver1)
+                .seed(5)
+                .max_iterations(500)
+                .build(),
+        )
+}
+
+fn schedule(sessions: &[&[(&str, &[&str])]]) -> ApiSchedule {
+    sessions
+        .iter()
+        .enumerate()
+        .map(|(session, groups)| {
+            (
+                format!("session_{session}"),
+                groups
+                    .iter()
+                    .map(|(group_id, people)| {
+                        (
+                            group_id.to_string(),
+                            people.iter().map(|person| person.to_string()).collect(),
+                        )
+                    })
+                    .collect::<HashMap<_, _>>(),
+            )
+        })
+        .collect()
+}
+
+fn repair(
+    kind: ScheduleRepairKind,
+    session: u32,
+    person_id: &str,
+    from_group: Option<&str>,
+    to_group: &str,
+) -> ScheduleRepair {
+    ScheduleRepair {
+        kind,
+        session,
+        person_id: person_id.to_string(),
+        from_group: from_group.map(str::to_string),
+        to_group: to_group.to_string(),
+    }
+}
+
+#[test]
+fn repairs_overflow_displaced_immovable_and_missing_people() {
+    let mut input = problem()
+        .constraint(Constraint::ImmovablePerson(ImmovablePersonParams {
+            person_id: "p0".to_string(),
+            group_id: "g1".to_string(),
+            sessions: Some(vec![1]),
+        }))
+        .build();
+    input.initial_schedule = Some(schedule(&[
+        &[("g0", &["p0", "p1", "p2", "p3"]), ("g1", &["p4"])],
+        &[("g0", &["p0", "p1", "p2"]), ("g1", &["p3", "p4"])],
+    ]));
+    assert!(run_solver(&input).is_err());
+
+    let repaired = repair_initial_schedule(&input).expect("repair should succeed");
+
+    assert_eq!(
+        repaired.repairs,
+        vec![
+            repair(
+                ScheduleRepairKind::CapacityOverflow,
+                0,
+                "p3",
+                Some("g0"),
+                "g1"
+            ),
+            repair(ScheduleRepairKind::MissingPerson, 0, "p5", None, "g1"),
+            repair(
+                ScheduleRepairKind::DisplacedImmovable,
+                1,
+                "p0",
+                Some("g0"),
+                "g1"
+            ),
+            repair(ScheduleRepairKind::MissingPerson, 1, "p5", None, "g0"),
+        ]
+    );
+    assert_eq!(
+        repaired.schedule["session_1"]["g1"],
+        vec!["p3".to_string(), "p4".to_string(), "p0".to_string()]
+    );
+    assert_eq!(
+        repair_initial_schedule(&input).expect("repeat repair"),
+        repaired
+    );
+
+    input.initial_schedule = Some(repaired.schedule);
+    run_solver(&input).expect("repaired schedule is a valid warm start");
+}
+
+#[test]
+fn valid_drafts_need_no_repairs() {
+    let mut input = problem().build();
+    let draft = schedule(&[
+        &[("g0", &["p0", "p1", "p2"]), ("g1", &["p3", "p4", "p5"])],
+        &[("g0", &["p0", "p3", "p4"]), ("g1", &["p1", "p2", "p5"])],
+    ]);
+    input.initial_schedule = Some(draft.clone());
+
+    let repaired = repair_initial_schedule(&input).expect("repair should succeed");
+
+    assert!(repaired.repairs.is_empty());
+    assert_eq!(repaired.schedule, draft);
+}
+
+#[test]
+fn repair_needs_a_free_seat_and_an_initial_schedule() {
+    let mut input = ApiInput::builder()
+        .people(["p0", "p1", "p2"])
+        .group("g0", 2)
+        .sessions(1)
+        .build();
+    assert!(matches!(
+        repair_initial_schedule(&input),
+        Err(SolverError::ValidationError(_))
+    ));
+
+    input.initial_schedule = Some(schedule(&[&[("g0", &["p0", "p1"])]]));
+    assert_eq!(
+        repair_initial_schedule(&input),
+        Err(SolverError::InsufficientCapacity {
+            session: 0,
+            people: 3,
+            capacity: 2,
+        })
+    );
+}
@@ -0,0 +1,263 @@
+#![cfg(feature = "xlsx")]
+
+use gm_core::models::Constraint;
+use gm_core::xlsx_import::{import_workbook, XlsxImportError};
+
+/// Saved with deflate compression, using dynamic, fixed and stored blocks across its parts.
+/// The people sheet is the second tab, and cells use shared, rich, inline, boolean, formula
+/// and numeric values.
+const ATTENDEES: &[u8] = include_bytes!("fixtures/attendees.xlsx");
+
+/// Packs `parts` into an uncompressed ZIP archive.
+fn zip(parts: &[(String, String)]) -> Vec<u8> {
+    let mut archive = Vec::new();
+    let mut directory = Vec::new();
+    for (name, contents) in parts {
+        let offset = archive.len() as u32;
+        let sizes_and_name = |record: &mut Vec<u8>| {
+            record.extend(crc32(contents.as_bytes()).to_le_bytes());
+            record.extend((contents.len() as u32).to_le_bytes());
+            record.extend((contents.len() as u32).to_le_bytes());
+            record.extend((name.len() as u16).to_le_bytes());
+        };
+
+        archive.extend(0x0403_4b50u32.to_le_bytes());
+        archive.extend([20, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        sizes_and_name(&mut archive);
+        archive.extend([0, 0]);
+        archive.extend(name.as_bytes());
+        archive.extend(contents.as_bytes());
+
+        directory.extend(0x0201_4b50u32.to_le_bytes());
+        directory.extend([20, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        sizes_and_name(&mut directory);
+        directory.extend([0; 12]);
+        directory.extend(offset.to_le_bytes());
+        directory.extend(name.as_bytes());
+    }
+
+    let directory_offset = archive.len() as u32;
+    archive.extend(&directory);
+    archive.extend(0x0605_4b50u32.to_le_bytes());
+    archive.extend([0; 4]);
+    archive.extend((parts.len() as u16).to_le_bytes());
+    archive.extend((parts.len() as u16).to_le_bytes());
+    archive.extend((directory.len() as u32).to_le_bytes());
+    archive.extend(directory_offset.to_le_bytes());
+    archive.extend([0, 0]);
+    archive
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0u32, |crc, &byte| {
+        (0..8).fold(crc ^ u32::from(byte), |crc, _| {
+            (crc >> 1) ^ (0xEDB8_8320 & (crc & 1).wrapping_neg())
+        })
+    })
+}
+
+/// A workbook of inline-string sheets, given as rows of cells starting in column A.
+fn workbook(sheets: &[(&str, &[&[&str]])]) -> Vec<u8> {
+    let mut parts = Vec::new();
+    let mut sheet_list = String::new();
+    let mut relationships = String::new();
+    for (index, (name, rows)) in sheets.iter().enumerate() {
+        let number = index + 1;
+        sheet_list.push_str(&format!(
+            r#"<sheet name="{name}" sheetId="{number}" r:id="rId{number}"/>"#
+        ));
+        relationships.push_str(&format!(
+            r#"<Relationship Id="rId{number}" Target="worksheets/sheet{number}.xml"/>"#
+        ));
+        let rows: String = rows
+            .iter()
+            .enumerate()
+            .map(|(row, cells)| {
+                let cells: String = cells
+                    .iter()
+                    .map(|cell| format!(r#"<c t="inlineStr"><is><t>{cell}</t></is></c>"#))
+                    .collect();
+                format!(r#"<row r="{}">{cells}</row>"#, row + 1)
+            })
+            .collect();
+        parts.push((
+            format!("xl/worksheets/sheet{number}.xml"),
+            format!("<worksheet><sheetData>{rows}</sheetData></worksheet>"),
+        ));
+    }
+    parts.push((
+        "xl/workbook.xml".to_string(),
+        format!(r#"<workbook xmlns:r="rels"><sheets>{sheet_list}</sheets></workbook>"#),
+    ));
+    parts.push((
+        "xl/_rels/workbook.xml.rels".to_string(),
+        format!("<Relationships>{relationships}</Relationships>"),
+    ));
+    zip(&parts)
+}
+
+fn error(sheets: &[(&str, &[&[&str]])]) -> XlsxImportError {
+    import_workbook(&workbook(sheets)).expect_err("import should fail")
+}
+
+#[test]
+fn workbook_people_and_constraints_are_imported() {
+    let import = import_workbook(ATTENDEES).expect("valid workbook");
+
+    let ids: Vec<_> = import
+        .people
+        .iter()
+        .map(|person| person.id.as_str())
+        .collect();
+    assert_eq!(ids, ["alice", "bob", "Smith & Carol", "42"]);
+    assert_eq!(import.people[0].attributes["vip"], "TRUE");
+    assert_eq!(import.people[0].sessions, None);
+    assert_eq!(import.people[1].sessions, Some(vec![0, 2]));
+    assert_eq!(import.people[2].attributes["team"], "red");
+    assert_eq!(import.people[2].sessions, Some(vec![1]));
+    assert!(!import.people[2].attributes.contains_key("vip"));
+    assert_eq!(import.people[3].attributes["team"], "green");
+
+    assert_eq!(import.constraints.len(), 3);
+    assert!(matches!(
+        &import.constraints[0],
+        Constraint::MustStayTogether { people, sessions: None } if people == &["alice", "bob"]
+    ));
+    assert!(matches!(
+        &import.constraints[1],
+        Constraint::ShouldNotBeTogether { people, penalty_weight, sessions }
+            if people == &["bob", "Smith & Carol"]
+                && *penalty_weight == 250.5
+                && sessions == &Some(vec![0, 2])
+    ));
+    assert!(matches!(
+        &import.constraints[2],
+        Constraint::ShouldStayTogether { people, penalty_weight, .. }
+            if people == &["42", "alice"] && *penalty_weight == 1000.0
+    ));
+}
+
+#[test]
+fn the_first_sheet_holds_people_unless_one_is_named_people() {
+    let import = import_workbook(&workbook(&[
+        ("Attendees", &[&["id", "team"], &["alice", "red"]]),
+        ("Notes", &[&["id"], &["not a person"]]),
+    ]))
+    .expect("valid workbook");
+
+    assert_eq!(import.people.len(), 1);
+    assert_eq!(import.people[0].attributes["team"], "red");
+    assert!(import.constraints.is_empty());
+}
+
+#[test]
+fn errors_name_the_sheet_row_and_column() {
+    let duplicate = error(&[("People", &[&["id"], &["alice"], &["bob"], &["alice"]])]);
+    assert_eq!(duplicate.sheet.as_deref(), Some("People"));
+    assert_eq!(duplicate.row, Some(4));
+    assert_eq!(duplicate.column.as_deref(), Some("id"));
+    assert!(duplicate.message.contains("first used on row 2"));
+
+    let outside = error(&[("People", &[&["id"], &["alice", "red"]])]);
+    assert_eq!(outside.row, Some(2));
+    assert_eq!(outside.column.as_deref(), Some("B"));
+
+    let unknown_type = error(&[
+        ("People", &[&["id"], &["alice"], &["bob"]]),
+        (
+            "Constraints",
+            &[&["type", "people"], &["KeepTogether", "alice;bob"]],
+        ),
+    ]);
+    assert_eq!(unknown_type.sheet.as_deref(), Some("Constraints"));
+    assert_eq!(unknown_type.column.as_deref(), Some("type"));
+
+    let weighted_hard = error(&[
+        ("People", &[&["id"], &["alice"], &["bob"]]),
+        (
+            "Constraints",
+            &[
+                &["type", "people", "penalty_weight"],
+                &["MustStayApart", "alice, bob", "10"],
+            ],
+        ),
+    ]);
+    assert_eq!(weighted_hard.column.as_deref(), Some("penalty_weight"));
+    assert_eq!(
+        weighted_hard.to_string(),
+        "sheet 'Constraints', row 2, column 'penalty_weight': MustStayApart is a hard \
+         constraint and takes no penalty_weight"
+    );
+
+    let lone = error(&[
+        ("People", &[&["id"], &["alice"]]),
+        (
+            "Constraints",
+            &[&["type", "people"], &["MustStayApart", "alice"]],
+        ),
+    ]);
+    assert_eq!(lone.column.as_deref(), Some("people"));
+}
+
+#[test]
+fn non_workbooks_are_rejected() {
+    let not_zip = import_workbook(b"id,team\nalice,red\n").unwrap_err();
+    assert_eq!(not_zip.sheet, None);
+    assert!(not_zip.message.contains("not an .xlsx workbook"));
+
+    let truncated = import_workbook(&ATTENDEES[..ATTENDEES.len() / 2]).unwrap_err();
+    assert_eq!(truncated.sheet, None);
+}
+
+/// Deterministic stand-in for a fuzzer: flips, overwrites and truncates bytes of the fixture
+/// and of a stored-entry workbook, which must be rejected or imported but never panic.
+#[test]
+fn corrupted_workbooks_never_panic() {
+    let stored = workbook(&[
+        ("People", &[&["id", "team"], &["alice", "red"]]),
+        (
+            "Constraints",
+            &[&["type", "people"], &["MustStayApart", "alice;bob"]],
+        ),
+    ]);
+    let mut state = 0x9e37_79b9_7f4a_7c15u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state as usize
+    };
+
+    for original in [ATTENDEES, stored.as_slice()] {
+        for _ in 0..2_000 {
+            let mut bytes = original.to_vec();
+            for _ in 0..1 + next() % 4 {
+                let at = next() % bytes.len();
+                match next() % 3 {
+                    0 => bytes[at] ^= 1 << (next() % 8),
+                    1 => bytes[at] = next() as u8,
+                    _ => bytes.truncate(at.max(1)),
+                }
+            }
+            let _ = import_workbook(&bytes);
+        }
+    }
+}
+
+#[test]
+fn oversized_entries_are_refused_before_reading() {
+    let mut bytes = workbook(&[("People", &[&["id"], &["alice"]])]);
+    // Declare a 1 GiB uncompressed size for every entry in the central directory.
+    let mut offset = 0;
+    while let Some(found) = bytes[offset..]
+        .windows(4)
+        .position(|window| window == 0x0201_4b50u32.to_le_bytes())
+    {
+        let entry = offset + found;
+        bytes[entry + 24..entry + 28].copy_from_slice(&(1u32 << 30).to_le_bytes());
+        offset = entry + 4;
+    }
+
+    let error = import_workbook(&bytes).unwrap_err();
+    assert!(error.message.contains("larger than 64 MiB"), "{error}");
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "yaml")]
+
+use gm_core::models::{ApiInput, Constraint};
+
+const INPUT: &str = r#"
+# Hand-edited problem: comments are fine here.
+problem:
+  people:
+    - {id: alice, attributes: {team: red}}
+    - {id: bob, attributes: {team: blue}}
+    - {id: carol, attributes: {team: red}}
+    - {id: dave, attributes: {team: blue}}
+  groups:
+    - {id: t1, size: 2}
+    - {id: t2, size: 2}
+  num_sessions: 2
+objectives:
+  - {type: maximize_unique_contacts, weight: 1.0}
+constraints:
+  - type: ShouldNotBeTogether
+    people: [alice, carol]
+    penalty_weight: 5.0
+solver:
+  solver_type: solver1
+  seed: 9
+  stop_conditions: {max_iterations: 500}
+"#;
+
+#[test]
+fn yaml_input_matches_the_json_equivalent() {
+    let from_yaml = ApiInput::from_yaml_str(INPUT).expect("yaml should parse");
+    let json = serde_json::to_string(&from_yaml).expect("serialize");
+    let from_json: ApiInput = serde_json::from_str(&json).expect("json should parse");
+
+    assert_eq!(from_yaml.problem.num_sessions, 2);
+    assert_eq!(from_yaml.problem.people[1].attributes["team"], "blue");
+    assert!(matches!(
+        from_yaml.constraints[0],
+        Constraint::ShouldNotBeTogether { penalty_weight, .. } if penalty_weight == 5.0
+    ));
+    assert_eq!(from_yaml.solver.seed, Some(9));
+    assert_eq!(
+        serde_json::to_value(&from_json).unwrap(),
+        serde_json::to_value(&from_yaml).unwrap()
+    );
+    gm_core::run_solver(&from_yaml).expect("solve should succeed");
+}
+
+#[test]
+fn yaml_errors_point_at_the_offending_line() {
+    let error =
+        ApiInput::from_yaml_str("problem:\n  people: []\n  groups: []\n  num_sessions: two\n")
+            .unwrap_err();
+
+    assert_eq!(error.location().map(|at| at.line()), Some(4));
+}
@@ -0,0 +1,137 @@
+// Tests for ../js/solver_client.js against a fake Worker. Run with `node --test backend/wasm/js-tests/`.
+
+import assert from "node:assert/strict";
+import { afterEach, beforeEach, test } from "node:test";
+
+import { createSolverWorker, sharedAbortAvailable } from "../js/solver_client.js";
+
+class FakeWorker {
+  static instances = [];
+
+  constructor(url, options) {
+    this.url = url;
+    this.options = options;
+    this.posted = [];
+    this.terminated = false;
+    FakeWorker.instances.push(this);
+  }
+
+  postMessage(message) {
+    this.posted.push(message);
+  }
+
+  terminate() {
+    this.terminated = true;
+  }
+
+  reply(data) {
+    this.onmessage({ data });
+  }
+}
+
+beforeEach(() => {
+  FakeWorker.instances = [];
+  globalThis.Worker = FakeWorker;
+  globalThis.crossOriginIsolated = false;
+});
+
+afterEach(() => {
+  delete globalThis.Worker;
+  delete globalThis.crossOriginIsolated;
+});
+
+const workerUrl = "file:///solver_worker.js";
+
+test("solve forwards progress and resolves with the worker's result", async () => {
+  const client = createSolverWorker({ workerUrl });
+  const progress = [];
+
+  const solving = client.solve({ scenario: {} }, { onProgress: (update) => progress.push(update) });
+  const [worker] = FakeWorker.instances;
+  const [message] = worker.posted;
+  assert.equal(message.type, "solve");
+  assert.equal(message.reportProgress, true);
+  assert.deepEqual(worker.options, { type: "module" });
+
+  worker.reply({ type: "progress", id: message.id, progress: { iteration: 1 } });
+  worker.reply({ type: "result", id: message.id, result: { final_score: 3 } });
+
+  assert.deepEqual(await solving, { final_score: 3 });
+  assert.deepEqual(progress, [{ iteration: 1 }]);
+});
+
+test("solves share one worker and are matched by id", async () => {
+  const client = createSolverWorker({ workerUrl });
+
+  const first = client.solve({ n: 1 });
+  const second = client.solve({ n: 2 });
+  assert.equal(FakeWorker.instances.length, 1);
+  const [worker] = FakeWorker.instances;
+  const [firstMessage, secondMessage] = worker.posted;
+
+  worker.reply({ type: "result", id: secondMessage.id, result: "second" });
+  worker.reply({ type: "error", id: firstMessage.id, error: "bad input" });
+
+  assert.equal(await second, "second");
+  await assert.rejects(first, (error) => error === "bad input");
+});
+
+test("with shared memory, aborting sets the flag and the solve resolves", async () => {
+  globalThis.crossOriginIsolated = true;
+  assert.equal(sharedAbortAvailable(), true);
+  const client = createSolverWorker({ workerUrl });
+  const controller = new AbortController();
+
+  const solving = client.solve({}, { signal: controller.signal });
+  const [worker] = FakeWorker.instances;
+  const [message] = worker.posted;
+  const flag = new Int32Array(message.abortBuffer);
+  assert.equal(Atomics.load(flag, 0), 0);
+
+  controller.abort();
+  assert.equal(Atomics.load(flag, 0), 1);
+  assert.equal(worker.terminated, false);
+
+  worker.reply({ type: "result", id: message.id, result: "best so far" });
+  assert.equal(await solving, "best so far");
+});
+
+test("without shared memory, aborting terminates the worker and rejects", async () => {
+  assert.equal(sharedAbortAvailable(), false);
+  const client = createSolverWorker({ workerUrl });
+  const controller = new AbortController();
+
+  const solving = client.solve({}, { signal: controller.signal });
+  const [worker] = FakeWorker.instances;
+  assert.equal(worker.posted[0].abortBuffer, null);
+
+  controller.abort();
+  assert.equal(worker.terminated, true);
+  await assert.rejects(solving, { name: "AbortError" });
+
+  const next = client.solve({});
+  assert.equal(FakeWorker.instances.length, 2);
+  const replacement = FakeWorker.instances[1];
+  replacement.reply({ type: "result", id: replacement.posted[0].id, result: "fresh" });
+  assert.equal(await next, "fresh");
+});
+
+test("an already aborted signal rejects without starting a solve", async () => {
+  const client = createSolverWorker({ workerUrl });
+
+  await assert.rejects(client.solve({}, { signal: AbortSignal.abort() }), { name: "AbortError" });
+  assert.equal(FakeWorker.instances.length, 0);
+});
+
+test("a crashed worker rejects pending solves and is replaced", async () => {
+  const client = createSolverWorker({ workerUrl });
+
+  const solving = client.solve({});
+  const [worker] = FakeWorker.instances;
+  worker.onerror({ message: "out of memory", preventDefault() {} });
+
+  await assert.rejects(solving, { message: "out of memory" });
+  assert.equal(worker.terminated, true);
+  client.solve({});
+  assert.equal(FakeWorker.instances.length, 2);
+});
@@ -0,0 +1,78 @@
+// Tests for ../js/solver_worker.js with a stand-in for the generated gm_wasm.js.
+// Run with `node --test backend/wasm/js-tests/`.
+
+import assert from "node:assert/strict";
+import { copyFile, mkdtemp, writeFile } from "node:fs/promises";
+import { tmpdir } from "node:os";
+import { join } from "node:path";
+import { before, beforeEach, test } from "node:test";
+import { fileURLToPath, pathToFileURL } from "node:url";
+
+// Reports what it was called with; `fail: true` inputs throw like a rejected solve.
+const FAKE_WASM = `
+export default async function init() {}
+export function init_panic_hook() {}
+export function solve_cancellable(input, onProgress, signal) {
+  if (input.fail) {
+    throw new Error("invalid input");
+  }
+  const continued = onProgress ? onProgress({ iteration: 1 }) : null;
+  return { input, continued, hasSignal: signal !== null, aborted: signal?.aborted ?? false };
+}
+`;
+
+const posted = [];
+
+before(async () => {
+  const dir = await mkdtemp(join(tmpdir(), "gm-solver-worker-"));
+  await writeFile(join(dir, "package.json"), '{ "type": "module" }');
+  await writeFile(join(dir, "gm_wasm.js"), FAKE_WASM);
+  const worker = fileURLToPath(new URL("../js/solver_worker.js", import.meta.url));
+  await copyFile(worker, join(dir, "solver_worker.js"));
+
+  globalThis.self = { postMessage: (message) => posted.push(message) };
+  await import(pathToFileURL(join(dir, "solver_worker.js")).href);
+});
+
+beforeEach(() => {
+  posted.length = 0;
+});
+
+function solve(message) {
+  return self.onmessage({ data: { type: "solve", ...message } });
+}
+
+test("posts progress updates and then the result", async () => {
+  await solve({ id: 7, input: { scenario: {} }, abortBuffer: null, reportProgress: true });
+
+  assert.deepEqual(posted[0], { type: "progress", id: 7, progress: { iteration: 1 } });
+  assert.equal(posted[1].type, "result");
+  assert.equal(posted[1].id, 7);
+  assert.equal(posted[1].result.continued, true);
+  assert.equal(posted[1].result.hasSignal, false);
+});
+
+test("reads cancellation from the shared abort flag", async () => {
+  const abortBuffer = new SharedArrayBuffer(4);
+
+  await solve({ id: 1, input: {}, abortBuffer, reportProgress: false });
+  assert.equal(posted[0].result.hasSignal, true);
+  assert.equal(posted[0].result.aborted, false);
+
+  Atomics.store(new Int32Array(abortBuffer), 0, 1);
+  await solve({ id: 2, input: {}, abortBuffer, reportProgress: false });
+  assert.equal(posted[1].result.aborted, true);
+});
+
+test("reports solver errors by message", async () => {
+  await solve({ id: 3, input: { fail: true }, abortBuffer: null, reportProgress: false });
+
+  assert.deepEqual(posted, [{ type: "error", id: 3, error: "invalid input" }]);
+});
+
+test("ignores messages that are not solve requests", async () => {
+  await self.onmessage({ data: { type: "ping" } });
+  await self.onmessage({ data: null });
+
+  assert.deepEqual(posted, []);
+});
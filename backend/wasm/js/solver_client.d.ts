@@ -0,0 +1,31 @@
+/** Options for {@link SolverWorker.solve}. */
+export interface SolveOptions<Progress = unknown> {
+  /** Receives every progress update the solver reports. */
+  onProgress?: (progress: Progress) => void;
+  /**
+   * Stops the solve. With shared memory the promise resolves with the best result so far;
+   * otherwise the worker is terminated and the promise rejects with an `AbortError`.
+   */
+  signal?: AbortSignal;
+}
+
+/** A solver running in its own Web Worker. */
+export interface SolverWorker {
+  /** Solves a `solve-request` payload, resolving with the `solve-response` payload. */
+  solve<Result = unknown, Progress = unknown>(
+    input: unknown,
+    options?: SolveOptions<Progress>,
+  ): Promise<Result>;
+  /** Stops the worker, rejecting every pending solve with an `AbortError`. */
+  terminate(): void;
+}
+
+export interface CreateSolverWorkerOptions {
+  /** Location of `solver_worker.js`; defaults to the copy next to this module. */
+  workerUrl?: string | URL;
+}
+
+/** Whether aborts can stop a running solve cooperatively instead of terminating the worker. */
+export function sharedAbortAvailable(): boolean;
+
+export function createSolverWorker(options?: CreateSolverWorkerOptions): SolverWorker;
@@ -0,0 +1,99 @@
+// Main-thread helper that runs solves in a dedicated Web Worker (./solver_worker.js).
+//
+// Cancellation uses a shared abort flag when `SharedArrayBuffer` is available (the page must be
+// cross-origin isolated); the solver then stops at its next progress report and the promise
+// resolves with the best schedule found so far. Without shared memory the worker is terminated
+// instead, the promise rejects with an `AbortError`, and the next solve starts a fresh worker.
+
+function abortError() {
+  return new DOMException("The solve was aborted.", "AbortError");
+}
+
+export function sharedAbortAvailable() {
+  return typeof SharedArrayBuffer === "function" && globalThis.crossOriginIsolated === true;
+}
+
+export function createSolverWorker(options = {}) {
+  const workerUrl = options.workerUrl ?? new URL("./solver_worker.js", import.meta.url);
+  let worker = null;
+  let nextId = 1;
+  const pending = new Map();
+
+  function rejectAll(error) {
+    for (const entry of pending.values()) {
+      entry.cleanup();
+      entry.reject(error);
+    }
+    pending.clear();
+  }
+
+  function spawn() {
+    worker = new Worker(workerUrl, { type: "module" });
+    worker.onmessage = (event) => {
+      const message = event.data;
+      const entry = pending.get(message?.id);
+      if (!entry) {
+        return;
+      }
+      if (message.type === "progress") {
+        entry.onProgress?.(message.progress);
+        return;
+      }
+      pending.delete(message.id);
+      entry.cleanup();
+      if (message.type === "result") {
+        entry.resolve(message.result);
+      } else {
+        entry.reject(message.error);
+      }
+    };
+    worker.onerror = (event) => {
+      event.preventDefault();
+      const failed = worker;
+      worker = null;
+      failed.terminate();
+      rejectAll(new Error(event.message || "Solver worker failed."));
+    };
+    return worker;
+  }
+
+  function terminate() {
+    if (worker) {
+      worker.terminate();
+      worker = null;
+    }
+    rejectAll(abortError());
+  }
+
+  function solve(input, { onProgress, signal } = {}) {
+    if (signal?.aborted) {
+      return Promise.reject(abortError());
+    }
+
+    const id = nextId++;
+    const abortBuffer = sharedAbortAvailable() ? new SharedArrayBuffer(4) : null;
+    const target = worker ?? spawn();
+
+    return new Promise((resolve, reject) => {
+      const onAbort = () => {
+        if (abortBuffer) {
+          Atomics.store(new Int32Array(abortBuffer), 0, 1);
+        } else {
+          terminate();
+        }
+      };
+      const cleanup = () => signal?.removeEventListener("abort", onAbort);
+      pending.set(id, { resolve, reject, onProgress, cleanup });
+      signal?.addEventListener("abort", onAbort, { once: true });
+      target.postMessage({
+        type: "solve",
+        id,
+        input,
+        abortBuffer,
+        reportProgress: typeof onProgress === "function",
+      });
+    });
+  }
+
+  return { solve, terminate };
+}
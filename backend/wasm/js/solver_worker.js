@@ -0,0 +1,51 @@
+// Web Worker entry that runs the gm-wasm solver off the main thread.
+// Spawned by `createSolverWorker` in ./solver_client.js; see solver_client.d.ts for the protocol.
+
+import init, { init_panic_hook, solve_cancellable } from "./gm_wasm.js";
+
+let ready = null;
+
+function ensureInitialized() {
+  if (!ready) {
+    ready = init().then(() => init_panic_hook());
+  }
+  return ready;
+}
+
+function abortSignalFor(abortBuffer) {
+  if (!abortBuffer) {
+    return null;
+  }
+  const flag = new Int32Array(abortBuffer);
+  return {
+    get aborted() {
+      return Atomics.load(flag, 0) !== 0;
+    },
+  };
+}
+
+self.onmessage = async (event) => {
+  const message = event.data;
+  if (!message || message.type !== "solve") {
+    return;
+  }
+
+  const { id, input, abortBuffer, reportProgress } = message;
+  try {
+    await ensureInitialized();
+    const onProgress = reportProgress
+      ? (progress) => {
+          self.postMessage({ type: "progress", id, progress });
+          return true;
+        }
+      : null;
+    const result = solve_cancellable(input, onProgress, abortSignalFor(abortBuffer));
+    self.postMessage({ type: "result", id, result });
+  } catch (error) {
+    self.postMessage({
+      type: "error",
+      id,
+      error: error instanceof Error ? error.message : error,
+    });
+  }
+};
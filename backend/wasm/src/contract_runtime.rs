@@ -2,7 +2,7 @@
 
 use crate::public_errors::{
     evaluate_requires_initial_schedule_error, infeasible_scenario_error, internal_error,
-    invalid_input_error, parse_error, public_error_to_js_value,
+    invalid_input_error, parse_error, public_error_to_js_value, solver_error,
 };
 use gm_contracts::types::{
     PublicErrorEnvelope, RecommendSettingsRequest, ResultSummary, ValidateResponse, ValidationIssue,
@@ -10,8 +10,8 @@ use gm_contracts::types::{
 use gm_core::{
     calculate_recommended_settings_for, default_solver_configuration, default_solver_kind,
     models::{
-        ApiInput, Constraint, Group, Objective, Person, ProblemDefinition, ProgressUpdate,
-        SolverConfiguration, SolverResult,
+        ApiInput, Constraint, ContactHistory, Group, Objective, Person, ProblemDefinition,
+        ProgressUpdate, SolverConfiguration, SolverResult,
     },
     run_solver, run_solver_with_progress,
     solver::State,
@@ -19,6 +19,7 @@ use gm_core::{
 use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use serde::Serialize;
+use std::collections::HashMap;
 use wasm_bindgen::JsValue;
 
 const MAX_SAFE_JS_INTEGER: u64 = 9_007_199_254_740_991;
@@ -82,6 +83,8 @@ struct WasmScenarioContractInput {
     initial_schedule: Option<WasmInitialSchedule>,
     #[serde(default)]
     construction_seed_schedule: Option<WasmInitialSchedule>,
+    #[serde(default)]
+    contact_history: Option<ContactHistory>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -152,6 +155,17 @@ pub fn solve_with_progress_snapshot_js(
     serialize_output(&result, "solve")
 }
 
+pub fn solve_cancellable_js(
+    input: JsValue,
+    progress_callback: Option<js_sys::Function>,
+    abort_signal: Option<JsValue>,
+) -> Result<JsValue, JsValue> {
+    let request = parse_wasm_scenario_input(input, "solve", &["solve-request"])?;
+    let result = solve_cancellable_contract(&request, progress_callback, abort_signal)
+        .map_err(|error| public_error_to_js_value(&error))?;
+    serialize_output(&result, "solve")
+}
+
 pub fn validate_scenario_contract_js(input: JsValue) -> Result<JsValue, JsValue> {
     let request = parse_wasm_scenario_input(input, "validate-scenario", &["validate-request"])?;
     let response = validate_scenario_contract(&request);
@@ -189,18 +203,41 @@ pub fn inspect_result_contract_js(result: JsValue) -> Result<JsValue, JsValue> {
 
 pub fn solve_contract(request: &ApiInput) -> Result<SolverResult, PublicErrorEnvelope> {
     let adjusted = ensure_browser_safe_seed(request)?;
-    run_solver(&adjusted).map_err(|error| infeasible_scenario_error("solve", error.to_string()))
+    run_solver(&adjusted).map_err(|error| solver_error("solve", &error, &adjusted))
 }
 
 pub fn solve_with_progress_contract(
     request: &ApiInput,
     progress_callback: Option<js_sys::Function>,
 ) -> Result<SolverResult, PublicErrorEnvelope> {
-    solve_with_mapped_progress_contract(request, progress_callback, None, |progress| {
+    solve_with_mapped_progress_contract(request, progress_callback, None, None, |progress| {
         progress.clone()
     })
 }
 
+/// Solves with optional progress reporting, stopping early once `abort_signal.aborted` is
+/// truthy and returning the best schedule found so far.
+///
+/// The flag is read at every progress report, but the solve blocks the thread it runs on, so
+/// nothing queued on that thread's event loop runs until it returns. An `AbortSignal` aborted
+/// from the same thread therefore only counts if it was aborted before the call. To cancel a
+/// running solve, run it in a Web Worker and pass an object whose `aborted` getter reads a
+/// flag the page sets through a `SharedArrayBuffer`, as `js/solver_worker.js` does, or return
+/// `false` from `progress_callback`.
+pub fn solve_cancellable_contract(
+    request: &ApiInput,
+    progress_callback: Option<js_sys::Function>,
+    abort_signal: Option<JsValue>,
+) -> Result<SolverResult, PublicErrorEnvelope> {
+    solve_with_mapped_progress_contract(
+        request,
+        progress_callback,
+        None,
+        abort_signal,
+        |progress| progress.clone(),
+    )
+}
+
 pub fn solve_with_progress_snapshot_contract(
     request: &ApiInput,
     progress_callback: Option<js_sys::Function>,
@@ -210,6 +247,7 @@ pub fn solve_with_progress_snapshot_contract(
         request,
         progress_callback,
         best_schedule_callback,
+        None,
         |progress| WasmProgressSnapshot::from(progress),
     )
 }
@@ -218,6 +256,7 @@ fn solve_with_mapped_progress_contract<T, F>(
     request: &ApiInput,
     progress_callback: Option<js_sys::Function>,
     best_schedule_callback: Option<js_sys::Function>,
+    abort_signal: Option<JsValue>,
     map_progress: F,
 ) -> Result<SolverResult, PublicErrorEnvelope>
 where
@@ -226,8 +265,15 @@ where
 {
     let adjusted = ensure_browser_safe_seed(request)?;
 
-    if let Some(js_callback) = progress_callback {
+    if progress_callback.is_some() || abort_signal.is_some() {
         let rust_callback = Box::new(move |progress: &ProgressUpdate| -> bool {
+            if abort_signal.as_ref().is_some_and(is_aborted) {
+                return false;
+            }
+            let Some(js_callback) = &progress_callback else {
+                return true;
+            };
+
             let payload = map_progress(progress);
             let progress_value = match serde_wasm_bindgen::to_value(&payload) {
                 Ok(value) => value,
@@ -277,12 +323,19 @@ where
             unsafe { std::mem::transmute(rust_callback) };
 
         run_solver_with_progress(&adjusted, Some(&rust_callback))
-            .map_err(|error| infeasible_scenario_error("solve", error.to_string()))
+            .map_err(|error| solver_error("solve", &error, &adjusted))
     } else {
-        run_solver(&adjusted).map_err(|error| infeasible_scenario_error("solve", error.to_string()))
+        run_solver(&adjusted).map_err(|error| solver_error("solve", &error, &adjusted))
     }
 }
 
+/// Reads `signal.aborted`; see [`solve_cancellable_contract`] for when it can change mid-solve.
+fn is_aborted(signal: &JsValue) -> bool {
+    js_sys::Reflect::get(signal, &JsValue::from_str("aborted"))
+        .map(|aborted| aborted.is_truthy())
+        .unwrap_or(false)
+}
+
 impl From<&ProgressUpdate> for WasmProgressSnapshot {
     fn from(progress: &ProgressUpdate) -> Self {
         Self {
@@ -399,8 +452,8 @@ pub fn evaluate_input_contract(request: &ApiInput) -> Result<SolverResult, Publi
         return Err(evaluate_requires_initial_schedule_error());
     }
 
-    let mut state = State::new(&adjusted)
-        .map_err(|error| infeasible_scenario_error("evaluate-input", error.to_string()))?;
+    let mut state =
+        State::new(&adjusted).map_err(|error| solver_error("evaluate-input", &error, &adjusted))?;
     state._recalculate_locations_from_schedule();
     state._recalculate_scores();
     Ok(state.to_solver_result(state.current_cost, 0))
@@ -455,6 +508,7 @@ impl WasmScenario {
         self,
         initial_schedule: Option<WasmInitialSchedule>,
         construction_seed_schedule: Option<WasmInitialSchedule>,
+        contact_history: Option<ContactHistory>,
     ) -> ApiInput {
         ApiInput {
             problem: ProblemDefinition {
@@ -464,6 +518,7 @@ impl WasmScenario {
             },
             initial_schedule,
             construction_seed_schedule,
+            contact_history,
             objectives: default_objectives(self.objectives),
             constraints: self.constraints,
             solver: self.settings,
@@ -473,9 +528,11 @@ impl WasmScenario {
 
 impl From<WasmScenarioContractInput> for ApiInput {
     fn from(value: WasmScenarioContractInput) -> Self {
-        value
-            .scenario
-            .into_api_input(value.initial_schedule, value.construction_seed_schedule)
+        value.scenario.into_api_input(
+            value.initial_schedule,
+            value.construction_seed_schedule,
+            value.contact_history,
+        )
     }
 }
 
@@ -506,6 +563,8 @@ fn default_objectives(objectives: Vec<Objective>) -> Vec<Objective> {
         vec![Objective {
             r#type: "maximize_unique_contacts".to_string(),
             weight: 1.0,
+            person_weights: HashMap::new(),
+            session_weights: Vec::new(),
         }]
     } else {
         objectives
@@ -530,6 +589,7 @@ mod tests {
         gm_core::models::ApiInput {
             initial_schedule: None,
             construction_seed_schedule: None,
+            contact_history: None,
             problem: ProblemDefinition {
                 people: vec![
                     Person {
@@ -558,11 +618,15 @@ mod tests {
                         id: "g0".to_string(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                     Group {
                         id: "g1".to_string(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                 ],
                 num_sessions: 2,
@@ -570,6 +634,8 @@ mod tests {
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".to_string(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![],
             solver: SolverConfiguration {
@@ -579,6 +645,9 @@ mod tests {
                     time_limit_seconds: None,
                     no_improvement_iterations: Some(5),
                     stop_on_optimal_score: true,
+                    stop_on_feasible: false,
+                    feasible_score_threshold: None,
+                    convergence: None,
                 },
                 solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
                     initial_temperature: 5.0,
@@ -586,6 +655,7 @@ mod tests {
                     cooling_schedule: "geometric".to_string(),
                     reheat_after_no_improvement: Some(0),
                     reheat_cycles: Some(0),
+                    penalty_ramp: None,
                 }),
                 logging: Default::default(),
                 telemetry: Default::default(),
@@ -696,6 +766,8 @@ mod tests {
             repetition_penalty: 1,
             elapsed_seconds: 1.5,
             no_improvement_count: 2,
+            estimated_remaining_seconds: None,
+            phase: None,
             clique_swaps_tried: 1,
             clique_swaps_accepted: 1,
             clique_swaps_rejected: 0,
@@ -731,9 +803,13 @@ mod tests {
                 "session_0".to_string(),
                 HashMap::from([("g0".to_string(), vec!["p0".to_string()])]),
             )])),
+            best_schedule_diff: None,
             effective_seed: Some(42),
             move_policy: None,
             stop_reason: Some(StopReason::MaxIterationsReached),
+            checkpoint: None,
+            move_statistics: None,
+            constraint_penalties: None,
         };
 
         let snapshot = WasmProgressSnapshot::from(&progress);
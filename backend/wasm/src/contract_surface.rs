@@ -68,6 +68,11 @@ const WASM_BINDINGS: &[WasmContractBinding] = &[
         operation_id: Some(SOLVE_OPERATION_ID),
         note: "Mailbox-oriented solve export returning structured solve results while emitting scalar-only progress snapshots.",
     },
+    WasmContractBinding {
+        export_name: "solve_cancellable",
+        operation_id: Some(SOLVE_OPERATION_ID),
+        note: "Contract-native solve export with optional progress callbacks that stops early once an `aborted` flag is set; mid-solve cancellation needs the flag to be set from another thread, such as a SharedArrayBuffer written by the page while the solve runs in a worker.",
+    },
     WasmContractBinding {
         export_name: "validate_scenario",
         operation_id: Some(VALIDATE_SCENARIO_OPERATION_ID),
@@ -99,6 +99,22 @@ pub fn solve_with_progress_snapshot(
     )
 }
 
+/// Solves like [`solve_with_progress`], stopping early once `abort_signal.aborted` becomes
+/// truthy and returning the best schedule found so far.
+///
+/// The solve blocks its thread, so an `AbortSignal` aborted on that same thread cannot take
+/// effect until it returns. Cancel a running solve from another thread: run it in a worker
+/// with a `SharedArrayBuffer`-backed flag (see `js/solver_client.js`).
+#[wasm_bindgen]
+pub fn solve_cancellable(
+    input: JsValue,
+    progress_callback: Option<js_sys::Function>,
+    abort_signal: Option<JsValue>,
+) -> Result<JsValue, JsValue> {
+    init_panic_hook();
+    contract_runtime::solve_cancellable_js(input, progress_callback, abort_signal)
+}
+
 #[wasm_bindgen]
 pub fn validate_scenario(input: JsValue) -> Result<JsValue, JsValue> {
     init_panic_hook();
@@ -147,6 +163,7 @@ mod tests {
         ApiInput {
             initial_schedule: None,
             construction_seed_schedule: None,
+            contact_history: None,
             problem: ProblemDefinition {
                 people: vec![
                     Person {
@@ -175,11 +192,15 @@ mod tests {
                         id: "g0".to_string(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                     Group {
                         id: "g1".to_string(),
                         size: 2,
                         session_sizes: None,
+                        attributes: HashMap::new(),
+                        available_sessions: None,
                     },
                 ],
                 num_sessions: 2,
@@ -187,6 +208,8 @@ mod tests {
             objectives: vec![Objective {
                 r#type: "maximize_unique_contacts".to_string(),
                 weight: 1.0,
+                person_weights: HashMap::new(),
+                session_weights: Vec::new(),
             }],
             constraints: vec![],
             solver: SolverConfiguration {
@@ -196,6 +219,9 @@ mod tests {
                     time_limit_seconds: None,
                     no_improvement_iterations: Some(10),
                     stop_on_optimal_score: true,
+                    stop_on_feasible: false,
+                    feasible_score_threshold: None,
+                    convergence: None,
                 },
                 solver_params: SolverParams::SimulatedAnnealing(SimulatedAnnealingParams {
                     initial_temperature: 5.0,
@@ -203,6 +229,7 @@ mod tests {
                     cooling_schedule: "geometric".to_string(),
                     reheat_after_no_improvement: Some(0),
                     reheat_cycles: Some(0),
+                    penalty_ramp: None,
                 }),
                 logging: Default::default(),
                 telemetry: Default::default(),
@@ -363,6 +390,30 @@ mod tests {
             .all(|schedule| schedule.get("session_0").is_some()));
     }
 
+    #[wasm_bindgen_test]
+    fn solve_cancellable_stops_when_the_signal_is_aborted() {
+        let calls = Rc::new(RefCell::new(0usize));
+        let calls_clone = Rc::clone(&calls);
+        let callback = Closure::wrap(Box::new(move |_progress: JsValue| -> JsValue {
+            *calls_clone.borrow_mut() += 1;
+            JsValue::from_bool(true)
+        }) as Box<dyn FnMut(JsValue) -> JsValue>);
+        let function: js_sys::Function = callback
+            .as_ref()
+            .unchecked_ref::<js_sys::Function>()
+            .clone();
+
+        let signal = js_sys::Object::new();
+        js_sys::Reflect::set(&signal, &"aborted".into(), &JsValue::TRUE).unwrap();
+
+        let result_value =
+            solve_cancellable(valid_input_js(), Some(function), Some(signal.into())).unwrap();
+        let result: serde_json::Value = serde_wasm_bindgen::from_value(result_value).unwrap();
+
+        assert_eq!(*calls.borrow(), 0);
+        assert!(result.get("schedule").is_some());
+    }
+
     #[wasm_bindgen_test]
     fn validate_scenario_reports_expected_shape() {
         let result_value =
@@ -8,6 +8,7 @@ use gm_contracts::{
     schemas::schema_specs,
     types::{PublicError, PublicErrorEnvelope},
 };
+use gm_core::{models::ApiInput, solver_support::SolverError};
 use wasm_bindgen::JsValue;
 
 pub fn public_error_to_js_value(error: &PublicErrorEnvelope) -> JsValue {
@@ -104,6 +105,17 @@ pub fn infeasible_scenario_error(
     )
 }
 
+/// An [`infeasible_scenario_error`] that also names the entities of `input` the error refers to.
+pub fn solver_error(
+    operation_id: &str,
+    error: &SolverError,
+    input: &ApiInput,
+) -> PublicErrorEnvelope {
+    let mut envelope = infeasible_scenario_error(operation_id, error.to_string());
+    envelope.error.details = Some(error.details(input));
+    envelope
+}
+
 pub fn internal_error(operation_id: &str, message: impl Into<String>) -> PublicErrorEnvelope {
     api_error(
         INTERNAL_ERROR,
@@ -163,6 +175,7 @@ fn api_error(
                     .map(|operation_id| operation_id.to_string())
                     .collect()
             }),
+            details: None,
         },
     }
 }
@@ -53,11 +53,13 @@ print_success "Contract reference artifacts OK"
 # ============================================
 
 print_step "WASM: Building gm-wasm"
-cd backend/wasm
-wasm-pack build --target web --out-dir ../../webapp/public/pkg
-cd ../..
+./scripts/build-wasm.sh
 print_success "WASM build OK"
 
+print_step "WASM: Testing the worker helpers"
+node --test backend/wasm/js-tests/
+print_success "WASM worker helper tests OK"
+
 # ============================================
 # Frontend checks
 # ============================================
@@ -0,0 +1,12 @@
+#!/usr/bin/env bash
+# Builds gm-wasm and copies the worker helpers from backend/wasm/js next to it.
+# Usage: ./scripts/build-wasm.sh [out-dir]   (default: webapp/public/pkg)
+set -euo pipefail
+
+repo_root="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
+out_dir="${1:-$repo_root/webapp/public/pkg}"
+mkdir -p "$out_dir"
+out_dir="$(cd "$out_dir" && pwd)"
+
+wasm-pack build "$repo_root/backend/wasm" --target web --out-dir "$out_dir"
+cp "$repo_root"/backend/wasm/js/*.js "$repo_root"/backend/wasm/js/*.d.ts "$out_dir/"
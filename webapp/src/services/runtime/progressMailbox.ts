@@ -141,6 +141,8 @@ const STOP_REASON_TO_CODE: Record<StopReason, number> = {
   no_improvement_limit_reached: 3,
   progress_callback_requested_stop: 4,
   optimal_score_reached: 5,
+  converged: 6,
+  feasible_reached: 7,
 };
 
 const CODE_TO_STOP_REASON: Record<number, StopReason | undefined> = {
@@ -150,6 +152,8 @@ const CODE_TO_STOP_REASON: Record<number, StopReason | undefined> = {
   3: 'no_improvement_limit_reached',
   4: 'progress_callback_requested_stop',
   5: 'optimal_score_reached',
+  6: 'converged',
+  7: 'feasible_reached',
 };
 
 const FIELD_OFFSETS: Record<NumericField, number> = NUMERIC_FIELDS.reduce((offsets, field, index) => {
@@ -238,6 +238,11 @@ export interface WasmContractModule {
     progressCallback?: ((progress: WasmProgressSnapshot) => boolean) | null,
     bestScheduleCallback?: ((schedule: WarmStartSchedule) => void) | null,
   ) => RustResult;
+  solve_cancellable?: (
+    input: WasmContractSolveInput,
+    progressCallback?: WasmContractProgressCallback | null,
+    abortSignal?: { readonly aborted: boolean } | null,
+  ) => RustResult;
   validate_scenario: (input: WasmContractSolveInput) => WasmValidateResponse;
   get_default_solver_configuration: () => SolverSettings;
   recommend_settings: (input: WasmRecommendSettingsRequest) => SolverSettings;
@@ -276,6 +281,7 @@ export const WASM_RUNTIME_EXPORT_NAMES = {
   solve: "solve",
   solve_with_progress: "solve_with_progress",
   solve_with_progress_snapshot: "solve_with_progress_snapshot",
+  solve_cancellable: "solve_cancellable",
   validate_scenario: "validate_scenario",
   get_default_solver_configuration: "get_default_solver_configuration",
   recommend_settings: "recommend_settings",
@@ -89,6 +89,7 @@ export const recommend_settings = bindRuntimeFunction("recommend_settings");
 export const solve = bindRuntimeFunction("solve");
 export const solve_with_progress = bindRuntimeFunction("solve_with_progress");
 export const solve_with_progress_snapshot = bindRuntimeFunction("solve_with_progress_snapshot");
+export const solve_cancellable = bindRuntimeFunction("solve_cancellable");
 export const validate_scenario = bindRuntimeFunction("validate_scenario");
 export const evaluate_input = bindRuntimeFunction("evaluate_input");
 export const inspect_result = bindRuntimeFunction("inspect_result");
@@ -21,7 +21,9 @@ export type StopReason =
   | "no_improvement_limit_reached"
   | "no_improvement_time_limit_reached"
   | "progress_callback_requested_stop"
-  | "optimal_score_reached";
+  | "optimal_score_reached"
+  | "converged"
+  | "feasible_reached";
 
 export type AutoConstructorOutcome =
   | "initial_schedule"
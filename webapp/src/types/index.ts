@@ -156,7 +156,9 @@ export type StopReason =
   | "no_improvement_limit_reached"
   | "no_improvement_time_limit_reached"
   | "progress_callback_requested_stop"
-  | "optimal_score_reached";
+  | "optimal_score_reached"
+  | "converged"
+  | "feasible_reached";
 
 export type AutoConstructorOutcome =
   | "initial_schedule"
@@ -216,11 +218,20 @@ export interface SolverBenchmarkTelemetry {
   moves: MoveFamilyBenchmarkTelemetrySummary;
 }
 
+export interface ConvergenceStop {
+  window_iterations?: number;
+  max_acceptance_rate?: number;
+  max_temperature?: number | null;
+}
+
 export interface StopConditions {
   max_iterations?: number;
   time_limit_seconds?: number;
   no_improvement_iterations?: number;
   stop_on_optimal_score?: boolean;
+  stop_on_feasible?: boolean;
+  feasible_score_threshold?: number;
+  convergence?: ConvergenceStop | null;
 }
 
 export interface SolverParams {
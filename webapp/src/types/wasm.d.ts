@@ -17,6 +17,7 @@ declare module 'virtual:wasm-solver' {
   export const recommend_settings: WasmContractModule['recommend_settings'];
   export const solve: WasmContractModule['solve'];
   export const solve_with_progress: WasmContractModule['solve_with_progress'];
+  export const solve_cancellable: NonNullable<WasmContractModule['solve_cancellable']>;
   export const validate_scenario: WasmContractModule['validate_scenario'];
   export const evaluate_input: WasmContractModule['evaluate_input'];
   export const inspect_result: WasmContractModule['inspect_result'];